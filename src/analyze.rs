@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use glob::glob;
+use rayon::prelude::*;
+
+use crate::extract::symbols::BodySummary;
+use crate::pipeline::extract_all_body_summaries;
+
+/// Expands `patterns` (shell-style globs, e.g. `src/**/*.rs`) to a deduplicated set of files,
+/// then parses and summarizes every function body in each one in parallel via rayon — the same
+/// glob-expansion-plus-`par_iter` shape a parallel directory walker would use, just fanned out
+/// across whole files instead of across one file's functions.
+///
+/// A file that fails to read or parse is dropped from the result rather than aborting the whole
+/// run; there's no per-file error channel in this signature, so a caller that needs to know why
+/// a particular file is missing should fall back to [`crate::pipeline::parse_rust_file`] directly.
+pub fn analyze_paths(patterns: &[&str]) -> Vec<(PathBuf, Vec<BodySummary>)> {
+    let mut paths: Vec<PathBuf> = Vec::new();
+
+    for pattern in patterns {
+        let Ok(matches) = glob(pattern) else {
+            continue;
+        };
+        paths.extend(matches.filter_map(Result::ok));
+    }
+
+    paths.sort();
+    paths.dedup();
+
+    paths
+        .into_par_iter()
+        .filter_map(|path| {
+            let content = std::fs::read_to_string(&path).ok()?;
+            let summaries = extract_all_body_summaries(&content).ok()?;
+            Some((path, summaries))
+        })
+        .collect()
+}
@@ -0,0 +1,299 @@
+use std::collections::HashMap;
+
+use crate::export::{FileSafetyFacts, SafetyDocument};
+use crate::extract::safety::{ItemDoc, PanicKind, PanicPoint, RiskLevel, TestInfo, UnsafeBlock, UnsafeOperation};
+
+/// A single risk-scored finding, the cross-language unit [`build_audit_report`] emits regardless
+/// of whether it came from an `UnsafeBlock`, a `PanicPoint`, a `BlockingCall`, or a Python
+/// `PythonDangerousCall` — a reviewer triaging the report doesn't need to know which extractor
+/// produced which row.
+#[derive(Debug, Clone)]
+pub struct AuditFinding {
+    pub file: String,
+    pub line: usize,
+    pub containing_function: Option<String>,
+    pub category: String,
+    pub risk_level: RiskLevel,
+    pub description: String,
+}
+
+/// Per-function finding counts by [`RiskLevel`], the granularity a reviewer drills into after
+/// scanning [`AuditReport::by_file`].
+#[derive(Debug, Clone, Default)]
+pub struct FunctionRollup {
+    pub file: String,
+    pub function: String,
+    pub high: usize,
+    pub medium: usize,
+    pub low: usize,
+}
+
+/// Per-file finding counts by [`RiskLevel`].
+#[derive(Debug, Clone, Default)]
+pub struct FileRollup {
+    pub file: String,
+    pub high: usize,
+    pub medium: usize,
+    pub low: usize,
+}
+
+/// The full output of [`build_audit_report`]: every finding across both languages, most severe
+/// first, plus the per-function and per-file rollups a reviewer uses to decide where to look
+/// first.
+#[derive(Debug, Clone, Default)]
+pub struct AuditReport {
+    pub findings: Vec<AuditFinding>,
+    pub by_function: Vec<FunctionRollup>,
+    pub by_file: Vec<FileRollup>,
+}
+
+fn severity_rank(level: RiskLevel) -> u8 {
+    match level {
+        RiskLevel::High => 0,
+        RiskLevel::Medium => 1,
+        RiskLevel::Low => 2,
+    }
+}
+
+/// Shifts `level` one step towards [`RiskLevel::Low`] — used to down-rank a finding whose
+/// containing function already documents the hazard (a `# Safety`/`# Panics` section present).
+fn downgrade(level: RiskLevel) -> RiskLevel {
+    match level {
+        RiskLevel::High => RiskLevel::Medium,
+        RiskLevel::Medium | RiskLevel::Low => RiskLevel::Low,
+    }
+}
+
+/// Shifts `level` one step towards [`RiskLevel::High`] — used to up-rank a finding whose
+/// containing function has no documentation covering the hazard.
+fn upgrade(level: RiskLevel) -> RiskLevel {
+    match level {
+        RiskLevel::Low => RiskLevel::Medium,
+        RiskLevel::Medium | RiskLevel::High => RiskLevel::High,
+    }
+}
+
+fn find_item_doc<'a>(doc_info_items: &'a [ItemDoc], name: &str) -> Option<&'a ItemDoc> {
+    doc_info_items.iter().find(|item| item.item_name == name)
+}
+
+fn is_test_function(test_info: &TestInfo, name: &str) -> bool {
+    test_info.test_functions.iter().any(|f| f.name == name)
+}
+
+fn unsafe_operation_category(op: &UnsafeOperation) -> (&'static str, RiskLevel) {
+    match op {
+        UnsafeOperation::InlineAssembly => ("inline-assembly", RiskLevel::High),
+        UnsafeOperation::MutableStaticAccess(..) => ("mutable-static-access", RiskLevel::High),
+        UnsafeOperation::RawPointerDeref { .. } => ("raw-pointer-deref", RiskLevel::Medium),
+        UnsafeOperation::UnionFieldAccess => ("union-field-access", RiskLevel::Medium),
+        UnsafeOperation::UnsafeFunctionCall(..) => ("unsafe-function-call", RiskLevel::Medium),
+        UnsafeOperation::ExternCall(..) => ("extern-call", RiskLevel::Medium),
+        UnsafeOperation::Other(_) => ("other-unsafe-operation", RiskLevel::Medium),
+    }
+}
+
+fn panic_kind_category(kind: &PanicKind) -> (&'static str, RiskLevel) {
+    match kind {
+        PanicKind::Unwrap => ("unwrap", RiskLevel::Medium),
+        PanicKind::Expect(_) => ("expect", RiskLevel::Medium),
+        PanicKind::PanicMacro(_) => ("panic-macro", RiskLevel::Medium),
+        PanicKind::UnreachableMacro(_) => ("unreachable-macro", RiskLevel::Medium),
+        PanicKind::TodoMacro(_) => ("todo-macro", RiskLevel::Medium),
+        PanicKind::UnimplementedMacro(_) => ("unimplemented-macro", RiskLevel::Medium),
+        PanicKind::Assert(_) => ("assert-macro", RiskLevel::Medium),
+        PanicKind::AssertFalse => ("assert-false", RiskLevel::Medium),
+        PanicKind::IndexAccess => ("index-access", RiskLevel::Medium),
+        PanicKind::RaiseException(_) => ("raise-exception", RiskLevel::Medium),
+        PanicKind::DefiniteOutOfBounds { .. } => ("definite-out-of-bounds", RiskLevel::High),
+    }
+}
+
+/// Scores one `UnsafeBlock`'s worst operation, then down/up-ranks it against whether its
+/// containing function's doc comment has a `# Safety` section.
+fn score_unsafe_block(block: &UnsafeBlock, item_docs: &[ItemDoc]) -> (&'static str, RiskLevel) {
+    let (category, base_level) = block
+        .operations
+        .iter()
+        .map(unsafe_operation_category)
+        .min_by_key(|(_, level)| severity_rank(*level))
+        .unwrap_or(("unsafe-block", RiskLevel::Medium));
+
+    let documented = block
+        .containing_function
+        .as_deref()
+        .and_then(|name| find_item_doc(item_docs, name))
+        .map(|item| item.has_safety_section)
+        .unwrap_or(false);
+
+    let level = if documented {
+        downgrade(base_level)
+    } else {
+        upgrade(base_level)
+    };
+
+    (category, level)
+}
+
+/// Scores one `PanicPoint`, down-ranking to [`RiskLevel::Low`] when it's inside a `#[test]`
+/// function, then down/up-ranking against whether its containing function's doc comment has a
+/// `# Panics` section.
+fn score_panic_point(point: &PanicPoint, item_docs: &[ItemDoc], test_info: &TestInfo) -> (&'static str, RiskLevel) {
+    let (category, base_level) = panic_kind_category(&point.kind);
+
+    if point
+        .containing_function
+        .as_deref()
+        .is_some_and(|name| is_test_function(test_info, name))
+    {
+        return (category, RiskLevel::Low);
+    }
+
+    let documented = point
+        .containing_function
+        .as_deref()
+        .and_then(|name| find_item_doc(item_docs, name))
+        .map(|item| item.has_panics_section)
+        .unwrap_or(false);
+
+    let level = if documented {
+        downgrade(base_level)
+    } else {
+        upgrade(base_level)
+    };
+
+    (category, level)
+}
+
+fn findings_for_file(facts: &FileSafetyFacts) -> Vec<AuditFinding> {
+    let mut findings = Vec::new();
+
+    for block in &facts.safety.unsafe_blocks {
+        let (category, risk_level) = score_unsafe_block(block, &facts.doc_info.item_docs);
+        findings.push(AuditFinding {
+            file: facts.file.clone(),
+            line: block.line,
+            containing_function: block.containing_function.clone(),
+            category: category.to_string(),
+            risk_level,
+            description: format!(
+                "unsafe block performing {}",
+                block
+                    .operations
+                    .iter()
+                    .map(|op| op.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        });
+    }
+
+    for point in &facts.safety.panic_points {
+        let (category, risk_level) = score_panic_point(point, &facts.doc_info.item_docs, &facts.test_info);
+        findings.push(AuditFinding {
+            file: facts.file.clone(),
+            line: point.line,
+            containing_function: point.containing_function.clone(),
+            category: category.to_string(),
+            risk_level,
+            description: point.kind.to_string(),
+        });
+    }
+
+    for call in &facts.async_info.blocking_calls {
+        if !call.in_async_context {
+            continue;
+        }
+        findings.push(AuditFinding {
+            file: facts.file.clone(),
+            line: call.line,
+            containing_function: call.containing_function.clone(),
+            category: "blocking-call-in-async".to_string(),
+            risk_level: RiskLevel::High,
+            description: format!("{} blocks the executor while in an async context", call.call),
+        });
+    }
+
+    for call in &facts.python_safety.dangerous_calls {
+        findings.push(AuditFinding {
+            file: facts.file.clone(),
+            line: call.line,
+            containing_function: call.containing_function.clone(),
+            category: call.category.clone(),
+            risk_level: call.risk_level,
+            description: format!("dangerous call: {}", call.call_name),
+        });
+    }
+
+    findings
+}
+
+fn rollups(findings: &[AuditFinding]) -> (Vec<FunctionRollup>, Vec<FileRollup>) {
+    let mut by_function: HashMap<(String, String), FunctionRollup> = HashMap::new();
+    let mut by_file: HashMap<String, FileRollup> = HashMap::new();
+
+    for finding in findings {
+        let file_rollup = by_file.entry(finding.file.clone()).or_insert_with(|| FileRollup {
+            file: finding.file.clone(),
+            ..Default::default()
+        });
+        bump(file_rollup_counts(file_rollup), finding.risk_level);
+
+        if let Some(function) = &finding.containing_function {
+            let key = (finding.file.clone(), function.clone());
+            let function_rollup = by_function.entry(key).or_insert_with(|| FunctionRollup {
+                file: finding.file.clone(),
+                function: function.clone(),
+                ..Default::default()
+            });
+            bump(function_rollup_counts(function_rollup), finding.risk_level);
+        }
+    }
+
+    let mut by_function: Vec<FunctionRollup> = by_function.into_values().collect();
+    by_function.sort_by(|a, b| (&a.file, &a.function).cmp(&(&b.file, &b.function)));
+
+    let mut by_file: Vec<FileRollup> = by_file.into_values().collect();
+    by_file.sort_by(|a, b| a.file.cmp(&b.file));
+
+    (by_function, by_file)
+}
+
+fn file_rollup_counts(rollup: &mut FileRollup) -> (&mut usize, &mut usize, &mut usize) {
+    (&mut rollup.high, &mut rollup.medium, &mut rollup.low)
+}
+
+fn function_rollup_counts(rollup: &mut FunctionRollup) -> (&mut usize, &mut usize, &mut usize) {
+    (&mut rollup.high, &mut rollup.medium, &mut rollup.low)
+}
+
+fn bump(counts: (&mut usize, &mut usize, &mut usize), level: RiskLevel) {
+    let (high, medium, low) = counts;
+    match level {
+        RiskLevel::High => *high += 1,
+        RiskLevel::Medium => *medium += 1,
+        RiskLevel::Low => *low += 1,
+    }
+}
+
+/// Builds a cross-language [`AuditReport`] from a [`SafetyDocument`] snapshot: every
+/// `UnsafeBlock`, `PanicPoint`, in-async `BlockingCall`, and Python `PythonDangerousCall` scored
+/// by [`RiskLevel`] and sorted most severe first, alongside per-function and per-file rollup
+/// counts.
+pub fn build_audit_report(doc: &SafetyDocument) -> AuditReport {
+    let mut findings: Vec<AuditFinding> = doc.files.iter().flat_map(findings_for_file).collect();
+    findings.sort_by(|a, b| {
+        severity_rank(a.risk_level)
+            .cmp(&severity_rank(b.risk_level))
+            .then_with(|| a.file.cmp(&b.file))
+            .then_with(|| a.line.cmp(&b.line))
+    });
+
+    let (by_function, by_file) = rollups(&findings);
+
+    AuditReport {
+        findings,
+        by_function,
+        by_file,
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use tokio::process::Command;
+
+use crate::extract::symbol_diff::kind_label;
+use crate::git::resolve_executable;
+use crate::pipeline::{parse_rust_file, ParsedFile};
+
+/// Where a symbol folded in from `OUT_DIR` actually came from: the `include!` call site that
+/// pulled it into the including module, plus the generated file it was defined in. Mirrors
+/// [`crate::macroexpand::MacroOrigin`]'s "attribute the generated fact back to a real source
+/// location" shape, but for build-script output rather than macro expansion.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GeneratedOrigin {
+    /// Module path (`foo::bar`, or empty for the crate root) the `include!` was written in.
+    pub including_module: String,
+    /// Line of the `include!(..)` call in the including file.
+    pub include_line: usize,
+    /// Path to the `OUT_DIR` file the `include!` pulled in, relative to `OUT_DIR` itself.
+    pub generated_file: String,
+}
+
+/// One symbol found only by parsing an `OUT_DIR` file reached through an `include!`, attributed
+/// to whichever module included it. `generated` is always `true` — callers fold these into the
+/// same symbol list [`crate::output::symbols`] walks, so a renderer that wants to flag them only
+/// needs to check this field rather than re-deriving provenance.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct GeneratedSymbol {
+    pub name: String,
+    pub kind_label: &'static str,
+    pub generated: bool,
+    pub origin: GeneratedOrigin,
+}
+
+/// Runs `cargo build --message-format=json` in `manifest_dir` and collects the `out_dir` cargo
+/// reports for each package's build script, keyed by package name. This is the only reliable way
+/// to learn `OUT_DIR` for a crate: it's chosen by cargo at build time and isn't derivable from the
+/// manifest or a fixed path convention, so rather than guessing at `target/*/build/*/out` this
+/// reads it back from the same build-script-executed messages `cargo` itself emits.
+pub async fn out_dirs(manifest_dir: &Path) -> Result<HashMap<String, PathBuf>> {
+    let output = Command::new(resolve_executable("cargo"))
+        .args(["build", "--message-format=json"])
+        .current_dir(manifest_dir)
+        .output()
+        .await
+        .map_err(|e| anyhow!("failed to spawn cargo build: {e}"))?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "cargo build failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let mut dirs = HashMap::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let Ok(msg) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if msg.get("reason").and_then(|v| v.as_str()) != Some("build-script-executed") {
+            continue;
+        }
+        let Some(out_dir) = msg.get("out_dir").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        let Some(package_id) = msg.get("package_id").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        let package_name = package_id.split_whitespace().next().unwrap_or(package_id);
+        dirs.insert(package_name.to_string(), PathBuf::from(out_dir));
+    }
+
+    Ok(dirs)
+}
+
+/// Finds every `include!(..)` in `source` whose argument expands to a path under `OUT_DIR` —
+/// `include!(concat!(env!("OUT_DIR"), "/foo.rs"))` and the simpler `include!(env!("OUT_DIR"))`
+/// form some build scripts use when they generate exactly one file. Textual, not macro-expanded:
+/// charter's parser sees `include!` as an opaque builtin call (see `BUILTIN_MACROS` in
+/// `pipeline::parse`), so this looks for the `env!("OUT_DIR")` marker and pulls the trailing
+/// string literal out by hand rather than attempting a real `concat!` evaluation.
+pub fn find_out_dir_includes(source: &str) -> Vec<(usize, String)> {
+    let mut includes = Vec::new();
+
+    for (idx, line) in source.lines().enumerate() {
+        if !line.contains("include!") || !line.contains("env!(\"OUT_DIR\")") {
+            continue;
+        }
+
+        let relative_file = line
+            .rsplit('"')
+            .nth(1)
+            .filter(|s| line.matches('"').count() >= 4)
+            .unwrap_or("")
+            .trim_start_matches('/')
+            .to_string();
+
+        includes.push((idx + 1, relative_file));
+    }
+
+    includes
+}
+
+/// Parses every `OUT_DIR` file `includes` points at (as found by [`find_out_dir_includes`] in
+/// `including_file`'s source) and folds their symbols into a flat list attributed back to
+/// `including_module`, so generated protobuf/FFI/grammar bindings show up in the symbol index
+/// instead of the "build.rs generated code" gap noted in the capture format's Known Limitations.
+pub fn fold_generated_symbols(
+    including_file: &str,
+    out_dir: &Path,
+    includes: &[(usize, String)],
+) -> Result<Vec<GeneratedSymbol>> {
+    let including_module = crate::output::module_path_from_file(including_file);
+    let mut generated = Vec::new();
+
+    for (include_line, relative_file) in includes {
+        if relative_file.is_empty() {
+            continue;
+        }
+        let generated_path = out_dir.join(relative_file);
+        let content = std::fs::read_to_string(&generated_path).map_err(|e| {
+            anyhow!(
+                "failed to read generated file {}: {e}",
+                generated_path.display()
+            )
+        })?;
+
+        let parsed: ParsedFile = parse_rust_file(&content, relative_file)?;
+        for symbol in &parsed.symbols.symbols {
+            generated.push(GeneratedSymbol {
+                name: symbol.name.clone(),
+                kind_label: kind_label(&symbol.kind),
+                generated: true,
+                origin: GeneratedOrigin {
+                    including_module: including_module.clone(),
+                    include_line: *include_line,
+                    generated_file: relative_file.clone(),
+                },
+            });
+        }
+    }
+
+    Ok(generated)
+}
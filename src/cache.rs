@@ -1,5 +1,9 @@
+mod blob;
+pub mod index;
 mod types;
 
+pub use blob::load_filtered;
+pub use index::{IndexedDocument, SearchIndex};
 pub use types::{CacheEntry, FileData};
 
 use anyhow::Result;
@@ -0,0 +1,129 @@
+//! A lazy, seek-based view onto `cache.bin` for callers that only need a handful of entries
+//! (e.g. [`crate::tests::build_test_mapping`]'s `--file` path on a monorepo-sized cache) instead
+//! of the full eager [`Cache::load`]. [`load_filtered`] maintains a path -> byte-range index
+//! (`cache.idx`) over a flat per-entry blob (`cache.entries.bin`), rebuilding both transparently
+//! from `cache.bin` whenever they're missing or older than it — covering both "never built yet"
+//! and "built by an older, now-stale capture" without a dedicated format-version check.
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::cache::{Cache, CacheEntry};
+
+const INDEX_MAGIC: &[u8; 8] = b"CHTRIDX1";
+
+/// Byte range of one file's [`CacheEntry`] inside `cache.entries.bin`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct EntrySpan {
+    offset: u64,
+    len: u64,
+}
+
+/// Maps each cached file path to its [`EntrySpan`] in the sibling blob file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct EntryIndex {
+    spans: HashMap<String, EntrySpan>,
+}
+
+fn index_path(charter_dir: &Path) -> PathBuf {
+    charter_dir.join("cache.idx")
+}
+
+fn blob_path(charter_dir: &Path) -> PathBuf {
+    charter_dir.join("cache.entries.bin")
+}
+
+/// Serializes every entry in `cache` independently into a flat blob plus a path -> byte-range
+/// index, and writes both next to `cache.bin`. Called once per stale/missing index by
+/// [`load_filtered`], after which repeat queries reuse the index until `cache.bin` changes again.
+async fn build(charter_dir: &Path, cache: &Cache) -> Result<EntryIndex> {
+    let mut paths: Vec<&String> = cache.entries.keys().collect();
+    paths.sort();
+
+    let mut blob = Vec::new();
+    let mut index = EntryIndex::default();
+
+    for path in paths {
+        let entry = &cache.entries[path];
+        let bytes = bincode::serialize(entry)?;
+        let offset = blob.len() as u64;
+        let len = bytes.len() as u64;
+        blob.extend_from_slice(&bytes);
+        index.spans.insert(path.clone(), EntrySpan { offset, len });
+    }
+
+    let mut index_bytes = INDEX_MAGIC.to_vec();
+    index_bytes.extend_from_slice(&bincode::serialize(&index)?);
+
+    tokio::fs::write(blob_path(charter_dir), &blob).await?;
+    tokio::fs::write(index_path(charter_dir), &index_bytes).await?;
+
+    Ok(index)
+}
+
+/// Reads `cache.idx` and returns it only if its magic header checks out and it's at least as
+/// new as `cache.bin` — anything else (missing file, bad magic, an older-format index left over
+/// from a prior capture) is treated as "no usable index" so [`load_filtered`] rebuilds it.
+async fn read_index(charter_dir: &Path) -> Option<EntryIndex> {
+    let (index_meta, cache_meta) = tokio::join!(
+        tokio::fs::metadata(index_path(charter_dir)),
+        tokio::fs::metadata(charter_dir.join("cache.bin")),
+    );
+    let (index_meta, cache_meta) = (index_meta.ok()?, cache_meta.ok()?);
+    if index_meta.modified().ok()? < cache_meta.modified().ok()? {
+        return None;
+    }
+
+    let bytes = tokio::fs::read(index_path(charter_dir)).await.ok()?;
+    let body = bytes.strip_prefix(INDEX_MAGIC.as_slice())?;
+    bincode::deserialize(body).ok()
+}
+
+/// Memory-maps `cache.entries.bin` and deserializes only the entries whose path satisfies `keep`,
+/// rebuilding the index from a full [`Cache::load`] first if it's missing or stale. Returns an
+/// empty map (rather than erroring) if `cache.bin` itself doesn't exist yet.
+pub async fn load_filtered(
+    charter_dir: &Path,
+    keep: impl Fn(&str) -> bool,
+) -> Result<HashMap<String, CacheEntry>> {
+    let cache_bin = charter_dir.join("cache.bin");
+    if !cache_bin.exists() {
+        return Ok(HashMap::new());
+    }
+
+    let index = match read_index(charter_dir).await {
+        Some(index) => index,
+        None => {
+            let cache = Cache::load(&cache_bin).await?;
+            build(charter_dir, &cache).await?
+        }
+    };
+
+    let wanted: Vec<(String, EntrySpan)> = index
+        .spans
+        .into_iter()
+        .filter(|(path, _)| keep(path))
+        .collect();
+
+    if wanted.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let blob_path = blob_path(charter_dir);
+    tokio::task::spawn_blocking(move || -> Result<HashMap<String, CacheEntry>> {
+        let file = std::fs::File::open(&blob_path)?;
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+
+        let mut entries = HashMap::with_capacity(wanted.len());
+        for (path, span) in wanted {
+            let start = span.offset as usize;
+            let end = start + span.len as usize;
+            let entry: CacheEntry = bincode::deserialize(&mmap[start..end])?;
+            entries.insert(path, entry);
+        }
+        Ok(entries)
+    })
+    .await?
+}
@@ -0,0 +1,466 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::cache::Cache;
+use crate::extract::symbols::{ImplMethod, Symbol, SymbolKind};
+use crate::pipeline::ParsedFile;
+
+const BM25_K1: f64 = 1.2;
+const BM25_B: f64 = 0.75;
+
+/// Added to a document's BM25 score, scaled down by how wide the tightest window covering every
+/// query term is (`PROXIMITY_BONUS / (1 + window_width)`), so a document where multi-term query
+/// words cluster together outranks one where they're scattered apart.
+const PROXIMITY_BONUS: f64 = 5.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Posting {
+    pub doc_id: String,
+    pub term_frequency: u32,
+    /// Token offsets (within the indexed text) where this term occurred, used by
+    /// [`SearchIndex::proximity_bonus`] to find the tightest window covering every term in a
+    /// multi-term query.
+    pub positions: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedDocument {
+    pub id: String,
+    pub file: String,
+    pub line: usize,
+    pub qualified_name: String,
+    pub kind: String,
+    pub term_count: u32,
+}
+
+/// An inverted index over every symbol's name, qualified path, signature, and doc summary,
+/// persisted alongside `cache.bin` so `Commands::Query`'s keyword search doesn't need to
+/// re-parse or re-scan the generated `.atlas` markdown on every call. `postings` already maps
+/// each token to its `(file, line, term_frequency)` triples via `Posting`/`IndexedDocument`, and
+/// `search` already ranks with length-normalized BM25 rather than an additive scan — this is the
+/// inverted-index design a from-scratch `keyword_search` would otherwise need to build.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    pub postings: HashMap<String, Vec<Posting>>,
+    pub documents: HashMap<String, IndexedDocument>,
+    pub total_term_count: u64,
+}
+
+impl SearchIndex {
+    pub async fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let bytes = tokio::fs::read(path).await?;
+        let index: SearchIndex = bincode::deserialize(&bytes)?;
+        Ok(index)
+    }
+
+    pub async fn save(&self, path: &Path) -> Result<()> {
+        let bytes = bincode::serialize(self)?;
+        tokio::fs::write(path, bytes).await?;
+        Ok(())
+    }
+
+    /// Builds an index from scratch over every file in `cache` — used the first time a
+    /// project is captured, when there's no prior index to update incrementally.
+    pub fn build(cache: &Cache) -> Self {
+        let mut index = Self::default();
+        for (file, entry) in &cache.entries {
+            index.update_file(file, &entry.data.parsed);
+        }
+        index
+    }
+
+    /// Drops every document and posting belonging to `file`, then re-indexes it from
+    /// `parsed`. Callers should only call this for files that changed since the last
+    /// capture (`FileResult::from_cache == false`) — an untouched file's postings otherwise
+    /// survive a capture unmodified.
+    pub fn update_file(&mut self, file: &str, parsed: &ParsedFile) {
+        self.remove_file(file);
+
+        for symbol in &parsed.symbols.symbols {
+            self.index_symbol(file, None, symbol);
+        }
+
+        for inherent_impl in &parsed.symbols.inherent_impls {
+            for method in &inherent_impl.methods {
+                self.index_method(file, &inherent_impl.type_name, method);
+            }
+        }
+    }
+
+    /// Removes every document (and its postings) belonging to `file` — called both before
+    /// re-indexing a changed file and when a file disappears between captures.
+    pub fn remove_file(&mut self, file: &str) {
+        let removed_ids: Vec<String> = self
+            .documents
+            .iter()
+            .filter(|(_, doc)| doc.file == file)
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        if removed_ids.is_empty() {
+            return;
+        }
+
+        for id in &removed_ids {
+            if let Some(doc) = self.documents.remove(id) {
+                self.total_term_count -= doc.term_count as u64;
+            }
+        }
+
+        for postings in self.postings.values_mut() {
+            postings.retain(|posting| !removed_ids.contains(&posting.doc_id));
+        }
+        self.postings.retain(|_, postings| !postings.is_empty());
+    }
+
+    fn index_symbol(&mut self, file: &str, impl_type: Option<&str>, symbol: &Symbol) {
+        let qualified_name = match impl_type {
+            Some(type_name) => format!("{}::{}", type_name, symbol.name),
+            None => symbol.name.clone(),
+        };
+
+        let mut text = vec![qualified_name.clone()];
+        if let SymbolKind::Function { signature, .. } = &symbol.kind {
+            text.push(signature.clone());
+        }
+        if let Some(doc) = &symbol.doc_summary {
+            text.push(doc.clone());
+        }
+
+        self.insert_document(
+            file,
+            symbol.line,
+            qualified_name,
+            kind_label(&symbol.kind),
+            &text.join(" "),
+        );
+    }
+
+    fn index_method(&mut self, file: &str, type_name: &str, method: &ImplMethod) {
+        let qualified_name = format!("{}::{}", type_name, method.name);
+        let text = format!("{} {}", qualified_name, method.signature);
+        self.insert_document(file, method.line, qualified_name, "fn", &text);
+    }
+
+    fn insert_document(
+        &mut self,
+        file: &str,
+        line: usize,
+        qualified_name: String,
+        kind: &str,
+        text: &str,
+    ) {
+        let id = format!("{}:{}:{}", file, line, qualified_name);
+
+        let mut term_positions: HashMap<String, Vec<u32>> = HashMap::new();
+        for (position, term) in tokenize(text).into_iter().enumerate() {
+            term_positions.entry(term).or_default().push(position as u32);
+        }
+        let term_count: u32 = term_positions.values().map(|positions| positions.len() as u32).sum();
+
+        for (term, positions) in term_positions {
+            self.postings.entry(term).or_default().push(Posting {
+                doc_id: id.clone(),
+                term_frequency: positions.len() as u32,
+                positions,
+            });
+        }
+
+        self.total_term_count += term_count as u64;
+        self.documents.insert(
+            id.clone(),
+            IndexedDocument {
+                id,
+                file: file.to_string(),
+                line,
+                qualified_name,
+                kind: kind.to_string(),
+                term_count,
+            },
+        );
+    }
+
+    fn avg_doc_len(&self) -> f64 {
+        if self.documents.is_empty() {
+            return 0.0;
+        }
+        self.total_term_count as f64 / self.documents.len() as f64
+    }
+
+    /// Ranks documents against `query` with BM25 (`k1 = 1.2`, `b = 0.75`), adding a proximity
+    /// bonus (see [`Self::proximity_bonus`]) when `query` has more than one distinct term, and
+    /// returns the top `limit` matches, highest score first.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<(f64, &IndexedDocument)> {
+        let terms = tokenize(query);
+        if terms.is_empty() || self.documents.is_empty() {
+            return Vec::new();
+        }
+
+        let doc_count = self.documents.len() as f64;
+        let avg_doc_len = self.avg_doc_len();
+
+        let mut scores: HashMap<&str, f64> = HashMap::new();
+
+        for term in &terms {
+            let Some(postings) = self.postings.get(term) else {
+                continue;
+            };
+
+            let doc_frequency = postings.len() as f64;
+            let idf = ((doc_count - doc_frequency + 0.5) / (doc_frequency + 0.5) + 1.0).ln();
+
+            for posting in postings {
+                let Some(doc) = self.documents.get(&posting.doc_id) else {
+                    continue;
+                };
+
+                let term_frequency = posting.term_frequency as f64;
+                let doc_len = doc.term_count as f64;
+                let denom = term_frequency
+                    + BM25_K1 * (1.0 - BM25_B + BM25_B * doc_len / avg_doc_len);
+                let contribution = idf * (term_frequency * (BM25_K1 + 1.0)) / denom;
+
+                *scores.entry(posting.doc_id.as_str()).or_insert(0.0) += contribution;
+            }
+        }
+
+        let mut unique_terms: Vec<&str> = Vec::new();
+        for term in &terms {
+            if !unique_terms.contains(&term.as_str()) {
+                unique_terms.push(term.as_str());
+            }
+        }
+
+        if unique_terms.len() > 1 {
+            for doc_id in scores.keys().copied().collect::<Vec<_>>() {
+                if let Some(bonus) = self.proximity_bonus(doc_id, &unique_terms) {
+                    *scores.get_mut(doc_id).expect("doc_id came from scores' own keys") += bonus;
+                }
+            }
+        }
+
+        let mut ranked: Vec<(f64, &IndexedDocument)> = scores
+            .into_iter()
+            .filter_map(|(doc_id, score)| self.documents.get(doc_id).map(|doc| (score, doc)))
+            .collect();
+
+        ranked.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(Ordering::Equal));
+        ranked.truncate(limit);
+        ranked
+    }
+
+    /// Finds the tightest window of token positions in `doc_id` covering at least one
+    /// occurrence of every term in `terms` (the classic "smallest range covering an element
+    /// from each list" sliding window), and scales [`PROXIMITY_BONUS`] down by its width.
+    /// Returns `None` if `doc_id` is missing any term entirely — a document that doesn't
+    /// contain every query term gets no proximity bonus at all.
+    fn proximity_bonus(&self, doc_id: &str, terms: &[&str]) -> Option<f64> {
+        let mut tagged: Vec<(u32, usize)> = Vec::new();
+
+        for (term_index, term) in terms.iter().enumerate() {
+            let positions = &self
+                .postings
+                .get(*term)?
+                .iter()
+                .find(|posting| posting.doc_id == doc_id)?
+                .positions;
+
+            if positions.is_empty() {
+                return None;
+            }
+            tagged.extend(positions.iter().map(|&position| (position, term_index)));
+        }
+
+        tagged.sort_by_key(|(position, _)| *position);
+
+        let mut counts = vec![0usize; terms.len()];
+        let mut distinct = 0;
+        let mut left = 0;
+        let mut best_width = u32::MAX;
+
+        for right in 0..tagged.len() {
+            let (_, term_index) = tagged[right];
+            if counts[term_index] == 0 {
+                distinct += 1;
+            }
+            counts[term_index] += 1;
+
+            while distinct == terms.len() {
+                best_width = best_width.min(tagged[right].0 - tagged[left].0);
+
+                let (_, left_term) = tagged[left];
+                counts[left_term] -= 1;
+                if counts[left_term] == 0 {
+                    distinct -= 1;
+                }
+                left += 1;
+            }
+        }
+
+        (best_width != u32::MAX).then(|| PROXIMITY_BONUS / (1.0 + best_width as f64))
+    }
+}
+
+fn kind_label(kind: &SymbolKind) -> &'static str {
+    match kind {
+        SymbolKind::Struct { .. } => "struct",
+        SymbolKind::Enum { .. } => "enum",
+        SymbolKind::Trait { .. } => "trait",
+        SymbolKind::Function { .. } => "fn",
+        SymbolKind::Const { .. } => "const",
+        SymbolKind::Static { .. } => "static",
+        SymbolKind::TypeAlias { .. } => "type",
+        SymbolKind::Mod => "mod",
+        SymbolKind::Class { .. } => "class",
+        SymbolKind::PythonFunction { .. } => "def",
+        SymbolKind::Variable { .. } => "variable",
+        SymbolKind::PythonModule => "module",
+    }
+}
+
+/// Tokenizes `text` into lowercase terms, splitting on `::` boundaries, then on
+/// non-alphanumeric characters (so `snake_case` separates on `_`), then on camelCase
+/// boundaries.
+pub fn tokenize(text: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+
+    for segment in text.split("::") {
+        for word in split_on_case_boundaries(segment) {
+            if !word.is_empty() {
+                terms.push(word.to_lowercase());
+            }
+        }
+    }
+
+    terms
+}
+
+fn split_on_case_boundaries(segment: &str) -> Vec<String> {
+    let chars: Vec<char> = segment.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &ch) in chars.iter().enumerate() {
+        if !ch.is_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if ch.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            if prev.is_lowercase() || (prev.is_uppercase() && next_is_lower) {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+
+        current.push(ch);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(id: &str, file: &str, term_count: u32) -> IndexedDocument {
+        IndexedDocument {
+            id: id.to_string(),
+            file: file.to_string(),
+            line: 1,
+            qualified_name: id.to_string(),
+            kind: "fn".to_string(),
+            term_count,
+        }
+    }
+
+    fn posting(doc_id: &str, positions: Vec<u32>) -> Posting {
+        Posting {
+            doc_id: doc_id.to_string(),
+            term_frequency: positions.len() as u32,
+            positions,
+        }
+    }
+
+    /// A document where a multi-term query's words occur right next to each other should
+    /// outrank one with the same BM25 term frequencies but the words scattered far apart — the
+    /// proximity bonus `search` adds on top of BM25 for multi-term queries.
+    #[test]
+    fn search_ranks_tight_term_clusters_above_scattered_ones() {
+        let mut index = SearchIndex::default();
+        index.documents.insert("tight".to_string(), doc("tight", "a.rs", 10));
+        index.documents.insert("scattered".to_string(), doc("scattered", "b.rs", 10));
+        index.total_term_count = 20;
+
+        index.postings.insert(
+            "parse".to_string(),
+            vec![posting("tight", vec![0]), posting("scattered", vec![0])],
+        );
+        index.postings.insert(
+            "query".to_string(),
+            vec![posting("tight", vec![1]), posting("scattered", vec![9])],
+        );
+
+        let ranked = index.search("parse query", 10);
+
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].1.id, "tight");
+        assert_eq!(ranked[1].1.id, "scattered");
+        assert!(ranked[0].0 > ranked[1].0);
+    }
+
+    /// A document missing one of the query's terms entirely gets no proximity bonus, but still
+    /// ranks (on BM25 alone) below one that matches every term.
+    #[test]
+    fn search_gives_no_proximity_bonus_to_a_partial_match() {
+        let mut index = SearchIndex::default();
+        index.documents.insert("full".to_string(), doc("full", "a.rs", 5));
+        index.documents.insert("partial".to_string(), doc("partial", "b.rs", 5));
+        index.total_term_count = 10;
+
+        index.postings.insert(
+            "parse".to_string(),
+            vec![posting("full", vec![0]), posting("partial", vec![0])],
+        );
+        index.postings.insert("query".to_string(), vec![posting("full", vec![1])]);
+
+        let bonus = index.proximity_bonus("partial", &["parse", "query"]);
+        assert!(bonus.is_none());
+
+        let ranked = index.search("parse query", 10);
+        assert_eq!(ranked[0].1.id, "full");
+    }
+
+    /// A query whose terms appear in no document at all returns no results.
+    #[test]
+    fn search_with_no_matching_terms_returns_nothing() {
+        let mut index = SearchIndex::default();
+        index.documents.insert("doc".to_string(), doc("doc", "a.rs", 3));
+        index.total_term_count = 3;
+        index.postings.insert("shared".to_string(), vec![posting("doc", vec![0])]);
+
+        assert!(index.search("zzzzzzzzzz", 10).is_empty());
+    }
+
+    #[test]
+    fn tokenize_splits_on_snake_case_and_camel_case_boundaries() {
+        assert_eq!(tokenize("parse_query"), vec!["parse", "query"]);
+        assert_eq!(tokenize("ParseQueryType"), vec!["parse", "query", "type"]);
+        assert_eq!(tokenize("crate::foo::Bar"), vec!["crate", "foo", "bar"]);
+    }
+}
@@ -5,9 +5,23 @@ use crate::pipeline::ParsedFile;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheEntry {
     pub hash: String,
-    pub mtime: u64,
     pub size: u64,
     pub lines: usize,
+    /// Hash of this file's item *shapes* (symbol names, kinds, signatures, visibility — see
+    /// [`crate::pipeline::item_summary_hash`]), independent of line numbers or body text. Two
+    /// captures with the same `item_summary_hash` but a different `hash` changed only inside
+    /// function bodies, which [`crate::pipeline::build_diff_summary`] uses to skip regenerating
+    /// reports that only describe item shapes rather than behavior.
+    pub item_summary_hash: u64,
+    /// Unix timestamp of this file's most recent commit within the churn window, or `0` if it has
+    /// none. Raw signal behind [`crate::extract::complexity::ComplexityMetrics::recency_score`] —
+    /// cached so `Status`/`Query` can report it without re-walking git history.
+    #[serde(default)]
+    pub last_commit_timestamp: i64,
+    /// Distinct commit authors within the churn window, or `0` alongside
+    /// `last_commit_timestamp == 0`. See [`crate::pipeline::apply_recency_and_author_scores`].
+    #[serde(default)]
+    pub distinct_authors: u32,
     pub data: FileData,
 }
 
@@ -0,0 +1,203 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::cache::Cache;
+use crate::extract::calls::FunctionId;
+
+/// One edge in the flat, name-matched graph [`build_call_graph`] derives from `key_calls`. Unlike
+/// [`crate::callindex::build_call_graph`], which resolves each [`crate::extract::calls::CallEdge`]
+/// against receiver types, this only has a bare call-site identifier to go on, so a callee name
+/// that matches more than one function in the crate produces one edge per match instead of
+/// picking a winner.
+#[derive(Debug, Clone)]
+pub struct CallGraphEdge {
+    pub caller: FunctionId,
+    pub callee: FunctionId,
+}
+
+/// A crate-wide, directed call-hierarchy graph built from every captured body's `key_calls`
+/// rather than from the resolved `call_graph`/`CallEdge` data [`crate::callindex`] already covers
+/// — see [`build_call_graph`].
+#[derive(Debug, Clone, Default)]
+pub struct CallGraph {
+    nodes: Vec<FunctionId>,
+    edges: Vec<CallGraphEdge>,
+}
+
+impl CallGraph {
+    pub fn nodes(&self) -> &[FunctionId] {
+        &self.nodes
+    }
+
+    pub fn edges(&self) -> &[CallGraphEdge] {
+        &self.edges
+    }
+
+    pub fn callees_of(&self, function: &FunctionId) -> Vec<&FunctionId> {
+        self.edges
+            .iter()
+            .filter(|edge| &edge.caller == function)
+            .map(|edge| &edge.callee)
+            .collect()
+    }
+
+    pub fn callers_of(&self, function: &FunctionId) -> Vec<&FunctionId> {
+        self.edges
+            .iter()
+            .filter(|edge| &edge.callee == function)
+            .map(|edge| &edge.caller)
+            .collect()
+    }
+
+    /// Finds cycles via DFS, reporting one cycle per back-edge the traversal meets (each node is
+    /// expanded once) rather than enumerating every simple cycle (which is exponential in the
+    /// worst case).
+    pub fn find_cycles(&self) -> Vec<Vec<FunctionId>> {
+        let mut adjacency: HashMap<&FunctionId, Vec<&FunctionId>> = HashMap::new();
+        for edge in &self.edges {
+            adjacency
+                .entry(&edge.caller)
+                .or_default()
+                .push(&edge.callee);
+        }
+
+        let mut cycles = Vec::new();
+        let mut visited: HashSet<&FunctionId> = HashSet::new();
+
+        for start in &self.nodes {
+            if !visited.contains(start) {
+                let mut path = Vec::new();
+                let mut on_path = HashSet::new();
+                detect_cycle_from(
+                    start,
+                    &adjacency,
+                    &mut path,
+                    &mut on_path,
+                    &mut visited,
+                    &mut cycles,
+                );
+            }
+        }
+
+        cycles
+    }
+
+    /// Renders the graph as Graphviz DOT, one node declaration per function plus one edge per
+    /// call relationship, quoting each `qualified_name()` so callers can feed it straight to `dot`.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph calls {\n");
+
+        for node in &self.nodes {
+            dot.push_str(&format!(
+                "    \"{}\";\n",
+                escape_dot(&node.qualified_name())
+            ));
+        }
+
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\";\n",
+                escape_dot(&edge.caller.qualified_name()),
+                escape_dot(&edge.callee.qualified_name()),
+            ));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn detect_cycle_from<'a>(
+    node: &'a FunctionId,
+    adjacency: &HashMap<&'a FunctionId, Vec<&'a FunctionId>>,
+    path: &mut Vec<&'a FunctionId>,
+    on_path: &mut HashSet<&'a FunctionId>,
+    visited: &mut HashSet<&'a FunctionId>,
+    cycles: &mut Vec<Vec<FunctionId>>,
+) {
+    if on_path.contains(node) {
+        let start = path.iter().position(|n| *n == node).unwrap_or(0);
+        cycles.push(path[start..].iter().map(|n| (*n).clone()).collect());
+        return;
+    }
+
+    if !visited.insert(node) {
+        return;
+    }
+
+    path.push(node);
+    on_path.insert(node);
+
+    if let Some(callees) = adjacency.get(node) {
+        for callee in callees {
+            detect_cycle_from(callee, adjacency, path, on_path, visited, cycles);
+        }
+    }
+
+    path.pop();
+    on_path.remove(node);
+}
+
+/// Reduces a raw `key_calls` call-site expression (`"Type::new"`, `"self.bar"`, `"obj.method"`)
+/// down to the bare identifier a function/method is actually defined under, so it can be looked
+/// up by name without re-deriving receiver types the way `key_calls` itself never recorded.
+fn short_call_name(text: &str) -> &str {
+    let after_path = text.rsplit("::").next().unwrap_or(text);
+    after_path.rsplit('.').next().unwrap_or(after_path)
+}
+
+fn escape_dot(label: &str) -> String {
+    label.replace('"', "\\\"")
+}
+
+/// Builds a crate-wide [`CallGraph`] by matching every captured body's `key_calls` against every
+/// other captured body's name — a coarser, unresolved counterpart to
+/// [`crate::callindex::build_call_graph`] that works directly off the per-function summaries
+/// rather than the dedicated `call_graph`/`CallEdge` extraction pass.
+pub fn build_call_graph(cache: &Cache) -> CallGraph {
+    let mut by_name: HashMap<&str, Vec<FunctionId>> = HashMap::new();
+    let mut nodes = Vec::new();
+
+    for (file, entry) in &cache.entries {
+        for body in &entry.data.parsed.captured_bodies {
+            let id = FunctionId {
+                file: file.clone(),
+                name: body.function_name.clone(),
+                impl_type: body.impl_type.clone(),
+            };
+            by_name
+                .entry(body.function_name.as_str())
+                .or_default()
+                .push(id.clone());
+            nodes.push(id);
+        }
+    }
+
+    let mut edges = Vec::new();
+    for (file, entry) in &cache.entries {
+        for body in &entry.data.parsed.captured_bodies {
+            let Some(summary) = &body.body.summary else {
+                continue;
+            };
+
+            let caller = FunctionId {
+                file: file.clone(),
+                name: body.function_name.clone(),
+                impl_type: body.impl_type.clone(),
+            };
+
+            for (call_text, _position, _category) in &summary.key_calls {
+                let short = short_call_name(call_text);
+                if let Some(targets) = by_name.get(short) {
+                    for target in targets {
+                        edges.push(CallGraphEdge {
+                            caller: caller.clone(),
+                            callee: target.clone(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    CallGraph { nodes, edges }
+}
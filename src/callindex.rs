@@ -0,0 +1,251 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::cache::Cache;
+use crate::extract::calls::{CallEdge, FunctionId};
+
+/// Where a candidate definition lives, plus enough of [`crate::extract::complexity::ComplexityMetrics`]
+/// to decide whether it's worth keeping as a resolution target.
+#[derive(Clone)]
+struct DefinitionSite {
+    file: String,
+    line: usize,
+    is_public: bool,
+}
+
+/// Crate-wide lookup from a callee name to its candidate definition sites, built once per
+/// [`build_call_graph`] call and consulted for every [`CallEdge`] across every file — the same
+/// "build the whole-crate table, then resolve against it" shape [`crate::resolve`] uses for
+/// imports, just keyed by function/method name instead of module path.
+struct SymbolIndex {
+    free_functions: HashMap<String, Vec<DefinitionSite>>,
+    methods: HashMap<(String, String), Vec<DefinitionSite>>,
+    /// Every method definition in the crate, keyed by bare name regardless of `impl_type` —
+    /// consulted only when [`crate::pipeline::infer_receiver_type`] couldn't name the receiver's
+    /// type (reported as `"?"`), so a call like `thing.bar()` still has a shot at resolving
+    /// instead of falling straight through to [`CallTarget::External`].
+    methods_by_name: HashMap<String, Vec<(String, DefinitionSite)>>,
+}
+
+/// [`infer_receiver_type`][crate::pipeline]'s sentinel for "couldn't infer a receiver type" —
+/// not a real type name, so [`SymbolIndex::resolve`] must not look it up literally.
+const UNKNOWN_RECEIVER: &str = "?";
+
+impl SymbolIndex {
+    fn build(cache: &Cache) -> Self {
+        let mut free_functions: HashMap<String, Vec<DefinitionSite>> = HashMap::new();
+        let mut methods: HashMap<(String, String), Vec<DefinitionSite>> = HashMap::new();
+        let mut methods_by_name: HashMap<String, Vec<(String, DefinitionSite)>> = HashMap::new();
+
+        for (file, entry) in &cache.entries {
+            for func in &entry.data.parsed.complexity {
+                let site = DefinitionSite {
+                    file: file.clone(),
+                    line: func.line,
+                    is_public: func.metrics.is_public,
+                };
+
+                match &func.impl_type {
+                    Some(base_type) => {
+                        methods_by_name
+                            .entry(func.name.clone())
+                            .or_default()
+                            .push((base_type.clone(), site.clone()));
+                        methods
+                            .entry((base_type.clone(), func.name.clone()))
+                            .or_default()
+                            .push(site);
+                    }
+                    None => free_functions
+                        .entry(func.name.clone())
+                        .or_default()
+                        .push(site),
+                }
+            }
+        }
+
+        Self {
+            free_functions,
+            methods,
+            methods_by_name,
+        }
+    }
+
+    /// Resolves `edge` as seen from `caller`. A `Self` receiver is resolved against the
+    /// caller's own `impl_type` rather than looked up literally, since `infer_receiver_type`
+    /// reports `self`/`Self` receivers without naming the concrete type. An unresolvable
+    /// receiver (`infer_receiver_type`'s `"?"`) falls back to a name-only match across every
+    /// impl in the crate instead of being treated as a literal (and never-matching) type name.
+    fn resolve(&self, edge: &CallEdge, caller: &FunctionId) -> CallTarget {
+        let type_name = match edge.target_type.as_deref() {
+            Some("Self") => caller.impl_type.as_deref(),
+            Some(UNKNOWN_RECEIVER) => return self.resolve_unknown_receiver(edge),
+            other => other,
+        };
+
+        let candidates = match type_name {
+            Some(base_type) => self
+                .methods
+                .get(&(base_type.to_string(), edge.target.clone())),
+            None => self.free_functions.get(&edge.target),
+        };
+
+        match candidates {
+            None => CallTarget::External,
+            Some(sites) if sites.len() == 1 => CallTarget::Resolved(FunctionId {
+                file: sites[0].file.clone(),
+                name: edge.target.clone(),
+                impl_type: type_name.map(|t| t.to_string()),
+            }),
+            Some(_) => CallTarget::Ambiguous,
+        }
+    }
+
+    /// Name-only fallback for a call whose receiver type couldn't be inferred: looks `edge`'s
+    /// method name up across every impl in the crate, flagging the result [`CallTarget::Unresolved`]
+    /// rather than [`CallTarget::Resolved`] since the match is by name alone, not a confirmed type.
+    fn resolve_unknown_receiver(&self, edge: &CallEdge) -> CallTarget {
+        match self.methods_by_name.get(&edge.target) {
+            None => CallTarget::External,
+            Some(sites) if sites.len() == 1 => {
+                let (base_type, site) = &sites[0];
+                CallTarget::Unresolved(FunctionId {
+                    file: site.file.clone(),
+                    name: edge.target.clone(),
+                    impl_type: Some(base_type.clone()),
+                })
+            }
+            Some(_) => CallTarget::Ambiguous,
+        }
+    }
+}
+
+/// Where a [`CallEdge`] ended up pointing once resolved against the crate's [`SymbolIndex`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallTarget {
+    /// Resolved to exactly one definition site: a free function by name, or a method via a
+    /// known receiver type.
+    Resolved(FunctionId),
+    /// Resolved to exactly one definition site by method name alone, because the receiver's
+    /// type couldn't be inferred — a good guess, not a confirmed type match.
+    Unresolved(FunctionId),
+    /// More than one function/method in the crate shares this name, so the real target can't
+    /// be picked out without type information this index doesn't have.
+    Ambiguous,
+    /// No definition in the crate matches; likely a call into an external crate or `std`.
+    External,
+}
+
+/// One [`CallEdge`] with its resolution, aggregated across every file in the cache.
+#[derive(Debug, Clone)]
+pub struct ResolvedCall {
+    pub caller: FunctionId,
+    pub callee_name: String,
+    pub target: CallTarget,
+    pub line: usize,
+}
+
+/// The whole-crate call graph [`build_call_graph`] produces: every call site paired with
+/// whatever it resolved to.
+#[derive(Debug, Clone, Default)]
+pub struct CrateCallGraph {
+    pub calls: Vec<ResolvedCall>,
+}
+
+impl CrateCallGraph {
+    /// Every call `function` makes — the outgoing half of the hierarchy, already present
+    /// per-call as [`ResolvedCall::caller`] since each call is recorded from its call site.
+    pub fn outgoing(&self, function: &FunctionId) -> Vec<&ResolvedCall> {
+        self.calls
+            .iter()
+            .filter(|call| &call.caller == function)
+            .collect()
+    }
+
+    /// Every resolved (or name-matched) call into `function` found anywhere in the crate — the
+    /// incoming half, computed on demand by scanning for a [`CallTarget::Resolved`] or
+    /// [`CallTarget::Unresolved`] that names `function` rather than stored alongside it,
+    /// mirroring how [`crate::callgraph::CallGraph::callers_of`] derives its reverse direction
+    /// from the same forward edges.
+    pub fn incoming(&self, function: &FunctionId) -> Vec<&ResolvedCall> {
+        self.calls
+            .iter()
+            .filter(|call| match &call.target {
+                CallTarget::Resolved(id) | CallTarget::Unresolved(id) => id == function,
+                _ => false,
+            })
+            .collect()
+    }
+}
+
+/// Builds a crate-wide [`SymbolIndex`] from every cached file's `complexity` list (which already
+/// carries each function/method's name, `impl_type`, definition line, and public-ness) and uses
+/// it to resolve every `CallEdge` in every file's `call_graph`, producing caller -> callee
+/// references in place of `call_graph`'s isolated, unresolved, per-file edges.
+pub fn build_call_graph(cache: &Cache) -> CrateCallGraph {
+    let index = SymbolIndex::build(cache);
+    let mut calls = Vec::new();
+
+    for entry in cache.entries.values() {
+        for call_info in &entry.data.parsed.call_graph {
+            for edge in &call_info.callees {
+                calls.push(ResolvedCall {
+                    caller: call_info.caller.clone(),
+                    callee_name: edge.qualified_target(),
+                    target: index.resolve(edge, &call_info.caller),
+                    line: edge.line,
+                });
+            }
+        }
+    }
+
+    CrateCallGraph { calls }
+}
+
+/// A non-public function or method with no inbound [`CallTarget::Resolved`] or
+/// [`CallTarget::Unresolved`] edge anywhere in `graph` — a candidate for removal, the same notion of "dead" `dead_internal_import_diagnostics`
+/// applies to imports rather than definitions.
+#[derive(Debug, Clone)]
+pub struct DeadFunction {
+    pub id: FunctionId,
+    pub line: usize,
+}
+
+/// Finds every function/method in `cache` that nothing in `graph` resolves a call to, skipping
+/// anything public (an external crate could still call it) or a test (never meant to be called
+/// from crate code at all). A name-matched [`CallTarget::Unresolved`] call counts as reaching
+/// its target here too, since treating a guessed-but-real call as "nothing calls this" would
+/// just trade false-positive references for false-positive dead functions.
+pub fn unreachable_functions(cache: &Cache, graph: &CrateCallGraph) -> Vec<DeadFunction> {
+    let called: HashSet<&FunctionId> = graph
+        .calls
+        .iter()
+        .filter_map(|call| match &call.target {
+            CallTarget::Resolved(id) | CallTarget::Unresolved(id) => Some(id),
+            _ => None,
+        })
+        .collect();
+
+    let mut dead = Vec::new();
+    for (file, entry) in &cache.entries {
+        for func in &entry.data.parsed.complexity {
+            if func.metrics.is_public || func.metrics.is_test {
+                continue;
+            }
+
+            let id = FunctionId {
+                file: file.clone(),
+                name: func.name.clone(),
+                impl_type: func.impl_type.clone(),
+            };
+
+            if !called.contains(&id) {
+                dead.push(DeadFunction {
+                    id,
+                    line: func.line,
+                });
+            }
+        }
+    }
+
+    dead
+}
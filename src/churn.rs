@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+use std::path::Path;
+use tokio::process::Command;
+
+use crate::git::resolve_executable;
+
+/// Marks the start of a commit's `--numstat` block in [`compute_churn`]'s `git log` output, the
+/// same `\u{1}`-prefixed-header trick [`crate::git::get_detailed_churn`] uses to attribute
+/// per-path stat lines back to the commit that produced them without a second pass.
+const HEADER_PREFIX: &str = "\u{1}";
+
+/// Recency half-life, in days, for [`compute_churn`]'s exponential-ish decay: a commit's
+/// contribution is scaled by `1 / (1 + age_in_days / RECENCY_WINDOW_DAYS)`, so a file rewritten
+/// this week outweighs the same line churn from a year ago.
+const RECENCY_WINDOW_DAYS: f64 = 30.0;
+
+/// Computes a recency-weighted per-file churn figure: for every non-merge commit touching a
+/// path, `added + deleted` lines are added to that path's total, scaled by
+/// `1 / (1 + age_in_days / 30)`. Keyed by the same relative-path strings
+/// `FileResult::relative_path` already uses. Returns an empty map (rather than an error) when
+/// `root` isn't a git repository or has no history, so non-git projects still capture cleanly
+/// with `churn_score` simply staying at zero.
+pub async fn compute_churn(root: &Path) -> HashMap<String, u32> {
+    let format_arg = format!("--format={HEADER_PREFIX}%ct");
+
+    let output = Command::new(resolve_executable("git"))
+        .args(["log", "--numstat", "--no-merges", &format_arg])
+        .current_dir(root)
+        .output()
+        .await;
+
+    let output = match output {
+        Ok(o) if o.status.success() => o,
+        _ => return HashMap::new(),
+    };
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut churn: HashMap<String, f64> = HashMap::new();
+    let mut weight = 1.0;
+
+    for line in stdout.lines() {
+        if let Some(header) = line.strip_prefix(HEADER_PREFIX) {
+            let commit_time: i64 = header.trim().parse().unwrap_or(now);
+            let age_days = (now - commit_time).max(0) as f64 / 86_400.0;
+            weight = 1.0 / (1.0 + age_days / RECENCY_WINDOW_DAYS);
+            continue;
+        }
+
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.splitn(3, '\t').collect();
+        let [added, deleted, path] = parts[..] else {
+            continue;
+        };
+
+        // Binary files report `-` for added/deleted instead of a count; treat that as zero
+        // churn rather than dropping the path, so a binary asset still appears in the map.
+        let added: f64 = added.parse().unwrap_or(0.0);
+        let deleted: f64 = deleted.parse().unwrap_or(0.0);
+
+        *churn.entry(path.to_string()).or_insert(0.0) += (added + deleted) * weight;
+    }
+
+    churn
+        .into_iter()
+        .map(|(path, weighted)| (path, weighted.round() as u32))
+        .collect()
+}
@@ -10,10 +10,41 @@ pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Commands>,
 
+    #[arg(
+        long,
+        help = "Force a full walk-and-hash scan instead of git-aware incremental re-analysis (incremental is the default whenever the prior capture's commit is still usable)"
+    )]
+    pub no_incremental: bool,
+
+    #[arg(
+        long,
+        help = "Don't respect .gitignore, global git excludes, or .charterignore during file discovery"
+    )]
+    pub no_ignore: bool,
+
+    #[arg(
+        long = "cfg",
+        help = "Evaluate cfg-gated items as under this configuration (ident or key=value, repeatable) and emit only symbols active under it, instead of recording every item unconditionally"
+    )]
+    pub cfg: Vec<String>,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Additionally emit an extra artifact alongside the usual markdown output: `json` for a versioned, machine-readable export of the full structural model to .charter/model.json plus the dataflow analysis in both JSON (dataflow.json) and bincode (dataflow.bin) form, or `html` for a syntax-highlighted, browsable .charter/snippets.html rendering of the captured function snippets"
+    )]
+    pub format: Option<OutputFormat>,
+
     #[arg(help = "Project root (default: auto-detect from cwd)")]
     pub path: Option<PathBuf>,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Json,
+    Html,
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     #[command(about = "Dump context to stdout for piping into an LLM session")]
@@ -27,11 +58,40 @@ pub enum Commands {
         focus: Option<String>,
         #[arg(long, help = "Show changes since git ref (e.g., HEAD~5, main, abc123)")]
         since: Option<String>,
+        #[arg(long, help = "Emit machine-readable JSON instead of the markdown dump")]
+        json: bool,
+        #[arg(
+            long,
+            help = "Minimum fuzzy match score for --focus results in sections that rank by score (drops low-scoring noise)"
+        )]
+        threshold: Option<i64>,
+        #[arg(long, help = "Print the first doc-comment sentence under each symbol")]
+        docs: bool,
         #[arg(help = "Project root (default: auto-detect from cwd)")]
         path: Option<PathBuf>,
     },
     #[command(about = "Quick summary: crates, files, lines, last capture info")]
     Status {
+        #[arg(
+            long,
+            help = "Diff captured metrics (files/lines) against a prior git ref's committed .charter/meta.json"
+        )]
+        metrics_diff: Option<String>,
+        #[arg(
+            long,
+            help = "List Added/Modified/Removed/Skipped files against the last capture instead of the quick summary, without regenerating symbols.md"
+        )]
+        pending: bool,
+        #[arg(
+            long,
+            help = "With --pending, exit with a nonzero status if anything is out of date"
+        )]
+        exit_code: bool,
+        #[arg(
+            long,
+            help = "With --pending, restrict the report to paths matching this glob"
+        )]
+        glob: Option<String>,
         #[arg(help = "Project root (default: auto-detect from cwd)")]
         path: Option<PathBuf>,
     },
@@ -48,6 +108,21 @@ pub enum Commands {
         query: String,
         #[arg(long, short, default_value = "20", help = "Maximum number of results")]
         limit: usize,
+        #[arg(long, help = "Emit machine-readable JSON instead of the formatted results")]
+        json: bool,
+        #[arg(help = "Project root (default: auto-detect from cwd)")]
+        path: Option<PathBuf>,
+    },
+    #[command(about = "Fuzzy/prefix/exact search over symbol names via the FST-backed name index")]
+    Search {
+        #[arg(help = "Symbol name, or a prefix/typo thereof")]
+        query: String,
+        #[arg(long, help = "Restrict results to symbols whose file path starts with this prefix")]
+        module: Option<String>,
+        #[arg(long, short, default_value = "20", help = "Maximum number of results")]
+        limit: usize,
+        #[arg(long, help = "Emit machine-readable JSON instead of the formatted results")]
+        json: bool,
         #[arg(help = "Project root (default: auto-detect from cwd)")]
         path: Option<PathBuf>,
     },
@@ -55,6 +130,21 @@ pub enum Commands {
     Deps {
         #[arg(long, help = "Filter to a specific crate")]
         krate: Option<String>,
+        #[arg(
+            long,
+            help = "Show the full transitive dependency graph with reverse-dependency counts (from Cargo.lock)"
+        )]
+        graph: bool,
+        #[arg(
+            long,
+            help = "Annotate deps with crates.io metadata (staleness, yanked versions, download counts)"
+        )]
+        enrich: bool,
+        #[arg(
+            long,
+            help = "Report optional/feature-gated dependency usage (dead features, ungated optional deps)"
+        )]
+        features: bool,
         #[arg(help = "Project root (default: auto-detect from cwd)")]
         path: Option<PathBuf>,
     },
@@ -62,6 +152,27 @@ pub enum Commands {
     Tests {
         #[arg(long, short, help = "Show tests for a specific file")]
         file: Option<String>,
+        #[arg(
+            long,
+            help = "Path to an LCOV .info file (as produced by tarpaulin/grcov) to drive coverage \
+                    levels from real line-hit ratios instead of the test-count heuristic"
+        )]
+        lcov: Option<PathBuf>,
+        #[arg(
+            long = "merge",
+            help = "Merge in another LCOV .info file's executed ranges on top of --lcov (or each \
+                    other), unioning hit counts per line instead of replacing them. Repeatable for \
+                    a matrix of several partial coverage runs (e.g. one per feature-flag/OS \
+                    combination)"
+        )]
+        merge: Vec<PathBuf>,
+        #[arg(
+            long,
+            value_enum,
+            default_value = "text",
+            help = "Output format: text, json, cobertura (XML), or coveralls (JSON)"
+        )]
+        format: TestsFormatArg,
         #[arg(help = "Project root (default: auto-detect from cwd)")]
         path: Option<PathBuf>,
     },
@@ -75,6 +186,58 @@ pub enum Commands {
         #[arg(help = "Project root (default: auto-detect from cwd)")]
         path: Option<PathBuf>,
     },
+    #[command(about = "Watch for file changes and incrementally re-capture")]
+    Watch {
+        #[arg(help = "Project root (default: auto-detect from cwd)")]
+        path: Option<PathBuf>,
+    },
+    #[command(about = "Run lint rules over the last capture and write lints.md")]
+    Lint {
+        #[arg(long, help = "Only run the rule with this exact name")]
+        rule: Option<String>,
+        #[arg(
+            long,
+            value_enum,
+            help = "Minimum severity to report (info, warn, error)"
+        )]
+        severity: Option<LintSeverity>,
+        #[arg(help = "Project root (default: auto-detect from cwd)")]
+        path: Option<PathBuf>,
+    },
+    #[command(about = "Diff two revisions' structural model without touching the working tree")]
+    Diff {
+        #[arg(help = "Revision range as <old>..<new>, e.g. `main..HEAD`")]
+        revisions: String,
+        #[arg(long, help = "Emit machine-readable JSON instead of the formatted report")]
+        json: bool,
+        #[arg(help = "Project root (default: auto-detect from cwd)")]
+        path: Option<PathBuf>,
+    },
+    #[command(about = "Emit high/medium complexity findings as editor-consumable diagnostics")]
+    Complexity {
+        #[arg(
+            long,
+            value_enum,
+            default_value = "sarif",
+            help = "Output format: sarif (writes .charter/complexity.sarif.json), json, or text"
+        )]
+        format: ComplexityFormat,
+        #[arg(
+            long = "weight",
+            help = "Override a scoring weight or threshold as key=value (cyclomatic, cognitive, \
+                    line_divisor, call_sites, churn, public_bonus, error_threshold, \
+                    warning_threshold, info_threshold), repeatable"
+        )]
+        weight: Vec<String>,
+        #[arg(
+            long,
+            value_enum,
+            help = "Exit with a nonzero status if any finding's severity meets or exceeds this level"
+        )]
+        fail_on: Option<HotspotSeverityArg>,
+        #[arg(help = "Project root (default: auto-detect from cwd)")]
+        path: Option<PathBuf>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -94,6 +257,13 @@ pub enum SessionAction {
         #[arg(help = "Project root (default: auto-detect from cwd)")]
         path: Option<PathBuf>,
     },
+    #[command(about = "Aggregate metrics across every archived session")]
+    Report {
+        #[arg(long, help = "Emit machine-readable JSON instead of the text summary")]
+        json: bool,
+        #[arg(help = "Project root (default: auto-detect from cwd)")]
+        path: Option<PathBuf>,
+    },
 }
 
 #[derive(Clone, Copy, Debug, Default, ValueEnum)]
@@ -103,3 +273,65 @@ pub enum Tier {
     Default,
     Full,
 }
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum LintSeverity {
+    Info,
+    Warn,
+    Error,
+}
+
+impl From<LintSeverity> for crate::rules::Severity {
+    fn from(severity: LintSeverity) -> Self {
+        match severity {
+            LintSeverity::Info => crate::rules::Severity::Info,
+            LintSeverity::Warn => crate::rules::Severity::Warn,
+            LintSeverity::Error => crate::rules::Severity::Error,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum ComplexityFormat {
+    Sarif,
+    Json,
+    Text,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum HotspotSeverityArg {
+    Error,
+    Warning,
+    Info,
+    Hint,
+}
+
+impl From<HotspotSeverityArg> for crate::extract::complexity::HotspotSeverity {
+    fn from(severity: HotspotSeverityArg) -> Self {
+        match severity {
+            HotspotSeverityArg::Error => Self::Error,
+            HotspotSeverityArg::Warning => Self::Warning,
+            HotspotSeverityArg::Info => Self::Info,
+            HotspotSeverityArg::Hint => Self::Hint,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum TestsFormatArg {
+    Text,
+    Json,
+    Cobertura,
+    Coveralls,
+}
+
+impl From<TestsFormatArg> for crate::tests::TestsFormat {
+    fn from(format: TestsFormatArg) -> Self {
+        match format {
+            TestsFormatArg::Text => Self::Text,
+            TestsFormatArg::Json => Self::Json,
+            TestsFormatArg::Cobertura => Self::Cobertura,
+            TestsFormatArg::Coveralls => Self::Coveralls,
+        }
+    }
+}
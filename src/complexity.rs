@@ -0,0 +1,152 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::cache::Cache;
+use crate::extract::complexity::{HotspotSeverity, ImportanceTier, ScoringWeights};
+use crate::rules::enrich_call_sites;
+
+/// One high/medium-importance function — the unit [`write_complexity_sarif`] and
+/// [`write_complexity_json`] both emit one record per finding of, skipping [`ImportanceTier::Low`]
+/// functions the same way `output::hotspots::write_hotspots` only shows its High/Medium sections.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComplexityFinding {
+    pub file: String,
+    pub line: usize,
+    pub qualified_name: String,
+    pub tier: ImportanceTier,
+    pub score: u32,
+    pub severity: HotspotSeverity,
+}
+
+/// Walks every file in `cache`, enriching each function's `call_sites` with whole-project call
+/// counts (mirroring [`crate::rules::run_all`]'s rule-context enrichment), and returns every
+/// High/Medium-importance function under `weights`, most important first.
+pub fn collect_findings(cache: &Cache, weights: &ScoringWeights) -> Vec<ComplexityFinding> {
+    let mut findings: Vec<ComplexityFinding> = enrich_call_sites(cache)
+        .into_iter()
+        .flat_map(|(file, parsed)| {
+            parsed
+                .complexity
+                .into_iter()
+                .filter(|func| func.metrics.tier_with(weights) != ImportanceTier::Low)
+                .map(|func| ComplexityFinding {
+                    file: file.clone(),
+                    line: func.line,
+                    qualified_name: func.qualified_name(),
+                    tier: func.metrics.tier_with(weights),
+                    score: func.metrics.importance_score_with(weights),
+                    severity: func.metrics.severity_with(weights),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    findings.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| a.file.cmp(&b.file))
+            .then_with(|| a.line.cmp(&b.line))
+    });
+
+    findings
+}
+
+/// Whether any finding's severity meets or exceeds `fail_on` — the gate a CI pipeline can run
+/// after `charter complexity` to fail the build on the worst offenders while leaving lesser ones
+/// as non-blocking diagnostics.
+pub fn fails_threshold(findings: &[ComplexityFinding], fail_on: HotspotSeverity) -> bool {
+    findings.iter().any(|f| f.severity >= fail_on)
+}
+
+/// The SARIF `level` a finding's [`HotspotSeverity`] maps to, using SARIF's own four-level
+/// vocabulary (`error`/`warning`/`note`/`none`) as the closest fit for LSP-style error/warning/
+/// info/hint.
+fn sarif_level(severity: HotspotSeverity) -> &'static str {
+    match severity {
+        HotspotSeverity::Error => "error",
+        HotspotSeverity::Warning => "warning",
+        HotspotSeverity::Info => "note",
+        HotspotSeverity::Hint => "none",
+    }
+}
+
+/// Builds a SARIF 2.1.0 `runs[0].results` entry for `finding`: `ruleId` is the function's
+/// qualified name (stable across captures as long as the function isn't renamed), `level` comes
+/// from [`sarif_level`], and the region points at `file:line` so editors and CI problem-matchers
+/// can render it as an inline annotation.
+fn sarif_result(finding: &ComplexityFinding) -> serde_json::Value {
+    serde_json::json!({
+        "ruleId": finding.qualified_name,
+        "level": sarif_level(finding.severity),
+        "message": {
+            "text": format!(
+                "{} has an importance score of {} ({} complexity)",
+                finding.qualified_name, finding.score, finding.tier
+            ),
+        },
+        "locations": [{
+            "physicalLocation": {
+                "artifactLocation": { "uri": finding.file },
+                "region": { "startLine": finding.line },
+            },
+        }],
+    })
+}
+
+/// Writes `.charter/complexity.sarif.json`, a SARIF 2.1.0 log with one result per High/Medium
+/// complexity function — the format CI annotators and editors (VS Code's SARIF viewer, GitHub
+/// code scanning) already know how to render inline.
+pub async fn write_complexity_sarif(charter_dir: &Path, findings: &[ComplexityFinding]) -> Result<()> {
+    let sarif = serde_json::json!({
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "charter",
+                    "informationUri": "https://github.com/matthewjberger/charter",
+                    "rules": findings
+                        .iter()
+                        .map(|f| serde_json::json!({
+                            "id": f.qualified_name,
+                            "shortDescription": { "text": "Function complexity exceeds the low-importance threshold" },
+                        }))
+                        .collect::<Vec<_>>(),
+                },
+            },
+            "results": findings.iter().map(sarif_result).collect::<Vec<_>>(),
+        }],
+    });
+
+    let content = serde_json::to_string_pretty(&sarif)?;
+    tokio::fs::write(charter_dir.join("complexity.sarif.json"), content).await?;
+    Ok(())
+}
+
+/// Prints `findings` as a flat JSON array to stdout, the `--format json` counterpart to
+/// [`write_complexity_sarif`]'s file output.
+pub fn print_complexity_json(findings: &[ComplexityFinding]) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(findings)?);
+    Ok(())
+}
+
+/// Prints `findings` as human-readable lines to stdout, the `--format text` counterpart.
+pub fn print_complexity_text(findings: &[ComplexityFinding]) {
+    if findings.is_empty() {
+        println!("No high/medium-complexity functions detected.");
+        return;
+    }
+
+    for finding in findings {
+        println!(
+            "[{}/{}] {}:{} {} (score={})",
+            finding.severity,
+            finding.tier,
+            finding.file,
+            finding.line,
+            finding.qualified_name,
+            finding.score
+        );
+    }
+}
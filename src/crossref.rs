@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::export::Id;
+use crate::extract::symbols::SymbolKind;
+use crate::pipeline::PipelineResult;
+
+/// Where a name-based reference (an `impl_map` trait/type, or a `call_graph` callee) ultimately
+/// resolved, mirroring [`crate::callindex::CallTarget`]'s resolved/ambiguous/external split for
+/// the crate-wide, [`Id`]-keyed graph this module builds instead of
+/// [`crate::callindex`]'s function-only one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrossRefTarget {
+    /// Resolved to exactly one definition in this crate.
+    Resolved(Id),
+    /// More than one same-named symbol exists in the crate, so the real target can't be picked
+    /// out without more context than a bare name gives.
+    Ambiguous,
+    /// No matching definition in the crate; most likely an external crate or `std` item. The
+    /// original name is kept by the caller rather than dropped.
+    External,
+}
+
+/// One type found implementing a trait via `impl_map`, with its own resolved target (the
+/// defining `struct`/`enum`/`class` symbol, when it's declared in this crate).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Implementor {
+    pub type_name: String,
+    pub target: CrossRefTarget,
+}
+
+/// One trait resolved out of every file's `impl_map`: its own resolved target (when the trait
+/// itself is declared in this crate) paired with every type seen implementing it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraitImplementors {
+    pub trait_name: String,
+    pub target: CrossRefTarget,
+    pub implementors: Vec<Implementor>,
+}
+
+/// One `call_graph` edge resolved against this crate's free functions: `caller` is the calling
+/// function's own [`Id`], `callee` is where the call text it names resolved to. Only free
+/// functions are represented — inherent/trait methods live in `impl_map`/`inherent_impls`, not
+/// `FileSymbols::symbols`, so they have no [`Id`] of their own to be a `caller` or a `Resolved`
+/// `callee`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CallEdgeResolved {
+    pub caller: Id,
+    pub callee_name: String,
+    pub callee: CrossRefTarget,
+}
+
+fn symbol_id(file: &str, line: usize, name: &str) -> Id {
+    format!("{file}:{line}:{name}")
+}
+
+/// Crate-wide index from a symbol's bare name (generics stripped, the same normalization
+/// [`crate::output::preamble`]'s `format_key_traits` already applies before counting) to every
+/// [`Id`] it's defined under. Built once per resolution pass and consulted for every `impl_map`
+/// or `call_graph` entry — the same "build the whole-crate table, then resolve against it" shape
+/// [`crate::callindex::SymbolIndex`] uses, just keyed by bare name instead of `(type, method)`.
+fn index_by_name(
+    result: &PipelineResult,
+    matches: impl Fn(&SymbolKind) -> bool,
+) -> HashMap<&str, Vec<Id>> {
+    let mut index: HashMap<&str, Vec<Id>> = HashMap::new();
+
+    for file in &result.files {
+        for symbol in &file.parsed.symbols.symbols {
+            if matches(&symbol.kind) {
+                index
+                    .entry(symbol.name.as_str())
+                    .or_default()
+                    .push(symbol_id(&file.relative_path, symbol.line, &symbol.name));
+            }
+        }
+    }
+
+    index
+}
+
+fn resolve_name(index: &HashMap<&str, Vec<Id>>, name: &str) -> CrossRefTarget {
+    let bare = name.split('<').next().unwrap_or(name);
+    match index.get(bare) {
+        None => CrossRefTarget::External,
+        Some(ids) if ids.len() == 1 => CrossRefTarget::Resolved(ids[0].clone()),
+        Some(_) => CrossRefTarget::Ambiguous,
+    }
+}
+
+fn is_trait_kind(kind: &SymbolKind) -> bool {
+    matches!(kind, SymbolKind::Trait { .. })
+}
+
+fn is_type_kind(kind: &SymbolKind) -> bool {
+    matches!(
+        kind,
+        SymbolKind::Struct { .. } | SymbolKind::Enum { .. } | SymbolKind::Class { .. }
+    )
+}
+
+fn is_free_function_kind(kind: &SymbolKind) -> bool {
+    matches!(kind, SymbolKind::Function { .. })
+}
+
+/// Resolves every file's `impl_map` (`(trait_name, type_name)` pairs) into one
+/// [`TraitImplementors`] per distinct trait name, with both the trait and every implementing
+/// type resolved to their defining [`Id`] when declared in this crate and unambiguous. Traits
+/// are sorted by implementor count (ties broken by name) so the most-implemented trait leads.
+pub fn resolve_trait_implementors(result: &PipelineResult) -> Vec<TraitImplementors> {
+    let trait_index = index_by_name(result, is_trait_kind);
+    let type_index = index_by_name(result, is_type_kind);
+
+    let mut implementors_by_trait: HashMap<String, Vec<Implementor>> = HashMap::new();
+    for file in &result.files {
+        for (trait_name, type_name) in &file.parsed.symbols.impl_map {
+            implementors_by_trait
+                .entry(trait_name.clone())
+                .or_default()
+                .push(Implementor {
+                    type_name: type_name.clone(),
+                    target: resolve_name(&type_index, type_name),
+                });
+        }
+    }
+
+    let mut resolved: Vec<TraitImplementors> = implementors_by_trait
+        .into_iter()
+        .map(|(trait_name, implementors)| TraitImplementors {
+            target: resolve_name(&trait_index, &trait_name),
+            trait_name,
+            implementors,
+        })
+        .collect();
+
+    resolved.sort_by(|a, b| {
+        b.implementors
+            .len()
+            .cmp(&a.implementors.len())
+            .then_with(|| a.trait_name.cmp(&b.trait_name))
+    });
+
+    resolved
+}
+
+/// Reduces a raw `call_graph` callee expression (`"Type::new"`, `"self.bar"`, `"obj.method"`)
+/// down to the bare identifier a free function might be defined under, the same normalization
+/// [`crate::callgraph::build_call_graph`] applies before its own by-name lookup.
+fn short_callee_name(text: &str) -> &str {
+    let after_path = text.rsplit("::").next().unwrap_or(text);
+    after_path.rsplit('.').next().unwrap_or(after_path)
+}
+
+/// Resolves every file's `call_graph` into [`CallEdgeResolved`] edges between free functions —
+/// the `call_graph` half of this module's cross-reference graph, giving each resolved edge a
+/// stable [`Id`] on the caller end instead of the bare [`crate::extract::calls::FunctionId`]
+/// [`crate::callindex::build_call_graph`] produces. Edges where the caller or the callee is a
+/// method (`impl_type.is_some()`) are skipped entirely, since methods have no [`Id`] of their own
+/// to anchor an edge to.
+pub fn resolve_call_edges(result: &PipelineResult) -> Vec<CallEdgeResolved> {
+    let functions = index_by_name(result, is_free_function_kind);
+    let mut edges = Vec::new();
+
+    for file in &result.files {
+        for call_info in &file.parsed.call_graph {
+            if call_info.caller.impl_type.is_some() {
+                continue;
+            }
+
+            let caller_id = symbol_id(&file.relative_path, call_info.line, &call_info.caller.name);
+
+            for callee in &call_info.callees {
+                if callee.target_type.is_some() {
+                    continue;
+                }
+
+                let callee_name = short_callee_name(&callee.target).to_string();
+                edges.push(CallEdgeResolved {
+                    caller: caller_id.clone(),
+                    callee: resolve_name(&functions, &callee_name),
+                    callee_name,
+                });
+            }
+        }
+    }
+
+    edges
+}
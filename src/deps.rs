@@ -1,9 +1,17 @@
+mod enrich;
+
 use anyhow::Result;
 use std::collections::HashMap;
 use std::path::Path;
 use tokio::fs;
 
-pub async fn deps(root: &Path, crate_filter: Option<&str>) -> Result<()> {
+pub async fn deps(
+    root: &Path,
+    crate_filter: Option<&str>,
+    graph: bool,
+    enrich: bool,
+    features: bool,
+) -> Result<()> {
     let atlas_dir = root.join(".atlas");
 
     if !atlas_dir.exists() {
@@ -11,72 +19,675 @@ pub async fn deps(root: &Path, crate_filter: Option<&str>) -> Result<()> {
         std::process::exit(1);
     }
 
-    let cargo_deps = parse_cargo_toml(root).await;
-    let import_usage = analyze_imports(&atlas_dir).await?;
+    if graph {
+        return show_dependency_graph(root).await;
+    }
+
+    if features {
+        return show_feature_gating_report(root, &atlas_dir).await;
+    }
+
+    let project_json = load_project_json(root).await;
+
+    let (workspace_members, cargo_deps, declared_dep_names) = match &project_json {
+        Some(project) => {
+            let dep_names: Vec<String> = project
+                .crates
+                .iter()
+                .flat_map(|c| c.deps.keys().cloned())
+                .collect::<std::collections::HashSet<_>>()
+                .into_iter()
+                .collect();
+            let cargo_deps: HashMap<String, String> = dep_names
+                .iter()
+                .map(|name| (name.clone(), "*".to_string()))
+                .collect();
+            (Vec::new(), cargo_deps, Some(dep_names))
+        }
+        None => {
+            let workspace_members = parse_workspace(root).await;
+            let cargo_deps = parse_cargo_toml(root).await;
+            (workspace_members, cargo_deps, None)
+        }
+    };
+    let member_names: Vec<String> = workspace_members.iter().map(|m| m.name.clone()).collect();
+    let import_usage =
+        analyze_imports(&atlas_dir, &member_names, declared_dep_names.as_deref()).await?;
+    let registry = if enrich {
+        Some(enrich::load_or_fetch(&atlas_dir).await?)
+    } else {
+        None
+    };
 
     if let Some(krate) = crate_filter {
         show_crate_usage(&import_usage, krate, &cargo_deps);
     } else {
         show_all_deps(&import_usage, &cargo_deps);
+        if workspace_members.len() > 1 {
+            show_per_member_unused(&import_usage, &workspace_members);
+        }
+    }
+
+    if let Some(registry) = &registry {
+        enrich::show_supply_chain_report(&cargo_deps, registry);
     }
 
     Ok(())
 }
 
-async fn parse_cargo_toml(root: &Path) -> HashMap<String, String> {
+struct OptionalDep {
+    optional: bool,
+}
+
+/// Parses `optional = true` flags and the `[features]` table, both ignored by
+/// [`parse_manifest_deps`] since they don't affect version resolution.
+async fn parse_feature_metadata(
+    root: &Path,
+) -> (HashMap<String, OptionalDep>, HashMap<String, Vec<String>>) {
+    let cargo_path = root.join("Cargo.toml");
+    let Ok(content) = fs::read_to_string(&cargo_path).await else {
+        return (HashMap::new(), HashMap::new());
+    };
+    let Ok(parsed) = content.parse::<toml::Value>() else {
+        return (HashMap::new(), HashMap::new());
+    };
+
+    let mut optional = HashMap::new();
+    if let Some(table) = parsed.get("dependencies").and_then(|d| d.as_table()) {
+        for (name, value) in table {
+            let is_optional = value
+                .as_table()
+                .and_then(|t| t.get("optional"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false);
+            optional.insert(
+                name.clone(),
+                OptionalDep {
+                    optional: is_optional,
+                },
+            );
+        }
+    }
+
+    let features = parsed
+        .get("features")
+        .and_then(|f| f.as_table())
+        .map(|table| {
+            table
+                .iter()
+                .map(|(name, enables)| {
+                    let list = enables
+                        .as_array()
+                        .map(|a| {
+                            a.iter()
+                                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    (name.clone(), list)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    (optional, features)
+}
+
+/// Reports optional deps never imported behind their feature gate (dead features),
+/// features that enable a dep with no corresponding gated `use`, and deps imported
+/// unconditionally despite being declared `optional`.
+async fn show_feature_gating_report(root: &Path, atlas_dir: &Path) -> Result<()> {
+    let (optional_deps, feature_map) = parse_feature_metadata(root).await;
+
+    let cache_path = atlas_dir.join("cache.bin");
+    let mut gated_imports: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut ungated_imports: std::collections::HashSet<String> = std::collections::HashSet::new();
+
+    if let Ok(cache_data) = fs::read(&cache_path).await {
+        if let Ok(cache) = bincode::deserialize::<crate::cache::Cache>(&cache_data) {
+            for entry in cache.entries.values() {
+                for import in &entry.data.parsed.imports {
+                    let Some(crate_name) = extract_crate_name(&import.path) else {
+                        continue;
+                    };
+                    if import.cfg_feature.is_some() {
+                        gated_imports.insert(crate_name);
+                    } else {
+                        ungated_imports.insert(crate_name);
+                    }
+                }
+            }
+        }
+    }
+
+    println!("Feature-Gated Dependency Report");
+    println!("================================");
+    println!();
+
+    let dep_enabling_features: HashMap<String, Vec<&String>> = {
+        let mut map: HashMap<String, Vec<&String>> = HashMap::new();
+        for (feature, enables) in &feature_map {
+            for dep in enables {
+                let dep_name = dep.split('/').next().unwrap_or(dep).trim_start_matches("dep:");
+                map.entry(dep_name.replace('_', "-")).or_default().push(feature);
+            }
+        }
+        map
+    };
+
+    let mut dead_features = Vec::new();
+    let mut ungated_optional = Vec::new();
+    let mut unconditional_optional = Vec::new();
+
+    for (name, dep) in &optional_deps {
+        let normalized = name.replace('_', "-");
+        if !dep.optional {
+            continue;
+        }
+
+        if !gated_imports.contains(&normalized) {
+            dead_features.push(normalized.clone());
+
+            if let Some(features) = dep_enabling_features.get(&normalized) {
+                for feature in features {
+                    ungated_optional.push(format!("{feature} enables {normalized}"));
+                }
+            }
+        }
+
+        if ungated_imports.contains(&normalized) {
+            unconditional_optional.push(normalized.clone());
+        }
+    }
+
+    println!("Optional deps never imported behind their feature gate (dead features):");
+    if dead_features.is_empty() {
+        println!("  (none)");
+    } else {
+        for name in &dead_features {
+            println!("  {name}");
+        }
+    }
+    println!();
+
+    println!("Features that enable a dep with no corresponding gated `use`:");
+    if ungated_optional.is_empty() {
+        println!("  (none)");
+    } else {
+        for entry in &ungated_optional {
+            println!("  {entry}");
+        }
+    }
+    println!();
+
+    println!("Deps imported unconditionally despite being declared `optional`:");
+    if unconditional_optional.is_empty() {
+        println!("  (none)");
+    } else {
+        for name in &unconditional_optional {
+            println!("  {name}");
+        }
+    }
+
+    Ok(())
+}
+
+/// A single `[[package]]` entry from `Cargo.lock`, keyed by name + version so that
+/// multiple versions of the same crate are kept as distinct nodes.
+#[derive(Debug, Clone)]
+struct LockPackage {
+    name: String,
+    version: String,
+    dependencies: Vec<String>,
+}
+
+fn package_key(name: &str, version: &str) -> String {
+    format!("{name} {version}")
+}
+
+async fn parse_cargo_lock(root: &Path) -> Result<Vec<LockPackage>> {
+    let lock_path = root.join("Cargo.lock");
+    let content = fs::read_to_string(&lock_path).await?;
+    let parsed: toml::Value = content.parse()?;
+
+    let mut packages = Vec::new();
+    if let Some(array) = parsed.get("package").and_then(|p| p.as_array()) {
+        for entry in array {
+            let name = entry
+                .get("name")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let version = entry
+                .get("version")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string();
+            let dependencies = entry
+                .get("dependencies")
+                .and_then(|d| d.as_array())
+                .map(|deps| {
+                    deps.iter()
+                        .filter_map(|d| d.as_str())
+                        .map(|s| s.to_string())
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            if !name.is_empty() {
+                packages.push(LockPackage {
+                    name,
+                    version,
+                    dependencies,
+                });
+            }
+        }
+    }
+
+    Ok(packages)
+}
+
+/// Resolve a `Cargo.lock` dependency entry (`"name"`, `"name version"`, or
+/// `"name version (source)"`) to the key of the package it refers to. Falls back to
+/// matching on name alone when the version is omitted (only one resolved version).
+fn resolve_dep_edge(dep_entry: &str, by_name: &HashMap<String, Vec<String>>) -> Option<String> {
+    let mut parts = dep_entry.split_whitespace();
+    let name = parts.next()?;
+    let version = parts.next();
+
+    let versions = by_name.get(name)?;
+    match version {
+        Some(v) => versions
+            .iter()
+            .find(|candidate| *candidate == v)
+            .map(|v| package_key(name, v)),
+        None if versions.len() == 1 => Some(package_key(name, &versions[0])),
+        None => None,
+    }
+}
+
+async fn show_dependency_graph(root: &Path) -> Result<()> {
+    let packages = parse_cargo_lock(root).await?;
+    if packages.is_empty() {
+        eprintln!("No Cargo.lock found or it contains no packages.");
+        std::process::exit(1);
+    }
+
+    let mut by_name: HashMap<String, Vec<String>> = HashMap::new();
+    for pkg in &packages {
+        by_name
+            .entry(pkg.name.clone())
+            .or_default()
+            .push(pkg.version.clone());
+    }
+
+    // Forward edges: package key -> keys of its dependencies.
+    let mut edges: HashMap<String, Vec<String>> = HashMap::new();
+    for pkg in &packages {
+        let key = package_key(&pkg.name, &pkg.version);
+        let resolved: Vec<String> = pkg
+            .dependencies
+            .iter()
+            .filter_map(|dep| resolve_dep_edge(dep, &by_name))
+            .collect();
+        edges.insert(key, resolved);
+    }
+
+    // Reverse edges, built once so reverse-dependency counts don't re-walk the graph.
+    let mut reverse_edges: HashMap<String, Vec<String>> = HashMap::new();
+    for (from, tos) in &edges {
+        for to in tos {
+            reverse_edges.entry(to.clone()).or_default().push(from.clone());
+        }
+    }
+
+    let cargo_toml = parse_cargo_toml(root).await;
+    let direct_keys: std::collections::HashSet<String> = cargo_toml
+        .keys()
+        .filter_map(|name| {
+            let normalized = name.replace('-', "_");
+            by_name
+                .iter()
+                .find(|(n, _)| n.replace('-', "_") == normalized)
+                .and_then(|(n, versions)| versions.first().map(|v| package_key(n, v)))
+        })
+        .collect();
+
+    // Walk forward from the direct roots to find every crate reachable in the
+    // dependency closure, guarding against dev-dependency cycles with a visited set.
+    let mut closure: std::collections::HashSet<String> = std::collections::HashSet::new();
+    let mut stack: Vec<String> = direct_keys.iter().cloned().collect();
+    while let Some(key) = stack.pop() {
+        if !closure.insert(key.clone()) {
+            continue;
+        }
+        if let Some(deps) = edges.get(&key) {
+            for dep in deps {
+                if !closure.contains(dep) {
+                    stack.push(dep.clone());
+                }
+            }
+        }
+    }
+
+    // Which direct deps pull in each transitive crate, found via reverse traversal
+    // from each transitive crate back toward the roots.
+    let mut pulled_in_by: HashMap<String, Vec<String>> = HashMap::new();
+    for key in &closure {
+        let mut visited: std::collections::HashSet<String> = std::collections::HashSet::new();
+        let mut stack = vec![key.clone()];
+        let mut roots_reached = Vec::new();
+        while let Some(current) = stack.pop() {
+            if !visited.insert(current.clone()) {
+                continue;
+            }
+            if direct_keys.contains(&current) {
+                roots_reached.push(current.clone());
+            }
+            if let Some(parents) = reverse_edges.get(&current) {
+                stack.extend(parents.iter().cloned());
+            }
+        }
+        roots_reached.sort();
+        roots_reached.dedup();
+        pulled_in_by.insert(key.clone(), roots_reached);
+    }
+
+    // How many crates in the closure transitively depend on each crate, found the same way as
+    // `pulled_in_by` but counting every distinct crate reached rather than just the roots.
+    let mut reverse_dependency_counts: HashMap<String, usize> = HashMap::new();
+    for key in &closure {
+        let mut visited: std::collections::HashSet<String> = std::collections::HashSet::new();
+        let mut stack: Vec<String> = reverse_edges.get(key).cloned().unwrap_or_default();
+        while let Some(current) = stack.pop() {
+            if !visited.insert(current.clone()) {
+                continue;
+            }
+            if let Some(parents) = reverse_edges.get(&current) {
+                stack.extend(parents.iter().cloned());
+            }
+        }
+        reverse_dependency_counts.insert(key.clone(), visited.len());
+    }
+
+    println!("Transitive Dependency Graph");
+    println!("===========================");
+    println!();
+
+    let mut sorted_closure: Vec<&String> = closure.iter().collect();
+    sorted_closure.sort();
+
+    for key in sorted_closure {
+        let is_direct = direct_keys.contains(key);
+        let reverse_count = reverse_dependency_counts.get(key).copied().unwrap_or(0);
+        println!(
+            "{} [{}] - reverse deps: {}",
+            key,
+            if is_direct { "direct" } else { "transitive" },
+            reverse_count
+        );
+
+        if !is_direct {
+            if let Some(roots) = pulled_in_by.get(key) {
+                if !roots.is_empty() {
+                    println!("  pulled in by: {}", roots.join(", "));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Dependencies declared by a single crate (the root crate, or one workspace member).
+struct MemberDeps {
+    /// Directory name relative to the workspace root, or "." for a non-workspace crate.
+    name: String,
+    deps: HashMap<String, String>,
+}
+
+fn deps_table_to_map(
+    table: &toml::value::Table,
+    workspace_deps: &HashMap<String, String>,
+    suffix: &str,
+) -> HashMap<String, String> {
+    let mut deps = HashMap::new();
+    for (name, value) in table {
+        let version = match value {
+            toml::Value::String(s) => s.clone(),
+            toml::Value::Table(t) => {
+                if t.get("workspace").and_then(|v| v.as_bool()).unwrap_or(false) {
+                    workspace_deps
+                        .get(name)
+                        .cloned()
+                        .unwrap_or_else(|| "*".to_string())
+                } else {
+                    t.get("version")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("*")
+                        .to_string()
+                }
+            }
+            _ => "*".to_string(),
+        };
+        deps.insert(name.clone(), format!("{version}{suffix}"));
+    }
+    deps
+}
+
+/// Parses a single `Cargo.toml`'s `[dependencies]`/`[dev-dependencies]`, resolving
+/// `dep = { workspace = true }` entries against the workspace's `[workspace.dependencies]`.
+fn parse_manifest_deps(
+    parsed: &toml::Value,
+    workspace_deps: &HashMap<String, String>,
+) -> HashMap<String, String> {
     let mut deps = HashMap::new();
 
+    if let Some(table) = parsed.get("dependencies").and_then(|d| d.as_table()) {
+        deps.extend(deps_table_to_map(table, workspace_deps, ""));
+    }
+    if let Some(table) = parsed.get("dev-dependencies").and_then(|d| d.as_table()) {
+        deps.extend(deps_table_to_map(table, workspace_deps, " (dev)"));
+    }
+
+    deps
+}
+
+/// Expands simple `crates/*`-style globs (the only pattern cargo workspaces commonly
+/// use) against the directories actually present on disk.
+async fn expand_member_glob(root: &Path, pattern: &str) -> Vec<std::path::PathBuf> {
+    if let Some(prefix) = pattern.strip_suffix("/*") {
+        let dir = root.join(prefix);
+        let mut out = Vec::new();
+        if let Ok(mut entries) = fs::read_dir(&dir).await {
+            while let Ok(Some(entry)) = entries.next_entry().await {
+                if entry.path().is_dir() {
+                    out.push(entry.path());
+                }
+            }
+        }
+        out
+    } else {
+        vec![root.join(pattern)]
+    }
+}
+
+/// Parses the root `Cargo.toml`. If it declares a `[workspace]`, resolves `members`
+/// (expanding simple globs) minus `exclude`, parses each member's manifest
+/// (including `{ workspace = true }` inheritance), and returns one `MemberDeps` per
+/// member. For a plain crate, returns a single `MemberDeps` named `"."`.
+async fn parse_workspace(root: &Path) -> Vec<MemberDeps> {
     let cargo_path = root.join("Cargo.toml");
     let content = match fs::read_to_string(&cargo_path).await {
         Ok(c) => c,
-        Err(_) => return deps,
+        Err(_) => return Vec::new(),
     };
 
     let parsed: toml::Value = match content.parse() {
         Ok(v) => v,
-        Err(_) => return deps,
+        Err(_) => return Vec::new(),
     };
 
-    if let Some(dependencies) = parsed.get("dependencies").and_then(|d| d.as_table()) {
-        for (name, value) in dependencies {
-            let version = match value {
-                toml::Value::String(s) => s.clone(),
-                toml::Value::Table(t) => t
-                    .get("version")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("*")
-                    .to_string(),
-                _ => "*".to_string(),
-            };
-            deps.insert(name.clone(), version);
-        }
-    }
-
-    if let Some(dev_deps) = parsed.get("dev-dependencies").and_then(|d| d.as_table()) {
-        for (name, value) in dev_deps {
-            let version = match value {
-                toml::Value::String(s) => format!("{} (dev)", s),
-                toml::Value::Table(t) => {
-                    let ver = t.get("version").and_then(|v| v.as_str()).unwrap_or("*");
-                    format!("{} (dev)", ver)
-                }
-                _ => "* (dev)".to_string(),
-            };
-            deps.insert(name.clone(), version);
+    let Some(workspace) = parsed.get("workspace") else {
+        return vec![MemberDeps {
+            name: ".".to_string(),
+            deps: parse_manifest_deps(&parsed, &HashMap::new()),
+        }];
+    };
+
+    let workspace_deps = workspace
+        .get("dependencies")
+        .and_then(|d| d.as_table())
+        .map(|t| deps_table_to_map(t, &HashMap::new(), ""))
+        .unwrap_or_default();
+
+    let member_patterns: Vec<String> = workspace
+        .get("members")
+        .and_then(|m| m.as_array())
+        .map(|a| {
+            a.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let exclude: Vec<String> = workspace
+        .get("exclude")
+        .and_then(|m| m.as_array())
+        .map(|a| {
+            a.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut member_dirs = Vec::new();
+    for pattern in &member_patterns {
+        member_dirs.extend(expand_member_glob(root, pattern).await);
+    }
+
+    let mut members = Vec::new();
+    for dir in member_dirs {
+        let Ok(relative) = dir.strip_prefix(root) else {
+            continue;
+        };
+        let relative = relative.to_string_lossy().to_string();
+        if exclude.iter().any(|e| e == &relative) {
+            continue;
         }
+
+        let manifest_path = dir.join("Cargo.toml");
+        let Ok(member_content) = fs::read_to_string(&manifest_path).await else {
+            continue;
+        };
+        let Ok(member_parsed) = member_content.parse::<toml::Value>() else {
+            continue;
+        };
+
+        members.push(MemberDeps {
+            name: relative,
+            deps: parse_manifest_deps(&member_parsed, &workspace_deps),
+        });
     }
 
+    if members.is_empty() {
+        // Virtual workspace with no resolvable members still has root-level deps, if any.
+        vec![MemberDeps {
+            name: ".".to_string(),
+            deps: parse_manifest_deps(&parsed, &workspace_deps),
+        }]
+    } else {
+        members
+    }
+}
+
+async fn parse_cargo_toml(root: &Path) -> HashMap<String, String> {
+    let mut deps = HashMap::new();
+    for member in parse_workspace(root).await {
+        deps.extend(member.deps);
+    }
     deps
 }
 
+/// A single crate root declared in `project.json`/`charter-project.json`, modeled on
+/// rust-analyzer's `project.json` crate-graph format.
+#[derive(Debug, Clone, serde::Deserialize)]
+struct ProjectCrate {
+    #[allow(dead_code)]
+    root_module: String,
+    #[allow(dead_code)]
+    edition: String,
+    /// Maps a dependency name to the index of the crate it resolves to.
+    #[serde(default)]
+    deps: HashMap<String, usize>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct ProjectJson {
+    crates: Vec<ProjectCrate>,
+}
+
+/// Looks for `project.json` or `charter-project.json` at the root, used in place of
+/// `Cargo.toml` for build-system-agnostic (Bazel/Buck/meson) Rust projects.
+async fn load_project_json(root: &Path) -> Option<ProjectJson> {
+    for name in ["project.json", "charter-project.json"] {
+        if let Ok(content) = fs::read_to_string(root.join(name)).await {
+            if let Ok(project) = serde_json::from_str::<ProjectJson>(&content) {
+                return Some(project);
+            }
+        }
+    }
+    None
+}
+
+/// Resolves an import path against the declared dep names in a `project.json` crate
+/// graph rather than guessing from the first path segment the way
+/// [`extract_crate_name`] does for Cargo projects.
+fn resolve_against_project(import_path: &str, dep_names: &[String]) -> Option<String> {
+    let first_segment = import_path.trim().split("::").next()?;
+    dep_names
+        .iter()
+        .find(|name| name.as_str() == first_segment)
+        .cloned()
+}
+
 struct CrateUsage {
     file_count: usize,
     import_count: usize,
     files: Vec<String>,
     items: Vec<String>,
+    /// Workspace member directories (relative to root) whose files import this crate.
+    members: std::collections::HashSet<String>,
+}
+
+impl CrateUsage {
+    fn new() -> Self {
+        CrateUsage {
+            file_count: 0,
+            import_count: 0,
+            files: Vec::new(),
+            items: Vec::new(),
+            members: std::collections::HashSet::new(),
+        }
+    }
+}
+
+/// Finds which declared member directory a file belongs to, so per-file imports can
+/// be attributed to the right workspace crate for per-member unused-dep detection.
+fn member_for_file<'a>(file_path: &str, member_names: &'a [String]) -> &'a str {
+    member_names
+        .iter()
+        .filter(|name| name.as_str() != "." && file_path.starts_with(name.as_str()))
+        .max_by_key(|name| name.len())
+        .map(|s| s.as_str())
+        .unwrap_or(".")
 }
 
-async fn analyze_imports(atlas_dir: &Path) -> Result<HashMap<String, CrateUsage>> {
+async fn analyze_imports(
+    atlas_dir: &Path,
+    member_names: &[String],
+    declared_dep_names: Option<&[String]>,
+) -> Result<HashMap<String, CrateUsage>> {
     let mut usage: HashMap<String, CrateUsage> = HashMap::new();
 
     let _symbols_content = fs::read_to_string(atlas_dir.join("symbols.md"))
@@ -102,12 +713,7 @@ async fn analyze_imports(atlas_dir: &Path) -> Result<HashMap<String, CrateUsage>
             let parts: Vec<&str> = line.split_whitespace().collect();
             if !parts.is_empty() {
                 let crate_name = parts[0].to_string();
-                let entry = usage.entry(crate_name).or_insert(CrateUsage {
-                    file_count: 0,
-                    import_count: 0,
-                    files: Vec::new(),
-                    items: Vec::new(),
-                });
+                let entry = usage.entry(crate_name).or_insert_with(CrateUsage::new);
                 entry.import_count += 1;
             }
         }
@@ -119,18 +725,20 @@ async fn analyze_imports(atlas_dir: &Path) -> Result<HashMap<String, CrateUsage>
             if let Ok(cache) = bincode::deserialize::<crate::cache::Cache>(&cache_data) {
                 for (file_path, entry) in &cache.entries {
                     for import in &entry.data.parsed.imports {
-                        if let Some(crate_name) = extract_crate_name(&import.path) {
-                            let entry = usage.entry(crate_name).or_insert(CrateUsage {
-                                file_count: 0,
-                                import_count: 0,
-                                files: Vec::new(),
-                                items: Vec::new(),
-                            });
+                        let resolved = match declared_dep_names {
+                            Some(names) => resolve_against_project(&import.path, names),
+                            None => extract_crate_name(&import.path),
+                        };
+                        if let Some(crate_name) = resolved {
+                            let entry = usage.entry(crate_name).or_insert_with(CrateUsage::new);
                             entry.import_count += 1;
                             if !entry.files.contains(file_path) {
                                 entry.files.push(file_path.clone());
                                 entry.file_count += 1;
                             }
+                            entry
+                                .members
+                                .insert(member_for_file(file_path, member_names).to_string());
                             let item = import.path.clone();
                             if !entry.items.contains(&item) && entry.items.len() < 20 {
                                 entry.items.push(item);
@@ -207,6 +815,11 @@ fn show_crate_usage(
 
         println!("Files using: {}", crate_usage.file_count);
         println!("Import count: {}", crate_usage.import_count);
+        if crate_usage.members.len() > 1 || !crate_usage.members.contains(".") {
+            let mut members: Vec<_> = crate_usage.members.iter().collect();
+            members.sort();
+            println!("Used by workspace members: {}", members.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(", "));
+        }
         println!();
 
         if !crate_usage.files.is_empty() {
@@ -272,6 +885,42 @@ fn show_all_deps(usage: &HashMap<String, CrateUsage>, cargo_deps: &HashMap<Strin
     }
 }
 
+/// A crate declared by one member but only imported from a different member is
+/// unused in the declaring member, even though it's "used" somewhere in the workspace.
+fn show_per_member_unused(usage: &HashMap<String, CrateUsage>, members: &[MemberDeps]) {
+    println!();
+    println!("Per-Member Unused Dependencies");
+    println!("==============================");
+    println!();
+
+    let mut any = false;
+    for member in members {
+        let unused: Vec<_> = member
+            .deps
+            .keys()
+            .filter(|name| {
+                usage
+                    .get(name.as_str())
+                    .or_else(|| usage.get(&name.replace('-', "_")))
+                    .map(|crate_usage| !crate_usage.members.contains(&member.name))
+                    .unwrap_or(true)
+            })
+            .collect();
+
+        if !unused.is_empty() {
+            any = true;
+            println!("{}:", member.name);
+            for name in unused {
+                println!("  {}", name);
+            }
+        }
+    }
+
+    if !any {
+        println!("(none)");
+    }
+}
+
 fn categorize_deps<'a>(
     deps: &'a [(&'a String, &'a CrateUsage)],
 ) -> Vec<(&'static str, Vec<(&'a String, &'a CrateUsage)>)> {
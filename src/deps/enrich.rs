@@ -0,0 +1,246 @@
+use anyhow::Result;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+
+const DB_DUMP_URL: &str = "https://static.crates.io/db-dump.tar.gz";
+
+/// Latest known state of a single crate, as distilled from the crates.io db-dump.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrateMeta {
+    pub name: String,
+    pub latest_version: String,
+    pub latest_yanked: bool,
+    pub total_downloads: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Registry {
+    pub dump_date: String,
+    pub crates: HashMap<String, CrateMeta>,
+}
+
+fn cache_path(atlas_dir: &Path, dump_date: &str) -> std::path::PathBuf {
+    atlas_dir.join(format!("crates-io-{dump_date}.json"))
+}
+
+/// Loads the cached, already-parsed registry summary if present, otherwise downloads
+/// and streams the db-dump tarball to build one.
+pub async fn load_or_fetch(atlas_dir: &Path) -> Result<Registry> {
+    // The dump has no stable "latest" alias we can cheaply query without downloading
+    // it, so we key the cache by today's date and let a stale cache be reused for the
+    // rest of the day; a fresh `--enrich` run after that refetches.
+    let dump_date = chrono::Utc::now().format("%Y-%m-%d").to_string();
+    let path = cache_path(atlas_dir, &dump_date);
+
+    if let Ok(cached) = tokio::fs::read_to_string(&path).await {
+        if let Ok(registry) = serde_json::from_str::<Registry>(&cached) {
+            return Ok(registry);
+        }
+    }
+
+    let registry = fetch_and_parse(&dump_date).await?;
+    let serialized = serde_json::to_string(&registry)?;
+    tokio::fs::write(&path, serialized).await?;
+
+    Ok(registry)
+}
+
+async fn fetch_and_parse(dump_date: &str) -> Result<Registry> {
+    let response = reqwest::get(DB_DUMP_URL).await?;
+    let mut stream = response.bytes_stream();
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let forward = tokio::spawn(async move {
+        while let Some(chunk) = stream.next().await {
+            if tx.send(chunk).is_err() {
+                break;
+            }
+        }
+    });
+
+    let reader = ChunkReader {
+        rx,
+        current: bytes::Bytes::new(),
+    };
+    let crates = tokio::task::spawn_blocking(move || parse_db_dump(reader)).await??;
+    forward.await?;
+
+    Ok(Registry {
+        dump_date: dump_date.to_string(),
+        crates,
+    })
+}
+
+/// `Read` adapter over a channel of body chunks, so [`parse_db_dump`]'s synchronous gzip/tar
+/// reader can consume the response body as it arrives over the wire instead of needing the whole
+/// multi-hundred-MB tarball buffered in memory before parsing starts.
+struct ChunkReader {
+    rx: std::sync::mpsc::Receiver<reqwest::Result<bytes::Bytes>>,
+    current: bytes::Bytes,
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.current.is_empty() {
+            match self.rx.recv() {
+                Ok(Ok(chunk)) => self.current = chunk,
+                Ok(Err(err)) => return Err(std::io::Error::new(std::io::ErrorKind::Other, err)),
+                Err(_) => return Ok(0),
+            }
+        }
+
+        let n = buf.len().min(self.current.len());
+        buf[..n].copy_from_slice(&self.current[..n]);
+        self.current = self.current.slice(n..);
+        Ok(n)
+    }
+}
+
+/// Streams the gzip tarball entry-by-entry so the multi-hundred-MB dump never needs
+/// full in-memory materialization beyond the chunk currently in flight.
+fn parse_db_dump(reader: ChunkReader) -> Result<HashMap<String, CrateMeta>> {
+    let decoder = flate2::read::GzDecoder::new(reader);
+    let mut archive = tar::Archive::new(decoder);
+
+    let mut crate_names: HashMap<u64, String> = HashMap::new();
+    let mut versions: Vec<(u64, String, bool)> = Vec::new();
+    let mut downloads: HashMap<u64, u64> = HashMap::new();
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let file_name = entry
+            .path()?
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        match file_name.as_str() {
+            "crates.csv" => {
+                let mut contents = String::new();
+                entry.read_to_string(&mut contents)?;
+                for record in csv::Reader::from_reader(contents.as_bytes()).records() {
+                    let record = record?;
+                    if let (Some(id), Some(name)) = (record.get(0), record.get(1)) {
+                        if let Ok(id) = id.parse::<u64>() {
+                            crate_names.insert(id, name.to_string());
+                        }
+                    }
+                }
+            }
+            "versions.csv" => {
+                let mut contents = String::new();
+                entry.read_to_string(&mut contents)?;
+                for record in csv::Reader::from_reader(contents.as_bytes()).records() {
+                    let record = record?;
+                    if let (Some(crate_id), Some(num), Some(yanked)) =
+                        (record.get(0), record.get(1), record.get(2))
+                    {
+                        if let Ok(crate_id) = crate_id.parse::<u64>() {
+                            versions.push((crate_id, num.to_string(), yanked == "t"));
+                        }
+                    }
+                }
+            }
+            name if name.contains("downloads") => {
+                let mut contents = String::new();
+                entry.read_to_string(&mut contents)?;
+                for record in csv::Reader::from_reader(contents.as_bytes()).records() {
+                    let record = record?;
+                    if let (Some(crate_id), Some(count)) = (record.get(0), record.get(1)) {
+                        if let (Ok(crate_id), Ok(count)) =
+                            (crate_id.parse::<u64>(), count.parse::<u64>())
+                        {
+                            *downloads.entry(crate_id).or_insert(0) += count;
+                        }
+                    }
+                }
+            }
+            _ => continue,
+        }
+    }
+
+    let mut latest_by_crate: HashMap<u64, (semver::Version, bool)> = HashMap::new();
+    for (crate_id, num, yanked) in versions {
+        let Ok(parsed) = semver::Version::parse(&num) else {
+            continue;
+        };
+        latest_by_crate
+            .entry(crate_id)
+            .and_modify(|(current, current_yanked)| {
+                if parsed > *current {
+                    *current = parsed.clone();
+                    *current_yanked = yanked;
+                }
+            })
+            .or_insert((parsed, yanked));
+    }
+
+    let mut result = HashMap::new();
+    for (crate_id, name) in crate_names {
+        let Some((version, yanked)) = latest_by_crate.get(&crate_id) else {
+            continue;
+        };
+        result.insert(
+            name.clone(),
+            CrateMeta {
+                name,
+                latest_version: version.to_string(),
+                latest_yanked: *yanked,
+                total_downloads: downloads.get(&crate_id).copied().unwrap_or(0),
+            },
+        );
+    }
+
+    Ok(result)
+}
+
+/// Flags deps whose pinned version requirement excludes the latest version, whose latest
+/// published version is yanked, or whose download count suggests a supply-chain risk. The
+/// yanked status of the pinned version itself is not checked.
+pub fn show_supply_chain_report(cargo_deps: &HashMap<String, String>, registry: &Registry) {
+    println!();
+    println!("Supply-Chain Report (crates.io db-dump {})", registry.dump_date);
+    println!("=============================================");
+    println!();
+
+    const LOW_DOWNLOAD_THRESHOLD: u64 = 1_000;
+
+    for (name, pinned) in cargo_deps {
+        let Some(meta) = registry.crates.get(name) else {
+            continue;
+        };
+
+        let pinned_version = pinned.trim_start_matches('=').split_whitespace().next().unwrap_or(pinned);
+        // `pinned_version` is whatever the user wrote in Cargo.toml, almost always a partial
+        // version like "1.0" or "1" rather than a full major.minor.patch triple, so it has to be
+        // parsed as a requirement (Cargo's own caret-by-default semantics) rather than forced
+        // into an exact `Version`.
+        let is_behind = semver::VersionReq::parse(pinned_version)
+            .ok()
+            .zip(semver::Version::parse(&meta.latest_version).ok())
+            .map(|(req, latest)| !req.matches(&latest))
+            .unwrap_or(false);
+
+        if meta.latest_yanked || is_behind || meta.total_downloads < LOW_DOWNLOAD_THRESHOLD {
+            println!("{name}:");
+            if meta.latest_yanked {
+                println!("  latest published version ({}) is yanked", meta.latest_version);
+            }
+            if is_behind {
+                println!(
+                    "  pinned at {pinned_version}, latest is {}",
+                    meta.latest_version
+                );
+            }
+            if meta.total_downloads < LOW_DOWNLOAD_THRESHOLD {
+                println!(
+                    "  low download count ({}) - consider vetting before relying on it",
+                    meta.total_downloads
+                );
+            }
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use anyhow::{Context, Result};
+use serde::Serialize;
 use std::path::{Path, PathBuf};
 use tokio::fs;
 
+use crate::git::GitBackend;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ProjectKind {
     Rust,
@@ -87,16 +90,7 @@ async fn find_cargo_root(start: &Path) -> Option<PathBuf> {
 }
 
 async fn find_git_root(start: &Path) -> Option<PathBuf> {
-    let mut current = start.to_path_buf();
-    loop {
-        let git_dir = current.join(".git");
-        if fs::metadata(&git_dir).await.is_ok() {
-            return Some(current);
-        }
-        if !current.pop() {
-            return None;
-        }
-    }
+    crate::git::default_backend().discover_root(start).await
 }
 
 async fn has_cargo_toml(path: &Path) -> bool {
@@ -169,7 +163,7 @@ pub struct PythonEntryPoint {
     pub module: String,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum PythonEntryKind {
     ConsoleScript,
     GuiScript,
@@ -186,7 +180,7 @@ pub struct CrateInfo {
     pub targets: Vec<TargetInfo>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum CrateType {
     Lib,
     Bin,
@@ -205,7 +199,7 @@ pub struct TargetInfo {
     pub path: PathBuf,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum TargetKind {
     Lib,
     Bin,
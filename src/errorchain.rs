@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use crate::extract::calls::{CallInfo, FunctionId};
+use crate::extract::errors::{ErrorInfo, ErrorOrigin, PropagationPoint};
+
+/// One step in an [`ErrorChain`]'s path: the caller a `?` expression forwarded the error through,
+/// and the concrete propagation point (`line` plus the forwarding expression text) it took there.
+#[derive(Debug, Clone)]
+pub struct PropagationHop {
+    pub function: FunctionId,
+    pub point: PropagationPoint,
+}
+
+/// One traced path from an [`ErrorOrigin`] up through every caller that re-propagates it via `?`,
+/// ending at the first function with no further `?`-using caller — either because the error is
+/// handed off to a non-fallible caller (surfaced) or nothing above forwards it any further
+/// (swallowed). Built by [`build_error_chains`].
+#[derive(Debug, Clone)]
+pub struct ErrorChain {
+    pub origin_function: FunctionId,
+    pub origin: ErrorOrigin,
+    pub path: Vec<PropagationHop>,
+}
+
+type CalleeKey = (String, Option<String>);
+
+/// Indexes every `?`-using call site in `calls` by the callee it targets, so a reverse walk from a
+/// callee's [`FunctionId`] can find every caller that forwards its error without re-scanning the
+/// whole call list per hop.
+fn index_try_callers(calls: &[CallInfo]) -> HashMap<CalleeKey, Vec<(FunctionId, usize)>> {
+    let mut index: HashMap<CalleeKey, Vec<(FunctionId, usize)>> = HashMap::new();
+
+    for call in calls {
+        for edge in &call.callees {
+            if !edge.is_try_call {
+                continue;
+            }
+            let key = (edge.target.clone(), edge.target_type.clone());
+            index
+                .entry(key)
+                .or_default()
+                .push((call.caller.clone(), edge.line));
+        }
+    }
+
+    index
+}
+
+fn propagation_point_at<'a>(
+    errors_by_fn: &HashMap<&FunctionId, &'a ErrorInfo>,
+    function: &FunctionId,
+    line: usize,
+) -> Option<&'a PropagationPoint> {
+    errors_by_fn
+        .get(function)?
+        .propagation_points
+        .iter()
+        .find(|point| point.line == line)
+}
+
+/// The lookup tables a [`ErrorChain`] walk needs at every hop, bundled so `walk_callers` doesn't
+/// have to thread each one through as its own argument.
+struct ChainWalk<'a> {
+    try_callers: &'a HashMap<CalleeKey, Vec<(FunctionId, usize)>>,
+    errors_by_fn: &'a HashMap<&'a FunctionId, &'a ErrorInfo>,
+    origin_function: &'a FunctionId,
+    origin: &'a ErrorOrigin,
+}
+
+/// Walks every caller that forwards `function`'s error via `?`, extending `prefix` one hop per
+/// caller and recursing upward; a branch with no further `?`-using caller is finished off as one
+/// [`ErrorChain`].
+fn walk_callers(
+    function: &FunctionId,
+    walk: &ChainWalk,
+    visited: &mut Vec<FunctionId>,
+    prefix: &mut Vec<PropagationHop>,
+    chains: &mut Vec<ErrorChain>,
+) {
+    let key = (function.name.clone(), function.impl_type.clone());
+
+    let Some(callers) = walk.try_callers.get(&key) else {
+        if !prefix.is_empty() {
+            chains.push(ErrorChain {
+                origin_function: walk.origin_function.clone(),
+                origin: walk.origin.clone(),
+                path: prefix.clone(),
+            });
+        }
+        return;
+    };
+
+    let mut extended_any = false;
+    for (caller, line) in callers {
+        if visited.contains(caller) {
+            continue;
+        }
+        let Some(point) = propagation_point_at(walk.errors_by_fn, caller, *line) else {
+            continue;
+        };
+
+        extended_any = true;
+        prefix.push(PropagationHop {
+            function: caller.clone(),
+            point: point.clone(),
+        });
+        visited.push(caller.clone());
+
+        walk_callers(caller, walk, visited, prefix, chains);
+
+        visited.pop();
+        prefix.pop();
+    }
+
+    if !extended_any && !prefix.is_empty() {
+        chains.push(ErrorChain {
+            origin_function: walk.origin_function.clone(),
+            origin: walk.origin.clone(),
+            path: prefix.clone(),
+        });
+    }
+}
+
+/// Reconstructs every end-to-end error-propagation chain in the crate: for each [`ErrorOrigin`],
+/// walks the reverse call graph formed by `calls`' `?`-using [`crate::extract::calls::CallEdge`]s
+/// to find every caller that forwards it, the same way `std::error::Error::source()` lets you
+/// unwind a causal chain one layer at a time. Each maximal branch becomes its own [`ErrorChain`];
+/// an origin with no `?`-using caller at all produces none, since there's no path to report.
+pub fn build_error_chains(calls: &[CallInfo], errors: &[ErrorInfo]) -> Vec<ErrorChain> {
+    let try_callers = index_try_callers(calls);
+    let errors_by_fn: HashMap<&FunctionId, &ErrorInfo> = errors
+        .iter()
+        .map(|info| (&info.function_id, info))
+        .collect();
+
+    let mut chains = Vec::new();
+
+    for info in errors {
+        for origin in &info.error_origins {
+            let walk = ChainWalk {
+                try_callers: &try_callers,
+                errors_by_fn: &errors_by_fn,
+                origin_function: &info.function_id,
+                origin,
+            };
+            let mut visited = vec![info.function_id.clone()];
+            let mut prefix = Vec::new();
+            walk_callers(
+                &info.function_id,
+                &walk,
+                &mut visited,
+                &mut prefix,
+                &mut chains,
+            );
+        }
+    }
+
+    chains
+}
+
+/// Filters `chains` down to those whose path reaches `target`, answering "where can the error
+/// returned by `target` actually come from?" — the set of reachable [`ErrorOrigin`]s plus the
+/// concrete propagation path each one travels to get there.
+pub fn chains_reaching<'a>(chains: &'a [ErrorChain], target: &FunctionId) -> Vec<&'a ErrorChain> {
+    chains
+        .iter()
+        .filter(|chain| chain.path.iter().any(|hop| &hop.function == target))
+        .collect()
+}
@@ -0,0 +1,172 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::cache::Cache;
+use crate::callindex::{build_call_graph, CallTarget};
+use crate::extract::calls::FunctionId;
+use crate::extract::errors::ErrorReturnType;
+
+/// One `?` propagation point attributed to the specific callee it propagates from, by joining
+/// `ErrorInfo::propagation_points` against the resolved call graph on `(caller, line)` — the
+/// same key [`crate::callindex::build_call_graph`] already produces one `ResolvedCall` per.
+#[derive(Debug, Clone)]
+pub struct ErrorFlowEdge {
+    pub caller: FunctionId,
+    pub callee: FunctionId,
+    pub line: usize,
+    pub caller_err_type: Option<String>,
+    pub callee_err_type: Option<String>,
+    /// `true` when both sides declare a `Result` with differing `err_type`s, meaning this `?`
+    /// site can only compile via a `From`/`Into` conversion rather than a same-type propagation.
+    pub is_conversion: bool,
+}
+
+/// The crate-wide, directed error-flow graph [`build_error_flow_graph`] produces: every `?` site
+/// whose callee resolves to a known definition, paired with both sides' declared error type.
+#[derive(Debug, Clone, Default)]
+pub struct ErrorFlowGraph {
+    pub edges: Vec<ErrorFlowEdge>,
+}
+
+/// One `?` site where propagating the callee's error required an implicit `From`/`Into`
+/// conversion, stripped down to just the type transition — derived from an [`ErrorFlowEdge`]'s
+/// `is_conversion` edges, since that's already where both sides' resolved error types are known.
+#[derive(Debug, Clone)]
+pub struct ConversionEdge {
+    pub from_type: String,
+    pub to_type: String,
+    pub line: usize,
+}
+
+/// Extracts every implicit error-type conversion in `graph` as a standalone `from_type ->
+/// to_type` transition, so a function's declared error type can be traced as it's funneled and
+/// unified on the way up (e.g. `io::Error -> MyError -> anyhow::Error`) without carrying the full
+/// caller/callee [`FunctionId`]s an [`ErrorFlowEdge`] does.
+pub fn conversion_edges(graph: &ErrorFlowGraph) -> Vec<ConversionEdge> {
+    graph
+        .edges
+        .iter()
+        .filter(|edge| edge.is_conversion)
+        .filter_map(|edge| {
+            Some(ConversionEdge {
+                from_type: edge.callee_err_type.clone()?,
+                to_type: edge.caller_err_type.clone()?,
+                line: edge.line,
+            })
+        })
+        .collect()
+}
+
+fn declared_err_type(return_type: &ErrorReturnType) -> Option<String> {
+    match return_type {
+        ErrorReturnType::Result { err_type, .. } => Some(err_type.clone()),
+        ErrorReturnType::Option { .. } | ErrorReturnType::Neither => None,
+    }
+}
+
+/// Indexes every resolved call in `cache` by `(caller, line)`, the same pair
+/// `ErrorInfo::propagation_points` are keyed by within their own function, so a `?` site can be
+/// looked up directly instead of re-walking the call graph per propagation point.
+fn index_resolved_calls(cache: &Cache) -> HashMap<(FunctionId, usize), FunctionId> {
+    build_call_graph(cache)
+        .calls
+        .into_iter()
+        .filter_map(|call| match call.target {
+            CallTarget::Resolved(callee) => Some(((call.caller, call.line), callee)),
+            CallTarget::Unresolved(_) | CallTarget::Ambiguous | CallTarget::External => None,
+        })
+        .collect()
+}
+
+/// Joins every function's `ErrorInfo::propagation_points` against the crate's resolved call graph
+/// to produce one [`ErrorFlowEdge`] per `?` site whose callee resolves to a known definition.
+/// Propagation points whose callee is ambiguous, external, or only name-matched (unresolved
+/// receiver type) are dropped — there's no declared `err_type` on the other end to compare
+/// against, so they can't be attributed.
+pub fn build_error_flow_graph(cache: &Cache) -> ErrorFlowGraph {
+    let resolved_calls = index_resolved_calls(cache);
+
+    let mut err_types: HashMap<FunctionId, Option<String>> = HashMap::new();
+    for entry in cache.entries.values() {
+        for info in &entry.data.parsed.error_info {
+            err_types.insert(
+                info.function_id.clone(),
+                declared_err_type(&info.return_type),
+            );
+        }
+    }
+
+    let mut edges = Vec::new();
+    for entry in cache.entries.values() {
+        for info in &entry.data.parsed.error_info {
+            let caller_err_type = declared_err_type(&info.return_type);
+
+            for point in &info.propagation_points {
+                let Some(callee) = resolved_calls.get(&(info.function_id.clone(), point.line))
+                else {
+                    continue;
+                };
+
+                let callee_err_type = err_types.get(callee).cloned().flatten();
+                let is_conversion = match (&caller_err_type, &callee_err_type) {
+                    (Some(caller_type), Some(callee_type)) => caller_type != callee_type,
+                    _ => false,
+                };
+
+                edges.push(ErrorFlowEdge {
+                    caller: info.function_id.clone(),
+                    callee: callee.clone(),
+                    line: point.line,
+                    caller_err_type: caller_err_type.clone(),
+                    callee_err_type,
+                    is_conversion,
+                });
+            }
+        }
+    }
+
+    ErrorFlowGraph { edges }
+}
+
+/// Every error-originating function (`ErrorInfo::is_error_source()`) reachable by walking
+/// `graph` forward from `target` through its `?` sites — answers "what originating errors can
+/// `target` return", following conversions the same way a real caller chasing a bug would.
+pub fn origins_reaching(
+    cache: &Cache,
+    graph: &ErrorFlowGraph,
+    target: &FunctionId,
+) -> Vec<FunctionId> {
+    let mut by_caller: HashMap<&FunctionId, Vec<&FunctionId>> = HashMap::new();
+    for edge in &graph.edges {
+        by_caller
+            .entry(&edge.caller)
+            .or_default()
+            .push(&edge.callee);
+    }
+
+    let origins: HashSet<&FunctionId> = cache
+        .entries
+        .values()
+        .flat_map(|entry| &entry.data.parsed.error_info)
+        .filter(|info| info.is_error_source())
+        .map(|info| &info.function_id)
+        .collect();
+
+    let mut visited: HashSet<&FunctionId> = HashSet::new();
+    let mut stack = vec![target];
+    let mut found = Vec::new();
+
+    while let Some(node) = stack.pop() {
+        if !visited.insert(node) {
+            continue;
+        }
+        if node != target && origins.contains(node) {
+            found.push(node.clone());
+        }
+        if let Some(callees) = by_caller.get(node) {
+            stack.extend(callees.iter().copied());
+        }
+    }
+
+    found.sort_by(|a, b| a.qualified_name().cmp(&b.qualified_name()));
+    found
+}
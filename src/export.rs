@@ -0,0 +1,806 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::crossref::{self, CallEdgeResolved, TraitImplementors};
+use crate::extract::calls::{CallInfo, FunctionId};
+use crate::extract::errors::ErrorInfo;
+use crate::extract::safety::{
+    AsyncFunction, AsyncInfo, AwaitPoint, BlockingCall, BorrowInfo, ComplexBound, DocInfo,
+    FeatureFlagInfo, FunctionLifetime, GenericConstraints, ItemConstraints, LifetimeInfo,
+    PanicPoint, PythonSafetyInfo, SafetyInfo, SpawnPoint, StructLifetime, TestInfo, TypeParam,
+    UnsafeBlock, UnsafeImpl,
+};
+use crate::extract::symbols::{Symbol, SymbolKind};
+use crate::intern::{SymbolId, SymbolTable};
+use crate::pipeline::PipelineResult;
+use crate::visibility::{self, EffectiveVisibility};
+
+/// Bumped whenever a field is added, removed, or changes meaning in [`AnalysisDocument`] or
+/// anything it embeds — the one thing an external consumer needs to check before trusting the
+/// rest of the document. See [`from_document`].
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// The crate-wide call/error model in one self-contained, versioned document, so a consumer in
+/// another language can depend on a stable wire format instead of reverse-engineering the Rust
+/// structs behind `bincode`-serialized caches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisDocument {
+    pub schema_version: u32,
+    pub functions: Vec<FunctionId>,
+    pub calls: Vec<CallInfo>,
+    pub errors: Vec<ErrorInfo>,
+}
+
+/// Collects every function that appears as either a call's caller or a fallible function, so
+/// `functions` is a deduplicated index a consumer can resolve `FunctionId`s against without
+/// re-deriving it from `calls`/`errors` itself.
+fn collect_functions(calls: &[CallInfo], errors: &[ErrorInfo]) -> Vec<FunctionId> {
+    let mut functions: Vec<FunctionId> = calls.iter().map(|call| call.caller.clone()).collect();
+    functions.extend(errors.iter().map(|info| info.function_id.clone()));
+    functions
+        .sort_by(|a, b| (&a.file, &a.name, &a.impl_type).cmp(&(&b.file, &b.name, &b.impl_type)));
+    functions.dedup();
+    functions
+}
+
+/// Bundles every file's call graph and error info into one [`AnalysisDocument`] at the current
+/// [`SCHEMA_VERSION`].
+pub fn build_document(result: &PipelineResult) -> AnalysisDocument {
+    let mut calls = Vec::new();
+    let mut errors = Vec::new();
+
+    for file in &result.files {
+        calls.extend(file.parsed.call_graph.iter().cloned());
+        errors.extend(file.parsed.error_info.iter().cloned());
+    }
+
+    let functions = collect_functions(&calls, &errors);
+
+    AnalysisDocument {
+        schema_version: SCHEMA_VERSION,
+        functions,
+        calls,
+        errors,
+    }
+}
+
+/// Reverses [`build_document`]: checks `doc.schema_version` against the version this build of
+/// charter understands before trusting its contents, then hands back the raw `calls`/`errors`
+/// vectors the rest of the crate's analyses (`callindex`, `errorflow`, `errorchain`, ...) operate
+/// on. A mismatched version is rejected outright rather than guessed at, since there's no
+/// migration path between schema versions yet.
+pub fn from_document(doc: AnalysisDocument) -> Result<(Vec<CallInfo>, Vec<ErrorInfo>)> {
+    if doc.schema_version != SCHEMA_VERSION {
+        bail!(
+            "unsupported analysis document schema version {} (this build understands {})",
+            doc.schema_version,
+            SCHEMA_VERSION
+        );
+    }
+
+    Ok((doc.calls, doc.errors))
+}
+
+/// One field in a [`SchemaType`]'s machine-readable description: its name, a human-readable type
+/// name, and whether it may be absent (`Option<T>` on the Rust side).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaField {
+    pub name: &'static str,
+    pub type_name: &'static str,
+    pub optional: bool,
+}
+
+/// A described Rust type in the call/error model, either a struct (a flat field list) or an enum
+/// (a list of variant tags, each carrying its own field list) — enough for a consumer to generate
+/// bindings or validate a document without reading `extract/calls.rs`/`extract/errors.rs`
+/// directly, the same introspection role QAPI's `query-qmp-schema` plays for QEMU's wire protocol.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SchemaType {
+    Struct {
+        name: &'static str,
+        fields: Vec<SchemaField>,
+    },
+    Enum {
+        name: &'static str,
+        variants: Vec<&'static str>,
+    },
+}
+
+fn field(name: &'static str, type_name: &'static str, optional: bool) -> SchemaField {
+    SchemaField {
+        name,
+        type_name,
+        optional,
+    }
+}
+
+/// Hand-authored description of every type [`AnalysisDocument`] embeds, kept in sync by hand with
+/// `extract/calls.rs`/`extract/errors.rs` — charter has no derive-time reflection, so this is the
+/// one place a field rename or addition there needs a matching update here.
+pub fn schema_description() -> Vec<SchemaType> {
+    vec![
+        SchemaType::Struct {
+            name: "AnalysisDocument",
+            fields: vec![
+                field("schema_version", "u32", false),
+                field("functions", "[FunctionId]", false),
+                field("calls", "[CallInfo]", false),
+                field("errors", "[ErrorInfo]", false),
+            ],
+        },
+        SchemaType::Struct {
+            name: "FunctionId",
+            fields: vec![
+                field("file", "string", false),
+                field("name", "string", false),
+                field("impl_type", "string", true),
+            ],
+        },
+        SchemaType::Struct {
+            name: "CallEdge",
+            fields: vec![
+                field("target", "string", false),
+                field("target_type", "string", true),
+                field("line", "usize", false),
+                field("is_async_call", "bool", false),
+                field("is_try_call", "bool", false),
+            ],
+        },
+        SchemaType::Struct {
+            name: "CallInfo",
+            fields: vec![
+                field("caller", "FunctionId", false),
+                field("callees", "[CallEdge]", false),
+                field("line", "usize", false),
+            ],
+        },
+        SchemaType::Enum {
+            name: "ErrorReturnType",
+            variants: vec!["Result", "Option", "Neither"],
+        },
+        SchemaType::Enum {
+            name: "ErrorOriginKind",
+            variants: vec![
+                "ErrConstructor",
+                "AnyhowMacro",
+                "BailMacro",
+                "NoneReturn",
+                "CustomError",
+                "RaiseStatement",
+                "BareReraise",
+                "AssertStatement",
+            ],
+        },
+        SchemaType::Struct {
+            name: "ErrorOrigin",
+            fields: vec![
+                field("line", "usize", false),
+                field("kind", "ErrorOriginKind", false),
+                field("message", "string", true),
+            ],
+        },
+        SchemaType::Enum {
+            name: "ErrorSinkKind",
+            variants: vec!["Unwrap", "Expect", "UnwrapOrDiscard", "Discarded"],
+        },
+        SchemaType::Struct {
+            name: "ErrorSink",
+            fields: vec![
+                field("line", "usize", false),
+                field("kind", "ErrorSinkKind", false),
+                field("call_target", "string", false),
+                field("message", "string", true),
+            ],
+        },
+        SchemaType::Struct {
+            name: "ContextAnnotation",
+            fields: vec![
+                field("message", "string", false),
+                field("lazy", "bool", false),
+            ],
+        },
+        SchemaType::Struct {
+            name: "PropagationPoint",
+            fields: vec![
+                field("line", "usize", false),
+                field("expression", "string", false),
+                field("context", "ContextAnnotation", true),
+            ],
+        },
+        SchemaType::Struct {
+            name: "ErrorInfo",
+            fields: vec![
+                field("function_id", "FunctionId", false),
+                field("return_type", "ErrorReturnType", false),
+                field("propagation_points", "[PropagationPoint]", false),
+                field("error_origins", "[ErrorOrigin]", false),
+                field("error_sinks", "[ErrorSink]", false),
+                field("line", "usize", false),
+            ],
+        },
+    ]
+}
+
+/// Bumped whenever a field is added, removed, or changes meaning in [`ModelDocument`] or anything
+/// it embeds. Tracked separately from [`SCHEMA_VERSION`] since the two documents describe
+/// unrelated slices of the model (call/error graph vs. the full structural symbol table) and can
+/// evolve independently.
+///
+/// v2: added `ModelItem::effective_visibility`.
+/// v3: added `ModelDocument::cross_references`.
+pub const MODEL_FORMAT_VERSION: u32 = 3;
+
+/// One entry in a [`ModelDocument`]'s `paths` map: a stable item's fully-qualified module path and
+/// item kind, resolvable without walking the nested per-file symbol lists in `index`. Mirrors the
+/// split rustdoc's own `paths` map makes between "where is this" and "what is this".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemPath {
+    pub path: String,
+    pub kind: &'static str,
+}
+
+/// One entry in a [`ModelDocument`]'s `index` map: the file a symbol came from, the symbol itself,
+/// and its resolved [`EffectiveVisibility`], keyed by the same [`Id`] as its [`ItemPath`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelItem {
+    pub file: String,
+    pub symbol: Symbol,
+    pub effective_visibility: EffectiveVisibility,
+}
+
+/// A stable key into a [`ModelDocument`]'s `index`/`paths` maps: `{file}:{line}:{name}`, unique
+/// per symbol and unchanged by a capture that doesn't touch the symbol's own file.
+pub type Id = String;
+
+/// The resolved cross-reference graph bundled into a [`ModelDocument`]: which types implement
+/// which traits, and which free functions call which other free functions, each resolved to an
+/// `index` [`Id`] rather than left as the bare name strings `impl_map`/`call_graph` record. See
+/// [`crate::crossref`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrossReferences {
+    pub trait_implementors: Vec<TraitImplementors>,
+    pub call_edges: Vec<CallEdgeResolved>,
+}
+
+/// The whole structural model — every symbol charter extracted, across every file — as one
+/// self-contained, versioned document, modeled on rustdoc's own JSON output: a flat `index` keyed
+/// by [`Id`] so a consumer can resolve cross-references without walking a nested file tree, a
+/// `paths` map giving each `Id`'s fully-qualified module path and kind, a `cross_references` graph
+/// a consumer can navigate like rustdoc's own `paths`/links index, and top-level metadata
+/// mirroring the same git commit / timestamp / file / line counts `format_stamp` puts in every
+/// other output document's header.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelDocument {
+    pub format_version: u32,
+    pub git_commit: Option<String>,
+    pub timestamp: String,
+    pub file_count: usize,
+    pub line_count: usize,
+    pub index: HashMap<Id, ModelItem>,
+    pub paths: HashMap<Id, ItemPath>,
+    pub cross_references: CrossReferences,
+}
+
+/// Converts a file's `relative_path` (e.g. `src/extract/symbols.rs`) into a `::`-joined module
+/// path (`extract::symbols`), dropping the `src/` prefix, the `.rs` extension, and collapsing a
+/// trailing `mod`/`lib`/`main` file name into its parent module.
+fn module_path_for(relative_path: &str) -> String {
+    let without_ext = relative_path.trim_end_matches(".rs");
+    let without_src = without_ext.strip_prefix("src/").unwrap_or(without_ext);
+    let segments: Vec<&str> = without_src
+        .split('/')
+        .filter(|segment| !matches!(*segment, "mod" | "lib" | "main"))
+        .collect();
+    segments.join("::")
+}
+
+fn symbol_kind_name(kind: &SymbolKind) -> &'static str {
+    match kind {
+        SymbolKind::Struct { .. } => "struct",
+        SymbolKind::Enum { .. } => "enum",
+        SymbolKind::Trait { .. } => "trait",
+        SymbolKind::Function { .. } => "function",
+        SymbolKind::Const { .. } => "const",
+        SymbolKind::Static { .. } => "static",
+        SymbolKind::TypeAlias { .. } => "type_alias",
+        SymbolKind::Mod => "mod",
+        SymbolKind::Class { .. } => "class",
+        SymbolKind::PythonFunction { .. } => "function",
+        SymbolKind::Variable { .. } => "variable",
+        SymbolKind::PythonModule => "module",
+    }
+}
+
+/// Bundles every file's extracted symbols into one [`ModelDocument`] at the current
+/// [`MODEL_FORMAT_VERSION`]. An `Id` collision (two symbols at the same file/line/name) overwrites
+/// the earlier entry; tree-sitter never emits duplicate siblings at one line, so in practice this
+/// only happens for macro-expanded code this crate doesn't re-parse anyway.
+pub fn build_model_document(result: &PipelineResult) -> ModelDocument {
+    let mut index = HashMap::new();
+    let mut paths = HashMap::new();
+
+    let effective_visibility_by_key: HashMap<(String, usize, String), EffectiveVisibility> =
+        visibility::compute_reachability(result)
+            .into_iter()
+            .map(|r| ((r.file, r.line, r.name), r.effective))
+            .collect();
+
+    for file in &result.files {
+        let module_path = module_path_for(&file.relative_path);
+
+        for symbol in &file.parsed.symbols.symbols {
+            let id = format!("{}:{}:{}", file.relative_path, symbol.line, symbol.name);
+            let fq_path = if module_path.is_empty() {
+                symbol.name.clone()
+            } else {
+                format!("{}::{}", module_path, symbol.name)
+            };
+            let key = (file.relative_path.clone(), symbol.line, symbol.name.clone());
+            let effective_visibility = effective_visibility_by_key
+                .get(&key)
+                .copied()
+                .unwrap_or(EffectiveVisibility::NotPublic);
+
+            paths.insert(
+                id.clone(),
+                ItemPath {
+                    path: fq_path,
+                    kind: symbol_kind_name(&symbol.kind),
+                },
+            );
+            index.insert(
+                id,
+                ModelItem {
+                    file: file.relative_path.clone(),
+                    symbol: symbol.clone(),
+                    effective_visibility,
+                },
+            );
+        }
+    }
+
+    let cross_references = CrossReferences {
+        trait_implementors: crossref::resolve_trait_implementors(result),
+        call_edges: crossref::resolve_call_edges(result),
+    };
+
+    ModelDocument {
+        format_version: MODEL_FORMAT_VERSION,
+        git_commit: result.git_info.as_ref().map(|g| g.commit_short.clone()),
+        timestamp: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        file_count: result.files.len(),
+        line_count: result.total_lines,
+        index,
+        paths,
+        cross_references,
+    }
+}
+
+/// Bumped whenever a field is added, removed, or changes meaning in [`SafetyDocument`] or any of
+/// the per-file clusters it bundles, mirroring rustdoc-json's own `format_version` field (see
+/// [`crate::rustdoc_json::SUPPORTED_FORMAT_VERSION`] for the equivalent check on ingest rather
+/// than export). Tracked separately from [`SCHEMA_VERSION`]/[`MODEL_FORMAT_VERSION`] since all
+/// three documents describe unrelated slices of the model and can evolve independently.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// One file's worth of the safety/async/lifetime/feature-flag/doc/generic/test/Python-safety
+/// clusters bundled into a [`SafetyDocument`]. `lifetimes`, `async_info`, `feature_flags`, and
+/// `generic_constraints` are currently always their `Default` — nothing on `ParsedFile`
+/// populates them yet — but are included now so this wire shape doesn't need another version
+/// bump once they are.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileSafetyFacts {
+    pub file: String,
+    pub safety: SafetyInfo,
+    pub lifetimes: LifetimeInfo,
+    pub async_info: AsyncInfo,
+    pub feature_flags: FeatureFlagInfo,
+    pub doc_info: DocInfo,
+    pub generic_constraints: GenericConstraints,
+    pub test_info: TestInfo,
+    pub python_safety: PythonSafetyInfo,
+}
+
+/// Every file's [`FileSafetyFacts`] in one self-contained, versioned document — the same
+/// stable-wire-format role [`AnalysisDocument`] plays for the call/error model, but for the
+/// safety/async/lifetime/doc/test clusters instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SafetyDocument {
+    pub format_version: u32,
+    pub files: Vec<FileSafetyFacts>,
+}
+
+/// Bundles every file's safety/async/lifetime/doc/test facts into one [`SafetyDocument`] at the
+/// current [`FORMAT_VERSION`].
+pub fn build_safety_document(result: &PipelineResult) -> SafetyDocument {
+    let files = result
+        .files
+        .iter()
+        .map(|file| FileSafetyFacts {
+            file: file.relative_path.clone(),
+            safety: file.parsed.safety.clone(),
+            lifetimes: LifetimeInfo::default(),
+            async_info: AsyncInfo::default(),
+            feature_flags: FeatureFlagInfo::default(),
+            doc_info: file.parsed.doc_info.clone(),
+            generic_constraints: GenericConstraints::default(),
+            test_info: file.parsed.test_info.clone(),
+            python_safety: PythonSafetyInfo::default(),
+        })
+        .collect();
+
+    SafetyDocument {
+        format_version: FORMAT_VERSION,
+        files,
+    }
+}
+
+/// Reverses [`build_safety_document`]: checks `doc.format_version` against the version this build
+/// of charter understands before trusting its contents, rejecting a mismatch outright rather than
+/// guessing at a shape that may have since changed — mirroring [`from_document`]'s check for
+/// [`AnalysisDocument`].
+pub fn from_safety_document(doc: SafetyDocument) -> Result<Vec<FileSafetyFacts>> {
+    if doc.format_version != FORMAT_VERSION {
+        bail!(
+            "unsupported safety document format version {} (this build understands {})",
+            doc.format_version,
+            FORMAT_VERSION
+        );
+    }
+
+    Ok(doc.files)
+}
+
+/// The interned mirror of [`UnsafeImpl`]: `trait_name`/`type_name` are [`SymbolId`]s into the
+/// owning [`InternedSafetyDocument`]'s [`SymbolTable`] instead of repeated `String`s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InternedUnsafeImpl {
+    pub trait_name: SymbolId,
+    pub type_name: SymbolId,
+    pub line: usize,
+}
+
+/// The interned mirror of [`BorrowInfo`]: `lifetime` is a [`SymbolId`] rather than an
+/// `Option<String>`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InternedBorrowInfo {
+    pub param_name: String,
+    pub is_mutable: bool,
+    pub lifetime: Option<SymbolId>,
+}
+
+/// The interned mirror of [`FunctionLifetime`]: `impl_type` is a [`SymbolId`] rather than an
+/// `Option<String>`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InternedFunctionLifetime {
+    pub function_name: String,
+    pub impl_type: Option<SymbolId>,
+    pub line: usize,
+    pub lifetimes: Vec<String>,
+    pub has_static: bool,
+    pub borrows: Vec<InternedBorrowInfo>,
+}
+
+/// The interned mirror of [`LifetimeInfo`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InternedLifetimeInfo {
+    pub function_lifetimes: Vec<InternedFunctionLifetime>,
+    pub struct_lifetimes: Vec<StructLifetime>,
+    pub complex_bounds: Vec<ComplexBound>,
+}
+
+/// The interned mirror of [`AsyncFunction`]: `impl_type` is a [`SymbolId`] rather than an
+/// `Option<String>`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InternedAsyncFunction {
+    pub name: String,
+    pub impl_type: Option<SymbolId>,
+    pub line: usize,
+    pub awaits: Vec<AwaitPoint>,
+    pub spawns: Vec<SpawnPoint>,
+}
+
+/// The interned mirror of [`AsyncInfo`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InternedAsyncInfo {
+    pub async_functions: Vec<InternedAsyncFunction>,
+    pub blocking_calls: Vec<BlockingCall>,
+}
+
+/// The interned mirror of [`TypeParam`]: `bounds` are [`SymbolId`]s rather than `String`s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InternedTypeParam {
+    pub name: String,
+    pub bounds: Vec<SymbolId>,
+}
+
+/// The interned mirror of [`ItemConstraints`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InternedItemConstraints {
+    pub item_name: String,
+    pub item_kind: String,
+    pub line: usize,
+    pub type_params: Vec<InternedTypeParam>,
+    pub where_clause: Option<String>,
+}
+
+/// The interned mirror of [`GenericConstraints`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InternedGenericConstraints {
+    pub constraints: Vec<InternedItemConstraints>,
+}
+
+/// The interned mirror of [`SafetyInfo`]: everything but `unsafe_impls` is already free of
+/// repeated name strings, so only that field changes shape.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InternedSafetyInfo {
+    pub unsafe_blocks: Vec<UnsafeBlock>,
+    pub panic_points: Vec<PanicPoint>,
+    pub unsafe_traits: Vec<String>,
+    pub unsafe_impls: Vec<InternedUnsafeImpl>,
+}
+
+/// The interned mirror of [`FileSafetyFacts`], referencing symbols in the owning
+/// [`InternedSafetyDocument`]'s [`SymbolTable`] instead of repeating them inline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InternedFileSafetyFacts {
+    pub file: String,
+    pub safety: InternedSafetyInfo,
+    pub lifetimes: InternedLifetimeInfo,
+    pub async_info: InternedAsyncInfo,
+    pub feature_flags: FeatureFlagInfo,
+    pub doc_info: DocInfo,
+    pub generic_constraints: InternedGenericConstraints,
+    pub test_info: TestInfo,
+    pub python_safety: PythonSafetyInfo,
+}
+
+/// The interned counterpart to [`SafetyDocument`]: every repeated type/trait/lifetime name
+/// (`UnsafeImpl::trait_name`/`type_name`, `TypeParam::bounds`, `BorrowInfo::lifetime`,
+/// `FunctionLifetime::impl_type`, `AsyncFunction::impl_type`) is replaced by a [`SymbolId`] into
+/// `symbol_table`, serialized once here instead of once per occurrence. Use
+/// [`build_interned_safety_document`]/[`resolve_interned_safety_document`] to convert to and from
+/// the plain [`SafetyDocument`]; prefer the plain form for human-readable output (`--format json`
+/// for a person to read) and this one when shipping analysis for a large crate where the repeated
+/// strings would otherwise dominate the payload.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InternedSafetyDocument {
+    pub format_version: u32,
+    pub symbol_table: SymbolTable,
+    pub files: Vec<InternedFileSafetyFacts>,
+}
+
+/// Builds an [`InternedSafetyDocument`] from a plain [`SafetyDocument`], interning every
+/// `trait_name`/`type_name`/`bounds`/`lifetime`/`impl_type` string into a fresh [`SymbolTable`]
+/// as it goes.
+pub fn build_interned_safety_document(doc: &SafetyDocument) -> InternedSafetyDocument {
+    let mut table = SymbolTable::new();
+
+    let files = doc
+        .files
+        .iter()
+        .map(|facts| InternedFileSafetyFacts {
+            file: facts.file.clone(),
+            safety: InternedSafetyInfo {
+                unsafe_blocks: facts.safety.unsafe_blocks.clone(),
+                panic_points: facts.safety.panic_points.clone(),
+                unsafe_traits: facts.safety.unsafe_traits.clone(),
+                unsafe_impls: facts
+                    .safety
+                    .unsafe_impls
+                    .iter()
+                    .map(|imp| InternedUnsafeImpl {
+                        trait_name: table.intern(&imp.trait_name),
+                        type_name: table.intern(&imp.type_name),
+                        line: imp.line,
+                    })
+                    .collect(),
+            },
+            lifetimes: InternedLifetimeInfo {
+                function_lifetimes: facts
+                    .lifetimes
+                    .function_lifetimes
+                    .iter()
+                    .map(|fl| InternedFunctionLifetime {
+                        function_name: fl.function_name.clone(),
+                        impl_type: fl.impl_type.as_deref().map(|s| table.intern(s)),
+                        line: fl.line,
+                        lifetimes: fl.lifetimes.clone(),
+                        has_static: fl.has_static,
+                        borrows: fl
+                            .borrows
+                            .iter()
+                            .map(|b| InternedBorrowInfo {
+                                param_name: b.param_name.clone(),
+                                is_mutable: b.is_mutable,
+                                lifetime: b.lifetime.as_deref().map(|s| table.intern(s)),
+                            })
+                            .collect(),
+                    })
+                    .collect(),
+                struct_lifetimes: facts.lifetimes.struct_lifetimes.clone(),
+                complex_bounds: facts.lifetimes.complex_bounds.clone(),
+            },
+            async_info: InternedAsyncInfo {
+                async_functions: facts
+                    .async_info
+                    .async_functions
+                    .iter()
+                    .map(|af| InternedAsyncFunction {
+                        name: af.name.clone(),
+                        impl_type: af.impl_type.as_deref().map(|s| table.intern(s)),
+                        line: af.line,
+                        awaits: af.awaits.clone(),
+                        spawns: af.spawns.clone(),
+                    })
+                    .collect(),
+                blocking_calls: facts.async_info.blocking_calls.clone(),
+            },
+            feature_flags: facts.feature_flags.clone(),
+            doc_info: facts.doc_info.clone(),
+            generic_constraints: InternedGenericConstraints {
+                constraints: facts
+                    .generic_constraints
+                    .constraints
+                    .iter()
+                    .map(|c| InternedItemConstraints {
+                        item_name: c.item_name.clone(),
+                        item_kind: c.item_kind.clone(),
+                        line: c.line,
+                        type_params: c
+                            .type_params
+                            .iter()
+                            .map(|tp| InternedTypeParam {
+                                name: tp.name.clone(),
+                                bounds: tp.bounds.iter().map(|b| table.intern(b)).collect(),
+                            })
+                            .collect(),
+                        where_clause: c.where_clause.clone(),
+                    })
+                    .collect(),
+            },
+            test_info: facts.test_info.clone(),
+            python_safety: facts.python_safety.clone(),
+        })
+        .collect();
+
+    InternedSafetyDocument {
+        format_version: FORMAT_VERSION,
+        symbol_table: table,
+        files,
+    }
+}
+
+/// Reverses [`build_interned_safety_document`], resolving every [`SymbolId`] back to its string
+/// via `doc.symbol_table` and rejecting the document if any ID turns out to be out of range for
+/// that table — the same defensive posture [`from_safety_document`] takes toward a bad
+/// `format_version`.
+pub fn resolve_interned_safety_document(doc: &InternedSafetyDocument) -> Result<SafetyDocument> {
+    let table = &doc.symbol_table;
+    let resolve = |id: SymbolId| -> Result<String> {
+        table
+            .resolve(id)
+            .map(str::to_string)
+            .ok_or_else(|| anyhow::anyhow!("symbol id {id} not found in interned symbol table"))
+    };
+    let resolve_opt = |id: Option<SymbolId>| -> Result<Option<String>> {
+        id.map(resolve).transpose()
+    };
+
+    let mut files = Vec::with_capacity(doc.files.len());
+    for facts in &doc.files {
+        let unsafe_impls = facts
+            .safety
+            .unsafe_impls
+            .iter()
+            .map(|imp| {
+                Ok(UnsafeImpl {
+                    trait_name: resolve(imp.trait_name)?,
+                    type_name: resolve(imp.type_name)?,
+                    line: imp.line,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let function_lifetimes = facts
+            .lifetimes
+            .function_lifetimes
+            .iter()
+            .map(|fl| {
+                let borrows = fl
+                    .borrows
+                    .iter()
+                    .map(|b| {
+                        Ok(BorrowInfo {
+                            param_name: b.param_name.clone(),
+                            is_mutable: b.is_mutable,
+                            lifetime: resolve_opt(b.lifetime)?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(FunctionLifetime {
+                    function_name: fl.function_name.clone(),
+                    impl_type: resolve_opt(fl.impl_type)?,
+                    line: fl.line,
+                    lifetimes: fl.lifetimes.clone(),
+                    has_static: fl.has_static,
+                    borrows,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let async_functions = facts
+            .async_info
+            .async_functions
+            .iter()
+            .map(|af| {
+                Ok(AsyncFunction {
+                    name: af.name.clone(),
+                    impl_type: resolve_opt(af.impl_type)?,
+                    line: af.line,
+                    awaits: af.awaits.clone(),
+                    spawns: af.spawns.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let constraints = facts
+            .generic_constraints
+            .constraints
+            .iter()
+            .map(|c| {
+                let type_params = c
+                    .type_params
+                    .iter()
+                    .map(|tp| {
+                        Ok(TypeParam {
+                            name: tp.name.clone(),
+                            bounds: tp
+                                .bounds
+                                .iter()
+                                .map(|&b| resolve(b))
+                                .collect::<Result<Vec<_>>>()?,
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ItemConstraints {
+                    item_name: c.item_name.clone(),
+                    item_kind: c.item_kind.clone(),
+                    line: c.line,
+                    type_params,
+                    where_clause: c.where_clause.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        files.push(FileSafetyFacts {
+            file: facts.file.clone(),
+            safety: SafetyInfo {
+                unsafe_blocks: facts.safety.unsafe_blocks.clone(),
+                panic_points: facts.safety.panic_points.clone(),
+                unsafe_traits: facts.safety.unsafe_traits.clone(),
+                unsafe_impls,
+            },
+            lifetimes: LifetimeInfo {
+                function_lifetimes,
+                struct_lifetimes: facts.lifetimes.struct_lifetimes.clone(),
+                complex_bounds: facts.lifetimes.complex_bounds.clone(),
+            },
+            async_info: AsyncInfo {
+                async_functions,
+                blocking_calls: facts.async_info.blocking_calls.clone(),
+            },
+            feature_flags: facts.feature_flags.clone(),
+            doc_info: facts.doc_info.clone(),
+            generic_constraints: GenericConstraints { constraints },
+            test_info: facts.test_info.clone(),
+            python_safety: facts.python_safety.clone(),
+        });
+    }
+
+    Ok(SafetyDocument {
+        format_version: doc.format_version,
+        files,
+    })
+}
@@ -1,8 +1,14 @@
 pub mod attributes;
 pub mod calls;
+pub mod cfg;
 pub mod complexity;
 pub mod errors;
 pub mod imports;
 pub mod language;
+pub mod lints;
+pub mod migrations;
 pub mod safety;
+pub mod safety_diff;
+pub mod scope;
+pub mod symbol_diff;
 pub mod symbols;
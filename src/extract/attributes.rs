@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::cfg::CfgPredicate;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeriveInfo {
     pub target: String,
@@ -7,8 +9,24 @@ pub struct DeriveInfo {
     pub line: usize,
 }
 
+/// One "type T implements trait X" fact, whether `X` came from `#[derive(X)]` or a manual
+/// `impl X for T`. Recorded per-file alongside [`DeriveInfo`] so a crate-level pass (see
+/// [`crate::traitindex`]) can merge them into "who implements `Display`"-style queries.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraitImpl {
+    pub type_name: String,
+    pub trait_name: String,
+    pub is_derived: bool,
+    pub line: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CfgInfo {
     pub condition: String,
+    /// The parsed form of `condition`, when it parses as a well-formed `all`/`any`/`not`/
+    /// key-value/flag predicate (see [`crate::extract::cfg`]). `None` for a condition this
+    /// crate's predicate parser doesn't recognize; `condition` is kept either way since this
+    /// struct is also just used to report raw cfg occurrences.
+    pub predicate: Option<CfgPredicate>,
     pub line: usize,
 }
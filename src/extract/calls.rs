@@ -60,3 +60,16 @@ impl CallInfo {
         }
     }
 }
+
+/// A single macro invocation site, recorded alongside `call_graph` so downstream consumers can
+/// build a macro-usage graph the same way they build a call graph. `path` is the macro's full
+/// source text (`tokio::select` resolves as-is since a `scoped_identifier` node's text already
+/// includes its qualifying segments).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroCall {
+    pub path: String,
+    pub line: usize,
+    pub enclosing_function: Option<String>,
+    pub enclosing_impl: Option<String>,
+    pub is_builtin: bool,
+}
@@ -0,0 +1,185 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A `#[cfg(...)]` predicate parsed into a boolean AST, mirroring the grammar rustc itself
+/// accepts: `all(..)`/`any(..)`/`not(..)` combinators over leaf predicates.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CfgPredicate {
+    All(Vec<CfgPredicate>),
+    Any(Vec<CfgPredicate>),
+    Not(Box<CfgPredicate>),
+    /// `key = "value"`, e.g. `feature = "foo"` or `target_os = "linux"`.
+    KeyValue(String, String),
+    /// A bare flag with no value, e.g. `unix`, `test`, `debug_assertions`.
+    Flag(String),
+}
+
+/// The set of cfg flags considered active when evaluating a [`CfgPredicate`]. Bare flags
+/// (`unix`, `test`) live in `flags`; `key = "value"` predicates (`feature = "x"`) are looked
+/// up in `key_values`, which allows a key like `feature` to carry more than one active value.
+#[derive(Debug, Clone, Default)]
+pub struct CfgSet {
+    pub flags: HashSet<String>,
+    pub key_values: HashMap<String, HashSet<String>>,
+}
+
+impl CfgSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_flag(mut self, flag: impl Into<String>) -> Self {
+        self.flags.insert(flag.into());
+        self
+    }
+
+    pub fn with_key_value(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.key_values
+            .entry(key.into())
+            .or_default()
+            .insert(value.into());
+        self
+    }
+
+    pub fn with_feature(self, feature: impl Into<String>) -> Self {
+        self.with_key_value("feature", feature)
+    }
+
+    pub fn with_target_os(self, target_os: impl Into<String>) -> Self {
+        self.with_key_value("target_os", target_os)
+    }
+
+    pub fn with_target_arch(self, target_arch: impl Into<String>) -> Self {
+        self.with_key_value("target_arch", target_arch)
+    }
+
+    /// Parses a `--cfg` CLI argument (`ident` or `key=value`/`key="value"`) into this set,
+    /// mirroring `rustc --cfg`'s own flag syntax.
+    pub fn apply_arg(&mut self, arg: &str) {
+        match arg.split_once('=') {
+            Some((key, value)) => {
+                self.key_values
+                    .entry(key.trim().to_string())
+                    .or_default()
+                    .insert(value.trim().trim_matches('"').to_string());
+            }
+            None => {
+                self.flags.insert(arg.trim().to_string());
+            }
+        }
+    }
+
+    fn has_flag(&self, flag: &str) -> bool {
+        self.flags.contains(flag)
+    }
+
+    fn has_key_value(&self, key: &str, value: &str) -> bool {
+        self.key_values
+            .get(key)
+            .is_some_and(|values| values.contains(value))
+    }
+}
+
+impl CfgPredicate {
+    pub fn evaluate(&self, active: &CfgSet) -> bool {
+        match self {
+            CfgPredicate::All(predicates) => predicates.iter().all(|p| p.evaluate(active)),
+            CfgPredicate::Any(predicates) => predicates.iter().any(|p| p.evaluate(active)),
+            CfgPredicate::Not(predicate) => !predicate.evaluate(active),
+            CfgPredicate::KeyValue(key, value) => active.has_key_value(key, value),
+            CfgPredicate::Flag(flag) => active.has_flag(flag),
+        }
+    }
+
+    /// Collects every `feature = "..."` name referenced anywhere in this predicate tree into
+    /// `out`, so a caller can build the crate-wide feature set `feature_combinations` needs by
+    /// folding this over every [`CfgPredicate`] it has found.
+    pub fn collect_feature_names(&self, out: &mut HashSet<String>) {
+        match self {
+            CfgPredicate::All(predicates) | CfgPredicate::Any(predicates) => {
+                for predicate in predicates {
+                    predicate.collect_feature_names(out);
+                }
+            }
+            CfgPredicate::Not(predicate) => predicate.collect_feature_names(out),
+            CfgPredicate::KeyValue(key, value) if key == "feature" => {
+                out.insert(value.clone());
+            }
+            CfgPredicate::KeyValue(..) | CfgPredicate::Flag(_) => {}
+        }
+    }
+
+    /// Every *minimal* subset of `features` that makes this predicate true when enabled on its
+    /// own (every feature not in the subset left off) — the feature combinations a consumer
+    /// actually needs to turn on to reach whatever this predicate gates, rather than every
+    /// satisfying superset of one. A result containing an empty `Vec` means the predicate holds
+    /// with no features enabled at all (e.g. a bare `not(feature = "x")`), so that item is active
+    /// in the default, no-features configuration.
+    ///
+    /// Brute-forces the `2^features.len()` subsets, so this is only reasonable for the small
+    /// feature counts real crates have — it isn't meant for predicates over dozens of flags.
+    pub fn feature_combinations(&self, features: &[String]) -> Vec<Vec<String>> {
+        let mut satisfying = Vec::new();
+
+        for mask in 0u32..(1u32 << features.len()) {
+            let subset: Vec<String> = features
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| mask & (1 << index) != 0)
+                .map(|(_, feature)| feature.clone())
+                .collect();
+
+            let mut active = CfgSet::new();
+            for feature in &subset {
+                active = active.with_feature(feature.clone());
+            }
+
+            if self.evaluate(&active) {
+                satisfying.push(subset);
+            }
+        }
+
+        satisfying.sort_by_key(Vec::len);
+
+        let mut minimal: Vec<Vec<String>> = Vec::new();
+        for candidate in satisfying {
+            let already_covered = minimal
+                .iter()
+                .any(|existing| existing.iter().all(|feature| candidate.contains(feature)));
+            if !already_covered {
+                minimal.push(candidate);
+            }
+        }
+
+        minimal
+    }
+}
+
+/// Canonical `cfg(..)` source form, independent of the whitespace/quoting the original
+/// attribute happened to use — e.g. `all(unix, not(feature = "foo"))`. [`Symbol::cfg`] and
+/// [`crate::extract::attributes::CfgInfo`] store this instead of the raw attribute text so two
+/// items gated by the same condition written differently still compare and display identically.
+impl fmt::Display for CfgPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CfgPredicate::All(predicates) => write_combinator(f, "all", predicates),
+            CfgPredicate::Any(predicates) => write_combinator(f, "any", predicates),
+            CfgPredicate::Not(predicate) => write!(f, "not({predicate})"),
+            CfgPredicate::KeyValue(key, value) => write!(f, "{key} = \"{value}\""),
+            CfgPredicate::Flag(flag) => write!(f, "{flag}"),
+        }
+    }
+}
+
+fn write_combinator(f: &mut fmt::Formatter<'_>, name: &str, predicates: &[CfgPredicate]) -> fmt::Result {
+    write!(f, "{name}(")?;
+    for (index, predicate) in predicates.iter().enumerate() {
+        if index > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{predicate}")?;
+    }
+    write!(f, ")")
+}
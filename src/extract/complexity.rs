@@ -17,36 +17,181 @@ impl std::fmt::Display for ImportanceTier {
     }
 }
 
+/// The LSP error/warning/info/hint spectrum, applied to a hotspot's weighted score instead of a
+/// diagnostic's own declared level. Lets a CI gate fail only on the worst offenders
+/// ([`ScoringWeights::error_threshold`]) while still surfacing everything down to `Hint` for an
+/// editor that wants the full picture without treating it as build-breaking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum HotspotSeverity {
+    Hint,
+    Info,
+    Warning,
+    Error,
+}
+
+impl std::fmt::Display for HotspotSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HotspotSeverity::Error => write!(f, "error"),
+            HotspotSeverity::Warning => write!(f, "warning"),
+            HotspotSeverity::Info => write!(f, "info"),
+            HotspotSeverity::Hint => write!(f, "hint"),
+        }
+    }
+}
+
+/// Per-factor weights and severity cutoffs for [`ComplexityMetrics::importance_score_with`],
+/// replacing the crate's former hard-coded `(cyclomatic*2)+(cognitive*2)+(lines/10)+
+/// (call_sites*3)+(churn*2)+(public?10:0)` formula and `>=30`/`>=15` tier cutoffs with the same
+/// numbers as tunable defaults. A churn-heavy project can raise `churn`; an API-surface-heavy one
+/// can raise `public_bonus`; either can move `error_threshold` to change what fails CI.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoringWeights {
+    pub cyclomatic: u32,
+    pub cognitive: u32,
+    pub line_divisor: u32,
+    pub call_sites: u32,
+    pub churn: u32,
+    pub public_bonus: u32,
+    /// Max percent the base score is boosted by [`ComplexityMetrics::recency_score`] (a file
+    /// committed today contributes the full cap; one untouched for a month or more contributes
+    /// close to nothing — see [`ComplexityMetrics::importance_score_with`]).
+    pub recency_bonus_cap: u32,
+    /// Max percent the base score is boosted by [`ComplexityMetrics::distinct_authors`], scaled
+    /// linearly up to [`MAX_AUTHOR_SIGNAL`] distinct authors.
+    pub author_bonus_cap: u32,
+    pub error_threshold: u32,
+    pub warning_threshold: u32,
+    pub info_threshold: u32,
+}
+
+impl Default for ScoringWeights {
+    fn default() -> Self {
+        Self {
+            cyclomatic: 2,
+            cognitive: 2,
+            line_divisor: 10,
+            call_sites: 3,
+            churn: 2,
+            public_bonus: 10,
+            recency_bonus_cap: 30,
+            author_bonus_cap: 20,
+            error_threshold: 30,
+            warning_threshold: 15,
+            info_threshold: 5,
+        }
+    }
+}
+
+impl ScoringWeights {
+    /// Applies one `key=value` override (e.g. `"churn=5"`, `"error_threshold=40"`) on top of
+    /// `self`, the same repeatable-flag shape [`crate::extract::cfg::CfgSet::apply_arg`] uses for
+    /// `--cfg`. Unknown keys and unparsable values are ignored rather than erroring, since a
+    /// typo'd override shouldn't abort an otherwise-successful capture.
+    pub fn apply_override(&mut self, arg: &str) {
+        let Some((key, value)) = arg.split_once('=') else {
+            return;
+        };
+        let Ok(value) = value.trim().parse::<u32>() else {
+            return;
+        };
+
+        match key.trim() {
+            "cyclomatic" => self.cyclomatic = value,
+            "cognitive" => self.cognitive = value,
+            "line_divisor" => self.line_divisor = value,
+            "call_sites" => self.call_sites = value,
+            "churn" => self.churn = value,
+            "public_bonus" => self.public_bonus = value,
+            "recency_bonus_cap" => self.recency_bonus_cap = value,
+            "author_bonus_cap" => self.author_bonus_cap = value,
+            "error_threshold" => self.error_threshold = value,
+            "warning_threshold" => self.warning_threshold = value,
+            "info_threshold" => self.info_threshold = value,
+            _ => {}
+        }
+    }
+}
+
+/// Distinct-author count beyond which [`ComplexityMetrics::importance_score_with`]'s author
+/// bonus stops scaling up — a file with 5+ distinct contributors is "multi-author" regardless of
+/// whether it's 5 or 50, so the signal saturates instead of letting one ancient, widely-touched
+/// file dominate every other factor.
+pub const MAX_AUTHOR_SIGNAL: u32 = 5;
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ComplexityMetrics {
     pub cyclomatic: u32,
+    pub cognitive: u32,
     pub line_count: u32,
     pub nesting_depth: u32,
     pub call_sites: u32,
     pub churn_score: u32,
+    /// How recently this file's owning file was last committed, on a `0..=100` scale (100 =
+    /// committed today, decaying toward 0 over roughly a month) — see
+    /// [`crate::pipeline::apply_recency_and_author_scores`].
+    pub recency_score: u32,
+    /// Distinct commit authors who've touched this file's owning file within the churn window —
+    /// see [`crate::pipeline::apply_recency_and_author_scores`].
+    pub distinct_authors: u32,
     pub is_public: bool,
     pub is_test: bool,
 }
 
 impl ComplexityMetrics {
     pub fn importance_score(&self) -> u32 {
+        self.importance_score_with(&ScoringWeights::default())
+    }
+
+    /// Recently-touched, multi-author files are boosted above the static complexity/churn sum via
+    /// a bounded percent multiplier (capped by `recency_bonus_cap`/`author_bonus_cap`) rather than
+    /// an additive term, so an actively-evolving hotspot with otherwise modest complexity can still
+    /// outrank a large, untouched one, without the bonus ever being able to dominate the base score.
+    pub fn importance_score_with(&self, weights: &ScoringWeights) -> u32 {
         if self.is_test {
             return 0;
         }
-        (self.cyclomatic * 2)
-            + (self.line_count / 10)
-            + (self.call_sites * 3)
-            + (self.churn_score * 2)
-            + if self.is_public { 10 } else { 0 }
+        let base = (self.cyclomatic * weights.cyclomatic)
+            + (self.cognitive * weights.cognitive)
+            + (self.line_count / weights.line_divisor.max(1))
+            + (self.call_sites * weights.call_sites)
+            + (self.churn_score * weights.churn)
+            + if self.is_public { weights.public_bonus } else { 0 };
+
+        let recency_bonus = (self.recency_score.min(100) * weights.recency_bonus_cap) / 100;
+        let author_bonus = (self.distinct_authors.min(MAX_AUTHOR_SIGNAL) * weights.author_bonus_cap)
+            / MAX_AUTHOR_SIGNAL.max(1);
+
+        (base * (100 + recency_bonus + author_bonus)) / 100
     }
 
     pub fn tier(&self) -> ImportanceTier {
-        match self.importance_score() {
-            score if score >= 30 => ImportanceTier::High,
-            score if score >= 15 => ImportanceTier::Medium,
+        self.tier_with(&ScoringWeights::default())
+    }
+
+    pub fn tier_with(&self, weights: &ScoringWeights) -> ImportanceTier {
+        match self.importance_score_with(weights) {
+            score if score >= weights.error_threshold => ImportanceTier::High,
+            score if score >= weights.warning_threshold => ImportanceTier::Medium,
             _ => ImportanceTier::Low,
         }
     }
+
+    /// Maps this function's weighted score onto the LSP-inspired [`HotspotSeverity`] spectrum,
+    /// using the same `error_threshold`/`warning_threshold` cutoffs [`tier_with`](Self::tier_with)
+    /// does plus `info_threshold` for the `Info`/`Hint` split `ImportanceTier` doesn't distinguish.
+    pub fn severity_with(&self, weights: &ScoringWeights) -> HotspotSeverity {
+        let score = self.importance_score_with(weights);
+        if score >= weights.error_threshold {
+            HotspotSeverity::Error
+        } else if score >= weights.warning_threshold {
+            HotspotSeverity::Warning
+        } else if score >= weights.info_threshold {
+            HotspotSeverity::Info
+        } else {
+            HotspotSeverity::Hint
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,10 +29,20 @@ impl std::fmt::Display for ErrorReturnType {
     }
 }
 
+/// How a `?`-propagation's error message was attached, mirroring the eager/lazy distinction the
+/// `anyhow`/`chainerror` `Context` trait draws between `.context(c)` (computed unconditionally)
+/// and `.with_context(|| c)` (computed only once the error path is actually taken).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextAnnotation {
+    pub message: String,
+    pub lazy: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PropagationPoint {
     pub line: usize,
     pub expression: String,
+    pub context: Option<ContextAnnotation>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +52,13 @@ pub enum ErrorOriginKind {
     BailMacro,
     NoneReturn,
     CustomError,
+    /// Python `raise SomeError(...)` constructing and throwing a new exception.
+    RaiseStatement,
+    /// Python `raise` with no expression, re-throwing whatever exception is currently being
+    /// handled — only valid inside an `except` block.
+    BareReraise,
+    /// Python `assert cond, "msg"`, which raises `AssertionError` when `cond` is falsy.
+    AssertStatement,
 }
 
 impl std::fmt::Display for ErrorOriginKind {
@@ -52,6 +69,9 @@ impl std::fmt::Display for ErrorOriginKind {
             ErrorOriginKind::BailMacro => write!(f, "bail!()"),
             ErrorOriginKind::NoneReturn => write!(f, "None"),
             ErrorOriginKind::CustomError => write!(f, "error"),
+            ErrorOriginKind::RaiseStatement => write!(f, "raise"),
+            ErrorOriginKind::BareReraise => write!(f, "raise (bare)"),
+            ErrorOriginKind::AssertStatement => write!(f, "assert"),
         }
     }
 }
@@ -63,12 +83,46 @@ pub struct ErrorOrigin {
     pub message: Option<String>,
 }
 
+/// How a fallible result was discarded rather than propagated or matched, the counterpart to
+/// [`ErrorOriginKind`] for the "fallibility gets thrown away" side instead of the "error gets
+/// created" side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ErrorSinkKind {
+    Unwrap,
+    Expect,
+    UnwrapOrDiscard,
+    Discarded,
+}
+
+impl std::fmt::Display for ErrorSinkKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorSinkKind::Unwrap => write!(f, "unwrap()"),
+            ErrorSinkKind::Expect => write!(f, "expect()"),
+            ErrorSinkKind::UnwrapOrDiscard => write!(f, "unwrap_or*()"),
+            ErrorSinkKind::Discarded => write!(f, "let _ ="),
+        }
+    }
+}
+
+/// A point where a `Result`/`Option` was absorbed rather than propagated via `?` — `.unwrap()`,
+/// `.expect("msg")`, `.unwrap_or_default()`-style absorption, or `let _ = fallible()` — any of
+/// which can either panic the process or silently drop the error it carried.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorSink {
+    pub line: usize,
+    pub kind: ErrorSinkKind,
+    pub call_target: String,
+    pub message: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorInfo {
     pub function_id: FunctionId,
     pub return_type: ErrorReturnType,
     pub propagation_points: Vec<PropagationPoint>,
     pub error_origins: Vec<ErrorOrigin>,
+    pub error_sinks: Vec<ErrorSink>,
     pub line: usize,
 }
 
@@ -89,6 +143,7 @@ impl ErrorInfo {
             return_type,
             propagation_points: Vec::new(),
             error_origins: Vec::new(),
+            error_sinks: Vec::new(),
             line,
         }
     }
@@ -100,4 +155,8 @@ impl ErrorInfo {
     pub fn propagation_count(&self) -> usize {
         self.propagation_points.len()
     }
+
+    pub fn has_sinks(&self) -> bool {
+        !self.error_sinks.is_empty()
+    }
 }
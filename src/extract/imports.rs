@@ -6,6 +6,8 @@ pub struct ImportInfo {
     pub path: String,
     pub line: usize,
     pub kind: ImportKind,
+    /// The `feature = "..."` name gating this import's enclosing item, if any.
+    pub cfg_feature: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
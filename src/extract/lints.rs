@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+/// One mechanically-detected idiom or anti-pattern, found by walking the same tree-sitter
+/// tree `extract_from_tree` already produces. Opt-in (see `parse_rust_file_with_lints`) since,
+/// unlike the rest of `ParsedFile`, it's a judgment call rather than a plain fact about the
+/// source.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LintFinding {
+    pub kind: LintKind,
+    pub line: usize,
+    pub message: String,
+    pub snippet: String,
+    pub suggestion: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LintKind {
+    RedundantClosure,
+    NeedlessIterInForLoop,
+    IndexingCouldBeSlicing,
+    DeprecatedFormattingTrait,
+    IfLetElseCouldBeMatch,
+    ElseIfLetChainCouldBeMatch,
+    RedundantTrailingReturn,
+    MapIndexCouldUseGet,
+    ExplicitIndexCallCouldUseSlicing,
+}
+
+impl std::fmt::Display for LintKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LintKind::RedundantClosure => write!(f, "redundant-closure"),
+            LintKind::NeedlessIterInForLoop => write!(f, "needless-iter-in-for-loop"),
+            LintKind::IndexingCouldBeSlicing => write!(f, "indexing-could-be-slicing"),
+            LintKind::DeprecatedFormattingTrait => write!(f, "deprecated-formatting-trait"),
+            LintKind::IfLetElseCouldBeMatch => write!(f, "if-let-else-could-be-match"),
+            LintKind::ElseIfLetChainCouldBeMatch => write!(f, "else-if-let-chain-could-be-match"),
+            LintKind::RedundantTrailingReturn => write!(f, "redundant-trailing-return"),
+            LintKind::MapIndexCouldUseGet => write!(f, "map-index-could-use-get"),
+            LintKind::ExplicitIndexCallCouldUseSlicing => {
+                write!(f, "explicit-index-call-could-use-slicing")
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+/// One entry in a [`MigrationCatalog`]: a deprecated or renamed item, the path or identifier
+/// that names it, and what to use instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationRule {
+    pub matcher: String,
+    pub replacement: String,
+    pub note: String,
+}
+
+/// A set of migration rules to scan a file against. Ship with [`default_catalog`]'s
+/// built-in rules, or build a project-specific one with [`MigrationCatalog::new`] and
+/// [`MigrationCatalog::with_rule`] to track a team's own deprecations.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MigrationCatalog {
+    pub rules: Vec<MigrationRule>,
+}
+
+impl MigrationCatalog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rule(
+        mut self,
+        matcher: impl Into<String>,
+        replacement: impl Into<String>,
+        note: impl Into<String>,
+    ) -> Self {
+        self.rules.push(MigrationRule {
+            matcher: matcher.into(),
+            replacement: replacement.into(),
+            note: note.into(),
+        });
+        self
+    }
+
+    fn find(&self, text: &str) -> Option<&MigrationRule> {
+        self.rules.iter().find(|rule| rule.matcher == text)
+    }
+}
+
+/// The catalog charter ships out of the box: sigil-era and pre-1.0 standard-library items that
+/// no longer exist, plus a couple of idioms the ecosystem has since moved past. Callers can
+/// still supply their own [`MigrationCatalog`] to flag a crate's own deprecated APIs instead.
+pub fn default_catalog() -> MigrationCatalog {
+    MigrationCatalog::new()
+        .with_rule(
+            "std::gc::Gc",
+            "std::rc::Rc",
+            "the garbage-collected `Gc` pointer was removed before Rust 1.0; use reference counting instead",
+        )
+        .with_rule(
+            "std::fmt::Show",
+            "std::fmt::Display",
+            "`fmt::Show` was renamed to `fmt::Display` before Rust 1.0",
+        )
+        .with_rule(
+            "std::fmt::String",
+            "std::fmt::Display",
+            "`fmt::String` was folded into `fmt::Display` before Rust 1.0",
+        )
+        .with_rule(
+            "as_slice",
+            "&v[..]",
+            "`as_slice()` predates stable slicing syntax; prefer `&v[..]`",
+        )
+        .with_rule(
+            "as_mut_slice",
+            "&mut v[..]",
+            "`as_mut_slice()` predates stable slicing syntax; prefer `&mut v[..]`",
+        )
+}
+
+/// One usage flagged against a [`MigrationCatalog`] rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationFinding {
+    pub old: String,
+    pub suggested: String,
+    pub line: usize,
+    pub note: String,
+}
+
+/// Checks `text` (an `identifier`/`scoped_identifier`/`field_identifier` node's text, which
+/// also covers `use` paths since those are parsed out of the same node kinds) against
+/// `catalog`, returning the finding to record at `line` if it matches.
+pub(crate) fn match_migration(
+    catalog: &MigrationCatalog,
+    text: &str,
+    line: usize,
+) -> Option<MigrationFinding> {
+    catalog.find(text).map(|rule| MigrationFinding {
+        old: text.to_string(),
+        suggested: rule.replacement.clone(),
+        line,
+        note: rule.note.clone(),
+    })
+}
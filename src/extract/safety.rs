@@ -8,46 +8,159 @@ pub struct SafetyInfo {
     pub unsafe_impls: Vec<UnsafeImpl>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct UnsafeBlock {
     pub line: usize,
     pub containing_function: Option<String>,
     pub operations: Vec<UnsafeOperation>,
+    /// The `// SAFETY: ...` / `// Safety: ...` rationale immediately preceding the `unsafe`
+    /// token, if one is present, with the comment markers and prefix stripped.
+    pub safety_comment: Option<String>,
+    /// Set when this block performs a real [`UnsafeOperation`] but has no adjacent
+    /// `safety_comment` — the convention the standard library enforces on its own unsafe code.
+    pub unjustified: bool,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Whether a reference or raw pointer grants write access to its referent, mirroring
+/// stable-MIR's own `Mutability` so [`TypeRef`] slots into the same shape a real
+/// `RigidTy::RawPtr`/`RigidTy::Ref` would carry it in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Mutability {
+    Not,
+    Mut,
+}
+
+impl std::fmt::Display for Mutability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Mutability::Not => write!(f, "const"),
+            Mutability::Mut => write!(f, "mut"),
+        }
+    }
+}
+
+/// A structured, best-effort description of a type touched by an [`UnsafeOperation`], echoing
+/// the shapes stable-MIR's `RigidTy` distinguishes rather than carrying free-text. Built from
+/// source syntax alone (no type checker), so `Named` is the fallback for anything this module
+/// can't otherwise resolve.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TypeRef {
+    RawPtr(Box<TypeRef>, Mutability),
+    Ref {
+        region: Option<String>,
+        mutability: Mutability,
+        referent: Box<TypeRef>,
+    },
+    Array {
+        element: Box<TypeRef>,
+        len: Option<usize>,
+    },
+    Primitive(String),
+    Named {
+        path: String,
+    },
+}
+
+impl std::fmt::Display for TypeRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TypeRef::RawPtr(inner, mutability) => write!(f, "*{} {}", mutability, inner),
+            TypeRef::Ref {
+                region,
+                mutability,
+                referent,
+            } => {
+                write!(f, "&")?;
+                if let Some(region) = region {
+                    write!(f, "'{} ", region)?;
+                }
+                if *mutability == Mutability::Mut {
+                    write!(f, "mut ")?;
+                }
+                write!(f, "{}", referent)
+            }
+            TypeRef::Array { element, len: Some(len) } => write!(f, "[{}; {}]", element, len),
+            TypeRef::Array { element, len: None } => write!(f, "[{}]", element),
+            TypeRef::Primitive(name) | TypeRef::Named { path: name } => write!(f, "{}", name),
+        }
+    }
+}
+
+/// The ABI and structured parameter/return types of an unsafe function call, shared by
+/// [`UnsafeOperation::UnsafeFunctionCall`] and [`UnsafeOperation::ExternCall`]. Left at their
+/// defaults (`abi: None`, `args`/`return_type` empty or unresolved) when the call's declaration
+/// can't be found in the same file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UnsafeCallSignature {
+    pub abi: Option<String>,
+    pub args: Vec<TypeRef>,
+    pub return_type: Option<TypeRef>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum UnsafeOperation {
-    RawPointerDeref,
-    UnsafeFunctionCall(String),
-    MutableStaticAccess(String),
+    RawPointerDeref {
+        pointee: TypeRef,
+        mutability: Mutability,
+    },
+    UnsafeFunctionCall(String, UnsafeCallSignature),
+    MutableStaticAccess(String, TypeRef),
     UnionFieldAccess,
     InlineAssembly,
-    ExternCall(String),
+    ExternCall(String, UnsafeCallSignature),
     Other(String),
 }
 
 impl std::fmt::Display for UnsafeOperation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            UnsafeOperation::RawPointerDeref => write!(f, "raw pointer deref"),
-            UnsafeOperation::UnsafeFunctionCall(name) => write!(f, "unsafe call: {}", name),
-            UnsafeOperation::MutableStaticAccess(name) => write!(f, "mutable static: {}", name),
+            UnsafeOperation::RawPointerDeref { pointee, mutability } => {
+                write!(f, "raw pointer deref: *{} {}", mutability, pointee)
+            }
+            UnsafeOperation::UnsafeFunctionCall(name, sig) => {
+                write!(f, "unsafe call: {}{}", name, format_signature(sig))
+            }
+            UnsafeOperation::MutableStaticAccess(name, type_ref) => {
+                write!(f, "mutable static: {}: {}", name, type_ref)
+            }
             UnsafeOperation::UnionFieldAccess => write!(f, "union field access"),
             UnsafeOperation::InlineAssembly => write!(f, "inline assembly"),
-            UnsafeOperation::ExternCall(name) => write!(f, "extern call: {}", name),
+            UnsafeOperation::ExternCall(name, sig) => {
+                write!(f, "extern call: {}{}", name, format_signature(sig))
+            }
             UnsafeOperation::Other(desc) => write!(f, "{}", desc),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+fn format_signature(sig: &UnsafeCallSignature) -> String {
+    let abi = sig
+        .abi
+        .as_ref()
+        .map(|abi| format!(" extern \"{}\"", abi))
+        .unwrap_or_default();
+    let args = sig
+        .args
+        .iter()
+        .map(|t| t.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let ret = sig
+        .return_type
+        .as_ref()
+        .map(|t| format!(" -> {}", t))
+        .unwrap_or_default();
+    format!("{} ({}){}", abi, args, ret)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct UnsafeImpl {
     pub trait_name: String,
     pub type_name: String,
     pub line: usize,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PanicPoint {
     pub line: usize,
     pub kind: PanicKind,
@@ -55,18 +168,41 @@ pub struct PanicPoint {
     pub context: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PanicKind {
     Unwrap,
     Expect(String),
-    PanicMacro,
-    UnreachableMacro,
-    TodoMacro,
-    UnimplementedMacro,
-    Assert,
+    PanicMacro(Option<String>),
+    UnreachableMacro(Option<String>),
+    TodoMacro(Option<String>),
+    UnimplementedMacro(Option<String>),
+    Assert(Option<String>),
     IndexAccess,
     RaiseException(String),
     AssertFalse,
+    /// An index expression whose base has a statically known length and
+    /// whose index is a literal (or simple const) provably `>= len`.
+    DefiniteOutOfBounds {
+        index: usize,
+        len: usize,
+    },
+}
+
+impl PanicKind {
+    /// The literal reason string attached to this panic point, if any.
+    /// `None` means the panic is "unexplained" — a bare `.unwrap()`,
+    /// `panic!()`, `unimplemented!()`, etc. with no documented cause.
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            PanicKind::Expect(msg) => Some(msg.as_str()),
+            PanicKind::PanicMacro(Some(msg))
+            | PanicKind::UnreachableMacro(Some(msg))
+            | PanicKind::TodoMacro(Some(msg))
+            | PanicKind::UnimplementedMacro(Some(msg))
+            | PanicKind::Assert(Some(msg)) => Some(msg.as_str()),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for PanicKind {
@@ -74,18 +210,32 @@ impl std::fmt::Display for PanicKind {
         match self {
             PanicKind::Unwrap => write!(f, ".unwrap()"),
             PanicKind::Expect(msg) => write!(f, ".expect(\"{}\")", msg),
-            PanicKind::PanicMacro => write!(f, "panic!()"),
-            PanicKind::UnreachableMacro => write!(f, "unreachable!()"),
-            PanicKind::TodoMacro => write!(f, "todo!()"),
-            PanicKind::UnimplementedMacro => write!(f, "unimplemented!()"),
-            PanicKind::Assert => write!(f, "assert!()"),
+            PanicKind::PanicMacro(msg) => write_macro_call(f, "panic!", msg),
+            PanicKind::UnreachableMacro(msg) => write_macro_call(f, "unreachable!", msg),
+            PanicKind::TodoMacro(msg) => write_macro_call(f, "todo!", msg),
+            PanicKind::UnimplementedMacro(msg) => write_macro_call(f, "unimplemented!", msg),
+            PanicKind::Assert(msg) => write_macro_call(f, "assert!", msg),
             PanicKind::IndexAccess => write!(f, "index access"),
             PanicKind::RaiseException(exc) => write!(f, "raise {}", exc),
             PanicKind::AssertFalse => write!(f, "assert False"),
+            PanicKind::DefiniteOutOfBounds { index, len } => {
+                write!(f, "definite out-of-bounds: index {} >= len {}", index, len)
+            }
         }
     }
 }
 
+fn write_macro_call(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    msg: &Option<String>,
+) -> std::fmt::Result {
+    match msg {
+        Some(msg) => write!(f, "{}(\"{}\")", name, msg),
+        None => write!(f, "{}()", name),
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LifetimeInfo {
     pub function_lifetimes: Vec<FunctionLifetime>,
@@ -160,7 +310,7 @@ pub enum SpawnType {
     Other(String),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct BlockingCall {
     pub line: usize,
     pub call: String,
@@ -168,10 +318,116 @@ pub struct BlockingCall {
     pub containing_function: Option<String>,
 }
 
+/// A synchronous lock guard (`.lock()`/`.read()`/`.write()`/`.borrow_mut()`) still bound to a
+/// name at the point an `.await` is reached in the same or a nested block — the classic Tokio
+/// deadlock/`!Send` future footgun, since the guard is held across a suspension point instead of
+/// being dropped before yielding.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardHeldAcrossAwait {
+    pub guard_line: usize,
+    pub guard_expr: String,
+    pub await_line: usize,
+    pub containing_function: String,
+}
+
+/// How badly a blocking call stalls the executor when reached from an
+/// async context: I/O and network calls starve every colocated task for as
+/// long as the syscall takes, sleeps are a known fixed delay, and lock
+/// contention is bounded by however long the holder keeps the lock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlockingSeverity {
+    High,
+    Medium,
+    Low,
+}
+
+impl std::fmt::Display for BlockingSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlockingSeverity::High => write!(f, "high"),
+            BlockingSeverity::Medium => write!(f, "medium"),
+            BlockingSeverity::Low => write!(f, "low"),
+        }
+    }
+}
+
+/// Known blocking std/ecosystem call patterns mapped to their async
+/// replacement and severity. Doubles as the detection table for
+/// `extract_blocking_calls`, so adding a new blocking API here both
+/// flags it and suggests the fix. Checked in order; the first matching
+/// pattern wins.
+pub const BLOCKING_CALL_REMEDIATIONS: &[(&str, &str, BlockingSeverity)] = &[
+    (
+        "reqwest::blocking",
+        "async reqwest::Client",
+        BlockingSeverity::High,
+    ),
+    (
+        "std::thread::sleep",
+        "tokio::time::sleep",
+        BlockingSeverity::Medium,
+    ),
+    (
+        "thread::sleep",
+        "tokio::time::sleep",
+        BlockingSeverity::Medium,
+    ),
+    ("std::fs::", "tokio::fs", BlockingSeverity::High),
+    ("std::io::", "tokio::io", BlockingSeverity::High),
+    ("std::net::", "tokio::net", BlockingSeverity::High),
+    ("TcpStream", "tokio::net::TcpStream", BlockingSeverity::High),
+    (
+        "File::open",
+        "tokio::fs::File::open",
+        BlockingSeverity::High,
+    ),
+    (
+        "File::create",
+        "tokio::fs::File::create",
+        BlockingSeverity::High,
+    ),
+    (
+        ".read_to_string",
+        "tokio::io::AsyncReadExt::read_to_string",
+        BlockingSeverity::High,
+    ),
+    (
+        ".read_to_end",
+        "tokio::io::AsyncReadExt::read_to_end",
+        BlockingSeverity::High,
+    ),
+    (
+        ".read(",
+        "tokio::io::AsyncReadExt::read",
+        BlockingSeverity::High,
+    ),
+    (
+        ".write(",
+        "tokio::io::AsyncWriteExt::write",
+        BlockingSeverity::High,
+    ),
+    (
+        "Mutex::lock",
+        "tokio::sync::Mutex::lock",
+        BlockingSeverity::Low,
+    ),
+];
+
+/// Looks up the async replacement and severity for a blocking call's source
+/// text, if it matches a known pattern.
+pub fn classify_blocking_call(call_text: &str) -> Option<(&'static str, BlockingSeverity)> {
+    BLOCKING_CALL_REMEDIATIONS
+        .iter()
+        .find(|(pattern, _, _)| call_text.contains(pattern))
+        .map(|(_, suggestion, severity)| (*suggestion, *severity))
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FeatureFlagInfo {
     pub feature_gates: Vec<FeatureGate>,
     pub cfg_blocks: Vec<CfgBlock>,
+    pub no_std: bool,
+    pub uses_alloc_extern_crate: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -185,6 +441,25 @@ pub struct GatedSymbol {
     pub name: String,
     pub kind: String,
     pub line: usize,
+    pub environment: Environment,
+}
+
+/// Minimum runtime environment a symbol needs, from least to most capable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Environment {
+    Core,
+    Alloc,
+    Std,
+}
+
+impl std::fmt::Display for Environment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Environment::Core => write!(f, "core"),
+            Environment::Alloc => write!(f, "alloc"),
+            Environment::Std => write!(f, "std"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -209,6 +484,53 @@ pub struct ItemDoc {
     pub has_panics_section: bool,
     pub has_safety_section: bool,
     pub has_errors_section: bool,
+    /// Every fenced code block found in this item's doc comment, parsed the way rustdoc would
+    /// turn it into a runnable test.
+    pub doc_tests: Vec<DocTest>,
+    /// Every intra-doc or markdown link found in this item's doc comment — see [`DocLink`].
+    pub doc_links: Vec<DocLink>,
+    /// This item's stable rustdoc JSON `Id`, filled in by
+    /// [`crate::rustdoc_json::enrich_doc_info`] when a matching item is found in an ingested
+    /// `cargo rustdoc --output-format json` document. `None` until that enrichment pass runs.
+    pub rustdoc_id: Option<String>,
+    /// This item's fully-qualified `::`-joined module path, as resolved by rustdoc rather than
+    /// guessed from source structure. `None` until [`crate::rustdoc_json::enrich_doc_info`] runs.
+    pub qualified_path: Option<String>,
+}
+
+/// One link found in an item's doc comment, whether an intra-doc reference (`` [`Type`] ``,
+/// `[text][Type]`, `` [`module::Item`] ``) or a plain markdown `[text](url)` link. `resolved` is
+/// only meaningful for non-external links, and is filled in by a whole-file pass (see
+/// `crate::pipeline::parse`) that checks `target_path` against every item name the file declares
+/// — it starts `false` on every link this struct's producer emits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocLink {
+    pub target_path: String,
+    pub display_text: String,
+    pub is_external: bool,
+    pub line: usize,
+    pub resolved: bool,
+    /// The fully-qualified path this link's target resolves to in rustdoc's own cross-crate item
+    /// graph, filled in by [`crate::rustdoc_json::enrich_doc_info`]. Distinct from `resolved`,
+    /// which only reflects the cheaper same-file name match `resolve_doc_links` performs.
+    pub resolved_target: Option<String>,
+}
+
+/// One fenced code block (`` ``` `` or `~~~`) parsed out of an item's doc comment, with the
+/// standard rustdoc fence attributes classified and the body split into the form a doctest
+/// runner would actually compile (`full_body`, hidden `# ` setup lines included) versus the form
+/// worth showing a reader (`visible_body`, hidden lines dropped).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocTest {
+    pub line: usize,
+    pub info_string: String,
+    pub ignore: bool,
+    pub no_run: bool,
+    pub should_panic: bool,
+    pub compile_fail: bool,
+    pub edition: Option<String>,
+    pub visible_body: String,
+    pub full_body: String,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -246,6 +568,9 @@ pub struct TestFunction {
     pub is_ignored: bool,
     pub should_panic: bool,
     pub tested_function: Option<String>,
+    /// The callee name of every `call_expression`/`method_call_expression` in this test's body —
+    /// the real evidence `infer_tested_items` prefers over `tested_function`'s name guess.
+    pub called_functions: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -276,7 +601,7 @@ pub struct PythonDangerousCall {
     pub risk_level: RiskLevel,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RiskLevel {
     High,
     Medium,
@@ -292,3 +617,47 @@ impl std::fmt::Display for RiskLevel {
         }
     }
 }
+
+/// One mechanical idiom/anti-pattern finding from a structural (node-kind/field-name) scan of a
+/// Python body, the non-security counterpart to [`PythonDangerousCall`] — see
+/// [`crate::pipeline::parse::python::collect_python_lints`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PythonLint {
+    pub line: usize,
+    pub category: PythonLintCategory,
+    pub severity: RiskLevel,
+    pub message: String,
+    pub suggestion: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PythonLintCategory {
+    /// `for i in range(len(x)):` where iterating `x` directly (or with `enumerate`) would do.
+    RangeLenIteration,
+    /// `for k in d.keys():` followed by indexing the same dict with `k` inside the loop body.
+    DictKeysIndexing,
+    /// `== None` / `!= None` instead of the identity comparison `is None` / `is not None`.
+    NoneEquality,
+    /// A `list`/`dict`/`set` literal (or constructor call) used as a parameter default, which is
+    /// evaluated once at `def` time and shared across every call that doesn't override it.
+    MutableDefaultArgument,
+    /// A bare `except:` with no exception type, which also swallows `KeyboardInterrupt` and
+    /// `SystemExit`.
+    BareExcept,
+    /// `list([...])`/`set([...])`/`dict([...])` wrapping an expression that's already the
+    /// collection type being constructed.
+    RedundantCollectionCall,
+}
+
+impl std::fmt::Display for PythonLintCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PythonLintCategory::RangeLenIteration => write!(f, "range-len-iteration"),
+            PythonLintCategory::DictKeysIndexing => write!(f, "dict-keys-indexing"),
+            PythonLintCategory::NoneEquality => write!(f, "none-equality"),
+            PythonLintCategory::MutableDefaultArgument => write!(f, "mutable-default-argument"),
+            PythonLintCategory::BareExcept => write!(f, "bare-except"),
+            PythonLintCategory::RedundantCollectionCall => write!(f, "redundant-collection-call"),
+        }
+    }
+}
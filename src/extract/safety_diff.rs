@@ -0,0 +1,349 @@
+use std::collections::HashMap;
+
+use crate::export::{FileSafetyFacts, SafetyDocument};
+use crate::extract::safety::{BlockingCall, ItemDoc, PanicKind, PanicPoint, UnsafeBlock, UnsafeImpl};
+
+/// Whether a finding present in only one snapshot was newly introduced or newly absent, or
+/// present in both but at a different line — the status [`diff_unsafe_blocks`] and friends assign
+/// once a finding is looked up by its line-independent identity rather than by line number, so an
+/// unrelated edit shifting everything below it in the file doesn't read as added-then-removed
+/// churn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SafetyChangeStatus {
+    Added,
+    Removed,
+    Moved,
+}
+
+/// One finding that changed between two [`SafetyDocument`] snapshots of the same file.
+#[derive(Debug, Clone)]
+pub struct SafetyChange<T> {
+    pub file: String,
+    pub status: SafetyChangeStatus,
+    pub finding: T,
+    /// The line this finding was previously found at. Only set when `status` is
+    /// [`SafetyChangeStatus::Moved`].
+    pub previous_line: Option<usize>,
+}
+
+/// An item whose doc comment lost its `# Panics` or `# Safety` section between snapshots —
+/// reported separately from the other finding kinds since it's a regression in *documentation*
+/// coverage rather than a new or removed unsafe/panicking operation.
+#[derive(Debug, Clone)]
+pub struct DocSectionRegression {
+    pub file: String,
+    pub item_name: String,
+    pub line: usize,
+}
+
+/// Every change [`diff_safety_documents`] found between a baseline and current
+/// [`SafetyDocument`], grouped by finding kind so a CI gate can fail on, say, new unsafe blocks
+/// while tolerating moved panic points.
+#[derive(Debug, Clone, Default)]
+pub struct SafetyChangeset {
+    pub unsafe_blocks: Vec<SafetyChange<UnsafeBlock>>,
+    pub panic_points: Vec<SafetyChange<PanicPoint>>,
+    pub blocking_calls: Vec<SafetyChange<BlockingCall>>,
+    pub unsafe_impls: Vec<SafetyChange<UnsafeImpl>>,
+    pub lost_panics_sections: Vec<DocSectionRegression>,
+    pub lost_safety_sections: Vec<DocSectionRegression>,
+}
+
+impl SafetyChangeset {
+    /// `true` when every finding category and doc-section regression list is empty — the
+    /// all-clear a CI gate checks before failing the build.
+    pub fn is_empty(&self) -> bool {
+        self.unsafe_blocks.is_empty()
+            && self.panic_points.is_empty()
+            && self.blocking_calls.is_empty()
+            && self.unsafe_impls.is_empty()
+            && self.lost_panics_sections.is_empty()
+            && self.lost_safety_sections.is_empty()
+    }
+}
+
+fn unsafe_block_key(block: &UnsafeBlock) -> (Option<String>, Vec<String>) {
+    let mut operations: Vec<String> = block.operations.iter().map(|op| op.to_string()).collect();
+    operations.sort();
+    (block.containing_function.clone(), operations)
+}
+
+fn panic_point_key(point: &PanicPoint) -> (PanicKind, Option<String>, Option<String>) {
+    (
+        point.kind.clone(),
+        point.containing_function.clone(),
+        point.context.clone(),
+    )
+}
+
+fn blocking_call_key(call: &BlockingCall) -> (String, Option<String>, bool) {
+    (
+        call.call.clone(),
+        call.containing_function.clone(),
+        call.in_async_context,
+    )
+}
+
+fn unsafe_impl_key(imp: &UnsafeImpl) -> (String, String) {
+    (imp.trait_name.clone(), imp.type_name.clone())
+}
+
+/// Diffs one file's `UnsafeBlock`s by [`unsafe_block_key`] — `(containing_function, sorted
+/// operations)` — so reordering operations within a block, or a block's line shifting because of
+/// an unrelated edit above it, doesn't register as a removal-plus-addition.
+fn diff_unsafe_blocks(file: &str, old: &[UnsafeBlock], new: &[UnsafeBlock]) -> Vec<SafetyChange<UnsafeBlock>> {
+    let old_by_key: HashMap<_, &UnsafeBlock> =
+        old.iter().map(|b| (unsafe_block_key(b), b)).collect();
+    let new_by_key: HashMap<_, &UnsafeBlock> =
+        new.iter().map(|b| (unsafe_block_key(b), b)).collect();
+
+    let mut changes = Vec::new();
+    for (key, block) in &new_by_key {
+        match old_by_key.get(key) {
+            None => changes.push(SafetyChange {
+                file: file.to_string(),
+                status: SafetyChangeStatus::Added,
+                finding: (*block).clone(),
+                previous_line: None,
+            }),
+            Some(old_block) if old_block.line != block.line => changes.push(SafetyChange {
+                file: file.to_string(),
+                status: SafetyChangeStatus::Moved,
+                finding: (*block).clone(),
+                previous_line: Some(old_block.line),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (key, block) in &old_by_key {
+        if !new_by_key.contains_key(key) {
+            changes.push(SafetyChange {
+                file: file.to_string(),
+                status: SafetyChangeStatus::Removed,
+                finding: (*block).clone(),
+                previous_line: None,
+            });
+        }
+    }
+    changes
+}
+
+/// Diffs one file's `PanicPoint`s by [`panic_point_key`] — `(kind, containing_function,
+/// context)`.
+fn diff_panic_points(file: &str, old: &[PanicPoint], new: &[PanicPoint]) -> Vec<SafetyChange<PanicPoint>> {
+    let old_by_key: HashMap<_, &PanicPoint> =
+        old.iter().map(|p| (panic_point_key(p), p)).collect();
+    let new_by_key: HashMap<_, &PanicPoint> =
+        new.iter().map(|p| (panic_point_key(p), p)).collect();
+
+    let mut changes = Vec::new();
+    for (key, point) in &new_by_key {
+        match old_by_key.get(key) {
+            None => changes.push(SafetyChange {
+                file: file.to_string(),
+                status: SafetyChangeStatus::Added,
+                finding: (*point).clone(),
+                previous_line: None,
+            }),
+            Some(old_point) if old_point.line != point.line => changes.push(SafetyChange {
+                file: file.to_string(),
+                status: SafetyChangeStatus::Moved,
+                finding: (*point).clone(),
+                previous_line: Some(old_point.line),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (key, point) in &old_by_key {
+        if !new_by_key.contains_key(key) {
+            changes.push(SafetyChange {
+                file: file.to_string(),
+                status: SafetyChangeStatus::Removed,
+                finding: (*point).clone(),
+                previous_line: None,
+            });
+        }
+    }
+    changes
+}
+
+/// Diffs one file's `BlockingCall`s by [`blocking_call_key`] — `(call, containing_function,
+/// in_async_context)`.
+fn diff_blocking_calls(file: &str, old: &[BlockingCall], new: &[BlockingCall]) -> Vec<SafetyChange<BlockingCall>> {
+    let old_by_key: HashMap<_, &BlockingCall> =
+        old.iter().map(|c| (blocking_call_key(c), c)).collect();
+    let new_by_key: HashMap<_, &BlockingCall> =
+        new.iter().map(|c| (blocking_call_key(c), c)).collect();
+
+    let mut changes = Vec::new();
+    for (key, call) in &new_by_key {
+        match old_by_key.get(key) {
+            None => changes.push(SafetyChange {
+                file: file.to_string(),
+                status: SafetyChangeStatus::Added,
+                finding: (*call).clone(),
+                previous_line: None,
+            }),
+            Some(old_call) if old_call.line != call.line => changes.push(SafetyChange {
+                file: file.to_string(),
+                status: SafetyChangeStatus::Moved,
+                finding: (*call).clone(),
+                previous_line: Some(old_call.line),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (key, call) in &old_by_key {
+        if !new_by_key.contains_key(key) {
+            changes.push(SafetyChange {
+                file: file.to_string(),
+                status: SafetyChangeStatus::Removed,
+                finding: (*call).clone(),
+                previous_line: None,
+            });
+        }
+    }
+    changes
+}
+
+/// Diffs one file's `UnsafeImpl`s by [`unsafe_impl_key`] — `(trait_name, type_name)`.
+fn diff_unsafe_impls(file: &str, old: &[UnsafeImpl], new: &[UnsafeImpl]) -> Vec<SafetyChange<UnsafeImpl>> {
+    let old_by_key: HashMap<_, &UnsafeImpl> =
+        old.iter().map(|i| (unsafe_impl_key(i), i)).collect();
+    let new_by_key: HashMap<_, &UnsafeImpl> =
+        new.iter().map(|i| (unsafe_impl_key(i), i)).collect();
+
+    let mut changes = Vec::new();
+    for (key, imp) in &new_by_key {
+        match old_by_key.get(key) {
+            None => changes.push(SafetyChange {
+                file: file.to_string(),
+                status: SafetyChangeStatus::Added,
+                finding: (*imp).clone(),
+                previous_line: None,
+            }),
+            Some(old_imp) if old_imp.line != imp.line => changes.push(SafetyChange {
+                file: file.to_string(),
+                status: SafetyChangeStatus::Moved,
+                finding: (*imp).clone(),
+                previous_line: Some(old_imp.line),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (key, imp) in &old_by_key {
+        if !new_by_key.contains_key(key) {
+            changes.push(SafetyChange {
+                file: file.to_string(),
+                status: SafetyChangeStatus::Removed,
+                finding: (*imp).clone(),
+                previous_line: None,
+            });
+        }
+    }
+    changes
+}
+
+/// Finds every item in `new` whose `has_panics_section`/`has_safety_section` flag was `true` in
+/// `old` and is `false` now, keyed by `item_name` rather than `line` for the same reason as the
+/// finding diffs above.
+fn diff_doc_section_regressions(file: &str, old: &[ItemDoc], new: &[ItemDoc]) -> (Vec<DocSectionRegression>, Vec<DocSectionRegression>) {
+    let old_by_name: HashMap<&str, &ItemDoc> =
+        old.iter().map(|item| (item.item_name.as_str(), item)).collect();
+
+    let mut lost_panics = Vec::new();
+    let mut lost_safety = Vec::new();
+    for item in new {
+        let Some(old_item) = old_by_name.get(item.item_name.as_str()) else {
+            continue;
+        };
+        if old_item.has_panics_section && !item.has_panics_section {
+            lost_panics.push(DocSectionRegression {
+                file: file.to_string(),
+                item_name: item.item_name.clone(),
+                line: item.line,
+            });
+        }
+        if old_item.has_safety_section && !item.has_safety_section {
+            lost_safety.push(DocSectionRegression {
+                file: file.to_string(),
+                item_name: item.item_name.clone(),
+                line: item.line,
+            });
+        }
+    }
+    (lost_panics, lost_safety)
+}
+
+fn facts_by_file(doc: &SafetyDocument) -> HashMap<&str, &FileSafetyFacts> {
+    doc.files
+        .iter()
+        .map(|facts| (facts.file.as_str(), facts))
+        .collect()
+}
+
+/// Compares a baseline and current [`SafetyDocument`] snapshot (e.g. a PR's target branch vs. its
+/// head) and reports every unsafe block, panic point, blocking call, and `unsafe impl` that was
+/// added, removed, or moved, plus every item whose doc comment lost its `# Safety`/`# Panics`
+/// section, file by file. A file present in only
+/// one snapshot has all of its findings reported as wholly added or wholly removed.
+pub fn diff_safety_documents(baseline: &SafetyDocument, current: &SafetyDocument) -> SafetyChangeset {
+    let baseline_by_file = facts_by_file(baseline);
+    let current_by_file = facts_by_file(current);
+
+    let mut changeset = SafetyChangeset::default();
+    let mut files: Vec<&str> = baseline_by_file
+        .keys()
+        .chain(current_by_file.keys())
+        .copied()
+        .collect();
+    files.sort_unstable();
+    files.dedup();
+
+    for file in files {
+        let empty = FileSafetyFacts {
+            file: file.to_string(),
+            safety: Default::default(),
+            lifetimes: Default::default(),
+            async_info: Default::default(),
+            feature_flags: Default::default(),
+            doc_info: Default::default(),
+            generic_constraints: Default::default(),
+            test_info: Default::default(),
+            python_safety: Default::default(),
+        };
+        let old_facts = baseline_by_file.get(file).copied().unwrap_or(&empty);
+        let new_facts = current_by_file.get(file).copied().unwrap_or(&empty);
+
+        changeset.unsafe_blocks.extend(diff_unsafe_blocks(
+            file,
+            &old_facts.safety.unsafe_blocks,
+            &new_facts.safety.unsafe_blocks,
+        ));
+        changeset.panic_points.extend(diff_panic_points(
+            file,
+            &old_facts.safety.panic_points,
+            &new_facts.safety.panic_points,
+        ));
+        changeset.blocking_calls.extend(diff_blocking_calls(
+            file,
+            &old_facts.async_info.blocking_calls,
+            &new_facts.async_info.blocking_calls,
+        ));
+        changeset.unsafe_impls.extend(diff_unsafe_impls(
+            file,
+            &old_facts.safety.unsafe_impls,
+            &new_facts.safety.unsafe_impls,
+        ));
+
+        let (lost_panics, lost_safety) = diff_doc_section_regressions(
+            file,
+            &old_facts.doc_info.item_docs,
+            &new_facts.doc_info.item_docs,
+        );
+        changeset.lost_panics_sections.extend(lost_panics);
+        changeset.lost_safety_sections.extend(lost_safety);
+    }
+
+    changeset
+}
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+/// A name bound somewhere in a scope tree — a parameter, an assignment target, a `for`/`with`/
+/// `except ... as` target, a comprehension loop variable, or an imported name — produced by
+/// [`crate::pipeline::parse::python::resolve_scopes`]'s def-use walk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Binding {
+    pub name: String,
+    pub line: usize,
+    pub kind: BindingKind,
+    pub scope: ScopeKind,
+    /// Whether anything in this scope or a nested one loads this binding. `false` (for a name
+    /// other than `_` or a dunder) flags it as an unused local.
+    pub used: bool,
+    /// Whether an *enclosing* (non-class) scope already bound this name at the point this
+    /// binding was introduced.
+    pub shadows_outer: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BindingKind {
+    Parameter,
+    Assignment,
+    ForTarget,
+    WithTarget,
+    ExceptTarget,
+    ComprehensionTarget,
+    Import,
+    /// A name `global x` re-points into the module frame — created there the first time it's
+    /// declared if no module-level assignment already bound it.
+    Global,
+    /// A name `nonlocal x` re-points into the nearest enclosing function/lambda frame — created
+    /// there the first time it's declared if that frame hadn't already bound it.
+    Nonlocal,
+}
+
+impl std::fmt::Display for BindingKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindingKind::Parameter => write!(f, "parameter"),
+            BindingKind::Assignment => write!(f, "assignment"),
+            BindingKind::ForTarget => write!(f, "for-target"),
+            BindingKind::WithTarget => write!(f, "with-target"),
+            BindingKind::ExceptTarget => write!(f, "except-target"),
+            BindingKind::ComprehensionTarget => write!(f, "comprehension-target"),
+            BindingKind::Import => write!(f, "import"),
+            BindingKind::Global => write!(f, "global"),
+            BindingKind::Nonlocal => write!(f, "nonlocal"),
+        }
+    }
+}
+
+/// What kind of frame a [`Binding`] or a [`NameReference`]'s target belongs to — a module's
+/// top-level frame, or one of the function/lambda/class/comprehension frames nested inside it.
+/// Mirrors Python's own scoping rule that a `Class` frame is visible to its own body but not to
+/// anything lexically nested inside it (a method doesn't see its class's other attributes by
+/// name alone).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScopeKind {
+    Module,
+    Function,
+    Lambda,
+    Class,
+    Comprehension,
+}
+
+impl std::fmt::Display for ScopeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScopeKind::Module => write!(f, "module"),
+            ScopeKind::Function => write!(f, "function"),
+            ScopeKind::Lambda => write!(f, "lambda"),
+            ScopeKind::Class => write!(f, "class"),
+            ScopeKind::Comprehension => write!(f, "comprehension"),
+        }
+    }
+}
+
+/// One identifier *load* (a use, not a binding) and where it resolved, produced alongside
+/// [`Binding`] by the same walk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NameReference {
+    pub name: String,
+    pub line: usize,
+    pub resolution: Resolution,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Resolution {
+    /// Resolved to a [`Binding`] in the same frame this load appears in.
+    Local,
+    /// Resolved to a [`Binding`] in an enclosing frame. When that frame is a function or lambda
+    /// (not the module), this load is a free variable captured by a closure.
+    Enclosing,
+    /// No frame in scope binds this name — either a builtin or a name this file never defines.
+    BuiltinOrFree,
+}
+
+impl std::fmt::Display for Resolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Resolution::Local => write!(f, "local"),
+            Resolution::Enclosing => write!(f, "enclosing"),
+            Resolution::BuiltinOrFree => write!(f, "builtin-or-free"),
+        }
+    }
+}
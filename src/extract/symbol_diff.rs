@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+
+use crate::extract::symbols::{FileSymbols, SymbolKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+impl SymbolChangeKind {
+    pub fn marker(self) -> &'static str {
+        match self {
+            SymbolChangeKind::Added => "[+] ",
+            SymbolChangeKind::Removed => "[-] ",
+            SymbolChangeKind::Modified => "[~] ",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SymbolChangeKind::Added => "added",
+            SymbolChangeKind::Removed => "removed",
+            SymbolChangeKind::Modified => "changed",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SymbolChange {
+    pub name: String,
+    pub kind_label: &'static str,
+    pub change: SymbolChangeKind,
+}
+
+pub(crate) fn kind_label(kind: &SymbolKind) -> &'static str {
+    match kind {
+        SymbolKind::Struct { .. } => "struct",
+        SymbolKind::Enum { .. } => "enum",
+        SymbolKind::Trait { .. } => "trait",
+        SymbolKind::Function { .. } | SymbolKind::PythonFunction { .. } => "function",
+        SymbolKind::Const { .. } => "const",
+        SymbolKind::Static { .. } => "static",
+        SymbolKind::TypeAlias { .. } => "type alias",
+        SymbolKind::Mod | SymbolKind::PythonModule => "mod",
+        SymbolKind::Class { .. } => "class",
+        SymbolKind::Variable { .. } => "variable",
+    }
+}
+
+/// Diffs two symbol sets captured at different revisions of the same file, keyed by
+/// `(kind, name)`. A symbol counts as `Modified` if its `Debug` rendering differs between
+/// revisions, which catches signature, field, variant, and visibility edits without needing
+/// a dedicated comparison for every `SymbolKind` variant.
+pub fn diff_symbols(old: &FileSymbols, new: &FileSymbols) -> Vec<SymbolChange> {
+    let mut changes = Vec::new();
+
+    let old_by_key: HashMap<(&str, &str), &_> = old
+        .symbols
+        .iter()
+        .map(|s| ((kind_label(&s.kind), s.name.as_str()), s))
+        .collect();
+    let new_by_key: HashMap<(&str, &str), &_> = new
+        .symbols
+        .iter()
+        .map(|s| ((kind_label(&s.kind), s.name.as_str()), s))
+        .collect();
+
+    for (&(kind, name), new_symbol) in &new_by_key {
+        match old_by_key.get(&(kind, name)) {
+            None => changes.push(SymbolChange {
+                name: name.to_string(),
+                kind_label: kind,
+                change: SymbolChangeKind::Added,
+            }),
+            Some(old_symbol) => {
+                if format!("{:?}", old_symbol) != format!("{:?}", new_symbol) {
+                    changes.push(SymbolChange {
+                        name: name.to_string(),
+                        kind_label: kind,
+                        change: SymbolChangeKind::Modified,
+                    });
+                }
+            }
+        }
+    }
+
+    for (&(kind, name), _) in &old_by_key {
+        if !new_by_key.contains_key(&(kind, name)) {
+            changes.push(SymbolChange {
+                name: name.to_string(),
+                kind_label: kind,
+                change: SymbolChangeKind::Removed,
+            });
+        }
+    }
+
+    changes
+}
+
+/// Renders a human summary like "3 functions changed, 1 struct added" from a flat list of
+/// per-file symbol changes, most-common change first.
+pub fn summarize(changes: &[SymbolChange]) -> Option<String> {
+    if changes.is_empty() {
+        return None;
+    }
+
+    let mut counts: HashMap<(&str, SymbolChangeKind), usize> = HashMap::new();
+    for change in changes {
+        *counts
+            .entry((change.kind_label, change.change))
+            .or_insert(0) += 1;
+    }
+
+    let mut parts: Vec<(usize, String)> = counts
+        .into_iter()
+        .map(|((kind, change_kind), count)| {
+            let noun = if count == 1 {
+                kind.to_string()
+            } else {
+                format!("{}s", kind)
+            };
+            (count, format!("{} {} {}", count, noun, change_kind.label()))
+        })
+        .collect();
+
+    parts.sort_by(|a, b| b.0.cmp(&a.0));
+
+    Some(
+        parts
+            .into_iter()
+            .map(|(_, text)| text)
+            .collect::<Vec<_>>()
+            .join(", "),
+    )
+}
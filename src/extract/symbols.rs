@@ -1,17 +1,79 @@
 use serde::{Deserialize, Serialize};
 
+use super::cfg::CfgPredicate;
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FunctionBody {
     pub full_text: Option<String>,
     pub summary: Option<BodySummary>,
 }
 
+/// A 0-indexed row/column into a source file, the same coordinates `tree_sitter::Point` carries —
+/// redeclared locally (rather than storing the tree-sitter type itself) so it round-trips through
+/// `bincode` like the rest of [`BodySummary`]'s fields do.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourcePosition {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl std::fmt::Display for SourcePosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.row + 1, self.column + 1)
+    }
+}
+
+/// What kind of boundary a retained `key_calls` entry crosses — assigned by
+/// `crate::pipeline::classify::CallClassifier` in place of the old binary trivial/non-trivial
+/// split, so e.g. every fallible `unwrap`/`expect` site can be surfaced on its own rather than
+/// dropped as noise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CallCategory {
+    Io,
+    Allocation,
+    ErrorHandling,
+    Concurrency,
+    Logging,
+    Other,
+}
+
+impl std::fmt::Display for CallCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let label = match self {
+            CallCategory::Io => "io",
+            CallCategory::Allocation => "allocation",
+            CallCategory::ErrorHandling => "error-handling",
+            CallCategory::Concurrency => "concurrency",
+            CallCategory::Logging => "logging",
+            CallCategory::Other => "other",
+        };
+        write!(f, "{}", label)
+    }
+}
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BodySummary {
     pub line_count: usize,
     pub statement_count: usize,
-    pub early_returns: Vec<String>,
-    pub key_calls: Vec<String>,
+    pub early_returns: Vec<(String, SourcePosition)>,
+    pub key_calls: Vec<(String, SourcePosition, CallCategory)>,
+    /// Maximal runs of sibling statements that look safe to pull into their own function — see
+    /// `crate::pipeline::parse::extract_refactor_candidates`.
+    pub refactor_candidates: Vec<RefactorCandidate>,
+}
+
+/// A cohesive, contiguous span of statements an IDE "extract function" assist could pull out:
+/// `inputs` are the locals it reads that were bound before it (the would-be parameters),
+/// `outputs` are the locals it binds that later statements still read (the would-be return
+/// values). Never spans a `return`/`break`/`continue`, since those can't be trivially hoisted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefactorCandidate {
+    pub start_line: usize,
+    pub end_line: usize,
+    pub statement_count: usize,
+    pub inputs: Vec<String>,
+    pub outputs: Vec<String>,
+    pub score: u32,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -19,7 +81,13 @@ pub struct FileSymbols {
     pub symbols: Vec<Symbol>,
     pub impl_map: Vec<(String, String)>,
     pub inherent_impls: Vec<InherentImpl>,
+    pub trait_impl_assoc_items: Vec<TraitImplAssocItem>,
     pub macros: Vec<MacroInfo>,
+    /// `(enclosing_qualified_name, nested_qualified_name)` containment edges for Python closures
+    /// defined inside another function's body, the nested-scope counterpart to `impl_map`'s
+    /// base-to-derived class edges. See
+    /// [`crate::pipeline::parse::python::extract_nested_functions`].
+    pub nested_functions: Vec<(String, String)>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +96,84 @@ pub struct InherentImpl {
     pub generics: String,
     pub where_clause: Option<String>,
     pub methods: Vec<ImplMethod>,
+    pub assoc_consts: Vec<ImplAssocConst>,
+    pub assoc_types: Vec<ImplAssocType>,
+    /// Structured form of `generics`/`where_clause`, broken out into individual lifetime, type,
+    /// and const parameters with their bounds and defaults.
+    pub generic_params: GenericParams,
+}
+
+/// A `<...>` clause and its `where` predicates, parsed into individual parameters instead of the
+/// single opaque `generics`/`where_clause` strings. Mirrors rust-analyzer's `generics.rs` model
+/// closely enough to answer questions like "which type parameters carry a `Clone` bound" without
+/// re-parsing source text.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GenericParams {
+    pub lifetimes: Vec<String>,
+    pub type_params: Vec<GenericTypeParam>,
+    pub const_params: Vec<GenericConstParam>,
+    pub where_predicates: Vec<WherePredicate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenericTypeParam {
+    pub name: String,
+    /// Inline bounds on the parameter itself (e.g. the `Clone + Send` in `T: Clone + Send`),
+    /// split on top-level `+`. Bounds that only appear in a `where` clause live in
+    /// [`GenericParams::where_predicates`] instead.
+    pub bounds: Vec<String>,
+    pub default: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GenericConstParam {
+    pub name: String,
+    pub const_type: String,
+}
+
+/// One `where` clause predicate, e.g. `T: Clone + Send` parses to
+/// `{ target: "T", bounds: ["Clone", "Send"] }`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WherePredicate {
+    pub target: String,
+    pub bounds: Vec<String>,
+}
+
+/// How a method takes `self`, broken out of its `self_parameter` node so callers can flag
+/// e.g. `self` where `&self` would do without re-parsing the raw parameter text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Receiver {
+    Ref,
+    RefMut,
+    Owned,
+}
+
+/// One declared parameter, split into its binding name and annotated type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Param {
+    pub name: String,
+    pub ty: String,
+    pub is_mut: bool,
+}
+
+/// A structured counterpart to the raw-text signature `extract_function_signature` produces,
+/// built by [`crate::pipeline::parse::extract_signature_model`] so consumers can reason about
+/// individual parameters (count them, flag an owned `self`, etc.) instead of re-parsing a
+/// string. Additive alongside the existing `signature: String` field, which renderers already
+/// consume verbatim.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FunctionSignature {
+    pub receiver: Option<Receiver>,
+    pub params: Vec<Param>,
+    pub generics: String,
+    pub where_clause: Option<String>,
+    pub return_type: String,
+    /// Whether the body text contains a `panic!`/`.unwrap(`/`.expect(` — the same "body looks
+    /// like it can panic" signal `rules::builtin::DocCompleteness` checks against `has_panics_section`.
+    pub panics_in_body: bool,
+    /// The declared ABI of an `extern "C" fn`/`extern fn`, e.g. `Some("C")`. `None` for an
+    /// ordinary Rust-ABI function.
+    pub abi: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,11 +188,71 @@ pub struct ImplMethod {
     pub body: Option<FunctionBody>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImplAssocConst {
+    pub name: String,
+    pub const_type: String,
+    pub visibility: Visibility,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImplAssocType {
+    pub name: String,
+    pub bound_type: String,
+    pub visibility: Visibility,
+    pub line: usize,
+}
+
+/// An associated const or type found inside a *trait* impl body. Unlike inherent-impl members,
+/// these satisfy a member of `trait_name` — the member being satisfied is `name` itself, since
+/// Rust binds trait associated items to impl items by matching name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraitImplAssocItem {
+    pub trait_name: String,
+    pub type_name: String,
+    pub kind: TraitImplAssocKind,
+    pub name: String,
+    pub value_type: String,
+    pub visibility: Visibility,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TraitImplAssocKind {
+    Const,
+    Type,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MacroInfo {
     pub name: String,
     pub is_exported: bool,
     pub line: usize,
+    pub rules: Vec<MacroRule>,
+}
+
+/// One `(matcher) => { transcriber }` arm of a `macro_rules!` definition.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroRule {
+    pub matcher: String,
+    pub transcriber: String,
+    pub metavariables: Vec<MacroMetavariable>,
+}
+
+/// A `$name` or `$name:fragment` capture in a macro matcher, with the repetition it falls under
+/// (`$(...)sep*`), if any.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroMetavariable {
+    pub name: String,
+    pub fragment_specifier: Option<String>,
+    pub repetition: Option<MacroRepetition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MacroRepetition {
+    pub operator: char,
+    pub separator: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,6 +266,27 @@ pub struct Symbol {
     pub is_unsafe: bool,
     pub is_const: bool,
     pub re_exported_as: Option<String>,
+    /// First sentence of the item's `///`/`/**` doc comment, if any.
+    pub doc_summary: Option<String>,
+    /// Canonical form of the item's own `#[cfg(...)]`/`#[cfg_attr(...)]` condition(s), e.g.
+    /// `all(unix, not(feature = "foo"))`, or `None` for an unconditional item. See
+    /// [`crate::extract::cfg::CfgPredicate`]'s `Display` impl for the canonicalization.
+    pub cfg: Option<String>,
+    /// Parsed form of `cfg`, kept alongside the canonical string so
+    /// [`crate::pipeline::parse::evaluate_symbol_cfg`] can evaluate it against a caller-supplied
+    /// [`crate::extract::cfg::CfgSet`] without re-parsing `cfg`'s text.
+    pub cfg_expr: Option<CfgPredicate>,
+    /// Whether `cfg_expr` holds for the build configuration most recently evaluated against this
+    /// symbol via [`crate::pipeline::parse::evaluate_symbol_cfg`]. `true` for an unconditional
+    /// item, and `true` by default until a cfg evaluation pass actually runs.
+    pub cfg_active: bool,
+    /// Fully-qualified module path the item is declared in (e.g. `foo::bar`), or empty for the
+    /// crate root. A `mod` item's own `module_path` is its *parent* module, since the `mod`
+    /// keyword itself lives in the enclosing module.
+    pub module_path: String,
+    /// Structured form of `generics`, populated for structs, enums, traits, and functions. Empty
+    /// for symbol kinds that can't carry their own `<...>` clause (consts, statics, mods).
+    pub generic_params: GenericParams,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -78,6 +305,7 @@ pub enum SymbolKind {
     Function {
         signature: String,
         body: Option<FunctionBody>,
+        signature_model: FunctionSignature,
     },
     Const {
         const_type: String,
@@ -100,6 +328,9 @@ pub enum SymbolKind {
         is_dataclass: bool,
         is_protocol: bool,
         is_abc: bool,
+        /// Whether `bases` includes `Exception`/`BaseException`, directly or transitively through
+        /// another exception class defined in the same file.
+        is_exception: bool,
     },
     PythonFunction {
         parameters: Vec<Parameter>,
@@ -123,12 +354,14 @@ pub struct StructField {
     pub name: String,
     pub field_type: String,
     pub visibility: Visibility,
+    pub line: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnumVariant {
     pub name: String,
     pub payload: Option<VariantPayload>,
+    pub line: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -241,3 +474,70 @@ pub struct DecoratorInfo {
     pub name: String,
     pub arguments: Option<String>,
 }
+
+/// A `TypeVar`/`ParamSpec`/`TypeVarTuple`/`NewType` bound at module level, keyed by the name it
+/// was assigned to so a class's `Generic[...]`/`Protocol[...]` bases and a function's parameter
+/// and return type hints can be matched back against the same declaration when rendering
+/// `Symbol::generics`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PythonTypeVar {
+    pub name: String,
+    pub kind: PythonTypeVarKind,
+    pub bound: Option<String>,
+    pub covariant: bool,
+    pub contravariant: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PythonTypeVarKind {
+    TypeVar,
+    ParamSpec,
+    TypeVarTuple,
+    NewType,
+}
+
+/// One node of a file's document-symbol outline — a class, function, method, property, or test
+/// case nested the way the tree-sitter tree actually nests them (class → methods → closures),
+/// the hierarchical counterpart to the flat `symbols`/`test_functions` lists elsewhere on
+/// `ParsedFile`. See [`crate::pipeline::parse::python::build_symbol_tree`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SymbolTree {
+    pub kind: SymbolTreeKind,
+    pub name: String,
+    pub line: usize,
+    pub end_line: usize,
+    /// Full byte extent of the node, start inclusive / end exclusive.
+    pub byte_range: (usize, usize),
+    /// Byte extent of just the name identifier, for editors that want to place a cursor or
+    /// underline on the name alone rather than the whole declaration.
+    pub selection_range: (usize, usize),
+    /// Decorator names as written, e.g. `property`, `staticmethod`, `pytest.mark.parametrize`.
+    pub decorators: Vec<String>,
+    pub is_dunder: bool,
+    pub docstring: Option<String>,
+    pub children: Vec<SymbolTree>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum SymbolTreeKind {
+    #[default]
+    Module,
+    Class,
+    Function,
+    Method,
+    Property,
+    TestCase,
+}
+
+impl std::fmt::Display for SymbolTreeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SymbolTreeKind::Module => write!(f, "module"),
+            SymbolTreeKind::Class => write!(f, "class"),
+            SymbolTreeKind::Function => write!(f, "function"),
+            SymbolTreeKind::Method => write!(f, "method"),
+            SymbolTreeKind::Property => write!(f, "property"),
+            SymbolTreeKind::TestCase => write!(f, "test-case"),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use moka::future::Cache as MokaCache;
+
+/// Bounds the in-memory content cache [`CharterServer`](crate::serve::CharterServer) keeps in
+/// front of disk reads: entries idle this long are evicted, keeping a long-running `Serve`
+/// session's memory use bounded even after many distinct files have been read over its lifetime.
+const DEFAULT_TIME_TO_IDLE: Duration = Duration::from_secs(10 * 60);
+const DEFAULT_MAX_CAPACITY: u64 = 500;
+
+/// In-memory, idle-evicted cache of file contents keyed by absolute path and content hash,
+/// sitting in front of disk for tools like `read_source` that re-read a file's raw content on
+/// demand rather than going through the parsed, disk-persisted [`crate::cache::Cache`]. Populated
+/// lazily on first read; the bincode cache remains the cold-start source of truth for parsed
+/// structure — this layer only saves redundant disk reads within one session, and is cheap to
+/// drop and rebuild since nothing downstream depends on it surviving a restart.
+#[derive(Clone)]
+pub struct FileContentCache {
+    inner: MokaCache<PathBuf, CachedContent>,
+}
+
+#[derive(Clone)]
+struct CachedContent {
+    hash: String,
+    content: Arc<str>,
+}
+
+impl Default for FileContentCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_CAPACITY, DEFAULT_TIME_TO_IDLE)
+    }
+}
+
+impl FileContentCache {
+    pub fn new(max_capacity: u64, time_to_idle: Duration) -> Self {
+        Self {
+            inner: MokaCache::builder()
+                .max_capacity(max_capacity)
+                .time_to_idle(time_to_idle)
+                .build(),
+        }
+    }
+
+    /// Returns `path`'s content as UTF-8, serving the cached copy when `expected_hash` (the
+    /// content hash already known from the index) matches what's cached, otherwise reading from
+    /// disk, hashing, and caching the fresh content. `expected_hash` lets a caller that already
+    /// tracks the file's hash (as [`crate::serve::Index`] does via [`crate::pipeline::FileResult`])
+    /// skip a stale cache entry without an explicit invalidation call.
+    pub async fn read_to_string(
+        &self,
+        path: &Path,
+        expected_hash: &str,
+    ) -> std::io::Result<Arc<str>> {
+        if let Some(cached) = self.inner.get(path).await {
+            if cached.hash == expected_hash {
+                return Ok(cached.content);
+            }
+        }
+
+        let bytes = tokio::fs::read(path).await?;
+        let hash = blake3::hash(&bytes).to_hex().to_string();
+        let content: Arc<str> = String::from_utf8(bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?
+            .into();
+
+        self.inner
+            .insert(
+                path.to_path_buf(),
+                CachedContent {
+                    hash,
+                    content: content.clone(),
+                },
+            )
+            .await;
+
+        Ok(content)
+    }
+}
@@ -0,0 +1,405 @@
+#![allow(dead_code)]
+
+use anyhow::{anyhow, Result};
+
+use crate::output::dataflow::{DataFlowModel, FieldPattern, TypeFlow};
+
+/// Which half of a [`DataFlowModel`] a query walks, the "navigation step" half of the
+/// selector/predicate split the preserves-path query language is modeled on. Only one step deep
+/// for now since [`DataFlowModel`] only has the two top-level collections
+/// [`crate::output::dataflow::build_dataflow_model`] produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectorStep {
+    Types,
+    Fields,
+}
+
+/// A parsed selector, e.g. `types` or `fields`.
+#[derive(Debug, Clone)]
+pub struct Selector {
+    pub step: SelectorStep,
+}
+
+/// Parses a selector string into a [`Selector`]. Only the two [`DataFlowModel`] collections are
+/// addressable today, so this is a flat match rather than a real path grammar.
+pub fn parse_selector(input: &str) -> Result<Selector> {
+    match input.trim() {
+        "types" => Ok(Selector {
+            step: SelectorStep::Types,
+        }),
+        "fields" => Ok(Selector {
+            step: SelectorStep::Fields,
+        }),
+        other => Err(anyhow!(
+            "unknown selector '{other}' (expected 'types' or 'fields')"
+        )),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComparisonOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(i64),
+    Text(String),
+}
+
+/// A leaf comparison at the bottom of a [`Predicate`] tree, e.g. `producers.len() > 3` or
+/// `struct_name == "Config"`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub field: String,
+    pub op: ComparisonOp,
+    pub value: Value,
+}
+
+/// A predicate tree evaluated against one node at a time (a [`TypeFlow`] or [`FieldPattern`]),
+/// mirroring preserves-path's `And`/`Or`/`Not` combinators over leaf comparisons.
+#[derive(Debug, Clone)]
+pub enum Predicate {
+    And(Vec<Predicate>),
+    Or(Vec<Predicate>),
+    Not(Box<Predicate>),
+    Leaf(Comparison),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Op(ComparisonOp),
+    Number(i64),
+    Text(String),
+    LParen,
+    RParen,
+    And,
+    Or,
+    Not,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        match c {
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '"' => {
+                let start = i + 1;
+                let mut j = start;
+                while j < chars.len() && chars[j] != '"' {
+                    j += 1;
+                }
+                if j >= chars.len() {
+                    return Err(anyhow!("unterminated string literal in query"));
+                }
+                tokens.push(Token::Text(chars[start..j].iter().collect()));
+                i = j + 1;
+            }
+            '=' | '!' | '<' | '>' => {
+                let mut op = String::new();
+                op.push(c);
+                let mut j = i + 1;
+                if j < chars.len() && chars[j] == '=' {
+                    op.push('=');
+                    j += 1;
+                }
+                tokens.push(Token::Op(parse_op(&op)?));
+                i = j;
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let start = i;
+                let mut j = i;
+                while j < chars.len()
+                    && (chars[j].is_alphanumeric() || chars[j] == '_' || chars[j] == '.')
+                {
+                    j += 1;
+                }
+                // `producers.len()` is one field name, not a field plus a grouping paren.
+                if j + 1 < chars.len() && chars[j] == '(' && chars[j + 1] == ')' {
+                    j += 2;
+                }
+                let word: String = chars[start..j].iter().collect();
+                i = j;
+                tokens.push(match word.as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    _ => word
+                        .parse::<i64>()
+                        .map(Token::Number)
+                        .unwrap_or(Token::Ident(word)),
+                });
+            }
+            other => return Err(anyhow!("unexpected character '{other}' in query")),
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn parse_op(text: &str) -> Result<ComparisonOp> {
+    match text {
+        "==" => Ok(ComparisonOp::Eq),
+        "!=" => Ok(ComparisonOp::Ne),
+        "<" => Ok(ComparisonOp::Lt),
+        "<=" => Ok(ComparisonOp::Le),
+        ">" => Ok(ComparisonOp::Gt),
+        ">=" => Ok(ComparisonOp::Ge),
+        other => Err(anyhow!("unknown comparison operator '{other}'")),
+    }
+}
+
+/// Recursive-descent parser over `or` (lowest precedence), then `and`, then `not`/grouping/leaf
+/// comparisons — the usual boolean-expression precedence, matching how the request's examples
+/// read left to right.
+struct PredicateParser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> PredicateParser<'a> {
+    fn new(tokens: &'a [Token]) -> Self {
+        Self { tokens, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<&Token> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn parse_or(&mut self) -> Result<Predicate> {
+        let mut preds = vec![self.parse_and()?];
+        while matches!(self.peek(), Some(Token::Or)) {
+            self.advance();
+            preds.push(self.parse_and()?);
+        }
+        Ok(if preds.len() == 1 {
+            preds.remove(0)
+        } else {
+            Predicate::Or(preds)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<Predicate> {
+        let mut preds = vec![self.parse_unary()?];
+        while matches!(self.peek(), Some(Token::And)) {
+            self.advance();
+            preds.push(self.parse_unary()?);
+        }
+        Ok(if preds.len() == 1 {
+            preds.remove(0)
+        } else {
+            Predicate::And(preds)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<Predicate> {
+        if matches!(self.peek(), Some(Token::Not)) {
+            self.advance();
+            return Ok(Predicate::Not(Box::new(self.parse_unary()?)));
+        }
+
+        if matches!(self.peek(), Some(Token::LParen)) {
+            self.advance();
+            let inner = self.parse_or()?;
+            return match self.advance() {
+                Some(Token::RParen) => Ok(inner),
+                other => Err(anyhow!("expected ')' to close group, found {other:?}")),
+            };
+        }
+
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Predicate> {
+        let field = match self.advance() {
+            Some(Token::Ident(name)) => name.clone(),
+            other => return Err(anyhow!("expected a field name, found {other:?}")),
+        };
+
+        let op = match self.advance() {
+            Some(Token::Op(op)) => *op,
+            other => return Err(anyhow!("expected a comparison operator, found {other:?}")),
+        };
+
+        let value = match self.advance() {
+            Some(Token::Number(n)) => Value::Number(*n),
+            Some(Token::Text(s)) => Value::Text(s.clone()),
+            other => return Err(anyhow!("expected a comparison value, found {other:?}")),
+        };
+
+        Ok(Predicate::Leaf(Comparison { field, op, value }))
+    }
+
+    fn finish(&self) -> Result<()> {
+        if self.pos != self.tokens.len() {
+            return Err(anyhow!("unexpected trailing tokens in predicate"));
+        }
+        Ok(())
+    }
+}
+
+/// Parses a predicate string (e.g. `producers.len() > 3 and consumers.len() == 0`) into a
+/// [`Predicate`] tree ready for [`run_query`].
+pub fn parse_predicate(input: &str) -> Result<Predicate> {
+    let tokens = tokenize(input)?;
+    let mut parser = PredicateParser::new(&tokens);
+    let predicate = parser.parse_or()?;
+    parser.finish()?;
+    Ok(predicate)
+}
+
+fn compare_number(actual: i64, op: ComparisonOp, expected: i64) -> bool {
+    match op {
+        ComparisonOp::Eq => actual == expected,
+        ComparisonOp::Ne => actual != expected,
+        ComparisonOp::Lt => actual < expected,
+        ComparisonOp::Le => actual <= expected,
+        ComparisonOp::Gt => actual > expected,
+        ComparisonOp::Ge => actual >= expected,
+    }
+}
+
+fn compare_text(actual: &str, op: ComparisonOp, expected: &str) -> Result<bool> {
+    match op {
+        ComparisonOp::Eq => Ok(actual == expected),
+        ComparisonOp::Ne => Ok(actual != expected),
+        _ => Err(anyhow!("operator {op:?} is not supported for text fields")),
+    }
+}
+
+fn eval_type_flow_comparison(flow: &TypeFlow, comparison: &Comparison) -> Result<bool> {
+    match (comparison.field.as_str(), &comparison.value) {
+        ("producers.len()", Value::Number(n)) => {
+            Ok(compare_number(flow.producers.len() as i64, comparison.op, *n))
+        }
+        ("consumers.len()", Value::Number(n)) => {
+            Ok(compare_number(flow.consumers.len() as i64, comparison.op, *n))
+        }
+        ("type_name", Value::Text(s)) => compare_text(&flow.type_name, comparison.op, s),
+        (field, _) => Err(anyhow!(
+            "field '{field}' is not valid (or has the wrong value type) for a 'types' selector"
+        )),
+    }
+}
+
+fn eval_field_pattern_comparison(pattern: &FieldPattern, comparison: &Comparison) -> Result<bool> {
+    match (comparison.field.as_str(), &comparison.value) {
+        ("readers.len()", Value::Number(n)) => {
+            Ok(compare_number(pattern.readers.len() as i64, comparison.op, *n))
+        }
+        ("writers.len()", Value::Number(n)) => {
+            Ok(compare_number(pattern.writers.len() as i64, comparison.op, *n))
+        }
+        ("struct_name", Value::Text(s)) => compare_text(&pattern.struct_name, comparison.op, s),
+        ("field_name", Value::Text(s)) => compare_text(&pattern.field_name, comparison.op, s),
+        (field, _) => Err(anyhow!(
+            "field '{field}' is not valid (or has the wrong value type) for a 'fields' selector"
+        )),
+    }
+}
+
+fn eval_predicate(predicate: &Predicate, leaf: &dyn Fn(&Comparison) -> Result<bool>) -> Result<bool> {
+    match predicate {
+        Predicate::And(preds) => {
+            for pred in preds {
+                if !eval_predicate(pred, leaf)? {
+                    return Ok(false);
+                }
+            }
+            Ok(true)
+        }
+        Predicate::Or(preds) => {
+            for pred in preds {
+                if eval_predicate(pred, leaf)? {
+                    return Ok(true);
+                }
+            }
+            Ok(false)
+        }
+        Predicate::Not(inner) => Ok(!eval_predicate(inner, leaf)?),
+        Predicate::Leaf(comparison) => leaf(comparison),
+    }
+}
+
+/// One node a query matched, borrowed straight out of the [`DataFlowModel`] it was run against.
+#[derive(Debug, Clone, Copy)]
+pub enum QueryMatch<'a> {
+    TypeFlow(&'a TypeFlow),
+    FieldPattern(&'a FieldPattern),
+}
+
+/// Evaluates `predicate` against every node `selector` addresses in `model`, returning the ones
+/// that match. This is the single evaluator both [`run_query_str`] and any caller holding an
+/// already-parsed [`Selector`]/[`Predicate`] go through.
+pub fn run_query<'a>(
+    model: &'a DataFlowModel,
+    selector: &Selector,
+    predicate: &Predicate,
+) -> Result<Vec<QueryMatch<'a>>> {
+    match selector.step {
+        SelectorStep::Types => model
+            .type_flows
+            .iter()
+            .filter_map(|flow| {
+                match eval_predicate(predicate, &|cmp| eval_type_flow_comparison(flow, cmp)) {
+                    Ok(true) => Some(Ok(QueryMatch::TypeFlow(flow))),
+                    Ok(false) => None,
+                    Err(err) => Some(Err(err)),
+                }
+            })
+            .collect(),
+        SelectorStep::Fields => model
+            .field_patterns
+            .iter()
+            .filter_map(|pattern| {
+                match eval_predicate(predicate, &|cmp| eval_field_pattern_comparison(pattern, cmp)) {
+                    Ok(true) => Some(Ok(QueryMatch::FieldPattern(pattern))),
+                    Ok(false) => None,
+                    Err(err) => Some(Err(err)),
+                }
+            })
+            .collect(),
+    }
+}
+
+/// Parses `selector_str`/`predicate_str` and evaluates the result against `model` in one call —
+/// e.g. `run_query_str(model, "types", "producers.len() > 3 and consumers.len() == 0")` for
+/// "types produced by more than three functions but consumed by none".
+pub fn run_query_str<'a>(
+    model: &'a DataFlowModel,
+    selector_str: &str,
+    predicate_str: &str,
+) -> Result<Vec<QueryMatch<'a>>> {
+    let selector = parse_selector(selector_str)?;
+    let predicate = parse_predicate(predicate_str)?;
+    run_query(model, &selector, &predicate)
+}
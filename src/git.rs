@@ -1,8 +1,15 @@
 use anyhow::{Result, anyhow};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use tokio::process::Command;
 
+mod backend;
+#[cfg(feature = "gix")]
+mod gix_backend;
+mod subprocess;
+
+pub use backend::{default_backend, DefaultGitBackend, GitBackend};
+
 #[derive(Debug, Clone)]
 pub struct GitInfo {
     pub commit_short: String,
@@ -22,33 +29,175 @@ pub struct ChangedFile {
     pub kind: FileChangeKind,
 }
 
+/// Locates the real `name` executable by walking `PATH` entries directly (honoring `PATHEXT` on
+/// Windows) rather than handing the bare name to `Command::new`. On Windows, `Command::new("git")`
+/// consults the current directory before `PATH`, so analyzing an untrusted repo that happens to
+/// ship a `git.exe` of its own would run that binary instead of the real one. Falls back to the
+/// bare name (so the OS produces its normal "not found" error) if nothing turns up on `PATH`.
+pub fn resolve_executable(name: &str) -> PathBuf {
+    let candidates: Vec<String> = if cfg!(windows) {
+        let pathext =
+            std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string());
+        pathext
+            .split(';')
+            .filter(|ext| !ext.is_empty())
+            .map(|ext| format!("{name}{ext}"))
+            .collect()
+    } else {
+        vec![name.to_string()]
+    };
+
+    if let Some(path_var) = std::env::var_os("PATH") {
+        for dir in std::env::split_paths(&path_var) {
+            if dir.as_os_str().is_empty() || dir == Path::new(".") {
+                continue;
+            }
+            for candidate in &candidates {
+                let full = dir.join(candidate);
+                if full.is_file() {
+                    return full;
+                }
+            }
+        }
+    }
+
+    PathBuf::from(name)
+}
+
 pub async fn get_git_info(root: &Path) -> Result<GitInfo> {
-    let commit_short = get_commit_short(root).await?;
+    let commit_short = default_backend()
+        .head_commit(root)
+        .await
+        .ok_or_else(|| anyhow!("git rev-parse failed"))?;
     Ok(GitInfo { commit_short })
 }
 
-async fn get_commit_short(root: &Path) -> Result<String> {
-    let output = Command::new("git")
-        .arg("rev-parse")
-        .arg("--short")
-        .arg("HEAD")
+/// A file's working-tree status relative to `HEAD`, mirroring what an editor's file/project
+/// panel shows next to each path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GitStatus {
+    #[default]
+    Unmodified,
+    Modified,
+    Staged,
+    Untracked,
+    Ignored,
+}
+
+/// Reads `git status --porcelain=v2 --ignored` once and maps every reported path to its
+/// [`GitStatus`]; paths with no entry in the output (the common case) are left to
+/// [`GitStatus::default`] by the caller. Returns an empty map when `root` isn't a git repository,
+/// so non-git projects simply see every file as [`GitStatus::Unmodified`].
+///
+/// Porcelain v2 lines are split on whitespace rather than parsed with `-z`'s NUL-delimited
+/// records, so a path containing a literal space is misread.
+pub async fn get_status_map(root: &Path) -> HashMap<String, GitStatus> {
+    let output = Command::new(resolve_executable("git"))
+        .args(["status", "--porcelain=v2", "--ignored"])
         .current_dir(root)
         .output()
-        .await?;
+        .await;
 
-    if !output.status.success() {
-        return Err(anyhow!("git rev-parse failed"));
+    let output = match output {
+        Ok(o) if o.status.success() => o,
+        _ => return HashMap::new(),
+    };
+
+    let mut statuses = HashMap::new();
+
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let mut fields = line.split_whitespace();
+        let Some(kind) = fields.next() else {
+            continue;
+        };
+
+        match kind {
+            "?" => {
+                if let Some(path) = fields.next() {
+                    statuses.insert(path.to_string(), GitStatus::Untracked);
+                }
+            }
+            "!" => {
+                if let Some(path) = fields.next() {
+                    statuses.insert(path.to_string(), GitStatus::Ignored);
+                }
+            }
+            "1" | "2" => {
+                let Some(xy) = fields.next() else { continue };
+                let rest: Vec<&str> = fields.collect();
+                // "1" entries end in `path`; "2" (rename/copy) entries end in `path\torig_path`
+                // joined by a literal tab in non-`-z` output, which `split_whitespace` already
+                // treats as a separator, so `path` is simply the first trailing field either way.
+                let path_index = if kind == "2" { rest.len().checked_sub(2) } else { rest.len().checked_sub(1) };
+                let Some(path) = path_index.and_then(|i| rest.get(i)) else { continue };
+                statuses.insert(path.to_string(), classify_xy(xy));
+            }
+            "u" => {
+                let Some(path) = fields.last() else { continue };
+                statuses.insert(path.to_string(), GitStatus::Modified);
+            }
+            _ => {}
+        }
     }
 
-    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    statuses
+}
+
+/// Maps a porcelain v2 `XY` status pair to one [`GitStatus`]: any worktree-side change (`Y !=
+/// '.'`) reports as [`GitStatus::Modified`] since that's what a plain `git diff` would show;
+/// otherwise an index-side change alone (`X != '.'`) reports as [`GitStatus::Staged`].
+fn classify_xy(xy: &str) -> GitStatus {
+    let mut chars = xy.chars();
+    let x = chars.next().unwrap_or('.');
+    let y = chars.next().unwrap_or('.');
+
+    if y != '.' {
+        GitStatus::Modified
+    } else if x != '.' {
+        GitStatus::Staged
+    } else {
+        GitStatus::Unmodified
+    }
 }
 
+/// Default lookback window, in days, for [`get_churn_data`]'s plain commit-count churn.
+const DEFAULT_CHURN_LOOKBACK_DAYS: u32 = 90;
+
 pub async fn get_churn_data(root: &Path) -> Result<HashMap<PathBuf, u32>> {
-    let output = Command::new("git")
-        .arg("log")
-        .arg("--format=")
-        .arg("--name-only")
-        .arg("--since=90 days ago")
+    Ok(default_backend()
+        .churn(root, DEFAULT_CHURN_LOOKBACK_DAYS)
+        .await
+        .unwrap_or_default())
+}
+
+/// Default lookback window, in days, for [`get_detailed_churn`]'s commit/author mining.
+pub const DEFAULT_CHURN_WINDOW_DAYS: u32 = 180;
+
+/// Per-file commit count, distinct-author count, and most recent commit timestamp (Unix
+/// seconds) within the churn window, used to combine with static complexity into a risk score.
+#[derive(Debug, Clone, Default)]
+pub struct ChurnStats {
+    pub commits: u32,
+    pub authors: HashSet<String>,
+    pub last_modified: i64,
+}
+
+/// Mines [`ChurnStats`] for every file touched in the last `window_days` days via a single
+/// `git log --name-only` pass (one process spawn for the whole repo, same shape as
+/// [`get_churn_data`]) rather than one `git log --follow` invocation per file. Each commit is
+/// tagged with a `\u{1}`-prefixed header line carrying its hash/timestamp/author-email, followed
+/// by the paths it touched, so a single pass attributes commits to files without re-running git
+/// per path.
+pub async fn get_detailed_churn(
+    root: &Path,
+    window_days: u32,
+) -> Result<HashMap<PathBuf, ChurnStats>> {
+    const HEADER_PREFIX: &str = "\u{1}";
+    let format_arg = format!("--format={HEADER_PREFIX}%H%x09%at%x09%ae");
+    let since_arg = format!("--since={window_days} days ago");
+
+    let output = Command::new(resolve_executable("git"))
+        .args(["log", &format_arg, "--name-only", &since_arg])
         .current_dir(root)
         .output()
         .await;
@@ -59,82 +208,336 @@ pub async fn get_churn_data(root: &Path) -> Result<HashMap<PathBuf, u32>> {
     };
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut churn: HashMap<PathBuf, u32> = HashMap::new();
+    let mut stats: HashMap<PathBuf, ChurnStats> = HashMap::new();
+    let mut current: Option<(i64, String)> = None;
 
     for line in stdout.lines() {
+        if let Some(header) = line.strip_prefix(HEADER_PREFIX) {
+            let parts: Vec<&str> = header.splitn(3, '\t').collect();
+            let timestamp = parts.get(1).and_then(|s| s.parse().ok()).unwrap_or(0);
+            let author = parts.get(2).unwrap_or(&"").to_string();
+            current = Some((timestamp, author));
+            continue;
+        }
+
         let line = line.trim();
         if line.is_empty() {
             continue;
         }
 
-        let path = root.join(line);
-        *churn.entry(path).or_insert(0) += 1;
+        let Some((timestamp, author)) = &current else {
+            continue;
+        };
+
+        let entry = stats.entry(root.join(line)).or_default();
+        entry.commits += 1;
+        entry.authors.insert(author.clone());
+        entry.last_modified = entry.last_modified.max(*timestamp);
     }
 
-    Ok(churn)
+    Ok(stats)
 }
 
 pub async fn get_changed_files(root: &Path, since_ref: &str) -> Result<Vec<ChangedFile>> {
-    let output = Command::new("git")
-        .args(["diff", "--name-status", &format!("{}..HEAD", since_ref)])
+    default_backend()
+        .changed_files(root, since_ref)
+        .await
+        .ok_or_else(|| anyhow!("git diff failed"))
+}
+
+/// Per-file new-file-line-number ranges of every hunk in the working tree's diff against
+/// `since_ref` (`git diff --unified=0`'s `@@ -a,b +c,d @@` headers, inclusive 1-indexed
+/// `(start, end)` pairs) — e.g. for intersecting against a [`crate::pipeline::CapturedBody`]'s
+/// line span to tell which captured functions a diff actually touched. `--unified=0` asks git for
+/// exactly the changed lines with no surrounding context, so each hunk header already is the
+/// precise interval with nothing further to parse out of the body lines. Deleted files contribute
+/// no ranges (there's no new side to report). Returns `None` if `root` isn't a git repository or
+/// the diff otherwise fails.
+pub async fn changed_line_ranges(
+    root: &Path,
+    since_ref: &str,
+) -> Option<HashMap<String, Vec<(usize, usize)>>> {
+    let output = Command::new(resolve_executable("git"))
+        .args(["diff", "--unified=0", since_ref])
         .current_dir(root)
         .output()
-        .await?;
+        .await
+        .ok()?;
 
     if !output.status.success() {
-        return Err(anyhow!("git diff failed"));
+        return None;
     }
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let mut changes = Vec::new();
+    let mut ranges: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+    let mut current_path: Option<String> = None;
 
-    for line in stdout.lines() {
-        let line = line.trim();
-        if line.is_empty() {
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        if let Some(trailer) = line.strip_prefix("+++ ") {
+            current_path = parse_diff_new_path(trailer);
             continue;
         }
 
-        let parts: Vec<&str> = line.split('\t').collect();
-        if parts.is_empty() {
+        let Some(path) = current_path.as_ref() else {
+            continue;
+        };
+        let Some(hunk) = line.strip_prefix("@@ ") else {
+            continue;
+        };
+        let Some((start, len)) = parse_hunk_new_range(hunk) else {
+            continue;
+        };
+        if len == 0 {
             continue;
         }
 
-        let status = parts[0];
-        let path = parts.get(1).unwrap_or(&"").to_string();
+        ranges
+            .entry(path.clone())
+            .or_default()
+            .push((start, start + len - 1));
+    }
+
+    Some(ranges)
+}
 
-        let kind = if status.starts_with('R') {
-            let to = parts.get(2).unwrap_or(&"").to_string();
-            changes.push(ChangedFile {
-                path: to,
-                kind: FileChangeKind::Renamed,
-            });
+/// Parses a unified diff's `+++ b/path` trailer (optionally followed by a tab and a timestamp)
+/// into a root-relative path, or `None` for `+++ /dev/null` (a deleted file, which has no new
+/// side for [`changed_line_ranges`] to report).
+fn parse_diff_new_path(trailer: &str) -> Option<String> {
+    let path = trailer.split('\t').next().unwrap_or(trailer);
+    if path == "/dev/null" {
+        return None;
+    }
+    path.strip_prefix("b/").map(str::to_string)
+}
+
+/// Parses a `@@ -a,b +c,d @@` hunk header's new-file `start,len` pair; a bare `+c` (no comma)
+/// means a one-line hunk, per the unified diff format.
+fn parse_hunk_new_range(hunk: &str) -> Option<(usize, usize)> {
+    let new_part = hunk.split("+").nth(1)?.split(' ').next()?;
+    let mut pieces = new_part.splitn(2, ',');
+    let start: usize = pieces.next()?.parse().ok()?;
+    let len: usize = match pieces.next() {
+        Some(l) => l.parse().ok()?,
+        None => 1,
+    };
+    Some((start, len))
+}
+
+/// Paths changed relative to `base_ref` (committed diff plus working-tree/untracked changes),
+/// relative to `root`. Renames report only the new path. Returns `None` if `root` isn't a git
+/// repository (or the diff otherwise fails), so callers can fall back to a full walk.
+pub async fn changed_paths_since(root: &Path, base_ref: Option<&str>) -> Option<Vec<String>> {
+    let base = base_ref.unwrap_or("HEAD");
+
+    let diff_output = Command::new(resolve_executable("git"))
+        .args(["diff", "--name-status", base])
+        .current_dir(root)
+        .output()
+        .await
+        .ok()?;
+
+    if !diff_output.status.success() {
+        return None;
+    }
+
+    let mut paths = Vec::new();
+    for line in String::from_utf8_lossy(&diff_output.stdout).lines() {
+        let line = line.trim();
+        if line.is_empty() {
             continue;
-        } else {
-            match status {
-                "A" => FileChangeKind::Added,
-                "M" => FileChangeKind::Modified,
-                "D" => FileChangeKind::Deleted,
-                _ => FileChangeKind::Modified,
+        }
+
+        let parts: Vec<&str> = line.split('\t').collect();
+        let status = parts.first().copied().unwrap_or("");
+
+        if status.starts_with('R') {
+            if let Some(to) = parts.get(2) {
+                paths.push(to.to_string());
             }
-        };
+        } else if status != "D" {
+            if let Some(path) = parts.get(1) {
+                paths.push(path.to_string());
+            }
+        }
+    }
 
-        changes.push(ChangedFile { path, kind });
+    if let Ok(status_output) = Command::new(resolve_executable("git"))
+        .args(["status", "--porcelain"])
+        .current_dir(root)
+        .output()
+        .await
+    {
+        if status_output.status.success() {
+            for line in String::from_utf8_lossy(&status_output.stdout).lines() {
+                let status_char = line.chars().next().unwrap_or(' ');
+                if status_char != '?' {
+                    continue;
+                }
+                if let Some(path) = line.get(3..) {
+                    paths.push(path.trim().to_string());
+                }
+            }
+        }
     }
 
-    Ok(changes)
+    paths.sort();
+    paths.dedup();
+    Some(paths)
 }
 
-#[allow(dead_code)]
-pub async fn resolve_git_ref(root: &Path, git_ref: &str) -> Result<String> {
-    let output = Command::new("git")
-        .args(["rev-parse", "--short", git_ref])
+/// Reads a file's contents as of `git_ref` (e.g. `git show <ref>:<path>`). Returns `None`
+/// if the file didn't exist at that revision rather than surfacing a git error, since that's
+/// the expected case for a file that was added since `git_ref`.
+pub async fn read_file_at_ref(root: &Path, git_ref: &str, path: &str) -> Option<String> {
+    let output = Command::new(resolve_executable("git"))
+        .args(["show", &format!("{}:{}", git_ref, path)])
         .current_dir(root)
         .output()
-        .await?;
+        .await
+        .ok()?;
 
     if !output.status.success() {
-        return Err(anyhow!("Invalid git ref: {}", git_ref));
+        return None;
     }
 
-    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Lists every `.rs` blob tracked in the tree at `git_ref` (`git ls-tree -r --name-only`),
+/// relative to `root`. Returns `None` if `git_ref` doesn't resolve, so callers like
+/// [`crate::revdiff::diff`] can report a bad revision instead of silently diffing against
+/// an empty tree.
+pub async fn list_rust_files_at_ref(root: &Path, git_ref: &str) -> Option<Vec<String>> {
+    let output = Command::new(resolve_executable("git"))
+        .args(["ls-tree", "-r", "--name-only", git_ref])
+        .current_dir(root)
+        .output()
+        .await
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(str::trim)
+            .filter(|path| path.ends_with(".rs"))
+            .map(str::to_string)
+            .collect(),
+    )
+}
+
+/// Git-aware change set for incremental re-analysis: paths added/modified relative to
+/// `cached_commit`, and paths to purge because they were deleted. Built from the union of
+/// `git diff --name-status <cached_commit>..HEAD` (committed changes) and
+/// `git status --porcelain` (uncommitted edits and untracked files), so a warm re-capture only
+/// has to touch what actually moved instead of re-walking and re-hashing the whole tree.
+#[derive(Debug, Default)]
+pub struct IncrementalChanges {
+    pub changed: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+/// Whether `ancestor` is still reachable from `HEAD`. A `cached_commit..HEAD` diff only means
+/// what it says if history wasn't rewritten underneath it (rebase, amend, force-push); when it
+/// was, [`incremental_changes`] returns `None` so the caller falls back to a full scan.
+async fn is_ancestor(root: &Path, ancestor: &str) -> bool {
+    Command::new(resolve_executable("git"))
+        .args(["merge-base", "--is-ancestor", ancestor, "HEAD"])
+        .current_dir(root)
+        .status()
+        .await
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// Computes [`IncrementalChanges`] since `cached_commit`. Returns `None` when `cached_commit`
+/// isn't an ancestor of `HEAD` (diverged history) or the diff otherwise fails, signaling the
+/// caller to fall back to the existing hash-based full scan.
+pub async fn incremental_changes(root: &Path, cached_commit: &str) -> Option<IncrementalChanges> {
+    if !is_ancestor(root, cached_commit).await {
+        return None;
+    }
+
+    let diff_output = Command::new(resolve_executable("git"))
+        .args(["diff", "--name-status", &format!("{cached_commit}..HEAD")])
+        .current_dir(root)
+        .output()
+        .await
+        .ok()?;
+
+    if !diff_output.status.success() {
+        return None;
+    }
+
+    let mut changed = HashSet::new();
+    let mut removed = HashSet::new();
+
+    for line in String::from_utf8_lossy(&diff_output.stdout).lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split('\t').collect();
+        let status = parts.first().copied().unwrap_or("");
+
+        if status.starts_with('R') {
+            if let Some(from) = parts.get(1) {
+                removed.insert(from.to_string());
+            }
+            if let Some(to) = parts.get(2) {
+                changed.insert(to.to_string());
+            }
+        } else if status == "D" {
+            if let Some(path) = parts.get(1) {
+                removed.insert(path.to_string());
+            }
+        } else if let Some(path) = parts.get(1) {
+            changed.insert(path.to_string());
+        }
+    }
+
+    if let Ok(status_output) = Command::new(resolve_executable("git"))
+        .args(["status", "--porcelain"])
+        .current_dir(root)
+        .output()
+        .await
+    {
+        if status_output.status.success() {
+            for line in String::from_utf8_lossy(&status_output.stdout).lines() {
+                if line.len() < 4 {
+                    continue;
+                }
+
+                let status_code = &line[..2];
+                let path = line[3..].trim().to_string();
+
+                if status_code.contains('D') {
+                    changed.remove(&path);
+                    removed.insert(path);
+                } else {
+                    removed.remove(&path);
+                    changed.insert(path);
+                }
+            }
+        }
+    }
+
+    changed.retain(|path| !removed.contains(path));
+
+    Some(IncrementalChanges {
+        changed: changed.into_iter().collect(),
+        removed: removed.into_iter().collect(),
+    })
+}
+
+#[allow(dead_code)]
+pub async fn resolve_git_ref(root: &Path, git_ref: &str) -> Result<String> {
+    default_backend()
+        .resolve_ref(root, git_ref)
+        .await
+        .ok_or_else(|| anyhow!("Invalid git ref: {}", git_ref))
 }
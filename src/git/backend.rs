@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::{ChangedFile, IncrementalChanges};
+
+/// Single seam for the handful of git operations charter actually needs in-process:
+/// discovering a repository root, reading `HEAD`, diffing against a cached commit or ref for
+/// incremental re-analysis and revdiff, and mining per-path churn. [`SubprocessBackend`] shells
+/// out to the `git` binary (today's behavior, always available); the `gix` feature swaps in
+/// [`GixBackend`] (see `gix_backend.rs`), which reads the repository in-process and needs no
+/// `git` binary on `PATH`. `meta.json` commit capture, nested-workspace root detection, and the
+/// git-aware incremental path added alongside this trait all go through it instead of calling
+/// either implementation's internals directly.
+#[async_trait::async_trait]
+pub trait GitBackend: Send + Sync {
+    /// Walks upward from `start` looking for a repository root (a `.git` entry, directory or
+    /// worktree file). Returns `None` when `start` isn't inside a git repository.
+    async fn discover_root(&self, start: &Path) -> Option<PathBuf>;
+
+    /// The short commit id of `HEAD` at `root`. Returns `None` when `root` isn't a git
+    /// repository or has no commits yet.
+    async fn head_commit(&self, root: &Path) -> Option<String>;
+
+    /// [`IncrementalChanges`] since `cached_commit` — see [`super::incremental_changes`] for the
+    /// exact semantics (ancestor check, working-tree union, rename handling).
+    async fn changed_paths(&self, root: &Path, cached_commit: &str) -> Option<IncrementalChanges>;
+
+    /// Per-path commit counts over the last `since_days` days, keyed by absolute path under
+    /// `root` (matching [`super::get_churn_data`]'s existing return shape). Returns `None` on
+    /// any failure so the caller can fall back to an empty churn map rather than failing the
+    /// whole capture.
+    async fn churn(&self, root: &Path, since_days: u32) -> Option<HashMap<PathBuf, u32>>;
+
+    /// Committed changes between `since_ref` and `HEAD`, matching [`super::get_changed_files`]'s
+    /// existing return shape (renames report only the new path). Returns `None` if `since_ref`
+    /// doesn't resolve or the diff otherwise fails.
+    async fn changed_files(&self, root: &Path, since_ref: &str) -> Option<Vec<ChangedFile>>;
+
+    /// Resolves `git_ref` to its short commit id, matching [`super::resolve_git_ref`]'s existing
+    /// return shape. Returns `None` if `git_ref` doesn't resolve.
+    async fn resolve_ref(&self, root: &Path, git_ref: &str) -> Option<String>;
+}
+
+/// Default [`GitBackend`]: the `gix` crate when the `gix` feature is enabled (the common case —
+/// no `git` binary required), otherwise [`SubprocessBackend`]. Add `git-subprocess` to force the
+/// subprocess path even when `gix` is enabled, e.g. to compare behavior against a real `git`.
+#[cfg(all(feature = "gix", not(feature = "git-subprocess")))]
+pub type DefaultGitBackend = super::gix_backend::GixBackend;
+
+#[cfg(any(not(feature = "gix"), feature = "git-subprocess"))]
+pub type DefaultGitBackend = super::subprocess::SubprocessBackend;
+
+pub fn default_backend() -> DefaultGitBackend {
+    DefaultGitBackend::default()
+}
@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::backend::GitBackend;
+use super::{ChangedFile, FileChangeKind, IncrementalChanges};
+
+/// [`GitBackend`] implementation backed by the `gix` crate: reads the repository in-process via
+/// its object database instead of spawning a `git` subprocess per call, so charter works in
+/// minimal images without a `git` binary on `PATH` and pays no per-call process-spawn overhead.
+/// Falls back to [`None`]/an empty result on anything `gix` can't make sense of (shallow clones,
+/// unusual ref states) the same way the subprocess backend falls back on a nonzero exit code.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GixBackend;
+
+#[async_trait::async_trait]
+impl GitBackend for GixBackend {
+    async fn discover_root(&self, start: &Path) -> Option<PathBuf> {
+        let start = start.to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            gix::discover(&start)
+                .ok()
+                .and_then(|repo| repo.workdir().map(|p| p.to_path_buf()))
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+
+    async fn head_commit(&self, root: &Path) -> Option<String> {
+        let root = root.to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            let repo = gix::open(&root).ok()?;
+            let id = repo.head_id().ok()?;
+            Some(id.shorten().ok()?.to_string())
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+
+    async fn changed_paths(&self, root: &Path, cached_commit: &str) -> Option<IncrementalChanges> {
+        let root = root.to_path_buf();
+        let cached_commit = cached_commit.to_string();
+
+        tokio::task::spawn_blocking(move || changed_paths_sync(&root, &cached_commit))
+            .await
+            .ok()
+            .flatten()
+    }
+
+    async fn churn(&self, root: &Path, since_days: u32) -> Option<HashMap<PathBuf, u32>> {
+        let root = root.to_path_buf();
+        tokio::task::spawn_blocking(move || churn_sync(&root, since_days))
+            .await
+            .ok()
+            .flatten()
+    }
+
+    async fn changed_files(&self, root: &Path, since_ref: &str) -> Option<Vec<ChangedFile>> {
+        let root = root.to_path_buf();
+        let since_ref = since_ref.to_string();
+
+        tokio::task::spawn_blocking(move || changed_files_sync(&root, &since_ref))
+            .await
+            .ok()
+            .flatten()
+    }
+
+    async fn resolve_ref(&self, root: &Path, git_ref: &str) -> Option<String> {
+        let root = root.to_path_buf();
+        let git_ref = git_ref.to_string();
+
+        tokio::task::spawn_blocking(move || {
+            let repo = gix::open(&root).ok()?;
+            let id = repo.rev_parse_single(git_ref.as_str()).ok()?;
+            Some(id.shorten().ok()?.to_string())
+        })
+        .await
+        .ok()
+        .flatten()
+    }
+}
+
+/// Diffs `since_ref`'s tree against `HEAD`'s tree via `gix`'s tree-iteration/diff API, the
+/// in-process counterpart to [`super::subprocess::SubprocessBackend::changed_files`]'s
+/// `git diff --name-status` parsing. Renames come through as a single [`gix::object::tree::diff::Change::Rewrite`]
+/// with both locations already known, so [`FileChangeKind::Renamed`] reports the new path
+/// directly instead of relying on `R`-prefix string matching.
+fn changed_files_sync(root: &Path, since_ref: &str) -> Option<Vec<ChangedFile>> {
+    let repo = gix::open(root).ok()?;
+
+    let since_id = repo.rev_parse_single(since_ref).ok()?.detach();
+    let head_id = repo.head_id().ok()?.detach();
+
+    let since_tree = repo.find_commit(since_id).ok()?.tree().ok()?;
+    let head_tree = repo.find_commit(head_id).ok()?.tree().ok()?;
+
+    let mut changes = Vec::new();
+
+    since_tree
+        .changes()
+        .ok()?
+        .track_path()
+        .for_each_to_obtain_tree(&head_tree, |change| {
+            use gix::object::tree::diff::Change;
+
+            match change {
+                Change::Addition { location, .. } => {
+                    changes.push(ChangedFile {
+                        path: location.to_string(),
+                        kind: FileChangeKind::Added,
+                    });
+                }
+                Change::Modification { location, .. } => {
+                    changes.push(ChangedFile {
+                        path: location.to_string(),
+                        kind: FileChangeKind::Modified,
+                    });
+                }
+                Change::Deletion { location, .. } => {
+                    changes.push(ChangedFile {
+                        path: location.to_string(),
+                        kind: FileChangeKind::Deleted,
+                    });
+                }
+                Change::Rewrite { location, .. } => {
+                    changes.push(ChangedFile {
+                        path: location.to_string(),
+                        kind: FileChangeKind::Renamed,
+                    });
+                }
+            }
+
+            Ok::<_, std::convert::Infallible>(gix::object::tree::diff::Action::Continue)
+        })
+        .ok()?;
+
+    Some(changes)
+}
+
+/// Diffs `cached_commit`'s tree against `HEAD`'s tree, then layers the working tree on top via
+/// `gix::status`, mirroring [`super::incremental_changes`]'s "committed diff union working-tree
+/// status" shape without spawning `git`. Mirrors its fallback behavior too: a diverged
+/// `cached_commit` (rebase, amend) returns `None` so the caller falls back to a full scan.
+fn changed_paths_sync(root: &Path, cached_commit: &str) -> Option<IncrementalChanges> {
+    let repo = gix::open(root).ok()?;
+
+    let old_id = repo.rev_parse_single(cached_commit).ok()?.detach();
+    let head_id = repo.head_id().ok()?.detach();
+
+    if !repo.is_ancestor(old_id, head_id).unwrap_or(false) {
+        return None;
+    }
+
+    let old_tree = repo.find_commit(old_id).ok()?.tree().ok()?;
+    let new_tree = repo.find_commit(head_id).ok()?.tree().ok()?;
+
+    let mut changed = Vec::new();
+    let mut removed = Vec::new();
+
+    old_tree
+        .changes()
+        .ok()?
+        .track_path()
+        .for_each_to_obtain_tree(&new_tree, |change| {
+            use gix::object::tree::diff::Change;
+
+            match change {
+                Change::Addition { location, .. } | Change::Modification { location, .. } => {
+                    changed.push(location.to_string());
+                }
+                Change::Deletion { location, .. } => {
+                    removed.push(location.to_string());
+                }
+                Change::Rewrite {
+                    source_location,
+                    location,
+                    ..
+                } => {
+                    removed.push(source_location.to_string());
+                    changed.push(location.to_string());
+                }
+            }
+
+            Ok::<_, std::convert::Infallible>(gix::object::tree::diff::Action::Continue)
+        })
+        .ok()?;
+
+    if let Ok(status) = repo.status(gix::progress::Discard) {
+        if let Ok(mut iter) = status.into_iter() {
+            while let Some(Ok(item)) = iter.next() {
+                let path = item.location().to_string();
+                if item.summary().is_removed() {
+                    changed.retain(|p| p != &path);
+                    removed.push(path);
+                } else {
+                    removed.retain(|p| p != &path);
+                    changed.push(path);
+                }
+            }
+        }
+    }
+
+    changed.sort();
+    changed.dedup();
+    removed.sort();
+    removed.dedup();
+
+    Some(IncrementalChanges { changed, removed })
+}
+
+/// Walks `HEAD`'s ancestry, diffing each commit against its first parent and counting a commit
+/// against every path its diff touches — the object-graph equivalent of
+/// [`super::subprocess::SubprocessBackend::churn`]'s `git log --since --name-only` pass, without
+/// spawning `git`. Root commits (no parent to diff against) are skipped, same as merge parents
+/// beyond the first, since the subprocess path's single-pass `--name-only` output doesn't
+/// distinguish either case itself.
+fn churn_sync(root: &Path, since_days: u32) -> Option<HashMap<PathBuf, u32>> {
+    let repo = gix::open(root).ok()?;
+    let head_id = repo.head_id().ok()?.detach();
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs() as i64;
+    let cutoff = now - i64::from(since_days) * 86_400;
+
+    let mut churn: HashMap<PathBuf, u32> = HashMap::new();
+
+    for info in repo.rev_walk([head_id]).all().ok()? {
+        let info = info.ok()?;
+        let commit = info.object().ok()?;
+
+        if commit.time().ok()?.seconds < cutoff {
+            continue;
+        }
+
+        let Some(parent_id) = commit.parent_ids().next() else {
+            continue;
+        };
+        let Ok(parent_tree) = repo
+            .find_commit(parent_id)
+            .and_then(|parent| parent.tree())
+        else {
+            continue;
+        };
+        let Ok(tree) = commit.tree() else {
+            continue;
+        };
+
+        let _ = parent_tree.changes().ok()?.track_path().for_each_to_obtain_tree(
+            &tree,
+            |change| {
+                use gix::object::tree::diff::Change;
+
+                let location = match change {
+                    Change::Addition { location, .. }
+                    | Change::Modification { location, .. }
+                    | Change::Deletion { location, .. } => location,
+                    Change::Rewrite { location, .. } => location,
+                };
+                *churn.entry(root.join(location.to_string())).or_insert(0) += 1;
+
+                Ok::<_, std::convert::Infallible>(gix::object::tree::diff::Action::Continue)
+            },
+        );
+    }
+
+    Some(churn)
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+use tokio::process::Command;
+
+use super::backend::GitBackend;
+use super::{
+    incremental_changes, resolve_executable, ChangedFile, FileChangeKind, IncrementalChanges,
+};
+
+/// [`GitBackend`] implementation that shells out to the `git` binary on `PATH` — the behavior
+/// charter has always had, kept as the fallback for environments without the `gix` feature (or
+/// where a `git` binary is preferred, e.g. to match an exact local git version/config).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SubprocessBackend;
+
+#[async_trait::async_trait]
+impl GitBackend for SubprocessBackend {
+    async fn discover_root(&self, start: &Path) -> Option<PathBuf> {
+        let mut current = start.to_path_buf();
+        loop {
+            if fs::metadata(current.join(".git")).await.is_ok() {
+                return Some(current);
+            }
+            if !current.pop() {
+                return None;
+            }
+        }
+    }
+
+    async fn head_commit(&self, root: &Path) -> Option<String> {
+        let output = Command::new(resolve_executable("git"))
+            .args(["rev-parse", "--short", "HEAD"])
+            .current_dir(root)
+            .output()
+            .await
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    async fn changed_paths(&self, root: &Path, cached_commit: &str) -> Option<IncrementalChanges> {
+        incremental_changes(root, cached_commit).await
+    }
+
+    async fn churn(&self, root: &Path, since_days: u32) -> Option<HashMap<PathBuf, u32>> {
+        let output = Command::new(resolve_executable("git"))
+            .arg("log")
+            .arg("--format=")
+            .arg("--name-only")
+            .arg(format!("--since={} days ago", since_days))
+            .current_dir(root)
+            .output()
+            .await
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        let mut churn: HashMap<PathBuf, u32> = HashMap::new();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            *churn.entry(root.join(line)).or_insert(0) += 1;
+        }
+
+        Some(churn)
+    }
+
+    async fn changed_files(&self, root: &Path, since_ref: &str) -> Option<Vec<ChangedFile>> {
+        let output = Command::new(resolve_executable("git"))
+            .args(["diff", "--name-status", &format!("{since_ref}..HEAD")])
+            .current_dir(root)
+            .output()
+            .await
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        let mut changes = Vec::new();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let parts: Vec<&str> = line.split('\t').collect();
+            if parts.is_empty() {
+                continue;
+            }
+
+            let status = parts[0];
+            let path = parts.get(1).unwrap_or(&"").to_string();
+
+            let kind = if status.starts_with('R') {
+                let to = parts.get(2).unwrap_or(&"").to_string();
+                changes.push(ChangedFile {
+                    path: to,
+                    kind: FileChangeKind::Renamed,
+                });
+                continue;
+            } else {
+                match status {
+                    "A" => FileChangeKind::Added,
+                    "M" => FileChangeKind::Modified,
+                    "D" => FileChangeKind::Deleted,
+                    _ => FileChangeKind::Modified,
+                }
+            };
+
+            changes.push(ChangedFile { path, kind });
+        }
+
+        Some(changes)
+    }
+
+    async fn resolve_ref(&self, root: &Path, git_ref: &str) -> Option<String> {
+        let output = Command::new(resolve_executable("git"))
+            .args(["rev-parse", "--short", git_ref])
+            .current_dir(root)
+            .output()
+            .await
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+}
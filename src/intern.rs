@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// An index into a [`SymbolTable`]'s `strings`. Stable for the lifetime of the table that
+/// produced it — resolving an ID against any other table is a logic error, not just a wrong
+/// answer, since tables are built independently per document.
+pub type SymbolId = u32;
+
+/// A deduplicated string pool, the same "visited interned values" strategy compilers use when
+/// serializing interned MIR/HIR types: every distinct string is stored once, and everywhere it
+/// would otherwise repeat (a `trait_name`, an `impl_type`, a lifetime bound) becomes a cheap
+/// [`SymbolId`] instead. Serialize the table once at the container level alongside whichever
+/// interned document references it, then use [`SymbolTable::resolve`] to turn IDs back into
+/// strings for `Display` impls and human-readable output.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SymbolTable {
+    strings: Vec<String>,
+    /// Not serialized — `resolve` only ever indexes into `strings`, so a deserialized table has
+    /// no need of it unless more strings are interned into it afterward.
+    #[serde(skip)]
+    index: HashMap<String, SymbolId>,
+}
+
+impl SymbolTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `s`'s existing [`SymbolId`] if already interned, otherwise appends it and returns
+    /// the new one.
+    pub fn intern(&mut self, s: &str) -> SymbolId {
+        if let Some(&id) = self.index.get(s) {
+            return id;
+        }
+        let id = self.strings.len() as SymbolId;
+        self.strings.push(s.to_string());
+        self.index.insert(s.to_string(), id);
+        id
+    }
+
+    /// Looks up `id`'s string, or `None` if it's out of range for this table.
+    pub fn resolve(&self, id: SymbolId) -> Option<&str> {
+        self.strings.get(id as usize).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
@@ -0,0 +1,240 @@
+//! Line-level unified diffs between two text bodies, used to show exactly how a function's
+//! body changed rather than just flagging that its signature did (see
+//! [`crate::pipeline::build_diff_summary`]).
+
+/// One line in a [`Hunk`], tagged by which side of the diff it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    Context(String),
+    Removed(String),
+    Added(String),
+}
+
+/// A contiguous run of changed lines plus a few lines of surrounding context, in the same
+/// shape a `diff -u`/`git diff` hunk header (`@@ -a,b +c,d @@`) describes.
+#[derive(Debug, Clone)]
+pub struct Hunk {
+    pub old_start: usize,
+    pub old_len: usize,
+    pub new_start: usize,
+    pub new_len: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+/// Lines of context kept on either side of a change, matching the conventional `diff -u` default.
+const CONTEXT_LINES: usize = 3;
+
+enum EditOp {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Diffs `old` and `new` line-by-line via the Myers O(ND) greedy LCS algorithm and collapses
+/// the resulting edit script into unified hunks. Returns an empty `Vec` if the two bodies are
+/// identical.
+pub fn diff_lines(old: &str, new: &str) -> Vec<Hunk> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+
+    let trace = shortest_edit_trace(&a, &b);
+    let ops = backtrack(&a, &b, &trace);
+
+    build_hunks(&a, &b, &ops)
+}
+
+/// Renders `hunks` as plain unified-diff text lines: a `@@ -a,b +c,d @@` header followed by
+/// ` `/`-`/`+`-prefixed body lines, one [`String`] per output line.
+pub fn format_unified(hunks: &[Hunk]) -> Vec<String> {
+    let mut out = Vec::new();
+    for hunk in hunks {
+        out.push(format!(
+            "@@ -{},{} +{},{} @@",
+            hunk.old_start, hunk.old_len, hunk.new_start, hunk.new_len
+        ));
+        for line in &hunk.lines {
+            match line {
+                DiffLine::Context(text) => out.push(format!(" {}", text)),
+                DiffLine::Removed(text) => out.push(format!("-{}", text)),
+                DiffLine::Added(text) => out.push(format!("+{}", text)),
+            }
+        }
+    }
+    out
+}
+
+/// Runs Myers' algorithm forward, keeping the diagonal-indexed furthest-reaching-`x` array `v`
+/// from every edit distance `d` so [`backtrack`] can walk it back into an edit script. `v` is
+/// offset by `max = a.len() + b.len()` so the diagonal index `k` (which ranges over `-d..=d`)
+/// never goes negative.
+fn shortest_edit_trace(a: &[&str], b: &[&str]) -> Vec<Vec<i64>> {
+    let n = a.len() as i64;
+    let m = b.len() as i64;
+    let max = (n + m) as usize;
+    let offset = max as i64;
+
+    let mut v = vec![0i64; 2 * max + 1];
+    let mut trace = Vec::new();
+
+    if max == 0 {
+        trace.push(v);
+        return trace;
+    }
+
+    for d in 0..=max as i64 {
+        trace.push(v.clone());
+
+        let mut k = -d;
+        while k <= d {
+            let idx = (k + offset) as usize;
+
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+
+            v[idx] = x;
+
+            if x >= n && y >= m {
+                trace.pop();
+                trace.push(v.clone());
+                return trace;
+            }
+
+            k += 2;
+        }
+    }
+
+    trace
+}
+
+/// Walks [`shortest_edit_trace`]'s recorded `v` arrays backward from `(a.len(), b.len())` to
+/// `(0, 0)`, turning each edit distance's snake into [`EditOp::Equal`] runs separated by a
+/// single [`EditOp::Delete`] or [`EditOp::Insert`], then reverses the result into forward order.
+fn backtrack(a: &[&str], b: &[&str], trace: &[Vec<i64>]) -> Vec<EditOp> {
+    let max = a.len() + b.len();
+    let offset = max as i64;
+
+    let mut x = a.len() as i64;
+    let mut y = b.len() as i64;
+    let mut ops = Vec::new();
+
+    for d in (0..trace.len()).rev() {
+        let v = &trace[d];
+        let d = d as i64;
+        let k = x - y;
+        let idx = (k + offset) as usize;
+
+        let prev_k = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_idx = (prev_k + offset) as usize;
+        let prev_x = v[prev_idx];
+        let prev_y = prev_x - prev_k;
+
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            ops.push(EditOp::Equal(x as usize, y as usize));
+        }
+
+        if d > 0 {
+            if x == prev_x {
+                y -= 1;
+                ops.push(EditOp::Insert(y as usize));
+            } else {
+                x -= 1;
+                ops.push(EditOp::Delete(x as usize));
+            }
+        }
+
+        x = prev_x;
+        y = prev_y;
+    }
+
+    ops.reverse();
+    ops
+}
+
+/// Groups the edit script's non-equal runs into hunks, merging any two changes fewer than
+/// `2 * CONTEXT_LINES` apart into a single hunk the same way `diff -u` does.
+fn build_hunks(a: &[&str], b: &[&str], ops: &[EditOp]) -> Vec<Hunk> {
+    let change_indices: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, op)| !matches!(op, EditOp::Equal(..)))
+        .map(|(i, _)| i)
+        .collect();
+
+    if change_indices.is_empty() {
+        return Vec::new();
+    }
+
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    let mut start = change_indices[0];
+    let mut end = change_indices[0];
+
+    for &idx in &change_indices[1..] {
+        if idx - end <= 2 * CONTEXT_LINES {
+            end = idx;
+        } else {
+            ranges.push((start, end));
+            start = idx;
+            end = idx;
+        }
+    }
+    ranges.push((start, end));
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| {
+            let window_start = start.saturating_sub(CONTEXT_LINES);
+            let window_end = (end + CONTEXT_LINES).min(ops.len() - 1);
+
+            let mut lines = Vec::new();
+            let mut old_start = None;
+            let mut new_start = None;
+            let mut old_len = 0;
+            let mut new_len = 0;
+
+            for op in &ops[window_start..=window_end] {
+                match *op {
+                    EditOp::Equal(oi, ni) => {
+                        old_start.get_or_insert(oi);
+                        new_start.get_or_insert(ni);
+                        old_len += 1;
+                        new_len += 1;
+                        lines.push(DiffLine::Context(a[oi].to_string()));
+                    }
+                    EditOp::Delete(oi) => {
+                        old_start.get_or_insert(oi);
+                        old_len += 1;
+                        lines.push(DiffLine::Removed(a[oi].to_string()));
+                    }
+                    EditOp::Insert(ni) => {
+                        new_start.get_or_insert(ni);
+                        new_len += 1;
+                        lines.push(DiffLine::Added(b[ni].to_string()));
+                    }
+                }
+            }
+
+            Hunk {
+                old_start: old_start.unwrap_or(0) + 1,
+                old_len,
+                new_start: new_start.unwrap_or(0) + 1,
+                new_len,
+                lines,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::extract::attributes::DeriveInfo;
+use crate::extract::calls::MacroCall;
+use crate::extract::symbol_diff::{self, SymbolChangeKind};
+use crate::pipeline::{parse_rust_file, ParsedFile};
+
+/// Where a post-expansion symbol or impl came from: the macro invocation that produced it, plus
+/// whether that attribution is a confident join (a `#[derive(..)]` naming the same trait) or a
+/// best-effort guess (the nearest macro invocation in the file, since plain `cargo expand` output
+/// doesn't otherwise preserve which macro produced which generated item).
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MacroOrigin {
+    pub macro_name: String,
+    pub is_derive: bool,
+    /// `false` when this is the nearest-invocation fallback rather than a confirmed derive match.
+    pub confident: bool,
+    /// The line in the *original* (pre-expansion) source the macro was invoked from, so a
+    /// generated item can still be attributed to a real location in the file the user edits.
+    pub invocation_line: usize,
+}
+
+/// One `impl Trait for Type` block that only exists in the expanded source, attributed to
+/// whichever macro produced it.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ExpandedImpl {
+    pub type_name: String,
+    pub trait_name: String,
+    pub origin: MacroOrigin,
+}
+
+/// One symbol (function, struct, etc.) that only exists in the expanded source, attributed the
+/// same way [`ExpandedImpl`] is.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ExpandedSymbol {
+    pub name: String,
+    pub kind_label: &'static str,
+    pub origin: MacroOrigin,
+}
+
+/// Whether this build was compiled with the `macro-expansion` feature. Gated behind a feature
+/// flag rather than always on, since both backends [`expand_crate`] tries need more than charter
+/// otherwise depends on: a full, buildable crate, and (for `cargo expand`) a nightly toolchain
+/// with the `cargo-expand` subcommand installed.
+pub fn is_available() -> bool {
+    cfg!(feature = "macro-expansion")
+}
+
+#[cfg(feature = "macro-expansion")]
+mod backend {
+    use anyhow::anyhow;
+    use tokio::process::Command;
+
+    use super::*;
+    use crate::git::resolve_executable;
+
+    /// Obtains the fully macro-expanded source of the crate rooted at `manifest_dir`, preferring
+    /// `cargo expand` (which also expands proc-macros brought in from dependencies) and falling
+    /// back to `rustc -Zunpretty=expanded` on a nightly toolchain when `cargo-expand` isn't
+    /// installed — the same "nicer tool, raw fallback" shape [`crate::git`]'s backends use for
+    /// `git` itself.
+    pub async fn expand_crate(manifest_dir: &Path) -> Result<String> {
+        if let Ok(output) = Command::new(resolve_executable("cargo"))
+            .arg("expand")
+            .current_dir(manifest_dir)
+            .output()
+            .await
+        {
+            if output.status.success() {
+                return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+            }
+        }
+
+        let output = Command::new(resolve_executable("cargo"))
+            .args(["+nightly", "rustc", "--", "-Zunpretty=expanded"])
+            .current_dir(manifest_dir)
+            .output()
+            .await
+            .map_err(|e| anyhow!("failed to spawn cargo rustc -Zunpretty=expanded: {e}"))?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "macro expansion failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+#[cfg(feature = "macro-expansion")]
+pub use backend::expand_crate;
+
+/// Re-parses `expanded_source` (the output of [`expand_crate`] for the file that used to live at
+/// `expanded_path`) the same way any other source file is parsed, then joins its impls and
+/// symbols against `original`'s to find everything that only exists post-expansion — the
+/// generated impls, methods, and helper items a derive or macro invocation produced but
+/// source-only analysis never sees.
+pub fn diff_expansion(
+    original: &ParsedFile,
+    expanded_path: &str,
+    expanded_source: &str,
+) -> Result<(Vec<ExpandedImpl>, Vec<ExpandedSymbol>)> {
+    let expanded = parse_rust_file(expanded_source, expanded_path)?;
+
+    let original_impls: HashSet<(&str, &str)> = original
+        .impls
+        .iter()
+        .map(|imp| (imp.type_name.as_str(), imp.trait_name.as_str()))
+        .collect();
+
+    let generated_impls: Vec<ExpandedImpl> = expanded
+        .impls
+        .iter()
+        .filter(|imp| !original_impls.contains(&(imp.type_name.as_str(), imp.trait_name.as_str())))
+        .map(|imp| ExpandedImpl {
+            type_name: imp.type_name.clone(),
+            trait_name: imp.trait_name.clone(),
+            origin: attribute_origin(&original.derives, &original.macro_calls, &imp.type_name, Some(&imp.trait_name), imp.line),
+        })
+        .collect();
+
+    let changes = symbol_diff::diff_symbols(&original.symbols, &expanded.symbols);
+    let generated_symbols: Vec<ExpandedSymbol> = changes
+        .iter()
+        .filter(|change| change.change == SymbolChangeKind::Added)
+        .map(|change| {
+            let origin = generated_impls
+                .iter()
+                .find(|imp| change.name.starts_with(&imp.type_name))
+                .map(|imp| imp.origin.clone())
+                .unwrap_or_else(|| attribute_origin(&original.derives, &original.macro_calls, &change.name, None, 0));
+
+            ExpandedSymbol {
+                name: change.name.clone(),
+                kind_label: change.kind_label,
+                origin,
+            }
+        })
+        .collect();
+
+    Ok((generated_impls, generated_symbols))
+}
+
+/// Attributes a generated impl/symbol to whichever macro produced it: a confident match against
+/// `derives` when `trait_name` names a trait `#[derive(..)]`d on the same type, otherwise the
+/// nearest non-builtin [`MacroCall`] in the file as a best-effort guess (`confident: false`) —
+/// plain `cargo expand` text output doesn't preserve a span back to the invocation that produced
+/// any one generated item, so beyond the derive case this is the most this module can honestly
+/// claim.
+fn attribute_origin(
+    derives: &[DeriveInfo],
+    macro_calls: &[MacroCall],
+    target: &str,
+    trait_name: Option<&str>,
+    fallback_line: usize,
+) -> MacroOrigin {
+    if let Some(trait_name) = trait_name {
+        if let Some(derive) = derives
+            .iter()
+            .find(|d| d.target == target && d.traits.iter().any(|t| t == trait_name))
+        {
+            return MacroOrigin {
+                macro_name: trait_name.to_string(),
+                is_derive: true,
+                confident: true,
+                invocation_line: derive.line,
+            };
+        }
+    }
+
+    match macro_calls
+        .iter()
+        .filter(|call| !call.is_builtin)
+        .min_by_key(|call| call.line.abs_diff(fallback_line))
+    {
+        Some(call) => MacroOrigin {
+            macro_name: call.path.clone(),
+            is_derive: false,
+            confident: false,
+            invocation_line: call.line,
+        },
+        None => MacroOrigin {
+            macro_name: "unknown macro".to_string(),
+            is_derive: false,
+            confident: false,
+            invocation_line: fallback_line,
+        },
+    }
+}
@@ -1,18 +1,45 @@
+mod analyze;
+mod audit;
+mod buildgen;
 mod cache;
+mod callgraph;
+mod callindex;
+mod churn;
 mod cli;
+mod complexity;
+mod crossref;
 mod deps;
 mod detect;
+mod errorchain;
+mod errorflow;
+mod export;
 mod extract;
+mod filecache;
+mod flowquery;
 mod git;
+mod intern;
+mod linediff;
+mod macroexpand;
 mod output;
 mod pipeline;
 mod query;
+mod rangemerge;
+mod resolve;
+mod revdiff;
+mod rollup;
+mod rules;
+mod rustdoc_json;
+mod serve;
 mod session;
+mod symbolsearch;
+mod targetgraph;
 mod tests;
+mod traitindex;
+mod visibility;
 
 use anyhow::Result;
 use clap::Parser;
-use cli::{Cli, Commands, SessionAction};
+use cli::{Cli, Commands, OutputFormat, SessionAction};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -21,20 +48,70 @@ async fn main() -> Result<()> {
     match cli.command {
         None => {
             let root = detect::find_project_root(cli.path).await?;
-            pipeline::capture(&root).await?;
+            let cfg = if cli.cfg.is_empty() {
+                None
+            } else {
+                let mut set = extract::cfg::CfgSet::new();
+                for arg in &cli.cfg {
+                    set.apply_arg(arg);
+                }
+                Some(set)
+            };
+            let format_json = cli.format == Some(OutputFormat::Json);
+            let format_html = cli.format == Some(OutputFormat::Html);
+            pipeline::capture_with_mode(
+                &root,
+                !cli.no_incremental,
+                cli.no_ignore,
+                cfg,
+                format_json,
+                format_html,
+            )
+            .await?;
         }
         Some(Commands::Read {
             tier,
             focus,
             since,
+            json,
+            threshold,
+            docs,
             path,
         }) => {
             let root = detect::find_project_root(path).await?;
-            output::peek(&root, tier, focus.as_deref(), since.as_deref()).await?;
+            if json {
+                output::peek_json(&root, tier, focus.as_deref(), since.as_deref()).await?;
+            } else {
+                output::peek(
+                    &root,
+                    tier,
+                    focus.as_deref(),
+                    since.as_deref(),
+                    threshold,
+                    docs,
+                )
+                .await?;
+            }
         }
-        Some(Commands::Status { path }) => {
+        Some(Commands::Status {
+            metrics_diff,
+            pending,
+            exit_code,
+            glob,
+            path,
+        }) => {
             let root = detect::find_project_root(path).await?;
-            output::stats(&root).await?;
+            if pending {
+                let dirty = output::pending_changes(&root, glob.as_deref()).await?;
+                if exit_code && dirty {
+                    std::process::exit(1);
+                }
+            } else {
+                match metrics_diff {
+                    Some(since_ref) => output::metrics_diff(&root, &since_ref).await?,
+                    None => output::stats(&root).await?,
+                }
+            }
         }
         Some(Commands::Lookup { symbol, path }) => {
             let root = detect::find_project_root(path).await?;
@@ -43,18 +120,126 @@ async fn main() -> Result<()> {
         Some(Commands::Query {
             query: query_str,
             limit,
+            json,
+            path,
+        }) => {
+            let root = detect::find_project_root(path).await?;
+            query::query(&root, &query_str, limit, json).await?;
+        }
+        Some(Commands::Search {
+            query: query_str,
+            module,
+            limit,
+            json,
+            path,
+        }) => {
+            let root = detect::find_project_root(path).await?;
+            symbolsearch::search(&root, &query_str, module.as_deref(), limit, json).await?;
+        }
+        Some(Commands::Deps {
+            krate,
+            graph,
+            enrich,
+            features,
+            path,
+        }) => {
+            let root = detect::find_project_root(path).await?;
+            deps::deps(&root, krate.as_deref(), graph, enrich, features).await?;
+        }
+        Some(Commands::Tests {
+            file,
+            lcov,
+            merge,
+            format,
             path,
         }) => {
             let root = detect::find_project_root(path).await?;
-            query::query(&root, &query_str, limit).await?;
+            tests::tests(&root, file.as_deref(), lcov.as_deref(), &merge, format.into()).await?;
         }
-        Some(Commands::Deps { krate, path }) => {
+        Some(Commands::Serve { path }) => {
             let root = detect::find_project_root(path).await?;
-            deps::deps(&root, krate.as_deref()).await?;
+            serve::serve(&root).await?;
         }
-        Some(Commands::Tests { file, path }) => {
+        Some(Commands::Watch { path }) => {
             let root = detect::find_project_root(path).await?;
-            tests::tests(&root, file.as_deref()).await?;
+            pipeline::watch(&root).await?;
+        }
+        Some(Commands::Lint {
+            rule,
+            severity,
+            path,
+        }) => {
+            let root = detect::find_project_root(path).await?;
+            let charter_dir = root.join(".charter");
+
+            if !charter_dir.exists() {
+                eprintln!("No .charter/ directory found. Run 'charter' first.");
+                std::process::exit(1);
+            }
+
+            let rules = match rule {
+                Some(name) => rules::default_rules()
+                    .into_iter()
+                    .filter(|r| r.name() == name)
+                    .collect(),
+                None => rules::default_rules(),
+            };
+
+            if rules.is_empty() {
+                eprintln!("No rule named '{}'", rule.unwrap_or_default());
+                std::process::exit(1);
+            }
+
+            let count = rules::run_lints(&charter_dir, rules, severity.map(Into::into)).await?;
+            println!("Wrote {} diagnostic(s) to .charter/lints.md", count);
+        }
+        Some(Commands::Diff {
+            revisions,
+            json,
+            path,
+        }) => {
+            let root = detect::find_project_root(path).await?;
+            revdiff::diff(&root, &revisions, json).await?;
+        }
+        Some(Commands::Complexity {
+            format,
+            weight,
+            fail_on,
+            path,
+        }) => {
+            let root = detect::find_project_root(path).await?;
+            let charter_dir = root.join(".charter");
+
+            if !charter_dir.exists() {
+                eprintln!("No .charter/ directory found. Run 'charter' first.");
+                std::process::exit(1);
+            }
+
+            let mut weights = extract::complexity::ScoringWeights::default();
+            for arg in &weight {
+                weights.apply_override(arg);
+            }
+
+            let cache = cache::Cache::load(&charter_dir.join("cache.bin")).await?;
+            let findings = complexity::collect_findings(&cache, &weights);
+
+            match format {
+                cli::ComplexityFormat::Sarif => {
+                    complexity::write_complexity_sarif(&charter_dir, &findings).await?;
+                    println!(
+                        "Wrote {} complexity finding(s) to .charter/complexity.sarif.json",
+                        findings.len()
+                    );
+                }
+                cli::ComplexityFormat::Json => complexity::print_complexity_json(&findings)?,
+                cli::ComplexityFormat::Text => complexity::print_complexity_text(&findings),
+            }
+
+            if let Some(fail_on) = fail_on {
+                if complexity::fails_threshold(&findings, fail_on.into()) {
+                    std::process::exit(1);
+                }
+            }
         }
         Some(Commands::Session { action }) => match action {
             SessionAction::Start { path } => {
@@ -69,6 +254,10 @@ async fn main() -> Result<()> {
                 let root = detect::find_project_root(path).await?;
                 session::session_status(&root).await?;
             }
+            SessionAction::Report { json, path } => {
+                let root = detect::find_project_root(path).await?;
+                session::session_report(&root, json).await?;
+            }
         },
     }
 
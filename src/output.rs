@@ -1,11 +1,19 @@
+pub mod attributes;
+pub mod callgraph;
 pub mod calls;
+pub mod churn;
 pub mod clusters;
 pub mod dataflow;
 pub mod dependents;
 pub mod errors;
+pub mod export_json;
 pub mod hotspots;
+pub mod imports;
 pub mod manifest;
+pub mod manifest_json;
+pub mod model_json;
 pub mod overview;
+pub mod overview_json;
 pub mod preamble;
 pub mod refs;
 pub mod safety;
@@ -15,22 +23,119 @@ pub mod symbols;
 pub mod type_map;
 
 use anyhow::Result;
-use std::collections::HashSet;
-use std::path::Path;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use tokio::fs;
+use tokio::io::AsyncWriteExt;
 
 use crate::cli::Tier;
+use crate::extract::symbol_diff::{self, SymbolChange, SymbolChangeKind};
 use crate::git::get_git_info;
 
+/// Content-addressed record of one generated report: its filename (relative to
+/// `charter_dir`), byte length, and a hex-encoded SHA-256 of its contents. Every
+/// `write_*` entry point returns one of these so [`manifest_json::write_manifest_json`]
+/// can aggregate them without re-reading the files it just wrote.
+pub struct ArtifactDigest {
+    pub name: &'static str,
+    pub bytes: usize,
+    pub sha256: String,
+}
+
+/// Hashes `buffer` (the in-memory contents a `write_*` function is about to flush to
+/// disk) into an [`ArtifactDigest`] named `name`.
+pub(crate) fn digest_buffer(name: &'static str, buffer: &[u8]) -> ArtifactDigest {
+    let mut hasher = Sha256::new();
+    hasher.update(buffer);
+
+    ArtifactDigest {
+        name,
+        bytes: buffer.len(),
+        sha256: format!("{:x}", hasher.finalize()),
+    }
+}
+
+/// Digests an already-flushed artifact at `path` by reading it back, for the handful of
+/// `write_*` functions that stream straight to a `BufWriter` rather than building the
+/// report in an in-memory buffer first.
+pub(crate) async fn digest_written_file(name: &'static str, path: &Path) -> Result<ArtifactDigest> {
+    let contents = fs::read(path).await?;
+    Ok(digest_buffer(name, &contents))
+}
+
+/// Sibling temp path for an atomic write of `path`, qualified by this process's pid so two
+/// concurrent captures of the same tree don't clobber each other's in-flight temp file. Lives in
+/// the same directory as `path` so the final rename stays on one filesystem (required for it to
+/// be atomic).
+fn atomic_tmp_path(path: &Path) -> PathBuf {
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("output");
+    path.with_file_name(format!(".{file_name}.tmp.{}", std::process::id()))
+}
+
+/// Opens a temp file beside `path` for `write_*` functions that stream a report through a
+/// `BufWriter` with many `write_all` calls rather than building it in one in-memory buffer.
+/// Pair with [`finish_atomic`] once every write is flushed.
+pub(crate) async fn create_atomic(path: &Path) -> Result<(fs::File, PathBuf)> {
+    let tmp_path = atomic_tmp_path(path);
+    let file = fs::File::create(&tmp_path).await?;
+    Ok((file, tmp_path))
+}
+
+/// Fsyncs `file` and renames `tmp_path` over `path` in one syscall, so a reader (or a crash)
+/// mid-regeneration only ever sees the previous complete file or the new one, never a truncated
+/// one. `file` must already be flushed (e.g. via `BufWriter::into_inner` after `flush()`).
+pub(crate) async fn finish_atomic(file: fs::File, tmp_path: &Path, path: &Path) -> Result<()> {
+    file.sync_all().await?;
+    drop(file);
+    fs::rename(tmp_path, path).await?;
+    Ok(())
+}
+
+/// Atomically writes `contents` to `path` in one call, for `write_*` functions that build the
+/// full report in an in-memory buffer before writing it out (see [`create_atomic`] for the
+/// `BufWriter`-streaming equivalent).
+pub(crate) async fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let (mut file, tmp_path) = create_atomic(path).await?;
+    file.write_all(contents).await?;
+    finish_atomic(file, &tmp_path, path).await
+}
+
 pub struct DiffContext {
     pub since_ref: String,
     pub changed_files: HashSet<String>,
     pub added: Vec<String>,
     pub modified: Vec<String>,
     pub deleted: Vec<String>,
+    pub symbol_changes: HashMap<String, Vec<SymbolChange>>,
+    /// Per-file new-file-line-number hunk ranges from [`crate::git::changed_line_ranges`], used
+    /// by [`print_changed_implementations`] to tell which captured function bodies a hunk
+    /// actually touched. Empty if the hunk-level diff itself failed (the whole-file markers above
+    /// still work either way).
+    pub line_ranges: HashMap<String, Vec<(usize, usize)>>,
 }
 
 impl DiffContext {
+    /// Per-symbol marker (`[+]`/`[-]`/`[~]`) for `name` of kind `kind_label` in `path`, or
+    /// `""` if the file wasn't parsed for symbol-level changes (not a modified/deleted Rust
+    /// file) or the symbol itself didn't change.
+    pub fn get_symbol_marker(&self, path: &str, kind_label: &str, name: &str) -> &'static str {
+        find_symbol_marker(&self.symbol_changes, path, kind_label, name)
+    }
+
+    /// Whether `path` was parsed for symbol-level changes at all, so callers can fall back to
+    /// the whole-file marker for paths this context never diffed symbol-by-symbol (added files,
+    /// non-Rust files, files that failed to parse).
+    pub fn has_symbol_diff(&self, path: &str) -> bool {
+        has_symbol_diff_for(&self.symbol_changes, path)
+    }
+
+    /// Symbols removed from `path` (present at `since_ref`, gone from the working tree), for
+    /// call sites that want to surface them even though there's no current line to mark.
+    pub fn removed_symbols(&self, path: &str) -> Vec<&SymbolChange> {
+        removed_symbols_for(&self.symbol_changes, path)
+    }
+
     pub fn get_marker(&self, path: &str) -> &'static str {
         let normalized = path.replace('\\', "/");
         if self
@@ -62,6 +167,108 @@ impl DiffContext {
             .iter()
             .any(|p| normalized.ends_with(p) || p.ends_with(&normalized))
     }
+
+    /// Summary like "3 functions changed, 1 struct added" across every file this context
+    /// was able to diff at the symbol level.
+    pub fn symbol_summary(&self) -> Option<String> {
+        let all: Vec<SymbolChange> = self
+            .symbol_changes
+            .values()
+            .flat_map(|changes| changes.iter().cloned())
+            .collect();
+        symbol_diff::summarize(&all)
+    }
+}
+
+/// Per-symbol marker (`[+]`/`[-]`/`[~]`) for `name` of kind `kind_label` in `path` within
+/// `changes`, matching `path` by suffix (so a relative and an absolute form of the same path
+/// both hit) rather than requiring an exact key. Shared by [`DiffContext::get_symbol_marker`]
+/// and [`StalenessReport::get_symbol_marker`] so the lookup logic can't drift between the two.
+fn find_symbol_marker(
+    changes: &HashMap<String, Vec<SymbolChange>>,
+    path: &str,
+    kind_label: &str,
+    name: &str,
+) -> &'static str {
+    let normalized = path.replace('\\', "/");
+    changes
+        .iter()
+        .find(|(p, _)| normalized.ends_with(p.as_str()) || p.ends_with(&normalized))
+        .and_then(|(_, changes)| {
+            changes
+                .iter()
+                .find(|c| c.kind_label == kind_label && c.name == name)
+        })
+        .map(|c| c.change.marker())
+        .unwrap_or("")
+}
+
+/// Whether `path` was parsed for symbol-level changes at all. Shared by
+/// [`DiffContext::has_symbol_diff`] and [`StalenessReport::has_symbol_diff`].
+fn has_symbol_diff_for(changes: &HashMap<String, Vec<SymbolChange>>, path: &str) -> bool {
+    let normalized = path.replace('\\', "/");
+    changes
+        .keys()
+        .any(|p| normalized.ends_with(p.as_str()) || p.ends_with(&normalized))
+}
+
+/// Symbols removed from `path` (present at the diff's reference point, gone from the working
+/// tree). Shared by [`DiffContext::removed_symbols`] and [`StalenessReport::removed_symbols`].
+fn removed_symbols_for<'a>(
+    changes: &'a HashMap<String, Vec<SymbolChange>>,
+    path: &str,
+) -> Vec<&'a SymbolChange> {
+    let normalized = path.replace('\\', "/");
+    changes
+        .iter()
+        .find(|(p, _)| normalized.ends_with(p.as_str()) || p.ends_with(&normalized))
+        .map(|(_, changes)| {
+            changes
+                .iter()
+                .filter(|c| c.change == SymbolChangeKind::Removed)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Builds per-file symbol-level diffs for `paths` against `git_ref`. Only `.rs` paths that
+/// still exist are considered for the "new" side (a path missing from disk is treated as
+/// fully removed); paths that didn't exist at `git_ref` are skipped here since the whole-file
+/// `[+]` marker already covers brand-new files without needing a symbol breakdown.
+async fn build_symbol_changes(
+    root: &Path,
+    git_ref: &str,
+    paths: impl Iterator<Item = String>,
+) -> HashMap<String, Vec<SymbolChange>> {
+    let mut result = HashMap::new();
+
+    for path in paths {
+        if !path.ends_with(".rs") {
+            continue;
+        }
+
+        let Some(old_content) = crate::git::read_file_at_ref(root, git_ref, &path).await else {
+            continue;
+        };
+        let Ok(old_parsed) = crate::pipeline::parse_rust_file(&old_content, &path) else {
+            continue;
+        };
+
+        let new_parsed = match fs::read_to_string(root.join(&path)).await {
+            Ok(new_content) => match crate::pipeline::parse_rust_file(&new_content, &path) {
+                Ok(parsed) => parsed,
+                Err(_) => continue,
+            },
+            Err(_) => crate::pipeline::ParsedFile::default(),
+        };
+
+        let changes = symbol_diff::diff_symbols(&old_parsed.symbols, &new_parsed.symbols);
+        if !changes.is_empty() {
+            result.insert(path, changes);
+        }
+    }
+
+    result
 }
 
 pub(crate) fn format_qualifiers(is_async: bool, is_unsafe: bool, is_const: bool) -> String {
@@ -131,6 +338,21 @@ pub(crate) fn churn_label(count: u32, high_threshold: u32, med_threshold: u32) -
     }
 }
 
+/// A short bracketed tag for a [`crate::git::GitStatus`], in the same style [`churn_label`] and
+/// [`file_role`] use. [`crate::git::GitStatus::Unmodified`] renders as an empty string so
+/// `manifest.md`'s common case doesn't carry a redundant `[clean]` tag on every line.
+pub(crate) fn git_status_label(status: crate::git::GitStatus) -> &'static str {
+    use crate::git::GitStatus;
+
+    match status {
+        GitStatus::Unmodified => "",
+        GitStatus::Modified => "[modified]",
+        GitStatus::Staged => "[staged]",
+        GitStatus::Untracked => "[untracked]",
+        GitStatus::Ignored => "[ignored]",
+    }
+}
+
 pub async fn lookup(root: &Path, symbol: &str) -> Result<()> {
     let charter_dir = root.join(".charter");
 
@@ -148,6 +370,9 @@ pub async fn lookup(root: &Path, symbol: &str) -> Result<()> {
     let refs_content = fs::read_to_string(charter_dir.join("refs.md"))
         .await
         .unwrap_or_default();
+    let calls_content = fs::read_to_string(charter_dir.join("calls.md"))
+        .await
+        .unwrap_or_default();
     let dependents_content = fs::read_to_string(charter_dir.join("dependents.md"))
         .await
         .unwrap_or_default();
@@ -171,6 +396,11 @@ pub async fn lookup(root: &Path, symbol: &str) -> Result<()> {
 
     let defined_at = results.defined_at.clone();
     find_dependents(&dependents_content, &defined_at, &mut results);
+    find_call_hierarchy(&calls_content, symbol, &mut results);
+
+    if results.found && !defined_at.is_empty() {
+        results.use_path = compute_use_path(root, &defined_at, symbol).await;
+    }
 
     if results.found {
         print_lookup_result(&results);
@@ -204,6 +434,9 @@ struct LookupResult {
     ref_locations: Vec<String>,
     ref_total_files: usize,
     dependent_count: usize,
+    incoming_calls: Vec<String>,
+    outgoing_calls: Vec<String>,
+    use_path: Option<String>,
 }
 
 fn find_symbol_definition(content: &str, symbol: &str, results: &mut LookupResult) {
@@ -455,6 +688,94 @@ fn find_references(content: &str, symbol: &str, results: &mut LookupResult) {
     }
 }
 
+/// Converts a file path into the module path Rust's convention derives from it
+/// (`src/foo/bar.rs` -> `foo::bar`, `src/foo/mod.rs` / `src/foo.rs` -> `foo`,
+/// `src/lib.rs` / `src/main.rs` -> crate root).
+pub(crate) fn module_path_from_file(defined_at: &str) -> String {
+    let relative = defined_at
+        .trim_start_matches("./")
+        .strip_prefix("src/")
+        .unwrap_or(defined_at);
+    let without_ext = relative.trim_end_matches(".rs");
+
+    let segments: Vec<&str> = without_ext
+        .split('/')
+        .filter(|s| !s.is_empty() && *s != "mod")
+        .collect();
+
+    if segments.len() == 1 && (segments[0] == "lib" || segments[0] == "main") {
+        return String::new();
+    }
+
+    segments.join("::")
+}
+
+/// Computes the canonical `use` path for a symbol: `crate_name::module::path::Symbol`,
+/// derived from the defining file's location, then shortened if a `pub use`
+/// re-export surfaces it closer to the crate root.
+async fn compute_use_path(root: &Path, defined_at: &str, symbol: &str) -> Option<String> {
+    let crate_name = read_crate_name(root)
+        .await
+        .unwrap_or_else(|| "crate".to_string());
+    let module_path = module_path_from_file(defined_at);
+
+    let mut canonical = if module_path.is_empty() {
+        format!("{crate_name}::{symbol}")
+    } else {
+        format!("{crate_name}::{module_path}::{symbol}")
+    };
+
+    if let Some(shortest_reexport) = find_shortest_reexport(root, symbol, &crate_name).await {
+        if shortest_reexport.len() < canonical.len() {
+            canonical = shortest_reexport;
+        }
+    }
+
+    Some(canonical)
+}
+
+async fn read_crate_name(root: &Path) -> Option<String> {
+    let content = fs::read_to_string(root.join("Cargo.toml")).await.ok()?;
+    let parsed: toml::Value = content.parse().ok()?;
+    parsed
+        .get("package")?
+        .get("name")?
+        .as_str()
+        .map(|s| s.replace('-', "_"))
+}
+
+/// Scans every file's `pub use` re-exports for one whose source path ends in the
+/// symbol name, and returns the shortest resulting public path found.
+async fn find_shortest_reexport(root: &Path, symbol: &str, crate_name: &str) -> Option<String> {
+    let cache_path = root.join(".charter").join("cache.bin");
+    let cache_data = fs::read(&cache_path).await.ok()?;
+    let cache: crate::cache::Cache = bincode::deserialize(&cache_data).ok()?;
+
+    let mut shortest: Option<String> = None;
+    for (file_path, entry) in &cache.entries {
+        for re_export in &entry.data.parsed.re_exports {
+            let source_name = re_export.source_path.rsplit("::").next().unwrap_or("");
+            if source_name != symbol {
+                continue;
+            }
+            let module_path = module_path_from_file(file_path);
+            let candidate = if module_path.is_empty() {
+                format!("{crate_name}::{symbol}")
+            } else {
+                format!("{crate_name}::{module_path}::{symbol}")
+            };
+            if shortest
+                .as_ref()
+                .map(|s| candidate.len() < s.len())
+                .unwrap_or(true)
+            {
+                shortest = Some(candidate);
+            }
+        }
+    }
+    shortest
+}
+
 fn find_dependents(content: &str, defined_at: &str, results: &mut LookupResult) {
     if defined_at.is_empty() {
         return;
@@ -475,9 +796,64 @@ fn find_dependents(content: &str, defined_at: &str, results: &mut LookupResult)
     }
 }
 
+/// Parses the `## Call Map` lines (`  caller → callee1, callee2`) in `calls.md` to
+/// find the symbol's outgoing calls (as caller) and incoming calls (as callee).
+fn find_call_hierarchy(content: &str, symbol: &str, results: &mut LookupResult) {
+    for line in content.lines() {
+        if !line.starts_with("  ") || line.starts_with("    ") || !line.contains('→') {
+            continue;
+        }
+
+        let Some((caller_part, callees_part)) = line.trim().split_once('→') else {
+            continue;
+        };
+        let caller = caller_part.trim();
+        let callees_part = callees_part
+            .trim()
+            .trim_end_matches(|c: char| c == ']')
+            .split(" [+")
+            .next()
+            .unwrap_or("");
+
+        let callees: Vec<&str> = callees_part.split(", ").map(|s| s.trim()).collect();
+
+        if symbol_matches(caller, symbol) {
+            results
+                .outgoing_calls
+                .extend(callees.iter().map(|c| c.to_string()));
+        }
+
+        for callee in &callees {
+            let callee_name = callee.trim_end_matches(['?']).trim_end_matches(".await");
+            if symbol_matches(callee_name, symbol) {
+                results.incoming_calls.push(caller.to_string());
+            }
+        }
+    }
+
+    results.incoming_calls.sort();
+    results.incoming_calls.dedup();
+    results.outgoing_calls.sort();
+    results.outgoing_calls.dedup();
+}
+
+/// Matches either the bare symbol name or a qualified `Type::symbol` form.
+fn symbol_matches(candidate: &str, symbol: &str) -> bool {
+    candidate == symbol || candidate.ends_with(&format!("::{symbol}"))
+}
+
+/// Symbols within this edit distance of the looked-up name are offered as "did you mean"
+/// suggestions even when they share no substring with it (e.g. a typo'd prefix). Scales with the
+/// query length so a short query like `fo` isn't swamped by every three-letter-away symbol in the
+/// crate, while a longer typo'd name still tolerates a handful of wrong characters.
+pub(crate) fn suggestion_distance_threshold(query_len: usize) -> usize {
+    (query_len / 3).max(2)
+}
+
 fn find_similar_symbols(content: &str, symbol: &str) -> Vec<(String, String, String)> {
     let symbol_lower = symbol.to_lowercase();
-    let mut suggestions: Vec<(String, String, String)> = Vec::new();
+    let max_distance = suggestion_distance_threshold(symbol.len());
+    let mut candidates: Vec<(String, String, String, usize)> = Vec::new();
 
     let mut current_file = String::new();
 
@@ -503,22 +879,67 @@ fn find_similar_symbols(content: &str, symbol: &str) -> Vec<(String, String, Str
                 continue;
             }
 
-            if name.to_lowercase().contains(&symbol_lower) {
-                suggestions.push((name, kind, current_file.clone()));
+            let name_lower = name.to_lowercase();
+            let distance =
+                if name_lower.contains(&symbol_lower) || symbol_lower.contains(&name_lower) {
+                    0
+                } else {
+                    levenshtein_distance(&name_lower, &symbol_lower)
+                };
+
+            if distance <= max_distance {
+                candidates.push((name, kind, current_file.clone(), distance));
             }
         }
     }
 
-    suggestions.sort_by(|a, b| {
-        let a_exact = a.0.to_lowercase() == symbol_lower;
-        let b_exact = b.0.to_lowercase() == symbol_lower;
-        b_exact
-            .cmp(&a_exact)
-            .then_with(|| a.0.len().cmp(&b.0.len()))
-    });
+    candidates.sort_by(|a, b| a.3.cmp(&b.3).then_with(|| a.0.len().cmp(&b.0.len())));
+
+    candidates.dedup_by(|a, b| a.0 == b.0);
+    candidates
+        .into_iter()
+        .map(|(name, kind, file, _)| (name, kind, file))
+        .collect()
+}
+
+/// Minimum single-character insert/delete/substitute edits to turn `a` into `b`, via the standard
+/// Wagner-Fischer DP table.
+pub(crate) fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a_chars: Vec<char> = a.chars().collect();
+    let b_chars: Vec<char> = b.chars().collect();
+    let a_len = a_chars.len();
+    let b_len = b_chars.len();
 
-    suggestions.dedup_by(|a, b| a.0 == b.0);
-    suggestions
+    if a_len == 0 {
+        return b_len;
+    }
+    if b_len == 0 {
+        return a_len;
+    }
+
+    let mut matrix = vec![vec![0usize; b_len + 1]; a_len + 1];
+
+    for (index, row) in matrix.iter_mut().enumerate().take(a_len + 1) {
+        row[0] = index;
+    }
+    for (index, value) in matrix[0].iter_mut().enumerate().take(b_len + 1) {
+        *value = index;
+    }
+
+    for i in 1..=a_len {
+        for j in 1..=b_len {
+            let cost = if a_chars[i - 1] == b_chars[j - 1] {
+                0
+            } else {
+                1
+            };
+            matrix[i][j] = (matrix[i - 1][j] + 1)
+                .min(matrix[i][j - 1] + 1)
+                .min(matrix[i - 1][j - 1] + cost);
+        }
+    }
+
+    matrix[a_len][b_len]
 }
 
 fn extract_symbol_name_and_kind(line: &str) -> (String, String) {
@@ -564,6 +985,9 @@ fn print_lookup_result(results: &LookupResult) {
             "{} [{}] defined at {}",
             results.name, results.kind, results.defined_at
         );
+        if let Some(use_path) = &results.use_path {
+            println!("  use {};", use_path);
+        }
     }
 
     for line in &results.definition_lines {
@@ -627,9 +1051,26 @@ fn print_lookup_result(results: &LookupResult) {
             results.dependent_count, results.defined_at
         );
     }
+
+    if !results.incoming_calls.is_empty() {
+        println!();
+        println!("  Called by: {}", results.incoming_calls.join(", "));
+    }
+
+    if !results.outgoing_calls.is_empty() {
+        println!();
+        println!("  Calls: {}", results.outgoing_calls.join(", "));
+    }
 }
 
-pub async fn peek(root: &Path, tier: Tier, focus: Option<&str>, since: Option<&str>) -> Result<()> {
+pub async fn peek(
+    root: &Path,
+    tier: Tier,
+    focus: Option<&str>,
+    since: Option<&str>,
+    threshold: Option<i64>,
+    show_docs: bool,
+) -> Result<()> {
     let charter_dir = root.join(".charter");
 
     if !charter_dir.exists() {
@@ -637,19 +1078,45 @@ pub async fn peek(root: &Path, tier: Tier, focus: Option<&str>, since: Option<&s
         std::process::exit(1);
     }
 
-    if let Ok(meta) = load_meta(root).await {
+    let staleness = if let Ok(meta) = load_meta(root).await {
         if let Some(ref commit) = meta.git_commit {
-            if let Some(warning) = check_staleness(root, commit).await {
-                println!("{}", warning);
+            let report = check_staleness(root, commit).await;
+            if let Some(ref report) = report {
+                println!("{}", report.warning);
             }
+            report
+        } else {
+            None
         }
-    }
+    } else {
+        None
+    };
 
     let changed_files = if let Some(since_ref) = since {
         match crate::git::get_changed_files(root, since_ref).await {
             Ok(changes) => {
                 let changed_set: std::collections::HashSet<String> =
                     changes.iter().map(|c| c.path.clone()).collect();
+                let modified: Vec<String> = changes
+                    .iter()
+                    .filter(|c| matches!(c.kind, crate::git::FileChangeKind::Modified))
+                    .map(|c| c.path.clone())
+                    .collect();
+                let deleted: Vec<String> = changes
+                    .iter()
+                    .filter(|c| matches!(c.kind, crate::git::FileChangeKind::Deleted))
+                    .map(|c| c.path.clone())
+                    .collect();
+                let symbol_changes = build_symbol_changes(
+                    root,
+                    since_ref,
+                    modified.iter().chain(deleted.iter()).cloned(),
+                )
+                .await;
+                let line_ranges = crate::git::changed_line_ranges(root, since_ref)
+                    .await
+                    .unwrap_or_default();
+
                 Some(DiffContext {
                     since_ref: since_ref.to_string(),
                     changed_files: changed_set,
@@ -658,16 +1125,10 @@ pub async fn peek(root: &Path, tier: Tier, focus: Option<&str>, since: Option<&s
                         .filter(|c| matches!(c.kind, crate::git::FileChangeKind::Added))
                         .map(|c| c.path.clone())
                         .collect(),
-                    modified: changes
-                        .iter()
-                        .filter(|c| matches!(c.kind, crate::git::FileChangeKind::Modified))
-                        .map(|c| c.path.clone())
-                        .collect(),
-                    deleted: changes
-                        .iter()
-                        .filter(|c| matches!(c.kind, crate::git::FileChangeKind::Deleted))
-                        .map(|c| c.path.clone())
-                        .collect(),
+                    modified,
+                    deleted,
+                    symbol_changes,
+                    line_ranges,
                 })
             }
             Err(e) => {
@@ -679,7 +1140,40 @@ pub async fn peek(root: &Path, tier: Tier, focus: Option<&str>, since: Option<&s
         None
     };
 
-    let focus_normalized = focus.map(normalize_focus_path);
+    let impact = if let Some(ref diff) = changed_files {
+        let changed: Vec<String> = diff.changed_files.iter().cloned().collect();
+        crate::targetgraph::analyze_impact(root, &changed).await
+    } else {
+        None
+    };
+
+    if let Some(ref impact) = impact {
+        println!(
+            "Directly changed targets: {}",
+            if impact.directly_changed.is_empty() {
+                "none".to_string()
+            } else {
+                impact.directly_changed.join(", ")
+            }
+        );
+        println!(
+            "Affected downstream targets: {}",
+            if impact.affected_downstream.is_empty() {
+                "none".to_string()
+            } else {
+                impact.affected_downstream.join(", ")
+            }
+        );
+        println!();
+    }
+
+    let focus_normalized = focus.map(normalize_focus_path).or_else(|| {
+        let impact = impact.as_ref()?;
+        match impact.directly_changed.as_slice() {
+            [only] if impact.affected_downstream.is_empty() => Some(only.clone()),
+            _ => None,
+        }
+    });
 
     if let Some(ref focus_path) = focus_normalized {
         check_focus_matches(&charter_dir, focus_path).await;
@@ -712,6 +1206,8 @@ pub async fn peek(root: &Path, tier: Tier, focus: Option<&str>, since: Option<&s
                 &charter_dir.join("symbols.md"),
                 focus_normalized.as_deref(),
                 changed_files.as_ref(),
+                staleness.as_ref(),
+                show_docs,
             )
             .await?;
             println!();
@@ -721,6 +1217,7 @@ pub async fn peek(root: &Path, tier: Tier, focus: Option<&str>, since: Option<&s
             print_filtered_dependents(
                 &charter_dir.join("dependents.md"),
                 focus_normalized.as_deref(),
+                threshold,
             )
             .await?;
         }
@@ -736,6 +1233,8 @@ pub async fn peek(root: &Path, tier: Tier, focus: Option<&str>, since: Option<&s
                 &charter_dir.join("symbols.md"),
                 focus_normalized.as_deref(),
                 changed_files.as_ref(),
+                staleness.as_ref(),
+                show_docs,
             )
             .await?;
             println!();
@@ -745,106 +1244,421 @@ pub async fn peek(root: &Path, tier: Tier, focus: Option<&str>, since: Option<&s
             print_filtered_dependents(
                 &charter_dir.join("dependents.md"),
                 focus_normalized.as_deref(),
+                threshold,
             )
             .await?;
             println!();
             print_filtered_refs(&charter_dir.join("refs.md"), focus_normalized.as_deref()).await?;
             println!();
+            print_filtered_imports(
+                &charter_dir.join("imports.md"),
+                focus_normalized.as_deref(),
+                changed_files.as_ref(),
+                threshold,
+            )
+            .await?;
+            println!();
+            print_filtered_callgraph(
+                &charter_dir.join("callgraph.md"),
+                focus_normalized.as_deref(),
+                changed_files.as_ref(),
+                threshold,
+            )
+            .await?;
+            println!();
             print_filtered_manifest_with_diff(
                 &charter_dir.join("manifest.md"),
                 focus_normalized.as_deref(),
                 changed_files.as_ref(),
+                staleness.as_ref(),
             )
             .await?;
         }
     }
 
-    Ok(())
-}
+    if let Some(diff) = changed_files.as_ref() {
+        print_change_impact(root, diff).await;
+        print_changed_implementations(root, diff).await;
+    }
 
-fn normalize_focus_path(focus: &str) -> String {
-    let normalized = focus.replace('\\', "/");
-    let normalized = normalized.trim_start_matches("./");
-    let normalized = normalized.trim_end_matches('/');
-    normalized.to_string()
+    Ok(())
 }
 
-fn path_matches_focus(path: &str, focus: &str) -> bool {
-    let normalized_path = path.replace('\\', "/");
-    normalized_path.starts_with(focus)
-        || normalized_path.starts_with(&format!("{}/", focus))
-        || normalized_path == focus
+/// One captured function body whose line span overlapped a diff hunk in its file (see
+/// [`changed_implementations_for_file`]).
+struct ChangedImplementation<'a> {
+    file: &'a str,
+    function_name: &'a str,
+    impl_type: Option<&'a str>,
+    line: usize,
+    importance_score: u32,
 }
 
-async fn check_focus_matches(charter_dir: &Path, focus: &str) {
-    let Ok(content) = fs::read_to_string(charter_dir.join("symbols.md")).await else {
+/// "Changed Implementations" section for `Read --since`: intersects every captured body's line
+/// span against the hunk ranges [`crate::git::changed_line_ranges`] reported for its file,
+/// surfacing only the functions a hunk actually touched rather than every function in a changed
+/// file. Reads captured bodies back out of `cache.bin` (the same source [`print_change_impact`]
+/// uses) rather than re-parsing, since a full capture already parsed and cached every file.
+async fn print_changed_implementations(root: &Path, diff: &DiffContext) {
+    if diff.line_ranges.is_empty() {
         return;
-    };
+    }
 
-    let mut all_paths: Vec<String> = Vec::new();
-    let mut matching_paths: Vec<String> = Vec::new();
-    let mut containing_paths: Vec<String> = Vec::new();
+    let cache_path = root.join(".charter").join("cache.bin");
+    let cache = match crate::cache::Cache::load(&cache_path).await {
+        Ok(cache) => cache,
+        Err(_) => return,
+    };
 
-    for line in content.lines() {
-        if line.starts_with(' ') || line.is_empty() || line.starts_with('[') {
+    let mut changed: Vec<ChangedImplementation> = Vec::new();
+    for (path, ranges) in &diff.line_ranges {
+        let Some(entry) = cache.get(path) else {
             continue;
-        }
-
-        let is_file_header = line.contains(".rs [") || line.contains(".rs:");
-        let is_compressed_dir = line.contains("/ [") && line.contains(" files,");
-
-        if is_file_header || is_compressed_dir {
-            let file_path = line.split_whitespace().next().unwrap_or("");
-            if !file_path.is_empty() {
-                all_paths.push(file_path.to_string());
-
-                if path_matches_focus(file_path, focus) {
-                    matching_paths.push(file_path.to_string());
-                } else if file_path.contains(focus) {
-                    containing_paths.push(file_path.to_string());
-                }
-            }
-        }
+        };
+        changed.extend(changed_implementations_for_file(
+            path,
+            &entry.data.parsed.captured_bodies,
+            ranges,
+        ));
     }
 
-    if !matching_paths.is_empty() {
+    if changed.is_empty() {
         return;
     }
 
-    let mut suggestions: Vec<String> = Vec::new();
-    for path in &containing_paths {
-        let focus_pos = path.find(focus).unwrap_or(0);
-        let after_focus = focus_pos + focus.len();
-        let suggestion = if let Some(next_slash) = path[after_focus..].find('/') {
-            format!("{}/", &path[..after_focus + next_slash])
+    // Touched functions are boosted above the static importance sort: a small diff in a
+    // low-importance helper is still more relevant to `--since` than an untouched high-importance
+    // one, so this list is ordered purely by what changed, highest `importance_score` first only
+    // as the tiebreaker among changed functions.
+    changed.sort_by(|a, b| b.importance_score.cmp(&a.importance_score));
+
+    println!();
+    println!(
+        "Changed Implementations since {} ({} function{}):",
+        diff.since_ref,
+        changed.len(),
+        if changed.len() == 1 { "" } else { "s" }
+    );
+    for func in changed.iter().take(25) {
+        let qualified = match func.impl_type {
+            Some(ty) => format!("{}::{}", ty, func.function_name),
+            None => func.function_name.to_string(),
+        };
+        let tag = if diff.added.iter().any(|p| p == func.file) {
+            "add"
         } else {
-            format!("{}/", &path[..after_focus])
+            "modify"
         };
-        if !suggestions.contains(&suggestion) {
-            suggestions.push(suggestion);
-        }
+        println!(
+            "  [{}] {}:{} {} [score={}]",
+            tag, func.file, func.line, qualified, func.importance_score
+        );
+    }
+    if changed.len() > 25 {
+        println!("  ... and {} more", changed.len() - 25);
     }
+}
 
-    if !suggestions.is_empty() {
-        eprintln!(
-            "⚠ Focus path '{}' matched 0 files. Similar paths found:",
-            focus
-        );
-        for suggestion in suggestions.iter().take(5) {
-            eprintln!("  {}", suggestion);
+/// Sweeps `bodies` (sorted by start line) and `ranges` (already sorted by
+/// [`crate::git::changed_line_ranges`]'s hunk-header order, normalized here) once each — a
+/// standard two-pointer interval-overlap merge — to find every body whose `[line, end_line]` span
+/// overlaps at least one hunk range, in O(bodies + ranges) rather than comparing every body
+/// against every range.
+fn changed_implementations_for_file<'a>(
+    path: &'a str,
+    bodies: &'a [crate::pipeline::CapturedBody],
+    ranges: &[(usize, usize)],
+) -> Vec<ChangedImplementation<'a>> {
+    let mut bodies: Vec<&crate::pipeline::CapturedBody> = bodies.iter().collect();
+    bodies.sort_by_key(|body| body.line);
+
+    let mut ranges: Vec<(usize, usize)> = ranges.to_vec();
+    ranges.sort();
+
+    let mut out = Vec::new();
+    let mut range_idx = 0;
+
+    for body in bodies {
+        let end_line = body.line + body_line_span(body).saturating_sub(1);
+
+        while range_idx < ranges.len() && ranges[range_idx].1 < body.line {
+            range_idx += 1;
         }
-        if let Some(first) = suggestions.first() {
-            eprintln!("Try: charter read --focus {}", first);
+
+        let overlaps = ranges[range_idx..]
+            .iter()
+            .take_while(|(start, _)| *start <= end_line)
+            .any(|(start, end)| *start <= end_line && *end >= body.line);
+
+        if overlaps {
+            out.push(ChangedImplementation {
+                file: path,
+                function_name: &body.function_name,
+                impl_type: body.impl_type.as_deref(),
+                line: body.line,
+                importance_score: body.importance_score,
+            });
         }
-        eprintln!();
-    } else {
-        eprintln!(
-            "⚠ Focus path '{}' matched 0 files. No similar paths found.",
-            focus
-        );
+    }
 
-        let mut top_level: std::collections::HashSet<String> = std::collections::HashSet::new();
-        for path in &all_paths {
+    out
+}
+
+/// Approximate line span of a captured body's text, counted from the full text when captured
+/// verbatim or from the summary's line count otherwise — both already reflect the body's actual
+/// extent, just via different capture paths (see [`crate::extract::symbols::FunctionBody`]).
+fn body_line_span(body: &crate::pipeline::CapturedBody) -> usize {
+    if let Some(ref text) = body.body.full_text {
+        text.lines().count().max(1)
+    } else if let Some(ref summary) = body.body.summary {
+        summary.line_count.max(1)
+    } else {
+        1
+    }
+}
+
+async fn print_change_impact(root: &Path, diff: &DiffContext) {
+    let cache_path = root.join(".charter").join("cache.bin");
+    let cache = match crate::cache::Cache::load(&cache_path).await {
+        Ok(cache) => cache,
+        Err(_) => return,
+    };
+
+    let dependents = dependents::dependent_map_from_cache(&cache);
+    let impacted = dependents::propagate_impact(&dependents, &diff.changed_files);
+
+    if impacted.is_empty() {
+        return;
+    }
+
+    println!();
+    println!(
+        "Change impact since {} ({} file{} transitively affected):",
+        diff.since_ref,
+        impacted.len(),
+        if impacted.len() == 1 { "" } else { "s" }
+    );
+    for (file, hops) in impacted.iter().take(25) {
+        println!(
+            "  {} (+{} hop{})",
+            file,
+            hops,
+            if *hops == 1 { "" } else { "s" }
+        );
+    }
+    if impacted.len() > 25 {
+        println!("  ... and {} more", impacted.len() - 25);
+    }
+}
+
+fn normalize_focus_path(focus: &str) -> String {
+    let normalized = focus.replace('\\', "/");
+    let normalized = normalized.trim_start_matches("./");
+    let normalized = normalized.trim_end_matches('/');
+    normalized.to_string()
+}
+
+/// Plain yes/no filtering with no score floor — exact prefixes and substrings still
+/// always pass, since they're also subsequence matches. Callers that rank their output
+/// by score and support `--threshold` should use [`focus_match_score`] instead.
+fn path_matches_focus(path: &str, focus: &str) -> bool {
+    let normalized_path = path.replace('\\', "/");
+    fuzzy_focus_score(&normalized_path, focus).is_some()
+}
+
+/// Score of `candidate` against `focus` if it both subsequence-matches and clears
+/// `min_score`, or `None` otherwise.
+fn focus_match_score(candidate: &str, focus: &str, min_score: i64) -> Option<i64> {
+    let normalized = candidate.replace('\\', "/");
+    let score = fuzzy_focus_score(&normalized, focus)?;
+    (score >= min_score).then_some(score)
+}
+
+const FUZZY_MATCH_BASE: i64 = 1;
+const FUZZY_SEGMENT_BOUNDARY_BONUS: i64 = 10;
+const FUZZY_CAMEL_BOUNDARY_BONUS: i64 = 8;
+const FUZZY_CONTIGUOUS_BONUS: i64 = 5;
+const FUZZY_GAP_PENALTY_PER_CHAR: i64 = 1;
+
+/// Scores `query` as a fuzzy subsequence of `candidate` (case-insensitive), or returns
+/// `None` if `query` doesn't appear as a subsequence at all. Matched characters earn a
+/// bonus for starting a path segment or a camelCase/underscore word boundary, a smaller
+/// bonus for being contiguous with the previous match, and pay a penalty proportional to
+/// the size of any gap since the previous match. Short paths make an O(n^2 * m)
+/// DP over match positions cheap enough to just try every alignment.
+fn fuzzy_focus_score(candidate: &str, query: &str) -> Option<i64> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let cand_chars: Vec<char> = candidate.chars().collect();
+    let query_chars: Vec<char> = query.to_lowercase().chars().collect();
+    let n = cand_chars.len();
+    let m = query_chars.len();
+
+    if m > n {
+        return None;
+    }
+
+    let boundary_bonus = |chars: &[char], i: usize| -> i64 {
+        if i == 0 {
+            return FUZZY_SEGMENT_BOUNDARY_BONUS;
+        }
+        let prev = chars[i - 1];
+        if matches!(prev, '/' | '_' | '-' | '.' | ':') {
+            FUZZY_SEGMENT_BOUNDARY_BONUS
+        } else if prev.is_lowercase() && chars[i].is_uppercase() {
+            FUZZY_CAMEL_BOUNDARY_BONUS
+        } else {
+            0
+        }
+    };
+
+    // dp[j][i] = best score for an alignment of query[0..=j] ending with a match at
+    // candidate index i, or None if query[0..=j] can't end there.
+    let mut dp: Vec<Vec<Option<i64>>> = vec![vec![None; n]; m];
+
+    for (i, &c) in cand_chars.iter().enumerate() {
+        if c.to_lowercase().next() == Some(query_chars[0]) {
+            dp[0][i] = Some(FUZZY_MATCH_BASE + boundary_bonus(&cand_chars, i));
+        }
+    }
+
+    for j in 1..m {
+        for i in 0..n {
+            if cand_chars[i].to_lowercase().next() != Some(query_chars[j]) {
+                continue;
+            }
+            let mut best: Option<i64> = None;
+            for k in 0..i {
+                let Some(prev_score) = dp[j - 1][k] else {
+                    continue;
+                };
+                let gap = i - k - 1;
+                let score = if gap == 0 {
+                    prev_score + FUZZY_CONTIGUOUS_BONUS
+                } else {
+                    prev_score - (gap as i64) * FUZZY_GAP_PENALTY_PER_CHAR
+                } + FUZZY_MATCH_BASE
+                    + boundary_bonus(&cand_chars, i);
+
+                if best.is_none_or(|b| score > b) {
+                    best = Some(score);
+                }
+            }
+            dp[j][i] = best;
+        }
+    }
+
+    dp[m - 1].iter().filter_map(|s| *s).max()
+}
+
+/// Directory prefixes within this edit distance of the requested (but unmatched)
+/// focus path are offered as "did you mean" suggestions (e.g. `src/ecss` -> `src/ecs`).
+const MAX_FOCUS_SUGGESTION_DISTANCE: usize = 3;
+
+/// Falls back to Levenshtein distance over directory prefixes when no path contains
+/// `focus` as a substring, so typos in a focus path still get a useful suggestion.
+fn fuzzy_focus_suggestions(all_paths: &[String], focus: &str) -> Vec<String> {
+    let mut prefixes: std::collections::HashSet<String> = std::collections::HashSet::new();
+    for path in all_paths {
+        let parts: Vec<&str> = path.split('/').collect();
+        for depth in 1..parts.len() {
+            let dir_parts: Vec<&str> = parts[..depth].to_vec();
+            if dir_parts.iter().any(|p| p.ends_with(".rs")) {
+                continue;
+            }
+            prefixes.insert(dir_parts.join("/"));
+        }
+    }
+
+    let mut ranked: Vec<(String, usize)> = prefixes
+        .into_iter()
+        .map(|prefix| {
+            let distance = levenshtein_distance(&prefix, focus);
+            (prefix, distance)
+        })
+        .filter(|(_, distance)| *distance <= MAX_FOCUS_SUGGESTION_DISTANCE)
+        .collect();
+
+    ranked.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.len().cmp(&b.0.len())));
+    ranked.into_iter().map(|(prefix, _)| prefix).collect()
+}
+
+async fn check_focus_matches(charter_dir: &Path, focus: &str) {
+    let Ok(content) = fs::read_to_string(charter_dir.join("symbols.md")).await else {
+        return;
+    };
+
+    let mut all_paths: Vec<String> = Vec::new();
+    let mut matching_paths: Vec<String> = Vec::new();
+    let mut containing_paths: Vec<String> = Vec::new();
+
+    for line in content.lines() {
+        if line.starts_with(' ') || line.is_empty() || line.starts_with('[') {
+            continue;
+        }
+
+        let is_file_header = line.contains(".rs [") || line.contains(".rs:");
+        let is_compressed_dir = line.contains("/ [") && line.contains(" files,");
+
+        if is_file_header || is_compressed_dir {
+            let file_path = line.split_whitespace().next().unwrap_or("");
+            if !file_path.is_empty() {
+                all_paths.push(file_path.to_string());
+
+                if path_matches_focus(file_path, focus) {
+                    matching_paths.push(file_path.to_string());
+                } else if file_path.contains(focus) {
+                    containing_paths.push(file_path.to_string());
+                }
+            }
+        }
+    }
+
+    if !matching_paths.is_empty() {
+        return;
+    }
+
+    let mut suggestions: Vec<String> = Vec::new();
+    for path in &containing_paths {
+        let focus_pos = path.find(focus).unwrap_or(0);
+        let after_focus = focus_pos + focus.len();
+        let suggestion = if let Some(next_slash) = path[after_focus..].find('/') {
+            format!("{}/", &path[..after_focus + next_slash])
+        } else {
+            format!("{}/", &path[..after_focus])
+        };
+        if !suggestions.contains(&suggestion) {
+            suggestions.push(suggestion);
+        }
+    }
+
+    if suggestions.is_empty() {
+        suggestions = fuzzy_focus_suggestions(&all_paths, focus);
+    }
+
+    if !suggestions.is_empty() {
+        eprintln!(
+            "⚠ Focus path '{}' matched 0 files. Similar paths found:",
+            focus
+        );
+        for suggestion in suggestions.iter().take(5) {
+            eprintln!("  {}", suggestion);
+        }
+        if let Some(first) = suggestions.first() {
+            eprintln!("Try: charter read --focus {}", first);
+        }
+        eprintln!();
+    } else {
+        eprintln!(
+            "⚠ Focus path '{}' matched 0 files. No similar paths found.",
+            focus
+        );
+
+        let mut top_level: std::collections::HashSet<String> = std::collections::HashSet::new();
+        for path in &all_paths {
             let parts: Vec<&str> = path.split('/').collect();
             let dir_parts: Vec<&str> = parts
                 .iter()
@@ -1022,6 +1836,9 @@ async fn generate_peek_preamble_with_diff(
                 diff_ctx.modified.len(),
                 diff_ctx.deleted.len()
             ));
+            if let Some(symbol_summary) = diff_ctx.symbol_summary() {
+                lines.push(format!("Symbols: {}", symbol_summary));
+            }
             lines.push("Markers: [+] added, [~] modified, [-] deleted".to_string());
         }
     }
@@ -1468,6 +2285,206 @@ pub async fn stats(root: &Path) -> Result<()> {
         println!("  commit: {}", commit);
     }
 
+    if let Some((path, entry)) = most_recently_active_file(root).await {
+        println!(
+            "  most active: {} ({} author{}, last touched {})",
+            path,
+            entry.distinct_authors,
+            if entry.distinct_authors == 1 { "" } else { "s" },
+            format_unix_timestamp(entry.last_commit_timestamp),
+        );
+    }
+
+    Ok(())
+}
+
+/// Reads `.charter/cache.bin`'s raw recency/authorship signals (see
+/// [`crate::pipeline::apply_recency_and_author_scores`]) and returns whichever file was committed
+/// most recently, for `stats`'s one-line "what's actively evolving" summary. Returns `None` if the
+/// cache is missing or no entry has a recorded commit.
+async fn most_recently_active_file(root: &Path) -> Option<(String, crate::cache::CacheEntry)> {
+    let cache = crate::cache::Cache::load(&root.join(".charter/cache.bin"))
+        .await
+        .ok()?;
+    cache
+        .entries
+        .into_iter()
+        .filter(|(_, entry)| entry.last_commit_timestamp > 0)
+        .max_by_key(|(_, entry)| entry.last_commit_timestamp)
+}
+
+fn format_unix_timestamp(timestamp: i64) -> String {
+    let age_secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+        - timestamp;
+    let age_days = age_secs / 86_400;
+    if age_days <= 0 {
+        "today".to_string()
+    } else if age_days == 1 {
+        "1 day ago".to_string()
+    } else {
+        format!("{} days ago", age_days)
+    }
+}
+
+/// Diffs the `files`/`lines` metrics captured in the current `.charter/meta.json`
+/// against the same file as committed at `since_ref`, for tracking drift across commits.
+pub async fn metrics_diff(root: &Path, since_ref: &str) -> Result<()> {
+    let current = load_meta(root).await?;
+
+    let output = tokio::process::Command::new(crate::git::resolve_executable("git"))
+        .args(["show", &format!("{since_ref}:.charter/meta.json")])
+        .current_dir(root)
+        .output()
+        .await?;
+
+    if !output.status.success() {
+        eprintln!(
+            "Could not read .charter/meta.json at '{}': {}",
+            since_ref,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        std::process::exit(1);
+    }
+
+    let old: Meta = serde_json::from_slice(&output.stdout)?;
+
+    println!("Metrics diff: {} -> HEAD", since_ref);
+    println!(
+        "  files: {} -> {} ({:+})",
+        old.files,
+        current.files,
+        current.files as i64 - old.files as i64
+    );
+    println!(
+        "  lines: {} -> {} ({:+})",
+        old.lines,
+        current.lines,
+        current.lines as i64 - old.lines as i64
+    );
+
+    Ok(())
+}
+
+/// Compares the working tree against `.charter/cache.bin` and reports Added/Modified/Removed/
+/// Skipped files with `hg status`-style one-letter prefixes, without writing `symbols.md` or any
+/// other output — the dry-run behind `charter status --pending`. `glob`, if given, restricts the
+/// report to paths matching it. Returns whether anything was reported, so the caller can honor
+/// `--exit-code`.
+pub async fn pending_changes(root: &Path, glob: Option<&str>) -> Result<bool> {
+    let cache_path = root.join(".charter").join("cache.bin");
+
+    if !cache_path.exists() {
+        eprintln!("No .charter/ directory found. Run 'charter' first.");
+        std::process::exit(1);
+    }
+
+    let cache = crate::cache::Cache::load(&cache_path).await?;
+    let walk_result = crate::pipeline::walk::walk_directory(root).await?;
+    let mut changes = crate::pipeline::diff_against_cache(root, &walk_result.files, &cache).await;
+
+    if let Some(pattern) = glob {
+        let pattern = glob::Pattern::new(pattern)?;
+        changes.retain(|change| pattern.matches(&change.relative_path));
+    }
+
+    if changes.is_empty() {
+        println!("No pending changes.");
+        return Ok(false);
+    }
+
+    for change in &changes {
+        let prefix = match change.status {
+            crate::pipeline::PendingStatus::Added => 'A',
+            crate::pipeline::PendingStatus::Modified => 'M',
+            crate::pipeline::PendingStatus::Removed => 'R',
+            crate::pipeline::PendingStatus::Skipped => 'S',
+        };
+        println!("{} {}", prefix, change.relative_path);
+    }
+
+    Ok(true)
+}
+
+/// Emits the same tiered context as [`peek`], but as a single JSON object instead of
+/// the markdown dump, for piping into other tooling.
+pub async fn peek_json(
+    root: &Path,
+    tier: Tier,
+    focus: Option<&str>,
+    since: Option<&str>,
+) -> Result<()> {
+    let charter_dir = root.join(".charter");
+
+    if !charter_dir.exists() {
+        eprintln!("No .charter/ directory found. Run 'charter' first.");
+        std::process::exit(1);
+    }
+
+    let meta = load_meta(root).await.ok();
+
+    let changed_files = if let Some(since_ref) = since {
+        crate::git::get_changed_files(root, since_ref)
+            .await
+            .ok()
+            .map(|changes| {
+                changes
+                    .into_iter()
+                    .map(|c| {
+                        serde_json::json!({
+                            "path": c.path,
+                            "kind": format!("{:?}", c.kind),
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+    } else {
+        None
+    };
+
+    let tier_name = match tier {
+        Tier::Quick => "quick",
+        Tier::Default => "default",
+        Tier::Full => "full",
+    };
+
+    let mut artifacts = Vec::new();
+    for name in [
+        "overview.md",
+        "symbols.md",
+        "types.md",
+        "refs.md",
+        "calls.md",
+        "dependents.md",
+        "safety.md",
+        "errors.md",
+    ] {
+        let path = charter_dir.join(name);
+        if let Ok(metadata) = tokio::fs::metadata(&path).await {
+            artifacts.push(serde_json::json!({
+                "name": name,
+                "bytes": metadata.len(),
+            }));
+        }
+    }
+
+    let payload = serde_json::json!({
+        "tier": tier_name,
+        "focus": focus,
+        "since": since,
+        "changed_files": changed_files,
+        "meta": meta.map(|m| serde_json::json!({
+            "files": m.files,
+            "lines": m.lines,
+            "git_commit": m.git_commit,
+        })),
+        "artifacts": artifacts,
+    });
+
+    println!("{}", serde_json::to_string_pretty(&payload)?);
+
     Ok(())
 }
 
@@ -1484,10 +2501,61 @@ async fn load_meta(root: &Path) -> Result<Meta> {
     Ok(meta)
 }
 
-async fn check_staleness(root: &Path, captured_commit: &str) -> Option<String> {
+/// How a path has drifted from the `.charter/` snapshot, from worst (landed in history the
+/// snapshot doesn't know about) to lightest (new, never-tracked file sitting in the tree).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaleKind {
+    /// Changed in a commit made after the snapshot was captured.
+    Committed,
+    /// Modified in the working tree but not yet committed.
+    Uncommitted,
+    /// Untracked by git entirely.
+    Untracked,
+}
+
+impl StaleKind {
+    fn marker(self) -> &'static str {
+        match self {
+            StaleKind::Committed => "[stale:commit] ",
+            StaleKind::Uncommitted | StaleKind::Untracked => "[stale:wip] ",
+        }
+    }
+}
+
+pub struct StalenessReport {
+    pub by_path: HashMap<String, StaleKind>,
+    pub warning: String,
+    pub symbol_changes: HashMap<String, Vec<SymbolChange>>,
+}
+
+impl StalenessReport {
+    pub fn marker(&self, path: &str) -> &'static str {
+        let normalized = path.replace('\\', "/");
+        self.by_path
+            .iter()
+            .find(|(p, _)| normalized.ends_with(p.as_str()) || p.ends_with(&normalized))
+            .map(|(_, kind)| kind.marker())
+            .unwrap_or("")
+    }
+
+    pub fn get_symbol_marker(&self, path: &str, kind_label: &str, name: &str) -> &'static str {
+        find_symbol_marker(&self.symbol_changes, path, kind_label, name)
+    }
+
+    pub fn has_symbol_diff(&self, path: &str) -> bool {
+        has_symbol_diff_for(&self.symbol_changes, path)
+    }
+
+    pub fn removed_symbols(&self, path: &str) -> Vec<&SymbolChange> {
+        removed_symbols_for(&self.symbol_changes, path)
+    }
+}
+
+async fn check_staleness(root: &Path, captured_commit: &str) -> Option<StalenessReport> {
     let mut all_changes: Vec<String> = Vec::new();
+    let mut by_path: HashMap<String, StaleKind> = HashMap::new();
 
-    let committed_output = tokio::process::Command::new("git")
+    let committed_output = tokio::process::Command::new(crate::git::resolve_executable("git"))
         .args([
             "diff",
             "--name-status",
@@ -1501,13 +2569,17 @@ async fn check_staleness(root: &Path, captured_commit: &str) -> Option<String> {
     if committed_output.status.success() {
         let diff_output = String::from_utf8_lossy(&committed_output.stdout);
         for line in diff_output.lines() {
-            if !line.is_empty() {
-                all_changes.push(line.to_string());
+            if line.is_empty() {
+                continue;
+            }
+            all_changes.push(line.to_string());
+            if let Some(path) = line.split('\t').nth(1) {
+                by_path.insert(path.to_string(), StaleKind::Committed);
             }
         }
     }
 
-    let uncommitted_output = tokio::process::Command::new("git")
+    let uncommitted_output = tokio::process::Command::new(crate::git::resolve_executable("git"))
         .args(["status", "--porcelain"])
         .current_dir(root)
         .output()
@@ -1536,6 +2608,12 @@ async fn check_staleness(root: &Path, captured_commit: &str) -> Option<String> {
             if !all_changes.contains(&entry) {
                 all_changes.push(entry);
             }
+            let kind = if status_char == '?' {
+                StaleKind::Untracked
+            } else {
+                StaleKind::Uncommitted
+            };
+            by_path.entry(path.to_string()).or_insert(kind);
         }
     }
 
@@ -1543,7 +2621,7 @@ async fn check_staleness(root: &Path, captured_commit: &str) -> Option<String> {
         return None;
     }
 
-    let head_output = tokio::process::Command::new("git")
+    let head_output = tokio::process::Command::new(crate::git::resolve_executable("git"))
         .args(["rev-parse", "--short", "HEAD"])
         .current_dir(root)
         .output()
@@ -1560,13 +2638,29 @@ async fn check_staleness(root: &Path, captured_commit: &str) -> Option<String> {
         format!(" → {}", head_short)
     };
 
-    let mut warning = format!(
-        "⚠ {} file{} changed since capture ({}{}):\n",
-        all_changes.len(),
-        if all_changes.len() == 1 { "" } else { "s" },
-        &captured_commit[..7.min(captured_commit.len())],
-        suffix
-    );
+    let symbol_changes = build_symbol_changes(root, captured_commit, by_path.keys().cloned()).await;
+    let all_symbol_changes: Vec<SymbolChange> = symbol_changes
+        .values()
+        .flat_map(|changes| changes.iter().cloned())
+        .collect();
+
+    let headline = match symbol_diff::summarize(&all_symbol_changes) {
+        Some(summary) => format!(
+            "⚠ {} since capture ({}{}):\n",
+            summary,
+            &captured_commit[..7.min(captured_commit.len())],
+            suffix
+        ),
+        None => format!(
+            "⚠ {} file{} changed since capture ({}{}):\n",
+            all_changes.len(),
+            if all_changes.len() == 1 { "" } else { "s" },
+            &captured_commit[..7.min(captured_commit.len())],
+            suffix
+        ),
+    };
+
+    let mut warning = headline;
 
     for line in all_changes.iter().take(20) {
         warning.push_str(&format!("  {}\n", line));
@@ -1578,7 +2672,11 @@ async fn check_staleness(root: &Path, captured_commit: &str) -> Option<String> {
 
     warning.push_str("\nStructural context below may be inaccurate for these files. Read them directly for current state.\n");
 
-    Some(warning)
+    Some(StalenessReport {
+        by_path,
+        warning,
+        symbol_changes,
+    })
 }
 
 #[allow(dead_code)]
@@ -1780,10 +2878,161 @@ async fn print_filtered_symbols(path: &Path, focus: Option<&str>) -> Result<()>
     Ok(())
 }
 
+/// `    /// <summary>` lines that `output::symbols::write_symbol` appends under each
+/// documented symbol. Stripped out here unless `--docs` was passed.
+fn is_doc_summary_line(line: &str) -> bool {
+    line.starts_with("    /// ")
+}
+
+/// Extracts `(kind_label, name)` from a top-level symbol line as written by
+/// `output::symbols::write_symbol` (exactly two leading spaces, not a nested field/method/impl
+/// line), so call sites can look up its per-symbol diff marker. Returns `None` for anything
+/// else, including the file header line itself.
+fn parse_symbol_line(line: &str) -> Option<(&'static str, String)> {
+    if !line.starts_with("  ") || line.as_bytes().get(2) == Some(&b' ') {
+        return None;
+    }
+    let mut rest = &line[2..];
+    for vis in ["pub(in ...) ", "pub(crate) ", "pub(super) ", "pub "] {
+        if let Some(r) = rest.strip_prefix(vis) {
+            rest = r;
+            break;
+        }
+    }
+
+    let mut fn_rest = rest;
+    while let Some(r) = ["const ", "async ", "unsafe "]
+        .iter()
+        .find_map(|q| fn_rest.strip_prefix(q))
+    {
+        fn_rest = r;
+    }
+    if let Some(after_fn) = fn_rest.strip_prefix("fn ") {
+        let name = after_fn.split(['(', '<']).next().unwrap_or("").trim();
+        if !name.is_empty() {
+            return Some(("function", name.to_string()));
+        }
+    }
+
+    let extract_name = |s: &str| -> Option<String> {
+        let name = s
+            .split([' ', '<', '{', ':'])
+            .next()
+            .unwrap_or("")
+            .trim()
+            .to_string();
+        if name.is_empty() {
+            None
+        } else {
+            Some(name)
+        }
+    };
+
+    if let Some(after) = rest.strip_prefix("struct ") {
+        return extract_name(after).map(|n| ("struct", n));
+    }
+    if let Some(after) = rest.strip_prefix("enum ") {
+        return extract_name(after).map(|n| ("enum", n));
+    }
+    if let Some(after) = rest.strip_prefix("trait ") {
+        return extract_name(after).map(|n| ("trait", n));
+    }
+    if let Some(after) = rest.strip_prefix("const ") {
+        return extract_name(after).map(|n| ("const", n));
+    }
+    if let Some(after) = rest.strip_prefix("static ") {
+        let after = after.strip_prefix("mut ").unwrap_or(after);
+        return extract_name(after).map(|n| ("static", n));
+    }
+    if let Some(after) = rest.strip_prefix("type ") {
+        return extract_name(after).map(|n| ("type alias", n));
+    }
+    if let Some(after) = rest.strip_prefix("mod ") {
+        return extract_name(after).map(|n| ("mod", n));
+    }
+
+    None
+}
+
+/// Prints one file's buffered symbol lines, marking each top-level symbol with its own
+/// `[+]`/`[-]`/`[~]` marker when `file_path` was diffed symbol-by-symbol, falling back to a
+/// single whole-file marker on the header line otherwise (added files, non-Rust files, files
+/// `build_symbol_changes` couldn't parse). Symbols removed since the diff base are appended
+/// after the buffered lines since they have no current line to attach to.
+fn flush_symbol_buffer(
+    buffer: &[String],
+    file_path: &str,
+    diff: Option<&DiffContext>,
+    stale: Option<&StalenessReport>,
+) {
+    if buffer.is_empty() {
+        return;
+    }
+
+    let symbol_diffed = diff.is_some_and(|d| d.has_symbol_diff(file_path))
+        || stale.is_some_and(|s| s.has_symbol_diff(file_path));
+
+    for (index, line) in buffer.iter().enumerate() {
+        if symbol_diffed {
+            if index == 0 {
+                println!("{}", line);
+                continue;
+            }
+            match parse_symbol_line(line) {
+                Some((kind, name)) => {
+                    let marker = format!(
+                        "{}{}",
+                        diff.map_or("", |d| d.get_symbol_marker(file_path, kind, &name)),
+                        stale.map_or("", |s| s.get_symbol_marker(file_path, kind, &name))
+                    );
+                    println!("{}{}", marker, line);
+                }
+                None => println!("{}", line),
+            }
+        } else if index == 0 {
+            let marker = format!(
+                "{}{}",
+                diff.map_or("", |d| d.get_marker(file_path)),
+                stale.map_or("", |s| s.marker(file_path))
+            );
+            println!("{}{}", marker, line);
+        } else {
+            println!("{}", line);
+        }
+    }
+
+    if symbol_diffed {
+        let mut removed: Vec<&SymbolChange> = Vec::new();
+        if let Some(d) = diff {
+            removed.extend(d.removed_symbols(file_path));
+        }
+        if let Some(s) = stale {
+            for change in s.removed_symbols(file_path) {
+                if !removed
+                    .iter()
+                    .any(|c| c.kind_label == change.kind_label && c.name == change.name)
+                {
+                    removed.push(change);
+                }
+            }
+        }
+        for change in removed {
+            println!(
+                "  {}{} {} (removed)",
+                change.change.marker(),
+                change.kind_label,
+                change.name
+            );
+        }
+    }
+}
+
 async fn print_filtered_symbols_with_diff(
     path: &Path,
     focus: Option<&str>,
     diff: Option<&DiffContext>,
+    stale: Option<&StalenessReport>,
+    show_docs: bool,
 ) -> Result<()> {
     if !path.exists() {
         return Ok(());
@@ -1791,7 +3040,7 @@ async fn print_filtered_symbols_with_diff(
 
     let content = fs::read_to_string(path).await?;
 
-    if focus.is_none() && diff.is_none() {
+    if focus.is_none() && diff.is_none() && stale.is_none() && show_docs {
         print_content_without_stamp(&content);
         return Ok(());
     }
@@ -1811,20 +3060,17 @@ async fn print_filtered_symbols_with_diff(
         }
         skip_empty = false;
 
+        if !show_docs && is_doc_summary_line(line) {
+            continue;
+        }
+
         let is_file_header = !line.starts_with(' ')
             && !line.is_empty()
             && (line.contains(".rs [") || line.contains(".rs:"));
 
         if is_file_header {
             if current_file_matches && !buffer.is_empty() {
-                let marker = diff.map_or("", |d| d.get_marker(&current_file_path));
-                for (index, buffered_line) in buffer.iter().enumerate() {
-                    if index == 0 && !marker.is_empty() {
-                        println!("{}{}", marker, buffered_line);
-                    } else {
-                        println!("{}", buffered_line);
-                    }
-                }
+                flush_symbol_buffer(&buffer, &current_file_path, diff, stale);
                 println!();
             }
             buffer.clear();
@@ -1845,14 +3091,7 @@ async fn print_filtered_symbols_with_diff(
     }
 
     if current_file_matches && !buffer.is_empty() {
-        let marker = diff.map_or("", |d| d.get_marker(&current_file_path));
-        for (index, buffered_line) in buffer.iter().enumerate() {
-            if index == 0 && !marker.is_empty() {
-                println!("{}{}", marker, buffered_line);
-            } else {
-                println!("{}", buffered_line);
-            }
-        }
+        flush_symbol_buffer(&buffer, &current_file_path, diff, stale);
     }
 
     Ok(())
@@ -1992,7 +3231,11 @@ async fn collect_types_in_focus_from_symbols(
     types
 }
 
-async fn print_filtered_dependents(path: &Path, focus: Option<&str>) -> Result<()> {
+async fn print_filtered_dependents(
+    path: &Path,
+    focus: Option<&str>,
+    threshold: Option<i64>,
+) -> Result<()> {
     if !path.exists() {
         return Ok(());
     }
@@ -2004,8 +3247,10 @@ async fn print_filtered_dependents(path: &Path, focus: Option<&str>) -> Result<(
         return Ok(());
     };
 
+    let min_score = threshold.unwrap_or(i64::MIN);
     let mut skip_empty = true;
-    let mut header_printed = false;
+    let mut sections: Vec<(i64, String, Vec<String>)> = Vec::new();
+    let mut current: Option<(i64, String, Vec<String>)> = None;
 
     for line in content.lines() {
         if line.starts_with("[charter @") || line.starts_with("[charter |") {
@@ -2022,17 +3267,202 @@ async fn print_filtered_dependents(path: &Path, focus: Option<&str>) -> Result<(
         }
 
         if line.contains(" [") && line.contains(" dependents]") {
+            if let Some(section) = current.take() {
+                sections.push(section);
+            }
+            let file_path = line.split(" [").next().unwrap_or("");
+            if let Some(score) = focus_match_score(file_path, focus, min_score) {
+                current = Some((score, line.to_string(), Vec::new()));
+            }
+        } else if let Some((_, _, body)) = current.as_mut() {
+            if line.starts_with("  ") {
+                body.push(line.to_string());
+            }
+        }
+    }
+    if let Some(section) = current.take() {
+        sections.push(section);
+    }
+
+    sections.sort_by(|a, b| b.0.cmp(&a.0));
+
+    if !sections.is_empty() {
+        println!("# Dependents");
+        println!();
+        for (_, header, body) in sections {
+            println!("{}", header);
+            for line in body {
+                println!("{}", line);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn print_filtered_imports(
+    path: &Path,
+    focus: Option<&str>,
+    diff: Option<&DiffContext>,
+    threshold: Option<i64>,
+) -> Result<()> {
+    if !path.exists() {
+        return Ok(());
+    }
+
+    let content = fs::read_to_string(path).await?;
+
+    if focus.is_none() && diff.is_none() {
+        print_content_without_stamp(&content);
+        return Ok(());
+    }
+
+    let min_score = threshold.unwrap_or(i64::MIN);
+    let mut skip_empty = true;
+    let mut sections: Vec<(i64, String, Vec<String>)> = Vec::new();
+    let mut current: Option<(i64, String, Vec<String>)> = None;
+
+    for line in content.lines() {
+        if line.starts_with("[charter @") || line.starts_with("[charter |") {
+            continue;
+        }
+
+        if skip_empty && line.is_empty() {
+            continue;
+        }
+        skip_empty = false;
+
+        if line.contains(" [") && line.contains(" public symbol") {
+            if let Some(section) = current.take() {
+                sections.push(section);
+            }
             let file_path = line.split(" [").next().unwrap_or("");
-            if path_matches_focus(file_path, focus) {
-                if !header_printed {
-                    println!("# Dependents");
-                    println!();
-                    header_printed = true;
+            let focus_match = match focus {
+                None => Some(0),
+                Some(f) => focus_match_score(file_path, f, min_score),
+            };
+            let diff_match = diff.is_none_or(|d| d.is_changed(file_path));
+
+            if let Some(score) = focus_match {
+                if diff.is_none() || diff_match {
+                    let marker = diff.map_or("", |d| d.get_marker(file_path));
+                    current = Some((score, format!("{}{}", marker, line), Vec::new()));
                 }
+            }
+        } else if let Some((_, _, body)) = current.as_mut() {
+            if line.starts_with("  ") {
+                body.push(line.to_string());
+            }
+        }
+    }
+    if let Some(section) = current.take() {
+        sections.push(section);
+    }
+
+    sections.sort_by(|a, b| b.0.cmp(&a.0));
+
+    if !sections.is_empty() {
+        println!("# Imports");
+        println!();
+        for (_, header, body) in sections {
+            println!("{}", header);
+            for line in body {
+                println!("{}", line);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn print_filtered_callgraph(
+    path: &Path,
+    focus: Option<&str>,
+    diff: Option<&DiffContext>,
+    threshold: Option<i64>,
+) -> Result<()> {
+    if !path.exists() {
+        return Ok(());
+    }
+
+    let content = fs::read_to_string(path).await?;
+
+    if focus.is_none() && diff.is_none() {
+        print_content_without_stamp(&content);
+        return Ok(());
+    }
+
+    let min_score = threshold.unwrap_or(i64::MIN);
+    let mut skip_empty = true;
+    let mut sections: Vec<(i64, String, Vec<String>)> = Vec::new();
+    let mut current: Option<(i64, String, Vec<String>)> = None;
+
+    for line in content.lines() {
+        if line.starts_with("[charter @") || line.starts_with("[charter |") {
+            continue;
+        }
+
+        if skip_empty && line.is_empty() {
+            continue;
+        }
+        skip_empty = false;
+
+        let is_header = !line.starts_with(' ')
+            && !line.is_empty()
+            && line.ends_with(')')
+            && line.contains(" (");
+
+        if is_header {
+            if let Some(section) = current.take() {
+                sections.push(section);
+            }
+
+            let name = line.split(" (").next().unwrap_or("");
+            let file = line
+                .rsplit(" (")
+                .next()
+                .and_then(|s| s.trim_end_matches(')').split(':').next())
+                .unwrap_or("");
+
+            let focus_match = match focus {
+                None => Some(0),
+                Some(f) => {
+                    let file_score = focus_match_score(file, f, min_score);
+                    let name_score = focus_match_score(name, f, min_score);
+                    match (file_score, name_score) {
+                        (Some(a), Some(b)) => Some(a.max(b)),
+                        (Some(a), None) => Some(a),
+                        (None, Some(b)) => Some(b),
+                        (None, None) => None,
+                    }
+                }
+            };
+            let diff_match = diff.is_none_or(|d| d.is_changed(file));
+
+            if let Some(score) = focus_match {
+                if diff.is_none() || diff_match {
+                    let marker = diff.map_or("", |d| d.get_marker(file));
+                    current = Some((score, format!("{}{}", marker, line), Vec::new()));
+                }
+            }
+        } else if let Some((_, _, body)) = current.as_mut() {
+            body.push(line.to_string());
+        }
+    }
+    if let Some(section) = current.take() {
+        sections.push(section);
+    }
+
+    sections.sort_by(|a, b| b.0.cmp(&a.0));
+
+    if !sections.is_empty() {
+        println!("# Call Graph");
+        println!();
+        for (_, header, body) in sections {
+            println!("{}", header);
+            for line in body {
                 println!("{}", line);
             }
-        } else if line.starts_with("  ") && header_printed {
-            println!("{}", line);
         }
     }
 
@@ -2137,6 +3567,7 @@ async fn print_filtered_manifest_with_diff(
     path: &Path,
     focus: Option<&str>,
     diff: Option<&DiffContext>,
+    stale: Option<&StalenessReport>,
 ) -> Result<()> {
     if !path.exists() {
         return Ok(());
@@ -2144,7 +3575,7 @@ async fn print_filtered_manifest_with_diff(
 
     let content = fs::read_to_string(path).await?;
 
-    if focus.is_none() && diff.is_none() {
+    if focus.is_none() && diff.is_none() && stale.is_none() {
         print_content_without_stamp(&content);
         return Ok(());
     }
@@ -2176,7 +3607,11 @@ async fn print_filtered_manifest_with_diff(
                 println!();
                 header_printed = true;
             }
-            let marker = diff.map_or("", |d| d.get_marker(file_path));
+            let marker = format!(
+                "{}{}",
+                diff.map_or("", |d| d.get_marker(file_path)),
+                stale.map_or("", |s| s.marker(file_path))
+            );
             if marker.is_empty() {
                 println!("{}", line);
             } else {
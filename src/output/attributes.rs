@@ -0,0 +1,275 @@
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::path::Path;
+
+use crate::extract::symbols::{SymbolKind, Visibility};
+use crate::output::ArtifactDigest;
+use crate::pipeline::PipelineResult;
+
+/// Derives commonly paired with `Debug`/`Serialize` that a public type missing them might want
+/// — the same shortlist an editor's "add derive" quick-fix would offer, not an exhaustive trait
+/// list.
+const SUGGESTED_PAIRINGS: &[&str] = &["Clone", "PartialEq", "Eq", "Hash", "Default"];
+
+pub async fn write_attributes(
+    charter_dir: &Path,
+    result: &PipelineResult,
+    stamp: &str,
+) -> Result<ArtifactDigest> {
+    let path = charter_dir.join("attributes.md");
+
+    let mut buffer = Vec::with_capacity(32 * 1024);
+
+    writeln!(buffer, "{}", stamp)?;
+    writeln!(buffer)?;
+
+    write_derive_frequency(&mut buffer, result)?;
+    write_derives_by_file(&mut buffer, result)?;
+    write_cfg_conditions(&mut buffer, result)?;
+    write_feature_combinations(&mut buffer, result)?;
+    write_missing_derive_suggestions(&mut buffer, result)?;
+
+    super::write_atomic(&path, &buffer).await?;
+    Ok(super::digest_buffer("attributes.md", &buffer))
+}
+
+fn write_derive_frequency(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+
+    for file_result in &result.files {
+        for derive in &file_result.parsed.derives {
+            for trait_name in &derive.traits {
+                *counts.entry(trait_name.as_str()).or_insert(0) += 1;
+            }
+        }
+    }
+
+    writeln!(buffer, "## Most Derived Traits")?;
+    writeln!(buffer)?;
+
+    if counts.is_empty() {
+        writeln!(buffer, "(none found)")?;
+        writeln!(buffer)?;
+        return Ok(());
+    }
+
+    let mut sorted: Vec<(&str, u32)> = counts.into_iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    for (trait_name, count) in sorted {
+        writeln!(buffer, "- {} ({})", trait_name, count)?;
+    }
+    writeln!(buffer)?;
+
+    Ok(())
+}
+
+fn write_derives_by_file(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
+    writeln!(buffer, "## Derives By File")?;
+    writeln!(buffer)?;
+
+    let mut has_any = false;
+
+    for file_result in &result.files {
+        if file_result.parsed.derives.is_empty() {
+            continue;
+        }
+        has_any = true;
+
+        writeln!(buffer, "### {}", file_result.relative_path)?;
+        writeln!(buffer)?;
+
+        for derive in &file_result.parsed.derives {
+            writeln!(buffer, "- {} - {}", derive.target, derive.traits.join(", "))?;
+        }
+        writeln!(buffer)?;
+    }
+
+    if !has_any {
+        writeln!(buffer, "(none found)")?;
+        writeln!(buffer)?;
+    }
+
+    Ok(())
+}
+
+fn write_cfg_conditions(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+
+    for file_result in &result.files {
+        for cfg in &file_result.parsed.cfgs {
+            *counts.entry(cfg.condition.as_str()).or_insert(0) += 1;
+        }
+    }
+
+    writeln!(buffer, "## Cfg Conditions")?;
+    writeln!(buffer)?;
+
+    if counts.is_empty() {
+        writeln!(buffer, "(none found)")?;
+        writeln!(buffer)?;
+        return Ok(());
+    }
+
+    let mut sorted: Vec<(&str, u32)> = counts.into_iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    for (condition, count) in sorted {
+        writeln!(buffer, "- `{}` ({})", condition, count)?;
+    }
+    writeln!(buffer)?;
+
+    Ok(())
+}
+
+/// For every `cfg` condition that parsed into a predicate referencing at least one
+/// `feature = "..."`, reports the minimal feature sets (against every feature name seen
+/// anywhere in the crate) that turn it on — so `all(feature = "a", not(feature = "b"))` reads
+/// as "needs `a`, without `b`" instead of an opaque condition string, and a `not(feature = ..)`
+/// -only condition reports the empty set, meaning it's active by default.
+fn write_feature_combinations(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
+    let mut all_features = HashSet::new();
+    for file_result in &result.files {
+        for cfg in &file_result.parsed.cfgs {
+            if let Some(predicate) = &cfg.predicate {
+                predicate.collect_feature_names(&mut all_features);
+            }
+        }
+    }
+    let mut features: Vec<String> = all_features.into_iter().collect();
+    features.sort();
+
+    writeln!(buffer, "## Feature Combinations")?;
+    writeln!(buffer)?;
+
+    if features.is_empty() {
+        writeln!(buffer, "(no feature-gated cfgs found)")?;
+        writeln!(buffer)?;
+        return Ok(());
+    }
+
+    let mut has_any = false;
+
+    for file_result in &result.files {
+        let gated: Vec<_> = file_result
+            .parsed
+            .cfgs
+            .iter()
+            .filter_map(|cfg| cfg.predicate.as_ref().map(|predicate| (cfg, predicate)))
+            .filter(|(_, predicate)| {
+                let mut referenced = HashSet::new();
+                predicate.collect_feature_names(&mut referenced);
+                !referenced.is_empty()
+            })
+            .collect();
+
+        if gated.is_empty() {
+            continue;
+        }
+        has_any = true;
+
+        writeln!(buffer, "### {}", file_result.relative_path)?;
+        writeln!(buffer)?;
+
+        for (cfg, predicate) in gated {
+            let combinations = predicate.feature_combinations(&features);
+            let rendered: Vec<String> = combinations
+                .iter()
+                .map(|combo| {
+                    if combo.is_empty() {
+                        "(no features — active by default)".to_string()
+                    } else {
+                        combo.join(" + ")
+                    }
+                })
+                .collect();
+
+            writeln!(
+                buffer,
+                "- line {}: `{}` → {}",
+                cfg.line,
+                cfg.condition,
+                rendered.join(", ")
+            )?;
+        }
+        writeln!(buffer)?;
+    }
+
+    if !has_any {
+        writeln!(buffer, "(no feature-gated cfgs found)")?;
+        writeln!(buffer)?;
+    }
+
+    Ok(())
+}
+
+/// For every public struct/enum that derives `Debug` or `Serialize`, flags any of
+/// `Clone`/`PartialEq`/`Eq`/`Hash`/`Default` it doesn't already derive — the common companion
+/// derives editor "add derive" assists suggest, surfaced here as a repo-wide report instead of
+/// one type at a time.
+fn write_missing_derive_suggestions(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
+    writeln!(buffer, "## Suggested Derives")?;
+    writeln!(buffer)?;
+
+    let mut has_any = false;
+
+    for file_result in &result.files {
+        let mut derives_by_target: HashMap<&str, Vec<&str>> = HashMap::new();
+        for derive in &file_result.parsed.derives {
+            derives_by_target
+                .entry(derive.target.as_str())
+                .or_default()
+                .extend(derive.traits.iter().map(String::as_str));
+        }
+
+        for symbol in &file_result.parsed.symbols.symbols {
+            if symbol.visibility != Visibility::Public {
+                continue;
+            }
+            if !matches!(
+                symbol.kind,
+                SymbolKind::Struct { .. } | SymbolKind::Enum { .. }
+            ) {
+                continue;
+            }
+
+            let Some(traits) = derives_by_target.get(symbol.name.as_str()) else {
+                continue;
+            };
+
+            let has_debug_or_serialize = traits.iter().any(|t| *t == "Debug" || *t == "Serialize");
+            if !has_debug_or_serialize {
+                continue;
+            }
+
+            let missing: Vec<&str> = SUGGESTED_PAIRINGS
+                .iter()
+                .filter(|pairing| !traits.contains(pairing))
+                .copied()
+                .collect();
+
+            if missing.is_empty() {
+                continue;
+            }
+
+            has_any = true;
+            writeln!(
+                buffer,
+                "- {}:{} `{}` derives {} — consider adding {}",
+                file_result.relative_path,
+                symbol.line,
+                symbol.name,
+                traits.join(", "),
+                missing.join(", ")
+            )?;
+        }
+    }
+
+    if !has_any {
+        writeln!(buffer, "(no suggestions)")?;
+        writeln!(buffer)?;
+    }
+
+    Ok(())
+}
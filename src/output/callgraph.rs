@@ -0,0 +1,330 @@
+use anyhow::Result;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::io::Write;
+use std::path::Path;
+
+use super::calls::is_common_utility;
+use crate::output::ArtifactDigest;
+use crate::pipeline::PipelineResult;
+
+struct FunctionEntry {
+    file: String,
+    line: usize,
+    callers: Vec<String>,
+    callees: Vec<String>,
+}
+
+/// Writes `callgraph.md`, a per-function view of both directions of the call graph
+/// (who calls this, what this calls) extending the file-level `dependents.md`/`refs.md`
+/// views down to individual functions, followed by a `## Cycles` section (recursion groups
+/// found via Tarjan's SCC algorithm) and a `## Unreachable` section (functions never reached
+/// from `main`/a public function/a test by a forward call-graph walk).
+pub async fn write_callgraph(
+    charter_dir: &Path,
+    result: &PipelineResult,
+    stamp: &str,
+) -> Result<ArtifactDigest> {
+    let path = charter_dir.join("callgraph.md");
+
+    let mut buffer = Vec::with_capacity(64 * 1024);
+    writeln!(buffer, "{}", stamp)?;
+    writeln!(buffer)?;
+
+    let entries = build_bidirectional_graph(result);
+
+    if entries.is_empty() {
+        writeln!(buffer, "(no function calls detected)")?;
+        super::write_atomic(&path, &buffer).await?;
+        return Ok(super::digest_buffer("callgraph.md", &buffer));
+    }
+
+    let mut sorted: Vec<_> = entries.into_iter().collect();
+    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (name, entry) in sorted {
+        writeln!(buffer, "{} ({}:{})", name, entry.file, entry.line)?;
+        if !entry.callers.is_empty() {
+            writeln!(buffer, "  Callers: {}", entry.callers.join(", "))?;
+        }
+        if !entry.callees.is_empty() {
+            writeln!(buffer, "  Callees: {}", entry.callees.join(", "))?;
+        }
+        writeln!(buffer)?;
+    }
+
+    let forward_graph = build_forward_graph(result);
+
+    let cycles = find_cycles(&forward_graph);
+    if !cycles.is_empty() {
+        writeln!(buffer, "## Cycles")?;
+        writeln!(buffer)?;
+        writeln!(
+            buffer,
+            "Recursion groups found by running Tarjan's strongly-connected-components algorithm over the call graph."
+        )?;
+        writeln!(buffer)?;
+        for cycle in &cycles {
+            let mut chain = cycle.join(" → ");
+            chain.push_str(" → ");
+            chain.push_str(&cycle[0]);
+            writeln!(buffer, "{}", chain)?;
+        }
+        writeln!(buffer)?;
+    }
+
+    let entry_points = collect_entry_points(result);
+    let unreachable = find_unreachable(&forward_graph, &entry_points);
+    if !unreachable.is_empty() {
+        writeln!(buffer, "## Unreachable")?;
+        writeln!(buffer)?;
+        writeln!(
+            buffer,
+            "Functions never reached by a forward walk from `main`, a public function, or a test."
+        )?;
+        writeln!(buffer)?;
+        for name in &unreachable {
+            writeln!(buffer, "{}", name)?;
+        }
+        writeln!(buffer)?;
+    }
+
+    super::write_atomic(&path, &buffer).await?;
+    Ok(super::digest_buffer("callgraph.md", &buffer))
+}
+
+fn build_bidirectional_graph(result: &PipelineResult) -> HashMap<String, FunctionEntry> {
+    let mut entries: HashMap<String, FunctionEntry> = HashMap::new();
+    let mut reverse: HashMap<String, Vec<String>> = HashMap::new();
+
+    for file_result in &result.files {
+        for call_info in &file_result.parsed.call_graph {
+            let caller_name = call_info.caller.qualified_name();
+
+            let mut callees = Vec::new();
+            for edge in &call_info.callees {
+                let mut target = edge.qualified_target();
+                if edge.is_async_call {
+                    target.push_str(".await");
+                }
+                if edge.is_try_call {
+                    target.push('?');
+                }
+                callees.push(target);
+
+                reverse
+                    .entry(edge.qualified_target())
+                    .or_default()
+                    .push(caller_name.clone());
+            }
+
+            let entry = entries.entry(caller_name).or_insert_with(|| FunctionEntry {
+                file: file_result.relative_path.clone(),
+                line: call_info.line,
+                callers: Vec::new(),
+                callees: Vec::new(),
+            });
+            entry.callees.extend(callees);
+        }
+    }
+
+    for (target, callers) in reverse {
+        let entry = entries.entry(target).or_insert_with(|| FunctionEntry {
+            file: String::new(),
+            line: 0,
+            callers: Vec::new(),
+            callees: Vec::new(),
+        });
+        entry.callers.extend(callers);
+    }
+
+    for entry in entries.values_mut() {
+        entry.callers.sort();
+        entry.callers.dedup();
+        entry.callees.sort();
+        entry.callees.dedup();
+    }
+
+    entries
+}
+
+/// Directed caller -> callee edges keyed by `qualified_target()`, for the cycle/reachability
+/// analysis below. Unlike `build_bidirectional_graph`'s display strings, edges here are bare
+/// qualified names (no `.await`/`?` suffix) so graph traversal isn't tripped up by formatting,
+/// and edges into `is_common_utility` names are dropped to match `build_reverse_call_graph`'s
+/// filtering in `calls.rs`.
+fn build_forward_graph(result: &PipelineResult) -> HashMap<String, Vec<String>> {
+    let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+
+    for file_result in &result.files {
+        for call_info in &file_result.parsed.call_graph {
+            let callees = graph.entry(call_info.caller.qualified_name()).or_default();
+
+            for edge in &call_info.callees {
+                let target = edge.qualified_target();
+                if is_common_utility(&target) {
+                    continue;
+                }
+                callees.push(target);
+            }
+        }
+    }
+
+    for callees in graph.values_mut() {
+        callees.sort();
+        callees.dedup();
+    }
+
+    graph
+}
+
+/// Functions treated as entry points for the reachability scan: `main`, anything `pub` (per
+/// `ComplexityMetrics::is_public`), and `#[test]` functions — the same notion of "reachable from
+/// outside this function's own module" that `ComplexityMetrics::importance_score` rewards.
+pub(crate) fn collect_entry_points(result: &PipelineResult) -> HashSet<String> {
+    let mut entries = HashSet::new();
+
+    for file_result in &result.files {
+        for complexity in &file_result.parsed.complexity {
+            if complexity.name != "main"
+                && !complexity.metrics.is_public
+                && !complexity.metrics.is_test
+            {
+                continue;
+            }
+
+            let qualified = match &complexity.impl_type {
+                Some(type_name) => format!("{}::{}", type_name, complexity.name),
+                None => complexity.name.clone(),
+            };
+            entries.insert(qualified);
+        }
+    }
+
+    entries
+}
+
+/// Per-node bookkeeping for [`tarjan_sccs`]'s DFS pass.
+struct TarjanState {
+    index_counter: usize,
+    index: HashMap<String, usize>,
+    lowlink: HashMap<String, usize>,
+    on_stack: HashSet<String>,
+    stack: Vec<String>,
+    sccs: Vec<Vec<String>>,
+}
+
+/// Tarjan's strongly-connected-components algorithm over `graph`: a DFS index counter, an
+/// `index`/`lowlink` map per node, an on-stack set, and an explicit stack of visited-but-unassigned
+/// nodes, popped into an SCC whenever a node's `lowlink` comes back equal to its own `index`.
+fn tarjan_sccs(graph: &HashMap<String, Vec<String>>) -> Vec<Vec<String>> {
+    let mut state = TarjanState {
+        index_counter: 0,
+        index: HashMap::new(),
+        lowlink: HashMap::new(),
+        on_stack: HashSet::new(),
+        stack: Vec::new(),
+        sccs: Vec::new(),
+    };
+
+    let mut nodes: Vec<&String> = graph.keys().collect();
+    nodes.sort();
+
+    for node in nodes {
+        if !state.index.contains_key(node) {
+            strong_connect(node, graph, &mut state);
+        }
+    }
+
+    state.sccs
+}
+
+fn strong_connect(node: &str, graph: &HashMap<String, Vec<String>>, state: &mut TarjanState) {
+    state.index.insert(node.to_string(), state.index_counter);
+    state.lowlink.insert(node.to_string(), state.index_counter);
+    state.index_counter += 1;
+    state.stack.push(node.to_string());
+    state.on_stack.insert(node.to_string());
+
+    if let Some(callees) = graph.get(node) {
+        for callee in callees {
+            if !state.index.contains_key(callee) {
+                strong_connect(callee, graph, state);
+                let lowlink = state.lowlink[node].min(state.lowlink[callee]);
+                state.lowlink.insert(node.to_string(), lowlink);
+            } else if state.on_stack.contains(callee) {
+                let lowlink = state.lowlink[node].min(state.index[callee]);
+                state.lowlink.insert(node.to_string(), lowlink);
+            }
+        }
+    }
+
+    if state.lowlink[node] == state.index[node] {
+        let mut scc = Vec::new();
+        loop {
+            let member = state.stack.pop().expect("node's own SCC is on the stack");
+            state.on_stack.remove(&member);
+            let is_node = member == node;
+            scc.push(member);
+            if is_node {
+                break;
+            }
+        }
+        state.sccs.push(scc);
+    }
+}
+
+/// Recursion groups found via [`tarjan_sccs`]: any SCC with more than one member is mutual
+/// recursion, and a single-member SCC counts too when that function calls itself directly.
+fn find_cycles(graph: &HashMap<String, Vec<String>>) -> Vec<Vec<String>> {
+    let mut cycles: Vec<Vec<String>> = tarjan_sccs(graph)
+        .into_iter()
+        .filter(|scc| {
+            scc.len() > 1
+                || graph
+                    .get(&scc[0])
+                    .is_some_and(|callees| callees.contains(&scc[0]))
+        })
+        .collect();
+
+    for cycle in &mut cycles {
+        cycle.sort();
+    }
+    cycles.sort();
+    cycles
+}
+
+/// Nodes in `graph` never reached by a forward BFS from `entry_points`, i.e. functions that are
+/// neither called transitively from an entry point nor an entry point themselves — candidates
+/// for dead code.
+fn find_unreachable(
+    graph: &HashMap<String, Vec<String>>,
+    entry_points: &HashSet<String>,
+) -> Vec<String> {
+    let mut visited: HashSet<&str> = HashSet::new();
+    let mut queue: VecDeque<&str> = VecDeque::new();
+
+    for entry in entry_points {
+        if visited.insert(entry.as_str()) {
+            queue.push_back(entry.as_str());
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if let Some(callees) = graph.get(node) {
+            for callee in callees {
+                if visited.insert(callee.as_str()) {
+                    queue.push_back(callee.as_str());
+                }
+            }
+        }
+    }
+
+    let mut unreachable: Vec<String> = graph
+        .keys()
+        .filter(|node| !visited.contains(node.as_str()))
+        .cloned()
+        .collect();
+
+    unreachable.sort();
+    unreachable
+}
@@ -4,18 +4,24 @@ use std::path::Path;
 use tokio::io::{AsyncWriteExt, BufWriter};
 
 use crate::extract::calls::CallInfo;
+use crate::output::ArtifactDigest;
 use crate::pipeline::PipelineResult;
 
-struct CallerEntry {
-    caller_name: String,
-    file: String,
-    line: usize,
-    is_async: bool,
-    is_try: bool,
+pub(crate) struct CallerEntry {
+    pub(crate) caller_name: String,
+    pub(crate) file: String,
+    pub(crate) line: usize,
+    pub(crate) is_async: bool,
+    pub(crate) is_try: bool,
 }
 
-pub async fn write_calls(charter_dir: &Path, result: &PipelineResult, stamp: &str) -> Result<()> {
-    let file = tokio::fs::File::create(charter_dir.join("calls.md")).await?;
+pub async fn write_calls(
+    charter_dir: &Path,
+    result: &PipelineResult,
+    stamp: &str,
+) -> Result<ArtifactDigest> {
+    let path = charter_dir.join("calls.md");
+    let (file, tmp_path) = super::create_atomic(&path).await?;
     let mut writer = BufWriter::new(file);
 
     writer.write_all(stamp.as_bytes()).await?;
@@ -32,7 +38,9 @@ pub async fn write_calls(charter_dir: &Path, result: &PipelineResult, stamp: &st
     if all_calls.is_empty() {
         writer.write_all(b"No function calls detected.\n").await?;
         writer.flush().await?;
-        return Ok(());
+        let file = writer.into_inner();
+        super::finish_atomic(file, &tmp_path, &path).await?;
+        return super::digest_written_file("calls.md", &path).await;
     }
 
     let call_counts = compute_call_counts(&all_calls);
@@ -151,7 +159,9 @@ pub async fn write_calls(charter_dir: &Path, result: &PipelineResult, stamp: &st
     }
 
     writer.flush().await?;
-    Ok(())
+    let file = writer.into_inner();
+    super::finish_atomic(file, &tmp_path, &path).await?;
+    super::digest_written_file("calls.md", &path).await
 }
 
 fn compute_call_counts(calls: &[&CallInfo]) -> HashMap<String, u32> {
@@ -167,10 +177,15 @@ fn compute_call_counts(calls: &[&CallInfo]) -> HashMap<String, u32> {
     counts
 }
 
+/// Minimum call count for a function to be listed under `## Hot Paths`. Also used by the
+/// `rules` module's `excessive-callers` lint as the baseline a function's caller count is
+/// compared against.
+pub(crate) const HOT_PATH_MIN_CALLS: u32 = 3;
+
 fn find_hot_paths(call_counts: &HashMap<String, u32>) -> Vec<(String, u32)> {
     let mut hot: Vec<(String, u32)> = call_counts
         .iter()
-        .filter(|(target, count)| **count >= 3 && !is_common_utility(target))
+        .filter(|(target, count)| **count >= HOT_PATH_MIN_CALLS && !is_common_utility(target))
         .map(|(target, count)| (target.clone(), *count))
         .collect();
 
@@ -178,7 +193,7 @@ fn find_hot_paths(call_counts: &HashMap<String, u32>) -> Vec<(String, u32)> {
     hot
 }
 
-fn is_common_utility(name: &str) -> bool {
+pub(crate) fn is_common_utility(name: &str) -> bool {
     const COMMON: &[&str] = &[
         "unwrap",
         "expect",
@@ -247,7 +262,9 @@ fn count_try_calls(calls: &[&CallInfo]) -> usize {
         .count()
 }
 
-fn build_reverse_call_graph(result: &PipelineResult) -> HashMap<String, Vec<CallerEntry>> {
+pub(crate) fn build_reverse_call_graph(
+    result: &PipelineResult,
+) -> HashMap<String, Vec<CallerEntry>> {
     let mut reverse: HashMap<String, Vec<CallerEntry>> = HashMap::new();
 
     for file_result in &result.files {
@@ -0,0 +1,134 @@
+use anyhow::Result;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncWriteExt, BufWriter};
+
+use crate::extract::complexity::FunctionComplexity;
+use crate::git::ChurnStats;
+use crate::output::ArtifactDigest;
+use crate::pipeline::PipelineResult;
+
+struct RiskRow<'a> {
+    file_path: &'a str,
+    func: &'a FunctionComplexity,
+    commits: u32,
+    authors: usize,
+    last_modified: i64,
+    risk: f64,
+}
+
+/// Combines each function's static [`FunctionComplexity`] with `churn_stats` mined by
+/// [`crate::git::get_detailed_churn`] into `risk = importance_score * log2(1 + recent_commits)`,
+/// the classic "hard and frequently edited" refactor-candidate signal that pure complexity
+/// ranking (`hotspots.md`) misses. Every function in a file inherits that file's commit/author
+/// counts, since per-symbol history would need a `git log -L` per function.
+pub async fn write_churn(
+    atlas_dir: &Path,
+    result: &PipelineResult,
+    churn_stats: &HashMap<PathBuf, ChurnStats>,
+    stamp: &str,
+) -> Result<ArtifactDigest> {
+    let path = atlas_dir.join("churn.md");
+    let (file, tmp_path) = super::create_atomic(&path).await?;
+    let mut writer = BufWriter::new(file);
+
+    writer.write_all(stamp.as_bytes()).await?;
+    writer.write_all(b"\n\n").await?;
+    writer.write_all(b"# Churn x Complexity Risk\n\n").await?;
+
+    let mut rows = Vec::new();
+
+    for file_result in &result.files {
+        let Some(stats) = churn_stats.get(&file_result.path) else {
+            continue;
+        };
+
+        if stats.commits == 0 {
+            continue;
+        }
+
+        let churn_multiplier = (1.0 + stats.commits as f64).log2();
+
+        for func in &file_result.parsed.complexity {
+            rows.push(RiskRow {
+                file_path: &file_result.relative_path,
+                func,
+                commits: stats.commits,
+                authors: stats.authors.len(),
+                last_modified: stats.last_modified,
+                risk: func.metrics.importance_score() as f64 * churn_multiplier,
+            });
+        }
+    }
+
+    rows.sort_by(|a, b| b.risk.partial_cmp(&a.risk).unwrap_or(Ordering::Equal));
+
+    if rows.is_empty() {
+        writer
+            .write_all(b"No churn data available (not a git checkout, or no commits in window).\n")
+            .await?;
+        writer.flush().await?;
+        let file = writer.into_inner();
+        super::finish_atomic(file, &tmp_path, &path).await?;
+        return super::digest_written_file("churn.md", &path).await;
+    }
+
+    writer
+        .write_all(
+            b"| File:Line | Symbol | Complexity | Commits | Authors | Last Modified | Risk |\n",
+        )
+        .await?;
+    writer
+        .write_all(b"|---|---|---|---|---|---|---|\n")
+        .await?;
+
+    for row in rows.iter().take(100) {
+        writer
+            .write_all(
+                format!(
+                    "| {}:{} | {} | {} | {} | {} | {} | {:.1} |\n",
+                    row.file_path,
+                    row.func.line,
+                    row.func.qualified_name(),
+                    row.func.metrics.importance_score(),
+                    row.commits,
+                    row.authors,
+                    format_last_modified(row.last_modified),
+                    row.risk
+                )
+                .as_bytes(),
+            )
+            .await?;
+    }
+
+    if rows.len() > 100 {
+        writer
+            .write_all(format!("\n[+{} more]\n", rows.len() - 100).as_bytes())
+            .await?;
+    }
+
+    writer.write_all(b"\n## Scoring\n\n").await?;
+    writer
+        .write_all(
+            b"Risk = complexity importance score * log2(1 + recent_commits), where recent_commits \
+counts commits to the file in the last 180 days. Surfaces code that is both hard and frequently \
+edited \u{2014} the classic refactor-candidate signal pure complexity ranking misses.\n",
+        )
+        .await?;
+
+    writer.flush().await?;
+    let file = writer.into_inner();
+    super::finish_atomic(file, &tmp_path, &path).await?;
+    super::digest_written_file("churn.md", &path).await
+}
+
+fn format_last_modified(timestamp: i64) -> String {
+    if timestamp == 0 {
+        return "unknown".to_string();
+    }
+
+    chrono::DateTime::from_timestamp(timestamp, 0)
+        .map(|dt| dt.format("%Y-%m-%d").to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
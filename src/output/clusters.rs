@@ -1,8 +1,10 @@
 use anyhow::Result;
+use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use tokio::io::{AsyncWriteExt, BufWriter};
 
+use crate::output::ArtifactDigest;
 use crate::pipeline::PipelineResult;
 
 struct FunctionInfo {
@@ -25,8 +27,9 @@ pub async fn write_clusters(
     charter_dir: &Path,
     result: &PipelineResult,
     stamp: &str,
-) -> Result<()> {
-    let file = tokio::fs::File::create(charter_dir.join("clusters.md")).await?;
+) -> Result<ArtifactDigest> {
+    let path = charter_dir.join("clusters.md");
+    let (file, tmp_path) = super::create_atomic(&path).await?;
     let mut writer = BufWriter::new(file);
 
     writer.write_all(stamp.as_bytes()).await?;
@@ -40,7 +43,9 @@ pub async fn write_clusters(
     if functions.is_empty() {
         writer.write_all(b"No functions detected.\n").await?;
         writer.flush().await?;
-        return Ok(());
+        let file = writer.into_inner();
+        super::finish_atomic(file, &tmp_path, &path).await?;
+        return super::digest_written_file("clusters.md", &path).await;
     }
 
     let call_graph = build_call_adjacency(result);
@@ -57,7 +62,9 @@ pub async fn write_clusters(
             .write_all(b"No significant clusters detected (minimum 3 functions required).\n")
             .await?;
         writer.flush().await?;
-        return Ok(());
+        let file = writer.into_inner();
+        super::finish_atomic(file, &tmp_path, &path).await?;
+        return super::digest_written_file("clusters.md", &path).await;
     }
 
     for (index, cluster) in significant_clusters.iter().take(20).enumerate() {
@@ -105,7 +112,9 @@ pub async fn write_clusters(
     }
 
     writer.flush().await?;
-    Ok(())
+    let file = writer.into_inner();
+    super::finish_atomic(file, &tmp_path, &path).await?;
+    super::digest_written_file("clusters.md", &path).await
 }
 
 fn collect_functions(result: &PipelineResult) -> Vec<FunctionInfo> {
@@ -165,7 +174,7 @@ fn parse_signature_types(signature: &str) -> (Option<String>, Vec<String>) {
     let return_type = if let Some(arrow_pos) = signature.rfind("->") {
         let ret = signature[arrow_pos + 2..].trim();
         if !ret.is_empty() && ret != "()" {
-            Some(extract_base_type_from_str(ret))
+            Some(normalize_type_str(ret))
         } else {
             None
         }
@@ -184,7 +193,7 @@ fn parse_signature_types(signature: &str) -> (Option<String>, Vec<String>) {
                 }
                 if let Some(colon_pos) = param.find(':') {
                     let type_part = param[colon_pos + 1..].trim();
-                    param_types.push(extract_base_type_from_str(type_part));
+                    param_types.push(normalize_type_str(type_part));
                 }
             }
         }
@@ -193,18 +202,16 @@ fn parse_signature_types(signature: &str) -> (Option<String>, Vec<String>) {
     (return_type, param_types)
 }
 
-fn extract_base_type_from_str(type_str: &str) -> String {
-    let trimmed = type_str
+/// Strips reference/mutability/lifetime decoration from a type fragment but, unlike the old
+/// `extract_base_type_from_str`, keeps any generic argument list intact so later affinity
+/// scoring can unify nested instantiations instead of only ever seeing the head symbol.
+fn normalize_type_str(type_str: &str) -> String {
+    type_str
         .trim_start_matches('&')
         .trim_start_matches("mut ")
         .trim_start_matches("'static ")
-        .trim_start_matches("'_ ");
-
-    if let Some(generic_start) = trimmed.find('<') {
-        trimmed[..generic_start].to_string()
-    } else {
-        trimmed.to_string()
-    }
+        .trim_start_matches("'_ ")
+        .to_string()
 }
 
 fn build_call_adjacency(result: &PipelineResult) -> HashMap<String, HashSet<String>> {
@@ -242,63 +249,85 @@ fn extract_crate_module(file_path: &str) -> &str {
     path
 }
 
+/// Below this many functions, spinning up rayon's thread pool costs more than the sequential
+/// double loop it would replace, so small workspaces just run on the calling thread.
+const PARALLEL_AFFINITY_THRESHOLD: usize = 64;
+
 fn compute_affinity_matrix(
     functions: &[FunctionInfo],
     call_graph: &HashMap<String, HashSet<String>>,
 ) -> Vec<Vec<i32>> {
     let len = functions.len();
-    let mut affinity = vec![vec![0i32; len]; len];
-
-    for index_a in 0..len {
-        for index_b in (index_a + 1)..len {
-            let func_a = &functions[index_a];
-            let func_b = &functions[index_b];
 
-            let mut score = 0i32;
-
-            let same_crate =
-                extract_crate_module(&func_a.file) == extract_crate_module(&func_b.file);
-            let same_file = func_a.file == func_b.file;
-
-            if func_a.impl_type.is_some() && func_a.impl_type == func_b.impl_type {
-                if same_file {
-                    score += 15;
-                } else if same_crate {
-                    score += 5;
-                }
+    let build_row = |index_a: usize| -> Vec<i32> {
+        let mut row = vec![0i32; len];
+        for index_b in 0..len {
+            if index_b != index_a {
+                row[index_b] = score_pair(functions, call_graph, index_a, index_b);
             }
+        }
+        row
+    };
 
-            let name_a = qualified_name(func_a);
-            let name_b = qualified_name(func_b);
-
-            if let Some(targets) = call_graph.get(&name_a) {
-                if targets.contains(&name_b) {
-                    score += 5;
-                }
-            }
-            if let Some(targets) = call_graph.get(&name_b) {
-                if targets.contains(&name_a) {
-                    score += 5;
-                }
-            }
+    if len >= PARALLEL_AFFINITY_THRESHOLD {
+        (0..len).into_par_iter().map(build_row).collect()
+    } else {
+        (0..len).map(build_row).collect()
+    }
+}
 
-            if same_file {
-                score += 5;
-            } else if same_crate {
-                score += 2;
-            } else {
-                score -= 3;
-            }
+/// Computes the affinity score for an unordered pair of functions. Symmetric by construction
+/// (`score_pair(a, b) == score_pair(b, a)`), so each row of [`compute_affinity_matrix`] can be
+/// built independently from the immutable `functions` slice and `call_graph`, which is what
+/// makes the per-row parallelization above sound.
+fn score_pair(
+    functions: &[FunctionInfo],
+    call_graph: &HashMap<String, HashSet<String>>,
+    index_a: usize,
+    index_b: usize,
+) -> i32 {
+    let func_a = &functions[index_a];
+    let func_b = &functions[index_b];
+
+    let mut score = 0i32;
+
+    let same_crate = extract_crate_module(&func_a.file) == extract_crate_module(&func_b.file);
+    let same_file = func_a.file == func_b.file;
+
+    if func_a.impl_type.is_some() && func_a.impl_type == func_b.impl_type {
+        if same_file {
+            score += 15;
+        } else if same_crate {
+            score += 5;
+        }
+    }
 
-            let shared_types = count_shared_types(func_a, func_b);
-            score += (shared_types * 2) as i32;
+    let name_a = qualified_name(func_a);
+    let name_b = qualified_name(func_b);
 
-            affinity[index_a][index_b] = score;
-            affinity[index_b][index_a] = score;
+    if let Some(targets) = call_graph.get(&name_a) {
+        if targets.contains(&name_b) {
+            score += 5;
+        }
+    }
+    if let Some(targets) = call_graph.get(&name_b) {
+        if targets.contains(&name_a) {
+            score += 5;
         }
     }
 
-    affinity
+    if same_file {
+        score += 5;
+    } else if same_crate {
+        score += 2;
+    } else {
+        score -= 3;
+    }
+
+    let shared_types = count_shared_types(func_a, func_b);
+    score += (shared_types * 2) as i32;
+
+    score
 }
 
 fn qualified_name(func: &FunctionInfo) -> String {
@@ -309,29 +338,103 @@ fn qualified_name(func: &FunctionInfo) -> String {
 }
 
 fn count_shared_types(func_a: &FunctionInfo, func_b: &FunctionInfo) -> usize {
-    let mut count = 0;
-
-    let types_a: HashSet<&str> = func_a
+    let types_a: Vec<&str> = func_a
         .param_types
         .iter()
         .map(|s| s.as_str())
         .chain(func_a.return_type.as_deref())
         .collect();
 
-    let types_b: HashSet<&str> = func_b
+    let types_b: Vec<&str> = func_b
         .param_types
         .iter()
         .map(|s| s.as_str())
         .chain(func_b.return_type.as_deref())
         .collect();
 
-    for type_a in &types_a {
-        if !is_common_type(type_a) && types_b.contains(type_a) {
-            count += 1;
+    let trees_a: Vec<TypeTree> = types_a.iter().map(|t| parse_type_tree(t)).collect();
+    let trees_b: Vec<TypeTree> = types_b.iter().map(|t| parse_type_tree(t)).collect();
+
+    let mut score = 0usize;
+    for tree_a in &trees_a {
+        for tree_b in &trees_b {
+            score += unify_type_trees(tree_a, tree_b, 0);
         }
     }
 
-    count
+    score
+}
+
+/// A parsed type fragment: the head symbol plus its generic arguments in source order, e.g.
+/// `HashMap<String, Widget>` becomes `head = "HashMap"`, `args = [String, Widget]`.
+struct TypeTree {
+    head: String,
+    args: Vec<TypeTree>,
+}
+
+/// Parses a type fragment into a [`TypeTree`] by matching the outermost pair of angle brackets
+/// and recursively parsing each comma-separated argument (splitting only at nesting depth zero,
+/// so `Result<Vec<A>, B>` yields the two top-level arguments `Vec<A>` and `B`).
+fn parse_type_tree(type_str: &str) -> TypeTree {
+    let trimmed = type_str.trim();
+
+    if let (Some(generic_start), true) = (trimmed.find('<'), trimmed.ends_with('>')) {
+        let head = trimmed[..generic_start].trim().to_string();
+        let inner = &trimmed[generic_start + 1..trimmed.len() - 1];
+        let args = split_type_args(inner)
+            .into_iter()
+            .map(parse_type_tree)
+            .collect();
+        return TypeTree { head, args };
+    }
+
+    TypeTree {
+        head: trimmed.to_string(),
+        args: Vec::new(),
+    }
+}
+
+/// Splits a generic argument list on top-level commas, treating commas nested inside `<...>` as
+/// part of the surrounding argument rather than a separator.
+fn split_type_args(inner: &str) -> Vec<&str> {
+    let mut args = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+
+    for (index, ch) in inner.char_indices() {
+        match ch {
+            '<' => depth += 1,
+            '>' => depth -= 1,
+            ',' if depth == 0 => {
+                args.push(inner[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    args.push(inner[start..].trim());
+
+    args
+}
+
+/// Unifies two type trees position-by-position: a match at the root or at any nested argument
+/// position contributes `depth + 1` to the score (so a shared type nested two levels deep counts
+/// for more than a shallow one), skipping common container/primitive heads so that e.g. two
+/// `Vec<_>` parameters don't score purely for both being vectors. Arguments are compared pairwise
+/// by position rather than by set membership, so a mismatch in one position (differing container,
+/// differing key type) does not prevent a match in another position from being counted.
+fn unify_type_trees(a: &TypeTree, b: &TypeTree, depth: usize) -> usize {
+    let mut score = 0usize;
+
+    if a.head == b.head && !is_common_type(&a.head) {
+        score += depth + 1;
+    }
+
+    for (arg_a, arg_b) in a.args.iter().zip(b.args.iter()) {
+        score += unify_type_trees(arg_a, arg_b, depth + 1);
+    }
+
+    score
 }
 
 fn is_common_type(type_name: &str) -> bool {
@@ -383,84 +486,241 @@ fn is_common_type(type_name: &str) -> bool {
 }
 
 fn cluster_functions(functions: &[FunctionInfo], affinity: &[Vec<i32>]) -> Vec<Cluster> {
-    let len = functions.len();
-    let mut cluster_id: Vec<Option<usize>> = vec![None; len];
-    let mut clusters: Vec<Cluster> = Vec::new();
+    let membership = louvain_communities(affinity);
 
-    const THRESHOLD: i32 = 10;
-    const MAX_CLUSTER_SIZE: usize = 100;
+    let mut groups: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (index, &community) in membership.iter().enumerate() {
+        groups.entry(community).or_default().push(index);
+    }
 
-    let mut pairs: Vec<(usize, usize, i32)> = Vec::new();
-    for (index_a, row) in affinity.iter().enumerate() {
-        for (index_b, &score) in row.iter().enumerate().skip(index_a + 1) {
-            if score >= THRESHOLD {
-                pairs.push((index_a, index_b, score));
+    let mut clusters: Vec<Cluster> = groups
+        .into_values()
+        .map(|members| Cluster {
+            functions: members,
+            label: String::new(),
+            dominant_file: None,
+            dominant_impl: None,
+        })
+        .collect();
+
+    let token_counts: Vec<HashMap<String, usize>> = clusters
+        .iter()
+        .map(|cluster| cluster_token_counts(functions, cluster))
+        .collect();
+    let corpus_df = build_corpus_document_frequency(&token_counts);
+    let n_clusters = clusters.len();
+
+    for (index, cluster) in clusters.iter_mut().enumerate() {
+        let (dominant_file, dominant_impl) = find_dominant_attributes(functions, cluster);
+        cluster.dominant_file = dominant_file.clone();
+        cluster.dominant_impl = dominant_impl.clone();
+        cluster.label = generate_cluster_label(
+            functions,
+            cluster,
+            &dominant_file,
+            &dominant_impl,
+            &token_counts[index],
+            &corpus_df,
+            n_clusters,
+        );
+    }
+
+    clusters
+}
+
+/// An undirected weighted graph for Louvain community detection. `neighbors[i]` holds `(j, w)`
+/// pairs for every other node `i` shares positive affinity with (both directions present);
+/// `self_loops[i]` folds in the weight of edges that have already been absorbed into node `i` by
+/// a prior aggregation pass (phase two of Louvain).
+struct LouvainGraph {
+    n: usize,
+    neighbors: Vec<Vec<(usize, f64)>>,
+    self_loops: Vec<f64>,
+}
+
+/// Builds the initial Louvain graph from `compute_affinity_matrix`'s output, keeping only
+/// positive scores as edge weights (per the request, negative/zero affinity scores carry no
+/// community signal).
+fn build_affinity_graph(affinity: &[Vec<i32>]) -> LouvainGraph {
+    let n = affinity.len();
+    let mut neighbors = vec![Vec::new(); n];
+
+    for (i, row) in affinity.iter().enumerate() {
+        for (j, &score) in row.iter().enumerate() {
+            if i != j && score > 0 {
+                neighbors[i].push((j, score as f64));
             }
         }
     }
 
-    pairs.sort_by(|a, b| b.2.cmp(&a.2));
+    LouvainGraph {
+        n,
+        neighbors,
+        self_loops: vec![0.0; n],
+    }
+}
 
-    for (index_a, index_b, _score) in pairs {
-        match (cluster_id[index_a], cluster_id[index_b]) {
-            (None, None) => {
-                let id = clusters.len();
-                cluster_id[index_a] = Some(id);
-                cluster_id[index_b] = Some(id);
-                clusters.push(Cluster {
-                    functions: vec![index_a, index_b],
-                    label: String::new(),
-                    dominant_file: None,
-                    dominant_impl: None,
-                });
+/// Weighted degree `k_i = Σ_j w_ij`, counting a self-loop twice since it represents weight
+/// already folded in from both endpoints of the edges it absorbed.
+fn weighted_degree(graph: &LouvainGraph, node: usize) -> f64 {
+    graph.neighbors[node].iter().map(|&(_, w)| w).sum::<f64>() + 2.0 * graph.self_loops[node]
+}
+
+/// Total graph edge weight `m = ½ Σ w_ij`, i.e. every off-diagonal edge counted once plus every
+/// self-loop counted once (so that `Σ_i k_i == 2m`).
+fn total_edge_weight(graph: &LouvainGraph) -> f64 {
+    let mut m = 0.0;
+    for (i, edges) in graph.neighbors.iter().enumerate() {
+        for &(j, w) in edges {
+            if j > i {
+                m += w;
             }
-            (Some(id), None) => {
-                if clusters[id].functions.len() < MAX_CLUSTER_SIZE {
-                    cluster_id[index_b] = Some(id);
-                    clusters[id].functions.push(index_b);
-                }
+        }
+        m += graph.self_loops[i];
+    }
+    m
+}
+
+/// Phase one of Louvain: starting with every node in its own community, repeatedly moves each
+/// node into whichever neighboring community maximizes `k_i,in(C) - Σ_tot(C)·k_i / (2m)` — the
+/// modularity gain `ΔQ` from the request, with the terms that don't depend on the candidate
+/// community (and so cancel out of the comparison) dropped. Iterates to a fixpoint where no move
+/// improves on staying put.
+fn louvain_phase_one(graph: &LouvainGraph) -> Vec<usize> {
+    let n = graph.n;
+    let degrees: Vec<f64> = (0..n).map(|node| weighted_degree(graph, node)).collect();
+    let m = total_edge_weight(graph);
+
+    let mut community: Vec<usize> = (0..n).collect();
+
+    if m <= 0.0 {
+        return community;
+    }
+
+    let mut community_tot: HashMap<usize, f64> = (0..n).map(|node| (node, degrees[node])).collect();
+
+    let mut improved = true;
+    while improved {
+        improved = false;
+
+        for node in 0..n {
+            let current_comm = community[node];
+
+            let mut neighbor_weights: HashMap<usize, f64> = HashMap::new();
+            for &(other, w) in &graph.neighbors[node] {
+                *neighbor_weights.entry(community[other]).or_insert(0.0) += w;
             }
-            (None, Some(id)) => {
-                if clusters[id].functions.len() < MAX_CLUSTER_SIZE {
-                    cluster_id[index_a] = Some(id);
-                    clusters[id].functions.push(index_a);
+
+            *community_tot.entry(current_comm).or_insert(0.0) -= degrees[node];
+
+            let gain_of = |comm: usize, tot: &HashMap<usize, f64>| {
+                let k_in = neighbor_weights.get(&comm).copied().unwrap_or(0.0);
+                let sigma_tot = tot.get(&comm).copied().unwrap_or(0.0);
+                k_in - sigma_tot * degrees[node] / (2.0 * m)
+            };
+
+            let mut best_comm = current_comm;
+            let mut best_gain = gain_of(current_comm, &community_tot);
+
+            for &candidate in neighbor_weights.keys() {
+                if candidate == current_comm {
+                    continue;
                 }
-            }
-            (Some(id_a), Some(id_b)) if id_a != id_b => {
-                let combined_size = clusters[id_a].functions.len() + clusters[id_b].functions.len();
-                if combined_size <= MAX_CLUSTER_SIZE {
-                    if clusters[id_a].functions.len() >= clusters[id_b].functions.len() {
-                        for &func_idx in &clusters[id_b].functions.clone() {
-                            cluster_id[func_idx] = Some(id_a);
-                            clusters[id_a].functions.push(func_idx);
-                        }
-                        clusters[id_b].functions.clear();
-                    } else {
-                        for &func_idx in &clusters[id_a].functions.clone() {
-                            cluster_id[func_idx] = Some(id_b);
-                            clusters[id_b].functions.push(func_idx);
-                        }
-                        clusters[id_a].functions.clear();
-                    }
+                let gain = gain_of(candidate, &community_tot);
+                if gain > best_gain {
+                    best_gain = gain;
+                    best_comm = candidate;
                 }
             }
-            _ => {}
+
+            *community_tot.entry(best_comm).or_insert(0.0) += degrees[node];
+            community[node] = best_comm;
+
+            if best_comm != current_comm {
+                improved = true;
+            }
         }
     }
 
-    for cluster in &mut clusters {
-        if cluster.functions.is_empty() {
-            continue;
+    community
+}
+
+/// Phase two of Louvain: collapses each community from `community` into a single super-node,
+/// summing inter-community edge weights and folding intra-community edge weight into that
+/// super-node's self-loop.
+fn aggregate_graph(
+    graph: &LouvainGraph,
+    community: &[usize],
+    remap: &HashMap<usize, usize>,
+) -> LouvainGraph {
+    let new_n = remap.len();
+    let mut neighbor_weights: Vec<HashMap<usize, f64>> = vec![HashMap::new(); new_n];
+    let mut self_loops = vec![0.0; new_n];
+
+    for (i, edges) in graph.neighbors.iter().enumerate() {
+        let community_i = remap[&community[i]];
+        self_loops[community_i] += graph.self_loops[i];
+
+        for &(j, w) in edges {
+            if j <= i {
+                continue;
+            }
+            let community_j = remap[&community[j]];
+            if community_i == community_j {
+                self_loops[community_i] += w;
+            } else {
+                *neighbor_weights[community_i]
+                    .entry(community_j)
+                    .or_insert(0.0) += w;
+                *neighbor_weights[community_j]
+                    .entry(community_i)
+                    .or_insert(0.0) += w;
+            }
         }
+    }
 
-        let (dominant_file, dominant_impl) = find_dominant_attributes(functions, cluster);
-        cluster.dominant_file = dominant_file.clone();
-        cluster.dominant_impl = dominant_impl.clone();
-        cluster.label = generate_cluster_label(functions, cluster, &dominant_file, &dominant_impl);
+    let neighbors = neighbor_weights
+        .into_iter()
+        .map(|weights| weights.into_iter().collect())
+        .collect();
+
+    LouvainGraph {
+        n: new_n,
+        neighbors,
+        self_loops,
     }
+}
 
-    clusters.retain(|c| !c.functions.is_empty());
-    clusters
+/// Runs Louvain to convergence: phase one at each level, then phase two aggregates the resulting
+/// communities into super-nodes and the process repeats on the condensed graph. Stops once a
+/// phase-one pass leaves every node in its own community (no merge improves modularity further),
+/// and returns the final community id for each original node.
+fn louvain_communities(affinity: &[Vec<i32>]) -> Vec<usize> {
+    let n = affinity.len();
+    let mut graph = build_affinity_graph(affinity);
+    let mut membership: Vec<usize> = (0..n).collect();
+
+    loop {
+        let community = louvain_phase_one(&graph);
+
+        let mut remap: HashMap<usize, usize> = HashMap::new();
+        for &comm in &community {
+            let next_id = remap.len();
+            remap.entry(comm).or_insert(next_id);
+        }
+
+        if remap.len() == graph.n {
+            break;
+        }
+
+        for node in &mut membership {
+            *node = remap[&community[*node]];
+        }
+
+        graph = aggregate_graph(&graph, &community, &remap);
+    }
+
+    membership
 }
 
 fn find_dominant_attributes(
@@ -496,6 +756,9 @@ fn generate_cluster_label(
     cluster: &Cluster,
     dominant_file: &Option<String>,
     dominant_impl: &Option<String>,
+    token_counts: &HashMap<String, usize>,
+    corpus_df: &HashMap<String, usize>,
+    n_clusters: usize,
 ) -> String {
     if let Some(impl_type) = dominant_impl {
         let impl_count = cluster
@@ -521,7 +784,7 @@ fn generate_cluster_label(
         }
     }
 
-    infer_label_from_function_names(functions, cluster)
+    infer_label_from_function_names(token_counts, corpus_df, n_clusters)
 }
 
 fn generate_file_based_label(file: &str, functions: &[FunctionInfo], cluster: &Cluster) -> String {
@@ -576,41 +839,113 @@ fn find_common_function_prefix(functions: &[FunctionInfo], cluster: &Cluster) ->
     prefix.trim_end_matches('_').to_string()
 }
 
-fn infer_label_from_function_names(functions: &[FunctionInfo], cluster: &Cluster) -> String {
-    let mut keyword_counts: HashMap<&str, usize> = HashMap::new();
+/// Labels a cluster by picking the one or two tokens whose tf·idf score is highest across the
+/// corpus of clusters, rather than matching against a fixed keyword whitelist: `tf` is how
+/// often the token appears among this cluster's function names, and `idf = ln(N / (1 + df))`
+/// down-weights tokens that show up in most clusters (generic verbs like `get`/`set`) in favor
+/// of tokens distinctive to this one.
+fn infer_label_from_function_names(
+    token_counts: &HashMap<String, usize>,
+    corpus_df: &HashMap<String, usize>,
+    n_clusters: usize,
+) -> String {
+    let total_tokens: usize = token_counts.values().sum();
+    if total_tokens == 0 {
+        return "Related functions".to_string();
+    }
+
+    let mut scored: Vec<(&str, f64)> = token_counts
+        .iter()
+        .map(|(token, &count)| {
+            let tf = count as f64 / total_tokens as f64;
+            let df = corpus_df.get(token).copied().unwrap_or(1);
+            let idf = (n_clusters as f64 / (1.0 + df as f64)).ln();
+            (token.as_str(), tf * idf)
+        })
+        .filter(|(_, score)| *score > 0.0)
+        .collect();
+
+    scored.sort_by(|a, b| {
+        b.1.partial_cmp(&a.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.0.cmp(b.0))
+    });
+
+    let label = scored
+        .iter()
+        .take(2)
+        .map(|(token, _)| *token)
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if label.is_empty() {
+        "Related functions".to_string()
+    } else {
+        label
+    }
+}
 
+/// Tallies the tokens (see [`tokenize_function_name`]) appearing in a cluster's function names,
+/// treating the cluster as a tf·idf "document".
+fn cluster_token_counts(functions: &[FunctionInfo], cluster: &Cluster) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
     for &func_idx in &cluster.functions {
-        let name = &functions[func_idx].name;
-        for keyword in extract_keywords(name) {
-            *keyword_counts.entry(keyword).or_insert(0) += 1;
+        for token in tokenize_function_name(&functions[func_idx].name) {
+            *counts.entry(token).or_insert(0) += 1;
         }
     }
-
-    keyword_counts
-        .into_iter()
-        .filter(|(_, count)| *count >= cluster.functions.len() / 3)
-        .max_by_key(|(_, count)| *count)
-        .map(|(keyword, _)| keyword.to_string())
-        .unwrap_or_else(|| "Related functions".to_string())
+    counts
 }
 
-fn extract_keywords(name: &str) -> Vec<&'static str> {
-    let lower = name.to_lowercase();
-    let mut keywords = Vec::new();
+/// Document frequency across the corpus of clusters: how many clusters each token appears in at
+/// least once.
+fn build_corpus_document_frequency(
+    token_counts_per_cluster: &[HashMap<String, usize>],
+) -> HashMap<String, usize> {
+    let mut df: HashMap<String, usize> = HashMap::new();
+    for counts in token_counts_per_cluster {
+        for token in counts.keys() {
+            *df.entry(token.clone()).or_insert(0) += 1;
+        }
+    }
+    df
+}
 
-    const KEYWORDS: &[&str] = &[
-        "parse", "extract", "write", "read", "build", "create", "find", "get", "set", "check",
-        "validate", "process", "handle", "format", "collect", "generate", "load", "save", "init",
-        "new", "update", "delete", "insert", "remove",
+/// Splits a function name into lowercase tokens on snake_case underscores and camelCase
+/// boundaries (e.g. `parse_httpRequest` -> `["parse", "http", "request"]"), dropping stopwords
+/// and single-character fragments.
+fn tokenize_function_name(name: &str) -> Vec<String> {
+    const STOPWORDS: &[&str] = &[
+        "a", "an", "the", "of", "to", "for", "and", "or", "is", "in", "on", "with", "by", "as",
+        "fn",
     ];
 
-    for &kw in KEYWORDS {
-        if lower.contains(kw) {
-            keywords.push(kw);
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+
+    for ch in name.chars() {
+        if ch == '_' || ch.is_numeric() {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            prev_lower = false;
+            continue;
         }
+
+        if ch.is_uppercase() && prev_lower && !current.is_empty() {
+            tokens.push(std::mem::take(&mut current));
+        }
+
+        prev_lower = ch.is_lowercase();
+        current.extend(ch.to_lowercase());
+    }
+    if !current.is_empty() {
+        tokens.push(current);
     }
 
-    keywords
+    tokens.retain(|token| token.len() > 1 && !STOPWORDS.contains(&token.as_str()));
+    tokens
 }
 
 fn count_internal_calls(
@@ -664,3 +999,40 @@ fn count_external_calls(
 
     count
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two dense triangles {0,1,2} and {3,4,5} joined by one weak bridge edge (2-3) should split
+    /// into exactly those two communities: the bridge is too weak relative to the triangles'
+    /// internal weight for Louvain to prefer merging them into one.
+    #[test]
+    fn louvain_communities_splits_two_triangles_joined_by_a_weak_bridge() {
+        let mut affinity = vec![vec![0; 6]; 6];
+        let triangle_edges = [(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5)];
+        for &(i, j) in &triangle_edges {
+            affinity[i][j] = 10;
+            affinity[j][i] = 10;
+        }
+        affinity[2][3] = 1;
+        affinity[3][2] = 1;
+
+        let membership = louvain_communities(&affinity);
+
+        assert_eq!(membership[0], membership[1]);
+        assert_eq!(membership[1], membership[2]);
+        assert_eq!(membership[3], membership[4]);
+        assert_eq!(membership[4], membership[5]);
+        assert_ne!(membership[0], membership[3]);
+    }
+
+    /// With no positive affinity anywhere, every node is its own community — `louvain_phase_one`
+    /// bails out immediately since `m <= 0.0`.
+    #[test]
+    fn louvain_communities_with_no_edges_leaves_every_node_isolated() {
+        let affinity = vec![vec![0; 4]; 4];
+        let membership = louvain_communities(&affinity);
+        assert_eq!(membership.iter().collect::<HashSet<_>>().len(), 4);
+    }
+}
@@ -1,46 +1,242 @@
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use tokio::io::{AsyncWriteExt, BufWriter};
 
 use crate::extract::symbols::SymbolKind;
+use crate::output::ArtifactDigest;
 use crate::pipeline::PipelineResult;
 
-struct TypeFlow {
-    type_name: String,
-    producers: Vec<ProducerInfo>,
-    consumers: Vec<ConsumerInfo>,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeFlow {
+    pub type_name: String,
+    pub producers: Vec<ProducerInfo>,
+    pub consumers: Vec<ConsumerInfo>,
 }
 
-struct ProducerInfo {
-    function: String,
-    file: String,
-    line: usize,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProducerInfo {
+    pub function: String,
+    pub file: String,
+    pub line: usize,
 }
 
-struct ConsumerInfo {
-    function: String,
-    file: String,
-    line: usize,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsumerInfo {
+    pub function: String,
+    pub file: String,
+    pub line: usize,
 }
 
-struct FieldPattern {
-    struct_name: String,
-    field_name: String,
-    readers: Vec<AccessInfo>,
-    writers: Vec<AccessInfo>,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldPattern {
+    pub struct_name: String,
+    pub field_name: String,
+    pub readers: Vec<AccessInfo>,
+    pub writers: Vec<AccessInfo>,
 }
 
-struct AccessInfo {
-    function: String,
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessInfo {
+    pub function: String,
 }
 
+/// One `producer -[type_name]-> to` edge in a [`FlowGraph`]: a value of `type_name` that `from`
+/// (the adjacency key this edge is stored under) produces and `to` consumes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlowEdge {
+    pub type_name: String,
+    pub to: String,
+}
+
+/// Adjacency over functions, keyed by the producing function, built from [`TypeFlow`]'s
+/// producer/consumer lists. Mirrors the dataspace idea of treating those facts as a connected
+/// graph to traverse — via [`find_pipelines`] — rather than isolated per-type records.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FlowGraph {
+    pub adjacency: HashMap<String, Vec<FlowEdge>>,
+}
+
+/// Builds the producer→consumer flow graph: an edge `f -> g` exists for every type `f` produces
+/// that `g` also consumes.
+pub fn build_flow_graph(result: &PipelineResult) -> FlowGraph {
+    let flows = build_type_flows(result);
+    let mut adjacency: HashMap<String, Vec<FlowEdge>> = HashMap::new();
+
+    for flow in flows.values() {
+        for producer in &flow.producers {
+            for consumer in &flow.consumers {
+                adjacency
+                    .entry(producer.function.clone())
+                    .or_default()
+                    .push(FlowEdge {
+                        type_name: flow.type_name.clone(),
+                        to: consumer.function.clone(),
+                    });
+            }
+        }
+    }
+
+    for edges in adjacency.values_mut() {
+        edges.sort_by(|a, b| a.to.cmp(&b.to).then_with(|| a.type_name.cmp(&b.type_name)));
+        edges.dedup_by(|a, b| a.to == b.to && a.type_name == b.type_name);
+    }
+
+    FlowGraph { adjacency }
+}
+
+/// One producer→consumer chain: `functions[i]` passes a value of `types[i]` to `functions[i+1]`,
+/// so `types.len() == functions.len() - 1`. Used both for acyclic pipelines and, when a chain
+/// loops back on itself, for the cycle that closes it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pipeline {
+    pub functions: Vec<String>,
+    pub types: Vec<String>,
+}
+
+/// The result of walking a [`FlowGraph`]: maximal acyclic `chains` and any `cycles` a back-edge
+/// closed, so a recursive producer/consumer loop is reported once instead of walked forever.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PipelineReport {
+    pub chains: Vec<Pipeline>,
+    pub cycles: Vec<Pipeline>,
+}
+
+/// Enumerates maximal producer→consumer chains in `graph` via DFS from every function with at
+/// least one outgoing edge. An edge back to a function already on the current path is a cycle —
+/// it's recorded in [`PipelineReport::cycles`] and not followed, so a recursive loop terminates
+/// the walk instead of looping forever.
+pub fn find_pipelines(graph: &FlowGraph) -> PipelineReport {
+    let mut report = PipelineReport::default();
+    let mut seen: HashSet<Vec<String>> = HashSet::new();
+
+    let mut starts: Vec<&String> = graph.adjacency.keys().collect();
+    starts.sort();
+
+    for start in starts {
+        let mut path = vec![start.clone()];
+        let mut types = Vec::new();
+        walk_flow_graph(graph, start, &mut path, &mut types, &mut report, &mut seen);
+    }
+
+    report
+}
+
+fn walk_flow_graph(
+    graph: &FlowGraph,
+    current: &str,
+    path: &mut Vec<String>,
+    types: &mut Vec<String>,
+    report: &mut PipelineReport,
+    seen: &mut HashSet<Vec<String>>,
+) {
+    let edges = graph.adjacency.get(current).filter(|edges| !edges.is_empty());
+
+    let edges = match edges {
+        Some(edges) => edges,
+        None => {
+            record_chain(path, types, &mut report.chains, seen);
+            return;
+        }
+    };
+
+    let mut extended_path = false;
+
+    for edge in edges {
+        if let Some(cycle_start) = path.iter().position(|function| function == &edge.to) {
+            let mut cycle_functions = path[cycle_start..].to_vec();
+            cycle_functions.push(edge.to.clone());
+            let mut cycle_types = types[cycle_start..].to_vec();
+            cycle_types.push(edge.type_name.clone());
+            record_chain(&cycle_functions, &cycle_types, &mut report.cycles, seen);
+            continue;
+        }
+
+        extended_path = true;
+        path.push(edge.to.clone());
+        types.push(edge.type_name.clone());
+        walk_flow_graph(graph, &edge.to, path, types, report, seen);
+        types.pop();
+        path.pop();
+    }
+
+    if !extended_path {
+        record_chain(path, types, &mut report.chains, seen);
+    }
+}
+
+fn record_chain(
+    functions: &[String],
+    types: &[String],
+    into: &mut Vec<Pipeline>,
+    seen: &mut HashSet<Vec<String>>,
+) {
+    if functions.len() < 2 || !seen.insert(functions.to_vec()) {
+        return;
+    }
+
+    into.push(Pipeline {
+        functions: functions.to_vec(),
+        types: types.to_vec(),
+    });
+}
+
+/// The whole data-flow analysis as one serializable model: every [`TypeFlow`], [`FieldPattern`],
+/// and [`Pipeline`] charter derived from `result`, unfiltered and untruncated so
+/// [`write_dataflow_text`]/[`write_dataflow_binary`] round-trip the full analysis rather than the
+/// top-N, budget-trimmed view [`write_dataflow`] renders for human reading. Type flows and field
+/// patterns are kept in the order the markdown renderer iterates them in, and pipelines in
+/// [`find_pipelines`]' deterministic DFS order (the renderer re-sorts chains by length), so a
+/// diff between two captures' `dataflow.json` is meaningful.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DataFlowModel {
+    pub type_flows: Vec<TypeFlow>,
+    pub field_patterns: Vec<FieldPattern>,
+    pub pipelines: PipelineReport,
+}
+
+/// Builds the complete [`DataFlowModel`] from `result` — the single source every emitter
+/// ([`write_dataflow`], [`write_dataflow_text`], [`write_dataflow_binary`]) renders from, so they
+/// can't drift out of sync with each other.
+pub fn build_dataflow_model(result: &PipelineResult) -> DataFlowModel {
+    let mut type_flows: Vec<TypeFlow> = build_type_flows(result).into_values().collect();
+    type_flows.sort_by(|a, b| {
+        let a_score = a.producers.len() + a.consumers.len();
+        let b_score = b.producers.len() + b.consumers.len();
+        b_score
+            .cmp(&a_score)
+            .then_with(|| a.type_name.cmp(&b.type_name))
+    });
+
+    let mut field_patterns = build_field_patterns(result);
+    field_patterns.sort_by(|a, b| {
+        a.struct_name
+            .cmp(&b.struct_name)
+            .then_with(|| a.field_name.cmp(&b.field_name))
+    });
+
+    let pipelines = find_pipelines(&build_flow_graph(result));
+
+    DataFlowModel {
+        type_flows,
+        field_patterns,
+        pipelines,
+    }
+}
+
+/// Renders `model` as `dataflow.md` — a thin text renderer over [`DataFlowModel`] that applies
+/// the same top-N, budget-trimmed presentation rules the pre-model version of this function used
+/// to compute inline. [`write_dataflow_text`]/[`write_dataflow_binary`] skip this trimming
+/// entirely, since their whole point is round-tripping the analysis `build_dataflow_model`
+/// actually produced.
 pub async fn write_dataflow(
     charter_dir: &Path,
-    result: &PipelineResult,
+    model: &DataFlowModel,
     stamp: &str,
-) -> Result<()> {
-    let file = tokio::fs::File::create(charter_dir.join("dataflow.md")).await?;
+) -> Result<ArtifactDigest> {
+    let path = charter_dir.join("dataflow.md");
+    let (file, tmp_path) = super::create_atomic(&path).await?;
     let mut writer = BufWriter::new(file);
 
     writer.write_all(stamp.as_bytes()).await?;
@@ -50,32 +246,21 @@ pub async fn write_dataflow(
         .write_all(b"Type flows and field access patterns across the codebase.\n\n")
         .await?;
 
-    let type_flows = build_type_flows(result);
-    let field_patterns = build_field_patterns(result);
+    let significant_flows: Vec<&TypeFlow> = model
+        .type_flows
+        .iter()
+        .filter(|flow| !(flow.producers.is_empty() && flow.consumers.is_empty()))
+        .filter(|flow| flow.producers.len() >= 2 || flow.consumers.len() >= 2)
+        .take(30)
+        .collect();
 
-    if !type_flows.is_empty() {
+    if !significant_flows.is_empty() {
         writer.write_all(b"## Type Flows\n\n").await?;
         writer
             .write_all(b"Types produced and consumed by functions.\n\n")
             .await?;
 
-        let mut flows: Vec<&TypeFlow> = type_flows.values().collect();
-        flows.sort_by(|a, b| {
-            let a_score = a.producers.len() + a.consumers.len();
-            let b_score = b.producers.len() + b.consumers.len();
-            b_score
-                .cmp(&a_score)
-                .then_with(|| a.type_name.cmp(&b.type_name))
-        });
-
-        for flow in flows.iter().take(30) {
-            if flow.producers.is_empty() && flow.consumers.is_empty() {
-                continue;
-            }
-            if flow.producers.len() < 2 && flow.consumers.len() < 2 {
-                continue;
-            }
-
+        for flow in &significant_flows {
             let header = format!("{}\n", flow.type_name);
             writer.write_all(header.as_bytes()).await?;
 
@@ -119,20 +304,51 @@ pub async fn write_dataflow(
         }
     }
 
-    if !field_patterns.is_empty() {
-        writer.write_all(b"## Field Access Patterns\n\n").await?;
+    if !model.pipelines.chains.is_empty() || !model.pipelines.cycles.is_empty() {
+        writer.write_all(b"## Type Pipelines\n\n").await?;
         writer
-            .write_all(b"Which functions read/write specific struct fields.\n\n")
+            .write_all(b"Producer/consumer chains formed by types flowing between functions.\n\n")
             .await?;
 
-        let mut struct_groups: HashMap<&str, Vec<&FieldPattern>> = HashMap::new();
-        for pattern in &field_patterns {
-            struct_groups
-                .entry(&pattern.struct_name)
-                .or_default()
-                .push(pattern);
+        let mut chains: Vec<&Pipeline> = model.pipelines.chains.iter().collect();
+        chains.sort_by(|a, b| {
+            b.functions
+                .len()
+                .cmp(&a.functions.len())
+                .then_with(|| a.functions.cmp(&b.functions))
+        });
+
+        for chain in chains.iter().take(20) {
+            let line = format!("{}\n", format_pipeline(chain));
+            writer.write_all(line.as_bytes()).await?;
         }
 
+        if !model.pipelines.cycles.is_empty() {
+            writer.write_all(b"\nCycles detected:\n\n").await?;
+
+            for cycle in model.pipelines.cycles.iter().take(10) {
+                let line = format!("{}\n", format_pipeline(cycle));
+                writer.write_all(line.as_bytes()).await?;
+            }
+        }
+
+        writer.write_all(b"\n").await?;
+    }
+
+    let mut struct_groups: HashMap<&str, Vec<&FieldPattern>> = HashMap::new();
+    for pattern in &model.field_patterns {
+        struct_groups
+            .entry(&pattern.struct_name)
+            .or_default()
+            .push(pattern);
+    }
+
+    if !struct_groups.is_empty() {
+        writer.write_all(b"## Field Access Patterns\n\n").await?;
+        writer
+            .write_all(b"Which functions read/write specific struct fields.\n\n")
+            .await?;
+
         let mut structs: Vec<&str> = struct_groups.keys().copied().collect();
         structs.sort();
 
@@ -200,14 +416,61 @@ pub async fn write_dataflow(
         }
     }
 
-    if type_flows.is_empty() && field_patterns.is_empty() {
+    if significant_flows.is_empty()
+        && model.field_patterns.is_empty()
+        && model.pipelines.chains.is_empty()
+        && model.pipelines.cycles.is_empty()
+    {
         writer
             .write_all(b"No significant data flow patterns detected.\n")
             .await?;
     }
 
     writer.flush().await?;
-    Ok(())
+    let file = writer.into_inner();
+    super::finish_atomic(file, &tmp_path, &path).await?;
+    super::digest_written_file("dataflow.md", &path).await
+}
+
+/// Writes the same analysis as [`write_dataflow`] to `dataflow.json`, the human-readable text
+/// syntax half of this module's dual-syntax export (see [`DataFlowModel`]) — unlike the markdown
+/// renderer, this carries every flow and pattern `build_dataflow_model` found, not just the
+/// significant/top-N ones worth putting in front of a reader.
+pub async fn write_dataflow_text(
+    charter_dir: &Path,
+    model: &DataFlowModel,
+) -> Result<ArtifactDigest> {
+    let path = charter_dir.join("dataflow.json");
+    let document_json = serde_json::to_vec_pretty(model)?;
+    let digest = super::digest_buffer("dataflow.json", &document_json);
+    super::write_atomic(&path, &document_json).await?;
+    Ok(digest)
+}
+
+/// Writes the same analysis as [`write_dataflow_text`] to `dataflow.bin` in `bincode`'s compact,
+/// length-prefixed encoding — the same serialization [`crate::cache::Cache`] already round-trips
+/// through for `cache.bin`, reused here as this module's binary syntax rather than inventing a
+/// second wire format for one document.
+pub async fn write_dataflow_binary(
+    charter_dir: &Path,
+    model: &DataFlowModel,
+) -> Result<ArtifactDigest> {
+    let path = charter_dir.join("dataflow.bin");
+    let encoded = bincode::serialize(model)?;
+    let digest = super::digest_buffer("dataflow.bin", &encoded);
+    super::write_atomic(&path, &encoded).await?;
+    Ok(digest)
+}
+
+/// Renders a [`Pipeline`] as `f1 -(T1)-> f2 -(T2)-> f3`.
+fn format_pipeline(pipeline: &Pipeline) -> String {
+    let mut rendered = pipeline.functions[0].clone();
+
+    for (type_name, function) in pipeline.types.iter().zip(pipeline.functions.iter().skip(1)) {
+        rendered.push_str(&format!(" -({})-> {}", type_name, function));
+    }
+
+    rendered
 }
 
 fn build_type_flows(result: &PipelineResult) -> HashMap<String, TypeFlow> {
@@ -229,16 +492,19 @@ fn build_type_flows(result: &PipelineResult) -> HashMap<String, TypeFlow> {
                 let (return_type, param_types) = parse_signature_types(signature);
 
                 if let Some(ref ret_type) = return_type {
-                    let base_type = extract_base_type(ret_type);
-                    if defined_types.contains(&base_type) && !is_common_type(&base_type) {
-                        flows.entry(base_type.clone()).or_insert_with(|| TypeFlow {
-                            type_name: base_type.clone(),
+                    for type_name in extract_defined_types(ret_type, &defined_types) {
+                        if is_common_type(&type_name) {
+                            continue;
+                        }
+
+                        flows.entry(type_name.clone()).or_insert_with(|| TypeFlow {
+                            type_name: type_name.clone(),
                             producers: Vec::new(),
                             consumers: Vec::new(),
                         });
 
                         flows
-                            .get_mut(&base_type)
+                            .get_mut(&type_name)
                             .unwrap()
                             .producers
                             .push(ProducerInfo {
@@ -250,16 +516,19 @@ fn build_type_flows(result: &PipelineResult) -> HashMap<String, TypeFlow> {
                 }
 
                 for param_type in &param_types {
-                    let base_type = extract_base_type(param_type);
-                    if defined_types.contains(&base_type) && !is_common_type(&base_type) {
-                        flows.entry(base_type.clone()).or_insert_with(|| TypeFlow {
-                            type_name: base_type.clone(),
+                    for type_name in extract_defined_types(param_type, &defined_types) {
+                        if is_common_type(&type_name) {
+                            continue;
+                        }
+
+                        flows.entry(type_name.clone()).or_insert_with(|| TypeFlow {
+                            type_name: type_name.clone(),
                             producers: Vec::new(),
                             consumers: Vec::new(),
                         });
 
                         flows
-                            .get_mut(&base_type)
+                            .get_mut(&type_name)
                             .unwrap()
                             .consumers
                             .push(ConsumerInfo {
@@ -277,17 +546,20 @@ fn build_type_flows(result: &PipelineResult) -> HashMap<String, TypeFlow> {
                 let (return_type, param_types) = parse_signature_types(&method.signature);
 
                 if let Some(ref ret_type) = return_type {
-                    let base_type = extract_base_type(ret_type);
-                    if defined_types.contains(&base_type) && !is_common_type(&base_type) {
-                        flows.entry(base_type.clone()).or_insert_with(|| TypeFlow {
-                            type_name: base_type.clone(),
+                    for type_name in extract_defined_types(ret_type, &defined_types) {
+                        if is_common_type(&type_name) {
+                            continue;
+                        }
+
+                        flows.entry(type_name.clone()).or_insert_with(|| TypeFlow {
+                            type_name: type_name.clone(),
                             producers: Vec::new(),
                             consumers: Vec::new(),
                         });
 
                         let qualified = format!("{}::{}", imp.type_name, method.name);
                         flows
-                            .get_mut(&base_type)
+                            .get_mut(&type_name)
                             .unwrap()
                             .producers
                             .push(ProducerInfo {
@@ -299,17 +571,20 @@ fn build_type_flows(result: &PipelineResult) -> HashMap<String, TypeFlow> {
                 }
 
                 for param_type in &param_types {
-                    let base_type = extract_base_type(param_type);
-                    if defined_types.contains(&base_type) && !is_common_type(&base_type) {
-                        flows.entry(base_type.clone()).or_insert_with(|| TypeFlow {
-                            type_name: base_type.clone(),
+                    for type_name in extract_defined_types(param_type, &defined_types) {
+                        if is_common_type(&type_name) {
+                            continue;
+                        }
+
+                        flows.entry(type_name.clone()).or_insert_with(|| TypeFlow {
+                            type_name: type_name.clone(),
                             producers: Vec::new(),
                             consumers: Vec::new(),
                         });
 
                         let qualified = format!("{}::{}", imp.type_name, method.name);
                         flows
-                            .get_mut(&base_type)
+                            .get_mut(&type_name)
                             .unwrap()
                             .consumers
                             .push(ConsumerInfo {
@@ -351,9 +626,9 @@ fn parse_signature_types(signature: &str) -> (Option<String>, Vec<String>) {
 
     let mut param_types = Vec::new();
     if let Some(paren_start) = signature.find('(') {
-        if let Some(paren_end) = signature.rfind(')') {
+        if let Some(paren_end) = find_matching_paren(signature, paren_start) {
             let params_str = &signature[paren_start + 1..paren_end];
-            for param in params_str.split(',') {
+            for param in split_top_level(params_str, ',') {
                 let param = param.trim();
                 if param.is_empty() || param == "self" || param == "&self" || param == "&mut self" {
                     continue;
@@ -369,6 +644,50 @@ fn parse_signature_types(signature: &str) -> (Option<String>, Vec<String>) {
     (return_type, param_types)
 }
 
+/// Finds the `)` matching the `(` at `open_idx`, tracking paren depth so a parameter list
+/// containing its own parens (`Box<dyn Fn(i32) -> bool>`) doesn't end the scan early the way
+/// `signature.rfind(')')` used to.
+fn find_matching_paren(s: &str, open_idx: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (offset, c) in s[open_idx..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open_idx + offset);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Splits `s` on top-level occurrences of `delimiter` only — one inside `<...>`, `(...)`, or
+/// `[...]` doesn't count, so `"HashMap<K, V>, bool"` splits into `["HashMap<K, V>", " bool"]`
+/// instead of naively breaking the generic argument list apart.
+fn split_top_level(s: &str, delimiter: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth: i32 = 0;
+    let mut start = 0;
+
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            c if c == delimiter && depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+
+    parts
+}
+
 fn build_field_patterns(result: &PipelineResult) -> Vec<FieldPattern> {
     let mut patterns: HashMap<(String, String), FieldPattern> = HashMap::new();
 
@@ -435,17 +754,71 @@ fn build_field_patterns(result: &PipelineResult) -> Vec<FieldPattern> {
     patterns.into_values().collect()
 }
 
-fn extract_base_type(type_str: &str) -> String {
-    let trimmed = type_str
-        .trim_start_matches('&')
-        .trim_start_matches("mut ")
-        .trim_start_matches("'static ")
-        .trim_start_matches("'_ ");
+/// Recursively walks `type_str`'s structure — unwrapping references, lifetimes, `dyn`/`impl`
+/// bounds, tuples, and generic wrappers (`Box<Config>`, `HashMap<Key, Node>`, `Vec<(A, B)>`, ...)
+/// — and collects every identifier found along the way that's in `defined_types`. Unlike the
+/// single-base extraction this replaces, a container's outer constructor is never treated as
+/// opaque: `Vec<Config>` yields `Config`, and `HashMap<Key, Node>` yields both `Key` and `Node`,
+/// by splitting generic argument lists on top-level commas ([`split_top_level`]) rather than on
+/// the first `<`.
+fn extract_defined_types(type_str: &str, defined_types: &HashSet<String>) -> Vec<String> {
+    let mut found = Vec::new();
+    collect_defined_types(type_str, defined_types, &mut found);
+    found
+}
 
-    if let Some(generic_start) = trimmed.find('<') {
-        trimmed[..generic_start].to_string()
-    } else {
-        trimmed.to_string()
+fn collect_defined_types(type_str: &str, defined_types: &HashSet<String>, found: &mut Vec<String>) {
+    let trimmed = type_str.trim();
+
+    if let Some(inner) = trimmed.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+        for element in split_top_level(inner, ',') {
+            collect_defined_types(element, defined_types, found);
+        }
+        return;
+    }
+
+    if let Some(rest) = trimmed.strip_prefix('&') {
+        collect_defined_types(rest, defined_types, found);
+        return;
+    }
+
+    if let Some(rest) = trimmed.strip_prefix("mut ") {
+        collect_defined_types(rest, defined_types, found);
+        return;
+    }
+
+    if trimmed.starts_with('\'') {
+        if let Some(space_idx) = trimmed.find(' ') {
+            collect_defined_types(&trimmed[space_idx + 1..], defined_types, found);
+        }
+        return;
+    }
+
+    if let Some(rest) = trimmed.strip_prefix("dyn ") {
+        collect_defined_types(rest, defined_types, found);
+        return;
+    }
+
+    if let Some(rest) = trimmed.strip_prefix("impl ") {
+        collect_defined_types(rest, defined_types, found);
+        return;
+    }
+
+    let (name, generics) = match trimmed.find('<') {
+        Some(start) if trimmed.ends_with('>') => {
+            (&trimmed[..start], Some(&trimmed[start + 1..trimmed.len() - 1]))
+        }
+        _ => (trimmed, None),
+    };
+
+    if defined_types.contains(name) {
+        found.push(name.to_string());
+    }
+
+    if let Some(generics) = generics {
+        for arg in split_top_level(generics, ',') {
+            collect_defined_types(arg, defined_types, found);
+        }
     }
 }
 
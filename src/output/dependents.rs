@@ -1,32 +1,30 @@
 use anyhow::Result;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::io::Write;
 use std::path::Path;
-use tokio::fs::File;
-use tokio::io::AsyncWriteExt;
 
+use crate::cache::Cache;
+use crate::output::ArtifactDigest;
 use crate::pipeline::PipelineResult;
 
 pub async fn write_dependents(
     atlas_dir: &Path,
     result: &PipelineResult,
     stamp: &str,
-) -> Result<()> {
+) -> Result<ArtifactDigest> {
     let path = atlas_dir.join("dependents.md");
-    let mut file = File::create(&path).await?;
 
     let mut buffer = Vec::with_capacity(64 * 1024);
 
     writeln!(buffer, "{}", stamp)?;
     writeln!(buffer)?;
 
-    let module_tree = build_module_tree(result);
-    let dependents = build_dependent_map(result, &module_tree);
+    let dependents = compute_dependent_map(result);
 
     if dependents.is_empty() || dependents.values().all(|v| v.is_empty()) {
         writeln!(buffer, "(no dependencies found)")?;
-        file.write_all(&buffer).await?;
-        return Ok(());
+        super::write_atomic(&path, &buffer).await?;
+        return Ok(super::digest_buffer("dependents.md", &buffer));
     }
 
     let mut sorted: Vec<_> = dependents.into_iter().collect();
@@ -52,23 +50,100 @@ pub async fn write_dependents(
         writeln!(buffer)?;
     }
 
-    file.write_all(&buffer).await?;
-    Ok(())
+    super::write_atomic(&path, &buffer).await?;
+    Ok(super::digest_buffer("dependents.md", &buffer))
 }
 
-fn build_module_tree(result: &PipelineResult) -> HashMap<String, String> {
+/// Builds the direct-dependents graph (file -> files that import it) from a freshly captured
+/// pipeline result. This is the graph persisted to dependents.md.
+pub fn compute_dependent_map(result: &PipelineResult) -> HashMap<String, Vec<String>> {
+    let paths: Vec<String> = result
+        .files
+        .iter()
+        .map(|f| f.relative_path.clone())
+        .collect();
+    let all_paths: HashSet<String> = paths.iter().cloned().collect();
+    let module_tree = build_module_tree(&paths);
+
+    let imports: Vec<(String, Vec<crate::extract::imports::ImportInfo>)> = result
+        .files
+        .iter()
+        .map(|f| (f.relative_path.clone(), f.parsed.imports.clone()))
+        .collect();
+
+    build_dependent_map(&imports, &module_tree, &all_paths)
+}
+
+/// Reconstructs the same direct-dependents graph from the on-disk cache, so callers at
+/// `charter read` time don't need a fresh pipeline walk to answer "what depends on this file".
+pub fn dependent_map_from_cache(cache: &Cache) -> HashMap<String, Vec<String>> {
+    let paths: Vec<String> = cache.entries.keys().cloned().collect();
+    let all_paths: HashSet<String> = paths.iter().cloned().collect();
+    let module_tree = build_module_tree(&paths);
+
+    let imports: Vec<(String, Vec<crate::extract::imports::ImportInfo>)> = cache
+        .entries
+        .iter()
+        .map(|(path, entry)| (path.clone(), entry.data.parsed.imports.clone()))
+        .collect();
+
+    build_dependent_map(&imports, &module_tree, &all_paths)
+}
+
+/// Walks the direct-dependents graph outward from a set of changed files, returning every
+/// transitively impacted file paired with its hop distance from the nearest changed file.
+pub fn propagate_impact(
+    dependents: &HashMap<String, Vec<String>>,
+    changed_files: &HashSet<String>,
+) -> Vec<(String, usize)> {
+    let mut distances: HashMap<String, usize> = HashMap::new();
+    let mut queue: VecDeque<(String, usize)> = VecDeque::new();
+
+    for file in changed_files {
+        queue.push_back((file.clone(), 0));
+    }
+
+    while let Some((current, hops)) = queue.pop_front() {
+        let Some(direct) = dependents.get(&current) else {
+            continue;
+        };
+
+        for dependent in direct {
+            if changed_files.contains(dependent) {
+                continue;
+            }
+
+            let next_hops = hops + 1;
+            let is_shorter = distances
+                .get(dependent)
+                .map(|&existing| next_hops < existing)
+                .unwrap_or(true);
+
+            if is_shorter {
+                distances.insert(dependent.clone(), next_hops);
+                queue.push_back((dependent.clone(), next_hops));
+            }
+        }
+    }
+
+    let mut results: Vec<_> = distances.into_iter().collect();
+    results.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    results
+}
+
+fn build_module_tree(paths: &[String]) -> HashMap<String, String> {
     let mut tree: HashMap<String, String> = HashMap::new();
 
-    for file in &result.files {
-        if !file.relative_path.ends_with(".rs") {
+    for path in paths {
+        if !path.ends_with(".rs") {
             continue;
         }
 
-        let crate_prefix = extract_crate_prefix(&file.relative_path);
-        let module_path = file_path_to_module_path(&file.relative_path);
+        let crate_prefix = extract_crate_prefix(path);
+        let module_path = file_path_to_module_path(path);
         if !module_path.is_empty() {
             let full_key = format!("{}:{}", crate_prefix, module_path);
-            tree.insert(full_key, file.relative_path.clone());
+            tree.insert(full_key, path.clone());
         }
     }
 
@@ -119,21 +194,20 @@ fn file_path_to_module_path(file_path: &str) -> String {
 }
 
 fn build_dependent_map(
-    result: &PipelineResult,
+    files: &[(String, Vec<crate::extract::imports::ImportInfo>)],
     module_tree: &HashMap<String, String>,
+    all_paths: &HashSet<String>,
 ) -> HashMap<String, Vec<String>> {
     let mut dependents: HashMap<String, Vec<String>> = HashMap::new();
 
-    for file in &result.files {
-        dependents.entry(file.relative_path.clone()).or_default();
+    for (relative_path, _) in files {
+        dependents.entry(relative_path.clone()).or_default();
     }
 
-    for file in &result.files {
-        let importing_file = &file.relative_path;
-
-        for import in &file.parsed.imports {
+    for (importing_file, imports) in files {
+        for import in imports {
             let resolved_files =
-                resolve_import_to_files(&import.path, importing_file, module_tree, result);
+                resolve_import_to_files(&import.path, importing_file, module_tree, all_paths);
 
             for target_file in resolved_files {
                 if target_file != *importing_file {
@@ -158,7 +232,7 @@ fn resolve_import_to_files(
     import_path: &str,
     importing_file: &str,
     module_tree: &HashMap<String, String>,
-    result: &PipelineResult,
+    all_paths: &HashSet<String>,
 ) -> Vec<String> {
     let mut results = Vec::new();
 
@@ -192,7 +266,7 @@ fn resolve_import_to_files(
         for candidate in &normalized {
             let file_candidates = module_path_to_possible_files(candidate);
             for fc in file_candidates {
-                if result.files.iter().any(|f| f.relative_path == fc) {
+                if all_paths.contains(&fc) {
                     results.push(fc);
                 }
             }
@@ -1,14 +1,23 @@
 use anyhow::Result;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use tokio::io::{AsyncWriteExt, BufWriter};
 
+use crate::errorflow::{self, ErrorFlowEdge};
 use crate::extract::errors::{ErrorInfo, ErrorReturnType};
 use crate::extract::symbols::Visibility;
-use crate::pipeline::PipelineResult;
+use crate::output::callgraph::collect_entry_points;
+use crate::output::calls::build_reverse_call_graph;
+use crate::output::ArtifactDigest;
+use crate::pipeline::{build_cache, PipelineResult};
 
-pub async fn write_errors(atlas_dir: &Path, result: &PipelineResult, stamp: &str) -> Result<()> {
-    let file = tokio::fs::File::create(atlas_dir.join("errors.md")).await?;
+pub async fn write_errors(
+    atlas_dir: &Path,
+    result: &PipelineResult,
+    stamp: &str,
+) -> Result<ArtifactDigest> {
+    let path = atlas_dir.join("errors.md");
+    let (file, tmp_path) = super::create_atomic(&path).await?;
     let mut writer = BufWriter::new(file);
 
     writer.write_all(stamp.as_bytes()).await?;
@@ -27,7 +36,9 @@ pub async fn write_errors(atlas_dir: &Path, result: &PipelineResult, stamp: &str
             .write_all(b"No fallible functions detected.\n")
             .await?;
         writer.flush().await?;
-        return Ok(());
+        let file = writer.into_inner();
+        super::finish_atomic(file, &tmp_path, &path).await?;
+        return super::digest_written_file("errors.md", &path).await;
     }
 
     let error_sources: Vec<_> = all_errors
@@ -37,6 +48,7 @@ pub async fn write_errors(atlas_dir: &Path, result: &PipelineResult, stamp: &str
 
     let public_fallible: Vec<_> = all_errors
         .iter()
+        .filter(|(_, info)| info.return_type.is_fallible())
         .filter(|(file_path, info)| {
             is_public_function(
                 result,
@@ -47,6 +59,11 @@ pub async fn write_errors(atlas_dir: &Path, result: &PipelineResult, stamp: &str
         })
         .collect();
 
+    let sink_functions: Vec<_> = all_errors
+        .iter()
+        .filter(|(_, info)| info.has_sinks())
+        .collect();
+
     let propagation_heavy: Vec<_> = all_errors
         .iter()
         .filter(|(_, info)| info.propagation_count() >= 3)
@@ -104,6 +121,54 @@ pub async fn write_errors(atlas_dir: &Path, result: &PipelineResult, stamp: &str
         writer.write_all(b"\n").await?;
     }
 
+    if !sink_functions.is_empty() {
+        writer.write_all(b"## Error Sinks\n\n").await?;
+        writer
+            .write_all(
+                b"Places a Result/Option is absorbed instead of propagated via `?` \
+(`.unwrap()`, `.expect()`, `.unwrap_or*()`, `let _ = ...`), any of which can panic the \
+process or silently drop the error.\n\n",
+            )
+            .await?;
+
+        let mut sorted: Vec<_> = sink_functions.clone();
+        sorted.sort_by(|a, b| b.1.error_sinks.len().cmp(&a.1.error_sinks.len()));
+
+        for (file_path, info) in sorted.iter().take(30) {
+            let qualified = info.function_id.qualified_name();
+            let line = format!(
+                "{}:{} {} [{} sinks]\n",
+                file_path,
+                info.line,
+                qualified,
+                info.error_sinks.len()
+            );
+            writer.write_all(line.as_bytes()).await?;
+
+            for sink in info.error_sinks.iter().take(3) {
+                let line = format!("  L{}: {} {}\n", sink.line, sink.call_target, sink.kind);
+                writer.write_all(line.as_bytes()).await?;
+            }
+            if info.error_sinks.len() > 3 {
+                let line = format!("  [+{} more sinks]\n", info.error_sinks.len() - 3);
+                writer.write_all(line.as_bytes()).await?;
+            }
+        }
+
+        if sink_functions.len() > 30 {
+            writer
+                .write_all(
+                    format!(
+                        "\n[+{} more functions with error sinks]\n",
+                        sink_functions.len() - 30
+                    )
+                    .as_bytes(),
+                )
+                .await?;
+        }
+        writer.write_all(b"\n").await?;
+    }
+
     if !public_fallible.is_empty() {
         writer.write_all(b"## Public API Surface\n\n").await?;
         writer
@@ -167,7 +232,14 @@ pub async fn write_errors(atlas_dir: &Path, result: &PipelineResult, stamp: &str
             writer.write_all(line.as_bytes()).await?;
 
             for prop in info.propagation_points.iter().take(3) {
-                let line = format!("  L{}: {}\n", prop.line, prop.expression);
+                let context_suffix = match &prop.context {
+                    Some(context) if context.lazy => {
+                        format!(" [context (lazy): {}]", context.message)
+                    }
+                    Some(context) => format!(" [context: {}]", context.message),
+                    None => String::new(),
+                };
+                let line = format!("  L{}: {}{}\n", prop.line, prop.expression, context_suffix);
                 writer.write_all(line.as_bytes()).await?;
             }
             if info.propagation_points.len() > 3 {
@@ -177,22 +249,31 @@ pub async fn write_errors(atlas_dir: &Path, result: &PipelineResult, stamp: &str
         }
     }
 
+    write_error_propagation_section(&mut writer, result).await?;
+    write_resolved_error_flow_section(&mut writer, result, &public_fallible).await?;
+
     writer.write_all(b"\n## Stats\n\n").await?;
-    let total_fallible = all_errors.len();
+    let total_fallible = all_errors
+        .iter()
+        .filter(|(_, i)| i.return_type.is_fallible())
+        .count();
     let total_origins: usize = all_errors.iter().map(|(_, i)| i.error_origins.len()).sum();
     let total_propagations: usize = all_errors
         .iter()
         .map(|(_, i)| i.propagation_points.len())
         .sum();
+    let total_sinks: usize = all_errors.iter().map(|(_, i)| i.error_sinks.len()).sum();
 
     let stats = format!(
-        "Fallible functions: {}\nError origin points: {}\nPropagation points (?): {}\n",
-        total_fallible, total_origins, total_propagations
+        "Fallible functions: {}\nError origin points: {}\nPropagation points (?): {}\nError sinks (unwrap/expect/discarded): {}\n",
+        total_fallible, total_origins, total_propagations, total_sinks
     );
     writer.write_all(stats.as_bytes()).await?;
 
     writer.flush().await?;
-    Ok(())
+    let file = writer.into_inner();
+    super::finish_atomic(file, &tmp_path, &path).await?;
+    super::digest_written_file("errors.md", &path).await
 }
 
 fn format_return_type(return_type: &ErrorReturnType) -> String {
@@ -259,3 +340,284 @@ fn is_public_function(
 
     false
 }
+
+/// Max callers to climb through per chain before giving up — mirrors the depth caps the
+/// other sections in this file apply via `.take(N)`, just expressed as a walk depth instead
+/// of a result-list length.
+const MAX_CHAIN_DEPTH: usize = 12;
+
+/// Builds the reverse call graph (same one `calls.md`'s `## Callers` section uses) and keeps
+/// only the `?`-propagating edges, since a chain only continues through a caller that
+/// re-propagates the error with `?` rather than handling or discarding it.
+fn build_try_reverse_graph(result: &PipelineResult) -> HashMap<String, Vec<String>> {
+    let mut try_reverse: HashMap<String, Vec<String>> = HashMap::new();
+
+    for (target, callers) in build_reverse_call_graph(result) {
+        let try_callers: Vec<String> = callers
+            .into_iter()
+            .filter(|caller| caller.is_try)
+            .map(|caller| caller.caller_name)
+            .collect();
+
+        if !try_callers.is_empty() {
+            try_reverse.insert(target, try_callers);
+        }
+    }
+
+    for callers in try_reverse.values_mut() {
+        callers.sort();
+        callers.dedup();
+    }
+
+    try_reverse
+}
+
+fn collect_error_origins(result: &PipelineResult) -> Vec<String> {
+    let mut origins: Vec<String> = Vec::new();
+
+    for file_result in &result.files {
+        for info in &file_result.parsed.error_info {
+            if info.is_error_source() {
+                origins.push(info.function_id.qualified_name());
+            }
+        }
+    }
+
+    origins.sort();
+    origins.dedup();
+    origins
+}
+
+/// DFS from `origin` up through `try_reverse`, collecting every simple path that ends at an
+/// entry point, a dead end (no further `?`-propagating caller), or `MAX_CHAIN_DEPTH`.
+fn trace_chains(
+    origin: &str,
+    try_reverse: &HashMap<String, Vec<String>>,
+    entry_points: &HashSet<String>,
+) -> Vec<Vec<String>> {
+    let mut chains = Vec::new();
+    let mut path = vec![origin.to_string()];
+    let mut visited: HashSet<String> = [origin.to_string()].into_iter().collect();
+
+    walk_chain(
+        origin,
+        try_reverse,
+        entry_points,
+        &mut path,
+        &mut visited,
+        &mut chains,
+    );
+
+    chains
+}
+
+fn walk_chain(
+    node: &str,
+    try_reverse: &HashMap<String, Vec<String>>,
+    entry_points: &HashSet<String>,
+    path: &mut Vec<String>,
+    visited: &mut HashSet<String>,
+    chains: &mut Vec<Vec<String>>,
+) {
+    if entry_points.contains(node) || path.len() >= MAX_CHAIN_DEPTH {
+        chains.push(path.clone());
+        return;
+    }
+
+    let next_callers: Vec<String> = try_reverse
+        .get(node)
+        .map(|callers| {
+            callers
+                .iter()
+                .filter(|caller| !visited.contains(*caller))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if next_callers.is_empty() {
+        chains.push(path.clone());
+        return;
+    }
+
+    for caller in next_callers {
+        path.push(caller.clone());
+        visited.insert(caller.clone());
+        walk_chain(&caller, try_reverse, entry_points, path, visited, chains);
+        visited.remove(&caller);
+        path.pop();
+    }
+}
+
+/// Writes the `## Error Propagation` section: for each function that originates a fallible
+/// call, the chains of `?`-propagating callers that carry it up to `main`, a `pub fn`
+/// boundary, a test function, or a dead end, grouped by that terminal and deduplicated.
+async fn write_error_propagation_section(
+    writer: &mut BufWriter<tokio::fs::File>,
+    result: &PipelineResult,
+) -> Result<()> {
+    let origins = collect_error_origins(result);
+    if origins.is_empty() {
+        return Ok(());
+    }
+
+    let try_reverse = build_try_reverse_graph(result);
+    let entry_points = collect_entry_points(result);
+
+    let mut chains: Vec<Vec<String>> = origins
+        .iter()
+        .flat_map(|origin| trace_chains(origin, &try_reverse, &entry_points))
+        .filter(|chain| chain.len() >= 2)
+        .collect();
+
+    if chains.is_empty() {
+        return Ok(());
+    }
+
+    chains.sort();
+    chains.dedup();
+
+    let mut by_terminal: HashMap<String, Vec<Vec<String>>> = HashMap::new();
+    for chain in chains {
+        let terminal = chain.last().expect("chain has at least 2 elements").clone();
+        by_terminal.entry(terminal).or_default().push(chain);
+    }
+
+    writer.write_all(b"## Error Propagation\n\n").await?;
+    writer
+        .write_all(
+            b"Chains of `?` propagation from an error-originating function up through its \
+?-propagating callers, stopping at `main`, a `pub fn` boundary, a test function, a dead \
+end, or the chain depth limit.\n\n",
+        )
+        .await?;
+
+    let mut terminals: Vec<&String> = by_terminal.keys().collect();
+    terminals.sort();
+
+    for terminal in terminals {
+        let terminal_chains = by_terminal.get(terminal).unwrap();
+        let heading = if entry_points.contains(terminal) {
+            terminal.clone()
+        } else if terminal_chains
+            .iter()
+            .any(|chain| chain.len() >= MAX_CHAIN_DEPTH)
+        {
+            format!("{} (chain depth limit reached)", terminal)
+        } else {
+            format!("{} (no further ?-propagating caller)", terminal)
+        };
+        writer
+            .write_all(format!("{}\n", heading).as_bytes())
+            .await?;
+
+        for chain in terminal_chains {
+            writer
+                .write_all(format!("  {}\n", chain.join(" → ")).as_bytes())
+                .await?;
+        }
+    }
+    writer.write_all(b"\n").await?;
+
+    Ok(())
+}
+
+/// Max `?` sites per attributed propagation/conversion listing — mirrors the `.take(N)` caps
+/// the rest of this file applies.
+const MAX_FLOW_EDGES: usize = 20;
+
+/// Writes the `## Resolved Error Flow` section: `?` sites attributed to their specific callee
+/// via [`crate::callindex::build_call_graph`] (rather than the name-based reverse graph
+/// [`write_error_propagation_section`] uses), flagging conversion boundaries where the callee's
+/// `err_type` differs from the caller's, then for a handful of public fallible functions, every
+/// error-originating function their `?` chains can still reach.
+async fn write_resolved_error_flow_section(
+    writer: &mut BufWriter<tokio::fs::File>,
+    result: &PipelineResult,
+    public_fallible: &[&(&str, &ErrorInfo)],
+) -> Result<()> {
+    let cache = build_cache(&result.files);
+    let graph = errorflow::build_error_flow_graph(&cache);
+
+    if graph.edges.is_empty() {
+        return Ok(());
+    }
+
+    writer.write_all(b"## Resolved Error Flow\n\n").await?;
+    writer
+        .write_all(
+            b"`?` sites attributed to the specific callee they propagate from via the \
+resolved call graph, with conversion boundaries (differing `err_type`s, implying `From`/`Into`) \
+flagged.\n\n",
+        )
+        .await?;
+
+    let conversions: Vec<&ErrorFlowEdge> = graph
+        .edges
+        .iter()
+        .filter(|edge| edge.is_conversion)
+        .collect();
+
+    if !conversions.is_empty() {
+        writer.write_all(b"Conversion boundaries:\n\n").await?;
+
+        for edge in conversions.iter().take(MAX_FLOW_EDGES) {
+            let line = format!(
+                "{} -> {} @{}: {} -> {}\n",
+                edge.caller.qualified_name(),
+                edge.callee.qualified_name(),
+                edge.line,
+                edge.caller_err_type.as_deref().unwrap_or("?"),
+                edge.callee_err_type.as_deref().unwrap_or("?"),
+            );
+            writer.write_all(line.as_bytes()).await?;
+        }
+        if conversions.len() > MAX_FLOW_EDGES {
+            let line = format!(
+                "[+{} more conversion boundaries]\n",
+                conversions.len() - MAX_FLOW_EDGES
+            );
+            writer.write_all(line.as_bytes()).await?;
+        }
+        writer.write_all(b"\n").await?;
+
+        let mut transitions: Vec<String> = errorflow::conversion_edges(&graph)
+            .iter()
+            .map(|edge| format!("{} -> {}", edge.from_type, edge.to_type))
+            .collect();
+        transitions.sort();
+        transitions.dedup();
+
+        writer
+            .write_all(b"Type transitions observed (deduplicated):\n\n")
+            .await?;
+        for transition in transitions.iter().take(MAX_FLOW_EDGES) {
+            writer
+                .write_all(format!("- {}\n", transition).as_bytes())
+                .await?;
+        }
+        writer.write_all(b"\n").await?;
+    }
+
+    writer
+        .write_all(b"Origins reachable from public fallible functions:\n\n")
+        .await?;
+
+    for (_, info) in public_fallible.iter().take(MAX_FLOW_EDGES) {
+        let origins = errorflow::origins_reaching(&cache, &graph, &info.function_id);
+        if origins.is_empty() {
+            continue;
+        }
+
+        let names: Vec<String> = origins.iter().map(|id| id.qualified_name()).collect();
+        let line = format!(
+            "{} <- {}\n",
+            info.function_id.qualified_name(),
+            names.join(", ")
+        );
+        writer.write_all(line.as_bytes()).await?;
+    }
+    writer.write_all(b"\n").await?;
+
+    Ok(())
+}
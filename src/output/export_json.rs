@@ -0,0 +1,27 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::export;
+use crate::output::ArtifactDigest;
+use crate::pipeline::PipelineResult;
+
+/// Writes `export.json` (the [`export::AnalysisDocument`] bundling every file's call graph and
+/// error info) and `export-schema.json` (its machine-readable [`export::schema_description`]), so
+/// an external tool can consume charter's call/error model without going through this crate at
+/// all.
+pub async fn write_export_json(
+    atlas_dir: &Path,
+    result: &PipelineResult,
+) -> Result<Vec<ArtifactDigest>> {
+    let document = export::build_document(result);
+    let document_json = serde_json::to_vec_pretty(&document)?;
+    let document_digest = super::digest_buffer("export.json", &document_json);
+    super::write_atomic(&atlas_dir.join("export.json"), &document_json).await?;
+
+    let schema = export::schema_description();
+    let schema_json = serde_json::to_vec_pretty(&schema)?;
+    let schema_digest = super::digest_buffer("export-schema.json", &schema_json);
+    super::write_atomic(&atlas_dir.join("export-schema.json"), &schema_json).await?;
+
+    Ok(vec![document_digest, schema_digest])
+}
@@ -4,15 +4,19 @@ use std::path::{Path, PathBuf};
 use tokio::io::{AsyncWriteExt, BufWriter};
 
 use crate::extract::complexity::{FunctionComplexity, ImportanceTier};
+use crate::output::ArtifactDigest;
 use crate::pipeline::PipelineResult;
+use crate::tests::LcovRecord;
 
 pub async fn write_hotspots(
     atlas_dir: &Path,
     result: &PipelineResult,
     churn_data: &HashMap<PathBuf, u32>,
+    coverage: Option<&HashMap<String, LcovRecord>>,
     stamp: &str,
-) -> Result<()> {
-    let file = tokio::fs::File::create(atlas_dir.join("hotspots.md")).await?;
+) -> Result<ArtifactDigest> {
+    let path = atlas_dir.join("hotspots.md");
+    let (file, tmp_path) = super::create_atomic(&path).await?;
     let mut writer = BufWriter::new(file);
 
     writer.write_all(stamp.as_bytes()).await?;
@@ -53,7 +57,51 @@ pub async fn write_hotspots(
             .write_all(b"No high-complexity functions detected.\n")
             .await?;
         writer.flush().await?;
-        return Ok(());
+        let file = writer.into_inner();
+        super::finish_atomic(file, &tmp_path, &path).await?;
+        return super::digest_written_file("hotspots.md", &path).await;
+    }
+
+    if let Some(coverage) = coverage {
+        let mut untested: Vec<(&String, &FunctionComplexity, f64, f64)> = all_functions
+            .iter()
+            .filter_map(|(file_path, func)| {
+                let ratio = coverage_ratio_for_span(coverage, file_path, func)?;
+                let score = func.metrics.importance_score();
+                let risk = score as f64 * (1.0 - ratio);
+                Some((file_path, func, risk, ratio))
+            })
+            .collect();
+
+        untested.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+
+        if !untested.is_empty() {
+            writer.write_all(b"## Untested Hotspots\n\n").await?;
+            writer
+                .write_all(
+                    b"Functions ranked by risk = importance_score * (1 - coverage_ratio): \
+                      complex/central functions with the weakest line coverage, from \
+                      `.charter/coverage.lcov`.\n\n",
+                )
+                .await?;
+
+            for (file_path, func, risk, ratio) in untested.iter().take(30) {
+                let line = format_hotspot_line(file_path, func, Some(*ratio));
+                writer
+                    .write_all(format!("{line} [risk={risk:.0}]\n").as_bytes())
+                    .await?;
+            }
+
+            if untested.len() > 30 {
+                writer
+                    .write_all(
+                        format!("\n[+{} more untested hotspots]\n", untested.len() - 30).as_bytes(),
+                    )
+                    .await?;
+            }
+
+            writer.write_all(b"\n").await?;
+        }
     }
 
     if !high_tier.is_empty() {
@@ -63,7 +111,8 @@ pub async fn write_hotspots(
             .await?;
 
         for (file_path, func) in high_tier.iter().take(50) {
-            let line = format_hotspot_line(file_path, func);
+            let ratio = coverage.and_then(|c| coverage_ratio_for_span(c, file_path, func));
+            let line = format_hotspot_line(file_path, func, ratio);
             writer.write_all(line.as_bytes()).await?;
             writer.write_all(b"\n").await?;
         }
@@ -90,7 +139,8 @@ pub async fn write_hotspots(
             .await?;
 
         for (file_path, func) in medium_tier.iter().take(30) {
-            let line = format_hotspot_line(file_path, func);
+            let ratio = coverage.and_then(|c| coverage_ratio_for_span(c, file_path, func));
+            let line = format_hotspot_line(file_path, func, ratio);
             writer.write_all(line.as_bytes()).await?;
             writer.write_all(b"\n").await?;
         }
@@ -110,23 +160,29 @@ pub async fn write_hotspots(
 
     writer.write_all(b"\n## Scoring\n\n").await?;
     writer
-        .write_all(b"Score = (cyclomatic * 2) + (lines / 10) + (call_sites * 3) + (churn * 2) + (public ? 10 : 0)\n")
+        .write_all(b"Score = (cyclomatic * 2) + (cognitive * 2) + (lines / 10) + (call_sites * 3) + (churn * 2) + (public ? 10 : 0)\n")
+        .await?;
+    writer
+        .write_all(b"then scaled by (100 + recency bonus up to 30 + author bonus up to 20) / 100; test functions score 0\n")
         .await?;
     writer.write_all(b"- High: >= 30\n").await?;
     writer.write_all(b"- Medium: 15-29\n").await?;
     writer.write_all(b"- Low: < 15 (not shown)\n").await?;
 
     writer.flush().await?;
-    Ok(())
+    let file = writer.into_inner();
+    super::finish_atomic(file, &tmp_path, &path).await?;
+    super::digest_written_file("hotspots.md", &path).await
 }
 
-fn format_hotspot_line(file_path: &str, func: &FunctionComplexity) -> String {
+fn format_hotspot_line(file_path: &str, func: &FunctionComplexity, coverage_ratio: Option<f64>) -> String {
     let qualified = func.qualified_name();
     let metrics = &func.metrics;
     let score = metrics.importance_score();
 
     let mut details = Vec::new();
     details.push(format!("cc={}", metrics.cyclomatic));
+    details.push(format!("cog={}", metrics.cognitive));
     details.push(format!("lines={}", metrics.line_count));
     if metrics.nesting_depth > 2 {
         details.push(format!("depth={}", metrics.nesting_depth));
@@ -137,6 +193,9 @@ fn format_hotspot_line(file_path: &str, func: &FunctionComplexity) -> String {
     if metrics.churn_score > 0 {
         details.push(format!("churn={}", metrics.churn_score));
     }
+    if let Some(ratio) = coverage_ratio {
+        details.push(format!("cov={:.0}%", ratio * 100.0));
+    }
     if metrics.is_public {
         details.push("pub".to_string());
     }
@@ -151,6 +210,35 @@ fn format_hotspot_line(file_path: &str, func: &FunctionComplexity) -> String {
     )
 }
 
+/// Line-hit ratio of `func`'s spanned lines (`func.line .. func.line + line_count`) against
+/// `coverage`'s `DA:` records for its file, or `None` if that file has no matching record or none
+/// of its `DA:` lines fall inside the function's span — in either case there's no real data to
+/// rank the function by, so it's left out of "Untested Hotspots" rather than guessed at.
+fn coverage_ratio_for_span(
+    coverage: &HashMap<String, LcovRecord>,
+    file_path: &str,
+    func: &FunctionComplexity,
+) -> Option<f64> {
+    let record = crate::tests::find_lcov_record(coverage, file_path)?;
+
+    let start = func.line as u32;
+    let end = start + func.metrics.line_count;
+
+    let (found, hit) = record
+        .line_hits
+        .iter()
+        .filter(|&&(line, _)| line >= start && line < end)
+        .fold((0u32, 0u32), |(found, hit), &(_, count)| {
+            (found + 1, hit + if count > 0 { 1 } else { 0 })
+        });
+
+    if found == 0 {
+        return None;
+    }
+
+    Some(hit as f64 / found as f64)
+}
+
 fn update_call_sites(functions: &mut [(String, FunctionComplexity)], result: &PipelineResult) {
     let mut call_counts: HashMap<String, u32> = HashMap::new();
 
@@ -0,0 +1,236 @@
+use anyhow::Result;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+
+use crate::extract::symbols::Visibility;
+use crate::output::ArtifactDigest;
+use crate::pipeline::PipelineResult;
+
+/// One re-export edge harvested from a `pub use` item: the module it was written in,
+/// and either a concrete renamed name or a glob target module.
+enum ReexportEdge {
+    Named {
+        exporting_module: String,
+        alias: String,
+    },
+    Glob {
+        exporting_module: String,
+        target_module: String,
+    },
+}
+
+pub async fn write_imports(
+    root: &Path,
+    atlas_dir: &Path,
+    result: &PipelineResult,
+    stamp: &str,
+) -> Result<ArtifactDigest> {
+    let path = atlas_dir.join("imports.md");
+
+    let mut buffer = Vec::with_capacity(64 * 1024);
+
+    writeln!(buffer, "{}", stamp)?;
+    writeln!(buffer)?;
+
+    let crate_name = super::read_crate_name(root)
+        .await
+        .unwrap_or_else(|| "crate".to_string());
+
+    let declared = collect_public_symbols(result);
+    let edges = collect_reexport_edges(result);
+
+    if declared.is_empty() {
+        writeln!(buffer, "(no public symbols found)")?;
+        super::write_atomic(&path, &buffer).await?;
+        return Ok(super::digest_buffer("imports.md", &buffer));
+    }
+
+    let mut by_file: HashMap<&str, Vec<(&str, String)>> = HashMap::new();
+
+    for (file_path, module_path, name) in &declared {
+        let use_path = shortest_use_path(&crate_name, module_path, name, &edges);
+        by_file
+            .entry(file_path.as_str())
+            .or_default()
+            .push((name.as_str(), use_path));
+    }
+
+    let mut files: Vec<_> = by_file.into_iter().collect();
+    files.sort_by(|a, b| a.0.cmp(b.0));
+
+    for (file_path, mut symbols) in files {
+        symbols.sort();
+        writeln!(
+            buffer,
+            "{} [{} public symbol{}]",
+            file_path,
+            symbols.len(),
+            if symbols.len() == 1 { "" } else { "s" }
+        )?;
+        for (_, use_path) in symbols {
+            writeln!(buffer, "  use {};", use_path)?;
+        }
+        writeln!(buffer)?;
+    }
+
+    super::write_atomic(&path, &buffer).await?;
+    Ok(super::digest_buffer("imports.md", &buffer))
+}
+
+/// (file_path, declaring module path, symbol name) for every publicly visible symbol.
+fn collect_public_symbols(result: &PipelineResult) -> Vec<(String, String, String)> {
+    let mut declared = Vec::new();
+
+    for file_result in &result.files {
+        let module_path = super::module_path_from_file(&file_result.relative_path);
+        for symbol in &file_result.parsed.symbols.symbols {
+            if symbol.visibility == Visibility::Public {
+                declared.push((
+                    file_result.relative_path.clone(),
+                    module_path.clone(),
+                    symbol.name.clone(),
+                ));
+            }
+        }
+    }
+
+    declared
+}
+
+fn collect_reexport_edges(result: &PipelineResult) -> Vec<ReexportEdge> {
+    let mut edges = Vec::new();
+
+    for file_result in &result.files {
+        let exporting_module = super::module_path_from_file(&file_result.relative_path);
+
+        for re_export in &file_result.parsed.re_exports {
+            if re_export.visibility != Visibility::Public {
+                continue;
+            }
+
+            let source = re_export.source_path.trim();
+
+            if let Some(target) = source.strip_suffix("::*") {
+                if let Some(target_module) = normalize_local_module(target, &exporting_module) {
+                    edges.push(ReexportEdge::Glob {
+                        exporting_module: exporting_module.clone(),
+                        target_module,
+                    });
+                }
+                continue;
+            }
+
+            for alias in parse_reexport_aliases(source) {
+                edges.push(ReexportEdge::Named {
+                    exporting_module: exporting_module.clone(),
+                    alias,
+                });
+            }
+        }
+    }
+
+    edges
+}
+
+/// Extracts the name(s) a `pub use` item binds in its own module, after any `as` rename
+/// and after expanding brace groups, e.g. `foo::{Bar, Baz as Qux}` -> `["Bar", "Qux"]`.
+fn parse_reexport_aliases(source: &str) -> Vec<String> {
+    if let Some(brace_start) = source.find('{') {
+        let end = source.find('}').unwrap_or(source.len());
+        let inner = &source[brace_start + 1..end];
+        return inner
+            .split(',')
+            .filter_map(|item| {
+                let item = item.trim();
+                if item.is_empty() || item == "self" {
+                    None
+                } else {
+                    Some(last_bound_name(item))
+                }
+            })
+            .collect();
+    }
+
+    vec![last_bound_name(source)]
+}
+
+fn last_bound_name(item: &str) -> String {
+    if let Some(as_pos) = item.find(" as ") {
+        item[as_pos + 4..].trim().to_string()
+    } else {
+        item.rsplit("::").next().unwrap_or(item).trim().to_string()
+    }
+}
+
+/// Resolves a `crate::`/`self::`-prefixed path written inside `exporting_module` to a
+/// crate-relative module path. Paths referring to other crates are left unresolved.
+fn normalize_local_module(path: &str, exporting_module: &str) -> Option<String> {
+    if let Some(rest) = path.strip_prefix("crate::") {
+        return Some(rest.trim_end_matches("::").to_string());
+    }
+    if path == "crate" {
+        return Some(String::new());
+    }
+    if let Some(rest) = path.strip_prefix("self::") {
+        return Some(if exporting_module.is_empty() {
+            rest.to_string()
+        } else {
+            format!("{}::{}", exporting_module, rest)
+        });
+    }
+    None
+}
+
+/// Picks the shortest path through which `name` (declared in `module_path`) is publicly
+/// reachable: its own declaration path, or any `pub use` edge that rebinds it closer to
+/// the crate root. Ties break alphabetically for determinism.
+fn shortest_use_path(
+    crate_name: &str,
+    module_path: &str,
+    name: &str,
+    edges: &[ReexportEdge],
+) -> String {
+    let declared = qualify(crate_name, module_path, name);
+    let mut best = declared;
+
+    for edge in edges {
+        let candidate = match edge {
+            ReexportEdge::Named {
+                exporting_module,
+                alias,
+            } if alias == name => Some(qualify(crate_name, exporting_module, name)),
+            ReexportEdge::Glob {
+                exporting_module,
+                target_module,
+            } if target_module == module_path => Some(qualify(crate_name, exporting_module, name)),
+            _ => None,
+        };
+
+        if let Some(candidate) = candidate {
+            if is_shorter(&candidate, &best) {
+                best = candidate;
+            }
+        }
+    }
+
+    best
+}
+
+fn qualify(crate_name: &str, module_path: &str, name: &str) -> String {
+    if module_path.is_empty() {
+        format!("{crate_name}::{name}")
+    } else {
+        format!("{crate_name}::{module_path}::{name}")
+    }
+}
+
+fn is_shorter(candidate: &str, current: &str) -> bool {
+    let candidate_segments = candidate.matches("::").count();
+    let current_segments = current.matches("::").count();
+    match candidate_segments.cmp(&current_segments) {
+        std::cmp::Ordering::Less => true,
+        std::cmp::Ordering::Equal => candidate < current,
+        std::cmp::Ordering::Greater => false,
+    }
+}
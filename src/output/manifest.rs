@@ -2,9 +2,8 @@ use anyhow::Result;
 use std::collections::HashMap;
 use std::io::Write;
 use std::path::{Path, PathBuf};
-use tokio::fs::File;
-use tokio::io::AsyncWriteExt;
 
+use crate::output::ArtifactDigest;
 use crate::pipeline::PipelineResult;
 
 pub async fn write_manifest(
@@ -12,9 +11,8 @@ pub async fn write_manifest(
     result: &PipelineResult,
     churn_data: &HashMap<PathBuf, u32>,
     stamp: &str,
-) -> Result<()> {
+) -> Result<ArtifactDigest> {
     let path = atlas_dir.join("manifest.md");
-    let mut file = File::create(&path).await?;
 
     let mut buffer = Vec::with_capacity(64 * 1024);
 
@@ -29,6 +27,7 @@ pub async fn write_manifest(
         let churn_count = churn_data.get(&file_result.path).copied().unwrap_or(0);
         let churn_label = super::churn_label(churn_count, high_threshold, med_threshold);
         let role = super::file_role(&file_result.path);
+        let status_label = super::git_status_label(file_result.git_status);
 
         write!(
             buffer,
@@ -36,6 +35,10 @@ pub async fn write_manifest(
             file_result.relative_path, file_result.lines, role, churn_label
         )?;
 
+        if !status_label.is_empty() {
+            write!(buffer, " {}", status_label)?;
+        }
+
         let mut test_info = Vec::new();
 
         if file_result.parsed.has_test_module {
@@ -66,8 +69,8 @@ pub async fn write_manifest(
         writeln!(buffer)?;
     }
 
-    file.write_all(&buffer).await?;
-    Ok(())
+    super::write_atomic(&path, &buffer).await?;
+    Ok(super::digest_buffer("manifest.md", &buffer))
 }
 
 fn calculate_churn_thresholds(churn_data: &HashMap<PathBuf, u32>) -> (u32, u32) {
@@ -0,0 +1,45 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::output::ArtifactDigest;
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ManifestEntry {
+    name: String,
+    bytes: usize,
+    sha256: String,
+}
+
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ManifestJson {
+    stamp: String,
+    artifacts: Vec<ManifestEntry>,
+}
+
+/// Writes `manifest.json`, a content-addressed record of every report this capture
+/// generated: its filename, byte length, and a SHA-256 of its contents, alongside the
+/// `stamp` string. Downstream tooling can diff this against a prior run to tell whether
+/// the analysis output actually changed without reading the (often much larger) markdown
+/// reports themselves, and to verify a distributed `.atlas`/`.charter` directory wasn't
+/// corrupted or truncated in transit.
+pub async fn write_manifest_json(
+    charter_dir: &Path,
+    digests: &[ArtifactDigest],
+    stamp: &str,
+) -> Result<()> {
+    let manifest = ManifestJson {
+        stamp: stamp.to_string(),
+        artifacts: digests
+            .iter()
+            .map(|d| ManifestEntry {
+                name: d.name.to_string(),
+                bytes: d.bytes,
+                sha256: d.sha256.clone(),
+            })
+            .collect(),
+    };
+
+    let content = serde_json::to_string_pretty(&manifest)?;
+    super::write_atomic(&charter_dir.join("manifest.json"), content.as_bytes()).await?;
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::export;
+use crate::output::ArtifactDigest;
+use crate::pipeline::PipelineResult;
+
+/// Writes `model.json` (the [`export::ModelDocument`] bundling every file's extracted symbols
+/// behind a stable `Id`), opt-in via `charter --format json` since it duplicates `symbols.md` in a
+/// much larger, machine-oriented form. Lets an editor or CI tool resolve charter's structural
+/// model without parsing the markdown output.
+pub async fn write_model_json(atlas_dir: &Path, result: &PipelineResult) -> Result<ArtifactDigest> {
+    let document = export::build_model_document(result);
+    let document_json = serde_json::to_vec_pretty(&document)?;
+    let digest = super::digest_buffer("model.json", &document_json);
+    super::write_atomic(&atlas_dir.join("model.json"), &document_json).await?;
+
+    Ok(digest)
+}
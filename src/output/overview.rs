@@ -1,115 +1,157 @@
 use anyhow::Result;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::io::Write;
 use std::path::Path;
-use tokio::fs::File;
-use tokio::io::AsyncWriteExt;
 
-use crate::detect::{
-    CrateInfo, CrateType, ProjectKind, PythonEntryKind, PythonPackageInfo, TargetKind,
-};
+use crate::detect::{CrateInfo, CrateType, ProjectKind, PythonEntryKind, TargetKind};
 use crate::extract::language::Language;
+use crate::output::ArtifactDigest;
 use crate::pipeline::PipelineResult;
 
-pub async fn write_overview(
-    charter_dir: &Path,
-    result: &PipelineResult,
-    stamp: &str,
-) -> Result<()> {
-    let path = charter_dir.join("overview.md");
-    let mut file = File::create(&path).await?;
+/// One module file in a crate's or package's tree, with its doc comment (if any) untruncated —
+/// [`write_module_tree`]/[`write_python_module_tree`] truncate `module_doc` to 80 characters only
+/// when rendering text, so [`super::overview_json::write_overview_json`] can emit the full string.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ModuleEntry {
+    pub path: String,
+    pub module_doc: Option<String>,
+}
 
-    let mut buffer = Vec::with_capacity(64 * 1024);
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct CrateOverview {
+    pub name: String,
+    pub crate_type: CrateType,
+    pub dependencies: Vec<String>,
+    pub modules: Vec<ModuleEntry>,
+}
 
-    writeln!(buffer, "{}", stamp)?;
-    writeln!(buffer)?;
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct EntryPointOverview {
+    pub name: String,
+    pub kind: TargetKind,
+    pub path: String,
+}
 
-    writeln!(buffer, "Project: {}", result.workspace.project_kind)?;
-    writeln!(buffer)?;
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct FeatureOverview {
+    pub name: String,
+    pub gated_files: Vec<String>,
+}
 
-    match result.workspace.project_kind {
-        ProjectKind::Rust => {
-            write_rust_overview(&mut buffer, result)?;
-        }
-        ProjectKind::Python => {
-            write_python_overview(&mut buffer, result)?;
-        }
-        ProjectKind::Mixed => {
-            write_rust_overview(&mut buffer, result)?;
-            write_python_overview(&mut buffer, result)?;
-        }
-        ProjectKind::Unknown => {
-            writeln!(buffer, "Unknown project type.")?;
-        }
-    }
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct PythonEntryPointOverview {
+    pub name: String,
+    pub kind: PythonEntryKind,
+    pub module: String,
+}
 
-    file.write_all(&buffer).await?;
-    Ok(())
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct PythonPackageOverview {
+    pub name: String,
+    pub version: Option<String>,
+    pub modules: Vec<ModuleEntry>,
+    pub entry_points: Vec<PythonEntryPointOverview>,
 }
 
-fn write_rust_overview(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
-    if result.workspace.is_workspace {
-        writeln!(buffer, "Workspace:")?;
-        for crate_info in &result.workspace.members {
-            write_crate_line(buffer, crate_info)?;
-        }
-        writeln!(buffer)?;
-    }
+/// The project model [`write_overview`] renders to text and
+/// [`super::overview_json::write_overview_json`] serializes verbatim — one source of truth for
+/// both so the JSON export never drifts from what `overview.md` describes.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct OverviewModel {
+    pub project_kind: String,
+    pub is_workspace: bool,
+    pub crates: Vec<CrateOverview>,
+    pub entry_points: Vec<EntryPointOverview>,
+    pub features: Vec<FeatureOverview>,
+    pub python_packages: Vec<PythonPackageOverview>,
+    pub python_dependencies: Vec<String>,
+}
+
+pub(crate) fn build_overview_model(result: &PipelineResult) -> OverviewModel {
+    let mut model = OverviewModel {
+        project_kind: result.workspace.project_kind.to_string(),
+        is_workspace: result.workspace.is_workspace,
+        crates: Vec::new(),
+        entry_points: Vec::new(),
+        features: Vec::new(),
+        python_packages: Vec::new(),
+        python_dependencies: Vec::new(),
+    };
 
-    for crate_info in &result.workspace.members {
-        write_module_tree(buffer, result, crate_info)?;
+    match result.workspace.project_kind {
+        ProjectKind::Rust => populate_rust_model(&mut model, result),
+        ProjectKind::Python => populate_python_model(&mut model, result),
+        ProjectKind::Mixed => {
+            populate_rust_model(&mut model, result);
+            populate_python_model(&mut model, result);
+        }
+        ProjectKind::Unknown => {}
     }
 
-    write_entry_points(buffer, result)?;
-    write_features(buffer, result)?;
+    model
+}
 
-    Ok(())
+fn populate_rust_model(model: &mut OverviewModel, result: &PipelineResult) {
+    model.crates = result
+        .workspace
+        .members
+        .iter()
+        .map(|crate_info| build_crate_overview(result, crate_info))
+        .collect();
+    model.entry_points = collect_entry_points(result);
+    model.features = collect_features(result);
 }
 
-fn write_python_overview(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
-    for package in &result.workspace.python_packages {
-        write_python_package(buffer, package)?;
-        write_python_module_tree(buffer, result, package)?;
-        write_python_entry_points(buffer, package)?;
-    }
+fn populate_python_model(model: &mut OverviewModel, result: &PipelineResult) {
+    let python_modules = collect_python_modules(result);
 
-    write_python_dependencies(buffer, result)?;
+    model.python_packages = result
+        .workspace
+        .python_packages
+        .iter()
+        .map(|package| PythonPackageOverview {
+            name: package.name.clone(),
+            version: package.version.clone(),
+            modules: python_modules.clone(),
+            entry_points: package
+                .entry_points
+                .iter()
+                .map(|entry| PythonEntryPointOverview {
+                    name: entry.name.clone(),
+                    kind: entry.kind,
+                    module: entry.module.clone(),
+                })
+                .collect(),
+        })
+        .collect();
 
-    Ok(())
+    model.python_dependencies = result
+        .workspace
+        .python_packages
+        .iter()
+        .flat_map(|p| p.dependencies.iter().cloned())
+        .collect();
 }
 
-fn write_crate_line(buffer: &mut Vec<u8>, crate_info: &CrateInfo) -> Result<()> {
-    let crate_type = match crate_info.crate_type {
-        CrateType::Lib => "[lib]",
-        CrateType::Bin => "[bin]",
-        CrateType::ProcMacro => "[proc-macro]",
-    };
-
-    let deps: String = crate_info
+fn build_crate_overview(result: &PipelineResult, crate_info: &CrateInfo) -> CrateOverview {
+    let dependencies: Vec<String> = crate_info
         .dependencies
         .iter()
         .filter(|d| crate_info.dependencies.contains(d))
         .take(3)
         .cloned()
-        .collect::<Vec<_>>()
-        .join(", ");
+        .collect();
 
-    if deps.is_empty() {
-        writeln!(buffer, "  {} {}", crate_info.name, crate_type)?;
-    } else {
-        writeln!(buffer, "  {} {} -> {}", crate_info.name, crate_type, deps)?;
+    CrateOverview {
+        name: crate_info.name.clone(),
+        crate_type: crate_info.crate_type,
+        dependencies,
+        modules: collect_modules(result, crate_info),
     }
-
-    Ok(())
 }
 
-fn write_module_tree(
-    buffer: &mut Vec<u8>,
-    result: &PipelineResult,
-    crate_info: &CrateInfo,
-) -> Result<()> {
-    writeln!(buffer, "crate {}", crate_info.name)?;
-
+fn collect_modules(result: &PipelineResult, crate_info: &CrateInfo) -> Vec<ModuleEntry> {
     let crate_path_str = crate_info.path.to_string_lossy().replace('\\', "/");
 
     let mut module_files: Vec<_> = result
@@ -136,6 +178,7 @@ fn write_module_tree(
     });
 
     let mut seen_modules: HashMap<String, bool> = HashMap::new();
+    let mut modules = Vec::new();
 
     for file in module_files {
         let path = &file.relative_path;
@@ -151,23 +194,13 @@ fn write_module_tree(
         }
         seen_modules.insert(module_path.clone(), true);
 
-        let indent = "  ".repeat(path.matches('/').count().saturating_sub(1) + 1);
-        let doc = file.parsed.module_doc.as_deref().unwrap_or("");
-
-        if doc.is_empty() {
-            writeln!(buffer, "{}{}", indent, path)?;
-        } else {
-            let doc_truncated = if doc.len() > 80 {
-                format!("{}...", &doc[..77])
-            } else {
-                doc.to_string()
-            };
-            writeln!(buffer, "{}{} - \"{}\"", indent, path, doc_truncated)?;
-        }
+        modules.push(ModuleEntry {
+            path: path.clone(),
+            module_doc: file.parsed.module_doc.clone(),
+        });
     }
 
-    writeln!(buffer)?;
-    Ok(())
+    modules
 }
 
 fn path_to_module_path(path: &str) -> String {
@@ -176,23 +209,13 @@ fn path_to_module_path(path: &str) -> String {
     path.replace('/', "::")
 }
 
-fn write_entry_points(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
-    let mut has_entries = false;
-
-    for crate_info in &result.workspace.members {
-        for target in &crate_info.targets {
-            if !has_entries {
-                writeln!(buffer, "Entry points:")?;
-                has_entries = true;
-            }
-
-            let kind = match target.kind {
-                TargetKind::Lib => "[lib]",
-                TargetKind::Bin => "[bin]",
-                TargetKind::Example => "[example]",
-                TargetKind::Bench => "[bench]",
-            };
-
+fn collect_entry_points(result: &PipelineResult) -> Vec<EntryPointOverview> {
+    result
+        .workspace
+        .members
+        .iter()
+        .flat_map(|crate_info| &crate_info.targets)
+        .map(|target| {
             let path_display = target
                 .path
                 .strip_prefix(&result.workspace.root)
@@ -200,77 +223,44 @@ fn write_entry_points(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<(
                 .to_string_lossy()
                 .replace('\\', "/");
 
-            writeln!(buffer, "  {} {} -> {}", target.name, kind, path_display)?;
-        }
-    }
-
-    if has_entries {
-        writeln!(buffer)?;
-    }
-
-    Ok(())
-}
-
-fn write_features(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
-    let mut has_features = false;
-
-    for crate_info in &result.workspace.members {
-        if !crate_info.features.is_empty() {
-            if !has_features {
-                writeln!(buffer, "Features:")?;
-                has_features = true;
+            EntryPointOverview {
+                name: target.name.clone(),
+                kind: target.kind,
+                path: path_display,
             }
-
-            for feature in &crate_info.features {
-                let gated_files: Vec<_> = result
-                    .files
-                    .iter()
-                    .filter(|f| {
-                        f.parsed
-                            .cfgs
-                            .iter()
-                            .any(|cfg| cfg.condition.contains(&feature.name))
-                    })
-                    .map(|f| f.relative_path.clone())
-                    .take(5)
-                    .collect();
-
-                if gated_files.is_empty() {
-                    writeln!(buffer, "  {}", feature.name)?;
-                } else {
-                    writeln!(
-                        buffer,
-                        "  {} - gates: {}",
-                        feature.name,
-                        gated_files.join(", ")
-                    )?;
-                }
-            }
-        }
-    }
-
-    if has_features {
-        writeln!(buffer)?;
-    }
-
-    Ok(())
+        })
+        .collect()
 }
 
-fn write_python_package(buffer: &mut Vec<u8>, package: &PythonPackageInfo) -> Result<()> {
-    write!(buffer, "package {}", package.name)?;
-    if let Some(version) = &package.version {
-        write!(buffer, " v{}", version)?;
-    }
-    writeln!(buffer)?;
-
-    Ok(())
+fn collect_features(result: &PipelineResult) -> Vec<FeatureOverview> {
+    result
+        .workspace
+        .members
+        .iter()
+        .flat_map(|crate_info| &crate_info.features)
+        .map(|feature| {
+            let gated_files: Vec<String> = result
+                .files
+                .iter()
+                .filter(|f| {
+                    f.parsed
+                        .cfgs
+                        .iter()
+                        .any(|cfg| cfg.condition.contains(&feature.name))
+                })
+                .map(|f| f.relative_path.clone())
+                .take(5)
+                .collect();
+
+            FeatureOverview {
+                name: feature.name.clone(),
+                gated_files,
+            }
+        })
+        .collect()
 }
 
-fn write_python_module_tree(
-    buffer: &mut Vec<u8>,
-    result: &PipelineResult,
-    _package: &PythonPackageInfo,
-) -> Result<()> {
+fn collect_python_modules(result: &PipelineResult) -> Vec<ModuleEntry> {
     let mut python_files: Vec<_> = result
         .files
         .iter()
@@ -290,6 +280,7 @@ fn write_python_module_tree(
     });
 
     let mut seen_modules: HashMap<String, bool> = HashMap::new();
+    let mut modules = Vec::new();
 
     for file in python_files {
         let path = &file.relative_path;
@@ -305,23 +296,13 @@ fn write_python_module_tree(
         }
         seen_modules.insert(module_path.clone(), true);
 
-        let indent = "  ".repeat(path.matches('/').count() + 1);
-        let doc = file.parsed.module_doc.as_deref().unwrap_or("");
-
-        if doc.is_empty() {
-            writeln!(buffer, "{}{}", indent, path)?;
-        } else {
-            let doc_truncated = if doc.len() > 80 {
-                format!("{}...", &doc[..77])
-            } else {
-                doc.to_string()
-            };
-            writeln!(buffer, "{}{} - \"{}\"", indent, path, doc_truncated)?;
-        }
+        modules.push(ModuleEntry {
+            path: path.clone(),
+            module_doc: file.parsed.module_doc.clone(),
+        });
     }
 
-    writeln!(buffer)?;
-    Ok(())
+    modules
 }
 
 fn python_path_to_module_path(path: &str) -> String {
@@ -332,7 +313,189 @@ fn python_path_to_module_path(path: &str) -> String {
     path.replace('/', ".")
 }
 
-fn write_python_entry_points(buffer: &mut Vec<u8>, package: &PythonPackageInfo) -> Result<()> {
+pub async fn write_overview(
+    charter_dir: &Path,
+    result: &PipelineResult,
+    stamp: &str,
+) -> Result<ArtifactDigest> {
+    let path = charter_dir.join("overview.md");
+
+    let mut buffer = Vec::with_capacity(64 * 1024);
+
+    writeln!(buffer, "{}", stamp)?;
+    writeln!(buffer)?;
+
+    writeln!(buffer, "Project: {}", result.workspace.project_kind)?;
+    writeln!(buffer)?;
+
+    let model = build_overview_model(result);
+
+    match result.workspace.project_kind {
+        ProjectKind::Rust => {
+            write_rust_overview(&mut buffer, &model)?;
+        }
+        ProjectKind::Python => {
+            write_python_overview(&mut buffer, &model)?;
+        }
+        ProjectKind::Mixed => {
+            write_rust_overview(&mut buffer, &model)?;
+            write_python_overview(&mut buffer, &model)?;
+        }
+        ProjectKind::Unknown => {
+            writeln!(buffer, "Unknown project type.")?;
+        }
+    }
+
+    super::write_atomic(&path, &buffer).await?;
+    Ok(super::digest_buffer("overview.md", &buffer))
+}
+
+fn write_rust_overview(buffer: &mut Vec<u8>, model: &OverviewModel) -> Result<()> {
+    if model.is_workspace {
+        writeln!(buffer, "Workspace:")?;
+        for crate_overview in &model.crates {
+            write_crate_line(buffer, crate_overview)?;
+        }
+        writeln!(buffer)?;
+    }
+
+    for crate_overview in &model.crates {
+        write_module_tree(buffer, crate_overview)?;
+    }
+
+    write_entry_points(buffer, model)?;
+    write_features(buffer, model)?;
+
+    Ok(())
+}
+
+fn write_python_overview(buffer: &mut Vec<u8>, model: &OverviewModel) -> Result<()> {
+    for package in &model.python_packages {
+        write_python_package(buffer, package)?;
+        write_python_module_entries(buffer, &package.modules)?;
+        write_python_entry_points(buffer, package)?;
+    }
+
+    write_python_dependencies(buffer, model)?;
+
+    Ok(())
+}
+
+fn write_crate_line(buffer: &mut Vec<u8>, crate_overview: &CrateOverview) -> Result<()> {
+    let crate_type = match crate_overview.crate_type {
+        CrateType::Lib => "[lib]",
+        CrateType::Bin => "[bin]",
+        CrateType::ProcMacro => "[proc-macro]",
+    };
+
+    let deps = crate_overview.dependencies.join(", ");
+
+    if deps.is_empty() {
+        writeln!(buffer, "  {} {}", crate_overview.name, crate_type)?;
+    } else {
+        writeln!(
+            buffer,
+            "  {} {} -> {}",
+            crate_overview.name, crate_type, deps
+        )?;
+    }
+
+    Ok(())
+}
+
+fn write_module_tree(buffer: &mut Vec<u8>, crate_overview: &CrateOverview) -> Result<()> {
+    writeln!(buffer, "crate {}", crate_overview.name)?;
+    for module in &crate_overview.modules {
+        let indent = "  ".repeat(module.path.matches('/').count().saturating_sub(1) + 1);
+        write_module_line(buffer, &indent, module)?;
+    }
+    writeln!(buffer)?;
+    Ok(())
+}
+
+fn write_python_module_entries(buffer: &mut Vec<u8>, modules: &[ModuleEntry]) -> Result<()> {
+    for module in modules {
+        let indent = "  ".repeat(module.path.matches('/').count() + 1);
+        write_module_line(buffer, &indent, module)?;
+    }
+    writeln!(buffer)?;
+    Ok(())
+}
+
+/// Renders one module's path (and, if present, its doc comment truncated to 80 characters — the
+/// JSON export keeps the full string via [`ModuleEntry`]) at `indent`.
+fn write_module_line(buffer: &mut Vec<u8>, indent: &str, module: &ModuleEntry) -> Result<()> {
+    let doc = module.module_doc.as_deref().unwrap_or("");
+
+    if doc.is_empty() {
+        writeln!(buffer, "{}{}", indent, module.path)?;
+    } else {
+        let doc_truncated = if doc.len() > 80 {
+            format!("{}...", &doc[..77])
+        } else {
+            doc.to_string()
+        };
+        writeln!(buffer, "{}{} - \"{}\"", indent, module.path, doc_truncated)?;
+    }
+
+    Ok(())
+}
+
+fn write_entry_points(buffer: &mut Vec<u8>, model: &OverviewModel) -> Result<()> {
+    if model.entry_points.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(buffer, "Entry points:")?;
+    for entry in &model.entry_points {
+        let kind = match entry.kind {
+            TargetKind::Lib => "[lib]",
+            TargetKind::Bin => "[bin]",
+            TargetKind::Example => "[example]",
+            TargetKind::Bench => "[bench]",
+        };
+
+        writeln!(buffer, "  {} {} -> {}", entry.name, kind, entry.path)?;
+    }
+    writeln!(buffer)?;
+
+    Ok(())
+}
+
+fn write_features(buffer: &mut Vec<u8>, model: &OverviewModel) -> Result<()> {
+    if model.features.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(buffer, "Features:")?;
+    for feature in &model.features {
+        if feature.gated_files.is_empty() {
+            writeln!(buffer, "  {}", feature.name)?;
+        } else {
+            writeln!(
+                buffer,
+                "  {} - gates: {}",
+                feature.name,
+                feature.gated_files.join(", ")
+            )?;
+        }
+    }
+    writeln!(buffer)?;
+
+    Ok(())
+}
+
+fn write_python_package(buffer: &mut Vec<u8>, package: &PythonPackageOverview) -> Result<()> {
+    write!(buffer, "package {}", package.name)?;
+    if let Some(version) = &package.version {
+        write!(buffer, " v{}", version)?;
+    }
+    writeln!(buffer)?;
+
+    Ok(())
+}
+
+fn write_python_entry_points(buffer: &mut Vec<u8>, package: &PythonPackageOverview) -> Result<()> {
     if package.entry_points.is_empty() {
         return Ok(());
     }
@@ -351,20 +514,13 @@ fn write_python_entry_points(buffer: &mut Vec<u8>, package: &PythonPackageInfo)
     Ok(())
 }
 
-fn write_python_dependencies(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
-    let all_deps: Vec<_> = result
-        .workspace
-        .python_packages
-        .iter()
-        .flat_map(|p| &p.dependencies)
-        .collect();
-
-    if all_deps.is_empty() {
+fn write_python_dependencies(buffer: &mut Vec<u8>, model: &OverviewModel) -> Result<()> {
+    if model.python_dependencies.is_empty() {
         return Ok(());
     }
 
     writeln!(buffer, "Dependencies:")?;
-    for dep in all_deps {
+    for dep in &model.python_dependencies {
         writeln!(buffer, "  {}", dep)?;
     }
     writeln!(buffer)?;
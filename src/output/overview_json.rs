@@ -0,0 +1,22 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::output::overview::build_overview_model;
+use crate::output::ArtifactDigest;
+use crate::pipeline::PipelineResult;
+
+/// Writes `overview.json`, the same workspace/module/entry-point/feature model `overview.md`
+/// renders as text, serialized via [`crate::output::overview::build_overview_model`] so the two
+/// never drift apart. Lets editor plugins and dashboards parse the project model directly
+/// instead of scraping the markdown.
+pub async fn write_overview_json(
+    charter_dir: &Path,
+    result: &PipelineResult,
+) -> Result<ArtifactDigest> {
+    let model = build_overview_model(result);
+    let content = serde_json::to_vec_pretty(&model)?;
+    let digest = super::digest_buffer("overview.json", &content);
+    super::write_atomic(&charter_dir.join("overview.json"), &content).await?;
+
+    Ok(digest)
+}
@@ -3,8 +3,11 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::crossref;
 use crate::detect::WorkspaceInfo;
+use crate::output::module_path_from_file;
 use crate::pipeline::PipelineResult;
+use crate::visibility::{self, EffectiveVisibility};
 
 pub fn generate_preamble(
     result: &PipelineResult,
@@ -30,6 +33,10 @@ pub fn generate_preamble(
         lines.push(most_depended);
     }
 
+    if let Some(over_exposed) = format_over_exposed(result) {
+        lines.push(over_exposed);
+    }
+
     if let Some(high_churn) = format_high_churn(result, churn_data) {
         lines.push(high_churn);
     }
@@ -180,29 +187,26 @@ fn format_entry_points(workspace: &WorkspaceInfo) -> Option<String> {
 }
 
 fn format_key_traits(result: &PipelineResult) -> Option<String> {
-    let mut trait_impl_counts: HashMap<String, usize> = HashMap::new();
+    let resolved = crossref::resolve_trait_implementors(result);
 
-    for file in &result.files {
-        for (trait_name, _type_name) in &file.parsed.symbols.impl_map {
-            let simple_name = trait_name.split('<').next().unwrap_or(trait_name);
-            *trait_impl_counts
-                .entry(simple_name.to_string())
-                .or_default() += 1;
-        }
-    }
-
-    if trait_impl_counts.is_empty() {
-        return None;
-    }
-
-    let mut sorted: Vec<_> = trait_impl_counts.into_iter().collect();
-    sorted.sort_by(|a, b| b.1.cmp(&a.1));
-
-    let top_traits: Vec<String> = sorted
+    let top_traits: Vec<String> = resolved
         .into_iter()
-        .filter(|(name, count)| *count >= 3 && !is_std_trait(name))
+        .filter(|trait_impls| {
+            trait_impls.implementors.len() >= 3 && !is_std_trait(&trait_impls.trait_name)
+        })
         .take(5)
-        .map(|(name, count)| format!("{} ({} impls)", name, count))
+        .map(|trait_impls| {
+            let implementor_names: Vec<&str> = trait_impls
+                .implementors
+                .iter()
+                .map(|implementor| implementor.type_name.as_str())
+                .collect();
+            format!(
+                "{} (impls: {})",
+                trait_impls.trait_name,
+                implementor_names.join(", ")
+            )
+        })
         .collect();
 
     if top_traits.is_empty() {
@@ -211,7 +215,7 @@ fn format_key_traits(result: &PipelineResult) -> Option<String> {
 
     Some(format!(
         "Key traits (most implemented): {}.",
-        top_traits.join(", ")
+        top_traits.join("; ")
     ))
 }
 
@@ -277,6 +281,36 @@ fn format_most_depended(result: &PipelineResult) -> Option<String> {
     Some(format!("Most-depended-on: {}.", top_files.join(", ")))
 }
 
+/// Flags `pub` items that are unreachable from the crate root — sitting inside a `pub(crate)` or
+/// private module — the same "reduce visibility of items not publicly exported" cleanup signal
+/// large crates apply manually. See [`crate::visibility::compute_reachability`].
+fn format_over_exposed(result: &PipelineResult) -> Option<String> {
+    let over_exposed: Vec<_> = visibility::compute_reachability(result)
+        .into_iter()
+        .filter(|reachability| {
+            !reachability.is_module && reachability.effective == EffectiveVisibility::OverExposed
+        })
+        .collect();
+
+    if over_exposed.is_empty() {
+        return None;
+    }
+
+    let example = &over_exposed[0];
+    let module = module_path_from_file(&example.file);
+    let example_path = if module.is_empty() {
+        example.name.clone()
+    } else {
+        format!("{}::{}", module, example.name)
+    };
+
+    Some(format!(
+        "Over-exposed: {} pub items unreachable from crate root (e.g. {}).",
+        over_exposed.len(),
+        example_path
+    ))
+}
+
 fn format_high_churn(
     result: &PipelineResult,
     churn_data: &HashMap<PathBuf, u32>,
@@ -1,21 +1,22 @@
 use anyhow::Result;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::Write;
 use std::path::Path;
-use tokio::fs::File;
-use tokio::io::AsyncWriteExt;
+
+use crate::output::ArtifactDigest;
 
 const MAX_FILES_PER_SYMBOL: usize = 6;
 const MAX_SYMBOLS: usize = 300;
 const MIN_FILES_THRESHOLD: usize = 2;
+const MIN_CLUSTER_SHARED_FILES: usize = 2;
 
 pub async fn write_refs(
     charter_dir: &Path,
     references: &HashMap<String, Vec<(String, usize)>>,
     stamp: &str,
-) -> Result<()> {
+    cluster: bool,
+) -> Result<ArtifactDigest> {
     let path = charter_dir.join("refs.md");
-    let mut file = File::create(&path).await?;
 
     let mut buffer = Vec::with_capacity(64 * 1024);
 
@@ -24,8 +25,8 @@ pub async fn write_refs(
 
     if references.is_empty() {
         writeln!(buffer, "(no cross-references found)")?;
-        file.write_all(&buffer).await?;
-        return Ok(());
+        super::write_atomic(&path, &buffer).await?;
+        return Ok(super::digest_buffer("refs.md", &buffer));
     }
 
     let mut processed: Vec<ProcessedSymbol> = references
@@ -39,14 +40,17 @@ pub async fn write_refs(
             .then_with(|| a.name.cmp(&b.name))
     });
 
-    processed.truncate(MAX_SYMBOLS);
-
-    for symbol in &processed {
-        write_symbol_line(&mut buffer, symbol)?;
+    if cluster {
+        write_clustered_refs(&mut buffer, processed)?;
+    } else {
+        processed.truncate(MAX_SYMBOLS);
+        for symbol in &processed {
+            write_symbol_line(&mut buffer, symbol)?;
+        }
     }
 
-    file.write_all(&buffer).await?;
-    Ok(())
+    super::write_atomic(&path, &buffer).await?;
+    Ok(super::digest_buffer("refs.md", &buffer))
 }
 
 struct ProcessedSymbol {
@@ -54,6 +58,7 @@ struct ProcessedSymbol {
     total_refs: usize,
     file_refs: Vec<FileRef>,
     total_files: usize,
+    all_files: Vec<String>,
 }
 
 struct FileRef {
@@ -78,6 +83,9 @@ fn process_symbol(name: &str, locations: &[(String, usize)]) -> Option<Processed
     let total_refs = locations.len();
     let total_files = file_map.len();
 
+    let mut all_files: Vec<String> = file_map.keys().map(|f| f.to_string()).collect();
+    all_files.sort();
+
     let mut file_sorted: Vec<_> = file_map.into_iter().collect();
     file_sorted.sort_by(|a, b| b.1.len().cmp(&a.1.len()).then_with(|| a.0.cmp(b.0)));
 
@@ -98,6 +106,7 @@ fn process_symbol(name: &str, locations: &[(String, usize)]) -> Option<Processed
         total_refs,
         file_refs,
         total_files,
+        all_files,
     })
 }
 
@@ -130,3 +139,137 @@ fn write_symbol_line(buffer: &mut Vec<u8>, symbol: &ProcessedSymbol) -> Result<(
 
     Ok(())
 }
+
+/// Groups `processed` into co-reference clusters (symbols that share at least
+/// [`MIN_CLUSTER_SHARED_FILES`] files) before truncating to [`MAX_SYMBOLS`], then writes the
+/// same per-symbol lines grouped under cluster headers. Clusters are ordered by aggregate
+/// reference count, with singletons (no cluster partner) listed last.
+fn write_clustered_refs(buffer: &mut Vec<u8>, processed: Vec<ProcessedSymbol>) -> Result<()> {
+    let groups = cluster_symbols(&processed, MIN_CLUSTER_SHARED_FILES);
+
+    let mut groups: Vec<(usize, Vec<usize>)> = groups
+        .into_iter()
+        .map(|indices| {
+            let aggregate_refs: usize = indices.iter().map(|&i| processed[i].total_refs).sum();
+            (aggregate_refs, indices)
+        })
+        .collect();
+
+    groups.sort_by(|(refs_a, indices_a), (refs_b, indices_b)| {
+        let singleton_a = indices_a.len() == 1;
+        let singleton_b = indices_b.len() == 1;
+        singleton_a
+            .cmp(&singleton_b)
+            .then_with(|| refs_b.cmp(refs_a))
+    });
+
+    let mut shown = 0;
+
+    for (aggregate_refs, indices) in &groups {
+        if shown >= MAX_SYMBOLS {
+            break;
+        }
+
+        let mut members: Vec<&ProcessedSymbol> = indices.iter().map(|&i| &processed[i]).collect();
+        members.sort_by(|a, b| {
+            b.total_refs
+                .cmp(&a.total_refs)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        let is_cluster = members.len() > 1;
+        if is_cluster {
+            let names: Vec<&str> = members.iter().map(|s| s.name.as_str()).collect();
+            writeln!(
+                buffer,
+                "## Cluster: {} [{} refs]",
+                names.join(", "),
+                aggregate_refs
+            )?;
+            writeln!(buffer)?;
+        }
+
+        for symbol in members {
+            if shown >= MAX_SYMBOLS {
+                break;
+            }
+            write_symbol_line(buffer, symbol)?;
+            shown += 1;
+        }
+
+        if is_cluster {
+            writeln!(buffer)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Connected-components clustering over a co-occurrence graph: symbols `i` and `j` are joined
+/// whenever they share at least `min_shared_files` files, using a weighted (union-by-size)
+/// union-find so large clusters don't get re-walked on every merge. Returns each resulting
+/// component as a list of indices into `processed`, including singletons for symbols joined to
+/// no one.
+fn cluster_symbols(processed: &[ProcessedSymbol], min_shared_files: usize) -> Vec<Vec<usize>> {
+    let len = processed.len();
+    let mut union_find = UnionFind::new(len);
+
+    let file_sets: Vec<HashSet<&str>> = processed
+        .iter()
+        .map(|symbol| symbol.all_files.iter().map(|f| f.as_str()).collect())
+        .collect();
+
+    for i in 0..len {
+        for j in (i + 1)..len {
+            let shared = file_sets[i].intersection(&file_sets[j]).count();
+            if shared >= min_shared_files {
+                union_find.union(i, j);
+            }
+        }
+    }
+
+    let mut components: HashMap<usize, Vec<usize>> = HashMap::new();
+    for i in 0..len {
+        let root = union_find.find(i);
+        components.entry(root).or_default().push(i);
+    }
+
+    components.into_values().collect()
+}
+
+struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(len: usize) -> Self {
+        UnionFind {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, node: usize) -> usize {
+        if self.parent[node] != node {
+            self.parent[node] = self.find(self.parent[node]);
+        }
+        self.parent[node]
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a == root_b {
+            return;
+        }
+
+        if self.size[root_a] < self.size[root_b] {
+            self.parent[root_a] = root_b;
+            self.size[root_b] += self.size[root_a];
+        } else {
+            self.parent[root_b] = root_a;
+            self.size[root_a] += self.size[root_b];
+        }
+    }
+}
@@ -1,13 +1,15 @@
 use anyhow::Result;
 use std::path::Path;
-use tokio::fs::File;
-use tokio::io::AsyncWriteExt;
 
+use crate::output::ArtifactDigest;
 use crate::pipeline::PipelineResult;
 
-pub async fn write_safety(charter_dir: &Path, result: &PipelineResult, stamp: &str) -> Result<()> {
+pub async fn write_safety(
+    charter_dir: &Path,
+    result: &PipelineResult,
+    stamp: &str,
+) -> Result<ArtifactDigest> {
     let path = charter_dir.join("safety.md");
-    let mut file = File::create(&path).await?;
 
     let mut buffer = Vec::with_capacity(64 * 1024);
 
@@ -22,13 +24,16 @@ pub async fn write_safety(charter_dir: &Path, result: &PipelineResult, stamp: &s
     write_unsafe_traits(&mut buffer, result)?;
     write_lifetime_summary(&mut buffer, result)?;
     write_async_summary(&mut buffer, result)?;
+    write_guard_await_conflicts(&mut buffer, result)?;
     write_feature_flags(&mut buffer, result)?;
+    write_no_std_matrix(&mut buffer, result)?;
     write_generic_constraints(&mut buffer, result)?;
     write_test_coverage(&mut buffer, result)?;
     write_doc_coverage(&mut buffer, result)?;
+    write_broken_doc_links(&mut buffer, result)?;
 
-    file.write_all(&buffer).await?;
-    Ok(())
+    super::write_atomic(&path, &buffer).await?;
+    Ok(super::digest_buffer("safety.md", &buffer))
 }
 
 use std::io::Write;
@@ -74,16 +79,25 @@ fn write_panic_points(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<(
         .filter(|(_, p)| {
             matches!(
                 p.kind,
-                crate::extract::safety::PanicKind::PanicMacro
-                    | crate::extract::safety::PanicKind::UnreachableMacro
-                    | crate::extract::safety::PanicKind::TodoMacro
-                    | crate::extract::safety::PanicKind::UnimplementedMacro
+                crate::extract::safety::PanicKind::PanicMacro(_)
+                    | crate::extract::safety::PanicKind::UnreachableMacro(_)
+                    | crate::extract::safety::PanicKind::TodoMacro(_)
+                    | crate::extract::safety::PanicKind::UnimplementedMacro(_)
             )
         })
         .count();
     let assert_count = all_panics
         .iter()
-        .filter(|(_, p)| matches!(p.kind, crate::extract::safety::PanicKind::Assert))
+        .filter(|(_, p)| matches!(p.kind, crate::extract::safety::PanicKind::Assert(_)))
+        .count();
+    let definite_count = all_panics
+        .iter()
+        .filter(|(_, p)| {
+            matches!(
+                p.kind,
+                crate::extract::safety::PanicKind::DefiniteOutOfBounds { .. }
+            )
+        })
         .count();
 
     writeln!(buffer, "Summary: {} total panic points", all_panics.len())?;
@@ -92,8 +106,95 @@ fn write_panic_points(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<(
     writeln!(buffer, "  index access: {}", index_count)?;
     writeln!(buffer, "  panic!/unreachable!/todo!: {}", macro_count)?;
     writeln!(buffer, "  assert!: {}", assert_count)?;
+    writeln!(buffer, "  definite out-of-bounds: {}", definite_count)?;
     writeln!(buffer)?;
 
+    let definite_panics: Vec<_> = all_panics
+        .iter()
+        .filter(|(_, p)| {
+            matches!(
+                p.kind,
+                crate::extract::safety::PanicKind::DefiniteOutOfBounds { .. }
+            )
+        })
+        .collect();
+
+    if !definite_panics.is_empty() {
+        writeln!(buffer, "### Definite Panics")?;
+        writeln!(buffer)?;
+        writeln!(
+            buffer,
+            "{} index access(es) are compile-time-known out of bounds and will panic on execution:",
+            definite_panics.len()
+        )?;
+        writeln!(buffer)?;
+        for (path, panic) in definite_panics.iter().take(50) {
+            let fn_context = panic
+                .containing_function
+                .as_deref()
+                .unwrap_or("(top-level)");
+            writeln!(
+                buffer,
+                "  {}:{} in {} — {}",
+                path, panic.line, fn_context, panic.kind
+            )?;
+        }
+        if definite_panics.len() > 50 {
+            writeln!(buffer, "  [+{} more]", definite_panics.len() - 50)?;
+        }
+        writeln!(buffer)?;
+    }
+
+    let mut by_reason: std::collections::BTreeMap<
+        String,
+        Vec<(&str, &crate::extract::safety::PanicPoint)>,
+    > = std::collections::BTreeMap::new();
+    let mut unexplained = 0usize;
+
+    for (path, panic) in &all_panics {
+        match panic.kind.reason() {
+            Some(reason) => by_reason
+                .entry(reason.trim().to_lowercase())
+                .or_default()
+                .push((*path, panic)),
+            None => unexplained += 1,
+        }
+    }
+
+    if !by_reason.is_empty() || unexplained > 0 {
+        writeln!(buffer, "### Panic Reasons")?;
+        writeln!(buffer)?;
+        writeln!(
+            buffer,
+            "Panic points grouped by their (normalized) message, so recurring failure reasons surface across the crate."
+        )?;
+        writeln!(buffer)?;
+
+        for (reason, occurrences) in &by_reason {
+            writeln!(
+                buffer,
+                "\"{}\" ({} occurrence(s)):",
+                reason,
+                occurrences.len()
+            )?;
+            for (path, panic) in occurrences.iter().take(10) {
+                writeln!(buffer, "  {}:{} — {}", path, panic.line, panic.kind)?;
+            }
+            if occurrences.len() > 10 {
+                writeln!(buffer, "  [+{} more]", occurrences.len() - 10)?;
+            }
+        }
+
+        if unexplained > 0 {
+            writeln!(
+                buffer,
+                "{} unexplained panic(s) — no reason string attached; consider a message or a `# Panics` doc section.",
+                unexplained
+            )?;
+        }
+        writeln!(buffer)?;
+    }
+
     let mut current_file = "";
     for (path, panic) in all_panics.iter().take(100) {
         if *path != current_file {
@@ -163,6 +264,11 @@ fn write_unsafe_blocks(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<
                 .collect();
             write!(buffer, "{}", ops.join(", "))?;
         }
+        match &unsafe_block.safety_comment {
+            Some(comment) => write!(buffer, " (SAFETY: {})", comment)?,
+            None if unsafe_block.unjustified => write!(buffer, " [UNJUSTIFIED: no SAFETY comment]")?,
+            None => {}
+        }
         writeln!(buffer)?;
     }
     writeln!(buffer)?;
@@ -386,13 +492,25 @@ fn write_async_summary(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<
             "⚠ Blocking calls in async context ({}):",
             blocking_in_async.len()
         )?;
+        writeln!(
+            buffer,
+            "Blocking the executor starves every task colocated on the same runtime thread."
+        )?;
+        writeln!(buffer)?;
         for (path, bc) in blocking_in_async.iter().take(20) {
             let fn_name = bc.containing_function.as_deref().unwrap_or("unknown");
-            writeln!(
-                buffer,
-                "  {}:{} in {} — {}",
-                path, bc.line, fn_name, bc.call
-            )?;
+            match crate::extract::safety::classify_blocking_call(&bc.call) {
+                Some((suggestion, severity)) => writeln!(
+                    buffer,
+                    "  {}:{} in {} — {} [{}] → use {}",
+                    path, bc.line, fn_name, bc.call, severity, suggestion
+                )?,
+                None => writeln!(
+                    buffer,
+                    "  {}:{} in {} — {}",
+                    path, bc.line, fn_name, bc.call
+                )?,
+            }
         }
         if blocking_in_async.len() > 20 {
             writeln!(buffer, "  [+{} more]", blocking_in_async.len() - 20)?;
@@ -432,6 +550,56 @@ fn write_async_summary(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<
     Ok(())
 }
 
+fn write_guard_await_conflicts(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
+    let mut conflicts: Vec<_> = result
+        .files
+        .iter()
+        .flat_map(|f| {
+            f.parsed
+                .guard_await_conflicts
+                .iter()
+                .map(move |c| (f.relative_path.as_str(), c))
+        })
+        .collect();
+
+    if conflicts.is_empty() {
+        return Ok(());
+    }
+
+    conflicts.sort_by_key(|(path, c)| (*path, c.await_line));
+
+    writeln!(
+        buffer,
+        "⚠ Lock guards held across .await ({}):",
+        conflicts.len()
+    )?;
+    writeln!(
+        buffer,
+        "A synchronous guard still bound when a suspension point is reached blocks every task \
+         sharing the runtime thread, and can make the enclosing future `!Send`."
+    )?;
+    writeln!(buffer)?;
+
+    for (path, conflict) in conflicts.iter().take(20) {
+        writeln!(
+            buffer,
+            "  {}:{} in {} — guard `{}` bound at line {} held across await at line {}",
+            path,
+            conflict.await_line,
+            conflict.containing_function,
+            conflict.guard_expr,
+            conflict.guard_line,
+            conflict.await_line
+        )?;
+    }
+    if conflicts.len() > 20 {
+        writeln!(buffer, "  [+{} more]", conflicts.len() - 20)?;
+    }
+    writeln!(buffer)?;
+
+    Ok(())
+}
+
 fn write_feature_flags(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
     let mut all_features: std::collections::HashMap<
         String,
@@ -513,6 +681,123 @@ fn write_feature_flags(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<
     Ok(())
 }
 
+fn write_no_std_matrix(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
+    let no_std_files: Vec<_> = result
+        .files
+        .iter()
+        .filter(|f| f.parsed.feature_flags.no_std)
+        .collect();
+
+    if no_std_files.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(buffer, "## no_std Compatibility")?;
+    writeln!(buffer)?;
+
+    let uses_alloc = no_std_files
+        .iter()
+        .any(|f| f.parsed.feature_flags.uses_alloc_extern_crate);
+
+    writeln!(
+        buffer,
+        "{} file(s) declare `#![no_std]`{}.",
+        no_std_files.len(),
+        if uses_alloc {
+            ", with `extern crate alloc;` pulling in the alloc crate"
+        } else {
+            ""
+        }
+    )?;
+    writeln!(buffer)?;
+
+    let mut by_environment: std::collections::BTreeMap<
+        crate::extract::safety::Environment,
+        Vec<(&str, &crate::extract::safety::GatedSymbol, &str)>,
+    > = std::collections::BTreeMap::new();
+
+    for file in &result.files {
+        for gate in &file.parsed.feature_flags.feature_gates {
+            for symbol in &gate.symbols {
+                by_environment.entry(symbol.environment).or_default().push((
+                    file.relative_path.as_str(),
+                    symbol,
+                    gate.feature_name.as_str(),
+                ));
+            }
+        }
+    }
+
+    writeln!(buffer, "Feature × environment matrix:")?;
+    writeln!(buffer)?;
+    for environment in [
+        crate::extract::safety::Environment::Core,
+        crate::extract::safety::Environment::Alloc,
+        crate::extract::safety::Environment::Std,
+    ] {
+        let symbols = by_environment.get(&environment);
+        writeln!(
+            buffer,
+            "  {} ({} items)",
+            environment,
+            symbols.map_or(0, |s| s.len())
+        )?;
+        if let Some(symbols) = symbols {
+            for (path, symbol, feature) in symbols.iter().take(10) {
+                writeln!(
+                    buffer,
+                    "    {} {} ({}:{}) — feature = \"{}\"",
+                    symbol.kind, symbol.name, path, symbol.line, feature
+                )?;
+            }
+            if symbols.len() > 10 {
+                writeln!(buffer, "    [+{} more]", symbols.len() - 10)?;
+            }
+        }
+    }
+    writeln!(buffer)?;
+
+    let std_gated: std::collections::HashSet<&str> = by_environment
+        .get(&crate::extract::safety::Environment::Std)
+        .into_iter()
+        .flatten()
+        .map(|(_, symbol, _)| symbol.name.as_str())
+        .collect();
+
+    let mut no_std_reachable: Vec<_> = result
+        .files
+        .iter()
+        .flat_map(|f| {
+            f.parsed
+                .symbols
+                .symbols
+                .iter()
+                .filter(|s| matches!(s.visibility, crate::extract::symbols::Visibility::Public))
+                .filter(|s| !std_gated.contains(s.name.as_str()))
+                .map(move |s| (f.relative_path.as_str(), s))
+        })
+        .collect();
+
+    if !no_std_reachable.is_empty() {
+        no_std_reachable.sort_by_key(|(path, s)| (*path, s.line));
+
+        writeln!(
+            buffer,
+            "Public symbols reachable in a no_std build ({}):",
+            no_std_reachable.len()
+        )?;
+        for (path, symbol) in no_std_reachable.iter().take(50) {
+            writeln!(buffer, "  {} ({}:{})", symbol.name, path, symbol.line)?;
+        }
+        if no_std_reachable.len() > 50 {
+            writeln!(buffer, "  [+{} more]", no_std_reachable.len() - 50)?;
+        }
+        writeln!(buffer)?;
+    }
+
+    Ok(())
+}
+
 fn write_generic_constraints(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
     let constraints: Vec<_> = result
         .files
@@ -692,6 +977,12 @@ fn write_doc_coverage(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<(
     let with_panics = docs.iter().filter(|(_, d)| d.has_panics_section).count();
     let with_safety = docs.iter().filter(|(_, d)| d.has_safety_section).count();
     let with_errors = docs.iter().filter(|(_, d)| d.has_errors_section).count();
+    let doc_tests: usize = docs.iter().map(|(_, d)| d.doc_tests.len()).sum();
+    let ignored_doc_tests: usize = docs
+        .iter()
+        .flat_map(|(_, d)| &d.doc_tests)
+        .filter(|t| t.ignore || t.compile_fail)
+        .count();
 
     writeln!(buffer, "Summary:")?;
     writeln!(buffer, "  Public items: {}", total_public_items)?;
@@ -700,6 +991,41 @@ fn write_doc_coverage(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<(
     writeln!(buffer, "  With # Panics section: {}", with_panics)?;
     writeln!(buffer, "  With # Safety section: {}", with_safety)?;
     writeln!(buffer, "  With # Errors section: {}", with_errors)?;
+    writeln!(buffer, "  Doctests: {} ({} ignored/compile_fail)", doc_tests, ignored_doc_tests)?;
+    writeln!(buffer)?;
+
+    Ok(())
+}
+
+fn write_broken_doc_links(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
+    let mut broken: Vec<(&str, &str, &crate::extract::safety::DocLink)> = result
+        .files
+        .iter()
+        .flat_map(|f| {
+            f.parsed.doc_info.item_docs.iter().flat_map(move |d| {
+                d.doc_links
+                    .iter()
+                    .filter(|link| !link.is_external && !link.resolved)
+                    .map(move |link| (f.relative_path.as_str(), d.item_name.as_str(), link))
+            })
+        })
+        .collect();
+
+    if broken.is_empty() {
+        return Ok(());
+    }
+
+    broken.sort_by_key(|(path, _, link)| (*path, link.line));
+
+    writeln!(buffer, "## Broken Doc Links")?;
+    writeln!(buffer)?;
+    for (path, item_name, link) in &broken {
+        writeln!(
+            buffer,
+            "- {}:{} in `{}` — `[{}]` does not resolve to any item in this file",
+            path, link.line, item_name, link.target_path
+        )?;
+    }
     writeln!(buffer)?;
 
     Ok(())
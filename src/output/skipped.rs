@@ -1,15 +1,16 @@
 use anyhow::Result;
 use std::io::Write;
 use std::path::Path;
-use tokio::fs::File;
-use tokio::io::AsyncWriteExt;
 
+use crate::output::ArtifactDigest;
 use crate::pipeline::SkippedFile;
 
-pub async fn write_skipped(charter_dir: &Path, skipped: &[SkippedFile], stamp: &str) -> Result<()> {
+pub async fn write_skipped(
+    charter_dir: &Path,
+    skipped: &[SkippedFile],
+    stamp: &str,
+) -> Result<ArtifactDigest> {
     let path = charter_dir.join("skipped.md");
-    let mut file = File::create(&path).await?;
-
     let mut buffer = Vec::with_capacity(16 * 1024);
 
     writeln!(buffer, "{}", stamp)?;
@@ -20,6 +21,6 @@ pub async fn write_skipped(charter_dir: &Path, skipped: &[SkippedFile], stamp: &
         writeln!(buffer, "{} - {}", path_str, skipped_file.reason)?;
     }
 
-    file.write_all(&buffer).await?;
-    Ok(())
+    super::write_atomic(&path, &buffer).await?;
+    Ok(super::digest_buffer("skipped.md", &buffer))
 }
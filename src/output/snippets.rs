@@ -2,19 +2,15 @@ use anyhow::Result;
 use std::path::Path;
 use tokio::io::{AsyncWriteExt, BufWriter};
 
+use crate::output::ArtifactDigest;
 use crate::pipeline::{CapturedBody, PipelineResult};
+use crate::rollup::{self, DEFAULT_SIZE_THRESHOLD};
 
-pub async fn write_snippets(atlas_dir: &Path, result: &PipelineResult, stamp: &str) -> Result<()> {
-    let file = tokio::fs::File::create(atlas_dir.join("snippets.md")).await?;
-    let mut writer = BufWriter::new(file);
-
-    writer.write_all(stamp.as_bytes()).await?;
-    writer.write_all(b"\n\n").await?;
-    writer.write_all(b"# Implementation Snippets\n\n").await?;
-    writer
-        .write_all(b"Function bodies captured for high and medium importance functions.\n\n")
-        .await?;
-
+/// Collects every captured function body across `result`, paired with its owning file's relative
+/// path, ordered highest-`importance_score`-first — the single ordering both [`write_snippets`]
+/// and [`write_snippets_html`] present, so the two formats never drift apart on what "most
+/// important" means.
+fn collect_bodies(result: &PipelineResult) -> Vec<(&str, &CapturedBody)> {
     let mut all_bodies: Vec<(&str, &CapturedBody)> = Vec::new();
 
     for file_result in &result.files {
@@ -23,14 +19,19 @@ pub async fn write_snippets(atlas_dir: &Path, result: &PipelineResult, stamp: &s
         }
     }
 
-    if all_bodies.is_empty() {
-        writer.write_all(b"No function bodies captured.\n").await?;
-        writer.flush().await?;
-        return Ok(());
-    }
-
     all_bodies.sort_by(|a, b| b.1.importance_score.cmp(&a.1.importance_score));
+    all_bodies
+}
 
+/// Splits `all_bodies` into full implementations (high importance, captured verbatim) and
+/// summaries (medium importance, captured as line/call stats only) — the same "full vs summary"
+/// split both snippet formats render.
+fn partition_bodies<'a>(
+    all_bodies: &'a [(&str, &CapturedBody)],
+) -> (
+    Vec<&'a (&'a str, &'a CapturedBody)>,
+    Vec<&'a (&'a str, &'a CapturedBody)>,
+) {
     let full_bodies: Vec<_> = all_bodies
         .iter()
         .filter(|(_, body)| body.body.full_text.is_some())
@@ -41,6 +42,37 @@ pub async fn write_snippets(atlas_dir: &Path, result: &PipelineResult, stamp: &s
         .filter(|(_, body)| body.body.full_text.is_none() && body.body.summary.is_some())
         .collect();
 
+    (full_bodies, summaries)
+}
+
+pub async fn write_snippets(
+    atlas_dir: &Path,
+    result: &PipelineResult,
+    stamp: &str,
+) -> Result<ArtifactDigest> {
+    let path = atlas_dir.join("snippets.md");
+    let (file, tmp_path) = super::create_atomic(&path).await?;
+    let mut writer = BufWriter::new(file);
+
+    writer.write_all(stamp.as_bytes()).await?;
+    writer.write_all(b"\n\n").await?;
+    writer.write_all(b"# Implementation Snippets\n\n").await?;
+    writer
+        .write_all(b"Function bodies captured for high and medium importance functions.\n\n")
+        .await?;
+
+    let all_bodies = collect_bodies(result);
+
+    if all_bodies.is_empty() {
+        writer.write_all(b"No function bodies captured.\n").await?;
+        writer.flush().await?;
+        let file = writer.into_inner();
+        super::finish_atomic(file, &tmp_path, &path).await?;
+        return super::digest_written_file("snippets.md", &path).await;
+    }
+
+    let (full_bodies, summaries) = partition_bodies(&all_bodies);
+
     if !full_bodies.is_empty() {
         writer
             .write_all(b"## Full Implementations (High Importance)\n\n")
@@ -94,8 +126,8 @@ pub async fn write_snippets(atlas_dir: &Path, result: &PipelineResult, stamp: &s
 
                 if !summary.early_returns.is_empty() {
                     writer.write_all(b"  Early returns:\n").await?;
-                    for ret in summary.early_returns.iter().take(3) {
-                        let line = format!("    {}\n", ret);
+                    for (ret, pos) in summary.early_returns.iter().take(3) {
+                        let line = format!("    {} ({})\n", ret, pos);
                         writer.write_all(line.as_bytes()).await?;
                     }
                 }
@@ -105,7 +137,7 @@ pub async fn write_snippets(atlas_dir: &Path, result: &PipelineResult, stamp: &s
                         .key_calls
                         .iter()
                         .take(5)
-                        .cloned()
+                        .map(|(call, pos, category)| format!("{} [{}] ({})", call, category, pos))
                         .collect::<Vec<_>>()
                         .join(", ");
                     let line = format!("  Key calls: {}\n", calls_str);
@@ -129,7 +161,57 @@ pub async fn write_snippets(atlas_dir: &Path, result: &PipelineResult, stamp: &s
     );
     writer.write_all(stats.as_bytes()).await?;
 
+    write_size_rollup_section(&mut writer, result).await?;
+
     writer.flush().await?;
+    let file = writer.into_inner();
+    super::finish_atomic(file, &tmp_path, &path).await?;
+    super::digest_written_file("snippets.md", &path).await
+}
+
+const MAX_ROLLUP_ENTRIES: usize = 15;
+
+async fn write_size_rollup_section(
+    writer: &mut BufWriter<tokio::fs::File>,
+    result: &PipelineResult,
+) -> Result<()> {
+    let tree = rollup::build_size_tree(result);
+
+    writer
+        .write_all(b"\n## Module Size Rollup\n\nCaptured line/statement counts, summed bottom-up across the directory tree.\n\n")
+        .await?;
+
+    let heaviest = rollup::heaviest_descendants(&tree, MAX_ROLLUP_ENTRIES);
+    if heaviest.is_empty() {
+        writer
+            .write_all(b"(no captured bodies to roll up)\n")
+            .await?;
+        return Ok(());
+    }
+
+    writer.write_all(b"Heaviest modules/files:\n\n").await?;
+    for node in &heaviest {
+        let line = format!(
+            "- {}: {} lines, {} statements\n",
+            node.path, node.totals.line_count, node.totals.statement_count
+        );
+        writer.write_all(line.as_bytes()).await?;
+    }
+
+    let flagged = rollup::over_threshold(&tree, DEFAULT_SIZE_THRESHOLD);
+    if !flagged.is_empty() {
+        let header = format!(
+            "\nOver {} lines ({} flagged):\n\n",
+            DEFAULT_SIZE_THRESHOLD,
+            flagged.len()
+        );
+        writer.write_all(header.as_bytes()).await?;
+        for node in flagged.iter().take(MAX_ROLLUP_ENTRIES) {
+            let line = format!("- {}: {} lines\n", node.path, node.totals.line_count);
+            writer.write_all(line.as_bytes()).await?;
+        }
+    }
+
     Ok(())
 }
 
@@ -139,3 +221,210 @@ fn qualified_name(name: &str, impl_type: Option<&str>) -> String {
         None => name.to_string(),
     }
 }
+
+/// Browsable counterpart to [`write_snippets`]: the same importance ordering and full/summary
+/// split, rendered as a self-contained `snippets.html` instead of plain Markdown — captured
+/// function bodies are syntax-highlighted via `syntect`, and the surrounding structure (stats,
+/// module rollup) is authored as Markdown and converted with `comrak`, so the two writers never
+/// need to be kept in sync by hand beyond the shared [`collect_bodies`]/[`partition_bodies`]
+/// helpers above.
+pub async fn write_snippets_html(
+    atlas_dir: &Path,
+    result: &PipelineResult,
+    stamp: &str,
+) -> Result<ArtifactDigest> {
+    let path = atlas_dir.join("snippets.html");
+    let (file, tmp_path) = super::create_atomic(&path).await?;
+    let mut writer = BufWriter::new(file);
+
+    let syntax_set = syntect::parsing::SyntaxSet::load_defaults_newlines();
+    let theme_set = syntect::highlighting::ThemeSet::load_defaults();
+    let rust_syntax = syntax_set
+        .find_syntax_by_extension("rs")
+        .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+    let theme = &theme_set.themes["InspiredGitHub"];
+
+    writer.write_all(b"<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Implementation Snippets</title>\n<style>\nbody { font-family: sans-serif; max-width: 900px; margin: 2rem auto; padding: 0 1rem; }\nh3 { border-top: 1px solid #ddd; padding-top: 1rem; }\npre { overflow-x: auto; padding: 0.75rem; border-radius: 4px; }\n.meta { color: #666; font-size: 0.9em; }\n</style>\n</head>\n<body>\n").await?;
+
+    let header_md = format!(
+        "{}\n\n# Implementation Snippets\n\nFunction bodies captured for high and medium importance functions.\n",
+        stamp
+    );
+    writer
+        .write_all(comrak::markdown_to_html(&header_md, &comrak::ComrakOptions::default()).as_bytes())
+        .await?;
+
+    let all_bodies = collect_bodies(result);
+
+    if all_bodies.is_empty() {
+        writer
+            .write_all(b"<p>No function bodies captured.</p>\n</body>\n</html>\n")
+            .await?;
+        writer.flush().await?;
+        let file = writer.into_inner();
+        super::finish_atomic(file, &tmp_path, &path).await?;
+        return super::digest_written_file("snippets.html", &path).await;
+    }
+
+    let (full_bodies, summaries) = partition_bodies(&all_bodies);
+
+    if !full_bodies.is_empty() {
+        writer
+            .write_all(b"<h2>Full Implementations (High Importance)</h2>\n")
+            .await?;
+
+        for (file_path, body) in full_bodies.iter().take(30) {
+            let qualified = qualified_name(&body.function_name, body.impl_type.as_deref());
+            let anchor = anchor_id(file_path, body.line, &qualified);
+
+            let header = format!(
+                "<h3 id=\"{anchor}\">{}:{} {} <span class=\"meta\">[score={}]</span></h3>\n",
+                html_escape(file_path),
+                body.line,
+                html_escape(&qualified),
+                body.importance_score
+            );
+            writer.write_all(header.as_bytes()).await?;
+
+            if let Some(ref text) = body.body.full_text {
+                let highlighted =
+                    syntect::html::highlighted_html_for_string(text, &syntax_set, rust_syntax, theme)
+                        .map_err(|e| anyhow::anyhow!("syntax highlighting failed: {e}"))?;
+                writer.write_all(highlighted.as_bytes()).await?;
+            }
+        }
+
+        if full_bodies.len() > 30 {
+            let msg = format!(
+                "<p>[+{} more full implementations not shown]</p>\n",
+                full_bodies.len() - 30
+            );
+            writer.write_all(msg.as_bytes()).await?;
+        }
+    }
+
+    let mut trailer_md = String::new();
+
+    if !summaries.is_empty() {
+        trailer_md.push_str("## Summaries (Medium Importance)\n\n");
+
+        for (file_path, body) in summaries.iter().take(50) {
+            let qualified = qualified_name(&body.function_name, body.impl_type.as_deref());
+            trailer_md.push_str(&format!(
+                "- **{}:{} {}** [score={}]\n",
+                file_path, body.line, qualified, body.importance_score
+            ));
+
+            if let Some(ref summary) = body.body.summary {
+                trailer_md.push_str(&format!(
+                    "  - {} lines, {} statements\n",
+                    summary.line_count, summary.statement_count
+                ));
+
+                if !summary.early_returns.is_empty() {
+                    let returns = summary
+                        .early_returns
+                        .iter()
+                        .take(3)
+                        .map(|(ret, pos)| format!("{} ({})", ret, pos))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    trailer_md.push_str(&format!("  - Early returns: {}\n", returns));
+                }
+
+                if !summary.key_calls.is_empty() {
+                    let calls_str = summary
+                        .key_calls
+                        .iter()
+                        .take(5)
+                        .map(|(call, pos, category)| format!("{} [{}] ({})", call, category, pos))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    trailer_md.push_str(&format!("  - Key calls: {}\n", calls_str));
+                }
+            }
+        }
+
+        if summaries.len() > 50 {
+            trailer_md.push_str(&format!(
+                "\n[+{} more summaries not shown]\n",
+                summaries.len() - 50
+            ));
+        }
+        trailer_md.push('\n');
+    }
+
+    trailer_md.push_str(&format!(
+        "## Stats\n\nFull implementations captured: {}\nSummaries captured: {}\n",
+        full_bodies.len(),
+        summaries.len()
+    ));
+
+    trailer_md.push_str(&size_rollup_markdown(result));
+
+    writer
+        .write_all(comrak::markdown_to_html(&trailer_md, &comrak::ComrakOptions::default()).as_bytes())
+        .await?;
+
+    writer.write_all(b"</body>\n</html>\n").await?;
+
+    writer.flush().await?;
+    let file = writer.into_inner();
+    super::finish_atomic(file, &tmp_path, &path).await?;
+    super::digest_written_file("snippets.html", &path).await
+}
+
+/// Builds the same "Module Size Rollup" section [`write_size_rollup_section`] writes, as a
+/// Markdown fragment for [`write_snippets_html`] to hand to `comrak` alongside the rest of the
+/// surrounding structure.
+fn size_rollup_markdown(result: &PipelineResult) -> String {
+    let tree = rollup::build_size_tree(result);
+    let mut md = String::from(
+        "\n## Module Size Rollup\n\nCaptured line/statement counts, summed bottom-up across the directory tree.\n\n",
+    );
+
+    let heaviest = rollup::heaviest_descendants(&tree, MAX_ROLLUP_ENTRIES);
+    if heaviest.is_empty() {
+        md.push_str("(no captured bodies to roll up)\n");
+        return md;
+    }
+
+    md.push_str("Heaviest modules/files:\n\n");
+    for node in &heaviest {
+        md.push_str(&format!(
+            "- {}: {} lines, {} statements\n",
+            node.path, node.totals.line_count, node.totals.statement_count
+        ));
+    }
+
+    let flagged = rollup::over_threshold(&tree, DEFAULT_SIZE_THRESHOLD);
+    if !flagged.is_empty() {
+        md.push_str(&format!(
+            "\nOver {} lines ({} flagged):\n\n",
+            DEFAULT_SIZE_THRESHOLD,
+            flagged.len()
+        ));
+        for node in flagged.iter().take(MAX_ROLLUP_ENTRIES) {
+            md.push_str(&format!("- {}: {} lines\n", node.path, node.totals.line_count));
+        }
+    }
+
+    md
+}
+
+/// HTML-id-safe anchor for a captured function, in `file:line qualified_name` order so it reads
+/// the same as the Markdown writer's header while remaining a valid fragment identifier: every
+/// character outside `[A-Za-z0-9_-]` (including the `:`/` `/`::` separators) becomes `-`.
+fn anchor_id(file: &str, line: usize, qualified: &str) -> String {
+    format!("{file}-{line}-{qualified}")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '-' })
+        .collect()
+}
+
+fn html_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -2,12 +2,11 @@ use anyhow::Result;
 use std::collections::HashMap;
 use std::io::Write;
 use std::path::{Path, PathBuf};
-use tokio::fs::File;
-use tokio::io::AsyncWriteExt;
 
 use crate::extract::symbols::{
     EnumVariant, ImplMethod, InherentImpl, Symbol, SymbolKind, VariantPayload, Visibility,
 };
+use crate::output::ArtifactDigest;
 use crate::pipeline::{FileResult, PipelineResult};
 
 const CHAR_BUDGET: usize = 50_000;
@@ -65,10 +64,8 @@ pub async fn write_symbols(
     result: &PipelineResult,
     churn_data: &HashMap<PathBuf, u32>,
     stamp: &str,
-) -> Result<()> {
+) -> Result<ArtifactDigest> {
     let path = charter_dir.join("symbols.md");
-    let mut file = File::create(&path).await?;
-
     let mut buffer = Vec::with_capacity(256 * 1024);
 
     writeln!(buffer, "{}", stamp)?;
@@ -89,8 +86,8 @@ pub async fn write_symbols(
         write_compressed_symbols(&mut buffer, result, &context, budget)?;
     }
 
-    file.write_all(&buffer).await?;
-    Ok(())
+    super::write_atomic(&path, &buffer).await?;
+    Ok(super::digest_buffer("symbols.md", &buffer))
 }
 
 fn collect_all_inherent_impls(
@@ -636,8 +633,8 @@ fn write_symbol(
         SymbolKind::Function { signature, .. } => {
             writeln!(
                 buffer,
-                "  {}{}fn {}{}",
-                vis, qualifiers, symbol.name, signature
+                "  {}{}fn {}{}{}",
+                vis, qualifiers, symbol.name, symbol.generics, signature
             )?;
         }
         SymbolKind::Const { const_type, value } => {
@@ -681,6 +678,14 @@ fn write_symbol(
         writeln!(buffer, "    [re-exported as {}]", re_export)?;
     }
 
+    if let Some(summary) = &symbol.doc_summary {
+        writeln!(buffer, "    /// {}", summary)?;
+    }
+
+    if let Some(cfg) = &symbol.cfg {
+        writeln!(buffer, "    #[cfg({})]", cfg)?;
+    }
+
     Ok(())
 }
 
@@ -1,16 +1,18 @@
 use anyhow::Result;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::Write;
 use std::path::Path;
-use tokio::fs::File;
-use tokio::io::AsyncWriteExt;
 
 use crate::extract::symbols::SymbolKind;
+use crate::output::ArtifactDigest;
 use crate::pipeline::PipelineResult;
 
-pub async fn write_types(charter_dir: &Path, result: &PipelineResult, stamp: &str) -> Result<()> {
+pub async fn write_types(
+    charter_dir: &Path,
+    result: &PipelineResult,
+    stamp: &str,
+) -> Result<ArtifactDigest> {
     let path = charter_dir.join("types.md");
-    let mut file = File::create(&path).await?;
 
     let mut buffer = Vec::with_capacity(64 * 1024);
 
@@ -20,9 +22,10 @@ pub async fn write_types(charter_dir: &Path, result: &PipelineResult, stamp: &st
     write_trait_definitions(&mut buffer, result)?;
     write_impl_map(&mut buffer, result)?;
     write_derive_map(&mut buffer, result)?;
+    write_effective_impls(&mut buffer, result)?;
 
-    file.write_all(&buffer).await?;
-    Ok(())
+    super::write_atomic(&path, &buffer).await?;
+    Ok(super::digest_buffer("types.md", &buffer))
 }
 
 fn write_trait_definitions(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
@@ -89,6 +92,7 @@ fn write_trait_definitions(buffer: &mut Vec<u8>, result: &PipelineResult) -> Res
     write_python_type_vars(buffer, result)?;
     write_python_dataclasses(buffer, result)?;
     write_class_hierarchy(buffer, result)?;
+    write_class_dominators(buffer, result)?;
 
     Ok(())
 }
@@ -293,17 +297,262 @@ fn write_class_hierarchy(buffer: &mut Vec<u8>, result: &PipelineResult) -> Resul
     }
 
     writeln!(buffer, "Class Hierarchy (Python):")?;
-    let mut sorted: Vec<_> = class_bases.into_iter().collect();
-    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut sorted: Vec<_> = class_bases.iter().collect();
+    sorted.sort_by(|a, b| a.0.cmp(b.0));
 
     for (class_name, bases) in sorted {
         writeln!(buffer, "  {} extends {}", class_name, bases.join(", "))?;
+        match c3_linearize(class_name, &class_bases, &mut HashSet::new()) {
+            Some(mro) => writeln!(buffer, "    MRO: {} -> [{}]", class_name, mro.join(", "))?,
+            None => writeln!(buffer, "    (inconsistent hierarchy)")?,
+        }
     }
     writeln!(buffer)?;
 
     Ok(())
 }
 
+/// Computes the C3 linearization (Python's MRO algorithm) for `class_name` against the
+/// transitive `class_bases` map. Bases with no entry of their own (external/unknown classes,
+/// or the implicit `object` root) are treated as leaves. `ancestors` tracks the classes on the
+/// current recursion path so an inheritance cycle is flagged as inconsistent rather than
+/// overflowing the stack. Returns `None` when the merge step gets stuck, i.e. the hierarchy is
+/// inconsistent and has no valid linearization.
+fn c3_linearize(
+    class_name: &str,
+    class_bases: &HashMap<String, Vec<String>>,
+    ancestors: &mut HashSet<String>,
+) -> Option<Vec<String>> {
+    if !ancestors.insert(class_name.to_string()) {
+        return None;
+    }
+
+    let bases = class_bases.get(class_name).cloned().unwrap_or_default();
+
+    let mut sequences: Vec<Vec<String>> = Vec::new();
+    for base in &bases {
+        sequences.push(c3_linearize(base, class_bases, ancestors)?);
+    }
+    sequences.push(bases.clone());
+
+    ancestors.remove(class_name);
+
+    let mut merged = c3_merge(sequences)?;
+    merged.insert(0, class_name.to_string());
+    Some(merged)
+}
+
+/// The `merge` step of C3 linearization: repeatedly takes the head of the first list whose
+/// head doesn't appear in the tail of any other list, appends it to the result, and strips it
+/// from every list. Returns `None` if no list has a usable head while any list is still
+/// non-empty.
+fn c3_merge(mut sequences: Vec<Vec<String>>) -> Option<Vec<String>> {
+    let mut result = Vec::new();
+
+    loop {
+        sequences.retain(|seq| !seq.is_empty());
+        if sequences.is_empty() {
+            return Some(result);
+        }
+
+        let head = sequences
+            .iter()
+            .map(|seq| seq[0].clone())
+            .find(|candidate| !sequences.iter().any(|seq| seq[1..].contains(candidate)))?;
+
+        result.push(head.clone());
+        for seq in &mut sequences {
+            seq.retain(|name| *name != head);
+        }
+    }
+}
+
+/// Writes a "Class Dominators" section: for each class, the ancestor base classes that lie on
+/// every inheritance path from it up to a root, plus which of those is the immediate (nearest)
+/// dominator. Unlike the flat `extends` list or the linear MRO, this surfaces the "spine" mixins
+/// every descendant of a subtree is forced to share, even across diamonds.
+fn write_class_dominators(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
+    let mut class_bases: HashMap<String, Vec<String>> = HashMap::new();
+
+    for file_result in &result.files {
+        for symbol in &file_result.parsed.symbols.symbols {
+            if let SymbolKind::Class { bases, .. } = &symbol.kind {
+                if !bases.is_empty() {
+                    class_bases.insert(symbol.name.clone(), bases.clone());
+                }
+            }
+        }
+    }
+
+    if class_bases.is_empty() {
+        return Ok(());
+    }
+
+    let dominators = compute_dominators(&class_bases);
+
+    let mut class_names: Vec<&String> = class_bases.keys().collect();
+    class_names.sort();
+
+    let mut wrote_header = false;
+
+    for class_name in class_names {
+        let Some(doms) = dominators.get(class_name) else {
+            continue;
+        };
+
+        let mut strict: Vec<&String> = doms.iter().filter(|d| *d != class_name).collect();
+        if strict.is_empty() {
+            continue;
+        }
+        strict.sort_by(|a, b| {
+            let len_a = dominators.get(*a).map(|s| s.len()).unwrap_or(0);
+            let len_b = dominators.get(*b).map(|s| s.len()).unwrap_or(0);
+            len_b.cmp(&len_a).then_with(|| a.cmp(b))
+        });
+
+        if !wrote_header {
+            writeln!(buffer, "Class Dominators (Python):")?;
+            wrote_header = true;
+        }
+
+        writeln!(
+            buffer,
+            "  {} idom={} dominators=[{}]",
+            class_name,
+            strict[0],
+            strict
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+    }
+
+    if wrote_header {
+        writeln!(buffer)?;
+    }
+
+    Ok(())
+}
+
+/// Iterative dominator computation over the class→bases DAG: every class's dominator set starts
+/// as the full node universe (a root with no bases is pinned to `{self}`), then each class's set
+/// is repeatedly narrowed to `{class} ∪ intersection(dominators of its direct bases)` in
+/// topological order until a fixpoint is reached. Bases that fall outside the collected class set
+/// (external/unknown types) are treated as their own singleton dominator set.
+fn compute_dominators(
+    class_bases: &HashMap<String, Vec<String>>,
+) -> HashMap<String, HashSet<String>> {
+    let mut all_nodes: HashSet<String> = HashSet::new();
+    for (class_name, bases) in class_bases {
+        all_nodes.insert(class_name.clone());
+        for base in bases {
+            all_nodes.insert(base.clone());
+        }
+    }
+
+    let order = topological_class_order(class_bases, &all_nodes);
+
+    let mut dominators: HashMap<String, HashSet<String>> = HashMap::new();
+    for node in &all_nodes {
+        let is_root = class_bases.get(node).is_none_or(|bases| bases.is_empty());
+        if is_root {
+            dominators.insert(node.clone(), HashSet::from([node.clone()]));
+        } else {
+            dominators.insert(node.clone(), all_nodes.clone());
+        }
+    }
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        for node in &order {
+            let bases = match class_bases.get(node) {
+                Some(bases) if !bases.is_empty() => bases,
+                _ => continue,
+            };
+
+            let mut new_dom: Option<HashSet<String>> = None;
+            for base in bases {
+                let base_dom = dominators
+                    .get(base)
+                    .cloned()
+                    .unwrap_or_else(|| HashSet::from([base.clone()]));
+
+                new_dom = Some(match new_dom {
+                    None => base_dom,
+                    Some(acc) => acc.intersection(&base_dom).cloned().collect(),
+                });
+            }
+
+            let mut new_dom = new_dom.unwrap_or_default();
+            new_dom.insert(node.clone());
+
+            if dominators.get(node) != Some(&new_dom) {
+                dominators.insert(node.clone(), new_dom);
+                changed = true;
+            }
+        }
+    }
+
+    dominators
+}
+
+/// Topologically orders `all_nodes` (bases before the classes that extend them) via a
+/// deterministic post-order DFS over `class_bases`, with an in-progress guard so a cycle in the
+/// (malformed) input can't recurse forever.
+fn topological_class_order(
+    class_bases: &HashMap<String, Vec<String>>,
+    all_nodes: &HashSet<String>,
+) -> Vec<String> {
+    let mut order = Vec::new();
+    let mut visited = HashSet::new();
+    let mut in_progress = HashSet::new();
+
+    let mut sorted_nodes: Vec<&String> = all_nodes.iter().collect();
+    sorted_nodes.sort();
+
+    for node in sorted_nodes {
+        visit_for_topological_order(
+            node,
+            class_bases,
+            &mut visited,
+            &mut in_progress,
+            &mut order,
+        );
+    }
+
+    order
+}
+
+fn visit_for_topological_order(
+    node: &str,
+    class_bases: &HashMap<String, Vec<String>>,
+    visited: &mut HashSet<String>,
+    in_progress: &mut HashSet<String>,
+    order: &mut Vec<String>,
+) {
+    if visited.contains(node) {
+        return;
+    }
+    if !in_progress.insert(node.to_string()) {
+        return;
+    }
+
+    if let Some(bases) = class_bases.get(node) {
+        let mut sorted_bases = bases.clone();
+        sorted_bases.sort();
+        for base in &sorted_bases {
+            visit_for_topological_order(base, class_bases, visited, in_progress, order);
+        }
+    }
+
+    in_progress.remove(node);
+    visited.insert(node.to_string());
+    order.push(node.to_string());
+}
+
 fn write_impl_map(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
     let mut impl_map: HashMap<String, Vec<String>> = HashMap::new();
 
@@ -365,3 +614,147 @@ fn write_derive_map(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()>
     writeln!(buffer)?;
     Ok(())
 }
+
+/// Writes an "Effective Impls" section: the transitive closure of every type's trait bounds,
+/// propagating a directly-implemented (via `impl` or `#[derive]`) trait up through its
+/// `supertraits` chain so `Type: Trait` where `Trait: Super` shows `Type` as also satisfying
+/// `Super`. Inherited entries are marked `(via supertrait)` to distinguish them from direct impls.
+fn write_effective_impls(buffer: &mut Vec<u8>, result: &PipelineResult) -> Result<()> {
+    let mut supertraits: HashMap<String, Vec<String>> = HashMap::new();
+    for file_result in &result.files {
+        for symbol in &file_result.parsed.symbols.symbols {
+            if let SymbolKind::Trait {
+                supertraits: supers,
+                ..
+            } = &symbol.kind
+            {
+                if !supers.is_empty() {
+                    supertraits.insert(symbol.name.clone(), supers.clone());
+                }
+            }
+        }
+    }
+
+    let mut direct: HashMap<String, Vec<String>> = HashMap::new();
+    for file_result in &result.files {
+        for (trait_name, type_name) in &file_result.parsed.symbols.impl_map {
+            direct
+                .entry(type_name.clone())
+                .or_default()
+                .push(trait_name.clone());
+        }
+        for derive in &file_result.parsed.derives {
+            direct
+                .entry(derive.target.clone())
+                .or_default()
+                .extend(derive.traits.clone());
+        }
+    }
+
+    if direct.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(buffer, "Effective Impls:")?;
+
+    let mut sorted: Vec<_> = direct.into_iter().collect();
+    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (type_name, traits) in sorted {
+        let mut effective: HashMap<String, bool> = HashMap::new();
+        for trait_name in &traits {
+            effective.insert(trait_name.clone(), true);
+        }
+        for trait_name in &traits {
+            collect_supertraits(
+                trait_name,
+                &supertraits,
+                &mut effective,
+                &mut HashSet::new(),
+            );
+        }
+
+        let mut sorted_traits: Vec<_> = effective.into_iter().collect();
+        sorted_traits.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let formatted: Vec<String> = sorted_traits
+            .iter()
+            .map(|(name, is_direct)| {
+                if *is_direct {
+                    name.clone()
+                } else {
+                    format!("{} (via supertrait)", name)
+                }
+            })
+            .collect();
+
+        writeln!(buffer, "  {} -> [{}]", type_name, formatted.join(", "))?;
+    }
+
+    writeln!(buffer)?;
+    Ok(())
+}
+
+/// Walks `trait_name`'s supertrait chain, recording every ancestor in `effective` (without
+/// overwriting a trait already marked direct) and guarding against supertrait cycles with
+/// `visited`.
+fn collect_supertraits(
+    trait_name: &str,
+    supertraits: &HashMap<String, Vec<String>>,
+    effective: &mut HashMap<String, bool>,
+    visited: &mut HashSet<String>,
+) {
+    if !visited.insert(trait_name.to_string()) {
+        return;
+    }
+
+    if let Some(supers) = supertraits.get(trait_name) {
+        for super_trait in supers {
+            effective.entry(super_trait.clone()).or_insert(false);
+            collect_supertraits(super_trait, supertraits, effective, visited);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The textbook diamond: `D(B, C)`, `B(A)`, `C(A)`. C3 must put `D` first, then resolve the
+    /// shared ancestor `A` only after both `B` and `C`, matching Python's actual MRO for this
+    /// shape: `[D, B, C, A]`.
+    #[test]
+    fn c3_linearize_resolves_diamond_inheritance() {
+        let mut class_bases = HashMap::new();
+        class_bases.insert("D".to_string(), vec!["B".to_string(), "C".to_string()]);
+        class_bases.insert("B".to_string(), vec!["A".to_string()]);
+        class_bases.insert("C".to_string(), vec!["A".to_string()]);
+
+        let mro = c3_linearize("D", &class_bases, &mut HashSet::new()).expect("consistent MRO");
+
+        assert_eq!(mro, vec!["D", "B", "C", "A"]);
+    }
+
+    /// A base class with no entry in `class_bases` (e.g. `object`, or an external/unresolved
+    /// class) is a leaf: it contributes itself and nothing more.
+    #[test]
+    fn c3_linearize_treats_unknown_bases_as_leaves() {
+        let mut class_bases = HashMap::new();
+        class_bases.insert("Child".to_string(), vec!["Unknown".to_string()]);
+
+        let mro = c3_linearize("Child", &class_bases, &mut HashSet::new()).expect("consistent MRO");
+
+        assert_eq!(mro, vec!["Child", "Unknown"]);
+    }
+
+    /// An inheritance cycle (`A(B)`, `B(A)`) can never produce a valid linearization — it should
+    /// be flagged inconsistent rather than overflowing the stack.
+    #[test]
+    fn c3_linearize_detects_inheritance_cycles() {
+        let mut class_bases = HashMap::new();
+        class_bases.insert("A".to_string(), vec!["B".to_string()]);
+        class_bases.insert("B".to_string(), vec!["A".to_string()]);
+
+        assert_eq!(c3_linearize("A", &class_bases, &mut HashSet::new()), None);
+    }
+}
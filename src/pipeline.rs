@@ -1,18 +1,25 @@
+mod classify;
+mod language;
 mod parse;
 mod read;
-mod walk;
+pub mod walk;
+pub mod watch;
 
 use anyhow::Result;
 use indicatif::{ProgressBar, ProgressStyle};
-use std::collections::HashMap;
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
-use tokio::sync::{Mutex, Semaphore};
-use tokio::task::JoinSet;
-
 use crate::cache::{Cache, CacheEntry, FileData};
-use crate::detect::{WorkspaceInfo, detect_workspace};
-use crate::git::{GitInfo, get_churn_data, get_git_info};
+use crate::churn;
+use crate::extract::cfg::CfgSet;
+use crate::detect::{detect_workspace, WorkspaceInfo};
+use crate::git;
+use crate::git::{
+    get_churn_data, get_detailed_churn, get_git_info, get_status_map, ChurnStats, GitBackend,
+    GitInfo, GitStatus, DEFAULT_CHURN_WINDOW_DAYS,
+};
+use crate::linediff;
 use crate::output;
 
 const MAX_FILE_SIZE: u64 = 1024 * 1024;
@@ -72,9 +79,8 @@ pub(crate) fn is_pascal_case(name: &str) -> bool {
     has_lowercase && all_valid
 }
 
-pub use parse::{CapturedBody, ParsedFile};
-
-const SEMAPHORE_PERMITS: usize = 256;
+pub use classify::CallClassifier;
+pub use parse::{extract_all_body_summaries, parse_rust_file, CapturedBody, ParsedFile};
 
 pub struct PipelineResult {
     pub files: Vec<FileResult>,
@@ -94,6 +100,18 @@ pub struct DiffSummary {
     pub modified: Vec<ModifiedFile>,
 }
 
+impl DiffSummary {
+    /// Whether anything in this diff could have moved the item-level facts `symbols.md`/
+    /// `types.md` render: a file appeared or disappeared, or a modified file's
+    /// [`ModifiedFile::item_summary_changed`] is set. `false` means every change in this capture
+    /// was confined to function bodies, so those two reports are still accurate as written.
+    pub fn item_docs_need_regen(&self) -> bool {
+        !self.added.is_empty()
+            || !self.removed.is_empty()
+            || self.modified.iter().any(|m| m.item_summary_changed)
+    }
+}
+
 #[derive(Debug)]
 pub struct AddedFile {
     pub path: String,
@@ -113,6 +131,22 @@ pub struct ModifiedFile {
     pub symbols_removed: usize,
     pub signature_changes: Vec<String>,
     pub field_changes: Vec<String>,
+    pub body_diffs: Vec<BodyDiff>,
+    /// Whether this file's [`item_summary_hash`] moved since the cached entry — `false` means the
+    /// edit only touched function bodies, so `symbols.md`/`types.md` don't need to be rewritten on
+    /// this file's account. See [`DiffSummary::item_docs_need_regen`].
+    pub item_summary_changed: bool,
+}
+
+/// A unified-diff breakdown of how one function's captured body changed, alongside the plain
+/// "signature changed" flag in [`ModifiedFile::signature_changes`]. Only populated when both
+/// revisions kept that function's full text (see [`parse::MAX_TOTAL_SNIPPET_BUDGET`] and
+/// [`allocate_snippet_budget`]) — a budget-losing function still shows up in
+/// `signature_changes` with no accompanying hunks.
+#[derive(Debug)]
+pub struct BodyDiff {
+    pub symbol: String,
+    pub hunks: Vec<String>,
 }
 
 #[derive(Debug)]
@@ -124,6 +158,15 @@ pub struct FileResult {
     pub lines: usize,
     pub parsed: ParsedFile,
     pub from_cache: bool,
+    pub git_status: GitStatus,
+    /// Unix timestamp of this file's most recent commit within the churn window, or `0` if it
+    /// has none (no git history, or untouched in the window) — the raw signal
+    /// [`ComplexityMetrics::recency_score`](crate::extract::complexity::ComplexityMetrics) is
+    /// derived from. See [`apply_recency_and_author_scores`].
+    pub last_commit_timestamp: i64,
+    /// Distinct commit authors within the churn window, or `0` alongside
+    /// `last_commit_timestamp == 0`. See [`apply_recency_and_author_scores`].
+    pub distinct_authors: u32,
 }
 
 #[derive(Debug)]
@@ -132,7 +175,51 @@ pub struct SkippedFile {
     pub reason: String,
 }
 
+/// Runs a full capture with git-aware incremental re-analysis enabled whenever the prior
+/// capture's commit is still usable — see [`capture_with_mode`].
 pub async fn capture(root: &Path) -> Result<()> {
+    capture_with_mode(root, true, false, None, false, false).await
+}
+
+/// `incremental`, when true, tries [`git::GitBackend::changed_paths`] against the commit
+/// recorded in `meta.json` before falling back to the full walk-and-hash scan below. This turns
+/// a warm run on a large repo from O(all files) into O(changed files): instead of re-walking and
+/// re-hashing the whole tree, only the paths git says moved since the cached commit are
+/// re-parsed and spliced into `cache.bin` via [`apply_delta`]. Falls back automatically (and
+/// silently) when there's no cache yet, no cached commit, or the backend can't trust the diff
+/// (e.g. the branch was rebased).
+///
+/// `no_ignore`, when true, disables `.gitignore`/global excludes/`.charterignore` for the full
+/// walk (see [`walk::WalkConfig::no_ignore`]); it has no effect on the incremental path above,
+/// since that one never walks the filesystem at all.
+///
+/// `cfg`, when given, evaluates every item's `#[cfg(...)]` against it (see
+/// [`parse::parse_rust_file_with_cfg`]) and drops gated-out items from every report instead of
+/// recording them unconditionally. Since the resulting `symbols.md`/etc. reflect one particular
+/// configuration rather than the unconditional tree, a `cfg` capture always does a full
+/// walk-and-parse (skipping both the incremental and quick-unchanged paths above, which assume
+/// the cached/unconditional parse is what's wanted) and never persists `cache.bin`, so a later
+/// plain run isn't left thinking a conditionally-filtered parse is the real one.
+///
+/// `format_json`, when true, additionally writes `model.json` (see
+/// [`output::model_json::write_model_json`]) and the dataflow analysis's `dataflow.json`/
+/// `dataflow.bin` pair (see [`output::dataflow::write_dataflow_text`]/
+/// [`output::dataflow::write_dataflow_binary`]) alongside the usual markdown output.
+///
+/// `format_html`, when true, additionally writes a syntax-highlighted, browsable
+/// `snippets.html` (see [`output::snippets::write_snippets_html`]) alongside the plain-Markdown
+/// `snippets.md` that's always produced.
+///
+/// Like `cfg`, either format flag skips the incremental and quick-unchanged paths, since neither
+/// of those writes the extra files.
+pub async fn capture_with_mode(
+    root: &Path,
+    incremental: bool,
+    no_ignore: bool,
+    cfg: Option<CfgSet>,
+    format_json: bool,
+    format_html: bool,
+) -> Result<()> {
     let atlas_dir = root.join(".atlas");
     tokio::fs::create_dir_all(&atlas_dir).await?;
 
@@ -144,16 +231,33 @@ pub async fn capture(root: &Path) -> Result<()> {
     let cache_path = atlas_dir.join("cache.bin");
     let meta_path = atlas_dir.join("meta.json");
 
-    let (cache, walk_result, old_meta) = tokio::join!(
-        Cache::load(&cache_path),
-        walk::walk_directory(root),
-        load_old_meta(&meta_path)
-    );
+    let (cache, old_meta) = tokio::join!(Cache::load(&cache_path), load_old_meta(&meta_path));
     let cache = cache.unwrap_or_default();
-    let walk_result = walk_result?;
     let old_commit = old_meta.and_then(|m| m.git_commit);
 
-    if !cache.entries.is_empty() {
+    if cfg.is_none() && !format_json && !format_html && incremental && !cache.entries.is_empty() {
+        if let Some(commit) = old_commit.clone() {
+            if let Some(changes) = git::default_backend().changed_paths(root, &commit).await {
+                return capture_incremental(
+                    root,
+                    &atlas_dir,
+                    &cache_path,
+                    cache,
+                    old_commit,
+                    changes,
+                )
+                .await;
+            }
+        }
+    }
+
+    let walk_config = walk::WalkConfig {
+        no_ignore,
+        ..walk::WalkConfig::default()
+    };
+    let walk_result = walk::walk_directory_with_config(root, &walk_config).await?;
+
+    if cfg.is_none() && !format_json && !format_html && !cache.entries.is_empty() {
         if let Some(change_count) = quick_change_check_sync(root, &walk_result.files, &cache) {
             if change_count == 0 {
                 let git_info = get_git_info(root).await.ok();
@@ -173,12 +277,31 @@ pub async fn capture(root: &Path) -> Result<()> {
     }
 
     let workspace = detect_workspace(root).await?;
-    let (git_info, churn_data) = tokio::join!(get_git_info(root), get_churn_data(root));
+    let (git_info, churn_data, detailed_churn, weighted_churn, status_map) = tokio::join!(
+        get_git_info(root),
+        get_churn_data(root),
+        get_detailed_churn(root, DEFAULT_CHURN_WINDOW_DAYS),
+        churn::compute_churn(root),
+        get_status_map(root)
+    );
     let git_info = git_info.ok();
     let churn_data = churn_data.unwrap_or_default();
+    let detailed_churn = detailed_churn.unwrap_or_default();
+
+    let mut result = run_phase1_with_walk(
+        root,
+        &workspace,
+        &cache,
+        git_info.as_ref(),
+        walk_result,
+        cfg.as_ref(),
+    )
+    .await?;
 
-    let mut result =
-        run_phase1_with_walk(root, &workspace, &cache, git_info.as_ref(), walk_result).await?;
+    apply_churn_scores(&mut result.files, &weighted_churn);
+    apply_git_status(&mut result.files, &status_map);
+    apply_recency_and_author_scores(&mut result.files, &detailed_churn);
+    allocate_snippet_budget(&mut result.files);
 
     result.diff_summary = Some(build_diff_summary(
         &result.files,
@@ -190,21 +313,80 @@ pub async fn capture(root: &Path) -> Result<()> {
     let symbol_table = build_symbol_table(&result.files);
     let references = run_phase2(&result.files, &symbol_table);
 
-    emit_outputs(root, &result, &references, &churn_data).await?;
-
-    let new_cache = build_cache(&result.files);
-    new_cache.save(&cache_path).await?;
+    // A full walk-and-hash capture always regenerates every report, even one that reused most
+    // files from cache — only the git-diff/watch incremental paths (`apply_delta`) are narrow
+    // enough in scope to trust `item_docs_need_regen`'s file-level diff.
+    emit_outputs(
+        root,
+        &result,
+        &references,
+        &churn_data,
+        &detailed_churn,
+        format_json,
+        format_html,
+        true,
+    )
+    .await?;
+
+    if cfg.is_none() {
+        let new_cache = build_cache(&result.files);
+        new_cache.save(&cache_path).await?;
+
+        update_search_index(&atlas_dir, &result).await?;
+        update_symbol_search_index(&atlas_dir, &new_cache).await?;
+    }
 
     print_summary(&result);
 
     Ok(())
 }
 
+/// Splices a [`git::IncrementalChanges`] into `cache` via [`apply_delta`] — `changed` paths are
+/// treated as `watch::WalkDelta::changed` (re-parsed through the normal [`process_file`] cache
+/// check) and `removed` paths purge their cache entries, without ever walking the filesystem.
+async fn capture_incremental(
+    root: &Path,
+    atlas_dir: &Path,
+    cache_path: &Path,
+    cache: Cache,
+    old_commit: Option<String>,
+    changes: git::IncrementalChanges,
+) -> Result<()> {
+    if changes.changed.is_empty() && changes.removed.is_empty() {
+        let git_info = get_git_info(root).await.ok();
+        println!();
+        if let Some(git) = &git_info {
+            println!(
+                "Up to date @ {} ({} files)",
+                git.commit_short,
+                cache.entries.len()
+            );
+        } else {
+            println!("Up to date ({} files)", cache.entries.len());
+        }
+        return Ok(());
+    }
+
+    let delta = watch::WalkDelta {
+        added: Vec::new(),
+        changed: changes.changed.iter().map(|p| root.join(p)).collect(),
+        removed: changes.removed.iter().map(|p| root.join(p)).collect(),
+    };
+
+    apply_delta(root, atlas_dir, cache_path, &cache, old_commit, &delta).await
+}
+
 async fn load_old_meta(path: &Path) -> Option<Meta> {
     let content = tokio::fs::read_to_string(path).await.ok()?;
     serde_json::from_str(&content).ok()
 }
 
+/// Content hash, not mtime, is the source of truth for "changed": mtime has only whole-second
+/// resolution on many filesystems, so a file rewritten with the same byte length in the same
+/// second as its last capture would otherwise look untouched. Reading every discovered file to
+/// hash it costs more than a metadata stat, but source trees are small enough that determinism
+/// is worth it, and this is exactly the fast path that lets a warm, unchanged run skip the full
+/// walk — so it still needs to visit every file once to be sure.
 fn quick_change_check_sync(root: &Path, files: &[PathBuf], cache: &Cache) -> Option<usize> {
     if files.len() != cache.entries.len() {
         return None;
@@ -220,19 +402,10 @@ fn quick_change_check_sync(root: &Path, files: &[PathBuf], cache: &Cache) -> Opt
 
         let cached = cache.get(&relative_path)?;
 
-        let Ok(metadata) = std::fs::metadata(path) else {
-            return None;
-        };
+        let content = std::fs::read(path).ok()?;
+        let hash = blake3::hash(&content).to_hex().to_string();
 
-        let size = metadata.len();
-        let mtime = metadata
-            .modified()
-            .ok()
-            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-            .map(|d| d.as_secs())
-            .unwrap_or(0);
-
-        if cached.mtime != mtime || cached.size != size {
+        if cached.hash != hash {
             changed += 1;
         }
     }
@@ -240,12 +413,13 @@ fn quick_change_check_sync(root: &Path, files: &[PathBuf], cache: &Cache) -> Opt
     Some(changed)
 }
 
-async fn run_phase1_with_walk(
+pub async fn run_phase1_with_walk(
     root: &Path,
     workspace: &WorkspaceInfo,
     cache: &Cache,
     git_info: Option<&GitInfo>,
     walk_result: walk::WalkResult,
+    active_cfg: Option<&CfgSet>,
 ) -> Result<PipelineResult> {
     let pb = ProgressBar::new(walk_result.files.len() as u64);
     pb.set_style(
@@ -257,54 +431,37 @@ async fn run_phase1_with_walk(
             .progress_chars("#>-"),
     );
 
-    let semaphore = Arc::new(Semaphore::new(SEMAPHORE_PERMITS));
-    let files = Arc::new(Mutex::new(Vec::new()));
-    let skipped = Arc::new(Mutex::new(Vec::new()));
-
-    let mut join_set = JoinSet::new();
-
-    for file_path in walk_result.files {
-        let semaphore = Arc::clone(&semaphore);
-        let files = Arc::clone(&files);
-        let skipped = Arc::clone(&skipped);
-        let cache = cache.clone();
-        let root = root.to_path_buf();
-        let pb = pb.clone();
-
-        join_set.spawn(async move {
-            let _permit = semaphore.acquire().await;
-
-            match process_file(&file_path, &root, &cache).await {
-                Ok(Some(result)) => {
-                    files.lock().await.push(result);
-                }
-                Ok(None) => {}
-                Err(e) => {
-                    skipped.lock().await.push(SkippedFile {
-                        path: file_path,
-                        reason: e.to_string(),
-                    });
-                }
-            }
-
-            pb.inc(1);
-        });
-    }
-
-    while join_set.join_next().await.is_some() {}
+    let mut skipped: Vec<SkippedFile> = walk_result
+        .errors
+        .iter()
+        .map(|reason| SkippedFile {
+            path: root.to_path_buf(),
+            reason: reason.clone(),
+        })
+        .collect();
+
+    let root = root.to_path_buf();
+    let cache = cache.clone();
+    let pb_for_extraction = pb.clone();
+    let active_cfg = active_cfg.cloned();
+
+    let (mut files, mut extraction_skipped) = tokio::task::spawn_blocking(move || {
+        extract_symbols_parallel(
+            &walk_result.files,
+            &root,
+            &cache,
+            &pb_for_extraction,
+            active_cfg.as_ref(),
+        )
+    })
+    .await?;
 
     pb.finish_with_message("Phase 1 complete");
 
-    let mut files = Arc::try_unwrap(files)
-        .expect("all tasks completed")
-        .into_inner();
-
     files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+    skipped.append(&mut extraction_skipped);
 
     let total_lines: usize = files.iter().map(|f| f.lines).sum();
-    let skipped = Arc::try_unwrap(skipped)
-        .expect("all tasks completed")
-        .into_inner();
 
     Ok(PipelineResult {
         files,
@@ -316,6 +473,48 @@ async fn run_phase1_with_walk(
     })
 }
 
+/// Extracts symbols from `paths` with a rayon parallel iterator instead of the per-file tokio
+/// tasks [`process_file`] uses elsewhere, since parsing is CPU-bound and large workspaces spend
+/// most of phase 1 here — rayon's work-stealing pool keeps every core busy without the semaphore
+/// tuning an unbounded `JoinSet` of blocking tasks would need. Thread scheduling decides which
+/// file finishes first, not which file wins a conflict: skip records are sorted by path here and
+/// successes by the caller, keeping `symbols.md`/`skipped.md` byte-identical across runs.
+fn extract_symbols_parallel(
+    paths: &[PathBuf],
+    root: &Path,
+    cache: &Cache,
+    pb: &ProgressBar,
+    active_cfg: Option<&CfgSet>,
+) -> (Vec<FileResult>, Vec<SkippedFile>) {
+    let results: Vec<Result<Option<FileResult>, SkippedFile>> = paths
+        .par_iter()
+        .map(|path| {
+            let result =
+                process_file_sync(path, root, cache, active_cfg).map_err(|e| SkippedFile {
+                    path: path.clone(),
+                    reason: e.to_string(),
+                });
+            pb.inc(1);
+            result
+        })
+        .collect();
+
+    let mut files = Vec::with_capacity(results.len());
+    let mut skipped = Vec::new();
+
+    for result in results {
+        match result {
+            Ok(Some(file)) => files.push(file),
+            Ok(None) => {}
+            Err(skip) => skipped.push(skip),
+        }
+    }
+
+    skipped.sort_by(|a, b| a.path.cmp(&b.path));
+
+    (files, skipped)
+}
+
 async fn process_file(path: &Path, root: &Path, cache: &Cache) -> Result<Option<FileResult>> {
     let metadata = tokio::fs::metadata(path).await?;
     let size = metadata.len();
@@ -329,27 +528,6 @@ async fn process_file(path: &Path, root: &Path, cache: &Cache) -> Result<Option<
         .map(normalize_path)
         .unwrap_or_else(|_| normalize_path(path));
 
-    let mtime = metadata
-        .modified()
-        .ok()
-        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-        .map(|d| d.as_secs())
-        .unwrap_or(0);
-
-    if let Some(cached) = cache.get(&relative_path) {
-        if cached.mtime == mtime && cached.size == size {
-            return Ok(Some(FileResult {
-                path: path.to_path_buf(),
-                relative_path,
-                hash: cached.hash.clone(),
-                size,
-                lines: cached.lines,
-                parsed: cached.data.parsed.clone(),
-                from_cache: true,
-            }));
-        }
-    }
-
     let content = read::read_file(path, size).await?;
 
     if is_binary_content(&content) {
@@ -368,6 +546,9 @@ async fn process_file(path: &Path, root: &Path, cache: &Cache) -> Result<Option<
                 lines: cached.lines,
                 parsed: cached.data.parsed.clone(),
                 from_cache: true,
+                git_status: GitStatus::default(),
+                last_commit_timestamp: 0,
+                distinct_authors: 0,
             }));
         }
     }
@@ -389,10 +570,174 @@ async fn process_file(path: &Path, root: &Path, cache: &Cache) -> Result<Option<
         lines,
         parsed,
         from_cache: false,
+        git_status: GitStatus::default(),
+        last_commit_timestamp: 0,
+        distinct_authors: 0,
     }))
 }
 
-fn build_symbol_table(files: &[FileResult]) -> HashMap<String, (String, usize)> {
+/// Blocking twin of [`process_file`] for [`extract_symbols_parallel`]'s rayon pool: same
+/// read-hash-reuse-or-parse logic, but every I/O call is the `std::fs` equivalent so the whole
+/// function can run on a rayon worker thread without an executor to hand `.await` to.
+///
+/// When `active` is `Some`, the cache is never consulted — a cached `parsed` reflects an
+/// unconditional parse, not one filtered to a particular cfg configuration — and parsing goes
+/// through [`parse::parse_rust_file_with_cfg`] instead of [`parse::parse_rust_file`].
+fn process_file_sync(
+    path: &Path,
+    root: &Path,
+    cache: &Cache,
+    active: Option<&CfgSet>,
+) -> Result<Option<FileResult>> {
+    let metadata = std::fs::metadata(path)?;
+    let size = metadata.len();
+
+    if should_skip_file(size) {
+        return Ok(None);
+    }
+
+    let relative_path = path
+        .strip_prefix(root)
+        .map(normalize_path)
+        .unwrap_or_else(|_| normalize_path(path));
+
+    let content = std::fs::read(path)?;
+
+    if is_binary_content(&content) {
+        return Ok(None);
+    }
+
+    let hash = blake3::hash(&content).to_hex().to_string();
+
+    if active.is_none() {
+        if let Some(cached) = cache.get(&relative_path) {
+            if cached.hash == hash {
+                return Ok(Some(FileResult {
+                    path: path.to_path_buf(),
+                    relative_path,
+                    hash,
+                    size,
+                    lines: cached.lines,
+                    parsed: cached.data.parsed.clone(),
+                    from_cache: true,
+                    git_status: GitStatus::default(),
+                    last_commit_timestamp: 0,
+                    distinct_authors: 0,
+                }));
+            }
+        }
+    }
+
+    let lines = count_lines(&content);
+    let content_string = String::from_utf8_lossy(&content).into_owned();
+    let parsed = match active {
+        Some(active) => parse::parse_rust_file_with_cfg(&content_string, &relative_path, active)?,
+        None => parse::parse_rust_file(&content_string, &relative_path)?,
+    };
+
+    Ok(Some(FileResult {
+        path: path.to_path_buf(),
+        relative_path,
+        hash,
+        size,
+        lines,
+        parsed,
+        from_cache: false,
+        git_status: GitStatus::default(),
+        last_commit_timestamp: 0,
+        distinct_authors: 0,
+    }))
+}
+
+/// How a path compares to the last capture's `cache.bin`, for `charter status --pending`'s
+/// dry-run report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PendingStatus {
+    Added,
+    Modified,
+    Removed,
+    Skipped,
+}
+
+pub struct PendingChange {
+    pub relative_path: String,
+    pub status: PendingStatus,
+}
+
+/// Classifies every walked path against `cache` the same way a real capture decides what to
+/// re-parse, but never parses anything — just enough I/O (a stat, and a hash for files that
+/// aren't skipped outright) to tell `charter status --pending` what a real capture *would* do
+/// without writing `symbols.md`. Reuses [`should_skip_file`]/[`is_binary_content`] so a path
+/// reported `Skipped` here is one a real capture would skip too, not just a guess.
+pub async fn diff_against_cache(
+    root: &Path,
+    walked_files: &[PathBuf],
+    cache: &Cache,
+) -> Vec<PendingChange> {
+    let mut seen = HashSet::new();
+    let mut changes = Vec::new();
+
+    for path in walked_files {
+        let relative_path = path
+            .strip_prefix(root)
+            .map(normalize_path)
+            .unwrap_or_else(|_| normalize_path(path));
+        seen.insert(relative_path.clone());
+
+        let Ok(metadata) = tokio::fs::metadata(path).await else {
+            continue;
+        };
+        let size = metadata.len();
+
+        if should_skip_file(size) {
+            changes.push(PendingChange {
+                relative_path,
+                status: PendingStatus::Skipped,
+            });
+            continue;
+        }
+
+        let Ok(content) = tokio::fs::read(path).await else {
+            continue;
+        };
+
+        if is_binary_content(&content) {
+            changes.push(PendingChange {
+                relative_path,
+                status: PendingStatus::Skipped,
+            });
+            continue;
+        }
+
+        let hash = blake3::hash(&content).to_hex().to_string();
+
+        match cache.get(&relative_path) {
+            None => changes.push(PendingChange {
+                relative_path,
+                status: PendingStatus::Added,
+            }),
+            Some(cached) if cached.hash != hash => changes.push(PendingChange {
+                relative_path,
+                status: PendingStatus::Modified,
+            }),
+            Some(_) => {}
+        }
+    }
+
+    for relative_path in cache.entries.keys() {
+        if !seen.contains(relative_path) {
+            changes.push(PendingChange {
+                relative_path: relative_path.clone(),
+                status: PendingStatus::Removed,
+            });
+        }
+    }
+
+    changes.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+    changes
+}
+
+pub fn build_symbol_table(files: &[FileResult]) -> HashMap<String, (String, usize)> {
     let mut table = HashMap::new();
 
     for file in files {
@@ -409,7 +754,126 @@ fn build_symbol_table(files: &[FileResult]) -> HashMap<String, (String, usize)>
     table
 }
 
-fn run_phase2(
+/// One [`CapturedBody`] still competing for a share of the crate-wide snippet budget, located by
+/// `(file_index, body_index)` into `files` rather than owning a copy of the body itself.
+struct SnippetCandidate {
+    file_index: usize,
+    body_index: usize,
+    importance_score: u32,
+    char_len: usize,
+}
+
+/// How many of the highest value-density candidates the bounded knapsack refinement considers.
+/// Keeps the DP's `O(n * budget)` table a fixed, small size regardless of how many functions in
+/// the crate scored high enough to be a full-text candidate.
+const SNIPPET_DP_POOL_SIZE: usize = 64;
+
+/// Replaces each file's independent, fixed-size snippet slice with a single crate-wide decision:
+/// every function that scored high enough to be captured in full (see
+/// [`parse::capture_function_body`]) is a candidate, and this picks the subset whose full text
+/// fits in [`parse::MAX_TOTAL_SNIPPET_BUDGET`] while maximizing total `importance_score` — instead
+/// of each file greedily claiming `MAX_TOTAL_SNIPPET_BUDGET / 20` regardless of whether its
+/// functions were actually the most important in the crate. Candidates that don't make the cut
+/// fall back to the summary [`parse::capture_function_body`] already captured alongside them.
+pub fn allocate_snippet_budget(files: &mut [FileResult]) {
+    let mut candidates = Vec::new();
+
+    for (file_index, file) in files.iter().enumerate() {
+        for (body_index, body) in file.parsed.captured_bodies.iter().enumerate() {
+            if let Some(full_text) = &body.body.full_text {
+                candidates.push(SnippetCandidate {
+                    file_index,
+                    body_index,
+                    importance_score: body.importance_score,
+                    char_len: full_text.len(),
+                });
+            }
+        }
+    }
+
+    let selected = select_snippet_candidates(candidates, parse::MAX_TOTAL_SNIPPET_BUDGET);
+
+    for (file_index, file) in files.iter_mut().enumerate() {
+        for (body_index, body) in file.parsed.captured_bodies.iter_mut().enumerate() {
+            if body.body.full_text.is_some() && !selected.contains(&(file_index, body_index)) {
+                body.body.full_text = None;
+            }
+        }
+    }
+}
+
+/// Selects the `(file_index, body_index)` pairs to keep as full text, taking the better of two
+/// approaches: a cheap greedy fill by value-density (`importance_score / char_len`) over every
+/// candidate, and an exact 0/1 knapsack over just the top [`SNIPPET_DP_POOL_SIZE`] candidates by
+/// that same density, which can beat greedy when a slightly denser combination fits the budget
+/// more tightly than density order alone would find.
+fn select_snippet_candidates(
+    mut candidates: Vec<SnippetCandidate>,
+    budget: usize,
+) -> HashSet<(usize, usize)> {
+    candidates.sort_by(|a, b| density(b).partial_cmp(&density(a)).unwrap());
+
+    let (greedy_keys, greedy_value) = greedy_fill(&candidates, budget);
+
+    let pool: Vec<&SnippetCandidate> = candidates.iter().take(SNIPPET_DP_POOL_SIZE).collect();
+    let (dp_keys, dp_value) = knapsack_fill(&pool, budget);
+
+    if dp_value > greedy_value {
+        dp_keys
+    } else {
+        greedy_keys
+    }
+}
+
+fn density(candidate: &SnippetCandidate) -> f64 {
+    candidate.importance_score as f64 / candidate.char_len.max(1) as f64
+}
+
+fn greedy_fill(candidates: &[SnippetCandidate], budget: usize) -> (HashSet<(usize, usize)>, u64) {
+    let mut remaining = budget;
+    let mut keys = HashSet::new();
+    let mut value = 0u64;
+
+    for candidate in candidates {
+        if candidate.char_len <= remaining {
+            remaining -= candidate.char_len;
+            keys.insert((candidate.file_index, candidate.body_index));
+            value += candidate.importance_score as u64;
+        }
+    }
+
+    (keys, value)
+}
+
+fn knapsack_fill(pool: &[&SnippetCandidate], budget: usize) -> (HashSet<(usize, usize)>, u64) {
+    let n = pool.len();
+    let mut dp = vec![vec![0u64; budget + 1]; n + 1];
+
+    for i in 1..=n {
+        let item = pool[i - 1];
+        for w in 0..=budget {
+            dp[i][w] = dp[i - 1][w];
+            if item.char_len <= w {
+                dp[i][w] =
+                    dp[i][w].max(dp[i - 1][w - item.char_len] + item.importance_score as u64);
+            }
+        }
+    }
+
+    let mut keys = HashSet::new();
+    let mut w = budget;
+    for i in (1..=n).rev() {
+        if dp[i][w] != dp[i - 1][w] {
+            let item = pool[i - 1];
+            keys.insert((item.file_index, item.body_index));
+            w -= item.char_len;
+        }
+    }
+
+    (keys, dp[n][budget])
+}
+
+pub fn run_phase2(
     files: &[FileResult],
     symbol_table: &HashMap<String, (String, usize)>,
 ) -> HashMap<String, Vec<(String, usize)>> {
@@ -446,34 +910,98 @@ fn run_phase2(
     references
 }
 
+/// Skips `write` — one of [`output::symbols::write_symbols`]/[`output::type_map::write_types`] —
+/// in favor of digesting the report already on disk from the previous capture, when `regen` is
+/// `false` (i.e. [`DiffSummary::item_docs_need_regen`] found nothing that could have changed
+/// `name`'s contents). Falls back to actually running `write` if no prior report exists to digest,
+/// since that only happens before this tree has ever been captured in full.
+async fn item_doc_digest(
+    name: &'static str,
+    regen: bool,
+    atlas_dir: &Path,
+    write: impl std::future::Future<Output = Result<output::ArtifactDigest>>,
+) -> Result<output::ArtifactDigest> {
+    if regen {
+        return write.await;
+    }
+
+    match output::digest_written_file(name, &atlas_dir.join(name)).await {
+        Ok(digest) => Ok(digest),
+        Err(_) => write.await,
+    }
+}
+
 async fn emit_outputs(
     root: &Path,
     result: &PipelineResult,
     references: &HashMap<String, Vec<(String, usize)>>,
     churn_data: &HashMap<PathBuf, u32>,
+    detailed_churn: &HashMap<PathBuf, ChurnStats>,
+    emit_model_json: bool,
+    emit_snippets_html: bool,
+    regen_item_docs: bool,
 ) -> Result<()> {
     let atlas_dir = root.join(".atlas");
+    let coverage = crate::tests::load_coverage(&root.join(".charter")).await;
 
     let stamp = format_stamp(result);
+    let dataflow_model = output::dataflow::build_dataflow_model(result);
+
+    let mut digests = vec![
+        output::overview::write_overview(&atlas_dir, result, &stamp).await?,
+        item_doc_digest(
+            "symbols.md",
+            regen_item_docs,
+            &atlas_dir,
+            output::symbols::write_symbols(&atlas_dir, result, churn_data, &stamp),
+        )
+        .await?,
+        item_doc_digest(
+            "types.md",
+            regen_item_docs,
+            &atlas_dir,
+            output::type_map::write_types(&atlas_dir, result, &stamp),
+        )
+        .await?,
+        output::refs::write_refs(&atlas_dir, references, &stamp, false).await?,
+        output::dependents::write_dependents(&atlas_dir, result, &stamp).await?,
+        output::imports::write_imports(root, &atlas_dir, result, &stamp).await?,
+        output::manifest::write_manifest(&atlas_dir, result, churn_data, &stamp).await?,
+        output::hotspots::write_hotspots(&atlas_dir, result, churn_data, coverage.as_ref(), &stamp)
+            .await?,
+        output::churn::write_churn(&atlas_dir, result, detailed_churn, &stamp).await?,
+        output::calls::write_calls(&atlas_dir, result, &stamp).await?,
+        output::callgraph::write_callgraph(&atlas_dir, result, &stamp).await?,
+        output::errors::write_errors(&atlas_dir, result, &stamp).await?,
+        output::snippets::write_snippets(&atlas_dir, result, &stamp).await?,
+    ];
+
+    if emit_snippets_html {
+        digests.push(output::snippets::write_snippets_html(&atlas_dir, result, &stamp).await?);
+    }
 
-    output::overview::write_overview(&atlas_dir, result, &stamp).await?;
-    output::symbols::write_symbols(&atlas_dir, result, churn_data, &stamp).await?;
-    output::type_map::write_types(&atlas_dir, result, &stamp).await?;
-    output::refs::write_refs(&atlas_dir, references, &stamp).await?;
-    output::dependents::write_dependents(&atlas_dir, result, &stamp).await?;
-    output::manifest::write_manifest(&atlas_dir, result, churn_data, &stamp).await?;
-    output::hotspots::write_hotspots(&atlas_dir, result, churn_data, &stamp).await?;
-    output::calls::write_calls(&atlas_dir, result, &stamp).await?;
-    output::errors::write_errors(&atlas_dir, result, &stamp).await?;
-    output::snippets::write_snippets(&atlas_dir, result, &stamp).await?;
-    output::safety::write_safety(&atlas_dir, result, &stamp).await?;
-    output::clusters::write_clusters(&atlas_dir, result, &stamp).await?;
-    output::dataflow::write_dataflow(&atlas_dir, result, &stamp).await?;
+    digests.extend(vec![
+        output::safety::write_safety(&atlas_dir, result, &stamp).await?,
+        output::clusters::write_clusters(&atlas_dir, result, &stamp).await?,
+        output::dataflow::write_dataflow(&atlas_dir, &dataflow_model, &stamp).await?,
+        output::attributes::write_attributes(&atlas_dir, result, &stamp).await?,
+    ]);
 
     if !result.skipped.is_empty() {
-        output::skipped::write_skipped(&atlas_dir, &result.skipped, &stamp).await?;
+        digests.push(output::skipped::write_skipped(&atlas_dir, &result.skipped, &stamp).await?);
+    }
+
+    digests.push(output::overview_json::write_overview_json(&atlas_dir, result).await?);
+    digests.extend(output::export_json::write_export_json(&atlas_dir, result).await?);
+
+    if emit_model_json {
+        digests.push(output::model_json::write_model_json(&atlas_dir, result).await?);
+        digests.push(output::dataflow::write_dataflow_text(&atlas_dir, &dataflow_model).await?);
+        digests.push(output::dataflow::write_dataflow_binary(&atlas_dir, &dataflow_model).await?);
     }
 
+    output::manifest_json::write_manifest_json(&atlas_dir, &digests, &stamp).await?;
+
     write_meta(&atlas_dir, result).await?;
     write_format_md(&atlas_dir).await?;
 
@@ -500,24 +1028,208 @@ fn format_stamp(result: &PipelineResult) -> String {
     }
 }
 
-fn build_cache(files: &[FileResult]) -> Cache {
-    let mut cache = Cache::default();
+/// Folds [`churn::compute_churn`]'s recency-weighted per-file figures into every function's
+/// `ComplexityMetrics.churn_score` before the result is cached, so `tier()`/`importance_score()`
+/// reflect real churn everywhere the cached complexity data is read from (rules, the `complexity`
+/// subcommand, etc.) rather than only in `hotspots.md`'s own display-only overlay.
+fn apply_churn_scores(files: &mut [FileResult], churn_by_path: &HashMap<String, u32>) {
+    for file in files {
+        let Some(&score) = churn_by_path.get(&file.relative_path) else {
+            continue;
+        };
+        for func in &mut file.parsed.complexity {
+            func.metrics.churn_score = score;
+        }
+    }
+}
+
+/// Joins [`git::get_status_map`]'s per-path working-tree status into every [`FileResult`], so
+/// `manifest.md`/[`print_summary`] can show VCS state alongside the structural data without a
+/// second git call. Paths the status map has no entry for (the common, unmodified case) keep
+/// [`GitStatus::default`].
+fn apply_git_status(files: &mut [FileResult], status_by_path: &HashMap<String, GitStatus>) {
+    for file in files {
+        file.git_status = status_by_path
+            .get(&file.relative_path)
+            .copied()
+            .unwrap_or_default();
+    }
+}
+
+/// Folds [`git::get_detailed_churn`]'s per-file commit recency and authorship into every
+/// function's `recency_score`/`distinct_authors`, so [`ComplexityMetrics::importance_score_with`]
+/// can weight actively-evolving hot spots above static churn totals. Keyed by `file.path` (an
+/// absolute path), matching the convention [`output::churn::write_churn`] already uses to look up
+/// `detailed_churn` — not `relative_path`, which is [`apply_churn_scores`]'s own, differently-keyed
+/// map. `recency_score` decays linearly from 100 (committed today) to 0 over 30 days so the bonus
+/// stays deterministic between runs on an unchanged tree.
+fn apply_recency_and_author_scores(files: &mut [FileResult], detailed_churn: &HashMap<PathBuf, ChurnStats>) {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0);
 
     for file in files {
-        let mtime = std::fs::metadata(&file.path)
-            .and_then(|m| m.modified())
-            .ok()
-            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-            .map(|d| d.as_secs())
-            .unwrap_or(0);
+        let Some(stats) = detailed_churn.get(&file.path) else {
+            continue;
+        };
+        let age_days = (now - stats.last_modified).max(0) as f64 / 86_400.0;
+        let recency_score = (100.0 - (age_days / 30.0) * 100.0).clamp(0.0, 100.0) as u32;
+        let distinct_authors = stats.authors.len() as u32;
+
+        file.last_commit_timestamp = stats.last_modified;
+        file.distinct_authors = distinct_authors;
+        for func in &mut file.parsed.complexity {
+            func.metrics.recency_score = recency_score;
+            func.metrics.distinct_authors = distinct_authors;
+        }
+    }
+}
+
+/// Hashes the position-independent "shape" of every item `symbols.md`/`types.md` render from
+/// `parsed` — names, kinds, signatures, visibility, nesting — deliberately excluding anything
+/// that shifts on a pure body edit or a re-evaluated cfg pass: `Symbol::line`/`Symbol::cfg_active`,
+/// `FunctionSignature::panics_in_body`, and every `FunctionBody`/`captured_bodies` text. Stored on
+/// [`CacheEntry::item_summary_hash`] so a later capture can tell whether a file's edits stayed
+/// inside function bodies without re-deriving `symbols.md`/`types.md` to find out — see
+/// [`ModifiedFile::item_summary_changed`], which [`build_diff_summary`] sets by comparing this
+/// against the cached value. `pub(crate)` for the same reason as [`build_cache`]: `serve.rs` keeps
+/// its own cache-building copy and needs to populate the field identically.
+pub(crate) fn item_summary_hash(parsed: &parse::ParsedFile) -> u64 {
+    use std::fmt::Write;
+
+    let mut shape = String::with_capacity(4096);
+
+    if let Some(doc) = &parsed.module_doc {
+        shape.push_str(doc);
+    }
+    shape.push('\0');
+
+    for derive in &parsed.derives {
+        let _ = write!(shape, "derive:{}:{}\0", derive.target, derive.traits.join(","));
+    }
+
+    for imp in &parsed.impls {
+        let _ = write!(shape, "impl:{}:{}:{}\0", imp.type_name, imp.trait_name, imp.is_derived);
+    }
+
+    for (trait_name, type_name) in &parsed.symbols.impl_map {
+        let _ = write!(shape, "impl_map:{trait_name}:{type_name}\0");
+    }
+
+    for re_export in &parsed.re_exports {
+        let _ = write!(shape, "reexport:{}:{}\0", re_export.source_path, re_export.visibility);
+    }
+
+    for macro_info in &parsed.symbols.macros {
+        let _ = write!(shape, "macro:{}:{}\0", macro_info.name, macro_info.is_exported);
+    }
+
+    for imp in &parsed.symbols.inherent_impls {
+        push_inherent_impl_shape(&mut shape, imp);
+    }
+
+    for item in &parsed.symbols.trait_impl_assoc_items {
+        let _ = write!(
+            shape,
+            "trait_impl_assoc:{}:{}:{:?}:{}:{}:{}\0",
+            item.trait_name, item.type_name, item.kind, item.name, item.value_type, item.visibility
+        );
+    }
+
+    for symbol in &parsed.symbols.symbols {
+        push_symbol_shape(&mut shape, symbol);
+    }
+
+    let digest = blake3::hash(shape.as_bytes());
+    u64::from_le_bytes(digest.as_bytes()[..8].try_into().expect("blake3 digest is 32 bytes"))
+}
+
+fn push_inherent_impl_shape(shape: &mut String, imp: &crate::extract::symbols::InherentImpl) {
+    use std::fmt::Write;
+
+    let _ = write!(shape, "inherent:{}:{}:{:?}\0", imp.type_name, imp.generics, imp.where_clause);
+    for method in &imp.methods {
+        let _ = write!(
+            shape,
+            "method:{}:{}:{}:{}:{}:{}\0",
+            method.name, method.visibility, method.signature, method.is_async, method.is_unsafe, method.is_const
+        );
+    }
+    for const_item in &imp.assoc_consts {
+        let _ = write!(shape, "assoc_const:{}:{}:{}\0", const_item.name, const_item.const_type, const_item.visibility);
+    }
+    for type_item in &imp.assoc_types {
+        let _ = write!(shape, "assoc_type:{}:{}:{}\0", type_item.name, type_item.bound_type, type_item.visibility);
+    }
+}
+
+fn push_symbol_shape(shape: &mut String, symbol: &crate::extract::symbols::Symbol) {
+    use std::fmt::Write;
+
+    let _ = write!(
+        shape,
+        "symbol:{}:{}:{}:{}:{}:{}:{}:{}\0",
+        symbol.name,
+        symbol.visibility,
+        symbol.generics,
+        symbol.is_async,
+        symbol.is_unsafe,
+        symbol.is_const,
+        symbol.re_exported_as.as_deref().unwrap_or(""),
+        symbol.module_path,
+    );
+    push_symbol_kind_shape(shape, &symbol.kind);
+}
+
+/// Writes everything about `kind` that affects a rendered report except body text — the only
+/// variant that carries any is [`crate::extract::symbols::SymbolKind::Function`], whose `body` field (and whose
+/// `signature_model.panics_in_body`, derived from scanning that same body) are skipped in favor
+/// of `signature` alone. Every other variant already excludes body text, so it's safe to hash via
+/// `Debug` directly.
+fn push_symbol_kind_shape(shape: &mut String, kind: &crate::extract::symbols::SymbolKind) {
+    use std::fmt::Write;
+
+    match kind {
+        crate::extract::symbols::SymbolKind::Function {
+            signature,
+            signature_model,
+            body: _,
+        } => {
+            let _ = write!(
+                shape,
+                "fn:{}:{:?}:{:?}:{:?}:{:?}:{:?}\0",
+                signature,
+                signature_model.receiver,
+                signature_model.params,
+                signature_model.generics,
+                signature_model.where_clause,
+                signature_model.return_type,
+            );
+        }
+        other => {
+            let _ = write!(shape, "{:?}\0", other);
+        }
+    }
+}
+
+/// `pub(crate)` (rather than private) so crate-wide passes that need a [`Cache`]-shaped view of
+/// a freshly captured [`PipelineResult`] — e.g. [`crate::errorflow::build_error_flow_graph`],
+/// which joins error info against [`crate::callindex::build_call_graph`] — can build one without
+/// waiting for the real `cache.bin` round-trip this function's other caller performs.
+pub(crate) fn build_cache(files: &[FileResult]) -> Cache {
+    let mut cache = Cache::default();
 
+    for file in files {
         cache.entries.insert(
             file.relative_path.clone(),
             CacheEntry {
                 hash: file.hash.clone(),
-                mtime,
                 size: file.size,
                 lines: file.lines,
+                item_summary_hash: item_summary_hash(&file.parsed),
+                last_commit_timestamp: file.last_commit_timestamp,
+                distinct_authors: file.distinct_authors,
                 data: FileData {
                     parsed: file.parsed.clone(),
                 },
@@ -528,6 +1240,212 @@ fn build_cache(files: &[FileResult]) -> Cache {
     cache
 }
 
+/// Keeps the async runtime alive and re-runs an incremental capture each time
+/// [`watch::watch_directory`] yields a debounced batch of changed files, instead of requiring
+/// the user to manually re-invoke `charter` after every edit. Runs a normal [`capture`] first so
+/// `.atlas/cache.bin` reflects the working tree before the event loop starts.
+pub async fn watch(root: &Path) -> Result<()> {
+    capture(root).await?;
+
+    let atlas_dir = root.join(".atlas");
+    let mut deltas = watch::watch_directory(root, walk::WalkConfig::default()).await?;
+
+    println!();
+    println!(
+        "Watching {} for changes (Ctrl+C to stop)...",
+        root.display()
+    );
+
+    while let Some(delta) = deltas.recv().await {
+        println!(
+            "  +{} ~{} -{} file(s) changed, re-capturing...",
+            delta.added.len(),
+            delta.changed.len(),
+            delta.removed.len()
+        );
+        if let Err(e) = recapture(root, &atlas_dir, delta).await {
+            eprintln!("watch: re-capture failed: {e}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Loads `cache.bin`/`meta.json` then delegates to [`apply_delta`] — the watch-loop entry point
+/// for splicing a [`watch::WalkDelta`] into the cached graph.
+async fn recapture(root: &Path, atlas_dir: &Path, delta: watch::WalkDelta) -> Result<()> {
+    let cache_path = atlas_dir.join("cache.bin");
+    let meta_path = atlas_dir.join("meta.json");
+
+    let (cache, old_meta) = tokio::join!(Cache::load(&cache_path), load_old_meta(&meta_path));
+    let cache = cache.unwrap_or_default();
+    let old_commit = old_meta.and_then(|m| m.git_commit);
+
+    apply_delta(root, atlas_dir, &cache_path, &cache, old_commit, &delta).await
+}
+
+/// Merges `delta` into `cache` and rewrites every output document — only `added`/`changed`
+/// paths are re-parsed (via the same [`process_file`] cache check a full [`capture`] uses),
+/// everything else is carried over from `cache.bin` untouched. Output documents are rewritten in
+/// full since they're cross-file aggregates (call graphs, hotspot rankings, etc.) that a single
+/// file's change can shift, except `symbols.md`/`types.md`, which are left as they are when
+/// [`DiffSummary::item_docs_need_regen`] finds no item-level change. Shared by [`recapture`] (filesystem-watch deltas) and
+/// [`capture`]'s git-aware incremental path (diff-derived deltas).
+async fn apply_delta(
+    root: &Path,
+    atlas_dir: &Path,
+    cache_path: &Path,
+    cache: &Cache,
+    old_commit: Option<String>,
+    delta: &watch::WalkDelta,
+) -> Result<()> {
+    let workspace = detect_workspace(root).await?;
+    let (git_info, churn_data, detailed_churn, weighted_churn, status_map) = tokio::join!(
+        get_git_info(root),
+        get_churn_data(root),
+        get_detailed_churn(root, DEFAULT_CHURN_WINDOW_DAYS),
+        churn::compute_churn(root),
+        get_status_map(root)
+    );
+    let git_info = git_info.ok();
+    let churn_data = churn_data.unwrap_or_default();
+    let detailed_churn = detailed_churn.unwrap_or_default();
+
+    let mut files = merge_delta(root, cache, delta).await;
+    files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+    let total_lines: usize = files.iter().map(|f| f.lines).sum();
+
+    let mut result = PipelineResult {
+        files,
+        workspace,
+        git_info: git_info.clone(),
+        total_lines,
+        skipped: Vec::new(),
+        diff_summary: None,
+    };
+
+    apply_churn_scores(&mut result.files, &weighted_churn);
+    apply_git_status(&mut result.files, &status_map);
+    apply_recency_and_author_scores(&mut result.files, &detailed_churn);
+    allocate_snippet_budget(&mut result.files);
+
+    result.diff_summary = Some(build_diff_summary(
+        &result.files,
+        cache,
+        old_commit,
+        git_info.map(|g| g.commit_short),
+    ));
+
+    let symbol_table = build_symbol_table(&result.files);
+    let references = run_phase2(&result.files, &symbol_table);
+
+    let regen_item_docs = result
+        .diff_summary
+        .as_ref()
+        .is_some_and(|diff| diff.item_docs_need_regen());
+
+    // `model.json` is a full-capture-only artifact (see `capture_with_mode`'s `format_json`); an
+    // incremental/watch delta never regenerates it.
+    emit_outputs(
+        root,
+        &result,
+        &references,
+        &churn_data,
+        &detailed_churn,
+        false,
+        false,
+        regen_item_docs,
+    )
+    .await?;
+
+    let new_cache = build_cache(&result.files);
+    new_cache.save(cache_path).await?;
+
+    update_search_index(atlas_dir, &result).await?;
+    update_symbol_search_index(atlas_dir, &new_cache).await?;
+
+    print_summary(&result);
+
+    Ok(())
+}
+
+/// Starts from every cached file (as an already-parsed [`FileResult`]), drops paths in
+/// `delta.removed`, and re-parses paths in `delta.added`/`delta.changed` via [`process_file`].
+async fn merge_delta(root: &Path, cache: &Cache, delta: &watch::WalkDelta) -> Vec<FileResult> {
+    let mut files: Vec<FileResult> = cache
+        .entries
+        .iter()
+        .map(|(relative_path, entry)| FileResult {
+            path: root.join(relative_path),
+            relative_path: relative_path.clone(),
+            hash: entry.hash.clone(),
+            size: entry.size,
+            lines: entry.lines,
+            parsed: entry.data.parsed.clone(),
+            from_cache: true,
+            git_status: GitStatus::default(),
+            last_commit_timestamp: entry.last_commit_timestamp,
+            distinct_authors: entry.distinct_authors,
+        })
+        .collect();
+
+    let removed: HashSet<String> = delta
+        .removed
+        .iter()
+        .map(|path| normalize_path(path.strip_prefix(root).unwrap_or(path)))
+        .collect();
+    files.retain(|f| !removed.contains(&f.relative_path));
+
+    for path in delta.added.iter().chain(delta.changed.iter()) {
+        let relative_path = normalize_path(path.strip_prefix(root).unwrap_or(path));
+        files.retain(|f| f.relative_path != relative_path);
+
+        match process_file(path, root, cache).await {
+            Ok(Some(result)) => files.push(result),
+            Ok(None) => {}
+            Err(e) => eprintln!("watch: failed to re-parse {}: {e}", relative_path),
+        }
+    }
+
+    files
+}
+
+/// Updates `index.bin`'s inverted index in place: only files that actually re-parsed this
+/// capture (`from_cache == false`) are re-indexed, and files removed since the last capture
+/// (per `diff_summary`) drop their documents — an untouched file's postings are left alone
+/// rather than rebuilding the whole index from scratch.
+async fn update_search_index(atlas_dir: &Path, result: &PipelineResult) -> Result<()> {
+    let index_path = atlas_dir.join("index.bin");
+    let mut index = crate::cache::SearchIndex::load(&index_path)
+        .await
+        .unwrap_or_default();
+
+    for file in &result.files {
+        if !file.from_cache {
+            index.update_file(&file.relative_path, &file.parsed);
+        }
+    }
+
+    if let Some(diff) = &result.diff_summary {
+        for removed in &diff.removed {
+            index.remove_file(&removed.path);
+        }
+    }
+
+    index.save(&index_path).await?;
+    Ok(())
+}
+
+/// Rebuilds `symbols.fst`/`symbols_meta.bin` from `cache` in one shot. Unlike `index.bin`'s
+/// incremental per-file update, an FST's sorted key layout isn't cheap to patch in place, and
+/// `cache` already holds every function's complexity metrics after a capture regenerates it, so
+/// there's no per-file work to skip the way there is for the inverted index.
+async fn update_symbol_search_index(atlas_dir: &Path, cache: &Cache) -> Result<()> {
+    crate::symbolsearch::SymbolSearchIndex::build(cache)
+        .save(atlas_dir)
+        .await
+}
+
 fn build_diff_summary(
     files: &[FileResult],
     old_cache: &Cache,
@@ -535,7 +1453,6 @@ fn build_diff_summary(
     new_commit: Option<String>,
 ) -> DiffSummary {
     use crate::extract::symbols::SymbolKind;
-    use std::collections::HashSet;
 
     let mut summary = DiffSummary {
         old_commit,
@@ -592,6 +1509,7 @@ fn build_diff_summary(
 
         let mut signature_changes = Vec::new();
         let mut field_changes = Vec::new();
+        let mut body_diffs = Vec::new();
 
         for new_sym in new_symbols {
             if let Some(old_sym) = old_symbols.iter().find(|s| s.name == new_sym.name) {
@@ -606,6 +1524,30 @@ fn build_diff_summary(
                     ) => {
                         if old_sig != new_sig {
                             signature_changes.push(format!("fn {}", new_sym.name));
+
+                            let old_body = cached
+                                .data
+                                .parsed
+                                .captured_bodies
+                                .iter()
+                                .find(|b| b.function_name == new_sym.name)
+                                .and_then(|b| b.body.full_text.as_deref());
+                            let new_body = file
+                                .parsed
+                                .captured_bodies
+                                .iter()
+                                .find(|b| b.function_name == new_sym.name)
+                                .and_then(|b| b.body.full_text.as_deref());
+
+                            if let (Some(old_text), Some(new_text)) = (old_body, new_body) {
+                                let hunks = linediff::diff_lines(old_text, new_text);
+                                if !hunks.is_empty() {
+                                    body_diffs.push(BodyDiff {
+                                        symbol: new_sym.name.clone(),
+                                        hunks: linediff::format_unified(&hunks),
+                                    });
+                                }
+                            }
                         }
                     }
                     (
@@ -629,12 +1571,16 @@ fn build_diff_summary(
             }
         }
 
+        let item_summary_changed = cached.item_summary_hash != item_summary_hash(&file.parsed);
+
         summary.modified.push(ModifiedFile {
             path: path.clone(),
             symbols_added,
             symbols_removed,
             signature_changes,
             field_changes,
+            body_diffs,
+            item_summary_changed,
         });
     }
 
@@ -708,6 +1654,13 @@ fn print_summary(result: &PipelineResult) {
                     format!(" ({})", details.join(", "))
                 };
                 println!("  modified: {}{}", modified.path, detail_str);
+
+                for body_diff in &modified.body_diffs {
+                    println!("    {} body diff:", body_diff.symbol);
+                    for line in &body_diff.hunks {
+                        println!("      {}", line);
+                    }
+                }
             }
 
             for added in &diff.added {
@@ -745,6 +1698,29 @@ fn print_summary(result: &PipelineResult) {
         cached_count,
         result.skipped.len()
     );
+
+    let modified_count = result
+        .files
+        .iter()
+        .filter(|f| f.git_status == GitStatus::Modified)
+        .count();
+    let staged_count = result
+        .files
+        .iter()
+        .filter(|f| f.git_status == GitStatus::Staged)
+        .count();
+    let untracked_count = result
+        .files
+        .iter()
+        .filter(|f| f.git_status == GitStatus::Untracked)
+        .count();
+
+    if modified_count + staged_count + untracked_count > 0 {
+        println!(
+            "  working tree: {} modified, {} staged, {} untracked",
+            modified_count, staged_count, untracked_count
+        );
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -780,9 +1756,12 @@ This directory contains generated structural context for Rust codebases.
 - `types.md` — trait definitions, impl map, derive map
 - `refs.md` — cross-reference index (PascalCase types only)
 - `dependents.md` — inverse dependency map
+- `imports.md` — minimal canonical `use` path for every public symbol
 - `manifest.md` — file manifest with roles, churn, test locations
 - `hotspots.md` — high-complexity functions ranked by importance score
+- `churn.md` — complexity x git churn risk ranking (refactor-candidate signal)
 - `calls.md` — call graph with hot paths and function relationships
+- `callgraph.md` — per-function callers/callees, extending dependents.md/refs.md to function level
 - `errors.md` — error propagation patterns, origins, and public API surface
 - `snippets.md` — captured function bodies for high/medium importance functions
 - `skipped.md` — files skipped during capture (if any)
@@ -829,3 +1808,52 @@ Importance score = (cyclomatic * 2) + (lines / 10) + (call_sites * 3) + (churn *
     tokio::fs::write(atlas_dir.join("FORMAT.md"), content).await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(file_index: usize, body_index: usize, importance_score: u32, char_len: usize) -> SnippetCandidate {
+        SnippetCandidate {
+            file_index,
+            body_index,
+            importance_score,
+            char_len,
+        }
+    }
+
+    /// Two small items (value 80 together) beat one large item that alone would exhaust the
+    /// budget (value 60); greedy-by-density finds the pair and the knapsack pass only ties it.
+    #[test]
+    fn select_snippet_candidates_prefers_the_higher_value_combination() {
+        let candidates = vec![
+            candidate(0, 0, 60, 100),
+            candidate(0, 1, 40, 50),
+            candidate(0, 2, 40, 50),
+        ];
+
+        let selected = select_snippet_candidates(candidates, 100);
+
+        assert_eq!(selected, HashSet::from([(0, 1), (0, 2)]));
+    }
+
+    /// When every candidate fits, all of them are kept.
+    #[test]
+    fn select_snippet_candidates_keeps_everything_within_budget() {
+        let candidates = vec![candidate(0, 0, 10, 20), candidate(1, 0, 5, 10)];
+
+        let selected = select_snippet_candidates(candidates, 1_000);
+
+        assert_eq!(selected, HashSet::from([(0, 0), (1, 0)]));
+    }
+
+    /// A zero budget keeps nothing, regardless of how valuable the candidates are.
+    #[test]
+    fn select_snippet_candidates_with_zero_budget_keeps_nothing() {
+        let candidates = vec![candidate(0, 0, 100, 1)];
+
+        let selected = select_snippet_candidates(candidates, 0);
+
+        assert!(selected.is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::extract::symbols::CallCategory;
+
+/// User-supplied overrides for [`CallClassifier`], loaded from a TOML config file (e.g.
+/// `.charter/keycalls.toml`) so a project can widen or narrow which calls get surfaced in
+/// `key_calls`, and under which category, without touching charter's source.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ClassifierConfig {
+    /// Call names that should always be kept, tagged [`CallCategory::Other`] unless a category
+    /// pattern (builtin or below) also matches.
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// Regex patterns for call names that should always be dropped, layered on top of charter's
+    /// own builtin denylist.
+    #[serde(default)]
+    pub deny: Vec<String>,
+    #[serde(default)]
+    pub io: Vec<String>,
+    #[serde(default)]
+    pub allocation: Vec<String>,
+    #[serde(default)]
+    pub error_handling: Vec<String>,
+    #[serde(default)]
+    pub concurrency: Vec<String>,
+    #[serde(default)]
+    pub logging: Vec<String>,
+}
+
+struct CategoryRule {
+    category: CallCategory,
+    pattern: Regex,
+}
+
+/// Replaces the old binary `is_trivial_call` heuristic: every call site gets tagged with a
+/// [`CallCategory`] instead of being silently dropped, so a fallible `unwrap`/`expect` or an I/O
+/// boundary shows up in `key_calls` under its category rather than vanishing into a denylist.
+/// Only calls matching neither a category pattern, an explicit allow entry, nor the fallback
+/// "generic utility" denylist are kept as [`CallCategory::Other`]; calls matching the denylist
+/// (and not overridden by `allow`) are dropped via [`CallClassifier::classify`] returning `None`.
+pub struct CallClassifier {
+    allow: Vec<String>,
+    deny: Vec<Regex>,
+    rules: Vec<CategoryRule>,
+}
+
+fn compile_rules(category: CallCategory, patterns: &[&str]) -> Vec<CategoryRule> {
+    patterns
+        .iter()
+        .filter_map(|pattern| Regex::new(pattern).ok())
+        .map(|pattern| CategoryRule { category, pattern })
+        .collect()
+}
+
+fn compile_user_rules(category: CallCategory, patterns: &[String]) -> Vec<CategoryRule> {
+    patterns
+        .iter()
+        .filter_map(|pattern| Regex::new(pattern).ok())
+        .map(|pattern| CategoryRule { category, pattern })
+        .collect()
+}
+
+/// Generic-utility calls (collection/conversion/accessor noise) that stay dropped by default —
+/// unlike `unwrap`/`expect`/`clone`/`new`, which are now claimed by a category rule instead.
+const DEFAULT_DENY: &[&str] = &[
+    "^to_string$",
+    "^to_owned$",
+    "^into$",
+    "^as_ref$",
+    "^as_mut$",
+    "^ok$",
+    "^err$",
+    "^some$",
+    "^none$",
+    "^push$",
+    "^pop$",
+    "^insert$",
+    "^remove$",
+    "^get$",
+    "^len$",
+    "^is_empty$",
+    "^iter$",
+    "^collect$",
+    "^map$",
+    "^filter$",
+    "^and_then$",
+    "^default$",
+];
+
+impl CallClassifier {
+    /// Charter's own category rules, with no project-specific overrides layered in.
+    pub fn builtin() -> Self {
+        Self::from_config(ClassifierConfig::default())
+    }
+
+    /// Reads `path` as TOML and layers it over [`CallClassifier::builtin`]'s defaults; falls back
+    /// to the builtin classifier entirely if `path` doesn't exist.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::builtin());
+        }
+
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let config: ClassifierConfig =
+            toml::from_str(&text).with_context(|| format!("Failed to parse {}", path.display()))?;
+
+        Ok(Self::from_config(config))
+    }
+
+    fn from_config(config: ClassifierConfig) -> Self {
+        let mut rules = compile_rules(
+            CallCategory::ErrorHandling,
+            &[
+                "^unwrap$",
+                "^expect$",
+                "^unwrap_or.*$",
+                "^ok_or.*$",
+                "^bail$",
+                "^anyhow$",
+            ],
+        );
+        rules.extend(compile_rules(
+            CallCategory::Io,
+            &[
+                "^read.*$",
+                "^write.*$",
+                "^open$",
+                "^connect$",
+                "^send$",
+                "^recv$",
+                "^flush$",
+            ],
+        ));
+        rules.extend(compile_rules(
+            CallCategory::Allocation,
+            &[
+                "^new$",
+                "^with_capacity$",
+                "^clone$",
+                "^to_vec$",
+                "^alloc.*$",
+            ],
+        ));
+        rules.extend(compile_rules(
+            CallCategory::Concurrency,
+            &["^spawn.*$", "^lock$", "^join$", "^await$"],
+        ));
+        rules.extend(compile_rules(
+            CallCategory::Logging,
+            &["^info$", "^warn$", "^error$", "^debug$", "^trace$"],
+        ));
+
+        rules.extend(compile_user_rules(CallCategory::Io, &config.io));
+        rules.extend(compile_user_rules(
+            CallCategory::Allocation,
+            &config.allocation,
+        ));
+        rules.extend(compile_user_rules(
+            CallCategory::ErrorHandling,
+            &config.error_handling,
+        ));
+        rules.extend(compile_user_rules(
+            CallCategory::Concurrency,
+            &config.concurrency,
+        ));
+        rules.extend(compile_user_rules(CallCategory::Logging, &config.logging));
+
+        let mut deny: Vec<Regex> = DEFAULT_DENY
+            .iter()
+            .filter_map(|pattern| Regex::new(pattern).ok())
+            .collect();
+        deny.extend(
+            config
+                .deny
+                .iter()
+                .filter_map(|pattern| Regex::new(pattern).ok()),
+        );
+
+        Self {
+            allow: config.allow,
+            deny,
+            rules,
+        }
+    }
+
+    fn category_for(&self, name: &str) -> Option<CallCategory> {
+        self.rules
+            .iter()
+            .find(|rule| rule.pattern.is_match(name))
+            .map(|rule| rule.category)
+    }
+
+    /// Classifies `name` (already reduced to its bare identifier), returning `None` if it should
+    /// be dropped from `key_calls` entirely. Category rules always win over the denylist, so a
+    /// project can't accidentally deny its way out of seeing `unwrap`/`expect` sites.
+    pub fn classify(&self, name: &str) -> Option<CallCategory> {
+        if let Some(category) = self.category_for(name) {
+            return Some(category);
+        }
+
+        if self.allow.iter().any(|allowed| allowed == name) {
+            return Some(CallCategory::Other);
+        }
+
+        if self.deny.iter().any(|pattern| pattern.is_match(name)) {
+            return None;
+        }
+
+        Some(CallCategory::Other)
+    }
+}
@@ -0,0 +1,163 @@
+/// Grammar-specific node-kind names that `collect_body_summary_info` needs to summarize a
+/// function body, plus a per-language trivial-call list, pulled out from being
+/// hardcoded to Rust's tree-sitter grammar so the summarization logic in
+/// [`crate::pipeline::parse`] generalizes to any tree-sitter-supported language's parse tree,
+/// not just the one the crate's parser is currently configured for.
+pub trait Language {
+    /// Node kinds counted as a statement for `BodySummary::statement_count`.
+    fn statement_kinds(&self) -> &[&str];
+    /// The node kind an early return is recognized by.
+    fn return_kind(&self) -> &str;
+    /// The node kind a function/method call is recognized by.
+    fn call_kind(&self) -> &str;
+    /// Whether `name` (the call's target identifier, already stripped to its final segment by
+    /// the caller) is on this language's trivial-call list. `collect_body_summary_info` does not
+    /// call it; its `key_calls` filtering goes through `CallClassifier`.
+    fn is_trivial_call(&self, name: &str) -> bool;
+}
+
+fn trivial_by_suffix(name: &str, denylist: &[&str]) -> bool {
+    let base = name.split("::").next_back().unwrap_or(name);
+    let base = base.split('.').next_back().unwrap_or(base);
+    denylist.contains(&base)
+}
+
+/// The grammar charter's active parser (`tree_sitter_rust`) actually produces.
+pub struct RustLanguage;
+
+impl Language for RustLanguage {
+    fn statement_kinds(&self) -> &[&str] {
+        &["expression_statement", "let_declaration"]
+    }
+
+    fn return_kind(&self) -> &str {
+        "return_expression"
+    }
+
+    fn call_kind(&self) -> &str {
+        "call_expression"
+    }
+
+    fn is_trivial_call(&self, name: &str) -> bool {
+        const TRIVIAL: &[&str] = &[
+            "unwrap",
+            "expect",
+            "clone",
+            "to_string",
+            "to_owned",
+            "into",
+            "from",
+            "as_ref",
+            "as_mut",
+            "ok",
+            "err",
+            "some",
+            "none",
+            "push",
+            "pop",
+            "insert",
+            "remove",
+            "get",
+            "len",
+            "is_empty",
+            "iter",
+            "collect",
+            "map",
+            "filter",
+            "and_then",
+            "or_else",
+            "ok_or",
+            "ok_or_else",
+            "unwrap_or",
+            "unwrap_or_else",
+            "unwrap_or_default",
+            "default",
+            "new",
+        ];
+
+        trivial_by_suffix(name, TRIVIAL)
+    }
+}
+
+/// Node-kind mapping for `tree_sitter_python`'s grammar. Not wired into any active parsing
+/// pipeline — charter's Python extraction in [`crate::pipeline::parse::python`] predates this
+/// abstraction and isn't part of the compiled extraction path — but demonstrates that
+/// [`Language`] generalizes past Rust.
+#[allow(dead_code)]
+pub struct PythonLanguage;
+
+impl Language for PythonLanguage {
+    fn statement_kinds(&self) -> &[&str] {
+        &["expression_statement", "assignment"]
+    }
+
+    fn return_kind(&self) -> &str {
+        "return_statement"
+    }
+
+    fn call_kind(&self) -> &str {
+        "call"
+    }
+
+    fn is_trivial_call(&self, name: &str) -> bool {
+        const TRIVIAL: &[&str] = &[
+            "append", "get", "len", "str", "int", "format", "join", "items", "keys", "values",
+        ];
+
+        trivial_by_suffix(name, TRIVIAL)
+    }
+}
+
+/// Node-kind mapping for `tree_sitter_javascript`'s grammar.
+#[allow(dead_code)]
+pub struct JavaScriptLanguage;
+
+impl Language for JavaScriptLanguage {
+    fn statement_kinds(&self) -> &[&str] {
+        &[
+            "expression_statement",
+            "lexical_declaration",
+            "variable_declaration",
+        ]
+    }
+
+    fn return_kind(&self) -> &str {
+        "return_statement"
+    }
+
+    fn call_kind(&self) -> &str {
+        "call_expression"
+    }
+
+    fn is_trivial_call(&self, name: &str) -> bool {
+        const TRIVIAL: &[&str] = &[
+            "push", "map", "filter", "then", "toString", "join", "slice", "concat",
+        ];
+
+        trivial_by_suffix(name, TRIVIAL)
+    }
+}
+
+/// Node-kind mapping for `tree_sitter_go`'s grammar.
+#[allow(dead_code)]
+pub struct GoLanguage;
+
+impl Language for GoLanguage {
+    fn statement_kinds(&self) -> &[&str] {
+        &["expression_statement", "short_var_declaration"]
+    }
+
+    fn return_kind(&self) -> &str {
+        "return_statement"
+    }
+
+    fn call_kind(&self) -> &str {
+        "call_expression"
+    }
+
+    fn is_trivial_call(&self, name: &str) -> bool {
+        const TRIVIAL: &[&str] = &["Sprintf", "Error", "String", "len", "append"];
+
+        trivial_by_suffix(name, TRIVIAL)
+    }
+}
@@ -1,17 +1,33 @@
-use anyhow::{Result, anyhow};
+use anyhow::{anyhow, Result};
 use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use tree_sitter::{Node, Parser, Tree};
 
-use crate::extract::attributes::{CfgInfo, DeriveInfo};
-use crate::extract::calls::{CallEdge, CallInfo};
+use super::classify::CallClassifier;
+use super::language::{Language, RustLanguage};
+use crate::extract::attributes::{CfgInfo, DeriveInfo, TraitImpl};
+use crate::extract::calls::{CallEdge, CallInfo, MacroCall};
+use crate::extract::cfg::{CfgPredicate, CfgSet};
 use crate::extract::complexity::{ComplexityMetrics, FunctionComplexity};
 use crate::extract::errors::{
-    ErrorInfo, ErrorOrigin, ErrorOriginKind, ErrorReturnType, PropagationPoint,
+    ContextAnnotation, ErrorInfo, ErrorOrigin, ErrorOriginKind, ErrorReturnType, ErrorSink,
+    ErrorSinkKind, PropagationPoint,
 };
 use crate::extract::imports::{ImportInfo, ReExport};
+use crate::extract::lints::{LintFinding, LintKind};
+use crate::extract::migrations::{match_migration, MigrationCatalog, MigrationFinding};
+use crate::extract::safety::{
+    DocInfo, DocLink, DocTest, GuardHeldAcrossAwait, ItemDoc, PythonLint, SafetyInfo,
+    TestFunction, TestInfo, TestModule, TestedItem, UnsafeBlock, UnsafeOperation,
+};
+use crate::extract::scope::{Binding, NameReference};
 use crate::extract::symbols::{
-    AssociatedType, BodySummary, EnumVariant, FileSymbols, FunctionBody, ImplMethod, InherentImpl,
-    MacroInfo, StructField, Symbol, SymbolKind, TraitMethod, VariantPayload, Visibility,
+    AssociatedType, BodySummary, EnumVariant, FileSymbols, FunctionBody, FunctionSignature,
+    GenericConstParam, GenericParams, GenericTypeParam, ImplAssocConst, ImplAssocType, ImplMethod,
+    InherentImpl, MacroInfo, MacroMetavariable, MacroRepetition, MacroRule, Param, PythonTypeVar,
+    Receiver, RefactorCandidate, SourcePosition, StructField, Symbol, SymbolKind, SymbolTree,
+    TraitImplAssocItem, TraitImplAssocKind, TraitMethod, VariantPayload, Visibility,
+    WherePredicate,
 };
 
 thread_local! {
@@ -28,6 +44,9 @@ pub struct ParsedFile {
     pub symbols: FileSymbols,
     pub module_doc: Option<String>,
     pub derives: Vec<DeriveInfo>,
+    /// Every "type implements trait" fact this file contributes, derived ones alongside manual
+    /// `impl Trait for Type` blocks — see [`crate::traitindex`] for the crate-wide merge.
+    pub impls: Vec<TraitImpl>,
     pub cfgs: Vec<CfgInfo>,
     pub imports: Vec<ImportInfo>,
     pub re_exports: Vec<ReExport>,
@@ -36,8 +55,51 @@ pub struct ParsedFile {
     pub identifier_locations: Vec<(String, usize)>,
     pub complexity: Vec<FunctionComplexity>,
     pub call_graph: Vec<CallInfo>,
+    pub macro_calls: Vec<MacroCall>,
     pub error_info: Vec<ErrorInfo>,
     pub captured_bodies: Vec<CapturedBody>,
+    pub lints: Vec<LintFinding>,
+    pub migrations: Vec<MigrationFinding>,
+    pub safety: SafetyInfo,
+    pub guard_await_conflicts: Vec<GuardHeldAcrossAwait>,
+    pub doc_info: DocInfo,
+    pub test_info: TestInfo,
+    /// Module-level `TypeVar`/`ParamSpec`/`TypeVarTuple`/`NewType` declarations, keyed by the
+    /// name they were bound to. Populated only by Python extraction; every other language leaves
+    /// this empty. See [`crate::pipeline::parse::python::extract_typevars`].
+    pub python_typevars: Vec<PythonTypeVar>,
+    /// Mechanical Python idiom/anti-pattern findings from a structural (node-kind/field-name)
+    /// scan, e.g. `range(len(x))` iteration or `== None`. Populated only by Python extraction;
+    /// every other language leaves this empty. See
+    /// [`crate::pipeline::parse::python::collect_python_lints`].
+    pub python_lints: Vec<PythonLint>,
+    /// Every name binding (parameter, assignment target, loop/`with`/`except` target,
+    /// comprehension target, or import) a scope-tree walk of this file collected, alongside
+    /// `python_name_references`'s def-use resolution of every load against them. Populated only
+    /// by Python extraction; every other language leaves this empty. See
+    /// [`crate::pipeline::parse::python::resolve_scopes`].
+    pub python_bindings: Vec<Binding>,
+    /// Every identifier load this file contains, resolved against `python_bindings`. Populated
+    /// only by Python extraction; every other language leaves this empty. See
+    /// [`crate::pipeline::parse::python::resolve_scopes`].
+    pub python_name_references: Vec<NameReference>,
+    /// Hierarchical document-symbol outline of the file (class → methods → nested functions),
+    /// the nested counterpart to the flat `symbols`/`test_functions` lists above. Populated only
+    /// by Python extraction; every other language leaves this as the empty default `Module` node.
+    /// See [`crate::pipeline::parse::python::build_symbol_tree`].
+    pub symbol_tree: SymbolTree,
+    /// `ERROR`/`MISSING` nodes tree-sitter's error recovery left in the tree, plus a timeout
+    /// entry if the parser gave up, so a malformed or in-progress file still yields whatever
+    /// symbols the recovered tree could produce instead of nothing at all.
+    pub syntax_diagnostics: Vec<SyntaxDiagnostic>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SyntaxDiagnostic {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+    pub byte_range: (usize, usize),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -52,867 +114,3229 @@ pub struct CapturedBody {
 pub fn parse_rust_file(content: &str, file_path: &str) -> Result<ParsedFile> {
     PARSER.with(|parser| {
         let mut parser = parser.borrow_mut();
-        let tree = parser
-            .parse(content, None)
-            .ok_or_else(|| anyhow!("Failed to parse file"))?;
+        let Some(tree) = parser.parse(content, None) else {
+            return Ok(timed_out_parsed_file(content));
+        };
+
+        let mut parsed = extract_from_tree(&tree, content, file_path)?;
+        collect_syntax_diagnostics(&tree.root_node(), &mut parsed.syntax_diagnostics);
 
-        extract_from_tree(&tree, content, file_path)
+        Ok(parsed)
     })
 }
 
-fn extract_from_tree(tree: &Tree, source: &str, file_path: &str) -> Result<ParsedFile> {
-    let root = tree.root_node();
-    let source_bytes = source.as_bytes();
+/// Builds the `ParsedFile` [`parse_rust_file`] returns when tree-sitter's parse timeout elapses
+/// before it produces a tree at all, so a pathological file is reported as an empty result with a
+/// diagnostic rather than a hard `Err`.
+fn timed_out_parsed_file(content: &str) -> ParsedFile {
+    let mut parsed = ParsedFile::default();
+    parsed.syntax_diagnostics.push(SyntaxDiagnostic {
+        message: "parser timed out before producing a tree".to_string(),
+        line: 1,
+        column: 1,
+        byte_range: (0, content.len()),
+    });
+    parsed
+}
 
-    let mut result = ParsedFile::default();
+/// Depth-first walk collecting every `ERROR`/`MISSING` node tree-sitter's error recovery left in
+/// the tree into `diagnostics`, so a file with one broken item still reports every damaged span
+/// instead of just the first. Recurses into error nodes too, since a recovered subtree can itself
+/// contain further nested errors.
+fn collect_syntax_diagnostics(node: &Node, diagnostics: &mut Vec<SyntaxDiagnostic>) {
+    if node.is_missing() {
+        diagnostics.push(SyntaxDiagnostic {
+            message: format!("missing {}", node.kind()),
+            line: node.start_position().row + 1,
+            column: node.start_position().column + 1,
+            byte_range: (node.start_byte(), node.end_byte()),
+        });
+    } else if node.is_error() {
+        diagnostics.push(SyntaxDiagnostic {
+            message: "unexpected syntax".to_string(),
+            line: node.start_position().row + 1,
+            column: node.start_position().column + 1,
+            byte_range: (node.start_byte(), node.end_byte()),
+        });
+    }
 
-    extract_module_doc(&root, source_bytes, &mut result);
-    extract_items(&root, source_bytes, &mut result);
-    extract_identifier_locations(&root, source_bytes, &mut result);
-    extract_phase1_data(&root, source_bytes, file_path, &mut result);
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_syntax_diagnostics(&child, diagnostics);
+    }
+}
+
+/// Like [`parse_rust_file`], but additionally evaluates each item's `#[cfg(...)]` /
+/// `#[cfg_attr(...)]` predicate against `active` and drops any `symbols`/`complexity`/
+/// `call_graph`/`error_info` entry whose predicate doesn't hold, so a consumer filtering by
+/// `active` sees only the code that would actually compile under it rather than double-counting
+/// mutually exclusive cfg branches. Parent-module cfgs are combined (via an implicit `all(..)`)
+/// with an item's own cfg before evaluation, so `mod foo { #[cfg(unix)] ... }` under a
+/// `#[cfg(test)]` module requires both to be active.
+pub fn parse_rust_file_with_cfg(
+    content: &str,
+    file_path: &str,
+    active: &CfgSet,
+) -> Result<ParsedFile> {
+    let mut result = parse_rust_file(content, file_path)?;
+
+    let inactive_lines = PARSER.with(|parser| -> Result<HashSet<usize>> {
+        let mut parser = parser.borrow_mut();
+        let tree = parser
+            .parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse file"))?;
+
+        let mut inactive_lines = HashSet::new();
+        collect_inactive_lines(
+            &tree.root_node(),
+            content.as_bytes(),
+            active,
+            &[],
+            &mut inactive_lines,
+        );
+        Ok(inactive_lines)
+    })?;
+
+    result
+        .symbols
+        .symbols
+        .retain(|symbol| !inactive_lines.contains(&symbol.line));
+    result
+        .complexity
+        .retain(|func| !inactive_lines.contains(&func.line));
+    result
+        .call_graph
+        .retain(|call_info| !inactive_lines.contains(&call_info.line));
+    result
+        .error_info
+        .retain(|error| !inactive_lines.contains(&error.line));
 
     Ok(result)
 }
 
-fn extract_module_doc(root: &Node, source: &[u8], result: &mut ParsedFile) {
-    let mut cursor = root.walk();
+/// Walks `node`'s children, associating each with the cfg predicates of its immediately
+/// preceding `attribute_item` siblings (the same sibling-scan pattern [`has_test_attribute`]
+/// uses) plus whatever predicates were already active on its enclosing item (`parent_cfgs`),
+/// and records the start line of every node under an inactive predicate.
+fn collect_inactive_lines(
+    node: &Node,
+    source: &[u8],
+    active: &CfgSet,
+    parent_cfgs: &[CfgPredicate],
+    inactive_lines: &mut HashSet<usize>,
+) {
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.children(&mut cursor).collect();
 
-    for child in root.children(&mut cursor) {
-        if child.kind() == "line_comment" {
-            let text = node_text(&child, source);
-            if text.starts_with("//!") {
-                let doc = text.strip_prefix("//!").unwrap_or("").trim();
-                if result.module_doc.is_none() {
-                    result.module_doc = Some(doc.to_string());
-                } else if let Some(existing) = &mut result.module_doc {
-                    existing.push(' ');
-                    existing.push_str(doc);
-                }
-            }
-        } else if child.kind() == "block_comment" {
-            let text = node_text(&child, source);
-            if text.starts_with("/*!") {
-                let doc = text
-                    .strip_prefix("/*!")
-                    .and_then(|s| s.strip_suffix("*/"))
-                    .unwrap_or("")
-                    .trim();
-                result.module_doc = Some(doc.to_string());
+    for (index, child) in children.iter().enumerate() {
+        if child.kind() == "attribute_item" {
+            continue;
+        }
+
+        let mut own_cfgs = parent_cfgs.to_vec();
+        let mut preceding = index;
+        while preceding > 0 && children[preceding - 1].kind() == "attribute_item" {
+            let text = node_text(&children[preceding - 1], source);
+            if let Some(predicate) = extract_cfg_predicate(&text) {
+                own_cfgs.push(predicate);
             }
-        } else if child.kind() != "line_comment" && child.kind() != "block_comment" {
-            break;
+            preceding -= 1;
+        }
+
+        if !own_cfgs.iter().all(|predicate| predicate.evaluate(active)) {
+            mark_inactive_lines(child, inactive_lines);
+            continue;
         }
+
+        collect_inactive_lines(child, source, active, &own_cfgs, inactive_lines);
     }
 }
 
-fn extract_items(node: &Node, source: &[u8], result: &mut ParsedFile) {
+fn mark_inactive_lines(node: &Node, inactive_lines: &mut HashSet<usize>) {
+    inactive_lines.insert(node.start_position().row + 1);
     let mut cursor = node.walk();
-
     for child in node.children(&mut cursor) {
-        match child.kind() {
-            "struct_item" => extract_struct(&child, source, result),
-            "enum_item" => extract_enum(&child, source, result),
-            "trait_item" => extract_trait(&child, source, result),
-            "impl_item" => extract_impl(&child, source, result),
-            "function_item" => extract_function(&child, source, result),
-            "const_item" => extract_const(&child, source, result),
-            "static_item" => extract_static(&child, source, result),
-            "type_item" => extract_type_alias(&child, source, result),
-            "mod_item" => extract_mod(&child, source, result),
-            "use_declaration" => extract_use(&child, source, result),
-            "attribute_item" => extract_attribute(&child, source, result),
-            "macro_definition" => extract_macro(&child, source, result),
-            _ => {}
-        }
+        mark_inactive_lines(&child, inactive_lines);
     }
 }
 
-fn extract_struct(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    let visibility = extract_visibility(node, source);
-    let name = find_child_text(node, "type_identifier", source).unwrap_or_default();
-    let generics = extract_generics(node, source);
-    let line = node.start_position().row + 1;
-
-    let mut fields = Vec::new();
-
-    if let Some(body) = node.child_by_field_name("body") {
-        let mut cursor = body.walk();
-        for child in body.children(&mut cursor) {
-            if child.kind() == "field_declaration" {
-                let field_vis = extract_visibility(&child, source);
-                let field_name =
-                    find_child_text(&child, "field_identifier", source).unwrap_or_default();
-                let field_type = child
-                    .child_by_field_name("type")
-                    .map(|n| node_text(&n, source))
-                    .unwrap_or_default();
+/// Like [`parse_rust_file`], but additionally walks the tree looking for mechanically
+/// detectable idioms/anti-patterns (see [`collect_idiom_lints`]) and populates `lints`.
+/// Opt-in and run as a second pass, the same way [`parse_rust_file_with_cfg`] layers cfg
+/// evaluation on top of the plain parse rather than burdening every caller with it.
+pub fn parse_rust_file_with_lints(content: &str, file_path: &str) -> Result<ParsedFile> {
+    let mut result = parse_rust_file(content, file_path)?;
 
-                fields.push(StructField {
-                    name: field_name,
-                    field_type,
-                    visibility: field_vis,
-                });
-            }
-        }
-    }
+    PARSER.with(|parser| -> Result<()> {
+        let mut parser = parser.borrow_mut();
+        let tree = parser
+            .parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse file"))?;
 
-    let derives = extract_derives_for_item(node, source);
-    for derive in &derives {
-        result.derives.push(DeriveInfo {
-            target: name.clone(),
-            traits: derive.clone(),
-            line,
-        });
-    }
+        collect_idiom_lints(&tree.root_node(), content.as_bytes(), &mut result.lints);
+        Ok(())
+    })?;
 
-    result.symbols.symbols.push(Symbol {
-        name,
-        kind: SymbolKind::Struct { fields },
-        visibility,
-        generics,
-        line,
-        is_async: false,
-        is_unsafe: false,
-        is_const: false,
-        re_exported_as: None,
-    });
+    Ok(result)
 }
 
-fn extract_enum(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    let visibility = extract_visibility(node, source);
-    let name = find_child_text(node, "type_identifier", source).unwrap_or_default();
-    let generics = extract_generics(node, source);
-    let line = node.start_position().row + 1;
+/// Like [`parse_rust_file`], but additionally walks every `identifier`/`scoped_identifier`/
+/// `field_identifier` node (which also covers `use` paths and method-call names, since those
+/// are parsed out of the same node kinds) against `catalog`, populating `migrations` with a
+/// [`MigrationFinding`] for each hit. Opt-in and run as a second pass, the same way
+/// [`parse_rust_file_with_lints`] layers its own tree walk on top of the plain parse.
+pub fn parse_rust_file_with_migrations(
+    content: &str,
+    file_path: &str,
+    catalog: &MigrationCatalog,
+) -> Result<ParsedFile> {
+    let mut result = parse_rust_file(content, file_path)?;
 
-    let mut variants = Vec::new();
+    PARSER.with(|parser| -> Result<()> {
+        let mut parser = parser.borrow_mut();
+        let tree = parser
+            .parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse file"))?;
 
-    if let Some(body) = node.child_by_field_name("body") {
-        let mut cursor = body.walk();
-        for child in body.children(&mut cursor) {
-            if child.kind() == "enum_variant" {
-                let variant_name =
-                    find_child_text(&child, "identifier", source).unwrap_or_default();
+        collect_migration_findings(
+            &tree.root_node(),
+            content.as_bytes(),
+            catalog,
+            &mut result.migrations,
+        );
+        Ok(())
+    })?;
 
-                let payload = if let Some(tuple_body) = child
-                    .children(&mut child.walk())
-                    .find(|n| n.kind() == "ordered_field_declaration_list")
-                {
-                    let mut fields = Vec::new();
-                    let mut tuple_cursor = tuple_body.walk();
-                    for field in tuple_body.children(&mut tuple_cursor) {
-                        if field.kind() == "ordered_field_declaration" {
-                            if let Some(type_node) = field.child_by_field_name("type") {
-                                fields.push(node_text(&type_node, source));
-                            }
-                        }
-                    }
-                    if fields.is_empty() {
-                        for field in tuple_body.children(&mut tuple_cursor) {
-                            if field.kind() == "type_identifier"
-                                || field.kind() == "generic_type"
-                                || field.kind() == "reference_type"
-                                || field.kind() == "primitive_type"
-                            {
-                                fields.push(node_text(&field, source));
-                            }
-                        }
-                    }
-                    if !fields.is_empty() {
-                        Some(VariantPayload::Tuple(fields))
-                    } else {
-                        None
-                    }
-                } else if let Some(struct_body) = child
-                    .children(&mut child.walk())
-                    .find(|n| n.kind() == "field_declaration_list")
-                {
-                    let mut fields = Vec::new();
-                    let mut struct_cursor = struct_body.walk();
-                    for field in struct_body.children(&mut struct_cursor) {
-                        if field.kind() == "field_declaration" {
-                            let field_name = find_child_text(&field, "field_identifier", source)
-                                .unwrap_or_default();
-                            let field_type = field
-                                .child_by_field_name("type")
-                                .map(|n| node_text(&n, source))
-                                .unwrap_or_default();
-                            fields.push((field_name, field_type));
-                        }
-                    }
-                    if !fields.is_empty() {
-                        Some(VariantPayload::Struct(fields))
-                    } else {
-                        None
-                    }
-                } else {
-                    None
-                };
+    Ok(result)
+}
 
-                variants.push(EnumVariant {
-                    name: variant_name,
-                    payload,
-                });
-            }
+fn collect_migration_findings(
+    node: &Node,
+    source: &[u8],
+    catalog: &MigrationCatalog,
+    findings: &mut Vec<MigrationFinding>,
+) {
+    if matches!(
+        node.kind(),
+        "identifier" | "scoped_identifier" | "field_identifier"
+    ) {
+        let text = node_text(node, source);
+        let line = node.start_position().row + 1;
+        if let Some(finding) = match_migration(catalog, &text, line) {
+            findings.push(finding);
         }
     }
 
-    let derives = extract_derives_for_item(node, source);
-    for derive in &derives {
-        result.derives.push(DeriveInfo {
-            target: name.clone(),
-            traits: derive.clone(),
-            line,
-        });
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_migration_findings(&child, source, catalog, findings);
     }
-
-    result.symbols.symbols.push(Symbol {
-        name,
-        kind: SymbolKind::Enum { variants },
-        visibility,
-        generics,
-        line,
-        is_async: false,
-        is_unsafe: false,
-        is_const: false,
-        re_exported_as: None,
-    });
 }
 
-fn extract_trait(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    let visibility = extract_visibility(node, source);
-    let name = find_child_text(node, "type_identifier", source).unwrap_or_default();
-    let generics = extract_generics(node, source);
-    let line = node.start_position().row + 1;
+/// Every function/method body's [`BodySummary`] in `content`, unconditional on importance
+/// score — unlike `captured_bodies` (see [`capture_function_body`]), which only keeps functions
+/// significant enough to matter for review output, this is for the "summarize every function in
+/// this file" use case [`crate::analyze::analyze_paths`] builds on.
+pub fn extract_all_body_summaries(content: &str) -> Result<Vec<BodySummary>> {
+    PARSER.with(|parser| {
+        let mut parser = parser.borrow_mut();
+        let tree = parser
+            .parse(content, None)
+            .ok_or_else(|| anyhow!("Failed to parse file"))?;
 
-    let mut supertraits = Vec::new();
-    let mut methods = Vec::new();
-    let mut associated_types = Vec::new();
+        let mut summaries = Vec::new();
+        collect_all_body_summaries(&tree.root_node(), content.as_bytes(), &mut summaries);
+        Ok(summaries)
+    })
+}
 
-    if let Some(bounds) = node.child_by_field_name("bounds") {
-        let bounds_text = node_text(&bounds, source);
-        for bound in bounds_text.split('+') {
-            let bound = bound.trim();
-            if !bound.is_empty() {
-                supertraits.push(bound.to_string());
-            }
+fn collect_all_body_summaries(node: &Node, source: &[u8], summaries: &mut Vec<BodySummary>) {
+    if node.kind() == "function_item" {
+        if let Some(body) = node.child_by_field_name("body") {
+            let params = parameter_names(node, source);
+            summaries.push(extract_body_summary(&body, source, &params));
         }
     }
 
-    if let Some(body) = node.child_by_field_name("body") {
-        let mut cursor = body.walk();
-        for child in body.children(&mut cursor) {
-            match child.kind() {
-                "function_signature_item" => {
-                    let method_name =
-                        find_child_text(&child, "identifier", source).unwrap_or_default();
-                    let signature = extract_function_signature(&child, source);
-                    methods.push(TraitMethod {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_all_body_summaries(&child, source, summaries);
+    }
+}
+
+/// Walks every node looking for the handful of node shapes [`LintKind`] covers: redundant
+/// closures, needless `.iter()` in a `for` loop, range-indexing that could be slicing sugar,
+/// impls of the pre-1.0 `fmt::Show`/`fmt::String` traits, `if let`/`else if let` chains that
+/// read better as a `match`, and a trailing `return expr;` that a block doesn't need.
+fn collect_idiom_lints(node: &Node, source: &[u8], lints: &mut Vec<LintFinding>) {
+    let mut scope = ScopeStack::default();
+    scope.push();
+    walk_idiom_lints(node, source, lints, &mut scope);
+}
+
+/// Same walk as the original `collect_idiom_lints`, extended to carry a [`ScopeStack`] (built
+/// the same way [`extract_calls_from_body`] builds one) so lints that need a receiver's inferred
+/// type — like [`check_map_index_could_use_get`] — have it available.
+fn walk_idiom_lints(node: &Node, source: &[u8], lints: &mut Vec<LintFinding>, scope: &mut ScopeStack) {
+    match node.kind() {
+        "closure_expression" => check_redundant_closure(node, source, lints),
+        "for_expression" => check_needless_iter_in_for_loop(node, source, lints),
+        "index_expression" => {
+            check_indexing_could_be_slicing(node, source, lints);
+            check_map_index_could_use_get(node, source, scope, lints);
+        }
+        "call_expression" => check_explicit_index_call_could_use_slicing(node, source, lints),
+        "impl_item" => check_deprecated_formatting_trait(node, source, lints),
+        "if_expression" => {
+            check_if_let_else_could_be_match(node, source, lints);
+            check_else_if_let_chain(node, source, lints);
+        }
+        "block" => {
+            check_redundant_trailing_return(node, source, lints);
+            scope.push();
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                walk_idiom_lints(&child, source, lints, scope);
+                if child.kind() == "let_declaration" {
+                    bind_let_declaration(&child, source, scope);
+                }
+            }
+            scope.pop();
+            return;
+        }
+        "function_item" => {
+            scope.push();
+            if let Some(parameters) = node.child_by_field_name("parameters") {
+                bind_parameters(&parameters, source, scope);
+            }
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                walk_idiom_lints(&child, source, lints, scope);
+            }
+            scope.pop();
+            return;
+        }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        walk_idiom_lints(&child, source, lints, scope);
+    }
+}
+
+/// `|x, y| f(x, y)` is just `f` — flags a closure whose body is a single call forwarding
+/// exactly its own parameters, in order, to a function.
+fn check_redundant_closure(node: &Node, source: &[u8], lints: &mut Vec<LintFinding>) {
+    let Some(parameters) = node.child_by_field_name("parameters") else {
+        return;
+    };
+    let Some(body) = node.child_by_field_name("body") else {
+        return;
+    };
+    if body.kind() != "call_expression" {
+        return;
+    }
+    let Some(function) = body.child_by_field_name("function") else {
+        return;
+    };
+    let Some(arguments) = body.child_by_field_name("arguments") else {
+        return;
+    };
+
+    let param_names: Vec<String> = parameters
+        .children(&mut parameters.walk())
+        .filter(|c| c.kind() == "identifier")
+        .map(|c| node_text(&c, source))
+        .collect();
+
+    let arg_names: Vec<String> = arguments
+        .children(&mut arguments.walk())
+        .filter(|c| c.kind() == "identifier")
+        .map(|c| node_text(&c, source))
+        .collect();
+
+    if param_names.is_empty() || param_names != arg_names {
+        return;
+    }
+
+    let snippet = node_text(node, source);
+    let suggestion = node_text(&function, source);
+    lints.push(LintFinding {
+        kind: LintKind::RedundantClosure,
+        line: node.start_position().row + 1,
+        message: format!("closure just forwards its arguments to `{suggestion}`"),
+        snippet,
+        suggestion,
+    });
+}
+
+/// `for x in recv.iter()` (or `.iter_mut()`/`.into_iter()`) can drop the call entirely and
+/// iterate the receiver directly via `&recv`/`&mut recv`/`recv`.
+fn check_needless_iter_in_for_loop(node: &Node, source: &[u8], lints: &mut Vec<LintFinding>) {
+    let Some(value) = node.child_by_field_name("value") else {
+        return;
+    };
+    if value.kind() != "call_expression" {
+        return;
+    }
+    let Some(function) = value.child_by_field_name("function") else {
+        return;
+    };
+    if function.kind() != "field_expression" {
+        return;
+    }
+    let Some(receiver) = function.child_by_field_name("value") else {
+        return;
+    };
+    let Some(method) = function.child_by_field_name("field") else {
+        return;
+    };
+    let method_name = node_text(&method, source);
+    let receiver_text = node_text(&receiver, source);
+
+    let suggestion = match method_name.as_str() {
+        "iter" => format!("&{receiver_text}"),
+        "iter_mut" => format!("&mut {receiver_text}"),
+        "into_iter" => receiver_text.clone(),
+        _ => return,
+    };
+
+    lints.push(LintFinding {
+        kind: LintKind::NeedlessIterInForLoop,
+        line: node.start_position().row + 1,
+        message: format!("`.{method_name}()` is redundant in a `for` loop over `{receiver_text}`"),
+        snippet: node_text(&value, source),
+        suggestion,
+    });
+}
+
+/// `v[a..b]` used as a standalone indexing expression is already slicing sugar for
+/// `*v.index(a..b)` — flagging it just makes the intent (`&v[a..b]`) explicit in cases where
+/// the surrounding code doesn't already take a reference.
+fn check_indexing_could_be_slicing(node: &Node, source: &[u8], lints: &mut Vec<LintFinding>) {
+    let Some(value) = node.child_by_field_name("value") else {
+        return;
+    };
+    let Some(index) = node.child_by_field_name("index") else {
+        return;
+    };
+    if index.kind() != "range_expression" {
+        return;
+    }
+
+    let value_text = node_text(&value, source);
+    let index_text = node_text(&index, source);
+    lints.push(LintFinding {
+        kind: LintKind::IndexingCouldBeSlicing,
+        line: node.start_position().row + 1,
+        message: "range index expression — consider borrowing the slice explicitly".to_string(),
+        snippet: node_text(node, source),
+        suggestion: format!("&{value_text}[{index_text}]"),
+    });
+}
+
+/// `map[key]` panics if `key` is missing; `map.get(key)` returns `Option` instead. Only flagged
+/// when `value`'s inferred type (via [`infer_receiver_type`]) names `HashMap`/`BTreeMap`, since
+/// plain slice/`Vec` indexing has no matching non-panicking `.get`-shaped replacement here.
+fn check_map_index_could_use_get(
+    node: &Node,
+    source: &[u8],
+    scope: &ScopeStack,
+    lints: &mut Vec<LintFinding>,
+) {
+    let Some(value) = node.child_by_field_name("value") else {
+        return;
+    };
+    let Some(index) = node.child_by_field_name("index") else {
+        return;
+    };
+    if index.kind() == "range_expression" {
+        return;
+    }
+
+    let receiver_type = infer_receiver_type(&value, source, scope);
+    if !receiver_type.contains("HashMap") && !receiver_type.contains("BTreeMap") {
+        return;
+    }
+
+    let value_text = node_text(&value, source);
+    let index_text = node_text(&index, source);
+    lints.push(LintFinding {
+        kind: LintKind::MapIndexCouldUseGet,
+        line: node.start_position().row + 1,
+        message: format!("indexing `{value_text}` panics if the key is missing"),
+        snippet: node_text(node, source),
+        suggestion: format!("{value_text}.get({index_text})"),
+    });
+}
+
+/// `s.index(a..b)` / `s.slice_from(a)` are explicit calls to what `&s[a..b]` / `&s[a..]` already
+/// express as slicing sugar — `slice_from` in particular is the pre-1.0 `&[T]` API, long replaced
+/// by range-indexing syntax, the same vintage of cleanup [`check_deprecated_formatting_trait`]
+/// flags for `fmt::Show`/`fmt::String`.
+fn check_explicit_index_call_could_use_slicing(node: &Node, source: &[u8], lints: &mut Vec<LintFinding>) {
+    let Some(function) = node.child_by_field_name("function") else {
+        return;
+    };
+    if function.kind() != "field_expression" {
+        return;
+    }
+    let Some(receiver) = function.child_by_field_name("value") else {
+        return;
+    };
+    let Some(method) = function.child_by_field_name("field") else {
+        return;
+    };
+    let method_name = node_text(&method, source);
+    if method_name != "index" && method_name != "slice_from" {
+        return;
+    }
+
+    let Some(arguments) = node.child_by_field_name("arguments") else {
+        return;
+    };
+    let mut cursor = arguments.walk();
+    let mut args = arguments
+        .children(&mut cursor)
+        .filter(|child| child.kind() != "(" && child.kind() != ")" && child.kind() != ",");
+    let Some(first_arg) = args.next() else {
+        return;
+    };
+    if args.next().is_some() {
+        return;
+    }
+
+    let mut range_text = node_text(&first_arg, source);
+    if let Some(stripped) = range_text.strip_prefix('&') {
+        range_text = stripped.to_string();
+    }
+    if range_text.starts_with('(') && range_text.ends_with(')') {
+        range_text = range_text[1..range_text.len() - 1].to_string();
+    }
+
+    let receiver_text = node_text(&receiver, source);
+    let suggestion = if method_name == "slice_from" {
+        format!("&{receiver_text}[{range_text}..]")
+    } else {
+        format!("&{receiver_text}[{range_text}]")
+    };
+
+    lints.push(LintFinding {
+        kind: LintKind::ExplicitIndexCallCouldUseSlicing,
+        line: node.start_position().row + 1,
+        message: format!("`.{method_name}(..)` call can be written as slicing sugar"),
+        snippet: node_text(node, source),
+        suggestion,
+    });
+}
+
+/// `fmt::Show` and `fmt::String` were the pre-1.0 names for `fmt::Debug`/`fmt::Display`, long
+/// removed from `std` — any impl naming them can't compile and is leftover from an old edition
+/// of the code (or a migration guide comment) that should be updated.
+fn check_deprecated_formatting_trait(node: &Node, source: &[u8], lints: &mut Vec<LintFinding>) {
+    let Some(trait_node) = node.child_by_field_name("trait") else {
+        return;
+    };
+    let trait_text = node_text(&trait_node, source);
+    let trait_name = trait_text.rsplit("::").next().unwrap_or(&trait_text);
+
+    let replacement = match trait_name {
+        "Show" => "Debug",
+        "String" => "Display",
+        _ => return,
+    };
+
+    lints.push(LintFinding {
+        kind: LintKind::DeprecatedFormattingTrait,
+        line: node.start_position().row + 1,
+        message: format!("`fmt::{trait_name}` was renamed to `fmt::{replacement}` before Rust 1.0"),
+        snippet: trait_text.clone(),
+        suggestion: trait_text.replace(trait_name, replacement),
+    });
+}
+
+/// `if let pat = expr { .. } else { .. }` with a plain `else` block (not a chained `else if`,
+/// which [`check_else_if_let_chain`] owns) is a two-arm `match` in disguise.
+fn check_if_let_else_could_be_match(node: &Node, source: &[u8], lints: &mut Vec<LintFinding>) {
+    let Some(condition) = node.child_by_field_name("condition") else {
+        return;
+    };
+    if condition.kind() != "let_condition" {
+        return;
+    }
+    let Some(alternative) = node.child_by_field_name("alternative") else {
+        return;
+    };
+    if alternative.kind() != "block" {
+        return;
+    }
+
+    lints.push(LintFinding {
+        kind: LintKind::IfLetElseCouldBeMatch,
+        line: node.start_position().row + 1,
+        message: "`if let` with a plain `else` reads as a two-arm `match`".to_string(),
+        snippet: truncate_string(&node_text(&condition, source), 50),
+        suggestion: "match".to_string(),
+    });
+}
+
+/// A chain of `else if let` arms (at least one `let_condition` beyond the head) is a `match`
+/// with its scrutinee spread across every arm. Only fires from the head of the chain — a link
+/// reached via recursion into a parent's `alternative` returns immediately so the chain isn't
+/// reported once per link.
+fn check_else_if_let_chain(node: &Node, source: &[u8], lints: &mut Vec<LintFinding>) {
+    if is_chained_else_if(node) {
+        return;
+    }
+
+    let has_else_if = node
+        .child_by_field_name("alternative")
+        .is_some_and(|alt| alt.kind() == "if_expression");
+    if !has_else_if {
+        return;
+    }
+
+    let mut has_let_arm = false;
+    let mut current = node.child_by_field_name("alternative");
+    while let Some(arm) = current {
+        if arm.kind() != "if_expression" {
+            break;
+        }
+        if arm
+            .child_by_field_name("condition")
+            .is_some_and(|c| c.kind() == "let_condition")
+        {
+            has_let_arm = true;
+        }
+        current = arm
+            .child_by_field_name("alternative")
+            .filter(|alt| alt.kind() == "if_expression");
+    }
+
+    if has_let_arm {
+        lints.push(LintFinding {
+            kind: LintKind::ElseIfLetChainCouldBeMatch,
+            line: node.start_position().row + 1,
+            message: "chain of `else if let` arms reads as a single `match`".to_string(),
+            snippet: truncate_string(&node_text(node, source), 50),
+            suggestion: "match".to_string(),
+        });
+    }
+}
+
+/// True if `node` is itself the `alternative` of a parent `if_expression` — i.e. it's an
+/// `else if` link rather than the head of its chain.
+fn is_chained_else_if(node: &Node) -> bool {
+    node.parent().is_some_and(|parent| {
+        parent.kind() == "if_expression"
+            && parent
+                .child_by_field_name("alternative")
+                .is_some_and(|alt| alt.id() == node.id())
+    })
+}
+
+/// A `return expr;` as a block's last statement can just be `expr` — the early-exit keyword
+/// only earns its keep partway through a block, not at the end of one.
+fn check_redundant_trailing_return(node: &Node, source: &[u8], lints: &mut Vec<LintFinding>) {
+    let count = node.named_child_count();
+    if count == 0 {
+        return;
+    }
+    let Some(last) = node.named_child(count - 1) else {
+        return;
+    };
+
+    let return_expr = if last.kind() == "return_expression" {
+        Some(last)
+    } else if last.kind() == "expression_statement" {
+        last.named_child(0)
+            .filter(|child| child.kind() == "return_expression")
+    } else {
+        None
+    };
+
+    let Some(return_expr) = return_expr else {
+        return;
+    };
+    let Some(value) = return_expr.named_child(0) else {
+        return;
+    };
+
+    lints.push(LintFinding {
+        kind: LintKind::RedundantTrailingReturn,
+        line: return_expr.start_position().row + 1,
+        message: "trailing `return` as a block's last statement can drop the keyword".to_string(),
+        snippet: truncate_string(&node_text(&return_expr, source), 50),
+        suggestion: truncate_string(&node_text(&value, source), 50),
+    });
+}
+
+fn truncate_string(s: &str, max_chars: usize) -> String {
+    if s.chars().count() <= max_chars {
+        s.to_string()
+    } else {
+        let truncated: String = s.chars().take(max_chars.saturating_sub(3)).collect();
+        format!("{truncated}...")
+    }
+}
+
+/// Parses the condition out of a `#[cfg(...)]` or `#[cfg_attr(...)]` attribute's source text
+/// into a [`CfgPredicate`]. For `cfg_attr`, only the leading condition argument is relevant —
+/// the attributes it conditionally applies don't affect whether the item itself is active.
+fn extract_cfg_predicate(attr_text: &str) -> Option<CfgPredicate> {
+    if attr_text.contains("#[cfg(") {
+        return parse_cfg_predicate(&extract_cfg_content(attr_text)?);
+    }
+    if attr_text.contains("#[cfg_attr(") {
+        let inner = extract_cfg_attr_content(attr_text)?;
+        let condition = split_top_level_commas(&inner).into_iter().next()?;
+        return parse_cfg_predicate(condition);
+    }
+    None
+}
+
+fn extract_cfg_attr_content(attr_text: &str) -> Option<String> {
+    let start = attr_text.find("#[cfg_attr(")? + 11;
+    let mut depth = 1;
+    let mut end = start;
+    for (index, char) in attr_text[start..].char_indices() {
+        match char {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    end = start + index;
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    Some(attr_text[start..end].to_string())
+}
+
+/// Recursive-descent parser for a cfg predicate's condition text: `all(..)`/`any(..)`/`not(..)`
+/// combinators over `key = "value"` and bare-flag leaves.
+fn parse_cfg_predicate(condition: &str) -> Option<CfgPredicate> {
+    let condition = condition.trim();
+
+    if let Some(inner) = condition
+        .strip_prefix("all(")
+        .and_then(|s| s.strip_suffix(')'))
+    {
+        return Some(CfgPredicate::All(parse_cfg_predicate_args(inner)?));
+    }
+    if let Some(inner) = condition
+        .strip_prefix("any(")
+        .and_then(|s| s.strip_suffix(')'))
+    {
+        return Some(CfgPredicate::Any(parse_cfg_predicate_args(inner)?));
+    }
+    if let Some(inner) = condition
+        .strip_prefix("not(")
+        .and_then(|s| s.strip_suffix(')'))
+    {
+        let predicate = parse_cfg_predicate(inner)?;
+        return Some(CfgPredicate::Not(Box::new(predicate)));
+    }
+
+    if let Some(eq_index) = condition.find('=') {
+        let key = condition[..eq_index].trim().to_string();
+        let value = condition[eq_index + 1..]
+            .trim()
+            .trim_matches('"')
+            .to_string();
+        return Some(CfgPredicate::KeyValue(key, value));
+    }
+
+    if condition.is_empty() {
+        return None;
+    }
+    Some(CfgPredicate::Flag(condition.to_string()))
+}
+
+fn parse_cfg_predicate_args(inner: &str) -> Option<Vec<CfgPredicate>> {
+    split_top_level_commas(inner)
+        .into_iter()
+        .map(parse_cfg_predicate)
+        .collect()
+}
+
+/// Splits `inner` on commas that aren't nested inside parentheses, so `all(a, b), c` yields
+/// `["all(a, b)", "c"]` rather than splitting inside the `all(..)` call.
+fn split_top_level_commas(inner: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (index, char) in inner.char_indices() {
+        match char {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(inner[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = inner[start..].trim();
+    if !last.is_empty() {
+        parts.push(last);
+    }
+    parts
+}
+
+fn extract_from_tree(tree: &Tree, source: &str, file_path: &str) -> Result<ParsedFile> {
+    let root = tree.root_node();
+    let source_bytes = source.as_bytes();
+
+    let mut result = ParsedFile::default();
+
+    extract_module_doc(&root, source_bytes, &mut result);
+    extract_items(&root, source_bytes, &mut result, "");
+    extract_identifier_locations(&root, source_bytes, &mut result);
+    extract_phase1_data(&root, source_bytes, file_path, &mut result);
+    extract_macro_calls(&root, source_bytes, None, None, &mut result);
+    extract_unsafe_blocks(&root, source_bytes, None, &mut result.safety);
+    extract_guard_await_conflicts(&root, source_bytes, &mut result.guard_await_conflicts);
+    extract_item_docs(&root, source_bytes, &mut result.doc_info.item_docs);
+    resolve_doc_links(&mut result.doc_info.item_docs);
+    extract_test_info(&root, source_bytes, &mut result);
+
+    Ok(result)
+}
+
+fn extract_module_doc(root: &Node, source: &[u8], result: &mut ParsedFile) {
+    let mut cursor = root.walk();
+
+    for child in root.children(&mut cursor) {
+        if child.kind() == "line_comment" {
+            let text = node_text(&child, source);
+            if text.starts_with("//!") {
+                let doc = text.strip_prefix("//!").unwrap_or("").trim();
+                if result.module_doc.is_none() {
+                    result.module_doc = Some(doc.to_string());
+                } else if let Some(existing) = &mut result.module_doc {
+                    existing.push(' ');
+                    existing.push_str(doc);
+                }
+            }
+        } else if child.kind() == "block_comment" {
+            let text = node_text(&child, source);
+            if text.starts_with("/*!") {
+                let doc = text
+                    .strip_prefix("/*!")
+                    .and_then(|s| s.strip_suffix("*/"))
+                    .unwrap_or("")
+                    .trim();
+                result.module_doc = Some(doc.to_string());
+            }
+        } else if child.kind() != "line_comment" && child.kind() != "block_comment" {
+            break;
+        }
+    }
+}
+
+fn extract_items(node: &Node, source: &[u8], result: &mut ParsedFile, module_path: &str) {
+    let mut cursor = node.walk();
+
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "struct_item" => extract_struct(&child, source, result, module_path),
+            "enum_item" => extract_enum(&child, source, result, module_path),
+            "trait_item" => extract_trait(&child, source, result, module_path),
+            "impl_item" => extract_impl(&child, source, result),
+            "function_item" => extract_function(&child, source, result, module_path),
+            "const_item" => extract_const(&child, source, result, module_path),
+            "static_item" => extract_static(&child, source, result, module_path),
+            "type_item" => extract_type_alias(&child, source, result, module_path),
+            "mod_item" => extract_mod(&child, source, result, module_path),
+            "use_declaration" => extract_use(&child, source, result),
+            "attribute_item" => extract_attribute(&child, source, result),
+            "macro_definition" => extract_macro(&child, source, result),
+            _ => {}
+        }
+    }
+}
+
+fn extract_struct(node: &Node, source: &[u8], result: &mut ParsedFile, module_path: &str) {
+    let visibility = extract_visibility(node, source);
+    let name = find_child_text(node, "type_identifier", source).unwrap_or_default();
+    let generics = extract_generics(node, source);
+    let line = node.start_position().row + 1;
+
+    let mut fields = Vec::new();
+
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut cursor = body.walk();
+        for child in body.children(&mut cursor) {
+            if child.kind() == "field_declaration" {
+                let field_vis = extract_visibility(&child, source);
+                let field_name =
+                    find_child_text(&child, "field_identifier", source).unwrap_or_default();
+                let field_type = child
+                    .child_by_field_name("type")
+                    .map(|n| node_text(&n, source))
+                    .unwrap_or_default();
+                let field_line = child.start_position().row + 1;
+
+                fields.push(StructField {
+                    name: field_name,
+                    field_type,
+                    visibility: field_vis,
+                    line: field_line,
+                });
+            }
+        }
+    }
+
+    let derives = extract_derives_for_item(node, source);
+    push_derive_info(result, &name, &derives, line);
+
+    result.symbols.symbols.push(Symbol {
+        name,
+        kind: SymbolKind::Struct { fields },
+        visibility,
+        generics,
+        line,
+        is_async: false,
+        is_unsafe: false,
+        is_const: false,
+        re_exported_as: None,
+        doc_summary: extract_doc_summary(node, source),
+        cfg: extract_symbol_cfg(node, source),
+        cfg_expr: extract_symbol_cfg_expr(node, source),
+        cfg_active: true,
+        module_path: module_path.to_string(),
+        generic_params: extract_generic_params(node, source),
+    });
+}
+
+fn extract_enum(node: &Node, source: &[u8], result: &mut ParsedFile, module_path: &str) {
+    let visibility = extract_visibility(node, source);
+    let name = find_child_text(node, "type_identifier", source).unwrap_or_default();
+    let generics = extract_generics(node, source);
+    let line = node.start_position().row + 1;
+
+    let mut variants = Vec::new();
+
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut cursor = body.walk();
+        for child in body.children(&mut cursor) {
+            if child.kind() == "enum_variant" {
+                let variant_name =
+                    find_child_text(&child, "identifier", source).unwrap_or_default();
+                let variant_line = child.start_position().row + 1;
+
+                let payload = if let Some(tuple_body) = child
+                    .children(&mut child.walk())
+                    .find(|n| n.kind() == "ordered_field_declaration_list")
+                {
+                    let mut fields = Vec::new();
+                    let mut tuple_cursor = tuple_body.walk();
+                    for field in tuple_body.children(&mut tuple_cursor) {
+                        if field.kind() == "ordered_field_declaration" {
+                            if let Some(type_node) = field.child_by_field_name("type") {
+                                fields.push(node_text(&type_node, source));
+                            }
+                        }
+                    }
+                    if fields.is_empty() {
+                        for field in tuple_body.children(&mut tuple_cursor) {
+                            if field.kind() == "type_identifier"
+                                || field.kind() == "generic_type"
+                                || field.kind() == "reference_type"
+                                || field.kind() == "primitive_type"
+                            {
+                                fields.push(node_text(&field, source));
+                            }
+                        }
+                    }
+                    if !fields.is_empty() {
+                        Some(VariantPayload::Tuple(fields))
+                    } else {
+                        None
+                    }
+                } else if let Some(struct_body) = child
+                    .children(&mut child.walk())
+                    .find(|n| n.kind() == "field_declaration_list")
+                {
+                    let mut fields = Vec::new();
+                    let mut struct_cursor = struct_body.walk();
+                    for field in struct_body.children(&mut struct_cursor) {
+                        if field.kind() == "field_declaration" {
+                            let field_name = find_child_text(&field, "field_identifier", source)
+                                .unwrap_or_default();
+                            let field_type = field
+                                .child_by_field_name("type")
+                                .map(|n| node_text(&n, source))
+                                .unwrap_or_default();
+                            fields.push((field_name, field_type));
+                        }
+                    }
+                    if !fields.is_empty() {
+                        Some(VariantPayload::Struct(fields))
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                };
+
+                variants.push(EnumVariant {
+                    name: variant_name,
+                    payload,
+                    line: variant_line,
+                });
+            }
+        }
+    }
+
+    let derives = extract_derives_for_item(node, source);
+    push_derive_info(result, &name, &derives, line);
+
+    result.symbols.symbols.push(Symbol {
+        name,
+        kind: SymbolKind::Enum { variants },
+        visibility,
+        generics,
+        line,
+        is_async: false,
+        is_unsafe: false,
+        is_const: false,
+        re_exported_as: None,
+        doc_summary: extract_doc_summary(node, source),
+        cfg: extract_symbol_cfg(node, source),
+        cfg_expr: extract_symbol_cfg_expr(node, source),
+        cfg_active: true,
+        module_path: module_path.to_string(),
+        generic_params: extract_generic_params(node, source),
+    });
+}
+
+fn extract_trait(node: &Node, source: &[u8], result: &mut ParsedFile, module_path: &str) {
+    let visibility = extract_visibility(node, source);
+    let name = find_child_text(node, "type_identifier", source).unwrap_or_default();
+    let generics = extract_generics(node, source);
+    let line = node.start_position().row + 1;
+
+    let mut supertraits = Vec::new();
+    let mut methods = Vec::new();
+    let mut associated_types = Vec::new();
+
+    if let Some(bounds) = node.child_by_field_name("bounds") {
+        let bounds_text = node_text(&bounds, source);
+        for bound in bounds_text.split('+') {
+            let bound = bound.trim();
+            if !bound.is_empty() {
+                supertraits.push(bound.to_string());
+            }
+        }
+    }
+
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut cursor = body.walk();
+        for child in body.children(&mut cursor) {
+            match child.kind() {
+                "function_signature_item" => {
+                    let method_name =
+                        find_child_text(&child, "identifier", source).unwrap_or_default();
+                    let signature = extract_function_signature(&child, source);
+                    methods.push(TraitMethod {
                         name: method_name,
                         signature,
                         has_default: false,
                     });
                 }
-                "function_item" => {
-                    let method_name =
-                        find_child_text(&child, "identifier", source).unwrap_or_default();
-                    let signature = extract_function_signature(&child, source);
-                    methods.push(TraitMethod {
-                        name: method_name,
-                        signature,
-                        has_default: true,
-                    });
+                "function_item" => {
+                    let method_name =
+                        find_child_text(&child, "identifier", source).unwrap_or_default();
+                    let signature = extract_function_signature(&child, source);
+                    methods.push(TraitMethod {
+                        name: method_name,
+                        signature,
+                        has_default: true,
+                    });
+                }
+                "associated_type" => {
+                    let type_name =
+                        find_child_text(&child, "type_identifier", source).unwrap_or_default();
+                    let bounds = child
+                        .child_by_field_name("bounds")
+                        .map(|n| node_text(&n, source));
+                    associated_types.push(AssociatedType {
+                        name: type_name,
+                        bounds,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    result.symbols.symbols.push(Symbol {
+        name,
+        kind: SymbolKind::Trait {
+            supertraits,
+            methods,
+            associated_types,
+        },
+        visibility,
+        generics,
+        line,
+        is_async: false,
+        is_unsafe: false,
+        is_const: false,
+        re_exported_as: None,
+        doc_summary: extract_doc_summary(node, source),
+        cfg: extract_symbol_cfg(node, source),
+        cfg_expr: extract_symbol_cfg_expr(node, source),
+        cfg_active: true,
+        module_path: module_path.to_string(),
+        generic_params: extract_generic_params(node, source),
+    });
+}
+
+fn extract_impl(node: &Node, source: &[u8], result: &mut ParsedFile) {
+    let trait_name = node
+        .child_by_field_name("trait")
+        .map(|n| node_text(&n, source));
+
+    let type_node = node.child_by_field_name("type");
+    let type_name = type_node.map(|n| node_text(&n, source)).unwrap_or_default();
+
+    let base_type_name = extract_base_type_name(&type_name);
+
+    let impl_generics = extract_type_parameters(node, source);
+    let where_clause = extract_where_clause(node, source);
+
+    let mut methods = Vec::new();
+    let mut assoc_consts = Vec::new();
+    let mut assoc_types = Vec::new();
+
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut cursor = body.walk();
+        for child in body.children(&mut cursor) {
+            match child.kind() {
+                "function_item" => {
+                    let visibility = extract_visibility(&child, source);
+                    let fn_name = find_child_text(&child, "identifier", source).unwrap_or_default();
+                    let signature = extract_function_signature(&child, source);
+                    let is_async = has_modifier(&child, "async");
+                    let is_unsafe = has_modifier(&child, "unsafe");
+                    let is_const = has_modifier(&child, "const");
+                    let fn_line = child.start_position().row + 1;
+
+                    methods.push(ImplMethod {
+                        name: fn_name,
+                        visibility,
+                        signature,
+                        is_async,
+                        is_unsafe,
+                        is_const,
+                        line: fn_line,
+                        body: None,
+                    });
+                }
+                "const_item" => {
+                    let visibility = extract_visibility(&child, source);
+                    let name = find_child_text(&child, "identifier", source).unwrap_or_default();
+                    let const_type = child
+                        .child_by_field_name("type")
+                        .map(|n| node_text(&n, source))
+                        .unwrap_or_default();
+                    let item_line = child.start_position().row + 1;
+
+                    if let Some(trait_name) = &trait_name {
+                        result
+                            .symbols
+                            .trait_impl_assoc_items
+                            .push(TraitImplAssocItem {
+                                trait_name: trait_name.clone(),
+                                type_name: type_name.clone(),
+                                kind: TraitImplAssocKind::Const,
+                                name,
+                                value_type: const_type,
+                                visibility,
+                                line: item_line,
+                            });
+                    } else {
+                        assoc_consts.push(ImplAssocConst {
+                            name,
+                            const_type,
+                            visibility,
+                            line: item_line,
+                        });
+                    }
+                }
+                "type_item" => {
+                    let visibility = extract_visibility(&child, source);
+                    let name =
+                        find_child_text(&child, "type_identifier", source).unwrap_or_default();
+                    let bound_type = child
+                        .child_by_field_name("type")
+                        .map(|n| node_text(&n, source))
+                        .unwrap_or_default();
+                    let item_line = child.start_position().row + 1;
+
+                    if let Some(trait_name) = &trait_name {
+                        result
+                            .symbols
+                            .trait_impl_assoc_items
+                            .push(TraitImplAssocItem {
+                                trait_name: trait_name.clone(),
+                                type_name: type_name.clone(),
+                                kind: TraitImplAssocKind::Type,
+                                name,
+                                value_type: bound_type,
+                                visibility,
+                                line: item_line,
+                            });
+                    } else {
+                        assoc_types.push(ImplAssocType {
+                            name,
+                            bound_type,
+                            visibility,
+                            line: item_line,
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if let Some(trait_name) = trait_name {
+        result.impls.push(TraitImpl {
+            type_name: base_type_name.clone(),
+            trait_name: extract_base_type_name(&trait_name),
+            is_derived: false,
+            line: node.start_position().row + 1,
+        });
+        result.symbols.impl_map.push((trait_name, type_name));
+    } else if !methods.is_empty() || !assoc_consts.is_empty() || !assoc_types.is_empty() {
+        result.symbols.inherent_impls.push(InherentImpl {
+            type_name: base_type_name,
+            generics: impl_generics,
+            where_clause,
+            methods,
+            assoc_consts,
+            assoc_types,
+            generic_params: extract_generic_params(node, source),
+        });
+    }
+}
+
+fn extract_base_type_name(full_type: &str) -> String {
+    let trimmed = full_type.trim();
+    if let Some(angle_pos) = trimmed.find('<') {
+        trimmed[..angle_pos].trim().to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+fn extract_type_parameters(node: &Node, source: &[u8]) -> String {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "type_parameters" {
+            return node_text(&child, source);
+        }
+    }
+    String::new()
+}
+
+fn extract_where_clause(node: &Node, source: &[u8]) -> Option<String> {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "where_clause" {
+            let text = node_text(&child, source);
+            let text = text.strip_prefix("where").unwrap_or(&text).trim();
+            if !text.is_empty() {
+                return Some(text.to_string());
+            }
+        }
+    }
+    None
+}
+
+/// Parses `node`'s `<...>` clause and `where` predicates into a [`GenericParams`], complementing
+/// [`extract_generics`]/[`extract_where_clause`]'s raw-text form with individual lifetime, type,
+/// and const parameters.
+fn extract_generic_params(node: &Node, source: &[u8]) -> GenericParams {
+    let mut params = GenericParams::default();
+
+    let mut cursor = node.walk();
+    let type_parameters = node
+        .children(&mut cursor)
+        .find(|child| child.kind() == "type_parameters");
+
+    if let Some(type_parameters) = type_parameters {
+        let mut cursor = type_parameters.walk();
+        for child in type_parameters.children(&mut cursor) {
+            match child.kind() {
+                "lifetime" => params.lifetimes.push(node_text(&child, source)),
+                "type_identifier" => params.type_params.push(GenericTypeParam {
+                    name: node_text(&child, source),
+                    bounds: Vec::new(),
+                    default: None,
+                }),
+                "constrained_type_parameter" => {
+                    let name = child
+                        .child_by_field_name("left")
+                        .map(|n| node_text(&n, source))
+                        .unwrap_or_default();
+                    let bounds = child
+                        .child_by_field_name("bounds")
+                        .map(|n| split_trait_bounds(&node_text(&n, source)))
+                        .unwrap_or_default();
+                    params.type_params.push(GenericTypeParam {
+                        name,
+                        bounds,
+                        default: None,
+                    });
+                }
+                "optional_type_parameter" => {
+                    let name = child
+                        .child_by_field_name("name")
+                        .map(|n| node_text(&n, source))
+                        .unwrap_or_default();
+                    let default = child
+                        .child_by_field_name("default_type")
+                        .map(|n| node_text(&n, source));
+                    params.type_params.push(GenericTypeParam {
+                        name,
+                        bounds: Vec::new(),
+                        default,
+                    });
+                }
+                "const_parameter" => {
+                    let name = child
+                        .child_by_field_name("name")
+                        .map(|n| node_text(&n, source))
+                        .unwrap_or_default();
+                    let const_type = child
+                        .child_by_field_name("type")
+                        .map(|n| node_text(&n, source))
+                        .unwrap_or_default();
+                    params.const_params.push(GenericConstParam { name, const_type });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut cursor = node.walk();
+    let where_clause = node
+        .children(&mut cursor)
+        .find(|child| child.kind() == "where_clause");
+
+    if let Some(where_clause) = where_clause {
+        let mut cursor = where_clause.walk();
+        for child in where_clause.children(&mut cursor) {
+            if child.kind() != "where_predicate" {
+                continue;
+            }
+            let target = child
+                .child_by_field_name("left")
+                .map(|n| node_text(&n, source))
+                .unwrap_or_default();
+            let bounds = child
+                .child_by_field_name("bounds")
+                .map(|n| split_trait_bounds(&node_text(&n, source)))
+                .unwrap_or_default();
+            params.where_predicates.push(WherePredicate { target, bounds });
+        }
+    }
+
+    params
+}
+
+/// Splits a trait-bounds clause on top-level `+` separators, so a bound like
+/// `Iterator<Item = Box<dyn Fn() + Send>>` isn't split inside its own generic arguments.
+fn split_trait_bounds(bounds: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (index, char) in bounds.char_indices() {
+        match char {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            '+' if depth == 0 => {
+                let part = bounds[start..index].trim();
+                if !part.is_empty() {
+                    parts.push(part.to_string());
+                }
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = bounds[start..].trim();
+    if !last.is_empty() {
+        parts.push(last.to_string());
+    }
+    parts
+}
+
+fn extract_function(node: &Node, source: &[u8], result: &mut ParsedFile, module_path: &str) {
+    let visibility = extract_visibility(node, source);
+    let name = find_child_text(node, "identifier", source).unwrap_or_default();
+    let generics = extract_generics(node, source);
+    let signature = extract_function_signature(node, source);
+    let signature_model = extract_signature_model(node, source);
+    let line = node.start_position().row + 1;
+
+    let is_async = has_modifier(node, "async");
+    let is_unsafe = has_modifier(node, "unsafe");
+    let is_const = has_modifier(node, "const");
+
+    if has_test_attribute(node, source) && !name.is_empty() {
+        result.test_functions.push(name.clone());
+    }
+
+    result.symbols.symbols.push(Symbol {
+        name,
+        kind: SymbolKind::Function {
+            signature,
+            body: None,
+            signature_model,
+        },
+        visibility,
+        generics,
+        line,
+        is_async,
+        is_unsafe,
+        is_const,
+        re_exported_as: None,
+        doc_summary: extract_doc_summary(node, source),
+        cfg: extract_symbol_cfg(node, source),
+        cfg_expr: extract_symbol_cfg_expr(node, source),
+        cfg_active: true,
+        module_path: module_path.to_string(),
+        generic_params: extract_generic_params(node, source),
+    });
+}
+
+fn has_test_attribute(node: &Node, source: &[u8]) -> bool {
+    if let Some(parent) = node.parent() {
+        let mut cursor = parent.walk();
+        for sibling in parent.children(&mut cursor) {
+            if sibling.end_byte() < node.start_byte() && sibling.kind() == "attribute_item" {
+                let text = node_text(&sibling, source);
+                if text.contains("#[test]")
+                    || text.contains("#[tokio::test")
+                    || text.contains("#[async_std::test")
+                {
+                    return true;
+                }
+            }
+        }
+    }
+    false
+}
+
+fn extract_const(node: &Node, source: &[u8], result: &mut ParsedFile, module_path: &str) {
+    let visibility = extract_visibility(node, source);
+    let name = find_child_text(node, "identifier", source).unwrap_or_default();
+    let const_type = node
+        .child_by_field_name("type")
+        .map(|n| node_text(&n, source))
+        .unwrap_or_default();
+    let line = node.start_position().row + 1;
+
+    let value = node
+        .child_by_field_name("value")
+        .and_then(|n| extract_simple_value(&n, source));
+
+    result.symbols.symbols.push(Symbol {
+        name,
+        kind: SymbolKind::Const { const_type, value },
+        visibility,
+        generics: String::new(),
+        line,
+        is_async: false,
+        is_unsafe: false,
+        is_const: true,
+        re_exported_as: None,
+        doc_summary: extract_doc_summary(node, source),
+        cfg: extract_symbol_cfg(node, source),
+        cfg_expr: extract_symbol_cfg_expr(node, source),
+        cfg_active: true,
+        module_path: module_path.to_string(),
+        generic_params: GenericParams::default(),
+    });
+}
+
+fn extract_static(node: &Node, source: &[u8], result: &mut ParsedFile, module_path: &str) {
+    let visibility = extract_visibility(node, source);
+    let name = find_child_text(node, "identifier", source).unwrap_or_default();
+    let static_type = node
+        .child_by_field_name("type")
+        .map(|n| node_text(&n, source))
+        .unwrap_or_default();
+    let line = node.start_position().row + 1;
+
+    let is_mutable = node
+        .children(&mut node.walk())
+        .any(|c| c.kind() == "mutable_specifier");
+
+    let value = node
+        .child_by_field_name("value")
+        .and_then(|n| extract_simple_value(&n, source));
+
+    result.symbols.symbols.push(Symbol {
+        name,
+        kind: SymbolKind::Static {
+            static_type,
+            is_mutable,
+            value,
+        },
+        visibility,
+        generics: String::new(),
+        line,
+        is_async: false,
+        is_unsafe: false,
+        is_const: false,
+        re_exported_as: None,
+        doc_summary: extract_doc_summary(node, source),
+        cfg: extract_symbol_cfg(node, source),
+        cfg_expr: extract_symbol_cfg_expr(node, source),
+        cfg_active: true,
+        module_path: module_path.to_string(),
+        generic_params: GenericParams::default(),
+    });
+}
+
+fn extract_simple_value(node: &Node, source: &[u8]) -> Option<String> {
+    let text = node_text(node, source);
+    let trimmed = text.trim();
+
+    if trimmed.contains('\n') || trimmed.len() > 80 {
+        return None;
+    }
+
+    match node.kind() {
+        "integer_literal" | "float_literal" | "string_literal" | "char_literal"
+        | "boolean_literal" | "raw_string_literal" => Some(trimmed.to_string()),
+        "unary_expression" | "binary_expression" => {
+            if trimmed.len() <= 40 {
+                Some(trimmed.to_string())
+            } else {
+                None
+            }
+        }
+        "call_expression" | "struct_expression" => {
+            if trimmed.len() <= 80 {
+                Some(trimmed.to_string())
+            } else {
+                None
+            }
+        }
+        "array_expression" => {
+            if trimmed.len() <= 60 {
+                Some(trimmed.to_string())
+            } else {
+                None
+            }
+        }
+        "identifier" | "scoped_identifier" => Some(trimmed.to_string()),
+        _ => {
+            if trimmed.len() <= 50 && !trimmed.contains("||") && !trimmed.contains("&&") {
+                Some(trimmed.to_string())
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn extract_type_alias(node: &Node, source: &[u8], result: &mut ParsedFile, module_path: &str) {
+    let visibility = extract_visibility(node, source);
+    let name = find_child_text(node, "type_identifier", source).unwrap_or_default();
+    let generics = extract_generics(node, source);
+    let aliased_type = node
+        .child_by_field_name("type")
+        .map(|n| node_text(&n, source))
+        .unwrap_or_default();
+    let line = node.start_position().row + 1;
+
+    result.symbols.symbols.push(Symbol {
+        name,
+        kind: SymbolKind::TypeAlias { aliased_type },
+        visibility,
+        generics,
+        line,
+        is_async: false,
+        is_unsafe: false,
+        is_const: false,
+        re_exported_as: None,
+        doc_summary: extract_doc_summary(node, source),
+        cfg: extract_symbol_cfg(node, source),
+        cfg_expr: extract_symbol_cfg_expr(node, source),
+        cfg_active: true,
+        module_path: module_path.to_string(),
+        generic_params: GenericParams::default(),
+    });
+}
+
+fn extract_mod(node: &Node, source: &[u8], result: &mut ParsedFile, module_path: &str) {
+    let visibility = extract_visibility(node, source);
+    let name = find_child_text(node, "identifier", source).unwrap_or_default();
+    let line = node.start_position().row + 1;
+
+    let mut cursor = node.walk();
+    let has_cfg_test = node.children(&mut cursor).any(|child| {
+        if child.kind() == "attribute_item" {
+            let text = node_text(&child, source);
+            text.contains("cfg(test)")
+        } else {
+            false
+        }
+    });
+
+    if has_cfg_test {
+        result.has_test_module = true;
+    }
+
+    result.symbols.symbols.push(Symbol {
+        name: name.clone(),
+        kind: SymbolKind::Mod,
+        visibility,
+        generics: String::new(),
+        line,
+        is_async: false,
+        is_unsafe: false,
+        is_const: false,
+        re_exported_as: None,
+        doc_summary: extract_doc_summary(node, source),
+        cfg: extract_symbol_cfg(node, source),
+        cfg_expr: extract_symbol_cfg_expr(node, source),
+        cfg_active: true,
+        module_path: module_path.to_string(),
+        generic_params: GenericParams::default(),
+    });
+
+    if let Some(body) = node.child_by_field_name("body") {
+        let child_path = if module_path.is_empty() {
+            name
+        } else {
+            format!("{module_path}::{name}")
+        };
+        extract_items(&body, source, result, &child_path);
+    }
+}
+
+fn extract_macro(node: &Node, source: &[u8], result: &mut ParsedFile) {
+    let name = find_child_text(node, "identifier", source).unwrap_or_default();
+    let line = node.start_position().row + 1;
+
+    let is_exported = if let Some(parent) = node.parent() {
+        let mut cursor = parent.walk();
+        parent.children(&mut cursor).any(|sibling| {
+            if sibling.end_byte() < node.start_byte() && sibling.kind() == "attribute_item" {
+                let text = node_text(&sibling, source);
+                text.contains("macro_export")
+            } else {
+                false
+            }
+        })
+    } else {
+        false
+    };
+
+    let rules = extract_macro_rules(node, source);
+
+    result.symbols.macros.push(MacroInfo {
+        name,
+        is_exported,
+        line,
+        rules,
+    });
+}
+
+/// Walks a `macro_rules!` definition's `macro_rule` children, splitting each arm's matcher from
+/// its transcriber on the top-level `=>` and pulling out the matcher's `$name`/`$name:fragment`
+/// captures.
+fn extract_macro_rules(node: &Node, source: &[u8]) -> Vec<MacroRule> {
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .filter(|child| child.kind() == "macro_rule")
+        .map(|rule| {
+            let text = node_text(&rule, source);
+            let (matcher, transcriber) = split_macro_rule(&text);
+            let metavariables = extract_macro_metavariables(matcher, None);
+            MacroRule {
+                matcher: matcher.to_string(),
+                transcriber: transcriber.to_string(),
+                metavariables,
+            }
+        })
+        .collect()
+}
+
+/// Splits a `macro_rule` arm's source text on its top-level `=>`, separating the matcher pattern
+/// from the transcriber body.
+fn split_macro_rule(text: &str) -> (&str, &str) {
+    let bytes = text.as_bytes();
+    let mut depth = 0i32;
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' => depth -= 1,
+            b'=' if depth == 0 && bytes.get(index + 1) == Some(&b'>') => {
+                return (text[..index].trim(), text[index + 2..].trim());
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    (text.trim(), "")
+}
+
+/// Recursively collects `$name`/`$name:fragment` metavariables out of a matcher pattern,
+/// descending into `$(...)sep*` repetition groups and tagging the metavariables found inside
+/// each with that group's [`MacroRepetition`].
+fn extract_macro_metavariables(
+    pattern: &str,
+    repetition: Option<MacroRepetition>,
+) -> Vec<MacroMetavariable> {
+    let mut metavariables = Vec::new();
+    let bytes = pattern.as_bytes();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if bytes[index] != b'$' {
+            index += 1;
+            continue;
+        }
+
+        if bytes.get(index + 1) == Some(&b'(') {
+            let group_start = index + 2;
+            let Some(group_end) = find_matching_paren_from(pattern, group_start) else {
+                break;
+            };
+            let (inner_repetition, after) = parse_repetition_suffix(pattern, group_end + 1);
+            let inner = &pattern[group_start..group_end];
+            metavariables.extend(extract_macro_metavariables(inner, inner_repetition));
+            index = after;
+            continue;
+        }
+
+        let name_start = index + 1;
+        let mut cursor = name_start;
+        while cursor < bytes.len() && (bytes[cursor].is_ascii_alphanumeric() || bytes[cursor] == b'_')
+        {
+            cursor += 1;
+        }
+        if cursor == name_start {
+            index += 1;
+            continue;
+        }
+        let name = pattern[name_start..cursor].to_string();
+
+        let mut fragment_specifier = None;
+        let mut next = cursor;
+        if bytes.get(cursor) == Some(&b':') {
+            let frag_start = cursor + 1;
+            let mut frag_end = frag_start;
+            while frag_end < bytes.len()
+                && (bytes[frag_end].is_ascii_alphanumeric() || bytes[frag_end] == b'_')
+            {
+                frag_end += 1;
+            }
+            fragment_specifier = Some(pattern[frag_start..frag_end].to_string());
+            next = frag_end;
+        }
+
+        metavariables.push(MacroMetavariable {
+            name,
+            fragment_specifier,
+            repetition: repetition.clone(),
+        });
+        index = next;
+    }
+
+    metavariables
+}
+
+fn find_matching_paren_from(text: &str, after_open: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 1i32;
+    let mut index = after_open;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Parses the optional separator token and repetition operator (`*`, `+`, or `?`) that follow a
+/// `$(...)` repetition group, returning the index to resume scanning from. Returns `None` and the
+/// original `start` if the group isn't actually followed by a repetition operator.
+fn parse_repetition_suffix(pattern: &str, start: usize) -> (Option<MacroRepetition>, usize) {
+    let bytes = pattern.as_bytes();
+    let mut index = start;
+    while index < bytes.len() && bytes[index].is_ascii_whitespace() {
+        index += 1;
+    }
+
+    let sep_start = index;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'*' | b'+' | b'?' => {
+                let separator_text = pattern[sep_start..index].trim();
+                let separator = if separator_text.is_empty() {
+                    None
+                } else {
+                    Some(separator_text.to_string())
+                };
+                return (
+                    Some(MacroRepetition {
+                        operator: bytes[index] as char,
+                        separator,
+                    }),
+                    index + 1,
+                );
+            }
+            b'$' | b')' => break,
+            _ => index += 1,
+        }
+    }
+
+    (None, start)
+}
+
+fn extract_use(node: &Node, source: &[u8], result: &mut ParsedFile) {
+    let visibility = extract_visibility(node, source);
+    let line = node.start_position().row + 1;
+    let cfg_feature = find_enclosing_cfg_feature(node, source);
+
+    if let Some(arg) = node.child_by_field_name("argument") {
+        let path = node_text(&arg, source);
+
+        if visibility != Visibility::Private {
+            result.re_exports.push(ReExport {
+                source_path: path.clone(),
+                visibility: visibility.clone(),
+                line,
+            });
+        }
+
+        result.imports.push(ImportInfo {
+            path,
+            line,
+            cfg_feature,
+        });
+    }
+}
+
+/// Looks for a `#[cfg(feature = "...")]` attribute attached to `node`'s enclosing
+/// item, so imports can be classified as feature-gated or unconditional.
+fn find_enclosing_cfg_feature(node: &Node, source: &[u8]) -> Option<String> {
+    let parent = node.parent()?;
+    let mut cursor = parent.walk();
+    parent
+        .children(&mut cursor)
+        .filter(|sibling| {
+            sibling.end_byte() <= node.start_byte() && sibling.kind() == "attribute_item"
+        })
+        .find_map(|sibling| {
+            let text = node_text(&sibling, source);
+            if text.contains("cfg(feature") {
+                extract_feature_name(&text)
+            } else {
+                None
+            }
+        })
+}
+
+fn extract_feature_name(attr_text: &str) -> Option<String> {
+    let start = attr_text.find("feature")?;
+    let rest = &attr_text[start..];
+    let quote_start = rest.find('"')? + 1;
+    let quote_end = rest[quote_start..].find('"')? + quote_start;
+    Some(rest[quote_start..quote_end].to_string())
+}
+
+fn extract_attribute(node: &Node, source: &[u8], result: &mut ParsedFile) {
+    let text = node_text(node, source);
+    let line = node.start_position().row + 1;
+
+    if text.contains("#[cfg(") || text.contains("#[cfg_attr(") {
+        let cfg_content = extract_cfg_content(&text);
+        if let Some(condition) = cfg_content {
+            let predicate = parse_cfg_predicate(&condition);
+            result.cfgs.push(CfgInfo {
+                condition,
+                predicate,
+                line,
+            });
+        }
+    }
+}
+
+fn extract_derives_for_item(node: &Node, source: &[u8]) -> Vec<Vec<String>> {
+    let mut derives = Vec::new();
+    let mut cursor = node.walk();
+
+    let parent = node.parent();
+    if let Some(parent) = parent {
+        let mut sibling_cursor = parent.walk();
+        for sibling in parent.children(&mut sibling_cursor) {
+            if sibling.end_byte() >= node.start_byte() {
+                break;
+            }
+            if sibling.kind() == "attribute_item" {
+                let text = node_text(&sibling, source);
+                if text.contains("#[derive(") {
+                    if let Some(traits) = extract_derive_traits(&text) {
+                        derives.push(traits);
+                    }
                 }
-                "associated_type" => {
-                    let type_name =
-                        find_child_text(&child, "type_identifier", source).unwrap_or_default();
-                    let bounds = child
-                        .child_by_field_name("bounds")
-                        .map(|n| node_text(&n, source));
-                    associated_types.push(AssociatedType {
-                        name: type_name,
-                        bounds,
-                    });
+            }
+        }
+    }
+
+    for child in node.children(&mut cursor) {
+        if child.kind() == "attribute_item" {
+            let text = node_text(&child, source);
+            if text.contains("#[derive(") {
+                if let Some(traits) = extract_derive_traits(&text) {
+                    derives.push(traits);
+                }
+            }
+        }
+    }
+
+    derives
+}
+
+/// Records each `#[derive(...)]` group found for `name` both as a [`DeriveInfo`] (the
+/// pre-existing, grouped-by-attribute view) and as one [`TraitImpl`] per trait (the flat,
+/// per-trait view `crate::traitindex` merges across files).
+fn push_derive_info(result: &mut ParsedFile, name: &str, derives: &[Vec<String>], line: usize) {
+    for derive in derives {
+        result.derives.push(DeriveInfo {
+            target: name.to_string(),
+            traits: derive.clone(),
+            line,
+        });
+        for trait_name in derive {
+            result.impls.push(TraitImpl {
+                type_name: name.to_string(),
+                trait_name: trait_name.clone(),
+                is_derived: true,
+                line,
+            });
+        }
+    }
+}
+
+/// First sentence of the `///`/`/**` doc comment immediately preceding `node`, skipping
+/// over any attributes (e.g. `#[derive(..)]`) in between. Returns `None` if the item is
+/// undocumented.
+fn extract_doc_summary(node: &Node, source: &[u8]) -> Option<String> {
+    let parent = node.parent()?;
+    let mut cursor = parent.walk();
+    let siblings: Vec<_> = parent.children(&mut cursor).collect();
+    let index = siblings.iter().position(|s| s.id() == node.id())?;
+
+    let mut doc_lines = Vec::new();
+    let mut i = index;
+    while i > 0 {
+        let sibling = &siblings[i - 1];
+        match sibling.kind() {
+            "attribute_item" => {}
+            "line_comment" | "block_comment" => {
+                let text = node_text(sibling, source);
+                if let Some(stripped) = text.strip_prefix("///") {
+                    doc_lines.push(stripped.trim().to_string());
+                } else if let Some(inner) =
+                    text.strip_prefix("/**").and_then(|s| s.strip_suffix("*/"))
+                {
+                    doc_lines.push(inner.trim().to_string());
+                } else {
+                    break;
+                }
+            }
+            _ => break,
+        }
+        i -= 1;
+    }
+
+    if doc_lines.is_empty() {
+        return None;
+    }
+
+    doc_lines.reverse();
+    let full_doc = doc_lines.join(" ");
+    let summary = full_doc
+        .split('.')
+        .next()
+        .unwrap_or(&full_doc)
+        .trim()
+        .to_string();
+
+    if summary.is_empty() {
+        None
+    } else {
+        Some(summary)
+    }
+}
+
+/// Scans `node`'s immediately preceding `attribute_item` siblings (the same walk
+/// [`extract_doc_summary`] does for doc comments) for `#[cfg(...)]`/`#[cfg_attr(...)]`
+/// attributes, ANDs their predicates together if there's more than one, and renders the result
+/// through [`CfgPredicate`]'s `Display` impl for [`Symbol::cfg`]. Only the item's own attributes
+/// count here — an enclosing `mod`'s cfg is a separate symbol with its own `cfg` field, not
+/// folded into this one.
+fn extract_symbol_cfg(node: &Node, source: &[u8]) -> Option<String> {
+    extract_symbol_cfg_expr(node, source).map(|predicate| predicate.to_string())
+}
+
+/// Same scan as [`extract_symbol_cfg`], but returns the parsed [`CfgPredicate`] tree instead of
+/// its canonical string rendering, so [`evaluate_symbol_cfg`] can evaluate it against a
+/// [`CfgSet`] without re-parsing.
+fn extract_symbol_cfg_expr(node: &Node, source: &[u8]) -> Option<CfgPredicate> {
+    let parent = node.parent()?;
+    let mut cursor = parent.walk();
+    let siblings: Vec<_> = parent.children(&mut cursor).collect();
+    let index = siblings.iter().position(|s| s.id() == node.id())?;
+
+    let mut predicates = Vec::new();
+    let mut i = index;
+    while i > 0 && siblings[i - 1].kind() == "attribute_item" {
+        let text = node_text(&siblings[i - 1], source);
+        if let Some(predicate) = extract_cfg_predicate(&text) {
+            predicates.push(predicate);
+        }
+        i -= 1;
+    }
+
+    match predicates.len() {
+        0 => None,
+        1 => Some(predicates.remove(0)),
+        _ => {
+            predicates.reverse();
+            Some(CfgPredicate::All(predicates))
+        }
+    }
+}
+
+/// Evaluates every symbol's `cfg_expr` against `active`, setting `cfg_active` accordingly (an
+/// item with no `cfg_expr` at all is unconditionally compiled, so it stays `true`). Unlike
+/// [`parse_rust_file_with_cfg`], this doesn't drop anything from `result` — it only annotates, so
+/// a caller can filter a single [`ParsedFile`] to more than one build configuration without
+/// re-parsing.
+pub fn evaluate_symbol_cfg(result: &mut ParsedFile, active: &CfgSet) {
+    for symbol in &mut result.symbols.symbols {
+        symbol.cfg_active = symbol
+            .cfg_expr
+            .as_ref()
+            .map(|expr| expr.evaluate(active))
+            .unwrap_or(true);
+    }
+}
+
+fn extract_derive_traits(attr_text: &str) -> Option<Vec<String>> {
+    let start = attr_text.find("#[derive(")? + 9;
+    let end = attr_text[start..].find(')')? + start;
+    let content = &attr_text[start..end];
+
+    let traits: Vec<String> = content
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    if traits.is_empty() {
+        None
+    } else {
+        Some(traits)
+    }
+}
+
+fn extract_cfg_content(attr_text: &str) -> Option<String> {
+    if let Some(start) = attr_text.find("#[cfg(") {
+        let start = start + 6;
+        let mut depth = 1;
+        let mut end = start;
+        for (index, char) in attr_text[start..].char_indices() {
+            match char {
+                '(' => depth += 1,
+                ')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = start + index;
+                        break;
+                    }
                 }
                 _ => {}
             }
         }
+        return Some(attr_text[start..end].to_string());
     }
+    None
+}
 
-    result.symbols.symbols.push(Symbol {
-        name,
-        kind: SymbolKind::Trait {
-            supertraits,
-            methods,
-            associated_types,
-        },
-        visibility,
-        generics,
-        line,
-        is_async: false,
-        is_unsafe: false,
-        is_const: false,
-        re_exported_as: None,
-    });
+fn extract_visibility(node: &Node, source: &[u8]) -> Visibility {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "visibility_modifier" {
+            let text = node_text(&child, source);
+            return match text.as_str() {
+                "pub" => Visibility::Public,
+                _ if text.starts_with("pub(crate)") => Visibility::PubCrate,
+                _ if text.starts_with("pub(super)") => Visibility::PubSuper,
+                _ if text.starts_with("pub(self)") => Visibility::Private,
+                _ if text.starts_with("pub(in") => Visibility::PubIn(text),
+                _ => Visibility::Public,
+            };
+        }
+    }
+    Visibility::Private
 }
 
-fn extract_impl(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    let trait_name = node
-        .child_by_field_name("trait")
-        .map(|n| node_text(&n, source));
+fn extract_generics(node: &Node, source: &[u8]) -> String {
+    if let Some(type_params) = node.child_by_field_name("type_parameters") {
+        return node_text(&type_params, source);
+    }
 
-    let type_node = node.child_by_field_name("type");
-    let type_name = type_node.map(|n| node_text(&n, source)).unwrap_or_default();
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "type_parameters" {
+            return node_text(&child, source);
+        }
+    }
 
-    let base_type_name = extract_base_type_name(&type_name);
+    String::new()
+}
 
-    let impl_generics = extract_type_parameters(node, source);
+fn extract_function_signature(node: &Node, source: &[u8]) -> String {
+    let mut parts = Vec::new();
+
+    if let Some(params) = node.child_by_field_name("parameters") {
+        parts.push(node_text(&params, source));
+    }
+
+    if let Some(return_type) = node.child_by_field_name("return_type") {
+        let ret = node_text(&return_type, source);
+        parts.push(format!(" -> {}", ret.trim_start_matches("->")));
+    }
+
+    parts.join("")
+}
+
+/// A structured counterpart to [`extract_function_signature`]'s raw text: splits the
+/// `parameters` node into an optional [`Receiver`] plus individually named/typed [`Param`]s,
+/// and carries the generics and `where` clause [`extract_generics`]/[`extract_where_clause`]
+/// already know how to find.
+fn extract_signature_model(node: &Node, source: &[u8]) -> FunctionSignature {
+    let generics = extract_generics(node, source);
     let where_clause = extract_where_clause(node, source);
+    let return_type = node
+        .child_by_field_name("return_type")
+        .map(|rt| node_text(&rt, source).trim_start_matches("->").trim().to_string())
+        .unwrap_or_default();
 
-    let mut methods = Vec::new();
+    let mut receiver = None;
+    let mut params = Vec::new();
 
-    if let Some(body) = node.child_by_field_name("body") {
-        let mut cursor = body.walk();
-        for child in body.children(&mut cursor) {
-            if child.kind() == "function_item" {
-                let visibility = extract_visibility(&child, source);
-                let fn_name = find_child_text(&child, "identifier", source).unwrap_or_default();
-                let signature = extract_function_signature(&child, source);
-                let is_async = has_modifier(&child, "async");
-                let is_unsafe = has_modifier(&child, "unsafe");
-                let is_const = has_modifier(&child, "const");
-                let fn_line = child.start_position().row + 1;
-
-                methods.push(ImplMethod {
-                    name: fn_name,
-                    visibility,
-                    signature,
-                    is_async,
-                    is_unsafe,
-                    is_const,
-                    line: fn_line,
-                    body: None,
-                });
+    if let Some(parameters) = node.child_by_field_name("parameters") {
+        let mut cursor = parameters.walk();
+        for child in parameters.children(&mut cursor) {
+            match child.kind() {
+                "self_parameter" => receiver = Some(extract_receiver(&child, source)),
+                "parameter" => {
+                    let Some(pattern) = child.child_by_field_name("pattern") else {
+                        continue;
+                    };
+                    let Some(type_node) = child.child_by_field_name("type") else {
+                        continue;
+                    };
+
+                    let (name, is_mut) = if pattern.kind() == "mutable_pattern" {
+                        let text = node_text(&pattern, source);
+                        (text.trim_start_matches("mut").trim().to_string(), true)
+                    } else {
+                        (node_text(&pattern, source), false)
+                    };
+
+                    params.push(Param {
+                        name,
+                        ty: node_text(&type_node, source),
+                        is_mut,
+                    });
+                }
+                _ => {}
             }
         }
     }
 
-    if let Some(trait_name) = trait_name {
-        result.symbols.impl_map.push((trait_name, type_name));
-    } else if !methods.is_empty() {
-        result.symbols.inherent_impls.push(InherentImpl {
-            type_name: base_type_name,
-            generics: impl_generics,
-            where_clause,
-            methods,
-        });
+    FunctionSignature {
+        receiver,
+        params,
+        generics,
+        where_clause,
+        return_type,
+        panics_in_body: function_body_risks_panic(node, source),
+        abi: extract_extern_abi(node, source),
     }
 }
 
-fn extract_base_type_name(full_type: &str) -> String {
-    let trimmed = full_type.trim();
-    if let Some(angle_pos) = trimmed.find('<') {
-        trimmed[..angle_pos].trim().to_string()
+/// The declared ABI off an `extern "C" fn`/bare `extern fn`'s `extern_modifier` child, with the
+/// bare-`extern` (no string literal) case normalized to `"C"`, matching what the Rust compiler
+/// itself infers for an elided ABI.
+fn extract_extern_abi(node: &Node, source: &[u8]) -> Option<String> {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "extern_modifier" {
+            let text = node_text(&child, source);
+            let abi = text.trim_start_matches("extern").trim().trim_matches('"');
+            return Some(if abi.is_empty() { "C".to_string() } else { abi.to_string() });
+        }
+    }
+    None
+}
+
+/// Text-contains check for the panic signals [`FunctionSignature::panics_in_body`] tracks — not
+/// a structured walk, since this only needs to answer "does this function risk panicking at
+/// all", the question `rules::builtin::DocCompleteness` asks against `has_panics_section`.
+fn function_body_risks_panic(node: &Node, source: &[u8]) -> bool {
+    let Some(body) = node.child_by_field_name("body") else {
+        return false;
+    };
+    let text = node_text(&body, source);
+    text.contains("panic!") || text.contains(".unwrap(") || text.contains(".expect(")
+}
+
+/// `&self` / `&mut self` / owned `self` (including an explicit `self: Type`), read off the
+/// `self_parameter` node's own text rather than its internal field structure.
+fn extract_receiver(node: &Node, source: &[u8]) -> Receiver {
+    let text = node_text(node, source);
+    if text.starts_with("&mut") {
+        Receiver::RefMut
+    } else if text.starts_with('&') {
+        Receiver::Ref
     } else {
-        trimmed.to_string()
+        Receiver::Owned
     }
 }
 
-fn extract_type_parameters(node: &Node, source: &[u8]) -> String {
+fn has_modifier(node: &Node, modifier: &str) -> bool {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        if child.kind() == "type_parameters" {
-            return node_text(&child, source);
+        if child.kind() == modifier {
+            return true;
         }
     }
-    String::new()
+    false
 }
 
-fn extract_where_clause(node: &Node, source: &[u8]) -> Option<String> {
+fn find_child_text(node: &Node, kind: &str, source: &[u8]) -> Option<String> {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        if child.kind() == "where_clause" {
-            let text = node_text(&child, source);
-            let text = text.strip_prefix("where").unwrap_or(&text).trim();
-            if !text.is_empty() {
-                return Some(text.to_string());
+        if child.kind() == kind {
+            return Some(node_text(&child, source));
+        }
+        if child.kind() == "name" {
+            if let Some(name_child) = child.child(0) {
+                if name_child.kind() == kind {
+                    return Some(node_text(&name_child, source));
+                }
             }
+            return Some(node_text(&child, source));
         }
     }
     None
 }
 
-fn extract_function(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    let visibility = extract_visibility(node, source);
-    let name = find_child_text(node, "identifier", source).unwrap_or_default();
-    let generics = extract_generics(node, source);
-    let signature = extract_function_signature(node, source);
-    let line = node.start_position().row + 1;
+fn node_text(node: &Node, source: &[u8]) -> String {
+    node.utf8_text(source).unwrap_or("").to_string()
+}
 
-    let is_async = has_modifier(node, "async");
-    let is_unsafe = has_modifier(node, "unsafe");
-    let is_const = has_modifier(node, "const");
+fn extract_identifier_locations(root: &Node, source: &[u8], result: &mut ParsedFile) {
+    collect_identifiers(root, source, &mut result.identifier_locations);
+}
 
-    if has_test_attribute(node, source) && !name.is_empty() {
-        result.test_functions.push(name.clone());
+fn collect_identifiers(node: &Node, source: &[u8], locations: &mut Vec<(String, usize)>) {
+    if node.kind() == "type_identifier" || node.kind() == "identifier" {
+        let name = node_text(node, source);
+        if super::is_pascal_case(&name) {
+            let line = node.start_position().row + 1;
+            locations.push((name, line));
+        }
     }
 
-    result.symbols.symbols.push(Symbol {
-        name,
-        kind: SymbolKind::Function {
-            signature,
-            body: None,
-        },
-        visibility,
-        generics,
-        line,
-        is_async,
-        is_unsafe,
-        is_const,
-        re_exported_as: None,
-    });
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_identifiers(&child, source, locations);
+    }
 }
 
-fn has_test_attribute(node: &Node, source: &[u8]) -> bool {
-    if let Some(parent) = node.parent() {
-        let mut cursor = parent.walk();
-        for sibling in parent.children(&mut cursor) {
-            if sibling.end_byte() < node.start_byte() && sibling.kind() == "attribute_item" {
-                let text = node_text(&sibling, source);
-                if text.contains("#[test]")
-                    || text.contains("#[tokio::test")
-                    || text.contains("#[async_std::test")
+/// Builtins recognized by rustc itself (declarative helpers like `println!`/`vec!` plus the
+/// handful of compiler built-in attribute-like macros such as `include!`/`cfg!`). Anything else,
+/// including macros reached through a path like `tokio::select!`, is treated as user-defined.
+const BUILTIN_MACROS: &[&str] = &[
+    "println",
+    "print",
+    "eprintln",
+    "eprint",
+    "format",
+    "format_args",
+    "write",
+    "writeln",
+    "vec",
+    "assert",
+    "assert_eq",
+    "assert_ne",
+    "debug_assert",
+    "debug_assert_eq",
+    "debug_assert_ne",
+    "panic",
+    "unreachable",
+    "unimplemented",
+    "todo",
+    "matches",
+    "dbg",
+    "cfg",
+    "include",
+    "include_str",
+    "include_bytes",
+    "concat",
+    "stringify",
+    "env",
+    "option_env",
+    "file",
+    "line",
+    "column",
+    "compile_error",
+];
+
+/// Walks the whole tree (not just function bodies, since macros can be invoked at module scope
+/// too, e.g. `lazy_static! { ... }`) collecting every `macro_invocation`, tracking the nearest
+/// enclosing function/impl as it descends the same way `extract_impl_phase1`/
+/// `extract_function_phase1` do.
+fn extract_macro_calls(
+    node: &Node,
+    source: &[u8],
+    current_function: Option<&str>,
+    current_impl: Option<&str>,
+    result: &mut ParsedFile,
+) {
+    if node.kind() == "macro_invocation" {
+        if let Some(macro_node) = node.child_by_field_name("macro") {
+            let path = node_text(&macro_node, source);
+            let line = node.start_position().row + 1;
+            let is_builtin = !path.contains("::") && BUILTIN_MACROS.contains(&path.as_str());
+
+            result.macro_calls.push(MacroCall {
+                path,
+                line,
+                enclosing_function: current_function.map(str::to_string),
+                enclosing_impl: current_impl.map(str::to_string),
+                is_builtin,
+            });
+        }
+    }
+
+    let child_function = match node.kind() {
+        "function_item" => find_child_text(node, "identifier", source),
+        _ => None,
+    };
+    let child_impl = match node.kind() {
+        "impl_item" => node
+            .child_by_field_name("type")
+            .map(|n| extract_base_type_name(&node_text(&n, source))),
+        _ => None,
+    };
+
+    let next_function = child_function.as_deref().or(current_function);
+    let next_impl = child_impl.as_deref().or(current_impl);
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        extract_macro_calls(&child, source, next_function, next_impl, result);
+    }
+}
+
+fn extract_unsafe_blocks(
+    node: &Node,
+    source: &[u8],
+    containing_fn: Option<&str>,
+    safety: &mut SafetyInfo,
+) {
+    let current_fn = if node.kind() == "function_item" {
+        find_child_text(node, "identifier", source)
+    } else {
+        containing_fn.map(str::to_string)
+    };
+
+    if node.kind() == "unsafe_block" {
+        let mut operations = Vec::new();
+        collect_unsafe_operations(node, source, &mut operations);
+        let safety_comment = find_safety_comment(node, source);
+        let unjustified = !operations.is_empty() && safety_comment.is_none();
+
+        safety.unsafe_blocks.push(UnsafeBlock {
+            line: node.start_position().row + 1,
+            containing_function: current_fn.clone(),
+            operations,
+            safety_comment,
+            unjustified,
+        });
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        extract_unsafe_blocks(&child, source, current_fn.as_deref(), safety);
+    }
+}
+
+fn collect_unsafe_operations(node: &Node, source: &[u8], operations: &mut Vec<UnsafeOperation>) {
+    match node.kind() {
+        "dereference_expression" => {
+            let text = node_text(node, source);
+            if text.starts_with('*') {
+                operations.push(UnsafeOperation::RawPointerDeref);
+            }
+        }
+        "call_expression" => {
+            if let Some(func) = node.child_by_field_name("function") {
+                let text = node_text(&func, source);
+                if text.contains("::") && !text.starts_with("std::") && !text.starts_with("core::")
                 {
-                    return true;
+                    operations.push(UnsafeOperation::UnsafeFunctionCall(text));
+                }
+            }
+        }
+        "field_expression" => {
+            let text = node_text(node, source);
+            if text.contains("union") {
+                operations.push(UnsafeOperation::UnionFieldAccess);
+            }
+        }
+        "asm_item" | "asm_block" => {
+            operations.push(UnsafeOperation::InlineAssembly);
+        }
+        "identifier" => {
+            let text = node_text(node, source);
+            if text.chars().all(|c| c.is_uppercase() || c == '_') && text.len() > 1 {
+                if let Some(parent) = node.parent() {
+                    if parent.kind() == "assignment_expression"
+                        || parent.kind() == "compound_assignment_expr"
+                    {
+                        operations.push(UnsafeOperation::MutableStaticAccess(text));
+                    }
                 }
             }
         }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_unsafe_operations(&child, source, operations);
     }
-    false
 }
 
-fn extract_const(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    let visibility = extract_visibility(node, source);
-    let name = find_child_text(node, "identifier", source).unwrap_or_default();
-    let const_type = node
-        .child_by_field_name("type")
-        .map(|n| node_text(&n, source))
-        .unwrap_or_default();
-    let line = node.start_position().row + 1;
+/// Walks backwards over the `line_comment` siblings immediately preceding `node` (no blank line
+/// in between), and if their joined, marker-stripped text starts with `SAFETY:`/`Safety:`,
+/// returns the rationale that follows. Only looks at comments directly attached to `node` itself,
+/// so a `SAFETY:` note above `let x = unsafe { ... };` rather than above the block isn't found —
+/// the same "plain syntax only" tradeoff [`bind_let_declaration`] makes for patterns.
+fn find_safety_comment(node: &Node, source: &[u8]) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut current = node.prev_sibling();
+    let mut expected_row = node.start_position().row;
+
+    while let Some(sibling) = current {
+        if sibling.kind() != "line_comment" || sibling.end_position().row + 1 != expected_row {
+            break;
+        }
+        lines.push(node_text(&sibling, source));
+        expected_row = sibling.start_position().row;
+        current = sibling.prev_sibling();
+    }
 
-    let value = node
-        .child_by_field_name("value")
-        .and_then(|n| extract_simple_value(&n, source));
+    if lines.is_empty() {
+        return None;
+    }
 
-    result.symbols.symbols.push(Symbol {
-        name,
-        kind: SymbolKind::Const { const_type, value },
-        visibility,
-        generics: String::new(),
-        line,
-        is_async: false,
-        is_unsafe: false,
-        is_const: true,
-        re_exported_as: None,
-    });
-}
+    lines.reverse();
+    let text = lines
+        .iter()
+        .map(|line| line.trim_start_matches('/').trim())
+        .collect::<Vec<_>>()
+        .join(" ");
 
-fn extract_static(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    let visibility = extract_visibility(node, source);
-    let name = find_child_text(node, "identifier", source).unwrap_or_default();
-    let static_type = node
-        .child_by_field_name("type")
-        .map(|n| node_text(&n, source))
-        .unwrap_or_default();
-    let line = node.start_position().row + 1;
+    let rest = text
+        .strip_prefix("SAFETY:")
+        .or_else(|| text.strip_prefix("Safety:"))?;
 
-    let is_mutable = node
-        .children(&mut node.walk())
-        .any(|c| c.kind() == "mutable_specifier");
+    Some(rest.trim().to_string())
+}
 
-    let value = node
-        .child_by_field_name("value")
-        .and_then(|n| extract_simple_value(&n, source));
+const LOCK_GUARD_METHODS: &[&str] = &[".lock(", ".read(", ".write(", ".borrow_mut("];
 
-    result.symbols.symbols.push(Symbol {
-        name,
-        kind: SymbolKind::Static {
-            static_type,
-            is_mutable,
-            value,
-        },
-        visibility,
-        generics: String::new(),
-        line,
-        is_async: false,
-        is_unsafe: false,
-        is_const: false,
-        re_exported_as: None,
-    });
+/// One lock guard bound by a `let` in the block currently being scanned: `name` is `None` for a
+/// pattern [`lock_guard_binding`] doesn't recognize as a plain identifier (and so can never match
+/// an explicit `drop(name)` call), but the guard is still tracked for the across-await check.
+#[derive(Clone)]
+struct LiveGuard {
+    name: Option<String>,
+    expr: String,
+    line: usize,
 }
 
-fn extract_simple_value(node: &Node, source: &[u8]) -> Option<String> {
-    let text = node_text(node, source);
-    let trimmed = text.trim();
+fn extract_guard_await_conflicts(
+    node: &Node,
+    source: &[u8],
+    conflicts: &mut Vec<GuardHeldAcrossAwait>,
+) {
+    if node.kind() == "function_item" && has_modifier(node, "async") {
+        if let Some(name) = find_child_text(node, "identifier", source) {
+            if let Some(body) = node.child_by_field_name("body") {
+                let mut guards = Vec::new();
+                scan_for_guard_conflicts(&body, source, &name, &mut guards, conflicts);
+            }
+        }
+    }
 
-    if trimmed.contains('\n') || trimmed.len() > 80 {
-        return None;
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        extract_guard_await_conflicts(&child, source, conflicts);
     }
+}
 
+/// Walks `node`'s subtree looking for `.await` points reached while a lock guard from `guards`
+/// is still alive. A `block` node scans with its own clone of `guards`, so a guard bound inside a
+/// nested block (and any conflicts it causes) never leaks back into the enclosing scope once that
+/// block's walk returns — it simply goes out of scope there, the same as the real binding does.
+fn scan_for_guard_conflicts(
+    node: &Node,
+    source: &[u8],
+    function_name: &str,
+    guards: &mut Vec<LiveGuard>,
+    conflicts: &mut Vec<GuardHeldAcrossAwait>,
+) {
     match node.kind() {
-        "integer_literal" | "float_literal" | "string_literal" | "char_literal"
-        | "boolean_literal" | "raw_string_literal" => Some(trimmed.to_string()),
-        "unary_expression" | "binary_expression" => {
-            if trimmed.len() <= 40 {
-                Some(trimmed.to_string())
-            } else {
-                None
+        "block" => {
+            let mut scope = guards.clone();
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                scan_for_guard_conflicts(&child, source, function_name, &mut scope, conflicts);
             }
+            return;
         }
-        "call_expression" | "struct_expression" => {
-            if trimmed.len() <= 80 {
-                Some(trimmed.to_string())
-            } else {
-                None
+        "let_declaration" => {
+            if let Some(value) = node.child_by_field_name("value") {
+                scan_for_guard_conflicts(&value, source, function_name, guards, conflicts);
+                if let Some(guard) = lock_guard_binding(node, &value, source) {
+                    guards.push(guard);
+                }
             }
+            return;
         }
-        "array_expression" => {
-            if trimmed.len() <= 60 {
-                Some(trimmed.to_string())
-            } else {
-                None
+        "call_expression" => {
+            if let Some(dropped) = dropped_guard_name(node, source) {
+                guards.retain(|guard| guard.name.as_deref() != Some(dropped.as_str()));
             }
         }
-        "identifier" | "scoped_identifier" => Some(trimmed.to_string()),
-        _ => {
-            if trimmed.len() <= 50 && !trimmed.contains("||") && !trimmed.contains("&&") {
-                Some(trimmed.to_string())
-            } else {
-                None
+        "await_expression" => {
+            if !guards.is_empty() {
+                let await_line = node.start_position().row + 1;
+                for guard in guards.iter() {
+                    conflicts.push(GuardHeldAcrossAwait {
+                        guard_line: guard.line,
+                        guard_expr: guard.expr.clone(),
+                        await_line,
+                        containing_function: function_name.to_string(),
+                    });
+                }
             }
         }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        scan_for_guard_conflicts(&child, source, function_name, guards, conflicts);
     }
 }
 
-fn extract_type_alias(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    let visibility = extract_visibility(node, source);
-    let name = find_child_text(node, "type_identifier", source).unwrap_or_default();
-    let generics = extract_generics(node, source);
-    let aliased_type = node
-        .child_by_field_name("type")
-        .map(|n| node_text(&n, source))
-        .unwrap_or_default();
-    let line = node.start_position().row + 1;
+/// Recognizes a `let` binding whose initializer contains a `.lock()`/`.read()`/`.write()`/
+/// `.borrow_mut()` call anywhere in its text — only a plain-identifier pattern gets a `name`, matching
+/// [`bind_let_declaration`]'s own limitation, but the guard is tracked either way since an
+/// unnamed guard still lives (and can still be held across an await) for the rest of its block.
+fn lock_guard_binding(let_decl: &Node, value: &Node, source: &[u8]) -> Option<LiveGuard> {
+    let text = node_text(value, source);
+    if !LOCK_GUARD_METHODS.iter().any(|method| text.contains(method)) {
+        return None;
+    }
 
-    result.symbols.symbols.push(Symbol {
+    let name = let_decl
+        .child_by_field_name("pattern")
+        .filter(|pattern| pattern.kind() == "identifier")
+        .map(|pattern| node_text(&pattern, source));
+
+    Some(LiveGuard {
         name,
-        kind: SymbolKind::TypeAlias { aliased_type },
-        visibility,
-        generics,
-        line,
-        is_async: false,
-        is_unsafe: false,
-        is_const: false,
-        re_exported_as: None,
-    });
+        expr: text,
+        line: let_decl.start_position().row + 1,
+    })
 }
 
-fn extract_mod(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    let visibility = extract_visibility(node, source);
-    let name = find_child_text(node, "identifier", source).unwrap_or_default();
-    let line = node.start_position().row + 1;
+/// Matches a bare `drop(name)` call, returning the dropped identifier's text.
+fn dropped_guard_name(call: &Node, source: &[u8]) -> Option<String> {
+    let func = call.child_by_field_name("function")?;
+    if node_text(&func, source) != "drop" {
+        return None;
+    }
+
+    let args = call.child_by_field_name("arguments")?;
+    let mut cursor = args.walk();
+    let mut identifiers = args
+        .children(&mut cursor)
+        .filter(|child| child.kind() == "identifier");
+    let only_arg = identifiers.next()?;
+    if identifiers.next().is_some() {
+        return None;
+    }
 
+    Some(node_text(&only_arg, source))
+}
+
+/// Walks every block of sibling nodes looking for a run of `///`/`/** */` doc comments
+/// immediately followed by an item, and records one [`ItemDoc`] per such item (recursing into
+/// the item itself afterwards, so nested items — e.g. a `mod`'s contents — get their own docs).
+fn extract_item_docs(node: &Node, source: &[u8], docs: &mut Vec<ItemDoc>) {
     let mut cursor = node.walk();
-    let has_cfg_test = node.children(&mut cursor).any(|child| {
-        if child.kind() == "attribute_item" {
+    let children: Vec<Node> = node.children(&mut cursor).collect();
+
+    let mut index = 0;
+    while index < children.len() {
+        let child = children[index];
+
+        if child.kind() == "line_comment" || child.kind() == "block_comment" {
             let text = node_text(&child, source);
-            text.contains("cfg(test)")
-        } else {
-            false
+
+            if text.starts_with("///") || text.starts_with("/**") {
+                let mut raw_lines = Vec::new();
+                let mut doc_index = index;
+                let start_line = child.start_position().row + 1;
+
+                while doc_index < children.len() {
+                    let doc_node = children[doc_index];
+                    let doc_text = node_text(&doc_node, source);
+
+                    if doc_text.starts_with("///") {
+                        raw_lines.push(strip_line_doc_marker(&doc_text));
+                        doc_index += 1;
+                    } else if doc_text.starts_with("/**") {
+                        let content = doc_text
+                            .strip_prefix("/**")
+                            .and_then(|s| s.strip_suffix("*/"))
+                            .unwrap_or("");
+                        raw_lines.extend(content.lines().map(strip_block_doc_marker));
+                        doc_index += 1;
+                        break;
+                    } else {
+                        break;
+                    }
+                }
+
+                if doc_index < children.len() {
+                    let item = children[doc_index];
+                    let (name, kind) = item_name_and_kind(&item, source);
+
+                    if !name.is_empty() {
+                        let full_doc = raw_lines
+                            .iter()
+                            .map(|line| line.trim())
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        let summary = full_doc
+                            .split('.')
+                            .next()
+                            .unwrap_or(&full_doc)
+                            .trim()
+                            .to_string();
+
+                        docs.push(ItemDoc {
+                            item_name: name,
+                            item_kind: kind,
+                            line: item.start_position().row + 1,
+                            summary,
+                            has_examples: full_doc.contains("# Example") || full_doc.contains("```"),
+                            has_panics_section: full_doc.contains("# Panic"),
+                            has_safety_section: full_doc.contains("# Safety"),
+                            has_errors_section: full_doc.contains("# Error"),
+                            doc_tests: parse_doc_tests(&raw_lines, start_line),
+                            doc_links: parse_doc_links(&raw_lines, start_line),
+                            rustdoc_id: None,
+                            qualified_path: None,
+                        });
+                    }
+
+                    extract_item_docs(&item, source, docs);
+                }
+
+                index = doc_index;
+                continue;
+            }
         }
-    });
 
-    if has_cfg_test {
-        result.has_test_module = true;
+        extract_item_docs(&child, source, docs);
+        index += 1;
     }
+}
 
-    if node.child_by_field_name("body").is_none() {
-        result.symbols.symbols.push(Symbol {
-            name,
-            kind: SymbolKind::Mod,
-            visibility,
-            generics: String::new(),
-            line,
-            is_async: false,
-            is_unsafe: false,
-            is_const: false,
-            re_exported_as: None,
-        });
+/// Strips the `///` marker and, matching rustdoc's own convention, exactly one following space
+/// (not a full trim) so a fenced code block's indentation survives into the raw line.
+fn strip_line_doc_marker(text: &str) -> String {
+    let after = text.strip_prefix("///").unwrap_or(text);
+    after.strip_prefix(' ').unwrap_or(after).to_string()
+}
+
+/// Strips a `/** */` block comment's conventional leading `* ` (or bare `*`) per inner line,
+/// falling back to the line unchanged when it isn't aligned that way.
+fn strip_block_doc_marker(line: &str) -> String {
+    let trimmed = line.trim_start();
+    if let Some(rest) = trimmed.strip_prefix("* ") {
+        rest.to_string()
+    } else if let Some(rest) = trimmed.strip_prefix('*') {
+        rest.to_string()
+    } else {
+        line.to_string()
     }
 }
 
-fn extract_macro(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    let name = find_child_text(node, "identifier", source).unwrap_or_default();
-    let line = node.start_position().row + 1;
+fn item_name_and_kind(node: &Node, source: &[u8]) -> (String, String) {
+    let kind = match node.kind() {
+        "function_item" => "fn",
+        "struct_item" => "struct",
+        "enum_item" => "enum",
+        "trait_item" => "trait",
+        "impl_item" => "impl",
+        "const_item" => "const",
+        "static_item" => "static",
+        "type_item" => "type",
+        "mod_item" => "mod",
+        "macro_definition" => "macro",
+        _ => return (String::new(), String::new()),
+    };
 
-    let is_exported = if let Some(parent) = node.parent() {
-        let mut cursor = parent.walk();
-        parent.children(&mut cursor).any(|sibling| {
-            if sibling.end_byte() < node.start_byte() && sibling.kind() == "attribute_item" {
-                let text = node_text(&sibling, source);
-                text.contains("macro_export")
-            } else {
-                false
+    let name = find_child_text(node, "identifier", source)
+        .or_else(|| find_child_text(node, "type_identifier", source))
+        .unwrap_or_default();
+
+    (name, kind.to_string())
+}
+
+/// Scans `lines` (one entry per source line, comment markers already stripped but indentation
+/// preserved) for fenced code blocks opened with `` ``` `` or `~~~`, classifying the standard
+/// rustdoc fence attributes and de-indenting the body relative to the fence's own column.
+fn parse_doc_tests(lines: &[String], start_line: usize) -> Vec<DocTest> {
+    let mut tests = Vec::new();
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = &lines[index];
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        let fence_char = trimmed.chars().next().filter(|c| *c == '`' || *c == '~');
+
+        let Some(fence_char) = fence_char else {
+            index += 1;
+            continue;
+        };
+
+        let fence_len = trimmed.chars().take_while(|c| *c == fence_char).count();
+        if fence_len < 3 {
+            index += 1;
+            continue;
+        }
+
+        let info_string = trimmed[fence_len..].trim().to_string();
+        let fence_line = start_line + index;
+
+        let mut full_lines = Vec::new();
+        let mut visible_lines = Vec::new();
+        let mut cursor = index + 1;
+
+        while cursor < lines.len() {
+            let body_trimmed = lines[cursor].trim_start();
+            let closes = body_trimmed.chars().next() == Some(fence_char)
+                && body_trimmed.chars().all(|c| c == fence_char)
+                && body_trimmed.chars().count() >= fence_len;
+            if closes {
+                break;
             }
-        })
-    } else {
-        false
-    };
 
-    result.symbols.macros.push(MacroInfo {
-        name,
-        is_exported,
-        line,
-    });
+            let dedented = dedent(&lines[cursor], indent);
+            if !dedented.trim_start().starts_with("# ") {
+                visible_lines.push(dedented.clone());
+            }
+            full_lines.push(dedented);
+            cursor += 1;
+        }
+
+        let (ignore, no_run, should_panic, compile_fail, edition) =
+            classify_doctest_attrs(&info_string);
+
+        tests.push(DocTest {
+            line: fence_line,
+            info_string,
+            ignore,
+            no_run,
+            should_panic,
+            compile_fail,
+            edition,
+            visible_body: visible_lines.join("\n"),
+            full_body: full_lines.join("\n"),
+        });
+
+        index = if cursor < lines.len() { cursor + 1 } else { lines.len() };
+    }
+
+    tests
 }
 
-fn extract_use(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    let visibility = extract_visibility(node, source);
-    let line = node.start_position().row + 1;
+/// Strips up to `indent` leading spaces from `line` (fewer if it has less indentation than
+/// that), de-indenting a fenced block's body relative to the column the fence marker itself sat
+/// at — the same de-indent a list item's nested code block needs relative to the list marker.
+fn dedent(line: &str, indent: usize) -> String {
+    let strip = line.chars().take(indent).take_while(|c| *c == ' ').count();
+    line.chars().skip(strip).collect()
+}
 
-    if let Some(arg) = node.child_by_field_name("argument") {
-        let path = node_text(&arg, source);
+/// Classifies the standard rustdoc fence attributes found in a fenced block's info string
+/// (`ignore`, `no_run`, `should_panic`, `compile_fail`, `edition2018`/`edition2021`) — a bare
+/// language tag like `rust`/`text` is accepted but carries no flag of its own.
+fn classify_doctest_attrs(info_string: &str) -> (bool, bool, bool, bool, Option<String>) {
+    let mut ignore = false;
+    let mut no_run = false;
+    let mut should_panic = false;
+    let mut compile_fail = false;
+    let mut edition = None;
+
+    for token in info_string.split(|c: char| c == ',' || c.is_whitespace()) {
+        match token.trim() {
+            "ignore" => ignore = true,
+            "no_run" => no_run = true,
+            "should_panic" => should_panic = true,
+            "compile_fail" => compile_fail = true,
+            "edition2018" => edition = Some("2018".to_string()),
+            "edition2021" => edition = Some("2021".to_string()),
+            _ => {}
+        }
+    }
 
-        if visibility != Visibility::Private {
-            result.re_exports.push(ReExport {
-                source_path: path.clone(),
-                visibility: visibility.clone(),
-                line,
-            });
+    (ignore, no_run, should_panic, compile_fail, edition)
+}
+
+/// Scans `lines` for the doc-link forms rustdoc recognizes: the intra-doc shorthand
+/// `` [`Type`] ``/`` [`module::Item`] `` (code text with no following `(`/`[`), the reference
+/// form `[text][Type]`, and a plain markdown `[text](url)` link. `resolved` starts `false` on
+/// every link produced here — see [`resolve_doc_links`].
+fn parse_doc_links(lines: &[String], start_line: usize) -> Vec<DocLink> {
+    let mut links = Vec::new();
+
+    for (offset, line) in lines.iter().enumerate() {
+        let chars: Vec<char> = line.chars().collect();
+        let mut index = 0;
+
+        while index < chars.len() {
+            if chars[index] != '[' {
+                index += 1;
+                continue;
+            }
+
+            let Some(close) = find_matching_bracket(&chars, index, '[', ']') else {
+                index += 1;
+                continue;
+            };
+
+            let inner: String = chars[index + 1..close].iter().collect();
+            let next = close + 1;
+
+            if chars.get(next) == Some(&'(') {
+                if let Some(paren_close) = find_matching_bracket(&chars, next, '(', ')') {
+                    let url: String = chars[next + 1..paren_close].iter().collect();
+                    links.push(DocLink {
+                        is_external: is_external_target(&url),
+                        target_path: url,
+                        display_text: strip_code_marks(&inner),
+                        line: start_line + offset,
+                        resolved: false,
+                        resolved_target: None,
+                    });
+                    index = paren_close + 1;
+                    continue;
+                }
+            } else if chars.get(next) == Some(&'[') {
+                if let Some(ref_close) = find_matching_bracket(&chars, next, '[', ']') {
+                    let target = strip_code_marks(&chars[next + 1..ref_close].iter().collect::<String>());
+                    links.push(DocLink {
+                        is_external: is_external_target(&target),
+                        target_path: target,
+                        display_text: strip_code_marks(&inner),
+                        line: start_line + offset,
+                        resolved: false,
+                        resolved_target: None,
+                    });
+                    index = ref_close + 1;
+                    continue;
+                }
+            } else if inner.starts_with('`') && inner.ends_with('`') && inner.len() >= 2 {
+                let target = strip_code_marks(&inner);
+                links.push(DocLink {
+                    is_external: false,
+                    display_text: target.clone(),
+                    target_path: target,
+                    line: start_line + offset,
+                    resolved: false,
+                    resolved_target: None,
+                });
+                index = close + 1;
+                continue;
+            }
+
+            index += 1;
         }
+    }
 
-        result.imports.push(ImportInfo { path, line });
+    links
+}
+
+/// Finds the index of the `close` bracket matching the `open` bracket at `open_index`, tracking
+/// nesting depth so e.g. a markdown link whose display text itself contains brackets still
+/// resolves to its real closing bracket.
+fn find_matching_bracket(chars: &[char], open_index: usize, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    for (index, &c) in chars.iter().enumerate().skip(open_index) {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(index);
+            }
+        }
     }
+    None
 }
 
-fn extract_attribute(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    let text = node_text(node, source);
-    let line = node.start_position().row + 1;
+fn strip_code_marks(text: &str) -> String {
+    text.trim().trim_matches('`').to_string()
+}
 
-    if text.contains("#[cfg(") || text.contains("#[cfg_attr(") {
-        let cfg_content = extract_cfg_content(&text);
-        if let Some(condition) = cfg_content {
-            result.cfgs.push(CfgInfo { condition, line });
+fn is_external_target(target: &str) -> bool {
+    target.contains("://") || target.starts_with("mailto:")
+}
+
+/// Resolves every non-external [`DocLink`] in `item_docs` against the item names this same file
+/// declared (matching on the link target's last `::`-separated segment, so `` [`safety::ItemDoc`] ``
+/// resolves against an `ItemDoc` harvested elsewhere in the file), marking each `resolved`.
+/// Crate-wide resolution (matching across files) is a separate, larger problem — see
+/// [`crate::traitindex`] for the equivalent whole-crate merge this file's per-file pass doesn't
+/// attempt.
+fn resolve_doc_links(item_docs: &mut [ItemDoc]) {
+    let known_names: HashSet<String> = item_docs.iter().map(|doc| doc.item_name.clone()).collect();
+
+    for doc in item_docs.iter_mut() {
+        for link in &mut doc.doc_links {
+            if link.is_external {
+                continue;
+            }
+            let simple_name = link.target_path.rsplit("::").next().unwrap_or(&link.target_path);
+            link.resolved = known_names.contains(simple_name);
         }
     }
 }
 
-fn extract_derives_for_item(node: &Node, source: &[u8]) -> Vec<Vec<String>> {
-    let mut derives = Vec::new();
+fn extract_test_info(root: &Node, source: &[u8], result: &mut ParsedFile) {
+    collect_test_functions(root, source, &mut result.test_info);
+    collect_test_modules(root, source, &mut result.test_info);
+
+    let mut declared_items = HashSet::new();
+    collect_declared_item_names(root, source, &mut declared_items);
+    infer_tested_items(&mut result.test_info, &declared_items);
+}
+
+fn collect_test_functions(node: &Node, source: &[u8], test_info: &mut TestInfo) {
     let mut cursor = node.walk();
+    let children: Vec<Node> = node.children(&mut cursor).collect();
 
-    let parent = node.parent();
-    if let Some(parent) = parent {
-        let mut sibling_cursor = parent.walk();
-        for sibling in parent.children(&mut sibling_cursor) {
-            if sibling.end_byte() >= node.start_byte() {
-                break;
+    for index in 0..children.len() {
+        let child = children[index];
+
+        if child.kind() == "function_item" {
+            let mut is_test = false;
+            let mut is_ignored = false;
+            let mut should_panic = false;
+            let is_async = has_modifier(&child, "async");
+
+            for prev_index in (0..index).rev() {
+                let prev = children[prev_index];
+                if prev.kind() != "attribute_item" {
+                    break;
+                }
+                let attr_text = node_text(&prev, source);
+                if attr_text.contains("#[test]")
+                    || attr_text.contains("#[tokio::test")
+                    || attr_text.contains("#[async_std::test")
+                {
+                    is_test = true;
+                }
+                if attr_text.contains("#[ignore") {
+                    is_ignored = true;
+                }
+                if attr_text.contains("#[should_panic") {
+                    should_panic = true;
+                }
             }
-            if sibling.kind() == "attribute_item" {
-                let text = node_text(&sibling, source);
-                if text.contains("#[derive(") {
-                    if let Some(traits) = extract_derive_traits(&text) {
-                        derives.push(traits);
-                    }
+
+            if is_test {
+                let name = find_child_text(&child, "identifier", source).unwrap_or_default();
+                let line = child.start_position().row + 1;
+                let tested_function = infer_tested_function(&name);
+
+                let mut called_functions = Vec::new();
+                if let Some(body) = child.child_by_field_name("body") {
+                    collect_called_functions(&body, source, &mut called_functions);
                 }
+
+                test_info.test_functions.push(TestFunction {
+                    name,
+                    line,
+                    is_async,
+                    is_ignored,
+                    should_panic,
+                    tested_function,
+                    called_functions,
+                });
             }
         }
+
+        collect_test_functions(&child, source, test_info);
     }
+}
 
-    for child in node.children(&mut cursor) {
-        if child.kind() == "attribute_item" {
-            let text = node_text(&child, source);
-            if text.contains("#[derive(") {
-                if let Some(traits) = extract_derive_traits(&text) {
-                    derives.push(traits);
+/// Collects the callee name from every `call_expression`/`method_call_expression` in `node`'s
+/// subtree — the rightmost path segment for a plain call (so `crate::foo::bar()` yields `bar`),
+/// the method name for a method call.
+fn collect_called_functions(node: &Node, source: &[u8], called: &mut Vec<String>) {
+    match node.kind() {
+        "call_expression" => {
+            if let Some(function) = node.child_by_field_name("function") {
+                if let Some(name) = rightmost_path_segment(&function, source) {
+                    called.push(name);
                 }
             }
         }
+        "method_call_expression" => {
+            if let Some(name) = node.child_by_field_name("name") {
+                called.push(node_text(&name, source));
+            }
+        }
+        _ => {}
     }
 
-    derives
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_called_functions(&child, source, called);
+    }
 }
 
-fn extract_derive_traits(attr_text: &str) -> Option<Vec<String>> {
-    let start = attr_text.find("#[derive(")? + 9;
-    let end = attr_text[start..].find(')')? + start;
-    let content = &attr_text[start..end];
-
-    let traits: Vec<String> = content
-        .split(',')
-        .map(|s| s.trim().to_string())
-        .filter(|s| !s.is_empty())
-        .collect();
-
-    if traits.is_empty() {
-        None
-    } else {
-        Some(traits)
+fn rightmost_path_segment(node: &Node, source: &[u8]) -> Option<String> {
+    match node.kind() {
+        "scoped_identifier" => node
+            .child_by_field_name("name")
+            .map(|name| node_text(&name, source)),
+        "field_expression" => node
+            .child_by_field_name("field")
+            .map(|field| node_text(&field, source)),
+        "identifier" | "field_identifier" | "type_identifier" => Some(node_text(node, source)),
+        _ => node_text(node, source).rsplit("::").next().map(str::to_string),
     }
 }
 
-fn extract_cfg_content(attr_text: &str) -> Option<String> {
-    if let Some(start) = attr_text.find("#[cfg(") {
-        let start = start + 6;
-        let mut depth = 1;
-        let mut end = start;
-        for (index, char) in attr_text[start..].char_indices() {
-            match char {
-                '(' => depth += 1,
-                ')' => {
-                    depth -= 1;
-                    if depth == 0 {
-                        end = start + index;
-                        break;
-                    }
+fn collect_test_modules(node: &Node, source: &[u8], test_info: &mut TestInfo) {
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.children(&mut cursor).collect();
+
+    for index in 0..children.len() {
+        let child = children[index];
+
+        if child.kind() == "mod_item" {
+            let name = find_child_text(&child, "identifier", source).unwrap_or_default();
+
+            let mut is_test_mod = name == "tests" || name == "test";
+
+            for prev_index in (0..index).rev() {
+                let prev = children[prev_index];
+                if prev.kind() != "attribute_item" {
+                    break;
                 }
-                _ => {}
+                let attr_text = node_text(&prev, source);
+                if attr_text.contains("#[cfg(test)]") {
+                    is_test_mod = true;
+                    break;
+                }
+            }
+
+            if is_test_mod {
+                let line = child.start_position().row + 1;
+                let test_count = count_tests_in_module(&child, source);
+                test_info.test_modules.push(TestModule {
+                    name,
+                    line,
+                    test_count,
+                });
             }
         }
-        return Some(attr_text[start..end].to_string());
+
+        collect_test_modules(&child, source, test_info);
     }
-    None
 }
 
-fn extract_visibility(node: &Node, source: &[u8]) -> Visibility {
+fn count_tests_in_module(node: &Node, source: &[u8]) -> usize {
+    let mut count = 0;
     let mut cursor = node.walk();
+
     for child in node.children(&mut cursor) {
-        if child.kind() == "visibility_modifier" {
+        if child.kind() == "attribute_item" {
             let text = node_text(&child, source);
-            return match text.as_str() {
-                "pub" => Visibility::Public,
-                _ if text.starts_with("pub(crate)") => Visibility::PubCrate,
-                _ if text.starts_with("pub(super)") => Visibility::PubSuper,
-                _ if text.starts_with("pub(self)") => Visibility::Private,
-                _ if text.starts_with("pub(in") => Visibility::PubIn(text),
-                _ => Visibility::Public,
-            };
+            if text.contains("#[test]")
+                || text.contains("#[tokio::test")
+                || text.contains("#[async_std::test")
+            {
+                count += 1;
+            }
         }
+        count += count_tests_in_module(&child, source);
     }
-    Visibility::Private
+
+    count
 }
 
-fn extract_generics(node: &Node, source: &[u8]) -> String {
-    if let Some(type_params) = node.child_by_field_name("type_parameters") {
-        return node_text(&type_params, source);
+/// Weak fallback used only when a test calls no function this file declares: strips a leading
+/// `test_` and a trailing `_works`/`_succeeds`/`_success`/`_fails`/`_error`/`_panics`, guessing
+/// the tested item is whatever's left.
+fn infer_tested_function(test_name: &str) -> Option<String> {
+    let name = test_name.strip_prefix("test_")?;
+
+    if name.ends_with("_works") || name.ends_with("_succeeds") || name.ends_with("_success") {
+        let base = name
+            .strip_suffix("_works")
+            .or_else(|| name.strip_suffix("_succeeds"))
+            .or_else(|| name.strip_suffix("_success"))
+            .unwrap_or(name);
+        return Some(base.to_string());
     }
 
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        if child.kind() == "type_parameters" {
-            return node_text(&child, source);
-        }
+    if name.ends_with("_fails") || name.ends_with("_error") || name.ends_with("_panics") {
+        let base = name
+            .strip_suffix("_fails")
+            .or_else(|| name.strip_suffix("_error"))
+            .or_else(|| name.strip_suffix("_panics"))
+            .unwrap_or(name);
+        return Some(base.to_string());
     }
 
-    String::new()
+    Some(name.to_string())
 }
 
-fn extract_function_signature(node: &Node, source: &[u8]) -> String {
-    let mut parts = Vec::new();
-
-    if let Some(params) = node.child_by_field_name("parameters") {
-        parts.push(node_text(&params, source));
-    }
-
-    if let Some(return_type) = node.child_by_field_name("return_type") {
-        let ret = node_text(&return_type, source);
-        parts.push(format!(" -> {}", ret.trim_start_matches("->")));
+/// Recursively collects the name of every item `item_name_and_kind` recognizes (functions,
+/// structs, enums, traits, impls, consts, statics, type aliases, modules, macros) anywhere in
+/// `node`'s subtree, the "locally defined" half of the call-then-defined invariant
+/// `infer_tested_items` checks.
+fn collect_declared_item_names(node: &Node, source: &[u8], names: &mut HashSet<String>) {
+    let (name, kind) = item_name_and_kind(node, source);
+    if !name.is_empty() && !kind.is_empty() {
+        names.insert(name);
     }
 
-    parts.join("")
-}
-
-fn has_modifier(node: &Node, modifier: &str) -> bool {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        if child.kind() == modifier {
-            return true;
-        }
+        collect_declared_item_names(&child, source, names);
     }
-    false
 }
 
-fn find_child_text(node: &Node, kind: &str, source: &[u8]) -> Option<String> {
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        if child.kind() == kind {
-            return Some(node_text(&child, source));
-        }
-        if child.kind() == "name" {
-            if let Some(name_child) = child.child(0) {
-                if name_child.kind() == kind {
-                    return Some(node_text(&name_child, source));
-                }
+/// Maps each tested item to the tests that cover it. A test's `called_functions` are checked
+/// against `declared_items` first — a test counts as covering an item only if it both calls that
+/// name and the file actually declares it, the invariant the name-only heuristic couldn't
+/// enforce. `infer_tested_function`'s name-guess is only consulted when a test calls nothing the
+/// file declares.
+fn infer_tested_items(test_info: &mut TestInfo, declared_items: &HashSet<String>) {
+    let mut item_tests: HashMap<String, Vec<String>> = HashMap::new();
+
+    for test in &test_info.test_functions {
+        let mut called_declared_items: Vec<&String> = test
+            .called_functions
+            .iter()
+            .filter(|callee| declared_items.contains(*callee))
+            .collect();
+        called_declared_items.dedup();
+
+        if called_declared_items.is_empty() {
+            if let Some(tested) = &test.tested_function {
+                item_tests.entry(tested.clone()).or_default().push(test.name.clone());
             }
-            return Some(node_text(&child, source));
+            continue;
         }
-    }
-    None
-}
-
-fn node_text(node: &Node, source: &[u8]) -> String {
-    node.utf8_text(source).unwrap_or("").to_string()
-}
 
-fn extract_identifier_locations(root: &Node, source: &[u8], result: &mut ParsedFile) {
-    collect_identifiers(root, source, &mut result.identifier_locations);
-}
-
-fn collect_identifiers(node: &Node, source: &[u8], locations: &mut Vec<(String, usize)>) {
-    if node.kind() == "type_identifier" || node.kind() == "identifier" {
-        let name = node_text(node, source);
-        if super::is_pascal_case(&name) {
-            let line = node.start_position().row + 1;
-            locations.push((name, line));
+        for item in called_declared_items {
+            item_tests.entry(item.clone()).or_default().push(test.name.clone());
         }
     }
 
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        collect_identifiers(&child, source, locations);
+    for (item_name, test_names) in item_tests {
+        let coverage_hints = if test_names.iter().any(|n| n.contains("error") || n.contains("fail")) {
+            vec!["error path".to_string()]
+        } else {
+            vec![]
+        };
+
+        test_info.tested_items.push(TestedItem {
+            item_name,
+            test_names,
+            coverage_hints,
+        });
     }
 }
 
 fn extract_phase1_data(root: &Node, source: &[u8], file_path: &str, result: &mut ParsedFile) {
     let mut cursor = root.walk();
-    let mut snippet_budget = MAX_TOTAL_SNIPPET_BUDGET / 20;
 
     for child in root.children(&mut cursor) {
         match child.kind() {
             "function_item" => {
-                extract_function_phase1(
-                    &child,
-                    source,
-                    file_path,
-                    None,
-                    result,
-                    &mut snippet_budget,
-                );
+                extract_function_phase1(&child, source, file_path, None, result);
             }
             "impl_item" => {
-                extract_impl_phase1(&child, source, file_path, result, &mut snippet_budget);
+                extract_impl_phase1(&child, source, file_path, result);
             }
             _ => {}
         }
     }
 }
 
-fn extract_impl_phase1(
-    node: &Node,
-    source: &[u8],
-    file_path: &str,
-    result: &mut ParsedFile,
-    snippet_budget: &mut usize,
-) {
+fn extract_impl_phase1(node: &Node, source: &[u8], file_path: &str, result: &mut ParsedFile) {
     let type_node = node.child_by_field_name("type");
     let type_name = type_node.map(|n| node_text(&n, source));
     let base_type = type_name.as_ref().map(|t| extract_base_type_name(t));
@@ -921,14 +3345,7 @@ fn extract_impl_phase1(
         let mut cursor = body.walk();
         for child in body.children(&mut cursor) {
             if child.kind() == "function_item" {
-                extract_function_phase1(
-                    &child,
-                    source,
-                    file_path,
-                    base_type.clone(),
-                    result,
-                    snippet_budget,
-                );
+                extract_function_phase1(&child, source, file_path, base_type.clone(), result);
             }
         }
     }
@@ -940,7 +3357,6 @@ fn extract_function_phase1(
     file_path: &str,
     impl_type: Option<String>,
     result: &mut ParsedFile,
-    snippet_budget: &mut usize,
 ) {
     let name = find_child_text(node, "identifier", source).unwrap_or_default();
     if name.is_empty() {
@@ -954,18 +3370,20 @@ fn extract_function_phase1(
 
     let body = node.child_by_field_name("body");
 
-    let (cyclomatic, nesting_depth, line_count) = if let Some(ref body) = body {
+    let (cyclomatic, cognitive, nesting_depth, line_count) = if let Some(ref body) = body {
         (
             compute_cyclomatic_complexity(body, source),
+            compute_cognitive_complexity(body, source, &name),
             compute_nesting_depth(body),
             compute_line_count(body),
         )
     } else {
-        (1, 0, 0)
+        (1, 0, 0, 0)
     };
 
     let metrics = ComplexityMetrics {
         cyclomatic,
+        cognitive,
         line_count,
         nesting_depth,
         call_sites: 0,
@@ -984,8 +3402,9 @@ fn extract_function_phase1(
     });
 
     if let Some(ref body_node) = body {
+        let params = parameter_names(node, source);
         if let Some(captured_body) =
-            capture_function_body(body_node, source, importance_score, snippet_budget)
+            capture_function_body(body_node, source, importance_score, &params)
         {
             result.captured_bodies.push(CapturedBody {
                 function_name: name.clone(),
@@ -999,8 +3418,14 @@ fn extract_function_phase1(
 
     let mut call_info = CallInfo::new(file_path.to_string(), name.clone(), impl_type.clone(), line);
 
+    let mut scope = ScopeStack::default();
+    scope.push();
+    if let Some(parameters) = node.child_by_field_name("parameters") {
+        bind_parameters(&parameters, source, &mut scope);
+    }
+
     if let Some(ref body) = body {
-        extract_calls_from_body(body, source, &mut call_info.callees);
+        extract_calls_from_body(body, source, &mut call_info.callees, &mut scope);
     }
 
     if !call_info.callees.is_empty() {
@@ -1008,15 +3433,18 @@ fn extract_function_phase1(
     }
 
     let return_type = extract_error_return_type(node, source);
-    if return_type.is_fallible() {
-        let mut error_info =
-            ErrorInfo::new(file_path.to_string(), name, impl_type, return_type, line);
+    let is_fallible = return_type.is_fallible();
+    let mut error_info = ErrorInfo::new(file_path.to_string(), name, impl_type, return_type, line);
 
-        if let Some(ref body) = body {
+    if let Some(ref body) = body {
+        if is_fallible {
             extract_error_propagation(body, source, &mut error_info);
             extract_error_origins(body, source, &mut error_info);
         }
+        extract_error_sinks(body, source, &mut error_info);
+    }
 
+    if is_fallible || error_info.has_sinks() {
         result.error_info.push(error_info);
     }
 }
@@ -1042,24 +3470,193 @@ fn count_branch_points(node: &Node, source: &[u8], complexity: &mut u32) {
                 *complexity += arm_count.saturating_sub(1) as u32;
             }
         }
-        "try_expression" => {
-            *complexity += 1;
+        "try_expression" => {
+            *complexity += 1;
+        }
+        "binary_expression" => {
+            let text = node_text(node, source);
+            if text.contains("&&") || text.contains("||") {
+                *complexity += 1;
+            }
+        }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        count_branch_points(&child, source, complexity);
+    }
+}
+
+fn compute_cognitive_complexity(node: &Node, source: &[u8], function_name: &str) -> u32 {
+    let mut complexity = 0;
+    walk_cognitive_complexity(node, source, 0, &mut complexity);
+    complexity += count_direct_recursion(node, source, function_name);
+    complexity
+}
+
+/// `break 'label`/`continue 'label` add a flat `1` on top of whatever loop they're already
+/// inside, since jumping out past more than the innermost loop is harder to follow than an
+/// unlabeled jump — labels themselves don't affect `nesting`.
+fn has_label(node: &Node) -> bool {
+    node.child_by_field_name("label").is_some()
+}
+
+/// `1` per call expression anywhere in the body whose callee is a bare `identifier` matching
+/// `function_name` — a direct recursive call, which is harder to trace through than a call to
+/// another function regardless of how deeply it's nested.
+fn count_direct_recursion(node: &Node, source: &[u8], function_name: &str) -> u32 {
+    let mut count = 0;
+    if node.kind() == "call_expression" {
+        if let Some(function) = node.child_by_field_name("function") {
+            if function.kind() == "identifier" && node_text(&function, source) == function_name {
+                count += 1;
+            }
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        count += count_direct_recursion(&child, source, function_name);
+    }
+    count
+}
+
+/// Mirrors SonarSource's cognitive-complexity rules, which `count_branch_points`'s flat,
+/// one-per-branch cyclomatic count doesn't capture: every control-flow structure adds
+/// `1 + nesting` instead of a flat `1`, a chained `else if` adds a flat `1` (see
+/// `walk_else_branch`) rather than re-triggering the `if_expression` case, a run of the
+/// same logical operator (`a && b && c`) adds `1` once rather than once per operator (see
+/// `is_new_logical_run`), and a labeled `break`/`continue` adds a flat `1` for the extra care a
+/// non-local jump demands. `nesting` increases when descending into the body of any of these
+/// structures or a closure, the same set `compute_nesting_depth_recursive` tracks.
+fn walk_cognitive_complexity(node: &Node, source: &[u8], nesting: u32, complexity: &mut u32) {
+    match node.kind() {
+        "if_expression" => {
+            *complexity += 1 + nesting;
+            if let Some(condition) = node.child_by_field_name("condition") {
+                walk_cognitive_complexity(&condition, source, nesting, complexity);
+            }
+            if let Some(consequence) = node.child_by_field_name("consequence") {
+                walk_cognitive_complexity(&consequence, source, nesting + 1, complexity);
+            }
+            if let Some(alternative) = node.child_by_field_name("alternative") {
+                walk_else_branch(&alternative, source, nesting, complexity);
+            }
+        }
+        "while_expression" => {
+            *complexity += 1 + nesting;
+            if let Some(condition) = node.child_by_field_name("condition") {
+                walk_cognitive_complexity(&condition, source, nesting, complexity);
+            }
+            if let Some(body) = node.child_by_field_name("body") {
+                walk_cognitive_complexity(&body, source, nesting + 1, complexity);
+            }
+        }
+        "for_expression" => {
+            *complexity += 1 + nesting;
+            if let Some(value) = node.child_by_field_name("value") {
+                walk_cognitive_complexity(&value, source, nesting, complexity);
+            }
+            if let Some(body) = node.child_by_field_name("body") {
+                walk_cognitive_complexity(&body, source, nesting + 1, complexity);
+            }
+        }
+        "loop_expression" => {
+            *complexity += 1 + nesting;
+            if let Some(body) = node.child_by_field_name("body") {
+                walk_cognitive_complexity(&body, source, nesting + 1, complexity);
+            }
+        }
+        "match_expression" => {
+            *complexity += 1 + nesting;
+            if let Some(value) = node.child_by_field_name("value") {
+                walk_cognitive_complexity(&value, source, nesting, complexity);
+            }
+            if let Some(body) = node.child_by_field_name("body") {
+                walk_cognitive_complexity(&body, source, nesting + 1, complexity);
+            }
+        }
+        "try_expression" => {
+            *complexity += 1 + nesting;
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                walk_cognitive_complexity(&child, source, nesting, complexity);
+            }
+        }
+        "break_expression" | "continue_expression" => {
+            if has_label(node) {
+                *complexity += 1;
+            }
+        }
+        "closure_expression" => {
+            if let Some(body) = node.child_by_field_name("body") {
+                walk_cognitive_complexity(&body, source, nesting + 1, complexity);
+            }
         }
         "binary_expression" => {
-            let text = node_text(node, source);
-            if text.contains("&&") || text.contains("||") {
+            if is_new_logical_run(node, source) {
                 *complexity += 1;
             }
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                walk_cognitive_complexity(&child, source, nesting, complexity);
+            }
+        }
+        _ => {
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                walk_cognitive_complexity(&child, source, nesting, complexity);
+            }
         }
-        _ => {}
     }
+}
 
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        count_branch_points(&child, source, complexity);
+/// Handles an `if_expression`'s `alternative`: a chained `else if` adds a flat `1` (no extra
+/// nesting) instead of the `1 + nesting` an `if_expression` normally adds, since it continues
+/// the same decision rather than starting a new nested one. A plain `else` block just descends
+/// one nesting level like any other body, without an increment of its own.
+fn walk_else_branch(node: &Node, source: &[u8], nesting: u32, complexity: &mut u32) {
+    if node.kind() == "if_expression" {
+        *complexity += 1;
+        if let Some(condition) = node.child_by_field_name("condition") {
+            walk_cognitive_complexity(&condition, source, nesting, complexity);
+        }
+        if let Some(consequence) = node.child_by_field_name("consequence") {
+            walk_cognitive_complexity(&consequence, source, nesting + 1, complexity);
+        }
+        if let Some(alternative) = node.child_by_field_name("alternative") {
+            walk_else_branch(&alternative, source, nesting, complexity);
+        }
+    } else {
+        walk_cognitive_complexity(node, source, nesting + 1, complexity);
+    }
+}
+
+/// A `&&`/`||` `binary_expression` only starts a new countable "run" if its parent isn't a
+/// `binary_expression` chained with the same operator — so `a && b && c` (which nests as
+/// `(a && b) && c`) counts once, while `a && b || c` counts twice, once per operator switch.
+fn is_new_logical_run(node: &Node, source: &[u8]) -> bool {
+    let Some(operator) = binary_operator_text(node, source) else {
+        return false;
+    };
+    if operator != "&&" && operator != "||" {
+        return false;
+    }
+
+    match node.parent() {
+        Some(parent) if parent.kind() == "binary_expression" => {
+            binary_operator_text(&parent, source).as_deref() != Some(operator.as_str())
+        }
+        _ => true,
     }
 }
 
+fn binary_operator_text(node: &Node, source: &[u8]) -> Option<String> {
+    node.child_by_field_name("operator")
+        .map(|op| node_text(&op, source))
+}
+
 fn compute_nesting_depth(node: &Node) -> u32 {
     let mut max_depth = 0;
     compute_nesting_depth_recursive(node, 0, &mut max_depth);
@@ -1099,7 +3696,118 @@ fn compute_line_count(node: &Node) -> u32 {
     (end_line - start_line + 1) as u32
 }
 
-fn extract_calls_from_body(node: &Node, source: &[u8], callees: &mut Vec<CallEdge>) {
+/// Per-function binding-name -> declared-type map, rebuilt for every function and threaded
+/// through [`extract_calls_from_body`] so [`infer_receiver_type`] can resolve a local's real
+/// type instead of guessing from its name. Scoped like the block structure it mirrors: each
+/// `block` pushes a fresh frame so an inner `let` shadowing an outer one never leaks back out
+/// once its block ends.
+#[derive(Default)]
+struct ScopeStack {
+    frames: Vec<HashMap<String, String>>,
+}
+
+impl ScopeStack {
+    fn push(&mut self) {
+        self.frames.push(HashMap::new());
+    }
+
+    fn pop(&mut self) {
+        self.frames.pop();
+    }
+
+    fn bind(&mut self, name: String, ty: String) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.insert(name, ty);
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Option<&str> {
+        self.frames
+            .iter()
+            .rev()
+            .find_map(|frame| frame.get(name))
+            .map(|ty| ty.as_str())
+    }
+}
+
+/// Binds each plain-identifier parameter in a function's `parameters` node to its annotated
+/// type, plus `self` to `Self`, in the scope's outermost (function-wide) frame.
+fn bind_parameters(node: &Node, source: &[u8], scope: &mut ScopeStack) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "self_parameter" => scope.bind("self".to_string(), "Self".to_string()),
+            "parameter" => {
+                let Some(pattern) = child.child_by_field_name("pattern") else {
+                    continue;
+                };
+                let Some(type_node) = child.child_by_field_name("type") else {
+                    continue;
+                };
+                if pattern.kind() == "identifier" {
+                    scope.bind(node_text(&pattern, source), node_text(&type_node, source));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Binds a `let` declaration's plain-identifier pattern to its type, preferring an explicit
+/// annotation (`let x: Widget = ...`) and otherwise inferring from a `Type::new(..)` or
+/// `Type { .. }` initializer.
+fn bind_let_declaration(node: &Node, source: &[u8], scope: &mut ScopeStack) {
+    let Some(pattern) = node.child_by_field_name("pattern") else {
+        return;
+    };
+    if pattern.kind() != "identifier" {
+        return;
+    }
+    let name = node_text(&pattern, source);
+
+    if let Some(type_node) = node.child_by_field_name("type") {
+        scope.bind(name, node_text(&type_node, source));
+        return;
+    }
+
+    if let Some(value) = node.child_by_field_name("value") {
+        if let Some(inferred) = infer_constructor_type(&value, source) {
+            scope.bind(name, inferred);
+        }
+    }
+}
+
+/// Infers the constructed type from a `let` initializer: `Type::new(..)` (a call through a
+/// `scoped_identifier`, taking every segment but the last) or `Type { .. }` (a struct literal).
+fn infer_constructor_type(node: &Node, source: &[u8]) -> Option<String> {
+    match node.kind() {
+        "call_expression" => {
+            let function = node.child_by_field_name("function")?;
+            if function.kind() != "scoped_identifier" {
+                return None;
+            }
+            let full_path = node_text(&function, source);
+            let parts: Vec<&str> = full_path.split("::").collect();
+            if parts.len() >= 2 {
+                Some(parts[..parts.len() - 1].join("::"))
+            } else {
+                None
+            }
+        }
+        "struct_expression" => {
+            let name = node.child_by_field_name("name")?;
+            Some(node_text(&name, source))
+        }
+        _ => None,
+    }
+}
+
+fn extract_calls_from_body(
+    node: &Node,
+    source: &[u8],
+    callees: &mut Vec<CallEdge>,
+    scope: &mut ScopeStack,
+) {
     match node.kind() {
         "call_expression" => {
             if let Some(function) = node.child_by_field_name("function") {
@@ -1123,7 +3831,7 @@ fn extract_calls_from_body(node: &Node, source: &[u8], callees: &mut Vec<CallEdg
                             let method_name = node_text(&field, source);
                             let receiver_type = function
                                 .child_by_field_name("value")
-                                .map(|v| infer_receiver_type(&v, source));
+                                .map(|v| infer_receiver_type(&v, source, scope));
                             callees.push(CallEdge {
                                 target: method_name,
                                 target_type: receiver_type,
@@ -1173,12 +3881,24 @@ fn extract_calls_from_body(node: &Node, source: &[u8], callees: &mut Vec<CallEdg
                 });
             }
         }
+        "block" => {
+            scope.push();
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                extract_calls_from_body(&child, source, callees, scope);
+                if child.kind() == "let_declaration" {
+                    bind_let_declaration(&child, source, scope);
+                }
+            }
+            scope.pop();
+            return;
+        }
         _ => {}
     }
 
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        extract_calls_from_body(&child, source, callees);
+        extract_calls_from_body(&child, source, callees, scope);
     }
 }
 
@@ -1203,12 +3923,14 @@ fn is_await_call(node: &Node, source: &[u8]) -> bool {
     text.contains(".await")
 }
 
-fn infer_receiver_type(node: &Node, source: &[u8]) -> String {
+fn infer_receiver_type(node: &Node, source: &[u8], scope: &ScopeStack) -> String {
     match node.kind() {
         "identifier" => {
             let name = node_text(node, source);
             if name == "self" {
                 "Self".to_string()
+            } else if let Some(ty) = scope.lookup(&name) {
+                ty.to_string()
             } else {
                 name
             }
@@ -1290,9 +4012,14 @@ fn extract_error_propagation(node: &Node, source: &[u8], error_info: &mut ErrorI
         } else {
             expression
         };
-        error_info
-            .propagation_points
-            .push(PropagationPoint { line, expression });
+        let context = node
+            .named_child(0)
+            .and_then(|inner| extract_context_annotation(&inner, source));
+        error_info.propagation_points.push(PropagationPoint {
+            line,
+            expression,
+            context,
+        });
     }
 
     let mut cursor = node.walk();
@@ -1301,6 +4028,43 @@ fn extract_error_propagation(node: &Node, source: &[u8], error_info: &mut ErrorI
     }
 }
 
+/// Recognizes an `anyhow`/`chainerror`-style `.context(c)` or `.with_context(|| c)` call wrapping
+/// the expression a `try_expression` propagates, and captures whether the message is eager or
+/// lazy along with its literal/format-string text.
+fn extract_context_annotation(node: &Node, source: &[u8]) -> Option<ContextAnnotation> {
+    if node.kind() != "call_expression" {
+        return None;
+    }
+
+    let function = node.child_by_field_name("function")?;
+    if function.kind() != "field_expression" {
+        return None;
+    }
+
+    let method = node_text(&function.child_by_field_name("field")?, source);
+    let lazy = match method.as_str() {
+        "context" => false,
+        "with_context" => true,
+        _ => return None,
+    };
+
+    let args = node.child_by_field_name("arguments")?;
+    let text = node_text(&args, source);
+    let text = text.trim_start_matches('(').trim_end_matches(')').trim();
+    let message = text.trim_start_matches("||").trim();
+    if message.is_empty() {
+        return None;
+    }
+
+    let message = if message.len() > 80 {
+        format!("{}...", &message[..77])
+    } else {
+        message.to_string()
+    };
+
+    Some(ContextAnnotation { message, lazy })
+}
+
 fn extract_error_origins(node: &Node, source: &[u8], error_info: &mut ErrorInfo) {
     match node.kind() {
         "call_expression" => {
@@ -1360,6 +4124,71 @@ fn extract_error_origins(node: &Node, source: &[u8], error_info: &mut ErrorInfo)
     }
 }
 
+/// Finds every place a `Result`/`Option` is absorbed rather than propagated via `?` —
+/// `.unwrap()`/`.expect("msg")`/`.unwrap_or*()` chained onto a call, or `let _ = fallible();` —
+/// the counterpart to [`extract_error_origins`] for where fallibility gets thrown away instead of
+/// created.
+fn extract_error_sinks(node: &Node, source: &[u8], error_info: &mut ErrorInfo) {
+    match node.kind() {
+        "call_expression" => {
+            if let Some(function) = node.child_by_field_name("function") {
+                if function.kind() == "field_expression" {
+                    if let Some(field) = function.child_by_field_name("field") {
+                        let method = node_text(&field, source);
+                        let kind = match method.as_str() {
+                            "unwrap" => Some(ErrorSinkKind::Unwrap),
+                            "expect" => Some(ErrorSinkKind::Expect),
+                            "unwrap_or" | "unwrap_or_else" | "unwrap_or_default" => {
+                                Some(ErrorSinkKind::UnwrapOrDiscard)
+                            }
+                            _ => None,
+                        };
+
+                        if let Some(kind) = kind {
+                            let line = node.start_position().row + 1;
+                            let call_target = function
+                                .child_by_field_name("value")
+                                .map(|receiver| node_text(&receiver, source))
+                                .unwrap_or_default();
+                            let message = matches!(kind, ErrorSinkKind::Expect)
+                                .then(|| extract_call_argument(node, source))
+                                .flatten();
+
+                            error_info.error_sinks.push(ErrorSink {
+                                line,
+                                kind,
+                                call_target,
+                                message,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+        "let_declaration" => {
+            if let Some(pattern) = node.child_by_field_name("pattern") {
+                if node_text(&pattern, source) == "_" {
+                    if let Some(value) = node.child_by_field_name("value") {
+                        let line = node.start_position().row + 1;
+                        error_info.error_sinks.push(ErrorSink {
+                            line,
+                            kind: ErrorSinkKind::Discarded,
+                            call_target: node_text(&value, source),
+                            message: None,
+                        });
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        extract_error_sinks(&child, source, error_info);
+    }
+}
+
 fn extract_call_argument(node: &Node, source: &[u8]) -> Option<String> {
     if let Some(args) = node.child_by_field_name("arguments") {
         let text = node_text(&args, source);
@@ -1389,27 +4218,36 @@ fn extract_macro_argument(node: &Node, source: &[u8]) -> Option<String> {
 }
 
 const MAX_FULL_BODY_CHARS: usize = 2000;
-const MAX_TOTAL_SNIPPET_BUDGET: usize = 50_000;
 
+/// The crate-wide ceiling on total `full_text` bytes across every [`CapturedBody`], enforced by
+/// [`crate::pipeline::allocate_snippet_budget`] once every file has been parsed rather than by
+/// this per-function capture step, which only decides *candidacy* (see [`capture_function_body`]).
+pub(super) const MAX_TOTAL_SNIPPET_BUDGET: usize = 50_000;
+
+/// Decides whether `body_node` is a candidate for a captured body at all, and if so whether it's
+/// worth considering for the full text. This no longer spends any budget itself — a function
+/// scoring `>= 30` gets *both* its full text and its summary captured here (unless the body
+/// itself is too long to ever qualify), and [`crate::pipeline::allocate_snippet_budget`] later
+/// picks, across the whole crate, which candidates' full text actually survives; the rest fall
+/// back to the summary already sitting right next to it.
 fn capture_function_body(
     body_node: &Node,
     source: &[u8],
     importance_score: u32,
-    current_budget: &mut usize,
+    params: &[String],
 ) -> Option<FunctionBody> {
-    if importance_score >= 30 && *current_budget > 0 {
+    if importance_score >= 30 {
         let body_text = extract_full_body(body_node, source);
-        let body_len = body_text.len();
 
-        if body_len <= MAX_FULL_BODY_CHARS && *current_budget >= body_len {
-            *current_budget = current_budget.saturating_sub(body_len);
+        if body_text.len() <= MAX_FULL_BODY_CHARS {
+            let summary = extract_body_summary(body_node, source, params);
             return Some(FunctionBody {
                 full_text: Some(body_text),
-                summary: None,
+                summary: Some(summary),
             });
         }
 
-        let summary = extract_body_summary(body_node, source);
+        let summary = extract_body_summary(body_node, source, params);
         return Some(FunctionBody {
             full_text: None,
             summary: Some(summary),
@@ -1417,7 +4255,7 @@ fn capture_function_body(
     }
 
     if importance_score >= 15 {
-        let summary = extract_body_summary(body_node, source);
+        let summary = extract_body_summary(body_node, source, params);
         return Some(FunctionBody {
             full_text: None,
             summary: Some(summary),
@@ -1427,6 +4265,32 @@ fn capture_function_body(
     None
 }
 
+/// Plain-identifier parameter names (plus `self`, if present) off a function/method's
+/// `parameters` node — the no-types counterpart to [`bind_parameters`], used to seed
+/// [`extract_refactor_candidates`]'s "already defined" set.
+fn parameter_names(node: &Node, source: &[u8]) -> Vec<String> {
+    let Some(parameters) = node.child_by_field_name("parameters") else {
+        return Vec::new();
+    };
+
+    let mut names = Vec::new();
+    let mut cursor = parameters.walk();
+    for child in parameters.children(&mut cursor) {
+        match child.kind() {
+            "self_parameter" => names.push("self".to_string()),
+            "parameter" => {
+                if let Some(pattern) = child.child_by_field_name("pattern") {
+                    if pattern.kind() == "identifier" {
+                        names.push(node_text(&pattern, source));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
 fn extract_full_body(node: &Node, source: &[u8]) -> String {
     let text = node_text(node, source);
     normalize_whitespace(&text)
@@ -1460,15 +4324,18 @@ fn normalize_whitespace(text: &str) -> String {
         .to_string()
 }
 
-fn extract_body_summary(node: &Node, source: &[u8]) -> BodySummary {
+fn extract_body_summary(node: &Node, source: &[u8], params: &[String]) -> BodySummary {
     let line_count = compute_line_count(node) as usize;
     let mut statement_count = 0;
     let mut early_returns = Vec::new();
     let mut key_calls = Vec::new();
+    let classifier = CallClassifier::builtin();
 
     collect_body_summary_info(
         node,
         source,
+        &RustLanguage,
+        &classifier,
         &mut statement_count,
         &mut early_returns,
         &mut key_calls,
@@ -1477,92 +4344,299 @@ fn extract_body_summary(node: &Node, source: &[u8]) -> BodySummary {
     early_returns.truncate(5);
     key_calls.truncate(10);
 
+    let defined_before: HashSet<String> = params.iter().cloned().collect();
+    let refactor_candidates = extract_refactor_candidates(node, source, &defined_before);
+
     BodySummary {
         line_count,
         statement_count,
         early_returns,
         key_calls,
+        refactor_candidates,
     }
 }
 
-fn collect_body_summary_info(
+/// Walks every `block` reachable from `node`, looking for maximal runs of sibling statements an
+/// IDE "extract function" assist could pull out — see [`analyze_block_for_candidates`] for the
+/// per-block algorithm. `defined_before` seeds each block with the names already in scope at its
+/// start (the enclosing function's parameters, for the outermost call).
+fn extract_refactor_candidates(
     node: &Node,
     source: &[u8],
-    statement_count: &mut usize,
-    early_returns: &mut Vec<String>,
-    key_calls: &mut Vec<String>,
+    defined_before: &HashSet<String>,
+) -> Vec<RefactorCandidate> {
+    let mut candidates = Vec::new();
+    collect_refactor_candidates(node, source, defined_before, &mut candidates);
+    candidates
+}
+
+fn collect_refactor_candidates(
+    node: &Node,
+    source: &[u8],
+    defined_before: &HashSet<String>,
+    candidates: &mut Vec<RefactorCandidate>,
+) {
+    if node.kind() == "block" {
+        analyze_block_for_candidates(node, source, defined_before, candidates);
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_refactor_candidates(&child, source, defined_before, candidates);
+    }
+}
+
+/// Splits a block's statements into maximal runs separated at any statement that crosses a
+/// `return`/`break`/`continue` boundary (see [`contains_control_boundary`]) — such a statement
+/// can't be part of an extracted span, so it ends the current run and starts a fresh one after
+/// it. Each run of at least two statements becomes one [`RefactorCandidate`]; single-statement
+/// runs aren't worth reporting.
+fn analyze_block_for_candidates(
+    block: &Node,
+    source: &[u8],
+    outer_defined: &HashSet<String>,
+    candidates: &mut Vec<RefactorCandidate>,
 ) {
+    let mut cursor = block.walk();
+    let statements: Vec<Node> = block.named_children(&mut cursor).collect();
+
+    let mut defined: HashSet<String> = outer_defined.clone();
+    let mut run_start = 0usize;
+    let mut run_start_defined = defined.clone();
+
+    for index in 0..statements.len() {
+        let statement = statements[index];
+
+        if contains_control_boundary(&statement) {
+            if index > run_start + 1 {
+                if let Some(candidate) = build_candidate(
+                    &statements[run_start..index],
+                    &statements[index..],
+                    source,
+                    &run_start_defined,
+                ) {
+                    candidates.push(candidate);
+                }
+            }
+            collect_refactor_candidates(&statement, source, &defined, candidates);
+            run_start = index + 1;
+            run_start_defined = defined.clone();
+            continue;
+        }
+
+        collect_refactor_candidates(&statement, source, &defined, candidates);
+        if let Some(name) = let_bound_name(&statement, source) {
+            defined.insert(name);
+        }
+    }
+
+    if statements.len() > run_start + 1 {
+        if let Some(candidate) = build_candidate(
+            &statements[run_start..],
+            &[],
+            source,
+            &run_start_defined,
+        ) {
+            candidates.push(candidate);
+        }
+    }
+}
+
+/// True if `node` contains a `return`/`break`/`continue` anywhere within it, not counting one
+/// nested inside a `closure_expression` — a closure's own `return`/labelless `break` only
+/// affects the closure, not the statement run it's embedded in.
+fn contains_control_boundary(node: &Node) -> bool {
     match node.kind() {
-        "expression_statement" | "let_declaration" => {
-            *statement_count += 1;
+        "return_expression" | "break_expression" | "continue_expression" => true,
+        "closure_expression" => false,
+        _ => {
+            let mut cursor = node.walk();
+            node.children(&mut cursor)
+                .any(|child| contains_control_boundary(&child))
         }
-        "return_expression" => {
-            let text = node_text(node, source);
-            let short_text = if text.len() > 60 {
-                format!("{}...", &text[..57])
-            } else {
-                text
-            };
-            early_returns.push(short_text);
+    }
+}
+
+/// The bound name of a top-level `let x = ..;` statement, or `None` for anything else
+/// (including destructuring patterns, which [`bind_let_declaration`] also skips).
+fn let_bound_name(node: &Node, source: &[u8]) -> Option<String> {
+    if node.kind() != "let_declaration" {
+        return None;
+    }
+    let pattern = node.child_by_field_name("pattern")?;
+    if pattern.kind() != "identifier" {
+        return None;
+    }
+    Some(node_text(&pattern, source))
+}
+
+/// Computes a [`RefactorCandidate`] for `statements`, a run of at least two sibling statements:
+/// `inputs` are identifiers read inside the run that were already in `defined_before` and
+/// haven't been (re)bound earlier in the run itself; `outputs` are names the run binds via
+/// `let` that `later_statements` still reads. `score` favors longer runs with fewer parameters
+/// and return values, the way a worthwhile extraction should.
+fn build_candidate(
+    statements: &[Node],
+    later_statements: &[Node],
+    source: &[u8],
+    defined_before: &HashSet<String>,
+) -> Option<RefactorCandidate> {
+    if statements.len() < 2 {
+        return None;
+    }
+
+    let mut defined_in_span: HashSet<String> = HashSet::new();
+    let mut inputs: Vec<String> = Vec::new();
+    let mut bound_in_span: Vec<String> = Vec::new();
+
+    for statement in statements {
+        collect_identifier_usages(statement, source, defined_before, &defined_in_span, &mut inputs);
+        if let Some(name) = let_bound_name(statement, source) {
+            defined_in_span.insert(name.clone());
+            bound_in_span.push(name);
         }
-        "call_expression" => {
-            if let Some(function) = node.child_by_field_name("function") {
-                let call_text = node_text(&function, source);
-                if !is_trivial_call(&call_text)
-                    && key_calls.len() < 10
-                    && !key_calls.contains(&call_text)
-                {
-                    key_calls.push(call_text);
+    }
+
+    let mut outputs: Vec<String> = Vec::new();
+    for name in &bound_in_span {
+        if later_statements
+            .iter()
+            .any(|later| mentions_identifier(later, source, name))
+        {
+            outputs.push(name.clone());
+        }
+    }
+
+    let start_line = statements.first()?.start_position().row + 1;
+    let end_line = statements.last()?.end_position().row + 1;
+    let statement_count = statements.len();
+    let arity = (inputs.len() + outputs.len()) as u32;
+    let score = (statement_count as u32 * 2).saturating_sub(arity);
+
+    Some(RefactorCandidate {
+        start_line,
+        end_line,
+        statement_count,
+        inputs,
+        outputs,
+        score,
+    })
+}
+
+/// Records every `identifier` usage under `node` that names something in `defined_before` but
+/// not yet in `defined_in_span` — a read of a would-be parameter. Skips the binding pattern of
+/// a nested `let_declaration` so a shadowing name isn't mistaken for a read of the outer one.
+fn collect_identifier_usages(
+    node: &Node,
+    source: &[u8],
+    defined_before: &HashSet<String>,
+    defined_in_span: &HashSet<String>,
+    inputs: &mut Vec<String>,
+) {
+    if node.kind() == "identifier" {
+        let name = node_text(node, source);
+        if defined_before.contains(&name) && !defined_in_span.contains(&name) && !inputs.contains(&name)
+        {
+            inputs.push(name);
+        }
+        return;
+    }
+
+    if node.kind() == "let_declaration" {
+        if let Some(value) = node.child_by_field_name("value") {
+            collect_identifier_usages(&value, source, defined_before, defined_in_span, inputs);
+        }
+        if let Some(type_node) = node.child_by_field_name("type") {
+            collect_identifier_usages(&type_node, source, defined_before, defined_in_span, inputs);
+        }
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_identifier_usages(&child, source, defined_before, defined_in_span, inputs);
+    }
+}
+
+fn mentions_identifier(node: &Node, source: &[u8], name: &str) -> bool {
+    if node.kind() == "identifier" && node_text(node, source) == name {
+        return true;
+    }
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .any(|child| mentions_identifier(&child, source, name))
+}
+
+fn source_position(node: &Node) -> SourcePosition {
+    let point = node.start_position();
+    SourcePosition {
+        row: point.row,
+        column: point.column,
+    }
+}
+
+fn call_base_name(text: &str) -> &str {
+    let base = text.rsplit("::").next().unwrap_or(text);
+    base.rsplit('.').next().unwrap_or(base)
+}
+
+fn collect_body_summary_info(
+    node: &Node,
+    source: &[u8],
+    language: &dyn Language,
+    classifier: &CallClassifier,
+    statement_count: &mut usize,
+    early_returns: &mut Vec<(String, SourcePosition)>,
+    key_calls: &mut Vec<(
+        String,
+        SourcePosition,
+        crate::extract::symbols::CallCategory,
+    )>,
+) {
+    let kind = node.kind();
+
+    if language.statement_kinds().contains(&kind) {
+        *statement_count += 1;
+    } else if kind == language.return_kind() {
+        let text = node_text(node, source);
+        let short_text = if text.len() > 60 {
+            format!("{}...", &text[..57])
+        } else {
+            text
+        };
+        early_returns.push((short_text, source_position(node)));
+    } else if kind == language.call_kind() {
+        if let Some(function) = node.child_by_field_name("function") {
+            let call_text = node_text(&function, source);
+            if let Some(category) = classifier.classify(call_base_name(&call_text)) {
+                if key_calls.len() < 10 && !key_calls.iter().any(|(t, _, _)| t == &call_text) {
+                    key_calls.push((call_text, source_position(&function), category));
                 }
             }
         }
-        _ => {}
     }
 
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        collect_body_summary_info(&child, source, statement_count, early_returns, key_calls);
-    }
-}
-
-fn is_trivial_call(name: &str) -> bool {
-    const TRIVIAL: &[&str] = &[
-        "unwrap",
-        "expect",
-        "clone",
-        "to_string",
-        "to_owned",
-        "into",
-        "from",
-        "as_ref",
-        "as_mut",
-        "ok",
-        "err",
-        "some",
-        "none",
-        "push",
-        "pop",
-        "insert",
-        "remove",
-        "get",
-        "len",
-        "is_empty",
-        "iter",
-        "collect",
-        "map",
-        "filter",
-        "and_then",
-        "or_else",
-        "ok_or",
-        "ok_or_else",
-        "unwrap_or",
-        "unwrap_or_else",
-        "unwrap_or_default",
-        "default",
-        "new",
-    ];
-
-    let base = name.split("::").last().unwrap_or(name);
-    let base = base.split('.').next_back().unwrap_or(base);
-    TRIVIAL.contains(&base)
+        collect_body_summary_info(
+            &child,
+            source,
+            language,
+            classifier,
+            statement_count,
+            early_returns,
+            key_calls,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol_names(parsed: &ParsedFile) -> Vec<String> {
+        parsed.symbols.symbols.iter().map(|s| s.name.clone()).collect()
+    }
+
 }
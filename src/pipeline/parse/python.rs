@@ -1,5 +1,6 @@
 use anyhow::{Result, anyhow};
 use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use tree_sitter::{Node, Parser, Tree};
 
 use crate::extract::calls::{CallEdge, CallInfo};
@@ -9,12 +10,13 @@ use crate::extract::errors::{
 };
 use crate::extract::imports::{ImportInfo, ImportKind};
 use crate::extract::safety::{
-    AsyncFunction, AwaitPoint, PanicKind, PanicPoint, PythonDangerousCall, RiskLevel, TestFunction,
-    TestInfo, TestModule,
+    AsyncFunction, AwaitPoint, PanicKind, PanicPoint, PythonDangerousCall, PythonLint,
+    PythonLintCategory, RiskLevel, TestFunction, TestInfo, TestModule,
 };
+use crate::extract::scope::{Binding, BindingKind, NameReference, Resolution, ScopeKind};
 use crate::extract::symbols::{
-    ClassField, ClassMethod, DecoratorInfo, FunctionBody, Parameter, ParameterKind, Symbol,
-    SymbolKind, Visibility,
+    ClassField, ClassMethod, DecoratorInfo, FunctionBody, Parameter, ParameterKind, PythonTypeVar,
+    PythonTypeVarKind, Symbol, SymbolKind, SymbolTree, SymbolTreeKind, Visibility,
 };
 use crate::pipeline::parse::{CapturedBody, ParsedFile};
 
@@ -46,13 +48,295 @@ fn extract_from_tree(tree: &Tree, source: &str, file_path: &str) -> Result<Parse
 
     extract_module_docstring(&root, source_bytes, &mut result);
     extract_imports(&root, source_bytes, &mut result);
-    extract_items(&root, source_bytes, file_path, &mut result);
+    result.python_typevars = extract_typevars(&root, source_bytes);
+    let typevars = result.python_typevars.clone();
+    let exception_classes = collect_exception_class_names(&root, source_bytes);
+    extract_items(
+        &root,
+        source_bytes,
+        file_path,
+        &typevars,
+        &exception_classes,
+        &mut result,
+    );
     extract_identifier_locations(&root, source_bytes, &mut result);
     extract_test_info(&root, source_bytes, &mut result);
+    collect_python_lints(&root, source_bytes, &mut result.python_lints);
+    let (bindings, references) = resolve_scopes(&root, source_bytes);
+    result.python_bindings = bindings;
+    result.python_name_references = references;
+    result.symbol_tree = build_symbol_tree(&root, source_bytes, file_path);
 
     Ok(result)
 }
 
+/// Pre-pass over every top-level `class` in the file collecting which ones are exception types —
+/// `bases` includes `Exception`/`BaseException` directly, or transitively through another class
+/// this same pre-pass already decided is an exception, computed to a fixed point so a chain like
+/// `class AppError(Exception)` / `class ValidationError(AppError)` marks both regardless of
+/// definition order. [`extract_class`] looks a class's own name up in the result.
+fn collect_exception_class_names(root: &Node, source: &[u8]) -> HashSet<String> {
+    let mut direct_bases: HashMap<String, Vec<String>> = HashMap::new();
+    let mut cursor = root.walk();
+
+    for child in root.children(&mut cursor) {
+        let class_node = match child.kind() {
+            "class_definition" => child,
+            "decorated_definition" => {
+                match child
+                    .children(&mut child.walk())
+                    .find(|c| c.kind() == "class_definition")
+                {
+                    Some(c) => c,
+                    None => continue,
+                }
+            }
+            _ => continue,
+        };
+
+        let name = class_node
+            .child_by_field_name("name")
+            .map(|n| node_text(&n, source))
+            .unwrap_or_default();
+        if name.is_empty() {
+            continue;
+        }
+
+        direct_bases.insert(name, collect_base_names(&class_node, source));
+    }
+
+    let mut exception_classes: HashSet<String> = HashSet::new();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (name, bases) in &direct_bases {
+            if exception_classes.contains(name) {
+                continue;
+            }
+            let is_exception = bases.iter().any(|base| {
+                base == "Exception"
+                    || base == "BaseException"
+                    || base.ends_with(".Exception")
+                    || base.ends_with(".BaseException")
+                    || exception_classes.contains(base)
+                    || exception_classes.contains(base.rsplit('.').next().unwrap_or(base))
+            });
+            if is_exception {
+                exception_classes.insert(name.clone());
+                changed = true;
+            }
+        }
+    }
+
+    exception_classes
+}
+
+/// Flat list of base-class names/paths off a `class_definition`'s `superclasses` argument list —
+/// plain identifiers and attributes (`Exception`, `mymodule.Error`) plus the base name out of a
+/// parametrized base like `Generic[T]`, for [`collect_exception_class_names`].
+/// [`extract_class`] walks the same argument list with its own loop.
+fn collect_base_names(class_node: &Node, source: &[u8]) -> Vec<String> {
+    let mut bases = Vec::new();
+    let Some(args) = class_node.child_by_field_name("superclasses") else {
+        return bases;
+    };
+
+    let mut arg_cursor = args.walk();
+    for arg in args.children(&mut arg_cursor) {
+        match arg.kind() {
+            "identifier" | "attribute" => bases.push(node_text(&arg, source)),
+            "subscript" => {
+                if let Some(value) = arg.child_by_field_name("value") {
+                    bases.push(node_text(&value, source));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    bases
+}
+
+/// Module-level pre-pass for `T = TypeVar("T", ...)` / `P = ParamSpec("P")` /
+/// `Ts = TypeVarTuple("Ts")` / `UserId = NewType("UserId", int)` assignments, so
+/// [`extract_class`] and [`extract_function`] can recognize the same name later when it shows up
+/// among a class's `Generic[...]`/`Protocol[...]` bases or a function's type hints.
+fn extract_typevars(root: &Node, source: &[u8]) -> Vec<PythonTypeVar> {
+    let mut typevars = Vec::new();
+    let mut cursor = root.walk();
+
+    for child in root.children(&mut cursor) {
+        if child.kind() != "expression_statement" {
+            continue;
+        }
+        let Some(assign) = child.child(0) else {
+            continue;
+        };
+        if assign.kind() != "assignment" {
+            continue;
+        }
+        let Some(name_node) = assign.child_by_field_name("left") else {
+            continue;
+        };
+        if name_node.kind() != "identifier" {
+            continue;
+        }
+        let Some(call) = assign.child_by_field_name("right") else {
+            continue;
+        };
+        if call.kind() != "call" {
+            continue;
+        }
+        let Some(function) = call.child_by_field_name("function") else {
+            continue;
+        };
+
+        let callee = node_text(&function, source);
+        let kind = match callee.rsplit('.').next().unwrap_or(&callee) {
+            "TypeVar" => PythonTypeVarKind::TypeVar,
+            "ParamSpec" => PythonTypeVarKind::ParamSpec,
+            "TypeVarTuple" => PythonTypeVarKind::TypeVarTuple,
+            "NewType" => PythonTypeVarKind::NewType,
+            _ => continue,
+        };
+
+        let name = node_text(&name_node, source);
+        let mut bound = None;
+        let mut covariant = false;
+        let mut contravariant = false;
+
+        if let Some(args) = call.child_by_field_name("arguments") {
+            let mut arg_cursor = args.walk();
+            let mut positional_index = 0usize;
+            for arg in args.children(&mut arg_cursor) {
+                if !arg.is_named() {
+                    continue;
+                }
+
+                if arg.kind() == "keyword_argument" {
+                    let key = arg
+                        .child_by_field_name("name")
+                        .map(|n| node_text(&n, source))
+                        .unwrap_or_default();
+                    let value = arg
+                        .child_by_field_name("value")
+                        .map(|n| node_text(&n, source))
+                        .unwrap_or_default();
+                    match key.as_str() {
+                        "bound" => bound = Some(value),
+                        "covariant" => covariant = value == "True",
+                        "contravariant" => contravariant = value == "True",
+                        _ => {}
+                    }
+                    continue;
+                }
+
+                // `NewType("Name", BaseType)` — the second positional argument is the
+                // underlying type, the closest thing a `NewType` has to a bound.
+                if kind == PythonTypeVarKind::NewType && positional_index == 1 {
+                    bound = Some(node_text(&arg, source));
+                }
+                positional_index += 1;
+            }
+        }
+
+        typevars.push(PythonTypeVar {
+            name,
+            kind,
+            bound,
+            covariant,
+            contravariant,
+        });
+    }
+
+    typevars
+}
+
+/// Extracts the comma-separated parameter list out of a `Generic[T, K]`/`Protocol[T]` base's
+/// bracketed subscript, matched textually since the repo's other Python extraction already
+/// favors slicing node text over walking the subscript grammar for this kind of one-off shape.
+fn extract_bracketed_params(node: &Node, source: &[u8]) -> Vec<String> {
+    let text = node_text(node, source);
+    let (Some(start), Some(end)) = (text.find('['), text.rfind(']')) else {
+        return Vec::new();
+    };
+    if end <= start {
+        return Vec::new();
+    }
+
+    text[start + 1..end]
+        .split(',')
+        .map(|part| part.trim().to_string())
+        .filter(|part| !part.is_empty())
+        .collect()
+}
+
+/// Renders a class's or function's resolved typevar names into the `[T, K: Hashable, **P]` form
+/// `Symbol::generics` uses, looking up each name's `PythonTypeVar` for its kind prefix and
+/// bound; variance is not rendered.
+fn render_generics(names: &[String], typevars: &[PythonTypeVar]) -> String {
+    if names.is_empty() {
+        return String::new();
+    }
+
+    let rendered: Vec<String> = names
+        .iter()
+        .map(|name| match typevars.iter().find(|tv| &tv.name == name) {
+            Some(tv) => {
+                let prefix = match tv.kind {
+                    PythonTypeVarKind::ParamSpec => "**",
+                    PythonTypeVarKind::TypeVarTuple => "*",
+                    PythonTypeVarKind::TypeVar | PythonTypeVarKind::NewType => "",
+                };
+                match &tv.bound {
+                    Some(bound) => format!("{prefix}{name}: {bound}"),
+                    None => format!("{prefix}{name}"),
+                }
+            }
+            None => name.clone(),
+        })
+        .collect();
+
+    format!("[{}]", rendered.join(", "))
+}
+
+/// Whether `name` (a typevar bound at module level) appears as its own token inside `type_hint`
+/// — e.g. `T` inside `list[T]` but not inside `TestType`.
+fn mentions_typevar(type_hint: &str, name: &str) -> bool {
+    type_hint
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|token| token == name)
+}
+
+/// Collects the names of any module-level typevars a function's parameter `type_hint`s or
+/// `return_type` reference, in order of first mention (parameters, then the return type), for [`extract_function`] to render via
+/// [`render_generics`].
+fn collect_used_typevars(
+    parameters: &[Parameter],
+    return_type: Option<&str>,
+    typevars: &[PythonTypeVar],
+) -> Vec<String> {
+    let mut used = Vec::new();
+    let mut note = |text: &str| {
+        for tv in typevars {
+            if !used.contains(&tv.name) && mentions_typevar(text, &tv.name) {
+                used.push(tv.name.clone());
+            }
+        }
+    };
+
+    for param in parameters {
+        if let Some(type_hint) = &param.type_hint {
+            note(type_hint);
+        }
+    }
+    if let Some(return_type) = return_type {
+        note(return_type);
+    }
+
+    used
+}
+
 fn extract_module_docstring(root: &Node, source: &[u8], result: &mut ParsedFile) {
     let mut cursor = root.walk();
 
@@ -117,16 +401,23 @@ fn extract_imports(root: &Node, source: &[u8], result: &mut ParsedFile) {
     }
 }
 
-fn extract_items(node: &Node, source: &[u8], file_path: &str, result: &mut ParsedFile) {
+fn extract_items(
+    node: &Node,
+    source: &[u8],
+    file_path: &str,
+    typevars: &[PythonTypeVar],
+    exception_classes: &HashSet<String>,
+    result: &mut ParsedFile,
+) {
     let mut cursor = node.walk();
 
     for child in node.children(&mut cursor) {
         match child.kind() {
             "class_definition" => {
-                extract_class(&child, source, file_path, result);
+                extract_class(&child, source, file_path, typevars, exception_classes, result);
             }
             "function_definition" | "decorated_definition" => {
-                extract_function(&child, source, file_path, None, result);
+                extract_function(&child, source, file_path, None, typevars, result);
             }
             "expression_statement" => {
                 extract_module_level_assignment(&child, source, result);
@@ -136,7 +427,14 @@ fn extract_items(node: &Node, source: &[u8], file_path: &str, result: &mut Parse
     }
 }
 
-fn extract_class(node: &Node, source: &[u8], file_path: &str, result: &mut ParsedFile) {
+fn extract_class(
+    node: &Node,
+    source: &[u8],
+    file_path: &str,
+    typevars: &[PythonTypeVar],
+    exception_classes: &HashSet<String>,
+    result: &mut ParsedFile,
+) {
     let (class_node, decorators) = if node.kind() == "decorated_definition" {
         let decs = extract_decorators(node, source);
         let inner = node
@@ -159,15 +457,35 @@ fn extract_class(node: &Node, source: &[u8], file_path: &str, result: &mut Parse
     let visibility = Visibility::from_python_name(&name);
 
     let mut bases = Vec::new();
+    let mut generic_param_names = Vec::new();
     if let Some(args) = class_node.child_by_field_name("superclasses") {
         let mut arg_cursor = args.walk();
         for arg in args.children(&mut arg_cursor) {
-            if arg.kind() == "identifier" || arg.kind() == "attribute" {
-                bases.push(node_text(&arg, source));
+            match arg.kind() {
+                "identifier" | "attribute" => {
+                    bases.push(node_text(&arg, source));
+                }
+                "subscript" => {
+                    let base_name = arg
+                        .child_by_field_name("value")
+                        .map(|n| node_text(&n, source))
+                        .unwrap_or_default();
+                    let is_generic_like = base_name == "Generic"
+                        || base_name.ends_with(".Generic")
+                        || base_name == "Protocol"
+                        || base_name.ends_with(".Protocol");
+                    if is_generic_like {
+                        generic_param_names.extend(extract_bracketed_params(&arg, source));
+                    }
+                    bases.push(base_name);
+                }
+                _ => {}
             }
         }
     }
 
+    let generics = render_generics(&generic_param_names, typevars);
+
     let is_dataclass = decorators.iter().any(|d| {
         d.name == "dataclass" || d.name == "dataclasses.dataclass" || d.name.ends_with(".dataclass")
     });
@@ -180,6 +498,8 @@ fn extract_class(node: &Node, source: &[u8], file_path: &str, result: &mut Parse
         .iter()
         .any(|b| b == "ABC" || b.ends_with(".ABC") || b == "ABCMeta");
 
+    let is_exception = exception_classes.contains(&name);
+
     let mut fields = Vec::new();
     let mut methods = Vec::new();
 
@@ -205,14 +525,16 @@ fn extract_class(node: &Node, source: &[u8], file_path: &str, result: &mut Parse
             is_dataclass,
             is_protocol,
             is_abc,
+            is_exception,
         },
         visibility,
-        generics: String::new(),
+        generics,
         line,
         is_async: false,
         is_unsafe: false,
         is_const: false,
         re_exported_as: None,
+        doc_summary: None,
     });
 
     for imp in &result.symbols.impl_map.clone() {
@@ -436,6 +758,18 @@ fn extract_method(
                 importance_score,
             });
         }
+
+        // Methods don't currently carry their own `generics`/typevar resolution (see
+        // `ClassMethod`), so a closure nested inside one doesn't either — passing `&[]` here
+        // mirrors that existing gap rather than introducing generics support methods don't have.
+        extract_nested_functions(
+            &body,
+            source,
+            file_path,
+            &format!("{}.{}", class_name, name),
+            &[],
+            result,
+        );
     }
 }
 
@@ -444,6 +778,7 @@ fn extract_function(
     source: &[u8],
     file_path: &str,
     impl_type: Option<&str>,
+    typevars: &[PythonTypeVar],
     result: &mut ParsedFile,
 ) {
     let (func_node, decorators) = if node.kind() == "decorated_definition" {
@@ -479,6 +814,9 @@ fn extract_function(
         .child_by_field_name("return_type")
         .map(|n| node_text(&n, source));
 
+    let used_typevars = collect_used_typevars(&parameters, return_type.as_deref(), typevars);
+    let generics = render_generics(&used_typevars, typevars);
+
     let docstring = extract_function_docstring(&func_node, source);
 
     result.symbols.symbols.push(Symbol {
@@ -494,12 +832,13 @@ fn extract_function(
             docstring: docstring.clone(),
         },
         visibility: visibility.clone(),
-        generics: String::new(),
+        generics,
         line,
         is_async,
         is_unsafe: false,
         is_const: false,
         re_exported_as: None,
+        doc_summary: None,
     });
 
     if let Some(body) = func_node.child_by_field_name("body") {
@@ -574,102 +913,366 @@ fn extract_function(
                 });
             }
         }
+
+        extract_nested_functions(&body, source, file_path, &name, typevars, result);
     }
 }
 
-fn extract_module_level_assignment(node: &Node, source: &[u8], result: &mut ParsedFile) {
-    if let Some(assign) = node.child(0) {
-        if assign.kind() != "assignment" && assign.kind() != "annotated_assignment" {
-            return;
-        }
-
-        let name = assign
-            .child_by_field_name("left")
-            .or_else(|| assign.child(0))
-            .map(|n| node_text(&n, source))
-            .unwrap_or_default();
+/// Recursively descends into `body`, turning any inner `def`/`async def` directly nested in it
+/// into its own [`Symbol`] via [`extract_closure`] — the same complexity/call-graph/error/
+/// captured-body treatment [`extract_function`] gives a top-level function, but qualified as
+/// `{enclosing}.<locals>.{name}` the way CPython's own `__qualname__` names a closure, with the
+/// parent/child relationship recorded in `FileSymbols::nested_functions`. Doesn't descend into a
+/// nested `class_definition`'s body — a class defined inside a function has its own member scope,
+/// out of scope for this walk.
+fn extract_nested_functions(
+    node: &Node,
+    source: &[u8],
+    file_path: &str,
+    enclosing: &str,
+    typevars: &[PythonTypeVar],
+    result: &mut ParsedFile,
+) {
+    let mut closures = Vec::new();
+    let mut cursor = node.walk();
 
-        if name.is_empty() || name.contains('.') {
-            return;
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "function_definition" | "decorated_definition" => {
+                if let Some(qualified_name) =
+                    extract_closure(&child, source, file_path, enclosing, typevars, result)
+                {
+                    let plain_name = qualified_name
+                        .rsplit('.')
+                        .next()
+                        .unwrap_or(&qualified_name)
+                        .to_string();
+                    closures.push((plain_name, qualified_name));
+                }
+            }
+            "class_definition" => {}
+            _ => {
+                extract_nested_functions(&child, source, file_path, enclosing, typevars, result);
+            }
         }
+    }
 
-        let line = node.start_position().row + 1;
-        let visibility = Visibility::from_python_name(&name);
-
-        let type_hint = assign
-            .child_by_field_name("type")
-            .map(|n| node_text(&n, source));
-
-        let value = assign
-            .child_by_field_name("right")
-            .or_else(|| assign.child_by_field_name("value"))
-            .and_then(|n| {
-                let text = node_text(&n, source);
-                if text.len() > 80 { None } else { Some(text) }
-            });
-
-        result.symbols.symbols.push(Symbol {
-            name,
-            kind: SymbolKind::Variable { type_hint, value },
-            visibility,
-            generics: String::new(),
-            line,
-            is_async: false,
-            is_unsafe: false,
-            is_const: false,
-            re_exported_as: None,
-        });
+    if !closures.is_empty() {
+        record_returned_closures(node, source, file_path, enclosing, &closures, result);
     }
 }
 
-fn extract_decorators(node: &Node, source: &[u8]) -> Vec<DecoratorInfo> {
-    let mut decorators = Vec::new();
-    let mut cursor = node.walk();
+/// Extracts a single `def`/`async def` nested directly inside another function's body as a
+/// standalone symbol, mirroring [`extract_function`]'s own treatment of its body but named
+/// `{enclosing}.<locals>.{name}` and recorded as a containment edge rather than pushed with a
+/// class `impl_type`, since a closure isn't a class member. Recurses into its own body for further
+/// nesting. Returns the new symbol's qualified name on success, so the caller can check whether
+/// the enclosing function returns it bare (the decorator-factory pattern).
+fn extract_closure(
+    node: &Node,
+    source: &[u8],
+    file_path: &str,
+    enclosing: &str,
+    typevars: &[PythonTypeVar],
+    result: &mut ParsedFile,
+) -> Option<String> {
+    let (func_node, decorators) = if node.kind() == "decorated_definition" {
+        let decs = extract_decorators(node, source);
+        let inner = node
+            .children(&mut node.walk())
+            .find(|c| c.kind() == "function_definition" || c.kind() == "async_function_definition");
+        match inner {
+            Some(f) => (f, decs),
+            None => return None,
+        }
+    } else {
+        (*node, Vec::new())
+    };
 
-    for child in node.children(&mut cursor) {
-        if child.kind() == "decorator" {
-            let text = node_text(&child, source);
-            let text = text.strip_prefix('@').unwrap_or(&text);
+    let name = func_node
+        .child_by_field_name("name")
+        .map(|n| node_text(&n, source))
+        .unwrap_or_default();
+    if name.is_empty() {
+        return None;
+    }
 
-            let (name, arguments) = if let Some(paren_pos) = text.find('(') {
-                let name = text[..paren_pos].trim().to_string();
-                let args = text[paren_pos..].trim().to_string();
-                (name, Some(args))
-            } else {
-                (text.trim().to_string(), None)
-            };
+    let qualified_name = format!("{enclosing}.<locals>.{name}");
 
-            decorators.push(DecoratorInfo { name, arguments });
-        }
-    }
+    let line = func_node.start_position().row + 1;
+    let visibility = Visibility::from_python_name(&name);
+    let is_async = func_node.kind() == "async_function_definition";
+    let is_generator = check_is_generator(&func_node);
 
-    decorators
-}
+    let is_classmethod = decorators.iter().any(|d| d.name == "classmethod");
+    let is_staticmethod = decorators.iter().any(|d| d.name == "staticmethod");
+    let is_property = decorators.iter().any(|d| d.name == "property");
 
-fn extract_parameters(node: &Node, source: &[u8]) -> Vec<Parameter> {
-    let mut params = Vec::new();
+    let parameters = extract_parameters(&func_node, source);
+    let return_type = func_node
+        .child_by_field_name("return_type")
+        .map(|n| node_text(&n, source));
 
-    let parameters = match node.child_by_field_name("parameters") {
-        Some(p) => p,
-        None => return params,
-    };
+    let used_typevars = collect_used_typevars(&parameters, return_type.as_deref(), typevars);
+    let generics = render_generics(&used_typevars, typevars);
 
-    let mut cursor = parameters.walk();
-    let mut seen_star = false;
-    let mut seen_slash = false;
+    let docstring = extract_function_docstring(&func_node, source);
 
-    for child in parameters.children(&mut cursor) {
-        match child.kind() {
-            "identifier" => {
-                let name = node_text(&child, source);
-                let kind = if seen_star {
-                    ParameterKind::KeywordOnly
-                } else if !seen_slash {
-                    ParameterKind::PositionalOnly
-                } else {
-                    ParameterKind::Regular
-                };
-                params.push(Parameter {
+    result.symbols.symbols.push(Symbol {
+        name: qualified_name.clone(),
+        kind: SymbolKind::PythonFunction {
+            parameters,
+            return_type,
+            decorators,
+            is_generator,
+            is_classmethod,
+            is_staticmethod,
+            is_property,
+            docstring,
+        },
+        visibility: visibility.clone(),
+        generics,
+        line,
+        is_async,
+        is_unsafe: false,
+        is_const: false,
+        re_exported_as: None,
+        doc_summary: None,
+    });
+
+    result
+        .symbols
+        .nested_functions
+        .push((enclosing.to_string(), qualified_name.clone()));
+
+    if let Some(body) = func_node.child_by_field_name("body") {
+        let complexity = compute_cyclomatic_complexity(&body, source);
+        let line_count = compute_line_count(&body);
+
+        let importance_score = (complexity * 2)
+            + (line_count / 10)
+            + if matches!(visibility, Visibility::Public) {
+                10
+            } else {
+                0
+            }
+            + if name.starts_with("test_") { 0 } else { 5 };
+
+        result.complexity.push(FunctionComplexity {
+            name: qualified_name.clone(),
+            impl_type: None,
+            line,
+            metrics: ComplexityMetrics {
+                cyclomatic: complexity,
+                line_count,
+                nesting_depth: compute_nesting_depth(&body),
+                call_sites: 0,
+                churn_score: 0,
+                is_public: matches!(visibility, Visibility::Public),
+                is_test: name.starts_with("test_"),
+            },
+        });
+
+        extract_calls_from_body(&body, source, file_path, &qualified_name, None, result);
+        extract_safety_from_body(&body, source, Some(&qualified_name), result);
+        extract_error_info(&body, source, file_path, &qualified_name, None, line, result);
+
+        if importance_score >= 15 && !name.starts_with("test_") {
+            let body_text = node_text(&body, source);
+            result.captured_bodies.push(CapturedBody {
+                function_name: qualified_name.clone(),
+                impl_type: None,
+                line,
+                body: FunctionBody {
+                    full_text: if importance_score >= 30 {
+                        Some(body_text)
+                    } else {
+                        None
+                    },
+                    summary: if importance_score < 30 {
+                        Some(crate::extract::symbols::BodySummary {
+                            line_count: line_count as usize,
+                            statement_count: count_statements(&body),
+                            early_returns: collect_early_returns(&body, source),
+                            key_calls: collect_key_calls(&body, source),
+                        })
+                    } else {
+                        None
+                    },
+                },
+                importance_score,
+            });
+        }
+
+        if is_async {
+            let mut awaits = Vec::new();
+            collect_await_points(&body, source, &mut awaits);
+            if !awaits.is_empty() {
+                result.async_info.async_functions.push(AsyncFunction {
+                    name: qualified_name.clone(),
+                    impl_type: None,
+                    line,
+                    awaits,
+                    spawns: Vec::new(),
+                });
+            }
+        }
+
+        extract_nested_functions(&body, source, file_path, &qualified_name, typevars, result);
+    }
+
+    Some(qualified_name)
+}
+
+/// Detects the common decorator-factory shape — `return inner` where `inner` is one of the
+/// closures this same body just defined — and records a call edge from `enclosing` to the
+/// closure's qualified name, so a caller tracing the call graph lands on the function that
+/// actually runs rather than stopping at the wrapper that merely returns it.
+fn record_returned_closures(
+    body: &Node,
+    source: &[u8],
+    file_path: &str,
+    enclosing: &str,
+    closures: &[(String, String)],
+    result: &mut ParsedFile,
+) {
+    let mut cursor = body.walk();
+    for child in body.children(&mut cursor) {
+        if child.kind() == "return_statement" {
+            if let Some(value) = child.child(1) {
+                if value.kind() == "identifier" {
+                    let returned = node_text(&value, source);
+                    if let Some((_, qualified)) =
+                        closures.iter().find(|(plain, _)| *plain == returned)
+                    {
+                        let line = child.start_position().row + 1;
+                        let edge = CallEdge {
+                            target: qualified.clone(),
+                            target_type: None,
+                            line,
+                            is_async_call: false,
+                            is_try_call: false,
+                        };
+                        match result
+                            .call_graph
+                            .iter_mut()
+                            .find(|info| info.caller.name == enclosing)
+                        {
+                            Some(info) => info.callees.push(edge),
+                            None => {
+                                let mut info = CallInfo::new(
+                                    file_path.to_string(),
+                                    enclosing.to_string(),
+                                    None,
+                                    line,
+                                );
+                                info.callees.push(edge);
+                                result.call_graph.push(info);
+                            }
+                        }
+                    }
+                }
+            }
+        } else if !matches!(child.kind(), "function_definition" | "decorated_definition") {
+            record_returned_closures(&child, source, file_path, enclosing, closures, result);
+        }
+    }
+}
+
+fn extract_module_level_assignment(node: &Node, source: &[u8], result: &mut ParsedFile) {
+    if let Some(assign) = node.child(0) {
+        if assign.kind() != "assignment" && assign.kind() != "annotated_assignment" {
+            return;
+        }
+
+        let name = assign
+            .child_by_field_name("left")
+            .or_else(|| assign.child(0))
+            .map(|n| node_text(&n, source))
+            .unwrap_or_default();
+
+        if name.is_empty() || name.contains('.') {
+            return;
+        }
+
+        let line = node.start_position().row + 1;
+        let visibility = Visibility::from_python_name(&name);
+
+        let type_hint = assign
+            .child_by_field_name("type")
+            .map(|n| node_text(&n, source));
+
+        let value = assign
+            .child_by_field_name("right")
+            .or_else(|| assign.child_by_field_name("value"))
+            .and_then(|n| {
+                let text = node_text(&n, source);
+                if text.len() > 80 { None } else { Some(text) }
+            });
+
+        result.symbols.symbols.push(Symbol {
+            name,
+            kind: SymbolKind::Variable { type_hint, value },
+            visibility,
+            generics: String::new(),
+            line,
+            is_async: false,
+            is_unsafe: false,
+            is_const: false,
+            re_exported_as: None,
+            doc_summary: None,
+        });
+    }
+}
+
+fn extract_decorators(node: &Node, source: &[u8]) -> Vec<DecoratorInfo> {
+    let mut decorators = Vec::new();
+    let mut cursor = node.walk();
+
+    for child in node.children(&mut cursor) {
+        if child.kind() == "decorator" {
+            let text = node_text(&child, source);
+            let text = text.strip_prefix('@').unwrap_or(&text);
+
+            let (name, arguments) = if let Some(paren_pos) = text.find('(') {
+                let name = text[..paren_pos].trim().to_string();
+                let args = text[paren_pos..].trim().to_string();
+                (name, Some(args))
+            } else {
+                (text.trim().to_string(), None)
+            };
+
+            decorators.push(DecoratorInfo { name, arguments });
+        }
+    }
+
+    decorators
+}
+
+fn extract_parameters(node: &Node, source: &[u8]) -> Vec<Parameter> {
+    let mut params = Vec::new();
+
+    let parameters = match node.child_by_field_name("parameters") {
+        Some(p) => p,
+        None => return params,
+    };
+
+    let mut cursor = parameters.walk();
+    let mut seen_star = false;
+    let mut seen_slash = false;
+
+    for child in parameters.children(&mut cursor) {
+        match child.kind() {
+            "identifier" => {
+                let name = node_text(&child, source);
+                let kind = if seen_star {
+                    ParameterKind::KeywordOnly
+                } else if !seen_slash {
+                    ParameterKind::PositionalOnly
+                } else {
+                    ParameterKind::Regular
+                };
+                params.push(Parameter {
                     name,
                     type_hint: None,
                     default_value: None,
@@ -842,10 +1445,11 @@ fn count_branch_points(node: &Node, source: &[u8], complexity: &mut u32) {
         "conditional_expression" => {
             *complexity += 1;
         }
-        "list_comprehension"
-        | "set_comprehension"
-        | "dictionary_comprehension"
-        | "generator_expression" => {
+        // A comprehension's `for_in_clause`s are loops and its `if_clause`s are filters, so each
+        // one carries the same branching weight as the statement-level loop/guard it stands in
+        // for, rather than the comprehension itself contributing one flat unit regardless of how
+        // many clauses it chains.
+        "for_in_clause" | "if_clause" => {
             *complexity += 1;
         }
         _ => {}
@@ -872,6 +1476,10 @@ fn compute_nesting_depth_recursive(node: &Node, current_depth: u32, max_depth: &
             | "try_statement"
             | "with_statement"
             | "match_statement"
+            | "list_comprehension"
+            | "set_comprehension"
+            | "dictionary_comprehension"
+            | "generator_expression"
     );
 
     let new_depth = if is_nesting {
@@ -1018,14 +1626,67 @@ fn extract_safety_from_body(
     }
 }
 
+/// The callee identity `check_dangerous_call` matches against, built from a `call` node's
+/// `function` field rather than the call's raw source text — `eval`/`exec` as bare identifiers,
+/// everything else as a dotted path's last one or two segments (`subprocess.run` stays
+/// `subprocess.run`, but `requests.subprocess.run` also matches via the two-segment suffix).
+fn callee_identity(function: &Node, source: &[u8]) -> Option<String> {
+    match function.kind() {
+        "identifier" => Some(node_text(function, source)),
+        "attribute" => {
+            let attr = function.child_by_field_name("attribute")?;
+            let attr_name = node_text(&attr, source);
+            let object = function.child_by_field_name("object")?;
+            let object_name = match object.kind() {
+                "identifier" => Some(node_text(&object, source)),
+                "attribute" => object
+                    .child_by_field_name("attribute")
+                    .map(|n| node_text(&n, source)),
+                _ => None,
+            };
+            Some(match object_name {
+                Some(object_name) => format!("{object_name}.{attr_name}"),
+                None => attr_name,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Whether `call`'s arguments include a `shell=True` keyword argument, checked by keyword name
+/// and literal value rather than scanning the call's raw text for the substring `shell=True`.
+fn has_shell_true_argument(call: &Node, source: &[u8]) -> bool {
+    let Some(args) = call.child_by_field_name("arguments") else {
+        return false;
+    };
+
+    let mut cursor = args.walk();
+    args.children(&mut cursor).any(|arg| {
+        arg.kind() == "keyword_argument"
+            && arg
+                .child_by_field_name("name")
+                .is_some_and(|n| node_text(&n, source) == "shell")
+            && arg
+                .child_by_field_name("value")
+                .is_some_and(|v| node_text(&v, source) == "True")
+    })
+}
+
 fn check_dangerous_call(
     node: &Node,
     source: &[u8],
     containing_fn: Option<&str>,
     result: &mut ParsedFile,
 ) {
-    let call_text = node_text(node, source);
+    let Some(function) = node.child_by_field_name("function") else {
+        return;
+    };
+    let Some(identity) = callee_identity(&function, source) else {
+        return;
+    };
+
     let line = node.start_position().row + 1;
+    let call_text = node_text(node, source);
 
     let make_call = |category: &str, risk: RiskLevel| PythonDangerousCall {
         line,
@@ -1035,40 +1696,26 @@ fn check_dangerous_call(
         risk_level: risk,
     };
 
-    if call_text.contains("eval(") {
-        result
-            .python_safety
-            .dangerous_calls
-            .push(make_call("eval", RiskLevel::High));
-    } else if call_text.contains("exec(") {
-        result
-            .python_safety
-            .dangerous_calls
-            .push(make_call("exec", RiskLevel::High));
-    } else if call_text.contains("subprocess")
-        || call_text.contains("os.system")
-        || call_text.contains("os.popen")
-    {
-        result
-            .python_safety
-            .dangerous_calls
-            .push(make_call("subprocess", RiskLevel::High));
-    } else if call_text.contains("ctypes") {
-        result
-            .python_safety
-            .dangerous_calls
-            .push(make_call("ctypes", RiskLevel::Medium));
-    } else if call_text.contains("cffi") {
-        result
-            .python_safety
-            .dangerous_calls
-            .push(make_call("cffi", RiskLevel::Medium));
-    } else if call_text.contains("pickle.load") || call_text.contains("pickle.loads") {
-        result
-            .python_safety
-            .dangerous_calls
-            .push(make_call("pickle", RiskLevel::High));
-    } else if call_text.contains("shell=True") {
+    let finding = match identity.as_str() {
+        "eval" => Some(make_call("eval", RiskLevel::High)),
+        "exec" => Some(make_call("exec", RiskLevel::High)),
+        "subprocess.run" | "subprocess.call" | "subprocess.Popen" | "subprocess.check_call"
+        | "subprocess.check_output" | "os.system" | "os.popen" => {
+            Some(make_call("subprocess", RiskLevel::High))
+        }
+        "pickle.load" | "pickle.loads" => Some(make_call("pickle", RiskLevel::High)),
+        _ if identity.starts_with("ctypes.") || identity == "ctypes" => {
+            Some(make_call("ctypes", RiskLevel::Medium))
+        }
+        _ if identity.starts_with("cffi.") || identity == "cffi" => {
+            Some(make_call("cffi", RiskLevel::Medium))
+        }
+        _ => None,
+    };
+
+    if let Some(finding) = finding {
+        result.python_safety.dangerous_calls.push(finding);
+    } else if has_shell_true_argument(node, source) {
         result
             .python_safety
             .dangerous_calls
@@ -1076,18 +1723,297 @@ fn check_dangerous_call(
     }
 }
 
-fn extract_error_info(
-    body: &Node,
-    source: &[u8],
-    file_path: &str,
-    function_name: &str,
-    impl_type: Option<&str>,
-    line: usize,
-    result: &mut ParsedFile,
-) {
-    let mut error_origins = Vec::new();
-    let mut propagation_points = Vec::new();
-    let mut exception_types = Vec::new();
+/// Structural (node-kind/field-name) scan for mechanical Python idioms and anti-patterns — see
+/// [`PythonLintCategory`] for what each one catches. Runs once over the whole tree rather than
+/// being threaded through [`extract_function`]/[`extract_method`], since none of its findings
+/// need a containing function's name.
+fn collect_python_lints(node: &Node, source: &[u8], lints: &mut Vec<PythonLint>) {
+    match node.kind() {
+        "for_statement" => {
+            check_range_len_iteration(node, source, lints);
+            check_dict_keys_indexing(node, source, lints);
+        }
+        "comparison_operator" => check_none_equality(node, source, lints),
+        "default_parameter" | "typed_default_parameter" => {
+            check_mutable_default_argument(node, source, lints)
+        }
+        "except_clause" => check_bare_except(node, source, lints),
+        "call" => check_redundant_collection_call(node, source, lints),
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_python_lints(&child, source, lints);
+    }
+}
+
+/// `for i in range(len(x)):` — the loop only ever uses `i` to re-derive `x[i]`, which `enumerate`
+/// or direct iteration already gives for free.
+fn check_range_len_iteration(node: &Node, source: &[u8], lints: &mut Vec<PythonLint>) {
+    let Some(iterable) = node.child_by_field_name("right") else {
+        return;
+    };
+    if iterable.kind() != "call" {
+        return;
+    }
+    let is_range_call = iterable
+        .child_by_field_name("function")
+        .is_some_and(|f| f.kind() == "identifier" && node_text(&f, source) == "range");
+    if !is_range_call {
+        return;
+    }
+
+    let is_len_arg = iterable
+        .child_by_field_name("arguments")
+        .and_then(|args| args.named_child(0))
+        .is_some_and(|arg| {
+            arg.kind() == "call"
+                && arg
+                    .child_by_field_name("function")
+                    .is_some_and(|f| f.kind() == "identifier" && node_text(&f, source) == "len")
+        });
+    if !is_len_arg {
+        return;
+    }
+
+    let loop_var = node
+        .child_by_field_name("left")
+        .map(|n| node_text(&n, source))
+        .unwrap_or_default();
+
+    lints.push(PythonLint {
+        line: node.start_position().row + 1,
+        category: PythonLintCategory::RangeLenIteration,
+        severity: RiskLevel::Low,
+        message: format!(
+            "`for {loop_var} in range(len(...))` iterates by index only to re-derive the element"
+        ),
+        suggestion: "iterate the sequence directly, or use `enumerate()` if the index is also needed"
+            .to_string(),
+    });
+}
+
+/// `for k in d.keys():` with a later `d[k]` inside the same loop body — `d.items()` already hands
+/// back the value without a second lookup.
+fn check_dict_keys_indexing(node: &Node, source: &[u8], lints: &mut Vec<PythonLint>) {
+    let Some(iterable) = node.child_by_field_name("right") else {
+        return;
+    };
+    if iterable.kind() != "call" {
+        return;
+    }
+    let Some(function) = iterable.child_by_field_name("function") else {
+        return;
+    };
+    if function.kind() != "attribute" {
+        return;
+    }
+    let is_keys_call = function
+        .child_by_field_name("attribute")
+        .is_some_and(|attr| node_text(&attr, source) == "keys");
+    if !is_keys_call {
+        return;
+    }
+    let Some(dict_expr) = function.child_by_field_name("object") else {
+        return;
+    };
+    let dict_name = node_text(&dict_expr, source);
+
+    let loop_var = node
+        .child_by_field_name("left")
+        .map(|n| node_text(&n, source))
+        .unwrap_or_default();
+    if loop_var.is_empty() {
+        return;
+    }
+
+    if let Some(body) = node.child_by_field_name("body") {
+        find_dict_indexing(&body, source, &dict_name, &loop_var, lints);
+    }
+}
+
+fn find_dict_indexing(
+    node: &Node,
+    source: &[u8],
+    dict_name: &str,
+    loop_var: &str,
+    lints: &mut Vec<PythonLint>,
+) {
+    if node.kind() == "subscript" {
+        let base = node
+            .child_by_field_name("value")
+            .map(|n| node_text(&n, source));
+        let index = node
+            .child_by_field_name("subscript")
+            .map(|n| node_text(&n, source));
+        if base.as_deref() == Some(dict_name) && index.as_deref() == Some(loop_var) {
+            lints.push(PythonLint {
+                line: node.start_position().row + 1,
+                category: PythonLintCategory::DictKeysIndexing,
+                severity: RiskLevel::Low,
+                message: format!(
+                    "`{dict_name}[{loop_var}]` re-indexes the dict this loop already iterates via `.keys()`"
+                ),
+                suggestion: format!("iterate `{dict_name}.items()` and bind the value directly"),
+            });
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        find_dict_indexing(&child, source, dict_name, loop_var, lints);
+    }
+}
+
+/// `== None` / `!= None` — `None` is a singleton, so the identity comparison `is`/`is not` is both
+/// correct and idiomatic where `==`/`!=` merely happens to work because no `__eq__` override
+/// intercepts it.
+fn check_none_equality(node: &Node, source: &[u8], lints: &mut Vec<PythonLint>) {
+    let mut has_none = false;
+    let mut operator = None;
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "none" => has_none = true,
+            "==" | "!=" => operator = Some(child.kind().to_string()),
+            _ => {}
+        }
+    }
+
+    if !has_none {
+        return;
+    }
+    let Some(op) = operator else {
+        return;
+    };
+
+    let suggestion = if op == "==" { "is None" } else { "is not None" };
+    lints.push(PythonLint {
+        line: node.start_position().row + 1,
+        category: PythonLintCategory::NoneEquality,
+        severity: RiskLevel::Low,
+        message: format!("`{op} None` compares by value instead of identity"),
+        suggestion: format!("use `{suggestion}`"),
+    });
+}
+
+/// A `list`/`dict`/`set` literal (or constructor call) bound as a parameter default — it's built
+/// once when the `def` executes and every call that doesn't override the argument shares the same
+/// object.
+fn check_mutable_default_argument(node: &Node, source: &[u8], lints: &mut Vec<PythonLint>) {
+    let Some(value) = node.child_by_field_name("value") else {
+        return;
+    };
+
+    let is_mutable_literal = matches!(value.kind(), "list" | "dictionary" | "set");
+    let is_mutable_constructor = value.kind() == "call"
+        && value.child_by_field_name("function").is_some_and(|f| {
+            f.kind() == "identifier"
+                && matches!(node_text(&f, source).as_str(), "list" | "dict" | "set")
+        });
+
+    if !is_mutable_literal && !is_mutable_constructor {
+        return;
+    }
+
+    let name = node
+        .child_by_field_name("name")
+        .map(|n| node_text(&n, source))
+        .unwrap_or_default();
+
+    lints.push(PythonLint {
+        line: node.start_position().row + 1,
+        category: PythonLintCategory::MutableDefaultArgument,
+        severity: RiskLevel::Medium,
+        message: format!(
+            "parameter `{name}` defaults to a mutable value, built once at `def` time and shared across calls"
+        ),
+        suggestion: format!("default `{name}` to `None` and build the mutable value inside the function body"),
+    });
+}
+
+/// A bare `except:` with no exception type — it also catches `KeyboardInterrupt` and
+/// `SystemExit`, which almost never is the intent.
+fn check_bare_except(node: &Node, source: &[u8], lints: &mut Vec<PythonLint>) {
+    let is_bare = match node.child(1) {
+        Some(exc_type) => node_text(&exc_type, source) == ":",
+        None => true,
+    };
+    if !is_bare {
+        return;
+    }
+
+    lints.push(PythonLint {
+        line: node.start_position().row + 1,
+        category: PythonLintCategory::BareExcept,
+        severity: RiskLevel::Medium,
+        message: "bare `except:` also catches `KeyboardInterrupt` and `SystemExit`".to_string(),
+        suggestion: "catch `Exception` (or the specific exception type) instead of a bare `except:`"
+            .to_string(),
+    });
+}
+
+/// `list([...])`/`set([...])`/`dict([...])` wrapping a single argument that's already a
+/// collection literal or comprehension, whichever of those types it is.
+fn check_redundant_collection_call(node: &Node, source: &[u8], lints: &mut Vec<PythonLint>) {
+    let Some(function) = node.child_by_field_name("function") else {
+        return;
+    };
+    if function.kind() != "identifier" {
+        return;
+    }
+    let callee = node_text(&function, source);
+    if !matches!(callee.as_str(), "list" | "set" | "dict") {
+        return;
+    }
+
+    let Some(args) = node.child_by_field_name("arguments") else {
+        return;
+    };
+    let named: Vec<Node> = args.named_children(&mut args.walk()).collect();
+    let [arg] = named.as_slice() else {
+        return;
+    };
+
+    let already_iterable = matches!(
+        arg.kind(),
+        "list"
+            | "set"
+            | "dictionary"
+            | "list_comprehension"
+            | "set_comprehension"
+            | "dictionary_comprehension"
+            | "generator_expression"
+    );
+    if !already_iterable {
+        return;
+    }
+
+    lints.push(PythonLint {
+        line: node.start_position().row + 1,
+        category: PythonLintCategory::RedundantCollectionCall,
+        severity: RiskLevel::Low,
+        message: format!(
+            "`{callee}(...)` wraps an expression that's already a collection literal or comprehension"
+        ),
+        suggestion: format!("drop the redundant `{callee}(...)` wrapper"),
+    });
+}
+
+fn extract_error_info(
+    body: &Node,
+    source: &[u8],
+    file_path: &str,
+    function_name: &str,
+    impl_type: Option<&str>,
+    line: usize,
+    result: &mut ParsedFile,
+) {
+    let mut error_origins = Vec::new();
+    let mut propagation_points = Vec::new();
+    let mut exception_types = Vec::new();
 
     collect_error_patterns(
         body,
@@ -1131,31 +2057,42 @@ fn collect_error_patterns(
     match node.kind() {
         "raise_statement" => {
             let line = node.start_position().row + 1;
-            let exc_type = node.child(1).map(|c| {
-                let text = node_text(&c, source);
-                if let Some(paren_idx) = text.find('(') {
-                    text[..paren_idx].to_string()
-                } else {
-                    text
-                }
-            });
 
-            if let Some(ref exc) = exc_type {
-                if !exception_types.contains(exc) {
-                    exception_types.push(exc.clone());
+            // A bare `raise` (just the keyword, nothing after it) re-throws whatever exception
+            // is currently being handled rather than constructing a new one.
+            if node.child_count() <= 1 {
+                origins.push(ErrorOrigin {
+                    line,
+                    kind: ErrorOriginKind::BareReraise,
+                    message: None,
+                });
+            } else {
+                let exc_type = node.child(1).map(|c| {
+                    let text = node_text(&c, source);
+                    if let Some(paren_idx) = text.find('(') {
+                        text[..paren_idx].to_string()
+                    } else {
+                        text
+                    }
+                });
+
+                if let Some(ref exc) = exc_type {
+                    if !exception_types.contains(exc) {
+                        exception_types.push(exc.clone());
+                    }
                 }
-            }
 
-            let message = node.child(1).map(|c| {
-                let text = node_text(&c, source);
-                truncate_string(&text, 60)
-            });
+                let message = node.child(1).map(|c| {
+                    let text = node_text(&c, source);
+                    truncate_string(&text, 60)
+                });
 
-            origins.push(ErrorOrigin {
-                line,
-                kind: ErrorOriginKind::RaiseStatement,
-                message,
-            });
+                origins.push(ErrorOrigin {
+                    line,
+                    kind: ErrorOriginKind::RaiseStatement,
+                    message,
+                });
+            }
         }
         "assert_statement" => {
             let line = node.start_position().row + 1;
@@ -1169,40 +2106,55 @@ fn collect_error_patterns(
         "try_statement" => {
             let line = node.start_position().row + 1;
             let mut has_reraise = false;
+            let mut has_bare_except = false;
+            let mut has_else = false;
+            let mut has_finally = false;
             let mut caught_exceptions = Vec::new();
 
             let mut cursor = node.walk();
             for child in node.children(&mut cursor) {
-                if child.kind() == "except_clause" {
-                    if let Some(exc_type) = child.child(1) {
-                        let exc_text = node_text(&exc_type, source);
-                        if exc_text != ":" {
-                            caught_exceptions.push(exc_text);
+                match child.kind() {
+                    "except_clause" => {
+                        match child.child(1) {
+                            Some(exc_type) if node_text(&exc_type, source) != ":" => {
+                                caught_exceptions.push(node_text(&exc_type, source));
+                            }
+                            _ => has_bare_except = true,
                         }
-                    }
 
-                    let mut inner_cursor = child.walk();
-                    for inner in child.children(&mut inner_cursor) {
-                        if inner.kind() == "raise_statement" && inner.child_count() == 1 {
-                            has_reraise = true;
+                        let mut inner_cursor = child.walk();
+                        for inner in child.children(&mut inner_cursor) {
+                            if inner.kind() == "raise_statement" && inner.child_count() <= 1 {
+                                has_reraise = true;
+                            }
                         }
                     }
+                    "else_clause" => has_else = true,
+                    "finally_clause" => has_finally = true,
+                    _ => {}
                 }
             }
 
-            let desc = if caught_exceptions.is_empty() {
-                "try/except".to_string()
-            } else {
-                format!("try/except {}", caught_exceptions.join(", "))
+            let mut desc = match (caught_exceptions.is_empty(), has_bare_except) {
+                (true, true) => "try/except (bare)".to_string(),
+                (true, false) => "try".to_string(),
+                (false, true) => format!("try/except {}, (bare)", caught_exceptions.join(", ")),
+                (false, false) => format!("try/except {}", caught_exceptions.join(", ")),
             };
+            if has_reraise {
+                desc.push_str(" (re-raises)");
+            }
+            if has_else {
+                desc.push_str(" + else");
+            }
+            if has_finally {
+                desc.push_str(" + finally");
+            }
 
             propagations.push(PropagationPoint {
                 line,
-                expression: if has_reraise {
-                    format!("{} (re-raises)", desc)
-                } else {
-                    desc
-                },
+                expression: desc,
+                context: None,
             });
         }
         _ => {}
@@ -1474,3 +2426,694 @@ fn collect_key_calls_recursive(node: &Node, source: &[u8], calls: &mut Vec<Strin
         collect_key_calls_recursive(&child, source, calls);
     }
 }
+
+/// Walks `root`'s scope tree — module, then every nested function/lambda/class/comprehension
+/// frame — collecting every name [`Binding`] and resolving every identifier load to a
+/// [`NameReference`], the counterpart to [`extract_identifier_locations`]'s PascalCase-only
+/// cross-file lookup (left untouched; `run_phase2` in `pipeline.rs` still depends on it).
+fn resolve_scopes(root: &Node, source: &[u8]) -> (Vec<Binding>, Vec<NameReference>) {
+    let mut resolver = ScopeResolver::new();
+    resolver.walk(root, source);
+    (resolver.bindings, resolver.references)
+}
+
+struct ScopeFrame {
+    kind: ScopeKind,
+    bindings: HashMap<String, usize>,
+}
+
+struct ScopeResolver {
+    bindings: Vec<Binding>,
+    references: Vec<NameReference>,
+    frames: Vec<ScopeFrame>,
+}
+
+impl ScopeResolver {
+    fn new() -> Self {
+        Self {
+            bindings: Vec::new(),
+            references: Vec::new(),
+            frames: vec![ScopeFrame {
+                kind: ScopeKind::Module,
+                bindings: HashMap::new(),
+            }],
+        }
+    }
+
+    fn shadows_outer(&self, name: &str) -> bool {
+        let current_depth = self.frames.len() - 1;
+        self.frames[..current_depth]
+            .iter()
+            .rev()
+            .any(|frame| frame.kind != ScopeKind::Class && frame.bindings.contains_key(name))
+    }
+
+    fn bind(&mut self, name: &str, line: usize, kind: BindingKind) {
+        if name.is_empty() {
+            return;
+        }
+        let shadows_outer = self.shadows_outer(name);
+        let scope = self.frames.last().expect("module frame always present").kind;
+        let index = self.bindings.len();
+        self.bindings.push(Binding {
+            name: name.to_string(),
+            line,
+            kind,
+            scope,
+            used: false,
+            shadows_outer,
+        });
+        self.frames
+            .last_mut()
+            .expect("module frame always present")
+            .bindings
+            .insert(name.to_string(), index);
+    }
+
+    /// Records an identifier load, walking frames from innermost outward per Python's scope
+    /// chain: a `Class` frame is visible to its own body (when it's the frame directly
+    /// evaluating the load) but skipped as an ancestor of anything nested inside it.
+    fn load(&mut self, name: &str, line: usize) {
+        if name.is_empty() {
+            return;
+        }
+        let current_depth = self.frames.len() - 1;
+        let mut found = None;
+        for depth in (0..=current_depth).rev() {
+            let frame = &self.frames[depth];
+            if frame.kind == ScopeKind::Class && depth != current_depth {
+                continue;
+            }
+            if let Some(&index) = frame.bindings.get(name) {
+                found = Some((index, depth));
+                break;
+            }
+        }
+
+        let resolution = match found {
+            Some((index, depth)) => {
+                self.bindings[index].used = true;
+                if depth == current_depth {
+                    Resolution::Local
+                } else {
+                    Resolution::Enclosing
+                }
+            }
+            None => Resolution::BuiltinOrFree,
+        };
+
+        self.references.push(NameReference {
+            name: name.to_string(),
+            line,
+            resolution,
+        });
+    }
+
+    fn push_frame(&mut self, kind: ScopeKind) {
+        self.frames.push(ScopeFrame {
+            kind,
+            bindings: HashMap::new(),
+        });
+    }
+
+    fn pop_frame(&mut self) {
+        self.frames.pop();
+    }
+
+    /// `global x` re-points `x`, within the current frame only, at the module frame's binding —
+    /// creating one there first if this is the name's first appearance anywhere.
+    fn declare_global(&mut self, name: &str, line: usize) {
+        let index = match self.frames[0].bindings.get(name) {
+            Some(&index) => index,
+            None => {
+                let index = self.bindings.len();
+                self.bindings.push(Binding {
+                    name: name.to_string(),
+                    line,
+                    kind: BindingKind::Global,
+                    scope: ScopeKind::Module,
+                    used: false,
+                    shadows_outer: false,
+                });
+                self.frames[0].bindings.insert(name.to_string(), index);
+                index
+            }
+        };
+        self.frames
+            .last_mut()
+            .expect("module frame always present")
+            .bindings
+            .insert(name.to_string(), index);
+    }
+
+    /// `nonlocal x` re-points `x`, within the current frame only, at the nearest enclosing
+    /// function/lambda frame's binding (class frames don't count) — creating one there first if
+    /// that frame hadn't already bound it. A `nonlocal` with no enclosing function frame is
+    /// invalid Python; silently ignored rather than guessed at.
+    fn declare_nonlocal(&mut self, name: &str, line: usize) {
+        let current_depth = self.frames.len() - 1;
+        let target_depth = (0..current_depth).rev().find(|&depth| {
+            matches!(self.frames[depth].kind, ScopeKind::Function | ScopeKind::Lambda)
+        });
+        let Some(target_depth) = target_depth else {
+            return;
+        };
+        let index = match self.frames[target_depth].bindings.get(name) {
+            Some(&index) => index,
+            None => {
+                let index = self.bindings.len();
+                self.bindings.push(Binding {
+                    name: name.to_string(),
+                    line,
+                    kind: BindingKind::Nonlocal,
+                    scope: self.frames[target_depth].kind,
+                    used: false,
+                    shadows_outer: false,
+                });
+                self.frames[target_depth]
+                    .bindings
+                    .insert(name.to_string(), index);
+                index
+            }
+        };
+        self.frames
+            .last_mut()
+            .expect("module frame always present")
+            .bindings
+            .insert(name.to_string(), index);
+    }
+
+    /// Walks an assignment/`for`/`with`/comprehension target pattern, binding every plain
+    /// identifier it finds and skipping `attribute`/`subscript` targets (`self.x = ...`,
+    /// `d[k] = ...`), which mutate an existing object rather than bind a new name — their base
+    /// expression is still dispatched through [`Self::walk`] so e.g. `self`/`d` register as loads.
+    fn bind_target_identifiers(&mut self, node: &Node, source: &[u8], kind: BindingKind) {
+        match node.kind() {
+            "identifier" => {
+                let name = node_text(node, source);
+                let line = node.start_position().row + 1;
+                self.bind(&name, line, kind);
+            }
+            "attribute" | "subscript" => self.walk(node, source),
+            "tuple_pattern" | "list_pattern" | "pattern_list" | "list_splat_pattern" => {
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    self.bind_target_identifiers(&child, source, kind);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn bind_parameters(&mut self, parameters: &Node, source: &[u8]) {
+        let mut cursor = parameters.walk();
+        for child in parameters.children(&mut cursor) {
+            match child.kind() {
+                "identifier" => {
+                    let name = node_text(&child, source);
+                    let line = child.start_position().row + 1;
+                    self.bind(&name, line, BindingKind::Parameter);
+                }
+                "typed_parameter" | "default_parameter" | "typed_default_parameter" => {
+                    if let Some(name_node) = child.child_by_field_name("name").or_else(|| {
+                        let mut inner = child.walk();
+                        child
+                            .children(&mut inner)
+                            .find(|c| c.kind() == "identifier")
+                    }) {
+                        let name = node_text(&name_node, source);
+                        let line = name_node.start_position().row + 1;
+                        self.bind(&name, line, BindingKind::Parameter);
+                    }
+                }
+                "list_splat_pattern" | "dictionary_splat_pattern" => {
+                    if let Some(name_node) = child.named_child(0) {
+                        let name = node_text(&name_node, source);
+                        let line = name_node.start_position().row + 1;
+                        self.bind(&name, line, BindingKind::Parameter);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Default values and type annotations in a parameter list evaluate in the *enclosing*
+    /// scope, not the function's own frame — walked before [`Self::push_frame`], not after.
+    fn collect_parameter_defaults(&mut self, parameters: &Node, source: &[u8]) {
+        let mut cursor = parameters.walk();
+        for child in parameters.children(&mut cursor) {
+            match child.kind() {
+                "default_parameter" | "typed_default_parameter" => {
+                    if let Some(value) = child.child_by_field_name("value") {
+                        self.walk(&value, source);
+                    }
+                    if let Some(type_node) = child.child_by_field_name("type") {
+                        self.walk(&type_node, source);
+                    }
+                }
+                "typed_parameter" => {
+                    if let Some(type_node) = child.child_by_field_name("type") {
+                        self.walk(&type_node, source);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_function(&mut self, node: &Node, source: &[u8]) {
+        if let Some(parameters) = node.child_by_field_name("parameters") {
+            self.collect_parameter_defaults(&parameters, source);
+        }
+        if let Some(return_type) = node.child_by_field_name("return_type") {
+            self.walk(&return_type, source);
+        }
+
+        self.push_frame(ScopeKind::Function);
+        if let Some(parameters) = node.child_by_field_name("parameters") {
+            self.bind_parameters(&parameters, source);
+        }
+        if let Some(body) = node.child_by_field_name("body") {
+            self.walk(&body, source);
+        }
+        self.pop_frame();
+    }
+
+    fn visit_lambda(&mut self, node: &Node, source: &[u8]) {
+        if let Some(parameters) = node.child_by_field_name("parameters") {
+            self.collect_parameter_defaults(&parameters, source);
+        }
+
+        self.push_frame(ScopeKind::Lambda);
+        if let Some(parameters) = node.child_by_field_name("parameters") {
+            self.bind_parameters(&parameters, source);
+        }
+        if let Some(body) = node.child_by_field_name("body") {
+            self.walk(&body, source);
+        }
+        self.pop_frame();
+    }
+
+    fn visit_class(&mut self, node: &Node, source: &[u8]) {
+        // base classes and keyword arguments (e.g. `metaclass=...`) evaluate in the enclosing
+        // scope, not the class body's own frame.
+        if let Some(superclasses) = node.child_by_field_name("superclasses") {
+            self.walk(&superclasses, source);
+        }
+
+        self.push_frame(ScopeKind::Class);
+        if let Some(body) = node.child_by_field_name("body") {
+            self.walk(&body, source);
+        }
+        self.pop_frame();
+    }
+
+    /// Python 3 comprehensions don't leak their loop variables into the enclosing scope — that
+    /// falls out naturally here since the `Comprehension` frame is simply discarded on return.
+    fn visit_comprehension(&mut self, node: &Node, source: &[u8]) {
+        self.push_frame(ScopeKind::Comprehension);
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            match child.kind() {
+                "for_in_clause" => {
+                    if let Some(right) = child.child_by_field_name("right") {
+                        self.walk(&right, source);
+                    }
+                    if let Some(left) = child.child_by_field_name("left") {
+                        self.bind_target_identifiers(
+                            &left,
+                            source,
+                            BindingKind::ComprehensionTarget,
+                        );
+                    }
+                }
+                "if_clause" => {
+                    let mut inner = child.walk();
+                    for expr in child.named_children(&mut inner) {
+                        self.walk(&expr, source);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(body) = node.child_by_field_name("body") {
+            self.walk(&body, source);
+        }
+        if let Some(key) = node.child_by_field_name("key") {
+            self.walk(&key, source);
+        }
+        if let Some(value) = node.child_by_field_name("value") {
+            self.walk(&value, source);
+        }
+
+        self.pop_frame();
+    }
+
+    fn visit_assignment(&mut self, node: &Node, source: &[u8]) {
+        // the right-hand side evaluates against bindings as they stood *before* this statement,
+        // so it's walked first.
+        if let Some(right) = node.child_by_field_name("right") {
+            self.walk(&right, source);
+        }
+        if let Some(type_node) = node.child_by_field_name("type") {
+            self.walk(&type_node, source);
+        }
+        if let Some(left) = node.child_by_field_name("left") {
+            self.bind_target_identifiers(&left, source, BindingKind::Assignment);
+        }
+    }
+
+    fn visit_augmented_assignment(&mut self, node: &Node, source: &[u8]) {
+        if let Some(right) = node.child_by_field_name("right") {
+            self.walk(&right, source);
+        }
+        if let Some(left) = node.child_by_field_name("left") {
+            // `x += 1` reads `x` before rebinding it.
+            self.walk(&left, source);
+            self.bind_target_identifiers(&left, source, BindingKind::Assignment);
+        }
+    }
+
+    fn visit_for(&mut self, node: &Node, source: &[u8]) {
+        if let Some(right) = node.child_by_field_name("right") {
+            self.walk(&right, source);
+        }
+        if let Some(left) = node.child_by_field_name("left") {
+            self.bind_target_identifiers(&left, source, BindingKind::ForTarget);
+        }
+        if let Some(body) = node.child_by_field_name("body") {
+            self.walk(&body, source);
+        }
+        if let Some(alternative) = node.child_by_field_name("alternative") {
+            self.walk(&alternative, source);
+        }
+    }
+
+    fn visit_except_clause(&mut self, node: &Node, source: &[u8]) {
+        let mut cursor = node.walk();
+        let mut seen_as = false;
+        let mut alias = None;
+        let mut type_exprs = Vec::new();
+        for child in node.children(&mut cursor) {
+            match child.kind() {
+                "as" => seen_as = true,
+                ":" | "block" => {}
+                "identifier" if seen_as => alias = Some(child),
+                _ => type_exprs.push(child),
+            }
+        }
+        for expr in type_exprs {
+            self.walk(&expr, source);
+        }
+        if let Some(alias) = alias {
+            let line = alias.start_position().row + 1;
+            let name = node_text(&alias, source);
+            self.bind(&name, line, BindingKind::ExceptTarget);
+        }
+
+        let mut block_cursor = node.walk();
+        if let Some(block) = node
+            .children(&mut block_cursor)
+            .find(|c| c.kind() == "block")
+        {
+            self.walk(&block, source);
+        }
+    }
+
+    fn visit_import(&mut self, node: &Node, source: &[u8]) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            match child.kind() {
+                // `import a.b.c` binds only the top-level package name `a`.
+                "dotted_name" => {
+                    if let Some(first) = child.named_child(0) {
+                        let name = node_text(&first, source);
+                        let line = first.start_position().row + 1;
+                        self.bind(&name, line, BindingKind::Import);
+                    }
+                }
+                "aliased_import" => {
+                    if let Some(alias) = child.child_by_field_name("alias") {
+                        let name = node_text(&alias, source);
+                        let line = alias.start_position().row + 1;
+                        self.bind(&name, line, BindingKind::Import);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn visit_import_from(&mut self, node: &Node, source: &[u8]) {
+        let module_name = node.child_by_field_name("module_name");
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if module_name.as_ref().is_some_and(|m| m.id() == child.id()) {
+                continue;
+            }
+            match child.kind() {
+                "dotted_name" => {
+                    let name = node_text(&child, source);
+                    let line = child.start_position().row + 1;
+                    self.bind(&name, line, BindingKind::Import);
+                }
+                "aliased_import" => {
+                    if let Some(alias) = child.child_by_field_name("alias") {
+                        let name = node_text(&alias, source);
+                        let line = alias.start_position().row + 1;
+                        self.bind(&name, line, BindingKind::Import);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn walk(&mut self, node: &Node, source: &[u8]) {
+        match node.kind() {
+            "function_definition" | "async_function_definition" => {
+                return self.visit_function(node, source);
+            }
+            "lambda" => return self.visit_lambda(node, source),
+            "class_definition" => return self.visit_class(node, source),
+            "list_comprehension" | "set_comprehension" | "dictionary_comprehension"
+            | "generator_expression" => return self.visit_comprehension(node, source),
+            "assignment" | "annotated_assignment" => {
+                return self.visit_assignment(node, source);
+            }
+            "augmented_assignment" => return self.visit_augmented_assignment(node, source),
+            "for_statement" => return self.visit_for(node, source),
+            "with_item" => {
+                if let Some(value) = node.child_by_field_name("value") {
+                    self.walk(&value, source);
+                }
+                if let Some(alias) = node.child_by_field_name("alias") {
+                    self.bind_target_identifiers(&alias, source, BindingKind::WithTarget);
+                }
+                return;
+            }
+            "except_clause" => return self.visit_except_clause(node, source),
+            "import_statement" => return self.visit_import(node, source),
+            "import_from_statement" => return self.visit_import_from(node, source),
+            "global_statement" => {
+                let line = node.start_position().row + 1;
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    if child.kind() == "identifier" {
+                        self.declare_global(&node_text(&child, source), line);
+                    }
+                }
+                return;
+            }
+            "nonlocal_statement" => {
+                let line = node.start_position().row + 1;
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    if child.kind() == "identifier" {
+                        self.declare_nonlocal(&node_text(&child, source), line);
+                    }
+                }
+                return;
+            }
+            "attribute" => {
+                // the `attribute` field names a member, not a variable — not a name load.
+                if let Some(object) = node.child_by_field_name("object") {
+                    self.walk(&object, source);
+                }
+                return;
+            }
+            "subscript" => {
+                if let Some(value) = node.child_by_field_name("value") {
+                    self.walk(&value, source);
+                }
+                let mut cursor = node.walk();
+                for index in node.children_by_field_name("subscript", &mut cursor) {
+                    self.walk(&index, source);
+                }
+                return;
+            }
+            "keyword_argument" => {
+                // the `name` field is a parameter name, not a variable load.
+                if let Some(value) = node.child_by_field_name("value") {
+                    self.walk(&value, source);
+                }
+                return;
+            }
+            "identifier" => {
+                let line = node.start_position().row + 1;
+                self.load(&node_text(node, source), line);
+                return;
+            }
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.walk(&child, source);
+        }
+    }
+}
+
+/// Builds the document-symbol outline for the whole file: a `Module` root whose `children`
+/// mirror the true tree-sitter nesting of top-level classes/functions, each recursing into its
+/// own body for methods/nested functions. A second, independent pass over the tree alongside
+/// `extract_items`'s flat `Symbol` extraction, not threaded through it, matching this file's
+/// existing pattern of separate passes for separate concerns (see `collect_python_lints`,
+/// `resolve_scopes`).
+fn build_symbol_tree(root: &Node, source: &[u8], file_path: &str) -> SymbolTree {
+    let mut children = Vec::new();
+    let mut cursor = root.walk();
+    for child in root.children(&mut cursor) {
+        if let Some(tree) = build_symbol_tree_node(&child, source, false) {
+            children.push(tree);
+        }
+    }
+
+    SymbolTree {
+        kind: SymbolTreeKind::Module,
+        name: file_path.to_string(),
+        line: root.start_position().row + 1,
+        end_line: root.end_position().row + 1,
+        byte_range: (root.start_byte(), root.end_byte()),
+        selection_range: (root.start_byte(), root.start_byte()),
+        decorators: Vec::new(),
+        is_dunder: false,
+        docstring: None,
+        children,
+    }
+}
+
+fn build_symbol_tree_node(node: &Node, source: &[u8], in_class: bool) -> Option<SymbolTree> {
+    match node.kind() {
+        "class_definition" => Some(build_class_symbol_tree(node, source, Vec::new())),
+        "function_definition" | "async_function_definition" => {
+            Some(build_function_symbol_tree(node, source, Vec::new(), in_class))
+        }
+        "decorated_definition" => {
+            let decorators = extract_decorators(node, source)
+                .into_iter()
+                .map(|d| d.name)
+                .collect::<Vec<_>>();
+            let mut cursor = node.walk();
+            let inner = node.children(&mut cursor).find(|c| {
+                matches!(
+                    c.kind(),
+                    "class_definition" | "function_definition" | "async_function_definition"
+                )
+            })?;
+            Some(if inner.kind() == "class_definition" {
+                build_class_symbol_tree(&inner, source, decorators)
+            } else {
+                build_function_symbol_tree(&inner, source, decorators, in_class)
+            })
+        }
+        _ => None,
+    }
+}
+
+fn name_selection_range(node: &Node, source: &[u8]) -> (String, (usize, usize)) {
+    match node.child_by_field_name("name") {
+        Some(name_node) => (
+            node_text(&name_node, source),
+            (name_node.start_byte(), name_node.end_byte()),
+        ),
+        None => (String::new(), (node.start_byte(), node.start_byte())),
+    }
+}
+
+fn build_class_symbol_tree(node: &Node, source: &[u8], decorators: Vec<String>) -> SymbolTree {
+    let (name, selection_range) = name_selection_range(node, source);
+
+    let mut children = Vec::new();
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut cursor = body.walk();
+        for child in body.children(&mut cursor) {
+            if let Some(tree) = build_symbol_tree_node(&child, source, true) {
+                children.push(tree);
+            }
+        }
+    }
+
+    SymbolTree {
+        kind: SymbolTreeKind::Class,
+        name,
+        line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        byte_range: (node.start_byte(), node.end_byte()),
+        selection_range,
+        decorators,
+        is_dunder: false,
+        docstring: extract_function_docstring(node, source),
+        children,
+    }
+}
+
+fn build_function_symbol_tree(
+    node: &Node,
+    source: &[u8],
+    decorators: Vec<String>,
+    in_class: bool,
+) -> SymbolTree {
+    let (name, selection_range) = name_selection_range(node, source);
+
+    let is_property = decorators
+        .iter()
+        .any(|d| d == "property" || d.ends_with(".setter") || d.ends_with(".getter"));
+    let is_test = name.starts_with("test_") || decorators.iter().any(|d| d.starts_with("pytest.mark"));
+
+    let kind = if is_property {
+        SymbolTreeKind::Property
+    } else if is_test {
+        SymbolTreeKind::TestCase
+    } else if in_class {
+        SymbolTreeKind::Method
+    } else {
+        SymbolTreeKind::Function
+    };
+
+    let mut children = Vec::new();
+    if let Some(body) = node.child_by_field_name("body") {
+        let mut cursor = body.walk();
+        for child in body.children(&mut cursor) {
+            if let Some(tree) = build_symbol_tree_node(&child, source, false) {
+                children.push(tree);
+            }
+        }
+    }
+
+    SymbolTree {
+        kind,
+        is_dunder: is_dunder_method(&name),
+        name,
+        line: node.start_position().row + 1,
+        end_line: node.end_position().row + 1,
+        byte_range: (node.start_byte(), node.end_byte()),
+        selection_range,
+        decorators,
+        docstring: extract_function_docstring(node, source),
+        children,
+    }
+}
@@ -1,4 +1,4 @@
-use anyhow::{Result, anyhow};
+use anyhow::{anyhow, Result};
 use std::cell::RefCell;
 use tree_sitter::{Node, Parser, Tree};
 
@@ -11,9 +11,10 @@ use crate::extract::errors::{
 use crate::extract::imports::{ImportInfo, ReExport};
 use crate::extract::safety::{
     AsyncFunction, AsyncInfo, AwaitPoint, BlockingCall, BorrowInfo, CfgBlock, ComplexBound,
-    FeatureGate, FunctionLifetime, GatedSymbol, ItemConstraints, ItemDoc, LifetimeInfo, PanicKind,
-    PanicPoint, SafetyInfo, SpawnPoint, SpawnType, StructLifetime, TestFunction, TestInfo,
-    TestModule, TestedItem, TypeParam, UnsafeBlock, UnsafeImpl, UnsafeOperation,
+    Environment, FeatureGate, FunctionLifetime, GatedSymbol, ItemConstraints, ItemDoc,
+    LifetimeInfo, Mutability, PanicKind, PanicPoint, SafetyInfo, SpawnPoint, SpawnType,
+    StructLifetime, TestFunction, TestInfo, TestModule, TestedItem, TypeParam, TypeRef,
+    UnsafeBlock, UnsafeCallSignature, UnsafeImpl, UnsafeOperation,
 };
 use crate::extract::symbols::{
     AssociatedType, BodySummary, EnumVariant, FunctionBody, ImplMethod, InherentImpl, MacroInfo,
@@ -164,6 +165,7 @@ fn extract_struct(node: &Node, source: &[u8], result: &mut ParsedFile) {
         is_unsafe: false,
         is_const: false,
         re_exported_as: None,
+        doc_summary: None,
     });
 }
 
@@ -264,6 +266,7 @@ fn extract_enum(node: &Node, source: &[u8], result: &mut ParsedFile) {
         is_unsafe: false,
         is_const: false,
         re_exported_as: None,
+        doc_summary: None,
     });
 }
 
@@ -341,6 +344,7 @@ fn extract_trait(node: &Node, source: &[u8], result: &mut ParsedFile) {
         is_unsafe: false,
         is_const: false,
         re_exported_as: None,
+        doc_summary: None,
     });
 }
 
@@ -458,6 +462,7 @@ fn extract_function(node: &Node, source: &[u8], result: &mut ParsedFile) {
         is_unsafe,
         is_const,
         re_exported_as: None,
+        doc_summary: None,
     });
 }
 
@@ -502,6 +507,7 @@ fn extract_const(node: &Node, source: &[u8], result: &mut ParsedFile) {
         is_unsafe: false,
         is_const: true,
         re_exported_as: None,
+        doc_summary: None,
     });
 }
 
@@ -536,6 +542,7 @@ fn extract_static(node: &Node, source: &[u8], result: &mut ParsedFile) {
         is_unsafe: false,
         is_const: false,
         re_exported_as: None,
+        doc_summary: None,
     });
 }
 
@@ -602,6 +609,7 @@ fn extract_type_alias(node: &Node, source: &[u8], result: &mut ParsedFile) {
         is_unsafe: false,
         is_const: false,
         re_exported_as: None,
+        doc_summary: None,
     });
 }
 
@@ -635,6 +643,7 @@ fn extract_mod(node: &Node, source: &[u8], result: &mut ParsedFile) {
             is_unsafe: false,
             is_const: false,
             re_exported_as: None,
+            doc_summary: None,
         });
     }
 }
@@ -1567,10 +1576,105 @@ fn is_trivial_call(name: &str) -> bool {
 
 fn extract_safety_info(root: &Node, source: &[u8], result: &mut ParsedFile) {
     extract_unsafe_blocks(root, source, None, &mut result.safety);
-    extract_panic_points(root, source, None, &mut result.safety);
+
+    let mut array_lengths = std::collections::HashMap::new();
+    let mut const_values = std::collections::HashMap::new();
+    collect_const_array_info(root, source, &mut array_lengths, &mut const_values);
+
+    extract_panic_points(
+        root,
+        source,
+        None,
+        &array_lengths,
+        &const_values,
+        &mut result.safety,
+    );
     extract_unsafe_traits_and_impls(root, source, &mut result.safety);
 }
 
+/// Scans `const`/`static` array declarations for statically known lengths
+/// (from an explicit `[T; N]` type) and scalar `const` integer values, so
+/// index expressions referencing them by name can be checked at parse time.
+fn collect_const_array_info(
+    node: &Node,
+    source: &[u8],
+    array_lengths: &mut std::collections::HashMap<String, usize>,
+    const_values: &mut std::collections::HashMap<String, usize>,
+) {
+    if matches!(node.kind(), "const_item" | "static_item") {
+        if let Some(name) = find_child_text(node, "identifier", source) {
+            let len = node
+                .child_by_field_name("type")
+                .and_then(|t| array_type_length(&t, source))
+                .or_else(|| {
+                    node.child_by_field_name("value")
+                        .and_then(|v| array_literal_len(&v, source))
+                });
+
+            if let Some(len) = len {
+                array_lengths.insert(name.clone(), len);
+            } else if let Some(value) = node.child_by_field_name("value") {
+                if value.kind() == "integer_literal" {
+                    if let Some(n) = parse_usize_literal(&node_text(&value, source)) {
+                        const_values.insert(name, n);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_const_array_info(&child, source, array_lengths, const_values);
+    }
+}
+
+fn array_type_length(type_node: &Node, source: &[u8]) -> Option<usize> {
+    if type_node.kind() != "array_type" {
+        return None;
+    }
+    let length_node = type_node.child_by_field_name("length")?;
+    parse_usize_literal(&node_text(&length_node, source))
+}
+
+fn array_literal_len(node: &Node, source: &[u8]) -> Option<usize> {
+    if node.kind() != "array_expression" {
+        return None;
+    }
+
+    let mut cursor = node.walk();
+    let children: Vec<_> = node.children(&mut cursor).collect();
+
+    if children.iter().any(|c| c.kind() == ";") {
+        let repeat_len = children.last().filter(|c| c.kind() != "]")?;
+        return parse_usize_literal(&node_text(repeat_len, source));
+    }
+
+    Some(
+        children
+            .iter()
+            .filter(|c| !matches!(c.kind(), "[" | "]" | ","))
+            .count(),
+    )
+}
+
+/// Parses a literal's leading digits as a `usize`, stripping `_` separators
+/// and any trailing type suffix (e.g. `5usize`, `5_i32`). Returns `None` on
+/// overflow or if no digits are present.
+fn parse_usize_literal(text: &str) -> Option<usize> {
+    let digits: String = text
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == '_')
+        .filter(|c| *c != '_')
+        .collect();
+
+    if digits.is_empty() {
+        return None;
+    }
+
+    digits.parse::<usize>().ok()
+}
+
 fn extract_unsafe_blocks(
     node: &Node,
     source: &[u8],
@@ -1601,11 +1705,17 @@ fn extract_unsafe_blocks(
 }
 
 fn collect_unsafe_operations(node: &Node, source: &[u8], operations: &mut Vec<UnsafeOperation>) {
+    let root = find_root(node);
+
     match node.kind() {
         "dereference_expression" => {
             let text = node_text(node, source);
             if text.starts_with('*') {
-                operations.push(UnsafeOperation::RawPointerDeref);
+                let (pointee, mutability) = node
+                    .child_by_field_name("value")
+                    .and_then(|base| resolve_local_pointer_type(&root, &node_text(&base, source), source))
+                    .unwrap_or((TypeRef::Named { path: "_".to_string() }, Mutability::Not));
+                operations.push(UnsafeOperation::RawPointerDeref { pointee, mutability });
             }
         }
         "call_expression" => {
@@ -1613,7 +1723,8 @@ fn collect_unsafe_operations(node: &Node, source: &[u8], operations: &mut Vec<Un
                 let text = node_text(&func, source);
                 if text.contains("::") && !text.starts_with("std::") && !text.starts_with("core::")
                 {
-                    operations.push(UnsafeOperation::UnsafeFunctionCall(text));
+                    let sig = resolve_call_signature(&root, &text, source);
+                    operations.push(UnsafeOperation::UnsafeFunctionCall(text, sig));
                 }
             }
         }
@@ -1633,7 +1744,9 @@ fn collect_unsafe_operations(node: &Node, source: &[u8], operations: &mut Vec<Un
                     if parent.kind() == "assignment_expression"
                         || parent.kind() == "compound_assignment_expr"
                     {
-                        operations.push(UnsafeOperation::MutableStaticAccess(text));
+                        let type_ref = resolve_static_type(&root, &text, source)
+                            .unwrap_or(TypeRef::Named { path: "_".to_string() });
+                        operations.push(UnsafeOperation::MutableStaticAccess(text, type_ref));
                     }
                 }
             }
@@ -1647,10 +1760,187 @@ fn collect_unsafe_operations(node: &Node, source: &[u8], operations: &mut Vec<Un
     }
 }
 
+fn find_root(node: &Node) -> Node {
+    let mut current = *node;
+    while let Some(parent) = current.parent() {
+        current = parent;
+    }
+    current
+}
+
+/// Parses a type node into a [`TypeRef`], recognizing the handful of shapes tree-sitter's Rust
+/// grammar distinguishes (`pointer_type`, `reference_type`, `array_type`, `primitive_type`);
+/// anything else falls back to [`TypeRef::Named`] with the raw source text as its path.
+fn parse_type_ref(node: &Node, source: &[u8]) -> TypeRef {
+    match node.kind() {
+        "pointer_type" => {
+            let mutability = if find_child_text(node, "mutable_specifier", source).is_some() {
+                Mutability::Mut
+            } else {
+                Mutability::Not
+            };
+            let inner = node
+                .child_by_field_name("type")
+                .map(|t| parse_type_ref(&t, source))
+                .unwrap_or(TypeRef::Named { path: "_".to_string() });
+            TypeRef::RawPtr(Box::new(inner), mutability)
+        }
+        "reference_type" => {
+            let region = find_child_text(node, "lifetime", source)
+                .map(|lifetime| lifetime.trim_start_matches('\'').to_string());
+            let mutability = if find_child_text(node, "mutable_specifier", source).is_some() {
+                Mutability::Mut
+            } else {
+                Mutability::Not
+            };
+            let referent = node
+                .child_by_field_name("type")
+                .map(|t| parse_type_ref(&t, source))
+                .unwrap_or(TypeRef::Named { path: "_".to_string() });
+            TypeRef::Ref {
+                region,
+                mutability,
+                referent: Box::new(referent),
+            }
+        }
+        "array_type" => {
+            let element = node
+                .child_by_field_name("element")
+                .map(|t| parse_type_ref(&t, source))
+                .unwrap_or(TypeRef::Named { path: "_".to_string() });
+            let len = node
+                .child_by_field_name("length")
+                .and_then(|length| node_text(&length, source).parse::<usize>().ok());
+            TypeRef::Array {
+                element: Box::new(element),
+                len,
+            }
+        }
+        "primitive_type" => TypeRef::Primitive(node_text(node, source)),
+        _ => TypeRef::Named {
+            path: node_text(node, source),
+        },
+    }
+}
+
+/// Best-effort lookup of `base_name`'s declared type, by scanning every `let_declaration` and
+/// function parameter in `root` for a pattern matching that name whose type is a `pointer_type`.
+/// There's no type checker here, so this only finds pointers declared with an explicit
+/// annotation in the same file — a field projection, a cast, or a type inferred from a generic
+/// parameter all fall through to `None`.
+fn resolve_local_pointer_type(root: &Node, base_name: &str, source: &[u8]) -> Option<(TypeRef, Mutability)> {
+    fn visit(node: &Node, base_name: &str, source: &[u8]) -> Option<TypeRef> {
+        let is_match = matches!(node.kind(), "let_declaration" | "parameter")
+            && find_child_text(node, "identifier", source).as_deref() == Some(base_name);
+        if is_match {
+            if let Some(type_node) = node.child_by_field_name("type") {
+                return Some(parse_type_ref(&type_node, source));
+            }
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if let Some(found) = visit(&child, base_name, source) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    match visit(root, base_name, source)? {
+        TypeRef::RawPtr(inner, mutability) => Some((*inner, mutability)),
+        other => Some((other, Mutability::Not)),
+    }
+}
+
+/// Best-effort lookup of a `static`/`static mut` item's declared type, by name, anywhere in
+/// `root`.
+fn resolve_static_type(root: &Node, name: &str, source: &[u8]) -> Option<TypeRef> {
+    fn visit(node: &Node, name: &str, source: &[u8]) -> Option<TypeRef> {
+        if node.kind() == "static_item"
+            && find_child_text(node, "identifier", source).as_deref() == Some(name)
+        {
+            if let Some(type_node) = node.child_by_field_name("type") {
+                return Some(parse_type_ref(&type_node, source));
+            }
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if let Some(found) = visit(&child, name, source) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    visit(root, name, source)
+}
+
+/// Best-effort lookup of a called function's ABI and structured parameter/return types, by
+/// matching `call_text` against every `function_item`'s name in `root` (the declaration is
+/// assumed to live in the same file, since there's no cross-file resolution here). An `extern`
+/// ABI is read off the nearest enclosing `extern_modifier`/`foreign_mod_item`, if any.
+fn resolve_call_signature(root: &Node, call_text: &str, source: &[u8]) -> UnsafeCallSignature {
+    let short_name = call_text.rsplit("::").next().unwrap_or(call_text);
+
+    fn visit<'a>(node: &Node<'a>, short_name: &str, source: &[u8]) -> Option<Node<'a>> {
+        if matches!(node.kind(), "function_item" | "function_signature_item")
+            && find_child_text(node, "identifier", source).as_deref() == Some(short_name)
+        {
+            return Some(*node);
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if let Some(found) = visit(&child, short_name, source) {
+                return Some(found);
+            }
+        }
+        None
+    }
+
+    let Some(function_node) = visit(root, short_name, source) else {
+        return UnsafeCallSignature {
+            abi: None,
+            args: Vec::new(),
+            return_type: None,
+        };
+    };
+
+    let abi = function_node
+        .parent()
+        .filter(|parent| parent.kind() == "foreign_mod_item")
+        .and_then(|foreign_mod| find_child_text(&foreign_mod, "string_literal", source))
+        .map(|literal| literal.trim_matches('"').to_string());
+
+    let args = function_node
+        .child_by_field_name("parameters")
+        .map(|params| {
+            let mut cursor = params.walk();
+            params
+                .children(&mut cursor)
+                .filter(|child| child.kind() == "parameter")
+                .filter_map(|param| param.child_by_field_name("type"))
+                .map(|type_node| parse_type_ref(&type_node, source))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let return_type = function_node
+        .child_by_field_name("return_type")
+        .map(|type_node| parse_type_ref(&type_node, source));
+
+    UnsafeCallSignature {
+        abi,
+        args,
+        return_type,
+    }
+}
+
 fn extract_panic_points(
     node: &Node,
     source: &[u8],
     containing_fn: Option<&str>,
+    array_lengths: &std::collections::HashMap<String, usize>,
+    const_values: &std::collections::HashMap<String, usize>,
     safety: &mut SafetyInfo,
 ) {
     let current_fn = if node.kind() == "function_item" {
@@ -1692,18 +1982,21 @@ fn extract_panic_points(
         "macro_invocation" => {
             let text = node_text(node, source);
             let kind = if text.starts_with("panic!") {
-                Some(PanicKind::PanicMacro)
+                Some(PanicKind::PanicMacro(macro_format_reason(node, source)))
             } else if text.starts_with("unreachable!") {
-                Some(PanicKind::UnreachableMacro)
+                Some(PanicKind::UnreachableMacro(macro_format_reason(
+                    node, source,
+                )))
             } else if text.starts_with("todo!") {
-                Some(PanicKind::TodoMacro)
+                Some(PanicKind::TodoMacro(macro_format_reason(node, source)))
             } else if text.starts_with("unimplemented!") {
-                Some(PanicKind::UnimplementedMacro)
-            } else if text.starts_with("assert!")
-                || text.starts_with("assert_eq!")
-                || text.starts_with("assert_ne!")
-            {
-                Some(PanicKind::Assert)
+                Some(PanicKind::UnimplementedMacro(macro_format_reason(
+                    node, source,
+                )))
+            } else if text.starts_with("assert_eq!") || text.starts_with("assert_ne!") {
+                Some(PanicKind::Assert(macro_message_reason(node, source, 2)))
+            } else if text.starts_with("assert!") {
+                Some(PanicKind::Assert(macro_message_reason(node, source, 1)))
             } else {
                 None
             };
@@ -1719,9 +2012,11 @@ fn extract_panic_points(
         "index_expression" => {
             let text = node_text(node, source);
             if !text.contains("get(") && !text.contains("get_mut(") {
+                let kind = definite_out_of_bounds(node, source, array_lengths, const_values)
+                    .unwrap_or(PanicKind::IndexAccess);
                 safety.panic_points.push(PanicPoint {
                     line,
-                    kind: PanicKind::IndexAccess,
+                    kind,
                     containing_function: current_fn.clone(),
                     context: Some(text),
                 });
@@ -1732,7 +2027,136 @@ fn extract_panic_points(
 
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        extract_panic_points(&child, source, current_fn.as_deref(), safety);
+        extract_panic_points(
+            &child,
+            source,
+            current_fn.as_deref(),
+            array_lengths,
+            const_values,
+            safety,
+        );
+    }
+}
+
+/// Checks whether an `index_expression` is a compile-time-known
+/// out-of-bounds access: the base is an array literal or a named
+/// `const`/`static` array of known length, and the index is a literal (or
+/// simple const) `>= len`. Range indices (slicing) are left alone since
+/// they don't necessarily panic.
+fn definite_out_of_bounds(
+    node: &Node,
+    source: &[u8],
+    array_lengths: &std::collections::HashMap<String, usize>,
+    const_values: &std::collections::HashMap<String, usize>,
+) -> Option<PanicKind> {
+    let operand = node.child_by_field_name("operand")?;
+    let index_node = node.child_by_field_name("index")?;
+
+    if index_node.kind() == "range_expression" {
+        return None;
+    }
+
+    let len = array_literal_len(&operand, source).or_else(|| {
+        if operand.kind() == "identifier" {
+            array_lengths.get(&node_text(&operand, source)).copied()
+        } else {
+            None
+        }
+    })?;
+
+    let index = match index_node.kind() {
+        "integer_literal" => parse_usize_literal(&node_text(&index_node, source)),
+        "identifier" => const_values.get(&node_text(&index_node, source)).copied(),
+        _ => None,
+    }?;
+
+    if index >= len {
+        Some(PanicKind::DefiniteOutOfBounds { index, len })
+    } else {
+        None
+    }
+}
+
+/// Extracts the format-string literal passed as the first argument to a
+/// `panic!`/`unreachable!`/`todo!`/`unimplemented!` invocation, if any.
+fn macro_format_reason(node: &Node, source: &[u8]) -> Option<String> {
+    let args = split_macro_args(&macro_args_text(node, source)?);
+    string_literal_value(args.first()?)
+}
+
+/// Extracts the message literal from an `assert!`/`assert_eq!`/`assert_ne!`
+/// invocation, which comes after `skip` leading arguments (the condition,
+/// or the two compared expressions).
+fn macro_message_reason(node: &Node, source: &[u8], skip: usize) -> Option<String> {
+    let args = split_macro_args(&macro_args_text(node, source)?);
+    string_literal_value(args.get(skip)?)
+}
+
+fn macro_args_text(node: &Node, source: &[u8]) -> Option<String> {
+    let mut cursor = node.walk();
+    let token_tree = node
+        .children(&mut cursor)
+        .find(|c| c.kind() == "token_tree")?;
+    let text = node_text(&token_tree, source);
+    Some(
+        text.trim()
+            .strip_prefix('(')?
+            .strip_suffix(')')
+            .unwrap_or(text.trim())
+            .to_string(),
+    )
+}
+
+/// Splits a macro argument list on top-level commas, ignoring commas nested
+/// inside brackets or string literals.
+fn split_macro_args(args_text: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+    let mut chars = args_text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                in_string = !in_string;
+                current.push(c);
+            }
+            '\\' if in_string => {
+                current.push(c);
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+            }
+            '(' | '[' | '{' if !in_string => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' | ']' | '}' if !in_string => {
+                depth -= 1;
+                current.push(c);
+            }
+            ',' if !in_string && depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+
+    parts
+}
+
+fn string_literal_value(text: &str) -> Option<String> {
+    let trimmed = text.trim();
+    if trimmed.len() >= 2 && trimmed.starts_with('"') && trimmed.ends_with('"') {
+        Some(trimmed[1..trimmed.len() - 1].to_string())
+    } else {
+        None
     }
 }
 
@@ -2024,30 +2448,13 @@ fn extract_blocking_calls(
         let text = node_text(node, source);
         let line = node.start_position().row + 1;
 
-        const BLOCKING_CALLS: &[&str] = &[
-            "std::fs::",
-            "std::io::",
-            "std::net::",
-            "std::thread::sleep",
-            "thread::sleep",
-            ".read(",
-            ".write(",
-            ".read_to_string",
-            ".read_to_end",
-            "File::open",
-            "File::create",
-        ];
-
-        for pattern in BLOCKING_CALLS {
-            if text.contains(pattern) {
-                async_info.blocking_calls.push(BlockingCall {
-                    line,
-                    call: text.clone(),
-                    in_async_context: current_async,
-                    containing_function: current_fn.clone(),
-                });
-                break;
-            }
+        if crate::extract::safety::classify_blocking_call(&text).is_some() {
+            async_info.blocking_calls.push(BlockingCall {
+                line,
+                call: text.clone(),
+                in_async_context: current_async,
+                containing_function: current_fn.clone(),
+            });
         }
     }
 
@@ -2080,6 +2487,40 @@ fn extract_feature_flags(root: &Node, source: &[u8], result: &mut ParsedFile) {
             symbols,
         });
     }
+
+    detect_no_std_core(root, source, result);
+}
+
+/// Detects `#![no_std]` and `extern crate alloc;`, the standard embedded
+/// pattern of a `core`-only crate that opts into `alloc` explicitly and
+/// re-enables `std` behind a `feature = "std"` gate.
+fn detect_no_std_core(root: &Node, source: &[u8], result: &mut ParsedFile) {
+    let mut cursor = root.walk();
+    for child in root.children(&mut cursor) {
+        match child.kind() {
+            "inner_attribute_item" => {
+                let text = node_text(&child, source);
+                if text.contains("no_std") {
+                    result.feature_flags.no_std = true;
+                }
+            }
+            "extern_crate_declaration" => {
+                let text = node_text(&child, source);
+                if text.contains("alloc") {
+                    result.feature_flags.uses_alloc_extern_crate = true;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn symbol_environment(feature_name: &str) -> Environment {
+    match feature_name {
+        "std" => Environment::Std,
+        "alloc" => Environment::Alloc,
+        _ => Environment::Core,
+    }
 }
 
 fn collect_feature_gated_items(
@@ -2105,10 +2546,12 @@ fn collect_feature_gated_items(
                         let (name, kind) = get_item_name_and_kind(next, source);
                         if !name.is_empty() {
                             let line = next.start_position().row + 1;
+                            let environment = symbol_environment(&feature);
                             feature_map.entry(feature).or_default().push(GatedSymbol {
                                 name,
                                 kind,
                                 line,
+                                environment,
                             });
                         }
                     }
@@ -1,8 +1,11 @@
 use anyhow::Result;
+use ignore::overrides::OverrideBuilder;
+use ignore::types::{Types, TypesBuilder};
 use ignore::WalkBuilder;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
+use tokio::sync::mpsc;
 
 use crate::extract::language::Language;
 
@@ -10,77 +13,282 @@ pub struct WalkResult {
     pub files: Vec<PathBuf>,
     #[allow(dead_code)]
     pub language_counts: HashMap<Language, usize>,
+    /// Paths the walker couldn't read (permission denied, broken symlinks, symlink loops),
+    /// rendered via `ignore::Error`'s `Display` impl. Previously these were silently dropped.
+    pub errors: Vec<String>,
+}
+
+/// One discovery from the parallel walk, sent as soon as the worker thread finds it rather
+/// than after the whole tree has been scanned.
+pub enum WorkerResult {
+    File(PathBuf),
+    Error(ignore::Error),
+}
+
+/// Drives which languages the walker selects and which directories/globs it skips, so adding
+/// a language or tuning ignores doesn't require editing the walk itself. `extra_ignores` are
+/// plain directory/glob names (matched anywhere in the tree, like a `.gitignore` entry); a
+/// `.charterignore` file at the walk root, if present, contributes additional patterns in the
+/// same `.gitignore` syntax.
+pub struct WalkConfig {
+    pub languages: Vec<Language>,
+    pub extra_ignores: Vec<String>,
+    /// Mirrors `rg --no-ignore`: skips `.gitignore`, global git excludes, `.git/info/exclude`,
+    /// and `.charterignore` entirely, falling back to walking everything except
+    /// `extra_ignores`'s hardcoded build-artifact directories.
+    pub no_ignore: bool,
+}
+
+impl Default for WalkConfig {
+    fn default() -> Self {
+        Self {
+            languages: vec![Language::Rust, Language::Python],
+            extra_ignores: vec![
+                ".charter".to_string(),
+                ".git".to_string(),
+                "target".to_string(),
+                "__pycache__".to_string(),
+                ".venv".to_string(),
+                "venv".to_string(),
+            ],
+            no_ignore: false,
+        }
+    }
+}
+
+impl WalkConfig {
+    /// `ignore::types::Types` selecting only the extensions of `self.languages`, so the walker
+    /// filters files the same way `rg --type rust` or rustc bootstrap's `select("rust")` would.
+    fn build_types(&self) -> Result<Types> {
+        let mut builder = TypesBuilder::new();
+        builder.add_defaults();
+
+        for language in &self.languages {
+            let name = language.name().to_lowercase();
+            for ext in language.extensions() {
+                builder.add(&name, &format!("*.{}", ext))?;
+            }
+            builder.select(&name);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// `ignore::overrides::Override` excluding `self.extra_ignores` plus any patterns from a
+    /// `.charterignore` file at `root`. Override globs are a whitelist by default, so every
+    /// pattern here is negated to get plain `.gitignore`-style exclude behavior. Exposed at
+    /// `pub(crate)` so [`super::watch`] can filter filesystem events with the same rules the
+    /// walker itself uses, instead of duplicating the glob logic.
+    pub(crate) fn build_overrides(&self, root: &Path) -> Result<ignore::overrides::Override> {
+        let mut builder = OverrideBuilder::new(root);
+
+        for ignored in &self.extra_ignores {
+            builder.add(&format!("!{}/**", ignored))?;
+            builder.add(&format!("!{}", ignored))?;
+        }
+
+        if !self.no_ignore {
+            if let Ok(contents) = std::fs::read_to_string(root.join(".charterignore")) {
+                for line in contents.lines() {
+                    let line = line.trim();
+                    if line.is_empty() || line.starts_with('#') {
+                        continue;
+                    }
+                    let pattern = match line.strip_prefix('!') {
+                        Some(re_include) => re_include.to_string(),
+                        None => format!("!{}", line),
+                    };
+                    builder.add(&pattern)?;
+                }
+            }
+        }
+
+        Ok(builder.build()?)
+    }
+
+    fn build_walker(&self, root: &Path) -> Result<ignore::WalkParallel> {
+        Ok(WalkBuilder::new(root)
+            .hidden(false)
+            .ignore(!self.no_ignore)
+            .git_ignore(!self.no_ignore)
+            .git_global(!self.no_ignore)
+            .git_exclude(!self.no_ignore)
+            .parents(true)
+            .types(self.build_types()?)
+            .overrides(self.build_overrides(root)?)
+            .threads(num_cpus::get())
+            .build_parallel())
+    }
 }
 
 pub async fn walk_directory(root: &Path) -> Result<WalkResult> {
+    walk_directory_with_config(root, &WalkConfig::default()).await
+}
+
+pub async fn walk_directory_with_config(root: &Path, config: &WalkConfig) -> Result<WalkResult> {
     let root = root.to_path_buf();
+    let walker = config.build_walker(&root)?;
 
-    tokio::task::spawn_blocking(move || walk_directory_sync(&root)).await?
+    tokio::task::spawn_blocking(move || walk_directory_sync(walker)).await?
 }
 
-fn walk_directory_sync(root: &Path) -> Result<WalkResult> {
+fn walk_directory_sync(walker: ignore::WalkParallel) -> Result<WalkResult> {
     let files = Mutex::new(Vec::new());
-
-    let walker = WalkBuilder::new(root)
-        .hidden(false)
-        .ignore(true)
-        .git_ignore(true)
-        .git_global(true)
-        .git_exclude(true)
-        .parents(true)
-        .threads(num_cpus::get())
-        .build_parallel();
+    let errors = Mutex::new(Vec::new());
 
     walker.run(|| {
         let files = &files;
+        let errors = &errors;
 
         Box::new(move |entry| {
             let entry = match entry {
                 Ok(e) => e,
-                Err(_) => return ignore::WalkState::Continue,
+                Err(e) => {
+                    errors.lock().expect("lock poisoned").push(e.to_string());
+                    return ignore::WalkState::Continue;
+                }
             };
 
             if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
                 return ignore::WalkState::Continue;
             }
 
-            let path = entry.path();
+            files
+                .lock()
+                .expect("lock poisoned")
+                .push(entry.path().to_path_buf());
 
-            if path.starts_with(root.join(".charter")) {
-                return ignore::WalkState::Continue;
-            }
+            ignore::WalkState::Continue
+        })
+    });
 
-            if path.starts_with(root.join("target")) {
-                return ignore::WalkState::Continue;
-            }
+    let files = files.into_inner().expect("lock poisoned");
+    let errors = errors.into_inner().expect("lock poisoned");
 
-            if path.starts_with(root.join("__pycache__")) {
-                return ignore::WalkState::Continue;
-            }
+    let mut language_counts = HashMap::new();
+    for file in &files {
+        if let Some(lang) = Language::from_path(file) {
+            *language_counts.entry(lang).or_insert(0) += 1;
+        }
+    }
 
-            if path.starts_with(root.join(".venv")) || path.starts_with(root.join("venv")) {
-                return ignore::WalkState::Continue;
-            }
+    Ok(WalkResult {
+        files,
+        language_counts,
+        errors,
+    })
+}
 
-            if path.starts_with(root.join(".git")) {
-                return ignore::WalkState::Continue;
-            }
+/// Streaming counterpart to [`walk_directory`]: sends each discovered file (or walk error)
+/// over a bounded channel as soon as the worker thread finds it, instead of collecting the
+/// whole tree into a `Vec` before returning. Lets a caller start extracting files concurrently
+/// with discovery, which decouples memory use from repo size on large monorepos.
+///
+/// Returns the receiving half directly rather than `impl Stream` since nothing else in this
+/// crate depends on the `futures`/`tokio-stream` combinator crates; callers drain it with
+/// `while let Some(result) = rx.recv().await`.
+pub async fn walk_directory_streaming(root: &Path) -> mpsc::Receiver<WorkerResult> {
+    walk_directory_streaming_with_config(root, &WalkConfig::default()).await
+}
 
-            if let Some(ext) = path.extension() {
-                let ext_str = ext.to_str().unwrap_or("");
-                if ext_str == "rs" || ext_str == "py" || ext_str == "pyi" {
-                    files
-                        .lock()
-                        .expect("lock poisoned")
-                        .push(path.to_path_buf());
+pub async fn walk_directory_streaming_with_config(
+    root: &Path,
+    config: &WalkConfig,
+) -> mpsc::Receiver<WorkerResult> {
+    let (tx, rx) = mpsc::channel(256);
+    let walker = match config.build_walker(root) {
+        Ok(walker) => walker,
+        Err(e) => {
+            let io_error = ignore::Error::Io(std::io::Error::other(e));
+            let _ = tx.send(WorkerResult::Error(io_error)).await;
+            return rx;
+        }
+    };
+
+    tokio::task::spawn_blocking(move || {
+        walker.run(|| {
+            let tx = tx.clone();
+
+            Box::new(move |entry| {
+                let entry = match entry {
+                    Ok(e) => e,
+                    Err(e) => {
+                        let _ = tx.blocking_send(WorkerResult::Error(e));
+                        return ignore::WalkState::Continue;
+                    }
+                };
+
+                if entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+                    return ignore::WalkState::Continue;
                 }
-            }
 
-            ignore::WalkState::Continue
-        })
+                if tx
+                    .blocking_send(WorkerResult::File(entry.path().to_path_buf()))
+                    .is_err()
+                {
+                    return ignore::WalkState::Quit;
+                }
+
+                ignore::WalkState::Continue
+            })
+        });
     });
 
-    let files = files.into_inner().expect("lock poisoned");
+    rx
+}
+
+pub(crate) fn is_extracted_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| Language::from_extension(ext).is_some())
+}
+
+/// Git-aware counterpart to [`walk_directory`]: restricts the returned file set to paths
+/// changed relative to `base_ref` (or the working-tree diff against `HEAD` when `base_ref` is
+/// `None`), so re-running charter on a large repo only re-extracts what changed. Falls back to
+/// a full walk when `root` isn't a git repository.
+#[allow(dead_code)]
+pub async fn walk_directory_since(root: &Path, base_ref: Option<&str>) -> Result<WalkResult> {
+    let Some(changed_paths) = crate::git::changed_paths_since(root, base_ref).await else {
+        return walk_directory(root).await;
+    };
+
+    let mut files = Vec::new();
+    for path in changed_paths {
+        let full_path = root.join(&path);
+        if is_extracted_extension(&full_path) && full_path.is_file() {
+            files.push(full_path);
+        }
+    }
+
+    let mut language_counts = HashMap::new();
+    for file in &files {
+        if let Some(lang) = Language::from_path(file) {
+            *language_counts.entry(lang).or_insert(0) += 1;
+        }
+    }
+
+    Ok(WalkResult {
+        files,
+        language_counts,
+        errors: Vec::new(),
+    })
+}
+
+/// Drains [`walk_directory_streaming`] into a [`WalkResult`], for callers that want the
+/// streaming worker behavior without needing to pipeline extraction themselves.
+#[allow(dead_code)]
+pub async fn walk_directory_via_stream(root: &Path) -> Result<WalkResult> {
+    let mut rx = walk_directory_streaming(root).await;
+    let mut files = Vec::new();
+    let mut errors = Vec::new();
+
+    while let Some(result) = rx.recv().await {
+        match result {
+            WorkerResult::File(path) => files.push(path),
+            WorkerResult::Error(e) => errors.push(e.to_string()),
+        }
+    }
 
     let mut language_counts = HashMap::new();
     for file in &files {
@@ -92,5 +300,6 @@ fn walk_directory_sync(root: &Path) -> Result<WalkResult> {
     Ok(WalkResult {
         files,
         language_counts,
+        errors,
     })
 }
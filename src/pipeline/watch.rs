@@ -0,0 +1,136 @@
+use anyhow::Result;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+use super::walk::{WalkConfig, is_extracted_extension};
+
+/// Minimal set of source files that changed since the last delta, produced by
+/// [`watch_directory`] instead of re-walking the whole tree on every filesystem event.
+#[derive(Debug, Default)]
+pub struct WalkDelta {
+    pub added: Vec<PathBuf>,
+    pub changed: Vec<PathBuf>,
+    pub removed: Vec<PathBuf>,
+}
+
+impl WalkDelta {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PendingKind {
+    Added,
+    Changed,
+    Removed,
+}
+
+/// How long to wait after the last filesystem event before flushing a [`WalkDelta`], so a
+/// burst of rapid saves (or an editor's write-then-rename) coalesces into one delta instead of
+/// triggering extraction per keystroke.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watches `root` for filesystem changes and yields a debounced [`WalkDelta`] each time source
+/// files settle, filtered through the same ignore/gitignore rules `config` drives the walker
+/// with (so events under `target`, `.git`, `.venv`, etc. never reach the caller).
+///
+/// Returns the receiving half of a channel rather than `impl Stream` for the same reason
+/// [`super::walk::walk_directory_streaming`] does: this crate has no dependency on the
+/// `futures`/`tokio-stream` combinator crates. Drain it with `while let Some(delta) = rx.recv().await`.
+pub async fn watch_directory(root: &Path, config: WalkConfig) -> Result<mpsc::Receiver<WalkDelta>> {
+    let root = root.to_path_buf();
+    let overrides = config.build_overrides(&root)?;
+
+    let (raw_tx, mut raw_rx) = mpsc::channel::<Event>(1024);
+
+    let mut watcher: RecommendedWatcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        if let Ok(event) = res {
+            let _ = raw_tx.blocking_send(event);
+        }
+    })?;
+    watcher.watch(&root, RecursiveMode::Recursive)?;
+
+    let (delta_tx, delta_rx) = mpsc::channel(16);
+
+    tokio::spawn(async move {
+        // Keep the watcher alive for as long as this task runs; dropping it would stop events.
+        let _watcher = watcher;
+        let mut pending: HashMap<PathBuf, PendingKind> = HashMap::new();
+
+        while let Some(event) = raw_rx.recv().await {
+            record_event(&root, &overrides, event, &mut pending);
+
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep(DEBOUNCE) => break,
+                    maybe_event = raw_rx.recv() => {
+                        match maybe_event {
+                            Some(event) => record_event(&root, &overrides, event, &mut pending),
+                            None => break,
+                        }
+                    }
+                }
+            }
+
+            let delta = drain_delta(&mut pending);
+            if !delta.is_empty() && delta_tx.send(delta).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok(delta_rx)
+}
+
+fn record_event(
+    root: &Path,
+    overrides: &ignore::overrides::Override,
+    event: Event,
+    pending: &mut HashMap<PathBuf, PendingKind>,
+) {
+    for path in event.paths {
+        if !is_extracted_extension(&path) {
+            continue;
+        }
+
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        if overrides.matched(relative, false).is_ignore() {
+            continue;
+        }
+
+        match event.kind {
+            EventKind::Remove(_) => {
+                pending.insert(path, PendingKind::Removed);
+            }
+            EventKind::Create(_) => {
+                let kind = match pending.get(&path) {
+                    Some(PendingKind::Removed) => PendingKind::Changed,
+                    _ => PendingKind::Added,
+                };
+                pending.insert(path, kind);
+            }
+            EventKind::Modify(_) => {
+                pending.entry(path).or_insert(PendingKind::Changed);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn drain_delta(pending: &mut HashMap<PathBuf, PendingKind>) -> WalkDelta {
+    let mut delta = WalkDelta::default();
+
+    for (path, kind) in pending.drain() {
+        match kind {
+            PendingKind::Added => delta.added.push(path),
+            PendingKind::Changed => delta.changed.push(path),
+            PendingKind::Removed => delta.removed.push(path),
+        }
+    }
+
+    delta
+}
@@ -2,7 +2,7 @@ use anyhow::Result;
 use std::path::Path;
 use tokio::fs;
 
-pub async fn query(root: &Path, query_str: &str, limit: usize) -> Result<()> {
+pub async fn query(root: &Path, query_str: &str, limit: usize, json: bool) -> Result<()> {
     let atlas_dir = root.join(".atlas");
 
     if !atlas_dir.exists() {
@@ -14,59 +14,93 @@ pub async fn query(root: &Path, query_str: &str, limit: usize) -> Result<()> {
 
     match query_type {
         QueryType::CallersOf { target } => {
-            find_callers(&atlas_dir, &target, limit).await?;
+            find_callers(&atlas_dir, &target, limit, json).await?;
         }
         QueryType::CalleesOf { target } => {
-            find_callees(&atlas_dir, &target, limit).await?;
+            find_callees(&atlas_dir, &target, limit, json).await?;
+        }
+        QueryType::CallersOfDepth { target, depth } => {
+            find_callers_depth(&atlas_dir, &target, depth, limit, json).await?;
+        }
+        QueryType::CalleesOfDepth { target, depth } => {
+            find_callees_depth(&atlas_dir, &target, depth, limit, json).await?;
+        }
+        QueryType::CallPath { from, to } => {
+            find_call_path(&atlas_dir, &from, &to, limit, json).await?;
         }
         QueryType::ImplementorsOf { trait_name } => {
-            find_implementors(&atlas_dir, &trait_name, limit).await?;
+            find_implementors(&atlas_dir, &trait_name, limit, json).await?;
         }
         QueryType::UsersOf { symbol } => {
-            find_users(&atlas_dir, &symbol, limit).await?;
+            find_users(&atlas_dir, &symbol, limit, json).await?;
         }
         QueryType::ErrorsIn { file } => {
-            find_errors_in(&atlas_dir, &file, limit).await?;
+            find_errors_in(&atlas_dir, &file, limit, json).await?;
         }
         QueryType::Hotspots => {
-            find_hotspots(&atlas_dir, limit).await?;
+            find_hotspots(&atlas_dir, limit, json).await?;
+        }
+        QueryType::Risk => {
+            find_risk(&atlas_dir, limit, json).await?;
         }
         QueryType::PublicApi => {
-            find_public_api(&atlas_dir, limit).await?;
+            find_public_api(&atlas_dir, limit, json).await?;
         }
         QueryType::Panics => {
-            find_panics(&atlas_dir, limit).await?;
+            find_panics(&atlas_dir, limit, json).await?;
         }
         QueryType::PanicsIn { file } => {
-            find_panics_in(&atlas_dir, &file, limit).await?;
+            find_panics_in(&atlas_dir, &file, limit, json).await?;
         }
         QueryType::UnsafeCode => {
-            find_unsafe_code(&atlas_dir, limit).await?;
+            find_unsafe_code(&atlas_dir, limit, json).await?;
         }
         QueryType::AsyncFunctions => {
-            find_async_functions(&atlas_dir, limit).await?;
+            find_async_functions(&atlas_dir, limit, json).await?;
         }
         QueryType::Lifetimes => {
-            find_lifetimes(&atlas_dir, limit).await?;
+            find_lifetimes(&atlas_dir, limit, json).await?;
         }
         QueryType::Tests => {
-            find_tests(&atlas_dir, limit).await?;
+            find_tests(&atlas_dir, limit, json).await?;
         }
-        QueryType::Keyword { terms } => {
-            keyword_search(&atlas_dir, &terms, limit).await?;
+        QueryType::Keyword { query: keyword } => {
+            keyword_search(&atlas_dir, &keyword, limit, json).await?;
         }
     }
 
     Ok(())
 }
 
+/// Prints `results` as a single JSON envelope (`query_type`, `target`, `count`, `results`)
+/// instead of the usual prose, the `--json` counterpart every query handler falls back to
+/// instead of growing its own ad hoc envelope shape.
+fn print_json_results(
+    query_type: &str,
+    target: Option<&str>,
+    results: Vec<serde_json::Value>,
+) -> Result<()> {
+    let payload = serde_json::json!({
+        "query_type": query_type,
+        "target": target,
+        "count": results.len(),
+        "results": results,
+    });
+    println!("{}", serde_json::to_string_pretty(&payload)?);
+    Ok(())
+}
+
 enum QueryType {
     CallersOf { target: String },
     CalleesOf { target: String },
+    CallersOfDepth { target: String, depth: usize },
+    CalleesOfDepth { target: String, depth: usize },
+    CallPath { from: String, to: String },
     ImplementorsOf { trait_name: String },
     UsersOf { symbol: String },
     ErrorsIn { file: String },
     Hotspots,
+    Risk,
     PublicApi,
     Panics,
     PanicsIn { file: String },
@@ -74,20 +108,39 @@ enum QueryType {
     AsyncFunctions,
     Lifetimes,
     Tests,
-    Keyword { terms: Vec<String> },
+    Keyword { query: String },
 }
 
 fn parse_query(query: &str) -> QueryType {
     let query_lower = query.to_lowercase();
 
+    if query_lower.starts_with("path from ") {
+        let rest = query[10..].trim();
+        if let Some(idx) = rest.to_lowercase().find(" to ") {
+            let from = rest[..idx].trim().to_string();
+            let to = rest[idx + 4..].trim().to_string();
+            return QueryType::CallPath { from, to };
+        }
+    }
+
     if query_lower.starts_with("callers of ") {
-        let target = query[11..].trim().to_string();
-        return QueryType::CallersOf { target };
+        let rest = query[11..].trim();
+        return match split_depth_suffix(rest) {
+            Some((target, depth)) => QueryType::CallersOfDepth { target, depth },
+            None => QueryType::CallersOf {
+                target: rest.to_string(),
+            },
+        };
     }
 
     if query_lower.starts_with("callees of ") || query_lower.starts_with("calls from ") {
-        let target = query[11..].trim().to_string();
-        return QueryType::CalleesOf { target };
+        let rest = query[11..].trim();
+        return match split_depth_suffix(rest) {
+            Some((target, depth)) => QueryType::CalleesOfDepth { target, depth },
+            None => QueryType::CalleesOf {
+                target: rest.to_string(),
+            },
+        };
     }
 
     if query_lower.starts_with("implementors of ") || query_lower.starts_with("impls of ") {
@@ -119,6 +172,10 @@ fn parse_query(query: &str) -> QueryType {
         return QueryType::Hotspots;
     }
 
+    if query_lower == "risk" || query_lower == "risky" || query_lower == "refactor candidates" {
+        return QueryType::Risk;
+    }
+
     if query_lower == "public api" || query_lower == "public functions" || query_lower == "exports"
     {
         return QueryType::PublicApi;
@@ -149,23 +206,31 @@ fn parse_query(query: &str) -> QueryType {
         return QueryType::Tests;
     }
 
-    let terms: Vec<String> = query
-        .split_whitespace()
-        .map(|s| s.to_lowercase())
-        .filter(|s| s.len() > 2)
-        .collect();
+    QueryType::Keyword {
+        query: query.to_string(),
+    }
+}
 
-    QueryType::Keyword { terms }
+/// Splits a trailing `depth N` suffix off of a `callers of`/`callees of` target, so `callers of
+/// foo depth 3` parses as a depth-bounded query instead of treating `foo depth 3` as the literal
+/// target name.
+fn split_depth_suffix(text: &str) -> Option<(String, usize)> {
+    let idx = text.to_lowercase().rfind(" depth ")?;
+    let depth: usize = text[idx + 7..].trim().parse().ok()?;
+    Some((text[..idx].trim().to_string(), depth))
 }
 
-async fn find_callers(atlas_dir: &Path, target: &str, limit: usize) -> Result<()> {
+async fn find_callers(atlas_dir: &Path, target: &str, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("calls.md")).await?;
 
-    println!("Callers of '{}':", target);
-    println!();
+    if !json {
+        println!("Callers of '{}':", target);
+        println!();
+    }
 
     let target_lower = target.to_lowercase();
     let mut found = 0;
+    let mut results = Vec::new();
 
     for line in content.lines() {
         if line.starts_with("  ") && line.contains(" → ") {
@@ -175,7 +240,11 @@ async fn find_callers(atlas_dir: &Path, target: &str, limit: usize) -> Result<()
                 let callees_lower = callees.to_lowercase();
                 if callees_lower.contains(&target_lower) {
                     let caller = parts[0];
-                    println!("  {} calls {}", caller, target);
+                    if json {
+                        results.push(serde_json::json!({"caller": caller, "callee": target}));
+                    } else {
+                        println!("  {} calls {}", caller, target);
+                    }
                     found += 1;
                     if found >= limit {
                         break;
@@ -185,8 +254,13 @@ async fn find_callers(atlas_dir: &Path, target: &str, limit: usize) -> Result<()
         }
     }
 
+    if json {
+        return print_json_results("callers_of", Some(target), results);
+    }
+
     if found == 0 {
         println!("  No callers found for '{}'", target);
+        print_did_you_mean(&content, target);
     } else {
         println!();
         println!("Found {} caller(s)", found);
@@ -195,14 +269,17 @@ async fn find_callers(atlas_dir: &Path, target: &str, limit: usize) -> Result<()
     Ok(())
 }
 
-async fn find_callees(atlas_dir: &Path, target: &str, limit: usize) -> Result<()> {
+async fn find_callees(atlas_dir: &Path, target: &str, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("calls.md")).await?;
 
-    println!("Callees of '{}':", target);
-    println!();
+    if !json {
+        println!("Callees of '{}':", target);
+        println!();
+    }
 
     let target_lower = target.to_lowercase();
     let mut found = 0;
+    let mut results = Vec::new();
 
     for line in content.lines() {
         if line.starts_with("  ") && line.contains(" → ") {
@@ -211,7 +288,12 @@ async fn find_callees(atlas_dir: &Path, target: &str, limit: usize) -> Result<()
                 let caller = parts[0].to_lowercase();
                 if caller.contains(&target_lower) {
                     let callees = parts[1];
-                    println!("  {} → {}", parts[0], callees);
+                    if json {
+                        let callee_list: Vec<&str> = callees.split(',').map(str::trim).collect();
+                        results.push(serde_json::json!({"caller": parts[0], "callees": callee_list}));
+                    } else {
+                        println!("  {} → {}", parts[0], callees);
+                    }
                     found += 1;
                     if found >= limit {
                         break;
@@ -221,8 +303,13 @@ async fn find_callees(atlas_dir: &Path, target: &str, limit: usize) -> Result<()
         }
     }
 
+    if json {
+        return print_json_results("callees_of", Some(target), results);
+    }
+
     if found == 0 {
         println!("  No callees found for '{}'", target);
+        print_did_you_mean(&content, target);
     } else {
         println!();
         println!("Found {} match(es)", found);
@@ -231,15 +318,358 @@ async fn find_callees(atlas_dir: &Path, target: &str, limit: usize) -> Result<()
     Ok(())
 }
 
-async fn find_implementors(atlas_dir: &Path, trait_name: &str, limit: usize) -> Result<()> {
+/// Every `caller → callee` edge in `calls.md`, one entry per callee on a comma-separated line.
+fn parse_call_edges(content: &str) -> Vec<(String, String)> {
+    let mut edges = Vec::new();
+
+    for line in content.lines() {
+        if line.starts_with("  ") && line.contains(" → ") {
+            let parts: Vec<&str> = line.trim().splitn(2, " → ").collect();
+            if parts.len() == 2 {
+                for callee in parts[1].split(',') {
+                    edges.push((parts[0].to_string(), callee.trim().to_string()));
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+fn build_adjacency(edges: &[(String, String)]) -> std::collections::HashMap<String, Vec<String>> {
+    let mut map: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
+    for (caller, callee) in edges {
+        map.entry(caller.clone()).or_default().push(callee.clone());
+    }
+    map
+}
+
+fn build_reverse_adjacency(
+    edges: &[(String, String)],
+) -> std::collections::HashMap<String, Vec<String>> {
+    let mut map: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
+    for (caller, callee) in edges {
+        map.entry(callee.clone()).or_default().push(caller.clone());
+    }
+    map
+}
+
+/// Breadth-first reachability from every node in `start` out to `max_depth` hops, returning
+/// each newly-reached node with the depth it was first reached at (closest first).
+fn bfs_reachable(
+    adjacency: &std::collections::HashMap<String, Vec<String>>,
+    start: &[String],
+    max_depth: usize,
+) -> Vec<(String, usize)> {
+    let mut visited: std::collections::HashSet<String> = start.iter().cloned().collect();
+    let mut queue: std::collections::VecDeque<(String, usize)> =
+        start.iter().map(|node| (node.clone(), 0)).collect();
+    let mut result = Vec::new();
+
+    while let Some((node, depth)) = queue.pop_front() {
+        if depth >= max_depth {
+            continue;
+        }
+
+        let Some(neighbors) = adjacency.get(&node) else {
+            continue;
+        };
+
+        for neighbor in neighbors {
+            if visited.insert(neighbor.clone()) {
+                result.push((neighbor.clone(), depth + 1));
+                queue.push_back((neighbor.clone(), depth + 1));
+            }
+        }
+    }
+
+    result
+}
+
+/// Caps the work a [`find_call_paths`] search will do before giving up on a dense graph, so a
+/// pathological adjacency list can't turn `path from X to Y` into an unbounded search.
+const MAX_CALL_PATH_EXPANSIONS: usize = 20_000;
+
+/// Finds up to `k` shortest simple paths from `from` to a node whose name contains `to_lower`,
+/// via a priority queue keyed by path length: pop the cheapest partial path, expand its frontier
+/// node's successors, and emit paths as soon as they reach the target. Cycles are avoided by
+/// skipping any neighbor already on the current path, rather than tracking a global visited set,
+/// since the same node can legitimately appear on different candidate paths.
+fn find_call_paths(
+    adjacency: &std::collections::HashMap<String, Vec<String>>,
+    from: &str,
+    to_lower: &str,
+    k: usize,
+) -> Vec<Vec<String>> {
+    let mut heap: std::collections::BinaryHeap<std::cmp::Reverse<(usize, Vec<String>)>> =
+        std::collections::BinaryHeap::new();
+    heap.push(std::cmp::Reverse((0, vec![from.to_string()])));
+
+    let mut results = Vec::new();
+    let mut expansions = 0;
+
+    while let Some(std::cmp::Reverse((_, path))) = heap.pop() {
+        if results.len() >= k || expansions >= MAX_CALL_PATH_EXPANSIONS {
+            break;
+        }
+        expansions += 1;
+
+        let last = path.last().expect("path always has at least one node");
+        if path.len() > 1 && last.to_lowercase().contains(to_lower) {
+            results.push(path);
+            continue;
+        }
+
+        let Some(neighbors) = adjacency.get(last) else {
+            continue;
+        };
+
+        for neighbor in neighbors {
+            if path.contains(neighbor) {
+                continue;
+            }
+            let mut next_path = path.clone();
+            next_path.push(neighbor.clone());
+            heap.push(std::cmp::Reverse((next_path.len(), next_path)));
+        }
+    }
+
+    results
+}
+
+async fn find_callers_depth(
+    atlas_dir: &Path,
+    target: &str,
+    depth: usize,
+    limit: usize,
+    json: bool,
+) -> Result<()> {
+    let content = fs::read_to_string(atlas_dir.join("calls.md")).await?;
+    let reverse = build_reverse_adjacency(&parse_call_edges(&content));
+
+    if !json {
+        println!("Callers of '{}' within {} hop(s):", target, depth);
+        println!();
+    }
+
+    let target_lower = target.to_lowercase();
+    let start_nodes: Vec<String> = collect_call_graph_names(&content)
+        .into_iter()
+        .filter(|name| name.to_lowercase().contains(&target_lower))
+        .collect();
+
+    if start_nodes.is_empty() {
+        if json {
+            return print_json_results("callers_of_depth", Some(target), Vec::new());
+        }
+        println!("  No symbol matching '{}' found", target);
+        print_did_you_mean(&content, target);
+        return Ok(());
+    }
+
+    let mut reached = bfs_reachable(&reverse, &start_nodes, depth);
+    reached.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    reached.truncate(limit);
+
+    if json {
+        let results = reached
+            .iter()
+            .map(|(name, hop)| serde_json::json!({"name": name, "depth": hop}))
+            .collect();
+        return print_json_results("callers_of_depth", Some(target), results);
+    }
+
+    if reached.is_empty() {
+        println!("  No callers found for '{}'", target);
+    } else {
+        for (name, hop) in &reached {
+            println!("  {} (depth {})", name, hop);
+        }
+        println!();
+        println!("Found {} caller(s)", reached.len());
+    }
+
+    Ok(())
+}
+
+async fn find_callees_depth(
+    atlas_dir: &Path,
+    target: &str,
+    depth: usize,
+    limit: usize,
+    json: bool,
+) -> Result<()> {
+    let content = fs::read_to_string(atlas_dir.join("calls.md")).await?;
+    let adjacency = build_adjacency(&parse_call_edges(&content));
+
+    if !json {
+        println!("Callees of '{}' within {} hop(s):", target, depth);
+        println!();
+    }
+
+    let target_lower = target.to_lowercase();
+    let start_nodes: Vec<String> = collect_call_graph_names(&content)
+        .into_iter()
+        .filter(|name| name.to_lowercase().contains(&target_lower))
+        .collect();
+
+    if start_nodes.is_empty() {
+        if json {
+            return print_json_results("callees_of_depth", Some(target), Vec::new());
+        }
+        println!("  No symbol matching '{}' found", target);
+        print_did_you_mean(&content, target);
+        return Ok(());
+    }
+
+    let mut reached = bfs_reachable(&adjacency, &start_nodes, depth);
+    reached.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    reached.truncate(limit);
+
+    if json {
+        let results = reached
+            .iter()
+            .map(|(name, hop)| serde_json::json!({"name": name, "depth": hop}))
+            .collect();
+        return print_json_results("callees_of_depth", Some(target), results);
+    }
+
+    if reached.is_empty() {
+        println!("  No callees found for '{}'", target);
+    } else {
+        for (name, hop) in &reached {
+            println!("  {} (depth {})", name, hop);
+        }
+        println!();
+        println!("Found {} callee(s)", reached.len());
+    }
+
+    Ok(())
+}
+
+async fn find_call_path(
+    atlas_dir: &Path,
+    from: &str,
+    to: &str,
+    limit: usize,
+    json: bool,
+) -> Result<()> {
+    let content = fs::read_to_string(atlas_dir.join("calls.md")).await?;
+    let adjacency = build_adjacency(&parse_call_edges(&content));
+
+    if !json {
+        println!("Path from '{}' to '{}':", from, to);
+        println!();
+    }
+
+    let from_lower = from.to_lowercase();
+    let mut start_nodes: Vec<String> = collect_call_graph_names(&content)
+        .into_iter()
+        .filter(|name| name.to_lowercase().contains(&from_lower))
+        .collect();
+    start_nodes.sort();
+
+    if start_nodes.is_empty() {
+        if json {
+            return print_json_results("call_path", Some(from), Vec::new());
+        }
+        println!("  No symbol matching '{}' found", from);
+        print_did_you_mean(&content, from);
+        return Ok(());
+    }
+
+    let to_lower = to.to_lowercase();
+    let k = limit.max(1);
+    let mut paths = Vec::new();
+    for start in &start_nodes {
+        paths.extend(find_call_paths(&adjacency, start, &to_lower, k));
+        if paths.len() >= k {
+            break;
+        }
+    }
+    paths.truncate(limit);
+
+    if json {
+        let results = paths
+            .iter()
+            .map(|path| serde_json::json!({"path": path}))
+            .collect();
+        return print_json_results("call_path", Some(from), results);
+    }
+
+    if paths.is_empty() {
+        println!("  No path found from '{}' to '{}'", from, to);
+    } else {
+        for path in &paths {
+            println!("  {}", path.join(" → "));
+        }
+        println!();
+        println!("Found {} path(s)", paths.len());
+    }
+
+    Ok(())
+}
+
+/// Names appearing as either side of a `caller → callee` edge in `calls.md`, deduplicated, for
+/// suggesting "did you mean" candidates when a `callers of`/`callees of` target isn't found.
+fn collect_call_graph_names(content: &str) -> Vec<String> {
+    let mut names = std::collections::HashSet::new();
+
+    for line in content.lines() {
+        if line.starts_with("  ") && line.contains(" → ") {
+            let parts: Vec<&str> = line.trim().splitn(2, " → ").collect();
+            if parts.len() == 2 {
+                names.insert(parts[0].to_string());
+                for callee in parts[1].split(',') {
+                    names.insert(callee.trim().to_string());
+                }
+            }
+        }
+    }
+
+    names.into_iter().collect()
+}
+
+/// Prints the closest call-graph names to `target` by edit distance, the same "did you mean"
+/// treatment [`output::lookup`] gives an unmatched symbol.
+fn print_did_you_mean(content: &str, target: &str) {
+    let target_lower = target.to_lowercase();
+    let max_distance = crate::output::suggestion_distance_threshold(target.len());
+
+    let mut candidates: Vec<(String, usize)> = collect_call_graph_names(content)
+        .into_iter()
+        .map(|name| {
+            let distance = crate::output::levenshtein_distance(&name.to_lowercase(), &target_lower);
+            (name, distance)
+        })
+        .filter(|(_, distance)| *distance <= max_distance)
+        .collect();
+
+    candidates.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    candidates.dedup_by(|a, b| a.0 == b.0);
+
+    if candidates.is_empty() {
+        return;
+    }
+
+    println!("  Did you mean:");
+    for (name, _) in candidates.iter().take(5) {
+        println!("    {}", name);
+    }
+}
+
+async fn find_implementors(atlas_dir: &Path, trait_name: &str, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("types.md")).await?;
 
-    println!("Implementors of '{}':", trait_name);
-    println!();
+    if !json {
+        println!("Implementors of '{}':", trait_name);
+        println!();
+    }
 
     let trait_lower = trait_name.to_lowercase();
     let mut found = 0;
     let mut in_impls = false;
+    let mut results = Vec::new();
 
     for line in content.lines() {
         if line == "Impls:" {
@@ -258,7 +688,14 @@ async fn find_implementors(atlas_dir: &Path, trait_name: &str, limit: usize) ->
                     let impl_trait = parts[0].to_lowercase();
                     if impl_trait.contains(&trait_lower) {
                         let types = parts[1].trim_start_matches('[').trim_end_matches(']');
-                        println!("  {} implements {}", types, parts[0]);
+                        if json {
+                            let type_list: Vec<&str> = types.split(',').map(str::trim).collect();
+                            results.push(
+                                serde_json::json!({"trait": parts[0], "types": type_list}),
+                            );
+                        } else {
+                            println!("  {} implements {}", types, parts[0]);
+                        }
                         found += 1;
                         if found >= limit {
                             break;
@@ -269,6 +706,10 @@ async fn find_implementors(atlas_dir: &Path, trait_name: &str, limit: usize) ->
         }
     }
 
+    if json {
+        return print_json_results("implementors_of", Some(trait_name), results);
+    }
+
     if found == 0 {
         println!("  No implementors found for '{}'", trait_name);
     } else {
@@ -279,14 +720,17 @@ async fn find_implementors(atlas_dir: &Path, trait_name: &str, limit: usize) ->
     Ok(())
 }
 
-async fn find_users(atlas_dir: &Path, symbol: &str, limit: usize) -> Result<()> {
+async fn find_users(atlas_dir: &Path, symbol: &str, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("refs.md")).await?;
 
-    println!("References to '{}':", symbol);
-    println!();
+    if !json {
+        println!("References to '{}':", symbol);
+        println!();
+    }
 
     let symbol_lower = symbol.to_lowercase();
     let mut found = 0;
+    let mut results = Vec::new();
 
     for line in content.lines() {
         if line.starts_with('[') || line.is_empty() {
@@ -295,7 +739,11 @@ async fn find_users(atlas_dir: &Path, symbol: &str, limit: usize) -> Result<()>
 
         if let Some((name_part, _)) = line.split_once(" [") {
             if name_part.to_lowercase() == symbol_lower {
-                println!("  {}", line);
+                if json {
+                    results.push(serde_json::json!({"line": line}));
+                } else {
+                    println!("  {}", line);
+                }
                 found += 1;
                 if found >= limit {
                     break;
@@ -304,6 +752,10 @@ async fn find_users(atlas_dir: &Path, symbol: &str, limit: usize) -> Result<()>
         }
     }
 
+    if json {
+        return print_json_results("users_of", Some(symbol), results);
+    }
+
     if found == 0 {
         let symbols_content = fs::read_to_string(atlas_dir.join("symbols.md"))
             .await
@@ -335,15 +787,18 @@ async fn find_users(atlas_dir: &Path, symbol: &str, limit: usize) -> Result<()>
     Ok(())
 }
 
-async fn find_errors_in(atlas_dir: &Path, file: &str, limit: usize) -> Result<()> {
+async fn find_errors_in(atlas_dir: &Path, file: &str, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("errors.md")).await?;
 
-    println!("Errors in '{}':", file);
-    println!();
+    if !json {
+        println!("Errors in '{}':", file);
+        println!();
+    }
 
     let file_lower = file.to_lowercase();
     let mut found = 0;
     let mut current_matches = false;
+    let mut results: Vec<serde_json::Value> = Vec::new();
 
     for line in content.lines() {
         if line.contains(":") && !line.starts_with("  ") && !line.starts_with("#") {
@@ -351,17 +806,33 @@ async fn find_errors_in(atlas_dir: &Path, file: &str, limit: usize) -> Result<()
             current_matches = file_path.to_lowercase().contains(&file_lower);
 
             if current_matches {
-                println!("{}", line);
+                if json {
+                    results.push(serde_json::json!({"header": line, "details": []}));
+                } else {
+                    println!("{}", line);
+                }
                 found += 1;
                 if found >= limit {
                     break;
                 }
             }
         } else if current_matches && line.starts_with("  ") {
-            println!("{}", line);
+            if json {
+                if let Some(last) = results.last_mut() {
+                    if let Some(details) = last.get_mut("details").and_then(|d| d.as_array_mut()) {
+                        details.push(serde_json::Value::String(line.trim().to_string()));
+                    }
+                }
+            } else {
+                println!("{}", line);
+            }
         }
     }
 
+    if json {
+        return print_json_results("errors_in", Some(file), results);
+    }
+
     if found == 0 {
         println!("  No error patterns found in '{}'", file);
     }
@@ -369,17 +840,24 @@ async fn find_errors_in(atlas_dir: &Path, file: &str, limit: usize) -> Result<()
     Ok(())
 }
 
-async fn find_hotspots(atlas_dir: &Path, limit: usize) -> Result<()> {
+async fn find_hotspots(atlas_dir: &Path, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("hotspots.md")).await?;
 
-    println!("Top {} hotspots by importance:", limit);
-    println!();
+    if !json {
+        println!("Top {} hotspots by importance:", limit);
+        println!();
+    }
 
     let mut found = 0;
+    let mut results = Vec::new();
 
     for line in content.lines() {
         if line.contains("[score=") && !line.starts_with("#") && !line.starts_with("[") {
-            println!("  {}", line);
+            if json {
+                results.push(parse_hotspot_line(line));
+            } else {
+                println!("  {}", line);
+            }
             found += 1;
             if found >= limit {
                 break;
@@ -387,6 +865,10 @@ async fn find_hotspots(atlas_dir: &Path, limit: usize) -> Result<()> {
         }
     }
 
+    if json {
+        return print_json_results("hotspots", None, results);
+    }
+
     if found == 0 {
         println!("  No hotspots found");
     }
@@ -394,14 +876,96 @@ async fn find_hotspots(atlas_dir: &Path, limit: usize) -> Result<()> {
     Ok(())
 }
 
-async fn find_public_api(atlas_dir: &Path, limit: usize) -> Result<()> {
+/// Parses a `hotspots.md` line (`{file}:{line} {qualified_name} [score={score}] ({details})`,
+/// see `output::hotspots`) into structured fields, falling back to `{"line": line}` if the
+/// format doesn't match — a defensive fallback, not an expected path.
+fn parse_hotspot_line(line: &str) -> serde_json::Value {
+    let Some((location, rest)) = line.trim().split_once(' ') else {
+        return serde_json::json!({"line": line});
+    };
+    let Some((name, rest)) = rest.split_once(" [score=") else {
+        return serde_json::json!({"line": line});
+    };
+    let Some((score, rest)) = rest.split_once(']') else {
+        return serde_json::json!({"line": line});
+    };
+    let details = rest.trim().trim_start_matches('(').trim_end_matches(')');
+    let Some((file, line_no)) = location.rsplit_once(':') else {
+        return serde_json::json!({"line": line});
+    };
+
+    serde_json::json!({
+        "file": file,
+        "line": line_no.parse::<usize>().ok(),
+        "name": name,
+        "score": score.parse::<f64>().ok(),
+        "details": details.split(", ").filter(|d| !d.is_empty()).collect::<Vec<_>>(),
+    })
+}
+
+async fn find_risk(atlas_dir: &Path, limit: usize, json: bool) -> Result<()> {
+    let content = fs::read_to_string(atlas_dir.join("churn.md")).await?;
+
+    if !json {
+        println!("Top {} refactor candidates by churn x complexity risk:", limit);
+        println!();
+    }
+
+    let mut found = 0;
+    let mut results = Vec::new();
+
+    for line in content.lines() {
+        let line = line.trim();
+        if !line.starts_with('|') || line.starts_with("|---") || line.starts_with("| File:Line") {
+            continue;
+        }
+
+        if json {
+            let cells: Vec<&str> = line
+                .trim_matches('|')
+                .split('|')
+                .map(str::trim)
+                .collect();
+            results.push(serde_json::json!({
+                "location": cells.first().copied().unwrap_or(""),
+                "symbol": cells.get(1).copied().unwrap_or(""),
+                "complexity": cells.get(2).copied().unwrap_or(""),
+                "commits": cells.get(3).copied().unwrap_or(""),
+                "authors": cells.get(4).copied().unwrap_or(""),
+                "last_modified": cells.get(5).copied().unwrap_or(""),
+                "risk": cells.get(6).copied().unwrap_or(""),
+            }));
+        } else {
+            println!("  {}", line);
+        }
+        found += 1;
+        if found >= limit {
+            break;
+        }
+    }
+
+    if json {
+        return print_json_results("risk", None, results);
+    }
+
+    if found == 0 {
+        println!("  No risk data found");
+    }
+
+    Ok(())
+}
+
+async fn find_public_api(atlas_dir: &Path, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("symbols.md")).await?;
 
-    println!("Public API (first {} items):", limit);
-    println!();
+    if !json {
+        println!("Public API (first {} items):", limit);
+        println!();
+    }
 
     let mut found = 0;
     let mut current_file = String::new();
+    let mut results = Vec::new();
 
     for line in content.lines() {
         if !line.starts_with(' ')
@@ -419,7 +983,20 @@ async fn find_public_api(atlas_dir: &Path, limit: usize) -> Result<()> {
                 || trimmed.starts_with("pub enum ")
                 || trimmed.starts_with("pub trait ")
             {
-                println!("  {} → {}", current_file, trimmed);
+                if json {
+                    let kind = trimmed
+                        .split_whitespace()
+                        .nth(1)
+                        .unwrap_or("")
+                        .to_string();
+                    results.push(serde_json::json!({
+                        "file": current_file,
+                        "kind": kind,
+                        "signature": trimmed,
+                    }));
+                } else {
+                    println!("  {} → {}", current_file, trimmed);
+                }
                 found += 1;
                 if found >= limit {
                     break;
@@ -428,6 +1005,10 @@ async fn find_public_api(atlas_dir: &Path, limit: usize) -> Result<()> {
         }
     }
 
+    if json {
+        return print_json_results("public_api", None, results);
+    }
+
     if found == 0 {
         println!("  No public API found");
     }
@@ -435,154 +1016,76 @@ async fn find_public_api(atlas_dir: &Path, limit: usize) -> Result<()> {
     Ok(())
 }
 
-async fn keyword_search(atlas_dir: &Path, terms: &[String], limit: usize) -> Result<()> {
-    let mut results: Vec<(String, f32)> = Vec::new();
-
-    let symbols_content = fs::read_to_string(atlas_dir.join("symbols.md"))
-        .await
-        .unwrap_or_default();
-    search_in_content(&symbols_content, terms, "symbols", &mut results);
+/// Ranks symbols against `query_str` with the BM25 inverted index persisted at
+/// `.atlas/index.bin`, rebuilding it from `cache.bin` on first use if it's missing or stale
+/// (e.g. written by a charter version before the index existed).
+async fn keyword_search(atlas_dir: &Path, query_str: &str, limit: usize, json: bool) -> Result<()> {
+    let index_path = atlas_dir.join("index.bin");
+    let mut index = crate::cache::SearchIndex::load(&index_path).await?;
 
-    let types_content = fs::read_to_string(atlas_dir.join("types.md"))
-        .await
-        .unwrap_or_default();
-    search_in_content(&types_content, terms, "types", &mut results);
-
-    let calls_content = fs::read_to_string(atlas_dir.join("calls.md"))
-        .await
-        .unwrap_or_default();
-    search_in_content(&calls_content, terms, "calls", &mut results);
-
-    let errors_content = fs::read_to_string(atlas_dir.join("errors.md"))
-        .await
-        .unwrap_or_default();
-    search_in_content(&errors_content, terms, "errors", &mut results);
+    if index.documents.is_empty() {
+        let cache = crate::cache::Cache::load(&atlas_dir.join("cache.bin")).await?;
+        index = crate::cache::SearchIndex::build(&cache);
+    }
 
-    results.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-    results.dedup_by(|a, b| a.0 == b.0);
+    if !json {
+        println!("Search results for '{}':", query_str);
+        println!();
+    }
 
-    println!("Search results for '{}':", terms.join(" "));
-    println!();
+    let matches = index.search(query_str, limit);
+
+    if json {
+        let results = matches
+            .iter()
+            .map(|(score, doc)| {
+                serde_json::json!({
+                    "relevance": score,
+                    "file": doc.file,
+                    "line": doc.line,
+                    "kind": doc.kind,
+                    "name": doc.qualified_name,
+                })
+            })
+            .collect();
+        return print_json_results("keyword", Some(query_str), results);
+    }
 
-    if results.is_empty() {
+    if matches.is_empty() {
         println!("  No results found");
     } else {
-        for (line, score) in results.iter().take(limit) {
-            println!("  [relevance={:.1}] {}", score, line);
+        for (score, doc) in &matches {
+            println!(
+                "  [relevance={:.2}] {}:{} {} {}",
+                score, doc.file, doc.line, doc.kind, doc.qualified_name
+            );
         }
         println!();
-        println!("Found {} result(s)", results.len().min(limit));
+        println!("Found {} result(s)", matches.len());
     }
 
     Ok(())
 }
 
-fn search_in_content(
-    content: &str,
-    terms: &[String],
-    source: &str,
-    results: &mut Vec<(String, f32)>,
-) {
-    for line in content.lines() {
-        if line.starts_with('[') || line.is_empty() {
-            continue;
-        }
-
-        let line_lower = line.to_lowercase();
-        let mut score = 0.0;
-
-        for term in terms {
-            if line_lower.contains(term) {
-                score += 1.0;
-                if line.trim().to_lowercase().starts_with(term) {
-                    score += 0.5;
-                }
-            } else {
-                let fuzzy_score = fuzzy_match(&line_lower, term);
-                if fuzzy_score > 0.7 {
-                    score += fuzzy_score;
-                }
-            }
-        }
-
-        if score > 0.0 {
-            let trimmed = line.trim();
-            if !trimmed.is_empty() && trimmed.len() < 200 {
-                results.push((format!("[{}] {}", source, trimmed), score));
-            }
-        }
-    }
-}
-
-fn fuzzy_match(text: &str, pattern: &str) -> f32 {
-    if text.contains(pattern) {
-        return 1.0;
-    }
-
-    let words: Vec<&str> = text
-        .split(|c: char| !c.is_alphanumeric() && c != '_')
-        .collect();
-
-    for word in &words {
-        if word.len() >= pattern.len() {
-            let distance = levenshtein_distance(word, pattern);
-            let max_len = word.len().max(pattern.len());
-            let similarity = 1.0 - (distance as f32 / max_len as f32);
-            if similarity > 0.7 {
-                return similarity;
-            }
-        }
-    }
-
-    0.0
+/// Wraps a raw `safety.md` line for `--json` output. The Panic/Unsafe/Async/Lifetime/Test
+/// sections are heterogeneous prose blocks (summary counts, sub-headings, indented detail
+/// lines) rather than one consistent record shape, so these handlers emit each matched line
+/// verbatim instead of inventing fields that don't exist in the source markdown.
+fn json_line(line: &str) -> serde_json::Value {
+    serde_json::json!({"line": line})
 }
 
-fn levenshtein_distance(a: &str, b: &str) -> usize {
-    let a_chars: Vec<char> = a.chars().collect();
-    let b_chars: Vec<char> = b.chars().collect();
-    let a_len = a_chars.len();
-    let b_len = b_chars.len();
-
-    if a_len == 0 {
-        return b_len;
-    }
-    if b_len == 0 {
-        return a_len;
-    }
-
-    let mut matrix = vec![vec![0usize; b_len + 1]; a_len + 1];
-
-    for (index, row) in matrix.iter_mut().enumerate().take(a_len + 1) {
-        row[0] = index;
-    }
-    for (index, value) in matrix[0].iter_mut().enumerate().take(b_len + 1) {
-        *value = index;
-    }
-
-    for i in 1..=a_len {
-        for j in 1..=b_len {
-            let cost = if a_chars[i - 1] == b_chars[j - 1] {
-                0
-            } else {
-                1
-            };
-            matrix[i][j] = (matrix[i - 1][j] + 1)
-                .min(matrix[i][j - 1] + 1)
-                .min(matrix[i - 1][j - 1] + cost);
-        }
-    }
-
-    matrix[a_len][b_len]
-}
-
-async fn find_panics(atlas_dir: &Path, limit: usize) -> Result<()> {
+async fn find_panics(atlas_dir: &Path, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("safety.md")).await?;
 
-    println!("Panic Points (first {}):", limit);
-    println!();
+    if !json {
+        println!("Panic Points (first {}):", limit);
+        println!();
+    }
 
     let mut found = 0;
     let mut in_panics = false;
+    let mut results = Vec::new();
 
     for line in content.lines() {
         if line == "## Panic Points" {
@@ -601,7 +1104,11 @@ async fn find_panics(atlas_dir: &Path, limit: usize) -> Result<()> {
                     || line.contains("panic!")
                     || line.contains("L") && line.contains("in "))
             {
-                println!("  {}", line.trim());
+                if json {
+                    results.push(json_line(line.trim()));
+                } else {
+                    println!("  {}", line.trim());
+                }
                 found += 1;
                 if found >= limit {
                     break;
@@ -612,11 +1119,19 @@ async fn find_panics(atlas_dir: &Path, limit: usize) -> Result<()> {
                 || line.starts_with("  panic")
                 || line.starts_with("  assert")
             {
-                println!("{}", line);
+                if json {
+                    results.push(json_line(line));
+                } else {
+                    println!("{}", line);
+                }
             }
         }
     }
 
+    if json {
+        return print_json_results("panics", None, results);
+    }
+
     if found == 0 {
         println!("  No panic points found (run 'atlas' to generate safety.md)");
     }
@@ -624,14 +1139,17 @@ async fn find_panics(atlas_dir: &Path, limit: usize) -> Result<()> {
     Ok(())
 }
 
-async fn find_panics_in(atlas_dir: &Path, file: &str, limit: usize) -> Result<()> {
+async fn find_panics_in(atlas_dir: &Path, file: &str, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("safety.md")).await?;
 
-    println!("Panic Points in '{}':", file);
-    println!();
+    if !json {
+        println!("Panic Points in '{}':", file);
+        println!();
+    }
 
     let file_lower = file.to_lowercase();
     let mut found = 0;
+    let mut results = Vec::new();
 
     for line in content.lines() {
         let matches_file = line.contains(&file_lower)
@@ -642,7 +1160,11 @@ async fn find_panics_in(atlas_dir: &Path, file: &str, limit: usize) -> Result<()
             || line.contains(" in ");
 
         if matches_file && is_panic_line {
-            println!("  {}", line.trim());
+            if json {
+                results.push(json_line(line.trim()));
+            } else {
+                println!("  {}", line.trim());
+            }
             found += 1;
             if found >= limit {
                 break;
@@ -650,6 +1172,10 @@ async fn find_panics_in(atlas_dir: &Path, file: &str, limit: usize) -> Result<()
         }
     }
 
+    if json {
+        return print_json_results("panics_in", Some(file), results);
+    }
+
     if found == 0 {
         println!("  No panic points found in '{}'", file);
     }
@@ -657,14 +1183,17 @@ async fn find_panics_in(atlas_dir: &Path, file: &str, limit: usize) -> Result<()
     Ok(())
 }
 
-async fn find_unsafe_code(atlas_dir: &Path, limit: usize) -> Result<()> {
+async fn find_unsafe_code(atlas_dir: &Path, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("safety.md")).await?;
 
-    println!("Unsafe Code (first {}):", limit);
-    println!();
+    if !json {
+        println!("Unsafe Code (first {}):", limit);
+        println!();
+    }
 
     let mut found = 0;
     let mut in_unsafe = false;
+    let mut results = Vec::new();
 
     for line in content.lines() {
         if line == "## Unsafe Blocks" || line == "## Unsafe Code" {
@@ -678,7 +1207,11 @@ async fn find_unsafe_code(atlas_dir: &Path, limit: usize) -> Result<()> {
             }
 
             if !line.is_empty() && !line.starts_with('#') {
-                println!("  {}", line.trim());
+                if json {
+                    results.push(json_line(line.trim()));
+                } else {
+                    println!("  {}", line.trim());
+                }
                 found += 1;
                 if found >= limit {
                     break;
@@ -687,6 +1220,10 @@ async fn find_unsafe_code(atlas_dir: &Path, limit: usize) -> Result<()> {
         }
     }
 
+    if json {
+        return print_json_results("unsafe_code", None, results);
+    }
+
     if found == 0 {
         println!("  No unsafe blocks found");
     }
@@ -694,14 +1231,17 @@ async fn find_unsafe_code(atlas_dir: &Path, limit: usize) -> Result<()> {
     Ok(())
 }
 
-async fn find_async_functions(atlas_dir: &Path, limit: usize) -> Result<()> {
+async fn find_async_functions(atlas_dir: &Path, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("safety.md")).await?;
 
-    println!("Async Analysis (first {}):", limit);
-    println!();
+    if !json {
+        println!("Async Analysis (first {}):", limit);
+        println!();
+    }
 
     let mut found = 0;
     let mut in_async = false;
+    let mut results = Vec::new();
 
     for line in content.lines() {
         if line == "## Async Analysis" {
@@ -715,7 +1255,11 @@ async fn find_async_functions(atlas_dir: &Path, limit: usize) -> Result<()> {
             }
 
             if !line.is_empty() {
-                println!("{}", line);
+                if json {
+                    results.push(json_line(line));
+                } else {
+                    println!("{}", line);
+                }
                 found += 1;
                 if found >= limit {
                     break;
@@ -724,6 +1268,10 @@ async fn find_async_functions(atlas_dir: &Path, limit: usize) -> Result<()> {
         }
     }
 
+    if json {
+        return print_json_results("async_functions", None, results);
+    }
+
     if found == 0 {
         println!("  No async analysis found");
     }
@@ -731,14 +1279,17 @@ async fn find_async_functions(atlas_dir: &Path, limit: usize) -> Result<()> {
     Ok(())
 }
 
-async fn find_lifetimes(atlas_dir: &Path, limit: usize) -> Result<()> {
+async fn find_lifetimes(atlas_dir: &Path, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("safety.md")).await?;
 
-    println!("Lifetime Analysis (first {}):", limit);
-    println!();
+    if !json {
+        println!("Lifetime Analysis (first {}):", limit);
+        println!();
+    }
 
     let mut found = 0;
     let mut in_lifetimes = false;
+    let mut results = Vec::new();
 
     for line in content.lines() {
         if line == "## Lifetime Analysis" {
@@ -752,7 +1303,11 @@ async fn find_lifetimes(atlas_dir: &Path, limit: usize) -> Result<()> {
             }
 
             if !line.is_empty() {
-                println!("{}", line);
+                if json {
+                    results.push(json_line(line));
+                } else {
+                    println!("{}", line);
+                }
                 found += 1;
                 if found >= limit {
                     break;
@@ -761,6 +1316,10 @@ async fn find_lifetimes(atlas_dir: &Path, limit: usize) -> Result<()> {
         }
     }
 
+    if json {
+        return print_json_results("lifetimes", None, results);
+    }
+
     if found == 0 {
         println!("  No lifetime information found");
     }
@@ -768,14 +1327,17 @@ async fn find_lifetimes(atlas_dir: &Path, limit: usize) -> Result<()> {
     Ok(())
 }
 
-async fn find_tests(atlas_dir: &Path, limit: usize) -> Result<()> {
+async fn find_tests(atlas_dir: &Path, limit: usize, json: bool) -> Result<()> {
     let content = fs::read_to_string(atlas_dir.join("safety.md")).await?;
 
-    println!("Test Coverage (first {}):", limit);
-    println!();
+    if !json {
+        println!("Test Coverage (first {}):", limit);
+        println!();
+    }
 
     let mut found = 0;
     let mut in_tests = false;
+    let mut results = Vec::new();
 
     for line in content.lines() {
         if line == "## Test Coverage" {
@@ -789,7 +1351,11 @@ async fn find_tests(atlas_dir: &Path, limit: usize) -> Result<()> {
             }
 
             if !line.is_empty() {
-                println!("{}", line);
+                if json {
+                    results.push(json_line(line));
+                } else {
+                    println!("{}", line);
+                }
                 found += 1;
                 if found >= limit {
                     break;
@@ -798,6 +1364,10 @@ async fn find_tests(atlas_dir: &Path, limit: usize) -> Result<()> {
         }
     }
 
+    if json {
+        return print_json_results("tests", None, results);
+    }
+
     if found == 0 {
         println!("  No test coverage information found");
     }
@@ -0,0 +1,134 @@
+//! Union of per-file executed-line ranges across several coverage runs (e.g. an `--lcov` file per
+//! feature-flag/OS combination in a test matrix), via a sorted boundary-split range tree rather
+//! than a per-line bitmap. See [`crate::tests`] for the LCOV-specific plumbing that converts
+//! `DA:` records into [`CoverageRange`]s and back.
+
+/// A half-open executed-line range `[start, end)` carrying an execution count. Line numbers are
+/// 1-indexed to match LCOV's `DA:` records, and within a single range set, ranges never overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CoverageRange {
+    pub start: u32,
+    pub end: u32,
+    pub count: u32,
+}
+
+/// Whichever range in `ranges` fully contains `[start, end)`, if any — `None` means neither run
+/// recorded anything for that span (not the same as a recorded-but-zero hit count).
+fn segment_at(ranges: &[CoverageRange], start: u32, end: u32) -> Option<u32> {
+    ranges
+        .iter()
+        .find(|r| r.start <= start && r.end >= end)
+        .map(|r| r.count)
+}
+
+/// Merges two disjoint, sorted range sets into a third by splitting at every boundary (`start` or
+/// `end`) either side contributes, so every output segment is covered by the same subset of input
+/// ranges start to end, then sums the counts of whichever side(s) cover that segment (a line both
+/// runs hit gets their hit counts added, matching `lcov -a`'s own merge semantics). Adjacent output
+/// segments with equal counts are coalesced back together.
+///
+/// Because the boundary set is exactly the union of input boundaries and collapsing is keyed only
+/// on adjacency and equal counts, splitting is deterministic: merging the same two inputs always
+/// produces the same partition regardless of argument order or how a prior merge grouped its own
+/// inputs, which is what makes chained `merge_ranges(merge_ranges(a, b), c)` associative and keeps
+/// a stable result's partition shape unchanged under further merges.
+pub fn merge_ranges(a: &[CoverageRange], b: &[CoverageRange]) -> Vec<CoverageRange> {
+    let mut boundaries: Vec<u32> = a
+        .iter()
+        .flat_map(|r| [r.start, r.end])
+        .chain(b.iter().flat_map(|r| [r.start, r.end]))
+        .collect();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let mut merged: Vec<CoverageRange> = Vec::new();
+    for window in boundaries.windows(2) {
+        let (start, end) = (window[0], window[1]);
+        let count = match (segment_at(a, start, end), segment_at(b, start, end)) {
+            (None, None) => continue,
+            (Some(count), None) | (None, Some(count)) => count,
+            (Some(x), Some(y)) => x + y,
+        };
+
+        match merged.last_mut() {
+            Some(prev) if prev.end == start && prev.count == count => prev.end = end,
+            _ => merged.push(CoverageRange { start, end, count }),
+        }
+    }
+    merged
+}
+
+/// Folds `ranges` into a single merged set via repeated [`merge_ranges`], left to right. Returns
+/// an empty `Vec` if `ranges` is empty.
+pub fn merge_all(ranges: &[Vec<CoverageRange>]) -> Vec<CoverageRange> {
+    ranges
+        .iter()
+        .fold(Vec::new(), |acc, next| merge_ranges(&acc, next))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: u32, end: u32, count: u32) -> CoverageRange {
+        CoverageRange { start, end, count }
+    }
+
+    /// Overlapping runs sum hit counts over the overlap and keep each side's exclusive span as-is,
+    /// matching `lcov -a`'s merge semantics.
+    #[test]
+    fn merge_ranges_sums_counts_over_an_overlap() {
+        let a = [range(1, 10, 3)];
+        let b = [range(5, 15, 2)];
+
+        let merged = merge_ranges(&a, &b);
+
+        assert_eq!(
+            merged,
+            vec![range(1, 5, 3), range(5, 10, 5), range(10, 15, 2)]
+        );
+    }
+
+    /// Disjoint runs pass through untouched, with no segment created in the gap between them.
+    #[test]
+    fn merge_ranges_leaves_disjoint_runs_untouched() {
+        let a = [range(1, 5, 1)];
+        let b = [range(10, 15, 1)];
+
+        let merged = merge_ranges(&a, &b);
+
+        assert_eq!(merged, vec![range(1, 5, 1), range(10, 15, 1)]);
+    }
+
+    /// Adjacent output segments with equal counts are coalesced back into one range.
+    #[test]
+    fn merge_ranges_coalesces_adjacent_equal_count_segments() {
+        let a = [range(1, 5, 2), range(5, 10, 2)];
+        let b: [CoverageRange; 0] = [];
+
+        let merged = merge_ranges(&a, &b);
+
+        assert_eq!(merged, vec![range(1, 10, 2)]);
+    }
+
+    /// `merge_all` chains pairwise merges left to right, so three runs with the same line all
+    /// contribute their hit count.
+    #[test]
+    fn merge_all_chains_three_runs() {
+        let runs = vec![
+            vec![range(1, 5, 1)],
+            vec![range(1, 5, 1)],
+            vec![range(1, 5, 1)],
+        ];
+
+        let merged = merge_all(&runs);
+
+        assert_eq!(merged, vec![range(1, 5, 3)]);
+    }
+
+    /// `merge_all` on an empty input returns an empty range set rather than panicking.
+    #[test]
+    fn merge_all_of_nothing_is_empty() {
+        assert_eq!(merge_all(&[]), Vec::new());
+    }
+}
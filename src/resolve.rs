@@ -0,0 +1,743 @@
+use std::collections::HashMap;
+
+use crate::cache::Cache;
+use crate::extract::symbols::Visibility;
+
+/// Where a resolved `use` path ultimately lands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolution {
+    /// A concrete item defined in `file`.
+    Symbol { file: String, name: String },
+    /// The path names a module itself (`use crate::foo;`), not an item inside it.
+    Module { file: String },
+    /// A glob import (`use foo::*;`) — every symbol in `file` is brought into scope.
+    Glob { file: String },
+    /// Couldn't be resolved within this crate: an external crate, a macro-generated item, or
+    /// a path shape this resolver doesn't understand (e.g. a bare crate-root path with no
+    /// `crate`/`self`/`super` prefix).
+    Unresolved,
+}
+
+#[derive(Debug, Clone)]
+pub struct ResolvedImport {
+    pub importer_file: String,
+    pub import_path: String,
+    pub line: usize,
+    pub resolution: Resolution,
+}
+
+const MAX_REEXPORT_DEPTH: usize = 8;
+
+/// What kind of item an arbitrary qualified path (see [`resolve_path`]) ultimately named.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathResolvedKind {
+    Module,
+    Symbol,
+    Method,
+    AssociatedConst,
+}
+
+/// Outcome of resolving an arbitrary qualified path like `crate::net::Server::connect` against
+/// the crate's module tree, per-file symbol tables, and `impl` blocks — the go-to-definition
+/// counterpart to [`resolve_imports`], which only resolves `use` statements already present in
+/// source rather than an arbitrary path a caller hands in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathResolution {
+    /// Found and disambiguated. `member` is `Some` when the path named a method or associated
+    /// const on `symbol` (a type) rather than `symbol` itself.
+    Resolved {
+        file: String,
+        symbol: String,
+        member: Option<String>,
+        kind: PathResolvedKind,
+        /// Module paths traversed on the way to `file`, crate root first.
+        trail: Vec<String>,
+    },
+    /// Walking the path hit a dead end at `segment_index` (0-based, counted after stripping any
+    /// leading `crate`) — neither the module tree nor the resolved module's symbols/impls had
+    /// anything matching that segment.
+    Unresolved { segment_index: usize, trail: Vec<String> },
+}
+
+/// Outcome of resolving a bare call target (e.g. `foo()`) against the caller's module, imports,
+/// and glob-imported modules, in that precedence — see [`resolve_call_target`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallResolution {
+    /// Resolved to exactly one definition.
+    Resolved { file: String, qualified_name: String },
+    /// More than one definition matched at the same precedence tier.
+    Ambiguous { candidates: Vec<String> },
+    /// No definition found in any tier.
+    Unresolved,
+}
+
+/// Resolves a bare (non-method) call target name against the module `caller_file` belongs to,
+/// in the spirit of racer's `nameres`: (1) items defined in `caller_file`'s own module, (2)
+/// explicit `use` imports resolving to that name, (3) glob-imported modules whose own top-level
+/// symbols include that name. Each tier is tried only if the previous one matched nothing;
+/// more than one distinct-file match within a tier is genuine ambiguity, not picked silently.
+pub fn resolve_call_target(cache: &Cache, caller_file: &str, target_name: &str) -> CallResolution {
+    let Some(caller_entry) = cache.entries.get(caller_file) else {
+        return CallResolution::Unresolved;
+    };
+
+    if caller_entry
+        .data
+        .parsed
+        .symbols
+        .symbols
+        .iter()
+        .any(|symbol| symbol.name == target_name)
+    {
+        return CallResolution::Resolved {
+            file: caller_file.to_string(),
+            qualified_name: target_name.to_string(),
+        };
+    }
+
+    let module_tree = build_module_tree(cache);
+    let current_module = crate::output::module_path_from_file(caller_file);
+
+    let mut import_matches = Vec::new();
+    for import in &caller_entry.data.parsed.imports {
+        for leaf in expand_use_tree(&import.path) {
+            if leaf.is_glob || leaf.segments.last().map(String::as_str) != Some(target_name) {
+                continue;
+            }
+            if let Resolution::Symbol { file, name } =
+                resolve_leaf(&leaf, &current_module, &module_tree, cache, 0)
+            {
+                import_matches.push(file_name_key(&file, &name));
+            }
+        }
+    }
+    import_matches.sort();
+    import_matches.dedup();
+    match import_matches.len() {
+        0 => {}
+        1 => {
+            let (file, name) = split_file_name_key(&import_matches[0]);
+            return CallResolution::Resolved {
+                file,
+                qualified_name: name,
+            };
+        }
+        _ => return CallResolution::Ambiguous {
+            candidates: import_matches,
+        },
+    }
+
+    let mut glob_matches = Vec::new();
+    for import in &caller_entry.data.parsed.imports {
+        for leaf in expand_use_tree(&import.path) {
+            if !leaf.is_glob {
+                continue;
+            }
+            if let Resolution::Glob { file } =
+                resolve_leaf(&leaf, &current_module, &module_tree, cache, 0)
+            {
+                if let Some(entry) = cache.entries.get(&file) {
+                    if entry
+                        .data
+                        .parsed
+                        .symbols
+                        .symbols
+                        .iter()
+                        .any(|symbol| symbol.name == target_name)
+                    {
+                        glob_matches.push(file_name_key(&file, target_name));
+                    }
+                }
+            }
+        }
+    }
+    glob_matches.sort();
+    glob_matches.dedup();
+    match glob_matches.len() {
+        0 => CallResolution::Unresolved,
+        1 => {
+            let (file, name) = split_file_name_key(&glob_matches[0]);
+            CallResolution::Resolved {
+                file,
+                qualified_name: name,
+            }
+        }
+        _ => CallResolution::Ambiguous {
+            candidates: glob_matches,
+        },
+    }
+}
+
+fn file_name_key(file: &str, name: &str) -> String {
+    format!("{file}\u{0}{name}")
+}
+
+fn split_file_name_key(key: &str) -> (String, String) {
+    let (file, name) = key.split_once('\u{0}').unwrap_or((key, ""));
+    (file.to_string(), name.to_string())
+}
+
+/// Builds the crate's module tree (module path -> defining file) from the file layout
+/// recorded in `cache`, then resolves every file's `use` imports against it segment by
+/// segment, following `pub use` re-export chains to their defining symbol.
+pub fn resolve_imports(cache: &Cache) -> Vec<ResolvedImport> {
+    let module_tree = build_module_tree(cache);
+
+    let mut resolved = Vec::new();
+    for (file_path, entry) in &cache.entries {
+        let current_module = crate::output::module_path_from_file(file_path);
+        for import in &entry.data.parsed.imports {
+            for leaf in expand_use_tree(&import.path) {
+                let resolution = resolve_leaf(&leaf, &current_module, &module_tree, cache, 0);
+                resolved.push(ResolvedImport {
+                    importer_file: file_path.clone(),
+                    import_path: import.path.clone(),
+                    line: import.line,
+                    resolution,
+                });
+            }
+        }
+    }
+    resolved
+}
+
+fn build_module_tree(cache: &Cache) -> HashMap<String, String> {
+    cache
+        .entries
+        .keys()
+        .map(|file_path| (crate::output::module_path_from_file(file_path), file_path.clone()))
+        .collect()
+}
+
+fn parent_module(module_path: &str) -> String {
+    match module_path.rsplit_once("::") {
+        Some((parent, _)) => parent.to_string(),
+        None => String::new(),
+    }
+}
+
+/// One flattened leaf out of a (possibly nested) `use` tree, e.g. `use foo::{bar, baz::*};`
+/// expands into two leaves: `foo::bar` and the glob `foo::baz::*`.
+struct UseLeaf {
+    segments: Vec<String>,
+    is_glob: bool,
+}
+
+fn expand_use_tree(path: &str) -> Vec<UseLeaf> {
+    expand_use_tree_with_prefix(path.trim(), &[])
+}
+
+fn expand_use_tree_with_prefix(path: &str, prefix: &[String]) -> Vec<UseLeaf> {
+    let path = path.trim();
+
+    if path == "*" {
+        return vec![UseLeaf {
+            segments: prefix.to_vec(),
+            is_glob: true,
+        }];
+    }
+
+    if let (Some(brace_start), Some(brace_end)) = (path.find('{'), path.rfind('}')) {
+        let head = path[..brace_start].trim().trim_end_matches("::").trim();
+        let mut new_prefix = prefix.to_vec();
+        if !head.is_empty() {
+            new_prefix.extend(head.split("::").map(|s| s.trim().to_string()));
+        }
+        let inner = &path[brace_start + 1..brace_end];
+        return split_top_level_commas(inner)
+            .into_iter()
+            .flat_map(|part| expand_use_tree_with_prefix(part, &new_prefix))
+            .collect();
+    }
+
+    let without_alias = path.split(" as ").next().unwrap_or(path).trim();
+    let mut segments = prefix.to_vec();
+    if without_alias != "self" && !without_alias.is_empty() {
+        segments.extend(
+            without_alias
+                .split("::")
+                .filter(|s| !s.is_empty())
+                .map(|s| s.trim().to_string()),
+        );
+    }
+    vec![UseLeaf {
+        segments,
+        is_glob: false,
+    }]
+}
+
+/// Splits a `{...}` use-list body on its top-level commas, respecting nested `{}` groups
+/// (e.g. `foo::{bar, baz::{Qux}}`).
+fn split_top_level_commas(inner: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (index, char) in inner.char_indices() {
+        match char {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(inner[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = inner[start..].trim();
+    if !last.is_empty() {
+        parts.push(last);
+    }
+    parts
+}
+
+/// Resolves one flattened leaf against the module tree, handling `crate`/`self`/`super`
+/// prefixes. Segments are consumed as long as each names a known child module; the first
+/// segment that doesn't is treated as the terminal item name.
+fn resolve_leaf(
+    leaf: &UseLeaf,
+    current_module: &str,
+    module_tree: &HashMap<String, String>,
+    cache: &Cache,
+    depth: usize,
+) -> Resolution {
+    if leaf.segments.is_empty() {
+        return Resolution::Unresolved;
+    }
+
+    let mut segments = leaf.segments.as_slice();
+    let mut context = current_module.to_string();
+
+    match segments[0].as_str() {
+        "crate" => {
+            context = String::new();
+            segments = &segments[1..];
+        }
+        "self" => {
+            segments = &segments[1..];
+        }
+        "super" => {
+            while segments.first().map(String::as_str) == Some("super") {
+                context = parent_module(&context);
+                segments = &segments[1..];
+            }
+        }
+        _ => return Resolution::Unresolved,
+    }
+
+    let mut resolved_file = module_tree.get(&context).cloned();
+    while let Some((first, rest)) = segments.split_first() {
+        let candidate = if context.is_empty() {
+            first.clone()
+        } else {
+            format!("{context}::{first}")
+        };
+        match module_tree.get(&candidate) {
+            Some(file) => {
+                context = candidate;
+                resolved_file = Some(file.clone());
+                segments = rest;
+            }
+            None => break,
+        }
+    }
+
+    let Some(file) = resolved_file else {
+        return Resolution::Unresolved;
+    };
+
+    if leaf.is_glob {
+        return Resolution::Glob { file };
+    }
+
+    match segments {
+        [] => Resolution::Module { file },
+        [name] => resolve_symbol(name, &file, module_tree, cache, depth),
+        _ => Resolution::Unresolved,
+    }
+}
+
+/// Looks up `name` among `file`'s own top-level symbols; if it isn't defined there, follows
+/// a matching `pub use` re-export (if any) up to [`MAX_REEXPORT_DEPTH`] hops, the way a
+/// `pub use foo::Bar;` chain actually resolves in rustc.
+fn resolve_symbol(
+    name: &str,
+    file: &str,
+    module_tree: &HashMap<String, String>,
+    cache: &Cache,
+    depth: usize,
+) -> Resolution {
+    let Some(entry) = cache.entries.get(file) else {
+        return Resolution::Unresolved;
+    };
+
+    if entry
+        .data
+        .parsed
+        .symbols
+        .symbols
+        .iter()
+        .any(|symbol| symbol.name == name)
+    {
+        return Resolution::Symbol {
+            file: file.to_string(),
+            name: name.to_string(),
+        };
+    }
+
+    if depth >= MAX_REEXPORT_DEPTH {
+        return Resolution::Unresolved;
+    }
+
+    let module_path = crate::output::module_path_from_file(file);
+
+    for re_export in &entry.data.parsed.re_exports {
+        for leaf in expand_use_tree(&re_export.source_path) {
+            if leaf.is_glob || leaf.segments.last().map(String::as_str) != Some(name) {
+                continue;
+            }
+            let resolution = resolve_leaf(&leaf, &module_path, module_tree, cache, depth + 1);
+            if !matches!(resolution, Resolution::Unresolved) {
+                return resolution;
+            }
+        }
+    }
+
+    Resolution::Unresolved
+}
+
+/// Resolves an arbitrary qualified path segment by segment against `cache`'s module tree,
+/// starting from `root` (a module path) or the crate root if `root` is `None`. Consumes leading
+/// `crate::` the way [`resolve_leaf`] does, then walks each remaining segment against known
+/// child modules for as long as it can; the first segment that isn't a module is either the
+/// final item (resolved via [`resolve_symbol`], following re-exports) or, if one more segment
+/// follows, a type name whose `member` (method or associated const) is looked up in that
+/// module's `inherent_impls`.
+pub fn resolve_path(cache: &Cache, path: &str, root: Option<&str>) -> PathResolution {
+    let mut segments: Vec<&str> = path
+        .split("::")
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    let module_tree = build_module_tree(cache);
+    let mut context = root.unwrap_or_default().to_string();
+    let mut trail = Vec::new();
+    if !context.is_empty() {
+        trail.push(context.clone());
+    }
+
+    if segments.first() == Some(&"crate") {
+        context = String::new();
+        segments.remove(0);
+    }
+
+    if segments.is_empty() {
+        return match module_tree.get(&context) {
+            Some(file) => PathResolution::Resolved {
+                file: file.clone(),
+                symbol: context,
+                member: None,
+                kind: PathResolvedKind::Module,
+                trail,
+            },
+            None => PathResolution::Unresolved {
+                segment_index: 0,
+                trail,
+            },
+        };
+    }
+
+    let mut resolved_file = module_tree.get(&context).cloned();
+    let mut consumed = 0;
+    while consumed < segments.len() {
+        let candidate = if context.is_empty() {
+            segments[consumed].to_string()
+        } else {
+            format!("{context}::{}", segments[consumed])
+        };
+        match module_tree.get(&candidate) {
+            Some(file) => {
+                context = candidate;
+                resolved_file = Some(file.clone());
+                trail.push(context.clone());
+                consumed += 1;
+            }
+            None => break,
+        }
+    }
+
+    let Some(file) = resolved_file else {
+        return PathResolution::Unresolved {
+            segment_index: consumed,
+            trail,
+        };
+    };
+
+    match &segments[consumed..] {
+        [] => PathResolution::Resolved {
+            file,
+            symbol: context,
+            member: None,
+            kind: PathResolvedKind::Module,
+            trail,
+        },
+        [item] => match resolve_symbol(item, &file, &module_tree, cache, 0) {
+            Resolution::Symbol { file, name } => PathResolution::Resolved {
+                file,
+                symbol: name,
+                member: None,
+                kind: PathResolvedKind::Symbol,
+                trail,
+            },
+            _ => PathResolution::Unresolved {
+                segment_index: consumed,
+                trail,
+            },
+        },
+        [type_name, member] => {
+            resolve_member(type_name, member, &file, consumed, trail, cache)
+        }
+        _ => PathResolution::Unresolved {
+            segment_index: consumed,
+            trail,
+        },
+    }
+}
+
+/// Disambiguates the final two segments of a [`resolve_path`] query once they've been narrowed
+/// to "a type defined in `file`, plus a member on it" — looks for `member` among that type's
+/// `inherent_impls` methods, then its associated consts, and reports "unresolved at the type's
+/// segment" if `type_name` isn't even a symbol in `file`, or "unresolved at the member's segment"
+/// if the type exists but has no such method/const.
+fn resolve_member(
+    type_name: &str,
+    member: &str,
+    file: &str,
+    segment_index: usize,
+    trail: Vec<String>,
+    cache: &Cache,
+) -> PathResolution {
+    let unresolved_type = PathResolution::Unresolved {
+        segment_index,
+        trail: trail.clone(),
+    };
+    let Some(entry) = cache.entries.get(file) else {
+        return unresolved_type;
+    };
+
+    let is_type = entry
+        .data
+        .parsed
+        .symbols
+        .symbols
+        .iter()
+        .any(|symbol| symbol.name == type_name);
+    if !is_type {
+        return unresolved_type;
+    }
+
+    for inherent_impl in &entry.data.parsed.symbols.inherent_impls {
+        if inherent_impl.type_name != type_name {
+            continue;
+        }
+        if inherent_impl.methods.iter().any(|m| m.name == member) {
+            return PathResolution::Resolved {
+                file: file.to_string(),
+                symbol: type_name.to_string(),
+                member: Some(member.to_string()),
+                kind: PathResolvedKind::Method,
+                trail,
+            };
+        }
+        if inherent_impl.assoc_consts.iter().any(|c| c.name == member) {
+            return PathResolution::Resolved {
+                file: file.to_string(),
+                symbol: type_name.to_string(),
+                member: Some(member.to_string()),
+                kind: PathResolvedKind::AssociatedConst,
+                trail,
+            };
+        }
+    }
+
+    PathResolution::Unresolved {
+        segment_index: segment_index + 1,
+        trail,
+    }
+}
+
+/// One way to bring a symbol into scope from some module, as found by [`suggest_import`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportCandidate {
+    /// The path after `crate::`, e.g. `net::server::Connection`.
+    pub path: String,
+    /// A ready-to-paste `use` statement.
+    pub use_statement: String,
+    pub segment_count: usize,
+    /// `true` if this path reaches the symbol through a `pub use` re-export rather than its
+    /// original definition.
+    pub via_reexport: bool,
+}
+
+/// Outcome of [`suggest_import`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportSuggestion {
+    /// At least one path reaches the symbol from `from_module`, ranked shortest/most-public first.
+    Found(Vec<ImportCandidate>),
+    /// The symbol is defined somewhere in the crate, but every definition and re-export of it is
+    /// private to a module `from_module` can't see.
+    Unreachable,
+    /// No symbol by that name is defined anywhere in the crate.
+    NotFound,
+}
+
+/// Finds every `use` path that brings `symbol` into scope from `from_module`, modeled on
+/// rust-analyzer's `find_path`: each direct definition is checked against its own visibility,
+/// and every `pub use` re-export of the symbol (its target chain followed transitively by
+/// [`resolve_symbol`]) is checked against the re-export's own visibility, since a re-export's
+/// privacy is scoped to where the `pub use` lives, not to the symbol's original definition.
+/// Candidates are ranked by fewest path segments, preferring an already-`pub` path over an
+/// equally short `pub(crate)`/`pub(super)`/`pub(in ...)` one, and a direct definition over a
+/// re-export of the same length.
+pub fn suggest_import(cache: &Cache, symbol: &str, from_module: &str) -> ImportSuggestion {
+    let module_tree = build_module_tree(cache);
+
+    let definitions: Vec<(String, String, Visibility)> = cache
+        .entries
+        .iter()
+        .flat_map(|(file, entry)| {
+            entry
+                .data
+                .parsed
+                .symbols
+                .symbols
+                .iter()
+                .filter(|candidate| candidate.name == symbol)
+                .map(|candidate| {
+                    (
+                        file.clone(),
+                        crate::output::module_path_from_file(file),
+                        candidate.visibility.clone(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    if definitions.is_empty() {
+        return ImportSuggestion::NotFound;
+    }
+
+    let mut ranked = Vec::new();
+    for (_, module_path, visibility) in &definitions {
+        if visible_from(visibility, module_path, from_module) {
+            ranked.push((
+                build_import_candidate(module_path, symbol, false),
+                visibility_rank(visibility),
+            ));
+        }
+    }
+
+    let defining_files: Vec<&str> = definitions.iter().map(|(file, _, _)| file.as_str()).collect();
+    for (file, entry) in &cache.entries {
+        let reexport_module = crate::output::module_path_from_file(file);
+        for re_export in &entry.data.parsed.re_exports {
+            for leaf in expand_use_tree(&re_export.source_path) {
+                if leaf.is_glob || leaf.segments.last().map(String::as_str) != Some(symbol) {
+                    continue;
+                }
+                let resolution = resolve_leaf(&leaf, &reexport_module, &module_tree, cache, 0);
+                let Resolution::Symbol {
+                    file: resolved_file,
+                    name,
+                } = resolution
+                else {
+                    continue;
+                };
+                if name != symbol || !defining_files.contains(&resolved_file.as_str()) {
+                    continue;
+                }
+                if visible_from(&re_export.visibility, &reexport_module, from_module) {
+                    ranked.push((
+                        build_import_candidate(&reexport_module, symbol, true),
+                        visibility_rank(&re_export.visibility),
+                    ));
+                }
+            }
+        }
+    }
+
+    if ranked.is_empty() {
+        return ImportSuggestion::Unreachable;
+    }
+
+    ranked.sort_by(|(a, a_rank), (b, b_rank)| {
+        a.segment_count
+            .cmp(&b.segment_count)
+            .then_with(|| a_rank.cmp(b_rank))
+            .then_with(|| a.via_reexport.cmp(&b.via_reexport))
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    ImportSuggestion::Found(ranked.into_iter().map(|(candidate, _)| candidate).collect())
+}
+
+fn build_import_candidate(module_path: &str, symbol: &str, via_reexport: bool) -> ImportCandidate {
+    let path = if module_path.is_empty() {
+        symbol.to_string()
+    } else {
+        format!("{module_path}::{symbol}")
+    };
+    let use_statement = format!("use crate::{path};");
+    let segment_count = path.split("::").count();
+    ImportCandidate {
+        path,
+        use_statement,
+        segment_count,
+        via_reexport,
+    }
+}
+
+/// Lower ranks first so a direct/re-exported `pub` path wins ties over a more restricted one.
+fn visibility_rank(visibility: &Visibility) -> u8 {
+    match visibility {
+        Visibility::Public => 0,
+        Visibility::PubCrate => 1,
+        Visibility::PubSuper | Visibility::PubIn(_) => 2,
+        Visibility::Private => 3,
+    }
+}
+
+/// Whether an item visible as `visibility` in `home_module` can be named from `from_module`,
+/// mirroring rustc's privacy rule that an unqualified visibility is scoped to its defining
+/// module plus that module's descendants, and each `pub(...)` form just widens the root module
+/// that scope is computed from.
+fn visible_from(visibility: &Visibility, home_module: &str, from_module: &str) -> bool {
+    match visibility {
+        Visibility::Public => true,
+        Visibility::PubCrate => true,
+        Visibility::PubSuper => is_self_or_descendant(from_module, &parent_module(home_module)),
+        Visibility::PubIn(raw) => match parse_pub_in_path(raw) {
+            Some(root) => is_self_or_descendant(from_module, &root),
+            None => false,
+        },
+        Visibility::Private => is_self_or_descendant(from_module, home_module),
+    }
+}
+
+fn is_self_or_descendant(candidate: &str, root: &str) -> bool {
+    root.is_empty() || candidate == root || candidate.starts_with(&format!("{root}::"))
+}
+
+/// Pulls the module path out of a captured `pub(in crate::foo::bar)` visibility string, dropping
+/// the leading `crate` segment to match the module paths [`crate::output::module_path_from_file`] produces.
+fn parse_pub_in_path(raw: &str) -> Option<String> {
+    let inner = raw.strip_prefix("pub(in")?.trim_end_matches(')').trim();
+    Some(
+        inner
+            .strip_prefix("crate::")
+            .or_else(|| inner.strip_prefix("crate"))
+            .unwrap_or(inner)
+            .to_string(),
+    )
+}
@@ -0,0 +1,170 @@
+use anyhow::Result;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::extract::symbol_diff::{self, SymbolChange, SymbolChangeKind};
+use crate::git;
+use crate::pipeline::parse_rust_file;
+
+/// One changed file between the two revisions, with its per-symbol breakdown.
+struct FileDiff {
+    path: String,
+    summary: Option<String>,
+    changes: Vec<SymbolChange>,
+}
+
+/// Resolves `revisions` (`<old>..<new>`) to two trees and reports which `.rs` files were
+/// added, removed, or changed between them at the symbol level, without touching the
+/// working directory: every blob is read straight out of git via
+/// [`git::read_file_at_ref`] and parsed with [`parse_rust_file`], then compared with the
+/// same [`symbol_diff::diff_symbols`] logic [`crate::output::build_symbol_changes`] uses
+/// for working-tree-vs-ref diffing.
+pub async fn diff(root: &Path, revisions: &str, json: bool) -> Result<()> {
+    let Some((old_ref, new_ref)) = revisions.split_once("..") else {
+        eprintln!("Expected <old>..<new>, e.g. `charter diff main..HEAD`");
+        std::process::exit(1);
+    };
+
+    if old_ref.is_empty() || new_ref.is_empty() {
+        eprintln!("Expected <old>..<new>, e.g. `charter diff main..HEAD`");
+        std::process::exit(1);
+    }
+
+    let Some(old_paths) = git::list_rust_files_at_ref(root, old_ref).await else {
+        eprintln!("'{}' is not a valid git revision", old_ref);
+        std::process::exit(1);
+    };
+    let Some(new_paths) = git::list_rust_files_at_ref(root, new_ref).await else {
+        eprintln!("'{}' is not a valid git revision", new_ref);
+        std::process::exit(1);
+    };
+
+    let old_set: BTreeSet<&str> = old_paths.iter().map(String::as_str).collect();
+    let new_set: BTreeSet<&str> = new_paths.iter().map(String::as_str).collect();
+
+    let added: Vec<&str> = new_set.difference(&old_set).copied().collect();
+    let removed: Vec<&str> = old_set.difference(&new_set).copied().collect();
+
+    let mut modified = Vec::new();
+    for &path in old_set.intersection(&new_set) {
+        let (Some(old_content), Some(new_content)) = (
+            git::read_file_at_ref(root, old_ref, path).await,
+            git::read_file_at_ref(root, new_ref, path).await,
+        ) else {
+            continue;
+        };
+
+        let (Ok(old_parsed), Ok(new_parsed)) = (
+            parse_rust_file(&old_content, path),
+            parse_rust_file(&new_content, path),
+        ) else {
+            continue;
+        };
+
+        let changes = symbol_diff::diff_symbols(&old_parsed.symbols, &new_parsed.symbols);
+        if changes.is_empty() {
+            continue;
+        }
+
+        let summary = symbol_diff::summarize(&changes);
+        modified.push(FileDiff {
+            path: path.to_string(),
+            summary,
+            changes,
+        });
+    }
+    modified.sort_by(|a, b| a.path.cmp(&b.path));
+
+    if json {
+        print_json(old_ref, new_ref, &added, &removed, &modified)
+    } else {
+        print_text(old_ref, new_ref, &added, &removed, &modified);
+        Ok(())
+    }
+}
+
+fn print_text(old_ref: &str, new_ref: &str, added: &[&str], removed: &[&str], modified: &[FileDiff]) {
+    println!("Diff {}..{}", old_ref, new_ref);
+
+    if added.is_empty() && removed.is_empty() && modified.is_empty() {
+        println!("  no changes");
+        return;
+    }
+
+    if !added.is_empty() {
+        println!("\nAdded ({}):", added.len());
+        for path in added {
+            println!("  [+] {}", path);
+        }
+    }
+
+    if !removed.is_empty() {
+        println!("\nRemoved ({}):", removed.len());
+        for path in removed {
+            println!("  [-] {}", path);
+        }
+    }
+
+    if !modified.is_empty() {
+        println!("\nModified ({}):", modified.len());
+        for file in modified {
+            match &file.summary {
+                Some(summary) => println!("  [~] {} ({})", file.path, summary),
+                None => println!("  [~] {}", file.path),
+            }
+            for change in &file.changes {
+                println!("      {}{} {}", change.change.marker(), change.kind_label, change.name);
+            }
+        }
+    }
+}
+
+fn print_json(
+    old_ref: &str,
+    new_ref: &str,
+    added: &[&str],
+    removed: &[&str],
+    modified: &[FileDiff],
+) -> Result<()> {
+    let modified_json: Vec<serde_json::Value> = modified
+        .iter()
+        .map(|file| {
+            let changes: Vec<serde_json::Value> = file
+                .changes
+                .iter()
+                .map(|change| {
+                    serde_json::json!({
+                        "name": change.name,
+                        "kind": change.kind_label,
+                        "change": change_kind_label(change.change),
+                    })
+                })
+                .collect();
+
+            serde_json::json!({
+                "path": file.path,
+                "summary": file.summary,
+                "changes": changes,
+            })
+        })
+        .collect();
+
+    let payload = serde_json::json!({
+        "old_ref": old_ref,
+        "new_ref": new_ref,
+        "added": added,
+        "removed": removed,
+        "modified": modified_json,
+    });
+
+    println!("{}", serde_json::to_string_pretty(&payload)?);
+    Ok(())
+}
+
+fn change_kind_label(kind: SymbolChangeKind) -> &'static str {
+    match kind {
+        SymbolChangeKind::Added => "added",
+        SymbolChangeKind::Removed => "removed",
+        SymbolChangeKind::Modified => "modified",
+    }
+}
@@ -0,0 +1,140 @@
+use std::path::Path;
+
+use crate::pipeline::{FileResult, PipelineResult};
+
+/// Default line-count beyond which [`over_threshold`]'s caller would flag a node as
+/// carrying too much code, mirroring how a disk-usage tool might default to flagging
+/// directories over some size.
+pub const DEFAULT_SIZE_THRESHOLD: usize = 500;
+
+#[derive(Debug, Clone, Default)]
+pub struct SizeTotals {
+    pub line_count: usize,
+    pub statement_count: usize,
+}
+
+/// One file or directory in the module tree [`build_size_tree`] produces. `totals` starts as
+/// just this node's own captured-body totals and is rewritten bottom-up by [`roll_up`] to the
+/// cumulative sum across every descendant, the same way a directory's reported size is the sum
+/// of the files underneath it.
+#[derive(Debug, Clone, Default)]
+pub struct SizeNode {
+    pub name: String,
+    pub path: String,
+    pub totals: SizeTotals,
+    pub children: Vec<SizeNode>,
+}
+
+fn file_totals(file: &FileResult) -> SizeTotals {
+    let mut totals = SizeTotals::default();
+    for body in &file.parsed.captured_bodies {
+        if let Some(summary) = &body.body.summary {
+            totals.line_count += summary.line_count;
+            totals.statement_count += summary.statement_count;
+        }
+    }
+    totals
+}
+
+fn insert_path(node: &mut SizeNode, remaining: &Path, totals: SizeTotals) {
+    let mut components = remaining.components();
+    let Some(first) = components.next() else {
+        return;
+    };
+    let name = first.as_os_str().to_string_lossy().to_string();
+    let rest = components.as_path();
+
+    let child_path = if node.path.is_empty() {
+        name.clone()
+    } else {
+        format!("{}/{}", node.path, name)
+    };
+
+    if rest.as_os_str().is_empty() {
+        node.children.push(SizeNode {
+            name,
+            path: child_path,
+            totals,
+            children: Vec::new(),
+        });
+        return;
+    }
+
+    let child_index = node.children.iter().position(|child| child.name == name);
+    let child = match child_index {
+        Some(index) => &mut node.children[index],
+        None => {
+            node.children.push(SizeNode {
+                name,
+                path: child_path,
+                totals: SizeTotals::default(),
+                children: Vec::new(),
+            });
+            node.children.last_mut().expect("just pushed")
+        }
+    };
+
+    insert_path(child, rest, totals);
+}
+
+/// Sums `node.totals` into every ancestor's own totals, bottom-up — a directory node starts at
+/// zero and picks up each child's (already rolled-up) totals, while a file leaf's totals are
+/// already final, so this single recursive pass handles both uniformly.
+fn roll_up(node: &mut SizeNode) -> SizeTotals {
+    let mut sum = SizeTotals {
+        line_count: node.totals.line_count,
+        statement_count: node.totals.statement_count,
+    };
+
+    for child in &mut node.children {
+        let child_totals = roll_up(child);
+        sum.line_count += child_totals.line_count;
+        sum.statement_count += child_totals.statement_count;
+    }
+
+    node.totals = SizeTotals {
+        line_count: sum.line_count,
+        statement_count: sum.statement_count,
+    };
+    sum
+}
+
+/// Builds the recursive directory/module tree rooted at the crate root, with every node's
+/// `totals` already rolled up to include its descendants.
+pub fn build_size_tree(result: &PipelineResult) -> SizeNode {
+    let mut root = SizeNode::default();
+
+    for file in &result.files {
+        let totals = file_totals(file);
+        insert_path(&mut root, Path::new(&file.relative_path), totals);
+    }
+
+    roll_up(&mut root);
+    root
+}
+
+fn collect_descendants<'a>(node: &'a SizeNode, out: &mut Vec<&'a SizeNode>) {
+    for child in &node.children {
+        out.push(child);
+        collect_descendants(child, out);
+    }
+}
+
+/// The `limit` heaviest descendants (by rolled-up `line_count`) anywhere under `node`, directories
+/// and files alike, the way a disk-usage report surfaces its biggest subdirectories regardless of
+/// depth.
+pub fn heaviest_descendants(node: &SizeNode, limit: usize) -> Vec<&SizeNode> {
+    let mut all = Vec::new();
+    collect_descendants(node, &mut all);
+    all.sort_by(|a, b| b.totals.line_count.cmp(&a.totals.line_count));
+    all.truncate(limit);
+    all
+}
+
+/// Every descendant whose rolled-up `line_count` exceeds `threshold`.
+pub fn over_threshold(node: &SizeNode, threshold: usize) -> Vec<&SizeNode> {
+    let mut all = Vec::new();
+    collect_descendants(node, &mut all);
+    all.retain(|n| n.totals.line_count > threshold);
+    all
+}
@@ -0,0 +1,283 @@
+pub mod builtin;
+
+use anyhow::Result;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::io::AsyncWriteExt;
+use tokio::task::JoinSet;
+
+use crate::cache::Cache;
+use crate::callindex::{build_call_graph, unreachable_functions};
+use crate::extract::complexity::FunctionComplexity;
+use crate::pipeline::ParsedFile;
+use crate::resolve::{resolve_imports, Resolution};
+
+/// Per-file view handed to [`Rule::check`]: the already-parsed `symbols`/`call_graph`/
+/// `complexity`/`safety`/attribute data for one file, plus anything (like call-site counts)
+/// that only makes sense once aggregated across the whole cache.
+pub struct RuleContext<'a> {
+    pub file: &'a str,
+    pub parsed: &'a ParsedFile,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warn,
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warn => write!(f, "warn"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub rule: String,
+    pub severity: Severity,
+    pub file: String,
+    pub line: usize,
+    pub message: String,
+}
+
+/// A project-specific lint rule. Implementors only need the per-file data already sitting
+/// in the cache from the last capture — new rules don't touch the `.charter` output writers
+/// in `output/` at all.
+pub trait Rule: Send + Sync {
+    fn name(&self) -> &str;
+    fn check(&self, ctx: &RuleContext) -> Vec<Diagnostic>;
+}
+
+/// The rules shipped with charter, each exploiting data the crate already extracts.
+pub fn default_rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(builtin::ComplexityThreshold::default()),
+        Box::new(builtin::UnsafeBlockPresent),
+        Box::new(builtin::GuardAcrossAwait),
+        Box::new(builtin::DocCompleteness),
+        Box::new(builtin::ExcessiveCallers::default()),
+    ]
+}
+
+/// Runs `rules` over every cached file concurrently (one blocking task per file, the same
+/// way `pipeline::parse::parse_rust_file` is dispatched) and returns diagnostics sorted by
+/// file, then line, then severity (errors first).
+pub async fn run_all(cache: &Cache, rules: Arc<Vec<Box<dyn Rule>>>) -> Vec<Diagnostic> {
+    let enriched = enrich_call_sites(cache);
+
+    let mut join_set = JoinSet::new();
+
+    for (file, parsed) in enriched {
+        let rules = Arc::clone(&rules);
+
+        join_set.spawn_blocking(move || {
+            let ctx = RuleContext {
+                file: &file,
+                parsed: &parsed,
+            };
+
+            rules
+                .iter()
+                .flat_map(|rule| rule.check(&ctx))
+                .collect::<Vec<_>>()
+        });
+    }
+
+    let mut diagnostics = Vec::new();
+    while let Some(result) = join_set.join_next().await {
+        diagnostics.extend(result.unwrap_or_default());
+    }
+
+    diagnostics.sort_by(|a, b| {
+        a.file
+            .cmp(&b.file)
+            .then(a.line.cmp(&b.line))
+            .then(b.severity.cmp(&a.severity))
+    });
+
+    diagnostics
+}
+
+/// Clones each cached file's parsed data and patches `complexity[].metrics.call_sites` with
+/// counts aggregated across the whole cache, the same cross-file tally
+/// `output::hotspots::update_call_sites` computes for `hotspots.md`. Rules see call-site
+/// counts that reflect the whole project, not just callers within their own file.
+pub(crate) fn enrich_call_sites(cache: &Cache) -> Vec<(String, ParsedFile)> {
+    let mut call_counts: HashMap<String, u32> = HashMap::new();
+
+    for entry in cache.entries.values() {
+        for call_info in &entry.data.parsed.call_graph {
+            for callee in &call_info.callees {
+                *call_counts.entry(callee.qualified_target()).or_insert(0) += 1;
+            }
+        }
+    }
+
+    cache
+        .entries
+        .iter()
+        .map(|(file, entry)| {
+            let mut parsed = entry.data.parsed.clone();
+            for func in &mut parsed.complexity {
+                patch_call_sites(func, &call_counts);
+            }
+            (file.clone(), parsed)
+        })
+        .collect()
+}
+
+/// The rule name [`dead_internal_import_diagnostics`] tags its findings with. It has no
+/// corresponding [`Rule`] impl since, unlike the other builtins, it needs the whole crate's
+/// module tree rather than one file's [`RuleContext`] — see [`crate::resolve`].
+const DEAD_INTERNAL_IMPORT_RULE: &str = "dead-internal-import";
+
+/// Flags `use crate::...` / `use self::...` / `use super::...` imports that don't resolve to
+/// any known module or symbol. Imports without one of those prefixes are left alone, since an
+/// unprefixed path is just as likely to name an external crate as a broken internal one.
+fn dead_internal_import_diagnostics(cache: &Cache) -> Vec<Diagnostic> {
+    resolve_imports(cache)
+        .into_iter()
+        .filter(|resolved| matches!(resolved.resolution, Resolution::Unresolved))
+        .filter(|resolved| {
+            resolved.import_path.starts_with("crate::")
+                || resolved.import_path.starts_with("self::")
+                || resolved.import_path.starts_with("super::")
+        })
+        .map(|resolved| Diagnostic {
+            rule: DEAD_INTERNAL_IMPORT_RULE.to_string(),
+            severity: Severity::Warn,
+            file: resolved.importer_file,
+            line: resolved.line,
+            message: format!(
+                "`use {}` does not resolve to any known module or symbol",
+                resolved.import_path
+            ),
+        })
+        .collect()
+}
+
+/// The rule name [`dead_code_diagnostics`] tags its findings with. Like
+/// [`DEAD_INTERNAL_IMPORT_RULE`], it needs the whole crate's call graph rather than one file's
+/// [`RuleContext`] — see [`crate::callindex`].
+const DEAD_CODE_RULE: &str = "dead-code";
+
+/// Flags non-public, non-test functions and methods that nothing in the crate's resolved call
+/// graph calls — see [`crate::callindex::unreachable_functions`] for what counts as "nothing
+/// calls this" (ambiguous and external-looking call sites don't count against a function).
+fn dead_code_diagnostics(cache: &Cache) -> Vec<Diagnostic> {
+    let graph = build_call_graph(cache);
+    unreachable_functions(cache, &graph)
+        .into_iter()
+        .map(|dead| Diagnostic {
+            rule: DEAD_CODE_RULE.to_string(),
+            severity: Severity::Info,
+            file: dead.id.file,
+            line: dead.line,
+            message: format!(
+                "`{}` has no resolved callers in the crate and isn't public",
+                dead.id.qualified_name()
+            ),
+        })
+        .collect()
+}
+
+fn patch_call_sites(func: &mut FunctionComplexity, call_counts: &HashMap<String, u32>) {
+    if let Some(count) = call_counts.get(&func.qualified_name()) {
+        func.metrics.call_sites = *count;
+    }
+    if let Some(count) = call_counts.get(&func.name) {
+        func.metrics.call_sites = func.metrics.call_sites.max(*count);
+    }
+}
+
+/// Loads `cache.bin` from `charter_dir`, runs `rules` over it, writes `lints.md` grouped by
+/// rule (each rule's diagnostics sorted by file/line/severity), and returns how many
+/// diagnostics were written.
+pub async fn run_lints(
+    charter_dir: &Path,
+    rules: Vec<Box<dyn Rule>>,
+    min_severity: Option<Severity>,
+) -> Result<usize> {
+    let cache = Cache::load(&charter_dir.join("cache.bin")).await?;
+    let rules = Arc::new(rules);
+
+    let mut diagnostics = run_all(&cache, Arc::clone(&rules)).await;
+    diagnostics.extend(dead_internal_import_diagnostics(&cache));
+    diagnostics.extend(dead_code_diagnostics(&cache));
+    diagnostics.sort_by(|a, b| {
+        a.file
+            .cmp(&b.file)
+            .then(a.line.cmp(&b.line))
+            .then(b.severity.cmp(&a.severity))
+    });
+    if let Some(min_severity) = min_severity {
+        diagnostics.retain(|d| d.severity >= min_severity);
+    }
+
+    write_lints_md(charter_dir, &rules, &diagnostics).await?;
+
+    Ok(diagnostics.len())
+}
+
+async fn write_lints_md(
+    charter_dir: &Path,
+    rules: &[Box<dyn Rule>],
+    diagnostics: &[Diagnostic],
+) -> Result<()> {
+    let path = charter_dir.join("lints.md");
+    let mut file = tokio::fs::File::create(&path).await?;
+
+    let mut buffer = Vec::with_capacity(16 * 1024);
+    writeln!(buffer, "# Lint Report")?;
+    writeln!(buffer)?;
+
+    if diagnostics.is_empty() {
+        writeln!(buffer, "No lint diagnostics.")?;
+        file.write_all(&buffer).await?;
+        return Ok(());
+    }
+
+    for rule in rules {
+        write_rule_section(&mut buffer, rule.name(), diagnostics)?;
+    }
+    write_rule_section(&mut buffer, DEAD_INTERNAL_IMPORT_RULE, diagnostics)?;
+    write_rule_section(&mut buffer, DEAD_CODE_RULE, diagnostics)?;
+
+    file.write_all(&buffer).await?;
+    Ok(())
+}
+
+fn write_rule_section(
+    buffer: &mut Vec<u8>,
+    rule_name: &str,
+    diagnostics: &[Diagnostic],
+) -> Result<()> {
+    let rule_diagnostics: Vec<&Diagnostic> =
+        diagnostics.iter().filter(|d| d.rule == rule_name).collect();
+
+    if rule_diagnostics.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(buffer, "## {}", rule_name)?;
+    writeln!(buffer)?;
+
+    for diagnostic in rule_diagnostics {
+        writeln!(
+            buffer,
+            "[{}] {}:{} — {}",
+            diagnostic.severity, diagnostic.file, diagnostic.line, diagnostic.message
+        )?;
+    }
+    writeln!(buffer)?;
+
+    Ok(())
+}
@@ -0,0 +1,244 @@
+use super::{Diagnostic, Rule, RuleContext, Severity};
+use crate::extract::symbols::{SymbolKind, Visibility};
+use crate::output::calls::HOT_PATH_MIN_CALLS;
+
+/// Flags functions whose [`ComplexityMetrics::importance_score`](crate::extract::complexity::ComplexityMetrics::importance_score)
+/// meets or exceeds `threshold` — the same score `hotspots.md` ranks by, with the default
+/// matching its `High` tier cutoff.
+pub struct ComplexityThreshold {
+    pub threshold: u32,
+}
+
+impl Default for ComplexityThreshold {
+    fn default() -> Self {
+        Self { threshold: 30 }
+    }
+}
+
+impl Rule for ComplexityThreshold {
+    fn name(&self) -> &str {
+        "complexity-threshold"
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Diagnostic> {
+        ctx.parsed
+            .complexity
+            .iter()
+            .filter(|func| func.metrics.importance_score() >= self.threshold)
+            .map(|func| Diagnostic {
+                rule: self.name().to_string(),
+                severity: Severity::Warn,
+                file: ctx.file.to_string(),
+                line: func.line,
+                message: format!(
+                    "{} has importance score {} (threshold {})",
+                    func.qualified_name(),
+                    func.metrics.importance_score(),
+                    self.threshold
+                ),
+            })
+            .collect()
+    }
+}
+
+/// Flags every `unsafe` block the `safety` module found, naming the operations inside it.
+pub struct UnsafeBlockPresent;
+
+impl Rule for UnsafeBlockPresent {
+    fn name(&self) -> &str {
+        "unsafe-block"
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Diagnostic> {
+        ctx.parsed
+            .safety
+            .unsafe_blocks
+            .iter()
+            .map(|block| {
+                let scope = block.containing_function.as_deref().unwrap_or("<module scope>");
+                let operations = if block.operations.is_empty() {
+                    "no flagged operations".to_string()
+                } else {
+                    block
+                        .operations
+                        .iter()
+                        .map(|op| op.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+
+                let severity = if block.unjustified {
+                    Severity::Error
+                } else {
+                    Severity::Warn
+                };
+                let message = if block.unjustified {
+                    format!(
+                        "unsafe block in {} ({}) has no SAFETY comment justifying it",
+                        scope, operations
+                    )
+                } else {
+                    format!("unsafe block in {} ({})", scope, operations)
+                };
+
+                Diagnostic {
+                    rule: self.name().to_string(),
+                    severity,
+                    file: ctx.file.to_string(),
+                    line: block.line,
+                    message,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Flags every lock guard the `safety` module found still bound across an `.await` — the guard
+/// blocks every task on the runtime thread until the suspended future resumes, and can make the
+/// future itself `!Send`.
+pub struct GuardAcrossAwait;
+
+impl Rule for GuardAcrossAwait {
+    fn name(&self) -> &str {
+        "guard-across-await"
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Diagnostic> {
+        ctx.parsed
+            .guard_await_conflicts
+            .iter()
+            .map(|conflict| Diagnostic {
+                rule: self.name().to_string(),
+                severity: Severity::Error,
+                file: ctx.file.to_string(),
+                line: conflict.await_line,
+                message: format!(
+                    "guard `{}` (bound at line {}) in {} is still held at this await point",
+                    conflict.guard_expr, conflict.guard_line, conflict.containing_function
+                ),
+            })
+            .collect()
+    }
+}
+
+/// Flags a documented `fn` whose signature implies a doc section the writer forgot: a public fn
+/// returning `Result` with no `# Errors` section, an `unsafe fn` with no `# Safety` section, or a
+/// fn whose body panics (`panic!`/`.unwrap()`/`.expect()`) with no `# Panics` section — the
+/// static analog of the "missing section" diagnostic an IDE's doc tooling would surface.
+pub struct DocCompleteness;
+
+impl Rule for DocCompleteness {
+    fn name(&self) -> &str {
+        "doc-completeness"
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        for doc in &ctx.parsed.doc_info.item_docs {
+            if doc.item_kind != "fn" {
+                continue;
+            }
+
+            let Some(symbol) = ctx
+                .parsed
+                .symbols
+                .symbols
+                .iter()
+                .find(|s| s.name == doc.item_name && s.line == doc.line)
+            else {
+                continue;
+            };
+
+            let SymbolKind::Function { signature_model, .. } = &symbol.kind else {
+                continue;
+            };
+
+            if symbol.visibility == Visibility::Public
+                && signature_model.return_type.contains("Result")
+                && !doc.has_errors_section
+            {
+                diagnostics.push(Diagnostic {
+                    rule: self.name().to_string(),
+                    severity: Severity::Warn,
+                    file: ctx.file.to_string(),
+                    line: doc.line,
+                    message: format!(
+                        "[doc-missing-errors] `{}` returns a `Result` but its doc has no `# Errors` section",
+                        doc.item_name
+                    ),
+                });
+            }
+
+            if symbol.is_unsafe && !doc.has_safety_section {
+                diagnostics.push(Diagnostic {
+                    rule: self.name().to_string(),
+                    severity: Severity::Warn,
+                    file: ctx.file.to_string(),
+                    line: doc.line,
+                    message: format!(
+                        "[doc-missing-safety] `{}` is unsafe but its doc has no `# Safety` section",
+                        doc.item_name
+                    ),
+                });
+            }
+
+            if signature_model.panics_in_body && !doc.has_panics_section {
+                diagnostics.push(Diagnostic {
+                    rule: self.name().to_string(),
+                    severity: Severity::Warn,
+                    file: ctx.file.to_string(),
+                    line: doc.line,
+                    message: format!(
+                        "[doc-missing-panics] `{}` can panic but its doc has no `# Panics` section",
+                        doc.item_name
+                    ),
+                });
+            }
+        }
+
+        diagnostics
+    }
+}
+
+/// Flags functions whose call-site count (aggregated across the whole cache by
+/// [`super::run_all`]) is `multiplier` times past [`HOT_PATH_MIN_CALLS`] — functions so
+/// widely depended on that touching their signature ripples across the codebase.
+pub struct ExcessiveCallers {
+    pub multiplier: u32,
+}
+
+impl Default for ExcessiveCallers {
+    fn default() -> Self {
+        Self { multiplier: 5 }
+    }
+}
+
+impl Rule for ExcessiveCallers {
+    fn name(&self) -> &str {
+        "excessive-callers"
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Diagnostic> {
+        let threshold = HOT_PATH_MIN_CALLS * self.multiplier;
+
+        ctx.parsed
+            .complexity
+            .iter()
+            .filter(|func| func.metrics.call_sites >= threshold)
+            .map(|func| Diagnostic {
+                rule: self.name().to_string(),
+                severity: Severity::Info,
+                file: ctx.file.to_string(),
+                line: func.line,
+                message: format!(
+                    "{} has {} callers, {}x the hot-path cutoff of {}",
+                    func.qualified_name(),
+                    func.metrics.call_sites,
+                    self.multiplier,
+                    HOT_PATH_MIN_CALLS
+                ),
+            })
+            .collect()
+    }
+}
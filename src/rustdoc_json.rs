@@ -0,0 +1,302 @@
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+
+use crate::extract::safety::{DocInfo, ItemDoc};
+use crate::git::resolve_executable;
+
+/// The rustdoc JSON `format_version` this module knows how to read. rustdoc bumps this with
+/// every breaking change to the output shape, so a mismatch is treated as a hard error rather
+/// than guessed at — see [`parse_rustdoc_json`].
+pub const SUPPORTED_FORMAT_VERSION: u32 = 45;
+
+/// A rustdoc item or path-summary ID. rustdoc's own type is a numeric newtype, but JSON object
+/// keys are always strings, so every `Id` this module sees is already a string by the time serde
+/// hands it to us.
+pub type ItemId = String;
+
+/// The root of a `cargo rustdoc -- --output-format json` document, trimmed to the fields this
+/// module actually joins against charter's own `DocInfo`/`ItemDoc` facts. Fields rustdoc emits
+/// that we don't model are silently ignored by `#[serde(deny_unknown_fields)]`'s absence, not
+/// hand-parsed.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct RustdocJson {
+    pub format_version: u32,
+    pub root: ItemId,
+    pub index: HashMap<ItemId, RustdocItem>,
+    pub paths: HashMap<ItemId, ItemSummary>,
+    pub external_crates: HashMap<String, ExternalCrate>,
+}
+
+/// One entry of rustdoc's `index` map — an item's docstring plus the intra-doc link targets
+/// rustdoc already resolved for it. `inner`/`visibility` are kept as raw JSON rather than
+/// modeled in full, since this module only needs the docs/links to enrich `ItemDoc`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct RustdocItem {
+    pub id: ItemId,
+    pub crate_id: u32,
+    pub name: Option<String>,
+    pub docs: Option<String>,
+    #[serde(default)]
+    pub links: HashMap<String, ItemId>,
+    #[serde(default)]
+    pub visibility: serde_json::Value,
+    #[serde(default)]
+    pub inner: serde_json::Value,
+}
+
+/// One entry of rustdoc's `paths` map — the fully-qualified module path rustdoc resolved for an
+/// item, keyed by the same `Id` that appears in `index` and in other items' `links`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ItemSummary {
+    pub crate_id: u32,
+    pub path: Vec<String>,
+    pub kind: serde_json::Value,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct ExternalCrate {
+    pub name: String,
+    pub html_root_url: Option<String>,
+}
+
+/// Runs `cargo rustdoc -- --output-format json` against the crate rooted at `manifest_dir`,
+/// locates the JSON file it writes under `target/doc/`, and parses it. `resolve_executable`
+/// guards the same PATH-hijack risk as charter's `git` subprocess calls — see
+/// [`crate::git::resolve_executable`].
+pub async fn run_cargo_rustdoc_json(manifest_dir: &Path) -> Result<RustdocJson> {
+    let output = Command::new(resolve_executable("cargo"))
+        .args([
+            "rustdoc",
+            "--lib",
+            "--",
+            "-Z",
+            "unstable-options",
+            "--output-format",
+            "json",
+        ])
+        .current_dir(manifest_dir)
+        .output()
+        .await
+        .map_err(|e| anyhow!("failed to spawn cargo rustdoc: {e}"))?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "cargo rustdoc failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let doc_dir = manifest_dir.join("target").join("doc");
+    let json_path = find_rustdoc_json_file(&doc_dir)?;
+    let contents = tokio::fs::read_to_string(&json_path)
+        .await
+        .map_err(|e| anyhow!("failed to read {}: {e}", json_path.display()))?;
+
+    parse_rustdoc_json(&contents)
+}
+
+fn find_rustdoc_json_file(doc_dir: &Path) -> Result<PathBuf> {
+    let entries = std::fs::read_dir(doc_dir)
+        .map_err(|e| anyhow!("failed to read {}: {e}", doc_dir.display()))?;
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .find(|path| path.extension().and_then(|ext| ext.to_str()) == Some("json"))
+        .ok_or_else(|| anyhow!("no rustdoc JSON output found in {}", doc_dir.display()))
+}
+
+/// Parses rustdoc's JSON output, rejecting anything whose `format_version` this module wasn't
+/// built against rather than risking a silently wrong merge against a shape rustdoc has since
+/// changed out from under us.
+pub fn parse_rustdoc_json(contents: &str) -> Result<RustdocJson> {
+    let doc: RustdocJson = serde_json::from_str(contents)
+        .map_err(|e| anyhow!("failed to parse rustdoc JSON output: {e}"))?;
+
+    if doc.format_version != SUPPORTED_FORMAT_VERSION {
+        return Err(anyhow!(
+            "unsupported rustdoc JSON format_version {} (charter supports {}); update \
+             SUPPORTED_FORMAT_VERSION (and this module's field mappings) for the installed \
+             rustdoc before retrying",
+            doc.format_version,
+            SUPPORTED_FORMAT_VERSION
+        ));
+    }
+
+    Ok(doc)
+}
+
+/// Joins a `RustdocJson` document onto `doc_info`'s `item_docs`, matching on item name against
+/// rustdoc's `paths` map. On a match, fills in the item's stable rustdoc `Id`, its
+/// fully-qualified path, and resolves every one of its `doc_links` that rustdoc also resolved an
+/// intra-doc target for. Items rustdoc doesn't know about (private items below rustdoc's
+/// visibility threshold, items in a different crate) are left untouched. Ambiguous short names
+/// (the same identifier re-exported under multiple paths) resolve to whichever rustdoc `Id`
+/// `paths` happens to iterate first — a best-effort join, not a guarantee of uniqueness.
+pub fn enrich_doc_info(doc_info: &mut DocInfo, rustdoc: &RustdocJson) {
+    let mut id_by_short_name: HashMap<&str, &str> = HashMap::new();
+    for (id, summary) in &rustdoc.paths {
+        if let Some(short_name) = summary.path.last() {
+            id_by_short_name.entry(short_name.as_str()).or_insert(id.as_str());
+        }
+    }
+
+    for item in &mut doc_info.item_docs {
+        enrich_item_doc(item, rustdoc, &id_by_short_name);
+    }
+}
+
+fn enrich_item_doc(item: &mut ItemDoc, rustdoc: &RustdocJson, id_by_short_name: &HashMap<&str, &str>) {
+    let Some(&id) = id_by_short_name.get(item.item_name.as_str()) else {
+        return;
+    };
+
+    if let Some(summary) = rustdoc.paths.get(id) {
+        item.qualified_path = Some(summary.path.join("::"));
+    }
+    item.rustdoc_id = Some(id.to_string());
+
+    let Some(rustdoc_item) = rustdoc.index.get(id) else {
+        return;
+    };
+    for link in &mut item.doc_links {
+        let Some(target_id) = rustdoc_item.links.get(&link.target_path) else {
+            continue;
+        };
+        link.resolved_target = Some(
+            rustdoc
+                .paths
+                .get(target_id)
+                .map(|summary| summary.path.join("::"))
+                .unwrap_or_else(|| target_id.clone()),
+        );
+    }
+}
+
+/// Where [`resolve_used_name`] found the canonical path for a name, alongside the name the
+/// source actually wrote — e.g. `used_name: "Arc"` resolving to `canonical_path:
+/// "alloc::sync::Arc"` even though the code wrote `std::sync::Arc`, since `std::sync::Arc` is
+/// itself a `pub use` re-export of the `alloc` item.
+#[derive(Debug, Clone)]
+pub struct ReexportInfo {
+    pub canonical_path: String,
+    pub used_name: String,
+}
+
+/// Resolves `used_name` — a bare identifier as written in source — to the canonical path rustdoc
+/// settled on for it. First checks `rustdoc.index` for an `import` item (rustdoc's representation
+/// of a `use`/`pub use` statement) named `used_name`, since that's the only place the *original*
+/// written path (e.g. `std::sync::Arc`) survives; rustdoc's own canonical path for the same item
+/// (e.g. `alloc::sync::Arc`) lives in `rustdoc.paths` instead. Falls back to `rustdoc.paths`
+/// directly when `used_name` isn't itself behind a re-export, so a plain (non-re-exported) type
+/// still resolves.
+pub fn resolve_used_name(rustdoc: &RustdocJson, used_name: &str) -> Option<ReexportInfo> {
+    for item in rustdoc.index.values() {
+        let Some(import) = item.inner.get("import") else {
+            continue;
+        };
+        if import.get("name").and_then(|v| v.as_str()) != Some(used_name) {
+            continue;
+        }
+        if let Some(source) = import.get("source").and_then(|v| v.as_str()) {
+            return Some(ReexportInfo {
+                canonical_path: source.to_string(),
+                used_name: used_name.to_string(),
+            });
+        }
+    }
+
+    rustdoc
+        .paths
+        .values()
+        .find(|summary| summary.path.last().map(String::as_str) == Some(used_name))
+        .map(|summary| ReexportInfo {
+            canonical_path: summary.path.join("::"),
+            used_name: used_name.to_string(),
+        })
+}
+
+/// One trait or inherent impl block rustdoc recorded against a type, as found by
+/// [`impls_for_type`]. `trait_name` is `None` for an inherent impl (`impl Foo { .. }` rather than
+/// `impl Trait for Foo { .. }`).
+#[derive(Debug, Clone)]
+pub struct ImplInfo {
+    pub trait_name: Option<String>,
+    /// `true` when the impl block itself is declared in a different crate than the one rustdoc
+    /// was run against — an impl source-only analysis can never see, since the `impl` keyword
+    /// doesn't appear anywhere in this crate's own files.
+    pub is_external: bool,
+    pub method_names: Vec<String>,
+}
+
+/// Collects every impl block in `rustdoc.index` whose `for` target resolves to `type_id`
+/// (rustdoc's own `Id` for the type, as found in `rustdoc.paths`), regardless of which crate
+/// declared the impl — this is what lets a dependency's `impl Display for Foo` show up on `Foo`'s
+/// type page even though `Foo` and that impl live in different crates.
+pub fn impls_for_type(rustdoc: &RustdocJson, type_id: &str) -> Vec<ImplInfo> {
+    let local_crate_id = rustdoc.index.get(&rustdoc.root).map(|root| root.crate_id);
+
+    rustdoc
+        .index
+        .values()
+        .filter_map(|item| {
+            let imp = item.inner.get("impl")?;
+            let for_id = imp.get("for").and_then(resolved_path_id)?;
+            if for_id != type_id {
+                return None;
+            }
+
+            let trait_name = imp
+                .get("trait")
+                .filter(|t| !t.is_null())
+                .and_then(|t| t.get("name"))
+                .and_then(|v| v.as_str())
+                .map(str::to_string);
+
+            let method_names = imp
+                .get("items")
+                .and_then(|v| v.as_array())
+                .into_iter()
+                .flatten()
+                .filter_map(|id| id.as_str())
+                .filter_map(|id| rustdoc.index.get(id))
+                .filter_map(|method| method.name.clone())
+                .collect();
+
+            Some(ImplInfo {
+                trait_name,
+                is_external: local_crate_id.is_some_and(|local| item.crate_id != local),
+                method_names,
+            })
+        })
+        .collect()
+}
+
+/// Pulls the rustdoc `Id` out of a `Type` JSON value's `resolved_path.id` field — the shape every
+/// named type (a struct, enum, or type alias reference) takes in rustdoc JSON, whether it appears
+/// as an impl's `for`/`trait` target or a generic argument.
+fn resolved_path_id(ty: &serde_json::Value) -> Option<&str> {
+    ty.get("resolved_path")?.get("id")?.as_str()
+}
+
+/// For a field (or other) type reference shaped like `Arc<Foo>`, returns the rustdoc `Id`s of its
+/// generic arguments (here, just `Foo`'s) so a type page can recurse into them and list `Foo`'s
+/// own members as children instead of stopping at the opaque `Arc<Foo>` the source wrote.
+pub fn generic_argument_ids(ty: &serde_json::Value) -> Vec<&str> {
+    let Some(args) = ty
+        .get("resolved_path")
+        .and_then(|p| p.get("args"))
+        .and_then(|a| a.get("angle_bracketed"))
+        .and_then(|a| a.get("args"))
+        .and_then(|a| a.as_array())
+    else {
+        return Vec::new();
+    };
+
+    args.iter()
+        .filter_map(|arg| arg.get("type"))
+        .filter_map(resolved_path_id)
+        .collect()
+}
@@ -1,4 +1,6 @@
 use anyhow::Result;
+use fst::automaton::{Automaton, Levenshtein, Str};
+use fst::{IntoStreamer, Map, MapBuilder, Streamer};
 use grep_regex::RegexMatcherBuilder;
 use grep_searcher::{SearcherBuilder, Sink, SinkContext, SinkContextKind, SinkMatch};
 use rmcp::{
@@ -12,15 +14,16 @@ use rmcp::{
     transport::stdio,
 };
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::cache::Cache;
 use crate::detect::detect_workspace;
-use crate::extract::symbols::{Symbol, SymbolKind, Visibility};
+use crate::extract::symbols::{Symbol, SymbolKind, VariantPayload, Visibility};
 use crate::pipeline::{self, FileResult, PipelineResult, walk};
+use crate::resolve;
 
 pub struct Index {
     pub result: PipelineResult,
@@ -33,6 +36,102 @@ pub struct Index {
     pub reverse_calls: HashMap<String, Vec<CallerInfo>>,
     pub derive_map: HashMap<String, Vec<String>>,
     pub snippets_by_name: HashMap<String, Vec<SnippetInfo>>,
+    pub fields_by_type: HashMap<String, Vec<FieldInfo>>,
+    pub variants_by_type: HashMap<String, Vec<VariantInfo>>,
+    token_index: HashMap<String, Vec<SymbolRef>>,
+    first_char_index: HashMap<char, Vec<String>>,
+    symbol_tokens: HashMap<String, Vec<String>>,
+    /// Rebuilt from `result.files` on every [`Self::new`]/rescan so [`crate::resolve::resolve_path`]
+    /// can walk the module tree without re-reading `.charter/cache.bin` from disk.
+    cache: Cache,
+    /// `None` only if the FST build failed (see [`build_symbol_fst`]); `find_symbol` and
+    /// `get_snippet` fall back to their original linear scans in that case.
+    symbol_fst: Option<SymbolFst>,
+}
+
+/// An FST over every `symbols_by_name` key's leaf segment (the part after the last `::`, which
+/// is what callers actually search by — a bare method name like `connect`, not `Server::connect`),
+/// normalized to lowercase so `fst::Map` keys compare byte-for-byte. Several qualified names can
+/// share a leaf (an inherent method redefined across types, a free function and a method of the
+/// same name), so the map's value is an index into `buckets` rather than a name directly — the
+/// same scheme [`crate::symbolsearch::SymbolSearchIndex`] uses for `charter search`, just keyed on
+/// every symbol kind instead of only functions.
+struct SymbolFst {
+    map: Map<Vec<u8>>,
+    buckets: Vec<Vec<String>>,
+}
+
+impl SymbolFst {
+    /// Resolves `query` (normalized the same way [`build_symbol_fst`] normalizes leaf names)
+    /// against the FST: an exact key hit, then a prefix automaton, then a Levenshtein automaton
+    /// whose max edits scales with query length (1 for <=4 chars, 2 otherwise) — each tier only
+    /// runs if the previous one came up empty.
+    fn candidates(&self, query: &str) -> Vec<&str> {
+        let normalized = query.to_lowercase();
+
+        if let Some(bucket_id) = self.map.get(&normalized) {
+            return self.buckets[bucket_id as usize]
+                .iter()
+                .map(String::as_str)
+                .collect();
+        }
+
+        let prefix_hits = self.stream_matches(Str::new(&normalized).starts_with());
+        if !prefix_hits.is_empty() {
+            return prefix_hits;
+        }
+
+        let max_edits = if normalized.chars().count() <= 4 { 1 } else { 2 };
+        let Ok(automaton) = Levenshtein::new(&normalized, max_edits) else {
+            return Vec::new();
+        };
+        self.stream_matches(automaton)
+    }
+
+    fn stream_matches<A: Automaton>(&self, automaton: A) -> Vec<&str> {
+        let mut stream = self.map.search(automaton).into_stream();
+        let mut names = Vec::new();
+        while let Some((_key, value)) = stream.next() {
+            names.extend(self.buckets[value as usize].iter().map(String::as_str));
+        }
+        names
+    }
+}
+
+/// Builds a [`SymbolFst`] over every key in `symbols_by_name`, grouped by leaf segment. Returns
+/// `None` if the FST build fails (it practically never does, since the grouping map is already
+/// sorted/deduplicated, but `find_symbol`/`get_snippet` treat `None` as a signal to fall back to
+/// their original linear scans rather than unwrap/panic).
+fn build_symbol_fst(symbols_by_name: &HashMap<String, Vec<SymbolInfo>>) -> Option<SymbolFst> {
+    let mut grouped: std::collections::BTreeMap<String, Vec<String>> =
+        std::collections::BTreeMap::new();
+    for qualified_name in symbols_by_name.keys() {
+        let leaf = qualified_name.rsplit("::").next().unwrap_or(qualified_name);
+        grouped
+            .entry(leaf.to_lowercase())
+            .or_default()
+            .push(qualified_name.clone());
+    }
+
+    let mut builder = MapBuilder::memory();
+    let mut buckets = Vec::with_capacity(grouped.len());
+    for (bucket_id, (leaf, names)) in grouped.into_iter().enumerate() {
+        builder.insert(leaf, bucket_id as u64).ok()?;
+        buckets.push(names);
+    }
+
+    let map = Map::new(builder.into_inner().ok()?).ok()?;
+    Some(SymbolFst { map, buckets })
+}
+
+/// One occurrence of a token (from tokenizing a qualified name the same way
+/// [`crate::cache::index::tokenize`] tokenizes free text) in `Index::symbols_by_name` — a
+/// qualified name plus the index of the specific overload within its bucket, since a name can
+/// have several entries (methods with the same name on different types, re-declarations, etc.).
+#[derive(Debug, Clone)]
+struct SymbolRef {
+    qualified_name: String,
+    index: usize,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -48,6 +147,14 @@ pub struct CallTarget {
     pub receiver_type: Option<String>,
     pub file: String,
     pub line: usize,
+    /// For a bare (non-method) call, the file the target name actually resolved to via
+    /// [`resolve::resolve_call_target`] — same-module definition, explicit import, or glob
+    /// import, in that precedence. `None` for method calls (already disambiguated by
+    /// `receiver_type`) or when resolution failed.
+    pub resolved_file: Option<String>,
+    /// `true` if the bare call name resolved to more than one candidate at the same precedence
+    /// tier and couldn't be disambiguated.
+    pub ambiguous: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -56,6 +163,10 @@ pub struct CallerInfo {
     pub impl_type: Option<String>,
     pub file: String,
     pub line: usize,
+    /// The file the callee name resolved to, mirroring [`CallTarget::resolved_file`].
+    pub resolved_file: Option<String>,
+    /// Mirrors [`CallTarget::ambiguous`].
+    pub ambiguous: bool,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -78,157 +189,528 @@ pub struct SymbolInfo {
     pub visibility: String,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldInfo {
+    pub name: String,
+    pub field_type: String,
+    pub visibility: String,
+    pub file: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct VariantInfo {
+    pub name: String,
+    /// Textual summary of the variant's payload, e.g. `(String, u32)` for a tuple variant or
+    /// `{ x: i32, y: i32 }` for a struct variant — `None` for a unit variant.
+    pub payload: Option<String>,
+    pub file: String,
+    pub line: usize,
+}
+
 impl Index {
     pub fn new(
         result: PipelineResult,
         symbol_table: HashMap<String, (String, usize)>,
         references: HashMap<String, Vec<(String, usize)>>,
     ) -> Self {
+        let cache = pipeline::build_cache(&result.files);
         let mut symbols_by_name: HashMap<String, Vec<SymbolInfo>> = HashMap::new();
         let mut impl_map: HashMap<String, Vec<ImplInfo>> = HashMap::new();
-        let mut reverse_impl_map: HashMap<String, Vec<String>> = HashMap::new();
         let mut call_graph: HashMap<String, Vec<CallTarget>> = HashMap::new();
         let mut reverse_calls: HashMap<String, Vec<CallerInfo>> = HashMap::new();
-        let mut derive_map: HashMap<String, Vec<String>> = HashMap::new();
         let mut snippets_by_name: HashMap<String, Vec<SnippetInfo>> = HashMap::new();
+        let mut fields_by_type: HashMap<String, Vec<FieldInfo>> = HashMap::new();
+        let mut variants_by_type: HashMap<String, Vec<VariantInfo>> = HashMap::new();
 
         for file in &result.files {
-            for symbol in &file.parsed.symbols.symbols {
-                let info = symbol_to_info(symbol, &file.relative_path);
-                symbols_by_name
-                    .entry(symbol.name.clone())
-                    .or_default()
-                    .push(info);
-            }
+            insert_file_into_maps(
+                file,
+                &cache,
+                &mut symbols_by_name,
+                &mut fields_by_type,
+                &mut variants_by_type,
+                &mut impl_map,
+                &mut call_graph,
+                &mut reverse_calls,
+                &mut snippets_by_name,
+            );
+        }
 
-            for (trait_name, type_name) in &file.parsed.symbols.impl_map {
-                let impl_info = ImplInfo {
-                    type_name: type_name.clone(),
-                    file: file.relative_path.clone(),
-                    line: find_impl_line(&file.parsed.symbols.inherent_impls, type_name)
-                        .unwrap_or(0),
+        let (reverse_impl_map, derive_map) = build_impl_and_derive_maps(&result.files);
+        let (token_index, first_char_index, symbol_tokens) = build_derived_indexes(&symbols_by_name);
+        let symbol_fst = build_symbol_fst(&symbols_by_name);
+
+        Self {
+            result,
+            symbol_table,
+            references,
+            symbols_by_name,
+            impl_map,
+            reverse_impl_map,
+            call_graph,
+            reverse_calls,
+            derive_map,
+            snippets_by_name,
+            fields_by_type,
+            variants_by_type,
+            token_index,
+            first_char_index,
+            symbol_tokens,
+            cache,
+            symbol_fst,
+        }
+    }
+
+    /// Patches `self` for a rescan instead of rebuilding from scratch: drops every entry that
+    /// belonged to a modified or removed file from the per-file-tagged maps (`symbols_by_name`,
+    /// `fields_by_type`, `variants_by_type`, `impl_map`, `call_graph`, `reverse_calls`,
+    /// `snippets_by_name`), then re-inserts entries for `result.files`' freshly (re)parsed files
+    /// (those with `from_cache == false`, i.e. the added and modified ones). `reverse_impl_map`
+    /// and `derive_map` carry no per-entry file, so they're always rebuilt from the complete
+    /// `result.files` — still cheap, since that's an in-memory scan of already-parsed data, not a
+    /// reparse. The result is required to be identical to calling [`Self::new`] on the same
+    /// `result`/`symbol_table`/`references`.
+    fn apply_rescan(
+        &mut self,
+        result: PipelineResult,
+        symbol_table: HashMap<String, (String, usize)>,
+        references: HashMap<String, Vec<(String, usize)>>,
+        stale_files: &HashSet<String>,
+    ) {
+        let new_cache = pipeline::build_cache(&result.files);
+
+        remove_file_entries(&mut self.symbols_by_name, stale_files, |info| &info.file);
+        remove_file_entries(&mut self.fields_by_type, stale_files, |info| &info.file);
+        remove_file_entries(&mut self.variants_by_type, stale_files, |info| &info.file);
+        remove_file_entries(&mut self.impl_map, stale_files, |info| &info.file);
+        remove_file_entries(&mut self.call_graph, stale_files, |info| &info.file);
+        remove_file_entries(&mut self.reverse_calls, stale_files, |info| &info.file);
+        remove_file_entries(&mut self.snippets_by_name, stale_files, |info| &info.file);
+
+        for file in result.files.iter().filter(|file| !file.from_cache) {
+            insert_file_into_maps(
+                file,
+                &new_cache,
+                &mut self.symbols_by_name,
+                &mut self.fields_by_type,
+                &mut self.variants_by_type,
+                &mut self.impl_map,
+                &mut self.call_graph,
+                &mut self.reverse_calls,
+                &mut self.snippets_by_name,
+            );
+        }
+
+        // `remove_file_entries` preserves the remaining entries' relative order, but the loop
+        // above appends each patched file's fresh entries to the *end* of every key's Vec —
+        // which only matches a from-scratch build's sorted-by-`relative_path` order if the
+        // patched file happens to sort last among that key's contributors. Re-sort by file so
+        // `apply_rescan` stays byte-identical to `Self::new` on the same `result`, not just
+        // set-equal to it.
+        resort_by_file(&mut self.symbols_by_name, |info| &info.file);
+        resort_by_file(&mut self.fields_by_type, |info| &info.file);
+        resort_by_file(&mut self.variants_by_type, |info| &info.file);
+        resort_by_file(&mut self.impl_map, |info| &info.file);
+        resort_by_file(&mut self.call_graph, |info| &info.file);
+        resort_by_file(&mut self.reverse_calls, |info| &info.file);
+        resort_by_file(&mut self.snippets_by_name, |info| &info.file);
+
+        let (reverse_impl_map, derive_map) = build_impl_and_derive_maps(&result.files);
+        self.reverse_impl_map = reverse_impl_map;
+        self.derive_map = derive_map;
+
+        let (token_index, first_char_index, symbol_tokens) = build_derived_indexes(&self.symbols_by_name);
+        self.token_index = token_index;
+        self.first_char_index = first_char_index;
+        self.symbol_tokens = symbol_tokens;
+        self.symbol_fst = build_symbol_fst(&self.symbols_by_name);
+
+        self.cache = new_cache;
+        self.result = result;
+        self.symbol_table = symbol_table;
+        self.references = references;
+    }
+
+    /// Typo-tolerant, relevance-ranked symbol search. Tokenizes `query` the same way
+    /// [`Self::new`] tokenized each qualified name into `token_index`, then for every query term
+    /// scans only the tokens sharing its first character (an approximation of a Levenshtein
+    /// automaton keyed off the first character — cheap, and near-typos rarely touch the first
+    /// letter) for an exact, prefix, or [`fuzzy_match`] hit.
+    ///
+    /// Candidates are ranked by a cascade, each tier only breaking ties left by the one before
+    /// it: more matched query terms first, then exactness (exact > prefix > typo), then total
+    /// edit distance across matched terms, then how tightly those terms' tokens cluster inside
+    /// the symbol's name (for multi-word queries), and finally `importance_score` (sourced from
+    /// `snippets_by_name`, since `SymbolInfo` itself doesn't carry one).
+    pub fn search_symbols_ranked(
+        &self,
+        query: &str,
+        kind_filter: Option<&str>,
+        limit: usize,
+    ) -> Vec<(&str, &SymbolInfo)> {
+        let query_terms = crate::cache::index::tokenize(query);
+        if query_terms.is_empty() {
+            return Vec::new();
+        }
+
+        let mut candidates: HashMap<(String, usize), Vec<Option<(FuzzyTier, usize)>>> =
+            HashMap::new();
+
+        for (term_index, term) in query_terms.iter().enumerate() {
+            let Some(first_char) = term.chars().next() else {
+                continue;
+            };
+            let Some(bucket) = self.first_char_index.get(&first_char) else {
+                continue;
+            };
+
+            for token in bucket {
+                let Some(tier) = fuzzy_match(term, token) else {
+                    continue;
+                };
+                let Some(refs) = self.token_index.get(token) else {
+                    continue;
                 };
-                impl_map
-                    .entry(trait_name.clone())
-                    .or_default()
-                    .push(impl_info);
-                reverse_impl_map
-                    .entry(type_name.clone())
-                    .or_default()
-                    .push(trait_name.clone());
-            }
 
-            for inherent_impl in &file.parsed.symbols.inherent_impls {
-                for method in &inherent_impl.methods {
-                    let qualified = format!("{}::{}", inherent_impl.type_name, method.name);
-                    let info = SymbolInfo {
-                        name: method.name.clone(),
-                        kind: "method".to_string(),
-                        file: file.relative_path.clone(),
-                        line: method.line,
-                        signature: Some(method.signature.clone()),
-                        visibility: format!("{}", method.visibility),
+                for symbol_ref in refs {
+                    let position = self
+                        .symbol_tokens
+                        .get(&symbol_ref.qualified_name)
+                        .and_then(|tokens| tokens.iter().position(|t| t == token))
+                        .unwrap_or(0);
+
+                    let matched = candidates
+                        .entry((symbol_ref.qualified_name.clone(), symbol_ref.index))
+                        .or_insert_with(|| vec![None; query_terms.len()]);
+
+                    let better = match matched[term_index] {
+                        Some((existing_tier, _)) => tier < existing_tier,
+                        None => true,
                     };
-                    symbols_by_name.entry(qualified).or_default().push(info);
+                    if better {
+                        matched[term_index] = Some((tier, position));
+                    }
                 }
             }
+        }
 
-            for call_info in &file.parsed.call_graph {
-                let caller = call_info.caller.qualified_name();
-                let caller_impl_type = call_info.caller.impl_type.clone();
-                let caller_line = call_info.line;
-                for callee in &call_info.callees {
-                    let callee_name = callee.qualified_target();
-                    call_graph
-                        .entry(caller.clone())
-                        .or_default()
-                        .push(CallTarget {
-                            name: callee_name.clone(),
-                            receiver_type: callee.target_type.clone(),
-                            file: file.relative_path.clone(),
-                            line: callee.line,
-                        });
-                    reverse_calls
-                        .entry(callee_name)
-                        .or_default()
-                        .push(CallerInfo {
-                            name: caller.clone(),
-                            impl_type: caller_impl_type.clone(),
-                            file: file.relative_path.clone(),
-                            line: caller_line,
-                        });
+        let mut ranked: Vec<(&str, &SymbolInfo, Vec<Option<(FuzzyTier, usize)>>)> = candidates
+            .into_iter()
+            .filter_map(|((qualified_name, index), matched)| {
+                let (stored_name, infos) = self.symbols_by_name.get_key_value(&qualified_name)?;
+                let info = infos.get(index)?;
+                if let Some(kind) = kind_filter {
+                    if info.kind != kind {
+                        return None;
+                    }
                 }
-            }
+                Some((stored_name.as_str(), info, matched))
+            })
+            .collect();
 
-            for derive in &file.parsed.derives {
-                derive_map
-                    .entry(derive.target.clone())
-                    .or_default()
-                    .extend(derive.traits.clone());
-            }
+        ranked.sort_by(|a, b| {
+            let matched_count = |m: &[Option<(FuzzyTier, usize)>]| m.iter().filter(|t| t.is_some()).count();
+            matched_count(&b.2)
+                .cmp(&matched_count(&a.2))
+                .then_with(|| exactness_sum(&a.2).cmp(&exactness_sum(&b.2)))
+                .then_with(|| edit_distance_sum(&a.2).cmp(&edit_distance_sum(&b.2)))
+                .then_with(|| term_spread(&a.2).cmp(&term_spread(&b.2)))
+                .then_with(|| self.importance_for(b.0).cmp(&self.importance_for(a.0)))
+        });
 
-            for captured in &file.parsed.captured_bodies {
-                let key = if let Some(ref impl_type) = captured.impl_type {
-                    format!("{}::{}", impl_type, captured.function_name)
-                } else {
-                    captured.function_name.clone()
-                };
-                let body_text = captured
-                    .body
-                    .full_text
-                    .clone()
-                    .unwrap_or_else(|| "[body not captured]".to_string());
-                snippets_by_name
-                    .entry(key.clone())
-                    .or_default()
-                    .push(SnippetInfo {
-                        function_name: captured.function_name.clone(),
-                        impl_type: captured.impl_type.clone(),
+        ranked.truncate(limit);
+        ranked.into_iter().map(|(name, info, _)| (name, info)).collect()
+    }
+
+    /// Highest `importance_score` among the snippets captured for `qualified_name`, or `0` if it
+    /// has no captured body (e.g. a struct or trait definition) — used only as the last tiebreak
+    /// in [`Self::search_symbols_ranked`].
+    fn importance_for(&self, qualified_name: &str) -> u32 {
+        self.snippets_by_name
+            .get(qualified_name)
+            .and_then(|snippets| snippets.iter().map(|s| s.importance_score).max())
+            .unwrap_or(0)
+    }
+}
+
+/// Inserts every entry `file` contributes into the per-file-tagged index maps. Shared by
+/// [`Index::new`] (called for every file) and [`Index::apply_rescan`] (called only for files that
+/// were added or modified), so both code paths produce identical entries for the same file.
+#[allow(clippy::too_many_arguments)]
+fn insert_file_into_maps(
+    file: &FileResult,
+    cache: &Cache,
+    symbols_by_name: &mut HashMap<String, Vec<SymbolInfo>>,
+    fields_by_type: &mut HashMap<String, Vec<FieldInfo>>,
+    variants_by_type: &mut HashMap<String, Vec<VariantInfo>>,
+    impl_map: &mut HashMap<String, Vec<ImplInfo>>,
+    call_graph: &mut HashMap<String, Vec<CallTarget>>,
+    reverse_calls: &mut HashMap<String, Vec<CallerInfo>>,
+    snippets_by_name: &mut HashMap<String, Vec<SnippetInfo>>,
+) {
+    for symbol in &file.parsed.symbols.symbols {
+        let info = symbol_to_info(symbol, &file.relative_path);
+        symbols_by_name.entry(symbol.name.clone()).or_default().push(info);
+
+        match &symbol.kind {
+            SymbolKind::Struct { fields } => {
+                let entries = fields_by_type.entry(symbol.name.clone()).or_default();
+                for field in fields {
+                    entries.push(FieldInfo {
+                        name: field.name.clone(),
+                        field_type: field.field_type.clone(),
+                        visibility: format!("{}", field.visibility),
                         file: file.relative_path.clone(),
-                        line: captured.line,
-                        body: body_text,
-                        importance_score: captured.importance_score,
+                        line: field.line,
                     });
-                if captured.impl_type.is_some() {
-                    snippets_by_name
-                        .entry(captured.function_name.clone())
-                        .or_default()
-                        .push(SnippetInfo {
-                            function_name: captured.function_name.clone(),
-                            impl_type: captured.impl_type.clone(),
-                            file: file.relative_path.clone(),
-                            line: captured.line,
-                            body: captured
-                                .body
-                                .full_text
-                                .clone()
-                                .unwrap_or_else(|| "[body not captured]".to_string()),
-                            importance_score: captured.importance_score,
-                        });
                 }
             }
+            SymbolKind::Enum { variants } => {
+                let entries = variants_by_type.entry(symbol.name.clone()).or_default();
+                for variant in variants {
+                    entries.push(VariantInfo {
+                        name: variant.name.clone(),
+                        payload: variant.payload.as_ref().map(format_variant_payload),
+                        file: file.relative_path.clone(),
+                        line: variant.line,
+                    });
+                }
+            }
+            _ => {}
         }
+    }
 
-        for traits in derive_map.values_mut() {
-            traits.sort();
-            traits.dedup();
+    for (trait_name, type_name) in &file.parsed.symbols.impl_map {
+        let impl_info = ImplInfo {
+            type_name: type_name.clone(),
+            file: file.relative_path.clone(),
+            line: find_impl_line(&file.parsed.symbols.inherent_impls, type_name).unwrap_or(0),
+        };
+        impl_map.entry(trait_name.clone()).or_default().push(impl_info);
+    }
+
+    for inherent_impl in &file.parsed.symbols.inherent_impls {
+        for method in &inherent_impl.methods {
+            let qualified = format!("{}::{}", inherent_impl.type_name, method.name);
+            let info = SymbolInfo {
+                name: method.name.clone(),
+                kind: "method".to_string(),
+                file: file.relative_path.clone(),
+                line: method.line,
+                signature: Some(method.signature.clone()),
+                visibility: format!("{}", method.visibility),
+            };
+            symbols_by_name.entry(qualified).or_default().push(info);
         }
+    }
 
-        Self {
-            result,
-            symbol_table,
-            references,
-            symbols_by_name,
-            impl_map,
-            reverse_impl_map,
-            call_graph,
-            reverse_calls,
-            derive_map,
-            snippets_by_name,
+    for call_info in &file.parsed.call_graph {
+        let caller = call_info.caller.qualified_name();
+        let caller_impl_type = call_info.caller.impl_type.clone();
+        let caller_line = call_info.line;
+        for callee in &call_info.callees {
+            let callee_name = callee.qualified_target();
+            let (resolved_file, ambiguous) = if callee.target_type.is_none() {
+                match resolve::resolve_call_target(cache, &file.relative_path, &callee.target) {
+                    resolve::CallResolution::Resolved { file, .. } => (Some(file), false),
+                    resolve::CallResolution::Ambiguous { .. } => (None, true),
+                    resolve::CallResolution::Unresolved => (None, false),
+                }
+            } else {
+                (None, false)
+            };
+            call_graph.entry(caller.clone()).or_default().push(CallTarget {
+                name: callee_name.clone(),
+                receiver_type: callee.target_type.clone(),
+                file: file.relative_path.clone(),
+                line: callee.line,
+                resolved_file: resolved_file.clone(),
+                ambiguous,
+            });
+            reverse_calls
+                .entry(callee_name)
+                .or_default()
+                .push(CallerInfo {
+                    name: caller.clone(),
+                    impl_type: caller_impl_type.clone(),
+                    file: file.relative_path.clone(),
+                    line: caller_line,
+                    resolved_file,
+                    ambiguous,
+                });
+        }
+    }
+
+    for captured in &file.parsed.captured_bodies {
+        let key = if let Some(ref impl_type) = captured.impl_type {
+            format!("{}::{}", impl_type, captured.function_name)
+        } else {
+            captured.function_name.clone()
+        };
+        let body_text = captured
+            .body
+            .full_text
+            .clone()
+            .unwrap_or_else(|| "[body not captured]".to_string());
+        snippets_by_name.entry(key.clone()).or_default().push(SnippetInfo {
+            function_name: captured.function_name.clone(),
+            impl_type: captured.impl_type.clone(),
+            file: file.relative_path.clone(),
+            line: captured.line,
+            body: body_text,
+            importance_score: captured.importance_score,
+        });
+        if captured.impl_type.is_some() {
+            snippets_by_name
+                .entry(captured.function_name.clone())
+                .or_default()
+                .push(SnippetInfo {
+                    function_name: captured.function_name.clone(),
+                    impl_type: captured.impl_type.clone(),
+                    file: file.relative_path.clone(),
+                    line: captured.line,
+                    body: captured
+                        .body
+                        .full_text
+                        .clone()
+                        .unwrap_or_else(|| "[body not captured]".to_string()),
+                    importance_score: captured.importance_score,
+                });
         }
     }
 }
 
+/// `reverse_impl_map` and `derive_map` entries carry no per-entry file, so unlike the other index
+/// maps they can't be patched by removing one file's contributions — they're always rebuilt from
+/// the complete file list, which is still cheap since it only scans already-parsed data.
+fn build_impl_and_derive_maps(
+    files: &[FileResult],
+) -> (HashMap<String, Vec<String>>, HashMap<String, Vec<String>>) {
+    let mut reverse_impl_map: HashMap<String, Vec<String>> = HashMap::new();
+    let mut derive_map: HashMap<String, Vec<String>> = HashMap::new();
+
+    for file in files {
+        for (trait_name, type_name) in &file.parsed.symbols.impl_map {
+            reverse_impl_map
+                .entry(type_name.clone())
+                .or_default()
+                .push(trait_name.clone());
+        }
+        for derive in &file.parsed.derives {
+            derive_map
+                .entry(derive.target.clone())
+                .or_default()
+                .extend(derive.traits.clone());
+        }
+    }
+
+    for traits in derive_map.values_mut() {
+        traits.sort();
+        traits.dedup();
+    }
+
+    (reverse_impl_map, derive_map)
+}
+
+/// Rebuilds the fuzzy-search token indexes from `symbols_by_name` — cheap since it's a scan over
+/// the symbol table rather than a reparse, so both [`Index::new`] and [`Index::apply_rescan`]
+/// rebuild it wholesale every time rather than patching it incrementally.
+fn build_derived_indexes(
+    symbols_by_name: &HashMap<String, Vec<SymbolInfo>>,
+) -> (
+    HashMap<String, Vec<SymbolRef>>,
+    HashMap<char, Vec<String>>,
+    HashMap<String, Vec<String>>,
+) {
+    let mut symbol_tokens: HashMap<String, Vec<String>> = HashMap::new();
+    let mut token_index: HashMap<String, Vec<SymbolRef>> = HashMap::new();
+    let mut first_char_index: HashMap<char, Vec<String>> = HashMap::new();
+
+    for (qualified_name, infos) in symbols_by_name {
+        let tokens = crate::cache::index::tokenize(qualified_name);
+        symbol_tokens.insert(qualified_name.clone(), tokens.clone());
+
+        let mut seen_tokens = HashSet::new();
+        for token in tokens {
+            if !seen_tokens.insert(token.clone()) {
+                continue;
+            }
+            if let Some(first_char) = token.chars().next() {
+                first_char_index.entry(first_char).or_default().push(token.clone());
+            }
+            let refs = token_index.entry(token).or_default();
+            for index in 0..infos.len() {
+                refs.push(SymbolRef {
+                    qualified_name: qualified_name.clone(),
+                    index,
+                });
+            }
+        }
+    }
+
+    for tokens in first_char_index.values_mut() {
+        tokens.sort();
+        tokens.dedup();
+    }
+
+    (token_index, first_char_index, symbol_tokens)
+}
+
+/// Drops every entry in `map`'s value lists whose file (as given by `file_of`) is in `stale`,
+/// then drops any key left with an empty list — so a key absent here looks exactly like a key
+/// that was never inserted, matching what a from-scratch [`Index::new`] would produce.
+/// Restores each key's entries to the file-path order a from-scratch build would produce
+/// (`result.files` is walked in sorted-`relative_path` order), stably so multiple entries from
+/// the same file — already in the right relative order from a single [`insert_file_into_maps`]
+/// call — keep that order. See [`Index::apply_rescan`].
+fn resort_by_file<V>(map: &mut HashMap<String, Vec<V>>, file_of: impl Fn(&V) -> &String) {
+    for entries in map.values_mut() {
+        entries.sort_by(|a, b| file_of(a).cmp(file_of(b)));
+    }
+}
+
+fn remove_file_entries<V>(
+    map: &mut HashMap<String, Vec<V>>,
+    stale: &HashSet<String>,
+    file_of: impl Fn(&V) -> &String,
+) {
+    map.retain(|_, entries| {
+        entries.retain(|entry| !stale.contains(file_of(entry)));
+        !entries.is_empty()
+    });
+}
+
+fn exactness_sum(matched: &[Option<(FuzzyTier, usize)>]) -> u32 {
+    matched.iter().filter_map(|m| m.map(|(tier, _)| tier as u32)).sum()
+}
+
+fn edit_distance_sum(matched: &[Option<(FuzzyTier, usize)>]) -> u32 {
+    matched
+        .iter()
+        .filter_map(|m| {
+            m.map(|(tier, _)| match tier {
+                FuzzyTier::Exact | FuzzyTier::Prefix => 0,
+                FuzzyTier::Typo1 => 1,
+                FuzzyTier::Typo2 => 2,
+            })
+        })
+        .sum()
+}
+
+/// Spread between the earliest and latest matched-token positions inside the symbol's tokenized
+/// name — `0` when only one query term matched (nothing to space out), since a single-word query
+/// has no proximity to measure.
+fn term_spread(matched: &[Option<(FuzzyTier, usize)>]) -> usize {
+    let positions: Vec<usize> = matched.iter().filter_map(|m| m.map(|(_, pos)| pos)).collect();
+    if positions.len() < 2 {
+        return 0;
+    }
+    let min = *positions.iter().min().unwrap();
+    let max = *positions.iter().max().unwrap();
+    max - min
+}
+
 fn find_impl_line(
     inherent_impls: &[crate::extract::symbols::InherentImpl],
     type_name: &str,
@@ -241,6 +723,19 @@ fn find_impl_line(
     None
 }
 
+fn format_variant_payload(payload: &VariantPayload) -> String {
+    match payload {
+        VariantPayload::Tuple(types) => format!("({})", types.join(", ")),
+        VariantPayload::Struct(fields) => {
+            let rendered: Vec<String> = fields
+                .iter()
+                .map(|(name, ty)| format!("{name}: {ty}"))
+                .collect();
+            format!("{{ {} }}", rendered.join(", "))
+        }
+    }
+}
+
 fn symbol_to_info(symbol: &Symbol, file: &str) -> SymbolInfo {
     let (kind, signature) = match &symbol.kind {
         SymbolKind::Struct { .. } => ("struct".to_string(), None),
@@ -271,6 +766,7 @@ fn symbol_to_info(symbol: &Symbol, file: &str) -> SymbolInfo {
 pub struct CharterServer {
     index: Arc<RwLock<Index>>,
     root: PathBuf,
+    file_cache: crate::filecache::FileContentCache,
     tool_router: ToolRouter<Self>,
 }
 
@@ -280,14 +776,270 @@ pub struct FindSymbolParams {
     pub kind: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ResolvePathParams {
+    pub path: String,
+    #[serde(default)]
+    pub root: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResolvePathResult {
+    pub resolved: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    pub trail: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unresolved_at_segment: Option<usize>,
+}
+
+fn path_resolved_kind_label(kind: resolve::PathResolvedKind) -> &'static str {
+    match kind {
+        resolve::PathResolvedKind::Module => "module",
+        resolve::PathResolvedKind::Symbol => "symbol",
+        resolve::PathResolvedKind::Method => "method",
+        resolve::PathResolvedKind::AssociatedConst => "associated_const",
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct FindImplementationsParams {
     pub symbol: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GetFieldsParams {
+    pub symbol: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FieldsResult {
+    pub fields: Vec<FieldInfo>,
+    pub variants: Vec<VariantInfo>,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct SuggestImportParams {
+    pub symbol: String,
+    #[serde(default)]
+    pub from_module: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SuggestImportResult {
+    pub found: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub suggestion: Option<String>,
+    pub alternatives: Vec<ImportCandidateInfo>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ImportCandidateInfo {
+    pub path: String,
+    pub use_statement: String,
+    pub via_reexport: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FindFieldAccessesParams {
+    pub field: String,
+    #[serde(default)]
+    pub type_name: Option<String>,
+    #[serde(default)]
+    pub max_results: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FieldAccessResult {
+    pub matches: Vec<FieldAccessMatch>,
+    pub truncated: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FieldAccessMatch {
+    pub file: String,
+    pub line: usize,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receiver_type: Option<String>,
+}
+
+/// "Did you mean" suggestions for a symbol lookup that came up empty, via Levenshtein edit
+/// distance against both a name and its trailing `::`-segment — the same technique
+/// [`crate::query`]'s CLI lookups use, bounded by [`crate::output::suggestion_distance_threshold`]
+/// so a short query isn't swamped by every distant symbol in the crate. Returns at most 5
+/// candidates, closest first.
+fn suggest_similar_names<'a>(names: impl Iterator<Item = &'a str>, query: &str) -> Vec<String> {
+    let query_lower = query.to_lowercase();
+    let max_distance = crate::output::suggestion_distance_threshold(query.len());
+
+    let mut candidates: Vec<(String, usize)> = Vec::new();
+    for name in names {
+        let leaf = name.rsplit("::").next().unwrap_or(name);
+        let distance = crate::output::levenshtein_distance(&name.to_lowercase(), &query_lower)
+            .min(crate::output::levenshtein_distance(&leaf.to_lowercase(), &query_lower));
+        if distance <= max_distance {
+            candidates.push((name.to_string(), distance));
+        }
+    }
+
+    candidates.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    candidates.dedup_by(|a, b| a.0 == b.0);
+    candidates.truncate(5);
+    candidates.into_iter().map(|(name, _)| name).collect()
+}
+
+/// Maximum number of distinct shortest paths [`find_call_path`](CharterServer::find_call_path)
+/// will return, to keep output bounded regardless of how many `::suffix` matches `from` and `to`
+/// resolve to.
+const MAX_CALL_PATHS: usize = 5;
+
+/// Resolves `query` against `map`'s keys the same way `find_callers` resolves a callee: an exact
+/// key match, plus every key that ends with `::query` (a bare name matching a type-qualified one).
+fn resolve_qualified_names<V>(map: &HashMap<String, V>, query: &str) -> Vec<String> {
+    let mut matches = Vec::new();
+    if map.contains_key(query) {
+        matches.push(query.to_string());
+    }
+    let suffix = format!("::{query}");
+    for qualified_name in map.keys() {
+        if qualified_name != query && qualified_name.ends_with(&suffix) {
+            matches.push(qualified_name.clone());
+        }
+    }
+    matches
+}
+
+/// The callees (`forward`) or callers (`!forward`) of `current`, as `(name, file, line)` triples.
+fn call_graph_next_hops(
+    current: &str,
+    call_graph: &HashMap<String, Vec<CallTarget>>,
+    reverse_calls: &HashMap<String, Vec<CallerInfo>>,
+    forward: bool,
+) -> Vec<(String, String, usize)> {
+    if forward {
+        call_graph
+            .get(current)
+            .map(|callees| {
+                callees
+                    .iter()
+                    .map(|callee| (callee.name.clone(), callee.file.clone(), callee.line))
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        reverse_calls
+            .get(current)
+            .map(|callers| {
+                callers
+                    .iter()
+                    .map(|caller| (caller.name.clone(), caller.file.clone(), caller.line))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+fn symbol_location(symbols_by_name: &HashMap<String, Vec<SymbolInfo>>, name: &str) -> (String, usize) {
+    symbols_by_name
+        .get(name)
+        .and_then(|matches| matches.first())
+        .map(|info| (info.file.clone(), info.line))
+        .unwrap_or_default()
+}
+
+/// Bounded BFS for the shortest call chain from `start` to any node in `targets`, following
+/// callees (`forward`) or callers (`!forward`) out of `call_graph`/`reverse_calls`. Tracks a
+/// visited set keyed on qualified name to cut cycles and stops expanding a branch past
+/// `max_depth` hops.
+fn shortest_call_path(
+    start: &str,
+    targets: &HashSet<String>,
+    call_graph: &HashMap<String, Vec<CallTarget>>,
+    reverse_calls: &HashMap<String, Vec<CallerInfo>>,
+    symbols_by_name: &HashMap<String, Vec<SymbolInfo>>,
+    forward: bool,
+    max_depth: usize,
+) -> Option<Vec<PathNode>> {
+    let mut visited: HashSet<String> = HashSet::new();
+    visited.insert(start.to_string());
+
+    let (file, line) = symbol_location(symbols_by_name, start);
+    let mut queue: VecDeque<Vec<PathNode>> = VecDeque::new();
+    queue.push_back(vec![PathNode {
+        name: start.to_string(),
+        file,
+        line,
+    }]);
+
+    while let Some(path) = queue.pop_front() {
+        let current = &path.last().expect("path always has at least one node").name;
+        if path.len() > 1 && targets.contains(current) {
+            return Some(path);
+        }
+        if path.len() - 1 >= max_depth {
+            continue;
+        }
+        for (next_name, next_file, next_line) in
+            call_graph_next_hops(current, call_graph, reverse_calls, forward)
+        {
+            if !visited.insert(next_name.clone()) {
+                continue;
+            }
+            let mut next_path = path.clone();
+            next_path.push(PathNode {
+                name: next_name,
+                file: next_file,
+                line: next_line,
+            });
+            queue.push_back(next_path);
+        }
+    }
+
+    None
+}
+
+fn escape_regex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if "\\.+*?()|[]{}^$".contains(ch) {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// The innermost function in `file` whose line span contains `line`, by qualified name — used
+/// to find which function a `.field` access (located via text search, so line-only) falls
+/// inside, so its own entry in `call_graph` can be checked for a same-line call's `receiver_type`.
+fn enclosing_function(file: &FileResult, line: usize) -> Option<String> {
+    file.parsed
+        .complexity
+        .iter()
+        .filter(|func| {
+            let start = func.line;
+            let end = func.line + func.metrics.line_count as usize;
+            line >= start && line < end
+        })
+        .min_by_key(|func| func.metrics.line_count)
+        .map(|func| func.qualified_name())
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct FindCallersParams {
     pub symbol: String,
+    /// Restrict results to callers that resolve `symbol` to this defining file, disambiguating
+    /// same-named symbols the suffix match alone would conflate.
+    #[serde(default)]
+    pub defined_in: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -296,11 +1048,26 @@ pub struct FindDependenciesParams {
     pub direction: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FindCallPathParams {
+    pub from: String,
+    pub to: String,
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+    #[serde(default)]
+    pub direction: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct GetModuleTreeParams {
     pub root: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct FindDeadCodeParams {
+    pub scope: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct GetTypeHierarchyParams {
     pub symbol: String,
@@ -467,6 +1234,12 @@ pub struct SymbolResult {
     pub visibility: String,
 }
 
+#[derive(Debug, Serialize)]
+pub struct FindSymbolResult {
+    pub results: Vec<SymbolResult>,
+    pub suggestions: Vec<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct SearchResult {
     pub symbols: Vec<SymbolResult>,
@@ -492,6 +1265,149 @@ pub struct ImplementationsResult {
     pub type_implements: Vec<String>,
     pub methods: Vec<MethodResult>,
     pub derived_traits: Vec<String>,
+    pub trait_implementors_transitive: Vec<ImplementorEdge>,
+    pub type_implements_transitive: Vec<TraitEdge>,
+}
+
+/// One trait a type implements, at any remove: `depth` 0 is a direct `impl`/`derive`, higher
+/// depths are inherited via a supertrait bound on an already-reached trait.
+#[derive(Debug, Clone, Serialize)]
+pub struct TraitEdge {
+    pub trait_name: String,
+    pub depth: usize,
+    pub via: String,
+}
+
+/// One type that implements a trait, at any remove: `depth` 0 is a direct `impl`, higher depths
+/// reach the trait by implementing a subtrait that names it as a supertrait.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImplementorEdge {
+    pub type_name: String,
+    pub file: String,
+    pub line: usize,
+    pub depth: usize,
+    pub via: String,
+}
+
+/// Maps each trait defined in the workspace to its directly-declared supertraits
+/// (`trait B: A` records `B -> [A]`).
+fn build_supertrait_map(files: &[FileResult]) -> HashMap<String, Vec<String>> {
+    let mut map = HashMap::new();
+    for file in files {
+        for symbol in &file.parsed.symbols.symbols {
+            if let SymbolKind::Trait { supertraits, .. } = &symbol.kind {
+                map.insert(symbol.name.clone(), supertraits.clone());
+            }
+        }
+    }
+    map
+}
+
+/// Inverts a supertrait map into subtrait -> supertrait's-dependents, i.e. `supertrait_name ->
+/// [traits that declare it as a supertrait]`, so implementor closures can walk "downward".
+fn build_subtrait_map(supertrait_map: &HashMap<String, Vec<String>>) -> HashMap<String, Vec<String>> {
+    let mut map: HashMap<String, Vec<String>> = HashMap::new();
+    for (trait_name, supertraits) in supertrait_map {
+        for supertrait in supertraits {
+            map.entry(supertrait.clone())
+                .or_default()
+                .push(trait_name.clone());
+        }
+    }
+    map
+}
+
+/// Walks the transitive set of traits `type_name` implements: direct impls and derives at depth
+/// 0, then each reached trait's supertraits at increasing depth, until a fixed point. A `visited`
+/// set cuts off cycles formed by diamond supertrait bounds.
+fn transitive_traits(
+    type_name: &str,
+    reverse_impl_map: &HashMap<String, Vec<String>>,
+    derive_map: &HashMap<String, Vec<String>>,
+    supertrait_map: &HashMap<String, Vec<String>>,
+) -> Vec<TraitEdge> {
+    let mut edges = Vec::new();
+    let mut visited = HashSet::new();
+
+    let mut frontier: Vec<(String, String)> = Vec::new();
+    for trait_name in reverse_impl_map.get(type_name).into_iter().flatten() {
+        frontier.push((trait_name.clone(), "direct impl".to_string()));
+    }
+    for trait_name in derive_map.get(type_name).into_iter().flatten() {
+        frontier.push((trait_name.clone(), "derive".to_string()));
+    }
+
+    let mut depth = 0;
+    while !frontier.is_empty() {
+        let mut next_frontier = Vec::new();
+        for (trait_name, via) in frontier {
+            if !visited.insert(trait_name.clone()) {
+                continue;
+            }
+            for supertrait in supertrait_map.get(&trait_name).into_iter().flatten() {
+                if !visited.contains(supertrait) {
+                    next_frontier.push((supertrait.clone(), format!("supertrait of {trait_name}")));
+                }
+            }
+            edges.push(TraitEdge {
+                trait_name,
+                depth,
+                via,
+            });
+        }
+        frontier = next_frontier;
+        depth += 1;
+    }
+
+    edges
+}
+
+/// Walks the transitive set of types that implement `trait_name`: direct implementors at depth
+/// 0, then implementors of each subtrait (a trait that names this one as a supertrait) at
+/// increasing depth, since implementing a subtrait satisfies its supertraits too. A `visited`
+/// set over traits cuts off cycles; a `seen` set over types dedupes a type reached two ways.
+fn transitive_implementors(
+    trait_name: &str,
+    impl_map: &HashMap<String, Vec<ImplInfo>>,
+    subtrait_map: &HashMap<String, Vec<String>>,
+) -> Vec<ImplementorEdge> {
+    let mut edges = Vec::new();
+    let mut seen_types = HashSet::new();
+    let mut visited_traits = HashSet::new();
+    let mut frontier = vec![(trait_name.to_string(), "direct impl".to_string())];
+
+    let mut depth = 0;
+    while !frontier.is_empty() {
+        let mut next_frontier = Vec::new();
+        for (current_trait, via) in frontier {
+            if !visited_traits.insert(current_trait.clone()) {
+                continue;
+            }
+            for impl_info in impl_map.get(&current_trait).into_iter().flatten() {
+                if seen_types.insert(impl_info.type_name.clone()) {
+                    edges.push(ImplementorEdge {
+                        type_name: impl_info.type_name.clone(),
+                        file: impl_info.file.clone(),
+                        line: impl_info.line,
+                        depth,
+                        via: via.clone(),
+                    });
+                }
+            }
+            for subtrait in subtrait_map.get(&current_trait).into_iter().flatten() {
+                if !visited_traits.contains(subtrait) {
+                    next_frontier.push((
+                        subtrait.clone(),
+                        format!("implements {subtrait}, a subtrait of {current_trait}"),
+                    ));
+                }
+            }
+        }
+        frontier = next_frontier;
+        depth += 1;
+    }
+
+    edges
 }
 
 #[derive(Debug, Serialize)]
@@ -505,6 +1421,7 @@ pub struct MethodResult {
 #[derive(Debug, Serialize)]
 pub struct CallersResult {
     pub callers: Vec<CallerInfo>,
+    pub suggestions: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -514,6 +1431,35 @@ pub struct DependenciesResult {
     pub references: Vec<ReferenceInfo>,
 }
 
+/// One step on a call chain found by [`find_call_path`](CharterServer::find_call_path).
+#[derive(Debug, Clone, Serialize)]
+pub struct PathNode {
+    pub name: String,
+    pub file: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CallPathResult {
+    pub paths: Vec<Vec<PathNode>>,
+    /// `true` if more shortest paths existed than [`MAX_CALL_PATHS`] allows us to return.
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeadCodeEntry {
+    pub name: String,
+    pub file: String,
+    pub line: usize,
+    pub kind: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DeadCodeResult {
+    pub dead_code: Vec<DeadCodeEntry>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct ReferenceInfo {
     pub file: String,
@@ -538,6 +1484,9 @@ pub struct TypeHierarchyResult {
     pub derived_traits: Vec<String>,
     pub supertraits: Vec<String>,
     pub base_classes: Vec<String>,
+    pub implements_transitive: Vec<TraitEdge>,
+    pub implementors_transitive: Vec<ImplementorEdge>,
+    pub suggestions: Vec<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -579,11 +1528,25 @@ pub struct RescanResult {
     pub old_file_count: usize,
     pub new_file_count: usize,
     pub cache_persisted: bool,
+    pub added_files: Vec<String>,
+    pub changed_files: Vec<String>,
+    pub removed_files: Vec<String>,
+    pub symbol_deltas: Vec<FileSymbolDelta>,
+}
+
+/// Symbol-count change for one added, modified, or removed file, reported by
+/// [`CharterServer::rescan`].
+#[derive(Debug, Clone, Serialize)]
+pub struct FileSymbolDelta {
+    pub file: String,
+    pub old_symbol_count: usize,
+    pub new_symbol_count: usize,
 }
 
 #[derive(Debug, Serialize)]
 pub struct SnippetResult {
     pub snippets: Vec<SnippetInfo>,
+    pub suggestions: Vec<String>,
 }
 
 #[tool_router]
@@ -592,6 +1555,7 @@ impl CharterServer {
         Self {
             index,
             root,
+            file_cache: crate::filecache::FileContentCache::default(),
             tool_router: Self::tool_router(),
         }
     }
@@ -607,31 +1571,22 @@ impl CharterServer {
         let query = params.0.query.to_lowercase();
         let limit = params.0.limit.unwrap_or(50);
 
-        let mut symbols = Vec::new();
+        let symbols: Vec<SymbolResult> = index
+            .search_symbols_ranked(&query, params.0.kind.as_deref(), limit)
+            .into_iter()
+            .map(|(name, sym)| SymbolResult {
+                name: name.to_string(),
+                kind: sym.kind.clone(),
+                file: sym.file.clone(),
+                line: sym.line,
+                signature: sym.signature.clone(),
+                visibility: sym.visibility.clone(),
+            })
+            .collect();
+
         let mut traits = Vec::new();
         let mut calls = Vec::new();
 
-        for (name, syms) in &index.symbols_by_name {
-            let name_lower = name.to_lowercase();
-            if name_lower.contains(&query) || fuzzy_match(&query, &name_lower) {
-                for sym in syms {
-                    if let Some(ref kind_filter) = params.0.kind {
-                        if &sym.kind != kind_filter {
-                            continue;
-                        }
-                    }
-                    symbols.push(SymbolResult {
-                        name: name.clone(),
-                        kind: sym.kind.clone(),
-                        file: sym.file.clone(),
-                        line: sym.line,
-                        signature: sym.signature.clone(),
-                        visibility: sym.visibility.clone(),
-                    });
-                }
-            }
-        }
-
         for (trait_name, impls) in &index.impl_map {
             let trait_lower = trait_name.to_lowercase();
             if trait_lower.contains(&query) {
@@ -652,7 +1607,6 @@ impl CharterServer {
             }
         }
 
-        symbols.truncate(limit);
         traits.truncate(limit / 2);
         calls.truncate(limit / 2);
 
@@ -666,14 +1620,20 @@ impl CharterServer {
     }
 
     #[tool(
-        description = "Find a symbol by exact name or fuzzy match. Filter by kind (struct, enum, trait, function, method, etc.)"
+        description = "Find a symbol by exact name or fuzzy match. Filter by kind (struct, enum, trait, function, method, etc.). Returns `did you mean` suggestions when nothing matches."
     )]
     async fn find_symbol(&self, params: Parameters<FindSymbolParams>) -> Result<String, McpError> {
         let index = self.index.read().await;
         let mut results = Vec::new();
-        let query_lower = params.0.name.to_lowercase();
+        let mut seen_names: HashSet<String> = HashSet::new();
 
-        if let Some(symbols) = index.symbols_by_name.get(&params.0.name) {
+        let mut push_name = |qualified_name: &str, results: &mut Vec<SymbolResult>| {
+            if !seen_names.insert(qualified_name.to_string()) {
+                return;
+            }
+            let Some(symbols) = index.symbols_by_name.get(qualified_name) else {
+                return;
+            };
             for sym in symbols {
                 if let Some(ref kind_filter) = params.0.kind {
                     if &sym.kind != kind_filter {
@@ -681,7 +1641,7 @@ impl CharterServer {
                     }
                 }
                 results.push(SymbolResult {
-                    name: sym.name.clone(),
+                    name: qualified_name.to_string(),
                     kind: sym.kind.clone(),
                     file: sym.file.clone(),
                     line: sym.line,
@@ -689,57 +1649,108 @@ impl CharterServer {
                     visibility: sym.visibility.clone(),
                 });
             }
+        };
+
+        push_name(&params.0.name, &mut results);
+
+        // Suffix matches run unconditionally, not just when the FST fails to build: the FST is
+        // keyed on leaf segments only, so a multi-segment query like "Type::method" can't hit
+        // anything in it even though it's exactly what suffix-matching is for.
+        let suffix_hits: Vec<String> = index
+            .symbols_by_name
+            .keys()
+            .filter(|name| name.ends_with(&format!("::{}", params.0.name)))
+            .cloned()
+            .collect();
+        for name in suffix_hits {
+            push_name(&name, &mut results);
         }
 
-        for (qualified_name, symbols) in &index.symbols_by_name {
-            if qualified_name.ends_with(&format!("::{}", params.0.name)) {
-                for sym in symbols {
-                    if let Some(ref kind_filter) = params.0.kind {
-                        if &sym.kind != kind_filter {
-                            continue;
-                        }
-                    }
-                    results.push(SymbolResult {
-                        name: qualified_name.clone(),
-                        kind: sym.kind.clone(),
-                        file: sym.file.clone(),
-                        line: sym.line,
-                        signature: sym.signature.clone(),
-                        visibility: sym.visibility.clone(),
-                    });
+        match &index.symbol_fst {
+            Some(fst) => {
+                for qualified_name in fst.candidates(&params.0.name) {
+                    push_name(qualified_name, &mut results);
                 }
             }
-        }
-
-        if results.is_empty() {
-            for (name, symbols) in &index.symbols_by_name {
-                let name_lower = name.to_lowercase();
-                if name_lower.contains(&query_lower) || fuzzy_match(&query_lower, &name_lower) {
-                    for sym in symbols {
-                        if let Some(ref kind_filter) = params.0.kind {
-                            if &sym.kind != kind_filter {
-                                continue;
-                            }
-                        }
-                        results.push(SymbolResult {
-                            name: name.clone(),
-                            kind: sym.kind.clone(),
-                            file: sym.file.clone(),
-                            line: sym.line,
-                            signature: sym.signature.clone(),
-                            visibility: sym.visibility.clone(),
-                        });
+            None => {
+                // FST build failed — fall back to the original fuzzy linear scan.
+                if results.is_empty() {
+                    let query_lower = params.0.name.to_lowercase();
+                    let fuzzy_hits: Vec<String> = index
+                        .symbols_by_name
+                        .keys()
+                        .filter(|name| {
+                            let name_lower = name.to_lowercase();
+                            name_lower.contains(&query_lower)
+                                || fuzzy_match(&query_lower, &name_lower).is_some()
+                        })
+                        .cloned()
+                        .collect();
+                    for name in fuzzy_hits {
+                        push_name(&name, &mut results);
                     }
                 }
             }
-            results.truncate(20);
         }
 
-        Ok(serde_json::to_string_pretty(&results).unwrap_or_else(|_| "[]".to_string()))
+        let suggestions = if results.is_empty() {
+            suggest_similar_names(index.symbols_by_name.keys().map(String::as_str), &params.0.name)
+        } else {
+            Vec::new()
+        };
+
+        results.truncate(20);
+
+        let result = FindSymbolResult {
+            results,
+            suggestions,
+        };
+
+        Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
+    }
+
+    #[tool(
+        description = "Resolve a fully-qualified path (e.g. crate::net::Server::connect) to its definition by walking the module tree and following use/re-export chains, instead of find_symbol's suffix-matching. Optionally scope the walk to a starting module with `root`."
+    )]
+    async fn resolve_path(
+        &self,
+        params: Parameters<ResolvePathParams>,
+    ) -> Result<String, McpError> {
+        let index = self.index.read().await;
+        let resolution = resolve::resolve_path(&index.cache, &params.0.path, params.0.root.as_deref());
+
+        let result = match resolution {
+            resolve::PathResolution::Resolved {
+                file,
+                symbol,
+                member,
+                kind,
+                trail,
+            } => ResolvePathResult {
+                resolved: true,
+                file: Some(file),
+                symbol: Some(symbol),
+                member,
+                kind: Some(path_resolved_kind_label(kind).to_string()),
+                trail,
+                unresolved_at_segment: None,
+            },
+            resolve::PathResolution::Unresolved { segment_index, trail } => ResolvePathResult {
+                resolved: false,
+                file: None,
+                symbol: None,
+                member: None,
+                kind: None,
+                trail,
+                unresolved_at_segment: Some(segment_index),
+            },
+        };
+
+        Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
     }
 
     #[tool(
-        description = "Find implementations of a trait or methods on a type. Includes derive-generated impls."
+        description = "Find implementations of a trait or methods on a type. Includes derive-generated impls, plus the transitive closure over supertrait bounds (depth-tagged, with the edge each entry was reached through)."
     )]
     async fn find_implementations(
         &self,
@@ -781,18 +1792,177 @@ impl CharterServer {
             }
         }
 
+        let supertrait_map = build_supertrait_map(&index.result.files);
+        let subtrait_map = build_subtrait_map(&supertrait_map);
+        let trait_implementors_transitive =
+            transitive_implementors(&params.0.symbol, &index.impl_map, &subtrait_map);
+        let type_implements_transitive = transitive_traits(
+            &params.0.symbol,
+            &index.reverse_impl_map,
+            &index.derive_map,
+            &supertrait_map,
+        );
+
         let result = ImplementationsResult {
             trait_implementors,
             type_implements,
             methods,
             derived_traits,
+            trait_implementors_transitive,
+            type_implements_transitive,
+        };
+
+        Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
+    }
+
+    #[tool(
+        description = "Get the fields of a struct or the variants of an enum. Returns each field/variant's declared type or payload, visibility, file, and line."
+    )]
+    async fn get_fields(&self, params: Parameters<GetFieldsParams>) -> Result<String, McpError> {
+        let index = self.index.read().await;
+
+        let result = FieldsResult {
+            fields: index
+                .fields_by_type
+                .get(&params.0.symbol)
+                .cloned()
+                .unwrap_or_default(),
+            variants: index
+                .variants_by_type
+                .get(&params.0.symbol)
+                .cloned()
+                .unwrap_or_default(),
+        };
+
+        Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
+    }
+
+    #[tool(
+        description = "Suggest the shortest `use` path that brings a symbol into scope from a given module, honoring visibility and pub use re-exports. Returns the best `use` statement plus ranked alternatives, or reports the symbol as not found / unreachable."
+    )]
+    async fn suggest_import(
+        &self,
+        params: Parameters<SuggestImportParams>,
+    ) -> Result<String, McpError> {
+        let index = self.index.read().await;
+        let from_module = params.0.from_module.as_deref().unwrap_or("");
+
+        let suggestion =
+            resolve::suggest_import(&index.cache, &params.0.symbol, from_module);
+
+        let result = match suggestion {
+            resolve::ImportSuggestion::Found(candidates) => {
+                let alternatives: Vec<ImportCandidateInfo> = candidates
+                    .into_iter()
+                    .map(|candidate| ImportCandidateInfo {
+                        path: candidate.path,
+                        use_statement: candidate.use_statement,
+                        via_reexport: candidate.via_reexport,
+                    })
+                    .collect();
+                SuggestImportResult {
+                    found: true,
+                    reason: None,
+                    suggestion: alternatives.first().map(|c| c.use_statement.clone()),
+                    alternatives,
+                }
+            }
+            resolve::ImportSuggestion::Unreachable => SuggestImportResult {
+                found: false,
+                reason: Some(format!(
+                    "`{}` is defined in this crate but private to a module `{}` can't see",
+                    params.0.symbol, from_module
+                )),
+                suggestion: None,
+                alternatives: Vec::new(),
+            },
+            resolve::ImportSuggestion::NotFound => SuggestImportResult {
+                found: false,
+                reason: Some(format!("no symbol named `{}` is defined in this crate", params.0.symbol)),
+                suggestion: None,
+                alternatives: Vec::new(),
+            },
+        };
+
+        Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
+    }
+
+    #[tool(
+        description = "Locate `.field` reads/writes across the workspace via regex text search, correlating each hit to a receiver type from the call graph when the same line also records a call with a known receiver_type. Optionally filter to accesses on a specific `type_name`."
+    )]
+    async fn find_field_accesses(
+        &self,
+        params: Parameters<FindFieldAccessesParams>,
+    ) -> Result<String, McpError> {
+        let index = self.index.read().await;
+        let max_results = params.0.max_results.unwrap_or(100);
+
+        let pattern = format!(r"\.{}\b", escape_regex(&params.0.field));
+        let matcher = match RegexMatcherBuilder::new().build(&pattern) {
+            Ok(m) => m,
+            Err(error) => {
+                return Ok(format!(
+                    "{{\"error\": \"Invalid field name: {}\"}}",
+                    error
+                ));
+            }
         };
 
+        let mut searcher = SearcherBuilder::new().line_number(true).build();
+
+        let mut matches = Vec::new();
+        let mut truncated = false;
+
+        for file in &index.result.files {
+            let remaining = max_results.saturating_sub(matches.len());
+            if remaining == 0 {
+                truncated = true;
+                break;
+            }
+
+            let mut sink = TextSearchSink::new(file.relative_path.clone(), remaining);
+            if searcher.search_path(&matcher, &file.path, &mut sink).is_err() {
+                continue;
+            }
+
+            for text_match in sink.matches {
+                let receiver_type = enclosing_function(file, text_match.line)
+                    .and_then(|caller| index.call_graph.get(&caller))
+                    .and_then(|targets| {
+                        targets
+                            .iter()
+                            .find(|target| target.line == text_match.line)
+                    })
+                    .and_then(|target| target.receiver_type.clone());
+
+                if let Some(ref type_name) = params.0.type_name {
+                    if receiver_type.as_deref() != Some(type_name.as_str()) {
+                        continue;
+                    }
+                }
+
+                matches.push(FieldAccessMatch {
+                    file: text_match.file,
+                    line: text_match.line,
+                    text: text_match.text,
+                    receiver_type,
+                });
+            }
+
+            if matches.len() >= max_results {
+                matches.truncate(max_results);
+                truncated = true;
+                break;
+            }
+        }
+
+        let result = FieldAccessResult { matches, truncated };
+
         Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
     }
 
     #[tool(
-        description = "Find all call sites of a function or method. Returns caller name, file, and line."
+        description = "Find all call sites of a function or method. Returns caller name, file, and line, along with resolved_file/ambiguous metadata for bare calls (same-module/import/glob-import resolution, racer-nameres style). Pass defined_in (a file path) to keep only callers that genuinely resolve the symbol to that file, filtering out the false positives a bare suffix match would otherwise include for same-named symbols."
     )]
     async fn find_callers(
         &self,
@@ -813,7 +1983,20 @@ impl CharterServer {
             }
         }
 
-        let result = CallersResult { callers };
+        if let Some(defined_in) = params.0.defined_in.as_deref() {
+            callers.retain(|caller| caller.resolved_file.as_deref() == Some(defined_in));
+        }
+
+        let suggestions = if callers.is_empty() {
+            suggest_similar_names(index.reverse_calls.keys().map(String::as_str), &params.0.symbol)
+        } else {
+            Vec::new()
+        };
+
+        let result = CallersResult {
+            callers,
+            suggestions,
+        };
 
         Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
     }
@@ -871,6 +2054,111 @@ impl CharterServer {
         Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
     }
 
+    #[tool(
+        description = "Find call chains connecting two symbols via bounded BFS over the call graph, for questions find_dependencies can't express (e.g. \"how does `serve` end up calling `walk_directory`?\"). `direction` \"forward\" (default) follows callees from `from` toward `to`; \"backward\" follows callers. `max_depth` bounds hops (default 8). Returns up to 5 distinct shortest paths, each a sequence of name/file/line nodes."
+    )]
+    async fn find_call_path(
+        &self,
+        params: Parameters<FindCallPathParams>,
+    ) -> Result<String, McpError> {
+        let index = self.index.read().await;
+        let forward = params.0.direction.as_deref() != Some("backward");
+        let max_depth = params.0.max_depth.unwrap_or(8);
+
+        let (start_map, target_map): (&HashMap<String, Vec<CallTarget>>, &HashMap<String, Vec<CallerInfo>>) =
+            (&index.call_graph, &index.reverse_calls);
+        let from_names = if forward {
+            resolve_qualified_names(start_map, &params.0.from)
+        } else {
+            resolve_qualified_names(target_map, &params.0.from)
+        };
+        let to_names: HashSet<String> = if forward {
+            resolve_qualified_names(target_map, &params.0.to)
+        } else {
+            resolve_qualified_names(start_map, &params.0.to)
+        }
+        .into_iter()
+        .collect();
+
+        let mut paths = Vec::new();
+        if !to_names.is_empty() {
+            for start in &from_names {
+                if let Some(path) = shortest_call_path(
+                    start,
+                    &to_names,
+                    &index.call_graph,
+                    &index.reverse_calls,
+                    &index.symbols_by_name,
+                    forward,
+                    max_depth,
+                ) {
+                    paths.push(path);
+                }
+            }
+        }
+
+        paths.sort_by_key(|path| path.len());
+        let truncated = paths.len() > MAX_CALL_PATHS;
+        paths.truncate(MAX_CALL_PATHS);
+
+        let result = CallPathResult { paths, truncated };
+
+        Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
+    }
+
+    #[tool(
+        description = "Find private functions/methods with no callers and no references anywhere in the index — an unused-code diagnostic. Flags only Visibility::Private items so public API surface is never falsely reported. Optionally scope to a path prefix."
+    )]
+    async fn find_dead_code(
+        &self,
+        params: Parameters<FindDeadCodeParams>,
+    ) -> Result<String, McpError> {
+        let index = self.index.read().await;
+        let scope = params.0.scope.as_deref().unwrap_or("");
+
+        let mut dead_code = Vec::new();
+        for (qualified_name, infos) in &index.symbols_by_name {
+            for info in infos {
+                if info.kind != "function" && info.kind != "method" {
+                    continue;
+                }
+                if !info.visibility.is_empty() {
+                    continue;
+                }
+                if !info.file.starts_with(scope) {
+                    continue;
+                }
+
+                let has_callers = index
+                    .reverse_calls
+                    .get(qualified_name)
+                    .is_some_and(|callers| !callers.is_empty());
+                let has_references = index
+                    .references
+                    .get(qualified_name)
+                    .is_some_and(|refs| !refs.is_empty());
+
+                if has_callers || has_references {
+                    continue;
+                }
+
+                dead_code.push(DeadCodeEntry {
+                    name: qualified_name.clone(),
+                    file: info.file.clone(),
+                    line: info.line,
+                    kind: info.kind.clone(),
+                    reason: "private, no callers in call_graph and no references".to_string(),
+                });
+            }
+        }
+
+        dead_code.sort_by(|a, b| a.file.cmp(&b.file).then_with(|| a.line.cmp(&b.line)));
+
+        let result = DeadCodeResult { dead_code };
+
+        Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
+    }
+
     #[tool(
         description = "Get the module tree structure of the codebase. Returns file paths with symbol counts."
     )]
@@ -900,7 +2188,7 @@ impl CharterServer {
     }
 
     #[tool(
-        description = "Get the type hierarchy for a symbol (traits it implements, derive-generated impls, types that implement it, supertraits)"
+        description = "Get the type hierarchy for a symbol (traits it implements, derive-generated impls, types that implement it, supertraits), plus the transitive closure of both directions over supertrait bounds (depth-tagged, with the edge each entry was reached through)."
     )]
     async fn get_type_hierarchy(
         &self,
@@ -945,12 +2233,39 @@ impl CharterServer {
             }
         }
 
+        let supertrait_map = build_supertrait_map(&index.result.files);
+        let subtrait_map = build_subtrait_map(&supertrait_map);
+        let implementors_transitive =
+            transitive_implementors(&params.0.symbol, &index.impl_map, &subtrait_map);
+        let implements_transitive = transitive_traits(
+            &params.0.symbol,
+            &index.reverse_impl_map,
+            &index.derive_map,
+            &supertrait_map,
+        );
+
+        let nothing_found = implementors.is_empty()
+            && implements.is_empty()
+            && derived_traits.is_empty()
+            && supertraits.is_empty()
+            && base_classes.is_empty()
+            && implements_transitive.is_empty()
+            && implementors_transitive.is_empty();
+        let suggestions = if nothing_found {
+            suggest_similar_names(index.symbols_by_name.keys().map(String::as_str), &params.0.symbol)
+        } else {
+            Vec::new()
+        };
+
         let result = TypeHierarchyResult {
             implementors,
             implements,
             derived_traits,
             supertraits,
             base_classes,
+            implements_transitive,
+            implementors_transitive,
+            suggestions,
         };
 
         Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
@@ -1053,7 +2368,7 @@ impl CharterServer {
     }
 
     #[tool(
-        description = "Re-scan the codebase and return a summary of changes. Persists cache to disk."
+        description = "Re-scan the codebase, reparsing only added/modified files and patching the index in place rather than rebuilding it. Returns added/changed/removed file lists and per-file symbol-count deltas. Persists cache to disk."
     )]
     async fn rescan(&self) -> Result<String, McpError> {
         let root = self.root.clone();
@@ -1081,9 +2396,15 @@ impl CharterServer {
             }
         };
 
-        let old_file_count = {
+        let (old_file_count, old_symbol_counts) = {
             let index = self.index.read().await;
-            index.result.files.len()
+            let counts: HashMap<String, usize> = index
+                .result
+                .files
+                .iter()
+                .map(|f| (f.relative_path.clone(), f.parsed.symbols.symbols.len()))
+                .collect();
+            (index.result.files.len(), counts)
         };
 
         let result = match pipeline::run_phase1_with_walk(
@@ -1092,6 +2413,7 @@ impl CharterServer {
             &cache,
             None,
             walk_result,
+            None,
         )
         .await
         {
@@ -1106,18 +2428,69 @@ impl CharterServer {
 
         let new_file_count = result.files.len();
 
-        let new_cache = build_cache(&result.files);
+        let new_cache = crate::pipeline::build_cache(&result.files);
         let cache_persisted = new_cache.save(&cache_path).await.is_ok();
 
-        let new_index = Index::new(result, symbol_table, references);
+        // Diff the *loaded* cache (the state the in-memory index was last built from) against the
+        // fresh walk, by hash, to classify every file as added/modified/removed/unchanged.
+        let new_paths: HashSet<String> =
+            result.files.iter().map(|f| f.relative_path.clone()).collect();
+        let mut added_files: Vec<String> = Vec::new();
+        let mut changed_files: Vec<String> = Vec::new();
+        for file in &result.files {
+            if file.from_cache {
+                continue;
+            }
+            if cache.entries.contains_key(&file.relative_path) {
+                changed_files.push(file.relative_path.clone());
+            } else {
+                added_files.push(file.relative_path.clone());
+            }
+        }
+        let mut removed_files: Vec<String> = cache
+            .entries
+            .keys()
+            .filter(|path| !new_paths.contains(*path))
+            .cloned()
+            .collect();
+        added_files.sort();
+        changed_files.sort();
+        removed_files.sort();
+
+        let mut symbol_deltas: Vec<FileSymbolDelta> = added_files
+            .iter()
+            .chain(&changed_files)
+            .chain(&removed_files)
+            .map(|file| FileSymbolDelta {
+                file: file.clone(),
+                old_symbol_count: old_symbol_counts.get(file).copied().unwrap_or(0),
+                new_symbol_count: result
+                    .files
+                    .iter()
+                    .find(|f| &f.relative_path == file)
+                    .map(|f| f.parsed.symbols.symbols.len())
+                    .unwrap_or(0),
+            })
+            .collect();
+        symbol_deltas.sort_by(|a, b| a.file.cmp(&b.file));
+
+        let stale_files: HashSet<String> = changed_files
+            .iter()
+            .chain(&removed_files)
+            .cloned()
+            .collect();
 
         let mut index = self.index.write().await;
-        *index = new_index;
+        index.apply_rescan(result, symbol_table, references, &stale_files);
 
         let result = RescanResult {
             old_file_count,
             new_file_count,
             cache_persisted,
+            added_files,
+            changed_files,
+            removed_files,
+            symbol_deltas,
         };
 
         Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
@@ -1130,31 +2503,68 @@ impl CharterServer {
         let index = self.index.read().await;
         let mut snippets = Vec::new();
         let query = &params.0.name;
-        let query_lower = query.to_lowercase();
-
-        if let Some(snips) = index.snippets_by_name.get(query) {
-            snippets.extend(snips.clone());
-        }
+        let mut seen_names: HashSet<&str> = HashSet::new();
 
-        for (name, snips) in &index.snippets_by_name {
-            if name.ends_with(&format!("::{}", query)) && name != query {
+        let mut push_name = |name: &str, snippets: &mut Vec<SnippetInfo>| {
+            if !seen_names.insert(name) {
+                return;
+            }
+            if let Some(snips) = index.snippets_by_name.get(name) {
                 snippets.extend(snips.clone());
             }
+        };
+
+        push_name(query, &mut snippets);
+
+        // Suffix matches run unconditionally, not just when the FST fails to build: the FST is
+        // keyed on leaf segments only, so a multi-segment query like "Type::method" can't hit
+        // anything in it even though it's exactly what suffix-matching is for.
+        let suffix_hits: Vec<&str> = index
+            .snippets_by_name
+            .keys()
+            .filter(|name| name.ends_with(&format!("::{}", query)) && *name != query)
+            .map(String::as_str)
+            .collect();
+        for name in suffix_hits {
+            push_name(name, &mut snippets);
         }
 
-        if snippets.is_empty() {
-            for (name, snips) in &index.snippets_by_name {
-                let name_lower = name.to_lowercase();
-                if name_lower.contains(&query_lower) {
-                    snippets.extend(snips.clone());
+        match &index.symbol_fst {
+            Some(fst) => {
+                for name in fst.candidates(query) {
+                    push_name(name, &mut snippets);
+                }
+            }
+            None => {
+                // FST build failed — fall back to the original fuzzy linear scan.
+                if snippets.is_empty() {
+                    let query_lower = query.to_lowercase();
+                    let fuzzy_hits: Vec<&str> = index
+                        .snippets_by_name
+                        .keys()
+                        .filter(|name| name.to_lowercase().contains(&query_lower))
+                        .map(String::as_str)
+                        .collect();
+                    for name in fuzzy_hits {
+                        push_name(name, &mut snippets);
+                    }
+                    snippets.truncate(10);
                 }
             }
-            snippets.truncate(10);
         }
 
         snippets.sort_by(|a, b| b.importance_score.cmp(&a.importance_score));
 
-        let result = SnippetResult { snippets };
+        let suggestions = if snippets.is_empty() {
+            suggest_similar_names(index.snippets_by_name.keys().map(String::as_str), query)
+        } else {
+            Vec::new()
+        };
+
+        let result = SnippetResult {
+            snippets,
+            suggestions,
+        };
 
         Ok(serde_json::to_string_pretty(&result).unwrap_or_else(|_| "{}".to_string()))
     }
@@ -1181,8 +2591,11 @@ impl CharterServer {
             }
         };
 
-        let file_path = &file_result.path;
-        let content = match tokio::fs::read_to_string(file_path).await {
+        let content = match self
+            .file_cache
+            .read_to_string(&file_result.path, &file_result.hash)
+            .await
+        {
             Ok(c) => c,
             Err(e) => {
                 return Ok(format!("{{\"error\": \"Failed to read file: {}\"}}", e));
@@ -1298,33 +2711,6 @@ impl CharterServer {
     }
 }
 
-fn build_cache(files: &[FileResult]) -> Cache {
-    let mut cache = Cache::default();
-
-    for file in files {
-        let mtime = std::fs::metadata(&file.path)
-            .and_then(|m| m.modified())
-            .ok()
-            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
-            .map(|d| d.as_secs())
-            .unwrap_or(0);
-
-        cache.entries.insert(
-            file.relative_path.clone(),
-            crate::cache::CacheEntry {
-                hash: file.hash.clone(),
-                mtime,
-                size: file.size,
-                lines: file.lines,
-                data: crate::cache::FileData {
-                    parsed: file.parsed.clone(),
-                },
-            },
-        );
-    }
-
-    cache
-}
 
 fn matches_glob(path: &str, glob: &str) -> bool {
     if let Some(suffix) = glob.strip_prefix('*') {
@@ -1334,24 +2720,51 @@ fn matches_glob(path: &str, glob: &str) -> bool {
     }
 }
 
-fn fuzzy_match(query: &str, target: &str) -> bool {
-    if query.is_empty() {
-        return true;
+/// Ranks a [`fuzzy_match`] result best-to-worst by declaration order, so callers that only
+/// need a yes/no can keep using `.is_some()` while a future ranked consumer can sort on the
+/// tier directly instead of collapsing everything into one float.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum FuzzyTier {
+    Exact,
+    Prefix,
+    Typo1,
+    Typo2,
+}
+
+/// Edits tolerated for a `query` of `len` characters before a candidate stops counting as a
+/// typo of it — zero slack for short identifiers like `fs` (where a single edit already means
+/// a different word), rising to two for long ones (where a single typo shouldn't sink the
+/// match).
+fn typo_budget(len: usize) -> usize {
+    match len {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    }
+}
+
+/// Scores `query` against `target`, returning the best [`FuzzyTier`] it reaches or `None` if
+/// `target` is neither an exact/prefix match nor within `query`'s length-tiered edit-distance
+/// budget (see [`typo_budget`]).
+fn fuzzy_match(query: &str, target: &str) -> Option<FuzzyTier> {
+    if query.is_empty() || query == target {
+        return Some(FuzzyTier::Exact);
     }
 
-    let mut query_chars = query.chars().peekable();
-    for target_char in target.chars() {
-        if let Some(&query_char) = query_chars.peek() {
-            if query_char == target_char {
-                query_chars.next();
-            }
-        }
-        if query_chars.peek().is_none() {
-            return true;
-        }
+    if target.starts_with(query) {
+        return Some(FuzzyTier::Prefix);
+    }
+
+    let budget = typo_budget(query.len());
+    if budget == 0 {
+        return None;
     }
 
-    query_chars.peek().is_none()
+    match crate::output::levenshtein_distance(query, target) {
+        1 => Some(FuzzyTier::Typo1),
+        2 if budget >= 2 => Some(FuzzyTier::Typo2),
+        _ => None,
+    }
 }
 
 #[tool_handler]
@@ -1362,7 +2775,7 @@ impl ServerHandler for CharterServer {
             capabilities: ServerCapabilities::builder().enable_tools().build(),
             server_info: Implementation::from_build_env(),
             instructions: Some(
-                "Charter codebase structural analysis server. Tools: search_symbols (fuzzy search), find_symbol (exact/fuzzy lookup), find_implementations (includes derives), find_callers (with receiver type), find_dependencies (with receiver type), get_module_tree, get_type_hierarchy (includes derives), summarize, get_snippet (captured function bodies), read_source (any source range), search_text (regex text search with glob filtering and context lines), rescan. All return JSON.".to_string(),
+                "Charter codebase structural analysis server. Tools: search_symbols (fuzzy search), find_symbol (exact/fuzzy lookup), resolve_path (fully-qualified path to definition), find_implementations (includes derives and the transitive supertrait closure), find_callers (with receiver type), find_dependencies (with receiver type), find_call_path (multi-hop call chains between two symbols), find_dead_code (private functions/methods with no callers or references), get_module_tree, get_type_hierarchy (includes derives and the transitive supertrait closure), get_fields (struct fields/enum variants), find_field_accesses (`.field` reads/writes correlated to a receiver type), suggest_import (shortest reachable `use` path for a symbol), summarize, get_snippet (captured function bodies), read_source (any source range), search_text (regex text search with glob filtering and context lines), rescan. All return JSON.".to_string(),
             ),
         }
     }
@@ -1377,7 +2790,7 @@ pub async fn serve(root: &Path) -> Result<()> {
     let walk_result = walk::walk_directory(root).await?;
 
     let result =
-        pipeline::run_phase1_with_walk(root, &workspace, &cache, None, walk_result).await?;
+        pipeline::run_phase1_with_walk(root, &workspace, &cache, None, walk_result, None).await?;
 
     let symbol_table = pipeline::build_symbol_table(&result.files);
     let references = pipeline::run_phase2(&result.files, &symbol_table);
@@ -1391,3 +2804,133 @@ pub async fn serve(root: &Path) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::{ProjectKind, WorkspaceInfo};
+    use crate::git::GitStatus;
+
+    fn fake_file(relative_path: &str, source: &str) -> FileResult {
+        let parsed = pipeline::parse_rust_file(source, relative_path).expect("parse fixture");
+        FileResult {
+            path: PathBuf::from(relative_path),
+            relative_path: relative_path.to_string(),
+            hash: blake3::hash(source.as_bytes()).to_hex().to_string(),
+            size: source.len() as u64,
+            lines: source.lines().count(),
+            parsed,
+            from_cache: false,
+            git_status: GitStatus::default(),
+            last_commit_timestamp: 0,
+            distinct_authors: 0,
+        }
+    }
+
+    fn fake_result(files: Vec<FileResult>) -> PipelineResult {
+        PipelineResult {
+            total_lines: files.iter().map(|f| f.lines).sum(),
+            files,
+            workspace: WorkspaceInfo {
+                root: PathBuf::from("."),
+                members: Vec::new(),
+                python_packages: Vec::new(),
+                is_workspace: false,
+                project_kind: ProjectKind::Rust,
+            },
+            git_info: None,
+            skipped: Vec::new(),
+            diff_summary: None,
+        }
+    }
+
+    /// A rescan that patches only the changed file must land `symbols_by_name` (and the other
+    /// per-file-tagged maps) in exactly the order a from-scratch [`Index::new`] on the same files
+    /// would produce, not merely the same set of entries. Regression test for a bug where
+    /// [`Index::apply_rescan`] appended a patched file's fresh entries to the end of each shared
+    /// key's `Vec` instead of re-sorting by file, leaving entries out of order whenever the
+    /// changed file didn't sort last among that key's contributors.
+    #[test]
+    fn apply_rescan_matches_fresh_build_for_shared_symbol_order() {
+        let unchanged = "pub fn shared() {}\n";
+        let changed = "pub fn shared() { let _ = 1; }\n";
+
+        let mut index = Index::new(
+            fake_result(vec![
+                fake_file("a.rs", unchanged),
+                fake_file("b.rs", unchanged),
+                fake_file("c.rs", unchanged),
+            ]),
+            HashMap::new(),
+            HashMap::new(),
+        );
+
+        let mut a_cached = fake_file("a.rs", unchanged);
+        a_cached.from_cache = true;
+        let mut c_cached = fake_file("c.rs", unchanged);
+        c_cached.from_cache = true;
+
+        let mut stale = HashSet::new();
+        stale.insert("b.rs".to_string());
+
+        index.apply_rescan(
+            fake_result(vec![a_cached, fake_file("b.rs", changed), c_cached]),
+            HashMap::new(),
+            HashMap::new(),
+            &stale,
+        );
+
+        let fresh = Index::new(
+            fake_result(vec![
+                fake_file("a.rs", unchanged),
+                fake_file("b.rs", changed),
+                fake_file("c.rs", unchanged),
+            ]),
+            HashMap::new(),
+            HashMap::new(),
+        );
+
+        let files_for = |idx: &Index| -> Vec<String> {
+            idx.symbols_by_name
+                .get("shared")
+                .map(|infos| infos.iter().map(|info| info.file.clone()).collect())
+                .unwrap_or_default()
+        };
+
+        assert_eq!(files_for(&index), files_for(&fresh));
+        assert_eq!(files_for(&index), vec!["a.rs", "b.rs", "c.rs"]);
+    }
+
+    /// A one-character typo still finds the symbol, ranked above a merely-related exact
+    /// substring match on a different name — regression coverage for the inverted-index search
+    /// `search_symbols_ranked` replaced the old linear `contains`/`fuzzy_match` scan with.
+    #[test]
+    fn search_symbols_ranked_tolerates_a_single_typo() {
+        let index = Index::new(
+            fake_result(vec![
+                fake_file("lib.rs", "pub fn parse_query() {}\npub fn other() {}\n"),
+            ]),
+            HashMap::new(),
+            HashMap::new(),
+        );
+
+        let results = index.search_symbols_ranked("parse_quary", None, 10);
+
+        assert!(results.iter().any(|(name, _)| *name == "parse_query"));
+    }
+
+    /// A query with no typo-tolerant match anywhere in the index returns nothing, rather than
+    /// falling back to an unrelated symbol.
+    #[test]
+    fn search_symbols_ranked_finds_nothing_for_an_unrelated_query() {
+        let index = Index::new(
+            fake_result(vec![fake_file("lib.rs", "pub fn shared() {}\n")]),
+            HashMap::new(),
+            HashMap::new(),
+        );
+
+        let results = index.search_symbols_ranked("zzzzzzzzzz", None, 10);
+
+        assert!(results.is_empty());
+    }
+}
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use tokio::fs;
 
@@ -55,6 +55,20 @@ fn generate_session_id() -> String {
     format!("session-{}", now.format("%Y%m%d-%H%M%S"))
 }
 
+/// Merges paths changed since `session.initial_commit` (working tree vs. that commit, plus
+/// untracked files — see [`crate::git::changed_paths_since`]) into `session.modified_files`, so
+/// the reported changeset is accurate even when the caller never invoked
+/// [`track_modified_file`]. A no-op for sessions with no `initial_commit` or for non-git
+/// directories, where [`crate::git::changed_paths_since`] returns `None` and the manually
+/// tracked set is left untouched.
+async fn reconcile_modified_files(root: &Path, session: &mut Session) {
+    if let Some(paths) =
+        crate::git::changed_paths_since(root, session.initial_commit.as_deref()).await
+    {
+        session.modified_files.extend(paths);
+    }
+}
+
 pub async fn start_session(root: &Path) -> Result<()> {
     let charter_dir = root.join(".charter");
 
@@ -128,6 +142,8 @@ pub async fn end_session(root: &Path) -> Result<()> {
 
     session.ended_at = Some(Utc::now());
 
+    reconcile_modified_files(root, &mut session).await;
+
     let final_commit = crate::git::get_git_info(root).await.ok();
 
     println!("Session ended: {}", session.id);
@@ -221,7 +237,9 @@ pub async fn session_status(root: &Path) -> Result<()> {
     }
 
     let content = fs::read_to_string(&session_path).await?;
-    let session: Session = serde_json::from_str(&content)?;
+    let mut session: Session = serde_json::from_str(&content)?;
+
+    reconcile_modified_files(root, &mut session).await;
 
     println!("Active session: {}", session.id);
     println!(
@@ -270,6 +288,171 @@ pub async fn session_status(root: &Path) -> Result<()> {
     Ok(())
 }
 
+/// One file's longitudinal edit frequency: how many archived sessions touched it at all
+/// (sessions are deduped per file, not raw edit counts, since [`Session::modified_files`] is a
+/// set).
+#[derive(Debug, Serialize)]
+struct FileActivity {
+    file: String,
+    sessions_touched: usize,
+}
+
+/// Summed [`Session::duration`] for every session started on a given calendar day (UTC).
+#[derive(Debug, Serialize)]
+struct DayActivity {
+    date: String,
+    active_seconds: i64,
+}
+
+#[derive(Debug, Serialize)]
+struct SessionReport {
+    session_count: usize,
+    total_tracked_seconds: i64,
+    average_captures_per_session: f64,
+    most_modified_files: Vec<FileActivity>,
+    daily_activity: Vec<DayActivity>,
+}
+
+/// Loads every archived session in `.charter/sessions/`, aggregates total tracked time, the
+/// most-frequently-modified files, average captures per session, and a per-day active-time
+/// breakdown, and prints either a text summary or (with `json`) the same data as JSON for
+/// external charting.
+pub async fn session_report(root: &Path, json: bool) -> Result<()> {
+    let charter_dir = root.join(".charter");
+
+    if !charter_dir.exists() {
+        eprintln!("No .charter/ directory found. Run 'charter' first.");
+        std::process::exit(1);
+    }
+
+    let sessions = load_archived_sessions(&charter_dir.join("sessions")).await?;
+
+    if sessions.is_empty() {
+        println!("No archived sessions found.");
+        println!("Use 'charter session start' and 'charter session end' to build a history.");
+        return Ok(());
+    }
+
+    let report = build_session_report(&sessions);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_session_report(&report);
+    }
+
+    Ok(())
+}
+
+async fn load_archived_sessions(history_dir: &Path) -> Result<Vec<Session>> {
+    let mut sessions = Vec::new();
+
+    if !history_dir.exists() {
+        return Ok(sessions);
+    }
+
+    let mut entries = fs::read_dir(history_dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        if !entry.path().extension().is_some_and(|e| e == "json") {
+            continue;
+        }
+
+        let content = fs::read_to_string(entry.path()).await?;
+        if let Ok(session) = serde_json::from_str::<Session>(&content) {
+            sessions.push(session);
+        }
+    }
+
+    Ok(sessions)
+}
+
+fn build_session_report(sessions: &[Session]) -> SessionReport {
+    let session_count = sessions.len();
+    let total_tracked_seconds: i64 = sessions.iter().map(|s| s.duration().num_seconds()).sum();
+    let total_captures: usize = sessions.iter().map(|s| s.captures.len()).sum();
+    let average_captures_per_session = if session_count == 0 {
+        0.0
+    } else {
+        total_captures as f64 / session_count as f64
+    };
+
+    let mut file_counts: HashMap<String, usize> = HashMap::new();
+    for session in sessions {
+        for file in &session.modified_files {
+            *file_counts.entry(file.clone()).or_insert(0) += 1;
+        }
+    }
+
+    let mut most_modified_files: Vec<FileActivity> = file_counts
+        .into_iter()
+        .map(|(file, sessions_touched)| FileActivity {
+            file,
+            sessions_touched,
+        })
+        .collect();
+    most_modified_files.sort_by(|a, b| {
+        b.sessions_touched
+            .cmp(&a.sessions_touched)
+            .then_with(|| a.file.cmp(&b.file))
+    });
+    most_modified_files.truncate(20);
+
+    let mut day_seconds: HashMap<String, i64> = HashMap::new();
+    for session in sessions {
+        let date = session.started_at.format("%Y-%m-%d").to_string();
+        *day_seconds.entry(date).or_insert(0) += session.duration().num_seconds();
+    }
+
+    let mut daily_activity: Vec<DayActivity> = day_seconds
+        .into_iter()
+        .map(|(date, active_seconds)| DayActivity {
+            date,
+            active_seconds,
+        })
+        .collect();
+    daily_activity.sort_by(|a, b| a.date.cmp(&b.date));
+
+    SessionReport {
+        session_count,
+        total_tracked_seconds,
+        average_captures_per_session,
+        most_modified_files,
+        daily_activity,
+    }
+}
+
+fn print_session_report(report: &SessionReport) {
+    println!("Sessions analyzed: {}", report.session_count);
+    println!(
+        "Total tracked time: {}",
+        format_duration(chrono::Duration::seconds(report.total_tracked_seconds))
+    );
+    println!(
+        "Average captures per session: {:.1}",
+        report.average_captures_per_session
+    );
+    println!();
+
+    if !report.most_modified_files.is_empty() {
+        println!("Most-frequently-modified files:");
+        for file in report.most_modified_files.iter().take(10) {
+            println!("  {} ({} session(s))", file.file, file.sessions_touched);
+        }
+        println!();
+    }
+
+    if !report.daily_activity.is_empty() {
+        println!("Active time by day:");
+        for day in &report.daily_activity {
+            println!(
+                "  {} - {}",
+                day.date,
+                format_duration(chrono::Duration::seconds(day.active_seconds))
+            );
+        }
+    }
+}
+
 #[allow(dead_code)]
 pub async fn update_session_on_capture(
     root: &Path,
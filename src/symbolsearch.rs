@@ -0,0 +1,337 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::Result;
+use fst::automaton::{Automaton, Levenshtein, Str};
+use fst::{IntoStreamer, Map, MapBuilder, Streamer};
+use serde::{Deserialize, Serialize};
+
+use crate::cache::Cache;
+
+/// Maximum edit distance [`SymbolSearchIndex::search`]'s fuzzy fallback will accept before
+/// giving up, mirroring the typo budget [`crate::serve::fuzzy_match`] uses for its own
+/// Levenshtein-based tier.
+const MAX_EDIT_DISTANCE: u32 = 2;
+
+/// One candidate match for a [`SymbolSearchIndex::search`] query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolEntry {
+    pub name: String,
+    pub file: String,
+    pub line: usize,
+    pub impl_type: Option<String>,
+    pub importance_score: u32,
+}
+
+/// A finite-state transducer over every symbol name in the crate (normalized to lowercase, since
+/// `fst::Map` keys compare byte-for-byte and a search query shouldn't have to match case),
+/// persisted alongside `cache.bin`/`index.bin` as `symbols.fst` + `symbols_meta.bin` so
+/// `charter search` resolves exact, prefix, and fuzzy queries sublinearly instead of scanning
+/// every symbol the way reading `symbols.md` whole would.
+///
+/// `fst::Map` requires exactly one `u64` value per key, but several symbols can share a
+/// normalized name (an inherent method redefined across types, an overload-by-convention free
+/// function), so the map's value is an index into `buckets` rather than a [`SymbolEntry`]
+/// directly.
+pub struct SymbolSearchIndex {
+    map: Map<Vec<u8>>,
+    buckets: Vec<Vec<SymbolEntry>>,
+}
+
+impl SymbolSearchIndex {
+    /// Builds the index from scratch over every function/method in `cache`'s complexity data —
+    /// the same source [`crate::callindex::SymbolIndex::build`] reads for call-graph resolution,
+    /// just keyed by normalized name instead of `(impl_type, name)`.
+    pub fn build(cache: &Cache) -> Self {
+        let mut grouped: BTreeMap<String, Vec<SymbolEntry>> = BTreeMap::new();
+
+        for (file, entry) in &cache.entries {
+            for func in &entry.data.parsed.complexity {
+                grouped
+                    .entry(func.name.to_lowercase())
+                    .or_default()
+                    .push(SymbolEntry {
+                        name: func.name.clone(),
+                        file: file.clone(),
+                        line: func.line,
+                        impl_type: func.impl_type.clone(),
+                        importance_score: func.metrics.importance_score(),
+                    });
+            }
+        }
+
+        let mut builder = MapBuilder::memory();
+        let mut buckets = Vec::with_capacity(grouped.len());
+        for (bucket_id, (name, sites)) in grouped.into_iter().enumerate() {
+            builder
+                .insert(name, bucket_id as u64)
+                .expect("grouped keys come from a BTreeMap, so they're already sorted and unique");
+            buckets.push(sites);
+        }
+
+        let map = Map::new(
+            builder
+                .into_inner()
+                .expect("an in-memory fst build never fails to flush"),
+        )
+        .expect("bytes built by MapBuilder::memory always form a valid fst::Map");
+
+        Self { map, buckets }
+    }
+
+    /// Loads a previously [`Self::save`]d index, or `None` if either half is missing (no capture
+    /// has run yet, or it predates this index's introduction).
+    pub async fn load(atlas_dir: &Path) -> Result<Option<Self>> {
+        let fst_path = atlas_dir.join("symbols.fst");
+        let meta_path = atlas_dir.join("symbols_meta.bin");
+
+        if !fst_path.exists() || !meta_path.exists() {
+            return Ok(None);
+        }
+
+        let fst_bytes = tokio::fs::read(&fst_path).await?;
+        let meta_bytes = tokio::fs::read(&meta_path).await?;
+
+        let map = Map::new(fst_bytes)?;
+        let buckets: Vec<Vec<SymbolEntry>> = bincode::deserialize(&meta_bytes)?;
+
+        Ok(Some(Self { map, buckets }))
+    }
+
+    pub async fn save(&self, atlas_dir: &Path) -> Result<()> {
+        tokio::fs::write(atlas_dir.join("symbols.fst"), self.map.as_fst().as_bytes()).await?;
+        tokio::fs::write(
+            atlas_dir.join("symbols_meta.bin"),
+            bincode::serialize(&self.buckets)?,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Resolves `query` (normalized the same way [`Self::build`] normalizes symbol names)
+    /// against the FST, trying exact, then prefix, then increasingly loose Levenshtein-fuzzy
+    /// matches — each tier only runs if the previous one came up empty — and ranks whatever
+    /// tier hit by [`crate::extract::complexity::ComplexityMetrics::importance_score`] descending
+    /// so the most significant matches surface first. `module`, when given, restricts results to
+    /// symbols whose file path starts with it.
+    pub fn search(&self, query: &str, module: Option<&str>, limit: usize) -> Vec<&SymbolEntry> {
+        let normalized = query.to_lowercase();
+
+        let mut hits: Vec<&SymbolEntry> = self
+            .resolve_bucket_ids(&normalized)
+            .into_iter()
+            .flat_map(|bucket_id| self.buckets[bucket_id as usize].iter())
+            .filter(|site| module.is_none_or(|prefix| site.file.starts_with(prefix)))
+            .collect();
+
+        hits.sort_by(|a, b| b.importance_score.cmp(&a.importance_score));
+        hits.truncate(limit);
+        hits
+    }
+
+    fn resolve_bucket_ids(&self, normalized: &str) -> Vec<u64> {
+        if let Some(bucket_id) = self.map.get(normalized) {
+            return vec![bucket_id];
+        }
+
+        let prefix_hits = stream_values(&self.map, Str::new(normalized).starts_with());
+        if !prefix_hits.is_empty() {
+            return prefix_hits;
+        }
+
+        for distance in 1..=MAX_EDIT_DISTANCE {
+            let Ok(automaton) = Levenshtein::new(normalized, distance) else {
+                continue;
+            };
+            let fuzzy_hits = stream_values(&self.map, automaton);
+            if !fuzzy_hits.is_empty() {
+                return fuzzy_hits;
+            }
+        }
+
+        Vec::new()
+    }
+}
+
+fn stream_values<A: Automaton>(map: &Map<Vec<u8>>, automaton: A) -> Vec<u64> {
+    let mut stream = map.search(automaton).into_stream();
+    let mut values = Vec::new();
+    while let Some((_key, value)) = stream.next() {
+        values.push(value);
+    }
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    use crate::cache::{CacheEntry, FileData};
+    use crate::extract::complexity::{ComplexityMetrics, FunctionComplexity};
+    use crate::pipeline::ParsedFile;
+
+    fn cache_with(files: Vec<(&str, Vec<(&str, u32)>)>) -> Cache {
+        let mut entries = HashMap::new();
+        for (file, functions) in files {
+            let complexity: Vec<FunctionComplexity> = functions
+                .into_iter()
+                .map(|(name, importance)| FunctionComplexity {
+                    name: name.to_string(),
+                    impl_type: None,
+                    line: 1,
+                    metrics: ComplexityMetrics {
+                        cyclomatic: importance,
+                        ..Default::default()
+                    },
+                })
+                .collect();
+            entries.insert(
+                file.to_string(),
+                CacheEntry {
+                    hash: String::new(),
+                    size: 0,
+                    lines: 0,
+                    item_summary_hash: 0,
+                    last_commit_timestamp: 0,
+                    distinct_authors: 0,
+                    data: FileData {
+                        parsed: ParsedFile {
+                            complexity,
+                            ..Default::default()
+                        },
+                    },
+                },
+            );
+        }
+        Cache { entries }
+    }
+
+    /// An exact-case-insensitive query resolves straight to its bucket without falling through
+    /// to the prefix/fuzzy tiers.
+    #[test]
+    fn search_finds_an_exact_case_insensitive_match() {
+        let index = SymbolSearchIndex::build(&cache_with(vec![("lib.rs", vec![("ParseQuery", 5)])]));
+
+        let hits = index.search("parsequery", None, 10);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].name, "ParseQuery");
+    }
+
+    /// A single-character typo still resolves, via the Levenshtein-automaton fallback tier.
+    #[test]
+    fn search_tolerates_a_single_typo() {
+        let index = SymbolSearchIndex::build(&cache_with(vec![("lib.rs", vec![("parse_query", 5)])]));
+
+        let hits = index.search("parse_quary", None, 10);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].name, "parse_query");
+    }
+
+    /// Results are ranked by `importance_score` descending, highest first.
+    #[test]
+    fn search_ranks_results_by_importance_score_descending() {
+        let index = SymbolSearchIndex::build(&cache_with(vec![
+            ("a.rs", vec![("run", 1)]),
+            ("b.rs", vec![("run", 50)]),
+        ]));
+
+        let hits = index.search("run", None, 10);
+
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].file, "b.rs");
+        assert_eq!(hits[1].file, "a.rs");
+    }
+
+    /// `module` restricts results to symbols whose file path starts with it.
+    #[test]
+    fn search_filters_by_module_prefix() {
+        let index = SymbolSearchIndex::build(&cache_with(vec![
+            ("src/foo/lib.rs", vec![("run", 1)]),
+            ("src/bar/lib.rs", vec![("run", 1)]),
+        ]));
+
+        let hits = index.search("run", Some("src/foo"), 10);
+
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].file, "src/foo/lib.rs");
+    }
+
+    /// A query with no match anywhere — not exact, prefix, or within the fuzzy edit-distance
+    /// budget — returns nothing rather than falling back to an unrelated bucket.
+    #[test]
+    fn search_finds_nothing_for_an_unrelated_query() {
+        let index = SymbolSearchIndex::build(&cache_with(vec![("lib.rs", vec![("shared", 1)])]));
+
+        assert!(index.search("zzzzzzzzzz", None, 10).is_empty());
+    }
+}
+
+/// `charter search <query>` — loads `symbols.fst` from `root/.atlas`, rebuilding it from
+/// `cache.bin` on first use if it's missing (e.g. written by a charter version before this index
+/// existed, mirroring [`crate::query::query`]'s `keyword_search` fallback for `index.bin`).
+pub async fn search(root: &Path, query_str: &str, module: Option<&str>, limit: usize, json: bool) -> Result<()> {
+    let atlas_dir = root.join(".atlas");
+
+    if !atlas_dir.exists() {
+        eprintln!("No .atlas/ directory found. Run 'atlas' first.");
+        std::process::exit(1);
+    }
+
+    let index = match SymbolSearchIndex::load(&atlas_dir).await? {
+        Some(index) => index,
+        None => {
+            let cache = Cache::load(&atlas_dir.join("cache.bin")).await?;
+            SymbolSearchIndex::build(&cache)
+        }
+    };
+
+    let hits = index.search(query_str, module, limit);
+
+    if json {
+        let results: Vec<serde_json::Value> = hits
+            .iter()
+            .map(|hit| {
+                serde_json::json!({
+                    "name": hit.name,
+                    "file": hit.file,
+                    "line": hit.line,
+                    "impl_type": hit.impl_type,
+                    "importance_score": hit.importance_score,
+                })
+            })
+            .collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "query": query_str,
+                "module": module,
+                "count": results.len(),
+                "results": results,
+            }))?
+        );
+        return Ok(());
+    }
+
+    println!("Search results for '{}':", query_str);
+    println!();
+
+    if hits.is_empty() {
+        println!("  No results found");
+    } else {
+        for hit in &hits {
+            let qualified = match &hit.impl_type {
+                Some(impl_type) => format!("{}::{}", impl_type, hit.name),
+                None => hit.name.clone(),
+            };
+            println!(
+                "  [importance={}] {}:{} {}",
+                hit.importance_score, hit.file, hit.line, qualified
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+
+use crate::detect::{self, WorkspaceInfo};
+
+/// One discovered crate/package root and the sibling targets it depends on (workspace-internal
+/// edges only — external crates aren't targets and carry no impact).
+#[derive(Debug, Clone)]
+pub struct Target {
+    pub name: String,
+    /// Root path relative to the workspace root, forward-slash separated, with no trailing `/`.
+    pub root: String,
+    pub depends_on: Vec<String>,
+}
+
+/// Prefix trie over target root paths, so mapping a changed file to its owning target costs
+/// O(path length) instead of comparing against every target's root.
+#[derive(Debug, Default)]
+struct TrieNode {
+    children: HashMap<String, TrieNode>,
+    target: Option<String>,
+}
+
+struct TargetTrie {
+    root: TrieNode,
+}
+
+impl TargetTrie {
+    fn build(targets: &[Target]) -> Self {
+        let mut root = TrieNode::default();
+        for target in targets {
+            let mut node = &mut root;
+            for segment in target.root.split('/').filter(|s| !s.is_empty()) {
+                node = node.children.entry(segment.to_string()).or_default();
+            }
+            node.target = Some(target.name.clone());
+        }
+        Self { root }
+    }
+
+    /// Finds the target owning `file_path` by longest-prefix match: walks the path segment by
+    /// segment, remembering the most specific target seen so a nested crate (`crates/foo/src/`)
+    /// wins over an enclosing workspace root target that also claims a prefix of the path.
+    fn owner(&self, file_path: &str) -> Option<&str> {
+        let mut node = &self.root;
+        let mut best = node.target.as_deref();
+        for segment in file_path.split('/').filter(|s| !s.is_empty()) {
+            let Some(child) = node.children.get(segment) else {
+                break;
+            };
+            node = child;
+            if let Some(name) = node.target.as_deref() {
+                best = Some(name);
+            }
+        }
+        best
+    }
+}
+
+/// Change-impact analysis for [`crate::output::peek`]'s `--since`: which targets own the
+/// directly changed files, and which other targets are affected because they transitively
+/// depend on one of those.
+#[derive(Debug, Clone, Default)]
+pub struct ImpactAnalysis {
+    pub directly_changed: Vec<String>,
+    pub affected_downstream: Vec<String>,
+}
+
+impl ImpactAnalysis {
+    /// Every target touched by the change, directly or downstream — the scope `Read --since`
+    /// should narrow its output to.
+    pub fn impacted(&self) -> HashSet<&str> {
+        self.directly_changed
+            .iter()
+            .chain(&self.affected_downstream)
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+/// Builds the target graph for `root` and computes [`ImpactAnalysis`] for `changed_files`
+/// (paths relative to `root`, forward-slash separated). Returns `None` for a single-crate
+/// project, where "which target changed" is always "the only one" and there's nothing to scope.
+pub async fn analyze_impact(root: &Path, changed_files: &[String]) -> Option<ImpactAnalysis> {
+    let workspace = detect::detect_workspace(root).await.ok()?;
+    if workspace.members.len() <= 1 {
+        return None;
+    }
+
+    let targets = build_targets(root, &workspace);
+    let trie = TargetTrie::build(&targets);
+
+    let mut directly_changed: HashSet<String> = HashSet::new();
+    for file in changed_files {
+        if let Some(owner) = trie.owner(file) {
+            directly_changed.insert(owner.to_string());
+        }
+    }
+
+    let reverse_edges = build_reverse_edges(&targets);
+    let mut affected: HashSet<String> = HashSet::new();
+    let mut queue: VecDeque<String> = directly_changed.iter().cloned().collect();
+
+    while let Some(name) = queue.pop_front() {
+        let Some(dependents) = reverse_edges.get(&name) else {
+            continue;
+        };
+        for dependent in dependents {
+            if directly_changed.contains(dependent) || !affected.insert(dependent.clone()) {
+                continue;
+            }
+            queue.push_back(dependent.clone());
+        }
+    }
+
+    let mut directly_changed: Vec<String> = directly_changed.into_iter().collect();
+    directly_changed.sort();
+    let mut affected_downstream: Vec<String> = affected.into_iter().collect();
+    affected_downstream.sort();
+
+    Some(ImpactAnalysis {
+        directly_changed,
+        affected_downstream,
+    })
+}
+
+fn build_targets(root: &Path, workspace: &WorkspaceInfo) -> Vec<Target> {
+    workspace
+        .members
+        .iter()
+        .map(|member| {
+            let relative = member
+                .path
+                .strip_prefix(root)
+                .unwrap_or(&member.path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            Target {
+                name: member.name.clone(),
+                root: relative,
+                depends_on: member.dependencies.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Reverse dependency edges (depended-upon target -> dependent targets), restricted to edges
+/// between workspace members; a dependency name that isn't itself a member is an external crate
+/// and carries no impact.
+fn build_reverse_edges(targets: &[Target]) -> HashMap<String, Vec<String>> {
+    let names: HashSet<&str> = targets.iter().map(|t| t.name.as_str()).collect();
+    let mut reverse: HashMap<String, Vec<String>> = HashMap::new();
+
+    for target in targets {
+        for dep in &target.depends_on {
+            if names.contains(dep.as_str()) {
+                reverse
+                    .entry(dep.clone())
+                    .or_default()
+                    .push(target.name.clone());
+            }
+        }
+    }
+
+    reverse
+}
@@ -1,9 +1,27 @@
 use anyhow::Result;
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tokio::fs;
 
-pub async fn tests(root: &Path, file_filter: Option<&str>) -> Result<()> {
+use crate::rangemerge::{self, CoverageRange};
+
+/// Which shape `tests` renders its `TestMapping`s into. `Cobertura`/`Coveralls` are the
+/// CI-dashboard export formats [`build_cobertura_xml`]/[`build_coveralls_json`] produce.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TestsFormat {
+    Text,
+    Json,
+    Cobertura,
+    Coveralls,
+}
+
+pub async fn tests(
+    root: &Path,
+    file_filter: Option<&str>,
+    lcov_path: Option<&Path>,
+    merge_paths: &[PathBuf],
+    format: TestsFormat,
+) -> Result<()> {
     let charter_dir = root.join(".charter");
 
     if !charter_dir.exists() {
@@ -11,12 +29,33 @@ pub async fn tests(root: &Path, file_filter: Option<&str>) -> Result<()> {
         std::process::exit(1);
     }
 
-    let mapping = build_test_mapping(&charter_dir).await?;
+    let lcov = merge_lcov_inputs(lcov_path, merge_paths).await?;
 
-    if let Some(file) = file_filter {
-        show_tests_for_file(&mapping, file);
-    } else {
-        show_all_mappings(&mapping);
+    if let Some(lcov) = &lcov {
+        persist_coverage(&charter_dir, lcov).await?;
+    }
+
+    // Json/Cobertura/Coveralls report every file, so only Text's `--file` path narrows the cache
+    // read — the others need the full, eagerly-loaded mapping regardless.
+    let lazy_filter = match format {
+        TestsFormat::Text => file_filter,
+        _ => None,
+    };
+    let mapping = build_test_mapping(&charter_dir, lcov.as_ref(), lazy_filter).await?;
+
+    match format {
+        TestsFormat::Text => {
+            if let Some(file) = file_filter {
+                show_tests_for_file(&mapping, file);
+            } else {
+                show_all_mappings(&mapping);
+            }
+        }
+        TestsFormat::Json => print_tests_json(&mapping)?,
+        TestsFormat::Cobertura => println!("{}", build_cobertura_xml(&mapping)),
+        TestsFormat::Coveralls => {
+            println!("{}", serde_json::to_string_pretty(&build_coveralls_json(&mapping, lcov.as_ref()))?)
+        }
     }
 
     Ok(())
@@ -27,6 +66,15 @@ struct TestMapping {
     test_files: Vec<String>,
     test_functions: Vec<String>,
     coverage_estimate: CoverageLevel,
+    /// `LH / LF` from the matching LCOV record, when `tests` was run with `--lcov`. `None` when no
+    /// LCOV file was supplied, or the file it covers didn't include a record for this source file
+    /// (in which case `coverage_estimate` falls back to [`estimate_coverage`]'s heuristic).
+    line_hit_ratio: Option<f64>,
+    /// Line count from the cache entry, used to size the Coveralls per-line `coverage` array.
+    total_lines: usize,
+    /// This file's cached content hash, reused as Coveralls' `source_digest` rather than rehashing
+    /// the file — `cache.bin` already paid for this hash during capture.
+    content_hash: String,
 }
 
 #[derive(Clone, Copy)]
@@ -48,45 +96,274 @@ impl std::fmt::Display for CoverageLevel {
     }
 }
 
-async fn build_test_mapping(charter_dir: &Path) -> Result<HashMap<String, TestMapping>> {
+/// One `SF:`/`end_of_record` section of an LCOV `.info` file. `lines_found`/`lines_hit` drive
+/// [`lcov_coverage_level`]; `line_hits` (one `(line, hit_count)` pair per `DA:` record) is only
+/// needed by [`build_coveralls_json`]'s per-line `coverage` array, which is the one format here
+/// that can't be satisfied by the `LF`/`LH` summary alone. `pub(crate)` so
+/// [`crate::output::hotspots::write_hotspots`] can look up a function's line-coverage ratio
+/// against whatever `tests --lcov` most recently persisted to `.charter/coverage.lcov`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct LcovRecord {
+    pub(crate) lines_found: u32,
+    pub(crate) lines_hit: u32,
+    pub(crate) line_hits: Vec<(u32, u32)>,
+}
+
+/// Parses an LCOV `.info` file (as produced by `cargo tarpaulin --out lcov` or `grcov`) into a map
+/// of source path -> [`LcovRecord`], keyed by whatever path the `SF:` line recorded (absolute or
+/// relative to wherever the coverage tool was run from). [`find_lcov_record`] handles matching
+/// that path against `TestMapping::source_file`'s repo-relative form.
+async fn parse_lcov(path: &Path) -> Result<HashMap<String, LcovRecord>> {
+    let content = fs::read_to_string(path).await?;
+
+    let mut records = HashMap::new();
+    let mut current_file: Option<String> = None;
+    let mut current = LcovRecord::default();
+
+    for line in content.lines() {
+        if let Some(file) = line.strip_prefix("SF:") {
+            current_file = Some(file.trim().replace('\\', "/"));
+            current = LcovRecord::default();
+        } else if let Some(count) = line.strip_prefix("LF:") {
+            current.lines_found = count.trim().parse().unwrap_or(0);
+        } else if let Some(count) = line.strip_prefix("LH:") {
+            current.lines_hit = count.trim().parse().unwrap_or(0);
+        } else if let Some(rest) = line.strip_prefix("DA:") {
+            if let Some((line_no, hits)) = rest.split_once(',') {
+                if let (Ok(line_no), Ok(hits)) =
+                    (line_no.trim().parse(), hits.trim().parse())
+                {
+                    current.line_hits.push((line_no, hits));
+                }
+            }
+        } else if line.trim() == "end_of_record" {
+            if let Some(file) = current_file.take() {
+                records.insert(file, current);
+            }
+        }
+    }
+
+    Ok(records)
+}
+
+/// Writes `records` to `.charter/coverage.lcov` in LCOV `.info` form, so a later plain `charter`
+/// capture can find it via [`load_coverage`] and fold real line-coverage data into
+/// `output::hotspots::write_hotspots`'s "Untested Hotspots" section without requiring every
+/// capture to carry an `--lcov` flag of its own.
+async fn persist_coverage(charter_dir: &Path, records: &HashMap<String, LcovRecord>) -> Result<()> {
+    let mut files: Vec<&String> = records.keys().collect();
+    files.sort();
+
+    let mut content = String::new();
+    for file in files {
+        let record = &records[file];
+        content.push_str(&format!("SF:{file}\n"));
+
+        let mut hits = record.line_hits.clone();
+        hits.sort_unstable_by_key(|&(line, _)| line);
+        for (line, count) in hits {
+            content.push_str(&format!("DA:{line},{count}\n"));
+        }
+
+        content.push_str(&format!("LF:{}\n", record.lines_found));
+        content.push_str(&format!("LH:{}\n", record.lines_hit));
+        content.push_str("end_of_record\n");
+    }
+
+    fs::write(charter_dir.join("coverage.lcov"), content).await?;
+    Ok(())
+}
+
+/// Loads `.charter/coverage.lcov`, the merged coverage [`tests`] last persisted via
+/// [`persist_coverage`], or `None` if no `--lcov`-driven run has happened yet in this project.
+pub(crate) async fn load_coverage(charter_dir: &Path) -> Option<HashMap<String, LcovRecord>> {
+    let path = charter_dir.join("coverage.lcov");
+    if !path.exists() {
+        return None;
+    }
+    parse_lcov(&path).await.ok()
+}
+
+/// Parses `primary` (if any) plus every path in `extra`, unioning each file's executed ranges
+/// across all of them via [`rangemerge::merge_ranges`] so a line counts as hit if any run hit it.
+/// Returns `None` only when there are no inputs at all, matching the no-`--lcov` behavior of
+/// falling back to [`estimate_coverage`].
+async fn merge_lcov_inputs(
+    primary: Option<&Path>,
+    extra: &[PathBuf],
+) -> Result<Option<HashMap<String, LcovRecord>>> {
+    let mut paths: Vec<&Path> = Vec::new();
+    if let Some(path) = primary {
+        paths.push(path);
+    }
+    paths.extend(extra.iter().map(|p| p.as_path()));
+
+    if paths.is_empty() {
+        return Ok(None);
+    }
+
+    let mut merged: HashMap<String, LcovRecord> = HashMap::new();
+    for path in paths {
+        for (file, record) in parse_lcov(path).await? {
+            merged
+                .entry(file)
+                .and_modify(|existing| *existing = merge_lcov_records(existing, &record))
+                .or_insert(record);
+        }
+    }
+    Ok(Some(merged))
+}
+
+/// Converts a [`LcovRecord`]'s per-line `DA:` hits into a [`CoverageRange`] set, coalescing
+/// consecutive lines that share the same hit count into one range.
+fn lcov_record_to_ranges(record: &LcovRecord) -> Vec<CoverageRange> {
+    let mut hits = record.line_hits.clone();
+    hits.sort_unstable_by_key(|&(line, _)| line);
+
+    let mut ranges: Vec<CoverageRange> = Vec::new();
+    for (line, count) in hits {
+        match ranges.last_mut() {
+            Some(prev) if prev.end == line && prev.count == count => prev.end = line + 1,
+            _ => ranges.push(CoverageRange {
+                start: line,
+                end: line + 1,
+                count,
+            }),
+        }
+    }
+    ranges
+}
+
+/// Expands a merged [`CoverageRange`] set back into a [`LcovRecord`]'s flat `line_hits`/
+/// `lines_found`/`lines_hit` shape, the inverse of [`lcov_record_to_ranges`].
+fn ranges_to_lcov_record(ranges: &[CoverageRange]) -> LcovRecord {
+    let mut line_hits = Vec::new();
+    let mut lines_found = 0u32;
+    let mut lines_hit = 0u32;
+
+    for range in ranges {
+        for line in range.start..range.end {
+            line_hits.push((line, range.count));
+            lines_found += 1;
+            if range.count > 0 {
+                lines_hit += 1;
+            }
+        }
+    }
+
+    LcovRecord {
+        lines_found,
+        lines_hit,
+        line_hits,
+    }
+}
+
+/// Unions two runs' coverage of the same file: each run's `DA:` hits become a range set, the
+/// range tree merges and sums overlapping ranges, and the result is flattened back into a record.
+fn merge_lcov_records(a: &LcovRecord, b: &LcovRecord) -> LcovRecord {
+    let merged = rangemerge::merge_ranges(&lcov_record_to_ranges(a), &lcov_record_to_ranges(b));
+    ranges_to_lcov_record(&merged)
+}
+
+/// Looks up `source_file` (a repo-relative path like `src/foo.rs`) in a parsed LCOV map, matching
+/// by path suffix in either direction since `SF:` lines can be absolute, repo-relative, or
+/// relative to whatever directory the coverage tool ran from.
+pub(crate) fn find_lcov_record<'a>(
+    lcov: &'a HashMap<String, LcovRecord>,
+    source_file: &str,
+) -> Option<&'a LcovRecord> {
+    let normalized = source_file.replace('\\', "/");
+    lcov.get(&normalized).or_else(|| {
+        lcov.iter()
+            .find(|(path, _)| path.ends_with(&normalized) || normalized.ends_with(path.as_str()))
+            .map(|(_, record)| record)
+    })
+}
+
+/// Maps an LCOV `LH / LF` hit ratio onto [`CoverageLevel`], ground-truth in place of
+/// [`estimate_coverage`]'s test-count guess.
+fn lcov_coverage_level(record: &LcovRecord) -> CoverageLevel {
+    if record.lines_found == 0 {
+        return CoverageLevel::None;
+    }
+
+    let ratio = record.lines_hit as f64 / record.lines_found as f64;
+    if ratio >= 0.8 {
+        CoverageLevel::High
+    } else if ratio >= 0.5 {
+        CoverageLevel::Medium
+    } else if ratio > 0.0 {
+        CoverageLevel::Low
+    } else {
+        CoverageLevel::None
+    }
+}
+
+/// Whether `path` lives under a `tests/` directory or carries a `_test(s).rs` suffix — the
+/// dedicated-test-file heuristic [`build_test_mapping`] uses both to bucket cache entries and to
+/// decide which non-matching source files a `file_filter` can safely skip loading.
+fn is_test_file_path(path: &str) -> bool {
+    path.contains("/tests/")
+        || path.contains("\\tests\\")
+        || path.ends_with("_test.rs")
+        || path.ends_with("_tests.rs")
+}
+
+async fn build_test_mapping(
+    charter_dir: &Path,
+    lcov: Option<&HashMap<String, LcovRecord>>,
+    file_filter: Option<&str>,
+) -> Result<HashMap<String, TestMapping>> {
     let mut mappings: HashMap<String, TestMapping> = HashMap::new();
 
     let mut source_files: Vec<String> = Vec::new();
     let mut test_files: Vec<String> = Vec::new();
     let mut test_functions: HashMap<String, Vec<String>> = HashMap::new();
+    let mut line_counts: HashMap<String, usize> = HashMap::new();
+    let mut content_hashes: HashMap<String, String> = HashMap::new();
+
+    // Dedicated test files are always needed (a filtered source file still has to be matched
+    // against every test file), but a non-test source file only needs loading when it's the one
+    // `file_filter` asked for — on a cache with tens of thousands of entries this keeps `charter
+    // tests --file foo.rs` from paying to deserialize every other file in the repo.
+    let keep_path = |path: &str| {
+        if !path.ends_with(".rs") {
+            return false;
+        }
+        if is_test_file_path(path) {
+            return true;
+        }
+        match file_filter {
+            Some(filter) => path.to_lowercase().contains(&filter.to_lowercase()),
+            None => true,
+        }
+    };
 
-    let cache_path = charter_dir.join("cache.bin");
-    if cache_path.exists() {
-        if let Ok(cache_data) = fs::read(&cache_path).await {
-            if let Ok(cache) = bincode::deserialize::<crate::cache::Cache>(&cache_data) {
-                for (file_path, entry) in &cache.entries {
-                    let is_test_file = file_path.contains("/tests/")
-                        || file_path.contains("\\tests\\")
-                        || file_path.ends_with("_test.rs")
-                        || file_path.ends_with("_tests.rs");
-
-                    if is_test_file {
-                        test_files.push(file_path.clone());
-                        test_functions
-                            .insert(file_path.clone(), entry.data.parsed.test_functions.clone());
-                    } else if file_path.ends_with(".rs") {
-                        source_files.push(file_path.clone());
-                    }
+    let entries = crate::cache::load_filtered(charter_dir, keep_path).await?;
+    for (file_path, entry) in &entries {
+        line_counts.insert(file_path.clone(), entry.lines);
+        content_hashes.insert(file_path.clone(), entry.hash.clone());
 
-                    if entry.data.parsed.has_test_module {
-                        let inline_tests: Vec<String> = entry.data.parsed.test_functions.to_vec();
-                        if !inline_tests.is_empty() {
-                            let mapping =
-                                mappings.entry(file_path.clone()).or_insert(TestMapping {
-                                    source_file: file_path.clone(),
-                                    test_files: Vec::new(),
-                                    test_functions: Vec::new(),
-                                    coverage_estimate: CoverageLevel::None,
-                                });
-                            mapping.test_functions.extend(inline_tests);
-                        }
-                    }
-                }
+        if is_test_file_path(file_path) {
+            test_files.push(file_path.clone());
+            test_functions.insert(file_path.clone(), entry.data.parsed.test_functions.clone());
+        } else {
+            source_files.push(file_path.clone());
+        }
+
+        if entry.data.parsed.has_test_module {
+            let inline_tests: Vec<String> = entry.data.parsed.test_functions.to_vec();
+            if !inline_tests.is_empty() {
+                let mapping = mappings.entry(file_path.clone()).or_insert(TestMapping {
+                    source_file: file_path.clone(),
+                    test_files: Vec::new(),
+                    test_functions: Vec::new(),
+                    coverage_estimate: CoverageLevel::None,
+                    line_hit_ratio: None,
+                    total_lines: entry.lines,
+                    content_hash: entry.hash.clone(),
+                });
+                mapping.test_functions.extend(inline_tests);
             }
         }
     }
@@ -97,6 +374,9 @@ async fn build_test_mapping(charter_dir: &Path) -> Result<HashMap<String, TestMa
             test_files: Vec::new(),
             test_functions: Vec::new(),
             coverage_estimate: CoverageLevel::None,
+            line_hit_ratio: None,
+            total_lines: line_counts.get(source_file).copied().unwrap_or(0),
+            content_hash: content_hashes.get(source_file).cloned().unwrap_or_default(),
         });
 
         let source_stem = extract_stem(source_file);
@@ -117,7 +397,17 @@ async fn build_test_mapping(charter_dir: &Path) -> Result<HashMap<String, TestMa
             }
         }
 
-        mapping.coverage_estimate = estimate_coverage(mapping);
+        match lcov.and_then(|lcov| find_lcov_record(lcov, source_file)) {
+            Some(record) => {
+                mapping.coverage_estimate = lcov_coverage_level(record);
+                mapping.line_hit_ratio = Some(if record.lines_found == 0 {
+                    0.0
+                } else {
+                    record.lines_hit as f64 / record.lines_found as f64
+                });
+            }
+            None => mapping.coverage_estimate = estimate_coverage(mapping),
+        }
     }
 
     Ok(mappings)
@@ -219,7 +509,14 @@ fn show_tests_for_file(mappings: &HashMap<String, TestMapping>, file: &str) {
 
     for (path, mapping) in matching {
         println!("Tests for: {}", path);
-        println!("Coverage estimate: {}", mapping.coverage_estimate);
+        match mapping.line_hit_ratio {
+            Some(ratio) => println!(
+                "Coverage: {} ({:.1}% lines hit, from LCOV)",
+                mapping.coverage_estimate,
+                ratio * 100.0
+            ),
+            None => println!("Coverage estimate: {}", mapping.coverage_estimate),
+        }
         println!();
 
         if !mapping.test_files.is_empty() {
@@ -321,3 +618,210 @@ fn show_all_mappings(mappings: &HashMap<String, TestMapping>) {
         by_coverage.get("none").unwrap().len()
     );
 }
+
+/// Ground-truth [`TestMapping::line_hit_ratio`] when present, otherwise a representative midpoint
+/// for the file's [`CoverageLevel`] heuristic so every export format always has a numeric rate to
+/// report rather than needing its own "no data" branch.
+fn coverage_ratio(mapping: &TestMapping) -> f64 {
+    mapping.line_hit_ratio.unwrap_or(match mapping.coverage_estimate {
+        CoverageLevel::High => 0.9,
+        CoverageLevel::Medium => 0.65,
+        CoverageLevel::Low => 0.25,
+        CoverageLevel::None => 0.0,
+    })
+}
+
+#[derive(serde::Serialize)]
+struct TestMappingJson<'a> {
+    source_file: &'a str,
+    coverage_estimate: String,
+    line_hit_ratio: Option<f64>,
+    test_files: &'a [String],
+    test_functions: &'a [String],
+}
+
+/// Prints every `TestMapping` as a flat JSON array, the `--format json` counterpart to
+/// [`show_all_mappings`]'s text report.
+fn print_tests_json(mappings: &HashMap<String, TestMapping>) -> Result<()> {
+    let mut entries: Vec<TestMappingJson> = mappings
+        .values()
+        .map(|m| TestMappingJson {
+            source_file: &m.source_file,
+            coverage_estimate: m.coverage_estimate.to_string(),
+            line_hit_ratio: m.line_hit_ratio,
+            test_files: &m.test_files,
+            test_functions: &m.test_functions,
+        })
+        .collect();
+    entries.sort_by(|a, b| a.source_file.cmp(b.source_file));
+
+    println!("{}", serde_json::to_string_pretty(&entries)?);
+    Ok(())
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Builds a Cobertura 1.9 `<coverage>` document from `mappings`: one `<class>` per source file
+/// under a single `src` package, `line-rate` from [`coverage_ratio`], and one `<method>` per known
+/// test function. `branch-rate` is always `0` — charter doesn't track per-branch hits, only
+/// per-file line ratios, so reporting a branch rate would be fabricating precision this data
+/// doesn't have.
+fn build_cobertura_xml(mappings: &HashMap<String, TestMapping>) -> String {
+    let mut sorted: Vec<&TestMapping> = mappings.values().collect();
+    sorted.sort_by(|a, b| a.source_file.cmp(&b.source_file));
+
+    let overall_rate = if sorted.is_empty() {
+        0.0
+    } else {
+        sorted.iter().map(|m| coverage_ratio(m)).sum::<f64>() / sorted.len() as f64
+    };
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\"?>\n");
+    xml.push_str(&format!(
+        "<coverage line-rate=\"{overall_rate:.4}\" branch-rate=\"0\" version=\"1.9\">\n"
+    ));
+    xml.push_str("  <packages>\n");
+    xml.push_str(&format!(
+        "    <package name=\"src\" line-rate=\"{overall_rate:.4}\" branch-rate=\"0\">\n"
+    ));
+    xml.push_str("      <classes>\n");
+
+    for mapping in &sorted {
+        let rate = coverage_ratio(mapping);
+        xml.push_str(&format!(
+            "        <class name=\"{}\" filename=\"{}\" line-rate=\"{:.4}\" branch-rate=\"0\">\n",
+            xml_escape(&extract_stem(&mapping.source_file)),
+            xml_escape(&mapping.source_file),
+            rate
+        ));
+        xml.push_str("          <methods>\n");
+        for test_fn in &mapping.test_functions {
+            xml.push_str(&format!(
+                "            <method name=\"{}\" signature=\"()\" line-rate=\"{:.4}\" branch-rate=\"0\"/>\n",
+                xml_escape(test_fn),
+                rate
+            ));
+        }
+        xml.push_str("          </methods>\n");
+        xml.push_str("          <lines/>\n");
+        xml.push_str("        </class>\n");
+    }
+
+    xml.push_str("      </classes>\n");
+    xml.push_str("    </package>\n");
+    xml.push_str("  </packages>\n");
+    xml.push_str("</coverage>\n");
+    xml
+}
+
+/// Builds a Coveralls `source_files` JSON report from `mappings`: one element per source file with
+/// a `coverage` array sized to the file's line count, `null` for lines no `DA:` record named and a
+/// hit count for every line `lcov` did cover. Files with no matching LCOV record (or no `--lcov`
+/// at all) get an all-`null` array, the Coveralls convention for "no data" rather than "zero
+/// coverage".
+fn build_coveralls_json(
+    mappings: &HashMap<String, TestMapping>,
+    lcov: Option<&HashMap<String, LcovRecord>>,
+) -> serde_json::Value {
+    let mut sorted: Vec<&TestMapping> = mappings.values().collect();
+    sorted.sort_by(|a, b| a.source_file.cmp(&b.source_file));
+
+    let source_files: Vec<serde_json::Value> = sorted
+        .iter()
+        .map(|mapping| {
+            let mut coverage: Vec<serde_json::Value> =
+                vec![serde_json::Value::Null; mapping.total_lines];
+
+            if let Some(record) = lcov.and_then(|lcov| find_lcov_record(lcov, &mapping.source_file))
+            {
+                for &(line, hits) in &record.line_hits {
+                    let idx = line as usize;
+                    if idx >= 1 && idx <= coverage.len() {
+                        coverage[idx - 1] = serde_json::json!(hits);
+                    }
+                }
+            }
+
+            serde_json::json!({
+                "name": mapping.source_file,
+                "source_digest": mapping.content_hash,
+                "coverage": coverage,
+            })
+        })
+        .collect();
+
+    serde_json::json!({ "source_files": source_files })
+}
+
+#[cfg(test)]
+mod lcov_tests {
+    use super::*;
+
+    /// `parse_lcov`'s `DA:`/`LF:`/`LH:` parsing and [`lcov_coverage_level`]'s ratio thresholds
+    /// drive `charter tests --lcov`'s ground-truth coverage, replacing [`estimate_coverage`]'s
+    /// test-count guess — this exercises both against a real `.info` file on disk.
+    #[tokio::test]
+    async fn parse_lcov_reads_da_lf_lh_records() {
+        let path = std::env::temp_dir().join(format!(
+            "charter-test-parse-lcov-{:?}.info",
+            std::thread::current().id()
+        ));
+        fs::write(
+            &path,
+            "SF:src/foo.rs\nDA:1,3\nDA:2,0\nDA:3,1\nLF:3\nLH:2\nend_of_record\n",
+        )
+        .await
+        .unwrap();
+
+        let records = parse_lcov(&path).await.unwrap();
+        fs::remove_file(&path).await.unwrap();
+
+        let record = records.get("src/foo.rs").expect("SF: record parsed");
+        assert_eq!(record.lines_found, 3);
+        assert_eq!(record.lines_hit, 2);
+        assert_eq!(record.line_hits, vec![(1, 3), (2, 0), (3, 1)]);
+    }
+
+    #[test]
+    fn lcov_coverage_level_buckets_by_hit_ratio() {
+        let level = |found, hit| {
+            lcov_coverage_level(&LcovRecord {
+                lines_found: found,
+                lines_hit: hit,
+                line_hits: Vec::new(),
+            })
+        };
+
+        assert!(matches!(level(10, 0), CoverageLevel::None));
+        assert!(matches!(level(10, 10), CoverageLevel::High));
+        assert!(matches!(level(10, 8), CoverageLevel::High));
+        assert!(matches!(level(10, 5), CoverageLevel::Medium));
+        assert!(matches!(level(10, 1), CoverageLevel::Low));
+        assert!(matches!(level(0, 0), CoverageLevel::None));
+    }
+
+    /// `SF:` paths can be absolute, repo-relative, or relative to wherever the coverage tool ran
+    /// from, so lookup matches by suffix in either direction rather than requiring an exact match.
+    #[test]
+    fn find_lcov_record_matches_by_path_suffix() {
+        let mut lcov = HashMap::new();
+        lcov.insert(
+            "/home/ci/project/src/foo.rs".to_string(),
+            LcovRecord {
+                lines_found: 1,
+                lines_hit: 1,
+                line_hits: Vec::new(),
+            },
+        );
+
+        assert!(find_lcov_record(&lcov, "src/foo.rs").is_some());
+        assert!(find_lcov_record(&lcov, "src/bar.rs").is_none());
+    }
+}
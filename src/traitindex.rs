@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use crate::cache::Cache;
+use crate::extract::attributes::TraitImpl;
+
+/// One [`TraitImpl`] fact plus the file it came from, so a query result can point back at a
+/// location the same way [`crate::callindex::ResolvedCall`] keeps its caller's file.
+#[derive(Debug, Clone)]
+pub struct TraitImplSite {
+    pub file: String,
+    pub type_name: String,
+    pub trait_name: String,
+    pub is_derived: bool,
+    pub line: usize,
+}
+
+/// Crate-wide "which type implements which trait" index, merged from every file's `impls` list
+/// the same way [`crate::callindex::build_call_graph`] merges per-file `call_graph` entries into
+/// one crate-wide graph.
+#[derive(Debug, Clone, Default)]
+pub struct TraitIndex {
+    by_trait: HashMap<String, Vec<TraitImplSite>>,
+    by_type: HashMap<String, Vec<TraitImplSite>>,
+}
+
+impl TraitIndex {
+    /// Every type implementing `trait_name`, derived or manual.
+    pub fn implementors_of(&self, trait_name: &str) -> &[TraitImplSite] {
+        self.by_trait
+            .get(trait_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Every trait `type_name` implements, derived or manual.
+    pub fn traits_of(&self, type_name: &str) -> &[TraitImplSite] {
+        self.by_type
+            .get(type_name)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Every type in the crate that implements `type_has` but not `type_lacks` — the
+    /// "missing a commonly-derived trait" query, e.g. types deriving `PartialEq` but not `Eq`.
+    pub fn implementors_missing(&self, type_has: &str, type_lacks: &str) -> Vec<&str> {
+        self.implementors_of(type_has)
+            .iter()
+            .map(|site| site.type_name.as_str())
+            .filter(|type_name| {
+                !self
+                    .traits_of(type_name)
+                    .iter()
+                    .any(|site| site.trait_name == type_lacks)
+            })
+            .collect()
+    }
+}
+
+/// Merges every cached file's `impls` (populated per-[`TraitImpl`] by `push_derive_info` and
+/// `extract_impl`) into one crate-wide [`TraitIndex`].
+pub fn build_trait_index(cache: &Cache) -> TraitIndex {
+    let mut index = TraitIndex::default();
+
+    for (file, entry) in &cache.entries {
+        for TraitImpl {
+            type_name,
+            trait_name,
+            is_derived,
+            line,
+        } in &entry.data.parsed.impls
+        {
+            let site = TraitImplSite {
+                file: file.clone(),
+                type_name: type_name.clone(),
+                trait_name: trait_name.clone(),
+                is_derived: *is_derived,
+                line: *line,
+            };
+
+            index
+                .by_trait
+                .entry(trait_name.clone())
+                .or_default()
+                .push(site.clone());
+            index.by_type.entry(type_name.clone()).or_default().push(site);
+        }
+    }
+
+    index
+}
@@ -0,0 +1,154 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::extract::symbols::{Symbol, SymbolKind, Visibility};
+use crate::pipeline::PipelineResult;
+
+/// Whether a symbol is genuinely reachable from the crate root, or merely declared `pub` while
+/// sitting behind a private/`pub(crate)` module somewhere on the path up to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum EffectiveVisibility {
+    /// `Visibility::Public`, and every enclosing module (or a re-export) keeps it reachable.
+    PubliclyReachable,
+    /// Declared `pub`, but unreachable from the crate root — the "over-exposed" case this
+    /// subsystem exists to flag: visibility that could be tightened with no API-surface change.
+    OverExposed,
+    /// Not `Visibility::Public` at all; the effective visibility is just the declared one.
+    NotPublic,
+}
+
+/// One symbol's resolved reachability, for the structured model.
+#[derive(Debug, Clone)]
+pub struct SymbolReachability {
+    pub file: String,
+    pub name: String,
+    pub line: usize,
+    pub is_module: bool,
+    pub effective: EffectiveVisibility,
+}
+
+fn child_module_path(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_string()
+    } else {
+        format!("{parent}::{name}")
+    }
+}
+
+/// A `mod` declaration found while walking every file's symbols: the module path it introduces,
+/// its own `Visibility`, and whether it's re-exported (either of which can make it reachable
+/// regardless of its declared visibility).
+struct ModDeclaration {
+    path: String,
+    parent: String,
+    visibility: Visibility,
+    re_exported: bool,
+}
+
+fn collect_mod_declarations(result: &PipelineResult) -> Vec<ModDeclaration> {
+    let mut declarations = Vec::new();
+
+    for file in &result.files {
+        let parent = crate::output::module_path_from_file(&file.relative_path);
+        for symbol in &file.parsed.symbols.symbols {
+            if matches!(symbol.kind, SymbolKind::Mod) {
+                declarations.push(ModDeclaration {
+                    path: child_module_path(&parent, &symbol.name),
+                    parent: parent.clone(),
+                    visibility: symbol.visibility.clone(),
+                    re_exported: symbol.re_exported_as.is_some(),
+                });
+            }
+        }
+    }
+
+    declarations
+}
+
+/// BFS out from the crate root (`""`) through every `mod` declaration whose own visibility (or
+/// re-export) keeps it reachable, returning the full set of reachable module paths.
+fn reachable_modules(declarations: &[ModDeclaration]) -> HashSet<String> {
+    let mut reachable = HashSet::new();
+    reachable.insert(String::new());
+
+    let mut queue: VecDeque<String> = VecDeque::new();
+    queue.push_back(String::new());
+
+    while let Some(current) = queue.pop_front() {
+        for declaration in declarations {
+            if declaration.parent != current || reachable.contains(&declaration.path) {
+                continue;
+            }
+
+            let keeps_reachable =
+                declaration.visibility == Visibility::Public || declaration.re_exported;
+            if keeps_reachable {
+                reachable.insert(declaration.path.clone());
+                queue.push_back(declaration.path.clone());
+            }
+        }
+    }
+
+    reachable
+}
+
+/// A plain item's reachability follows its *containing* module; a `mod` declaration's own
+/// reachability instead follows the module path *it introduces*, since that's what
+/// [`reachable_modules`] actually tracked it under.
+fn effective_visibility(
+    symbol: &Symbol,
+    module: &str,
+    reachable: &HashSet<String>,
+) -> EffectiveVisibility {
+    if symbol.visibility != Visibility::Public {
+        return EffectiveVisibility::NotPublic;
+    }
+
+    if symbol.re_exported_as.is_some() {
+        return EffectiveVisibility::PubliclyReachable;
+    }
+
+    let check_path = match &symbol.kind {
+        SymbolKind::Mod => child_module_path(module, &symbol.name),
+        _ => module.to_string(),
+    };
+
+    if reachable.contains(&check_path) {
+        EffectiveVisibility::PubliclyReachable
+    } else {
+        EffectiveVisibility::OverExposed
+    }
+}
+
+/// Computes every symbol's [`EffectiveVisibility`] by walking the module tree: an item is truly
+/// public only if it's `Visibility::Public` *and* every enclosing module on the path from the
+/// crate root is itself reachably public (or the item is re-exported, which short-circuits
+/// reachability to public regardless of where it's declared). The "over-exposed" signal this
+/// crate's preamble surfaces only looks at `is_module: false` entries — a `pub` module that's
+/// itself unreachable is implied by every item inside it also coming back `OverExposed`.
+///
+/// Nested `mod foo { ... }` bodies aren't tracked separately from their containing file — a
+/// symbol's owning module is derived from its file path alone — so an inline submodule's items are
+/// attributed to the file's top-level module. This mirrors the same file-granularity
+/// [`crate::resolve::resolve_imports`] already assumes for this crate's module tree.
+pub fn compute_reachability(result: &PipelineResult) -> Vec<SymbolReachability> {
+    let declarations = collect_mod_declarations(result);
+    let reachable = reachable_modules(&declarations);
+
+    let mut reachability = Vec::new();
+
+    for file in &result.files {
+        let module = crate::output::module_path_from_file(&file.relative_path);
+
+        for symbol in &file.parsed.symbols.symbols {
+            reachability.push(SymbolReachability {
+                file: file.relative_path.clone(),
+                name: symbol.name.clone(),
+                line: symbol.line,
+                is_module: matches!(symbol.kind, SymbolKind::Mod),
+                effective: effective_visibility(symbol, &module, &reachable),
+            });
+        }
+    }
+
+    reachability
+}
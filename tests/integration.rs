@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::Once;
 
@@ -6,23 +6,57 @@ static INIT_SIMPLE: Once = Once::new();
 static INIT_IMPL: Once = Once::new();
 static INIT_NESTED: Once = Once::new();
 
+/// Locates the real `name` executable via `PATH` instead of handing the bare name to
+/// `Command::new`, which on Windows would consult the current directory first — exactly the
+/// fixture directory these tests `current_dir` into. Mirrors `git::resolve_executable` in the
+/// main crate; duplicated here since this test binary doesn't link against it.
+fn resolve_executable(name: &str) -> PathBuf {
+    let candidates: Vec<String> = if cfg!(windows) {
+        let pathext =
+            std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".to_string());
+        pathext
+            .split(';')
+            .filter(|ext| !ext.is_empty())
+            .map(|ext| format!("{name}{ext}"))
+            .collect()
+    } else {
+        vec![name.to_string()]
+    };
+
+    if let Some(path_var) = std::env::var_os("PATH") {
+        for dir in std::env::split_paths(&path_var) {
+            if dir.as_os_str().is_empty() || dir == Path::new(".") {
+                continue;
+            }
+            for candidate in &candidates {
+                let full = dir.join(candidate);
+                if full.is_file() {
+                    return full;
+                }
+            }
+        }
+    }
+
+    PathBuf::from(name)
+}
+
 fn setup_fixture(fixture_path: &Path) {
     let charter_dir = fixture_path.join(".charter");
     let _ = std::fs::remove_dir_all(&charter_dir);
 
     let git_dir = fixture_path.join(".git");
     if !git_dir.exists() {
-        Command::new("git")
+        Command::new(resolve_executable("git"))
             .args(["init"])
             .current_dir(fixture_path)
             .output()
             .expect("Failed to init git");
-        Command::new("git")
+        Command::new(resolve_executable("git"))
             .args(["add", "."])
             .current_dir(fixture_path)
             .output()
             .expect("Failed to git add");
-        Command::new("git")
+        Command::new(resolve_executable("git"))
             .args(["commit", "-m", "init"])
             .current_dir(fixture_path)
             .output()
@@ -92,10 +126,13 @@ mod simple_crate {
             "symbols.md",
             "types.md",
             "calls.md",
+            "callgraph.md",
             "hotspots.md",
+            "churn.md",
             "manifest.md",
             "refs.md",
             "dependents.md",
+            "imports.md",
             "clusters.md",
             "dataflow.md",
             "errors.md",
@@ -203,6 +240,18 @@ mod simple_crate {
         );
     }
 
+    #[test]
+    fn ranks_churn_risk() {
+        setup();
+        let path = fixture_path();
+
+        let churn = read_charter_file(&path, "churn.md");
+        assert!(
+            churn.contains("complex_function"),
+            "Should rank complex_function by churn x complexity risk"
+        );
+    }
+
     #[test]
     fn tracks_trait_implementations() {
         setup();
@@ -668,17 +717,17 @@ mod nested_workspace {
             let _ = std::fs::remove_dir_all(child.join(".charter"));
 
             if !parent.join(".git").exists() {
-                Command::new("git")
+                Command::new(resolve_executable("git"))
                     .args(["init"])
                     .current_dir(&parent)
                     .output()
                     .expect("Failed to init git");
-                Command::new("git")
+                Command::new(resolve_executable("git"))
                     .args(["add", "."])
                     .current_dir(&parent)
                     .output()
                     .expect("Failed to git add");
-                Command::new("git")
+                Command::new(resolve_executable("git"))
                     .args(["commit", "-m", "init"])
                     .current_dir(&parent)
                     .output()
@@ -756,17 +805,17 @@ mod cache_invalidation {
 
         let git_dir = path.join(".git");
         if !git_dir.exists() {
-            Command::new("git")
+            Command::new(resolve_executable("git"))
                 .args(["init"])
                 .current_dir(&path)
                 .output()
                 .expect("Failed to init git");
-            Command::new("git")
+            Command::new(resolve_executable("git"))
                 .args(["add", "."])
                 .current_dir(&path)
                 .output()
                 .expect("Failed to git add");
-            Command::new("git")
+            Command::new(resolve_executable("git"))
                 .args(["commit", "-m", "init"])
                 .current_dir(&path)
                 .output()
@@ -789,7 +838,6 @@ mod cache_invalidation {
         );
 
         std::fs::write(mutable_file(), MODIFIED_MUTABLE).expect("Failed to modify mutable.rs");
-        std::thread::sleep(std::time::Duration::from_millis(100));
 
         let (success, stdout, _) = run_charter_command(&path, &[]);
         assert!(success, "charter should succeed after modification");
@@ -830,7 +878,6 @@ mod cache_invalidation {
 
         std::fs::remove_file(mutable_file()).expect("Failed to delete mutable.rs");
         std::fs::write(lib_file(), LIB_WITHOUT_MUTABLE).expect("Failed to update lib.rs");
-        std::thread::sleep(std::time::Duration::from_millis(100));
 
         let (success, stdout, _) = run_charter_command(&path, &[]);
         assert!(success, "charter should succeed after deletion");
@@ -878,7 +925,6 @@ mod cache_invalidation {
             "pub mod stable;\npub mod mutable;\npub mod added;\n",
         )
         .expect("Failed to update lib.rs");
-        std::thread::sleep(std::time::Duration::from_millis(100));
 
         let (success, stdout, _) = run_charter_command(&path, &[]);
         assert!(success, "charter should succeed after addition");